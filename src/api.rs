@@ -0,0 +1,266 @@
+//! Entry points for embedding UVg: introspect a database you already have
+//! a pool for (or a [`ConnectionConfig`]), then render the schema with any
+//! of the single-file generators. The binary goes through the same calls.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), uvg::error::UvgError> {
+//! let pool = sqlx::PgPool::connect("postgresql://localhost/app").await?;
+//! let schema = uvg::introspect_postgres(&pool, &uvg::IntrospectOptions::default()).await?;
+//! let models = uvg::generate(
+//!     &schema,
+//!     uvg::GeneratorKind::Declarative,
+//!     &uvg::cli::GeneratorOptions::default(),
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use sqlx::{MySqlPool, PgPool, SqlitePool};
+
+use crate::cli::GeneratorOptions;
+use crate::codegen::{
+    dbml, declarative, django, dot, fastapi, fixtures, proto, report, repositories, tables,
+};
+use crate::connection::ConnectionConfig;
+use crate::dialect::Dialect;
+use crate::error::UvgError;
+use crate::introspect::{mysql, pg, sqlite};
+use crate::primary_keys;
+use crate::schema::IntrospectedSchema;
+use crate::table_filter::TableFilter;
+
+/// What to introspect. The default reads every table and view of the
+/// dialect's default schema (the connected database on MySQL).
+#[derive(Debug)]
+pub struct IntrospectOptions {
+    /// Schemas to read; empty for the default schema.
+    pub schemas: Vec<String>,
+    pub table_filter: TableFilter,
+    pub noviews: bool,
+    /// Concurrent introspection queries.
+    pub concurrency: usize,
+}
+
+impl Default for IntrospectOptions {
+    fn default() -> Self {
+        Self {
+            schemas: Vec::new(),
+            table_filter: TableFilter::allow_all(),
+            noviews: false,
+            concurrency: 8,
+        }
+    }
+}
+
+impl IntrospectOptions {
+    fn schemas_or(&self, default: &str) -> Vec<String> {
+        if self.schemas.is_empty() {
+            vec![default.to_string()]
+        } else {
+            self.schemas.clone()
+        }
+    }
+}
+
+/// Connect with `config`, introspect, and disconnect. Connection failures
+/// come back as [`UvgError::Connection`], later ones as
+/// [`UvgError::Introspection`].
+pub async fn introspect(
+    config: ConnectionConfig,
+    options: &IntrospectOptions,
+) -> Result<IntrospectedSchema, UvgError> {
+    let schemas = match config.database_name() {
+        Some(db) => options.schemas_or(&db),
+        None => options.schemas_or(config.dialect().default_schema()),
+    };
+    crate::db::introspect_with_config(
+        config,
+        &schemas,
+        &options.table_filter,
+        options.noviews,
+        &GeneratorOptions::default(),
+        options.concurrency,
+    )
+    .await
+}
+
+/// Introspect a PostgreSQL database through an existing pool. Failures
+/// come back as [`UvgError::Introspection`].
+pub async fn introspect_postgres(
+    pool: &PgPool,
+    options: &IntrospectOptions,
+) -> Result<IntrospectedSchema, UvgError> {
+    pg::introspect(
+        pool,
+        &options.schemas_or(Dialect::Postgres.default_schema()),
+        &options.table_filter,
+        options.noviews,
+        &GeneratorOptions::default(),
+        options.concurrency,
+    )
+    .await
+    .map_err(UvgError::introspection)
+}
+
+/// Introspect a MySQL or MariaDB database through an existing pool. The
+/// default schema is the pool's current database.
+pub async fn introspect_mysql(
+    pool: &MySqlPool,
+    options: &IntrospectOptions,
+) -> Result<IntrospectedSchema, UvgError> {
+    let schemas = if options.schemas.is_empty() {
+        let current: Option<String> = sqlx::query_scalar("SELECT DATABASE()")
+            .fetch_one(pool)
+            .await
+            .map_err(|e| UvgError::introspection(e.into()))?;
+        vec![current.unwrap_or_default()]
+    } else {
+        options.schemas.clone()
+    };
+    mysql::introspect(
+        pool,
+        &schemas,
+        &options.table_filter,
+        options.noviews,
+        &GeneratorOptions::default(),
+        options.concurrency,
+    )
+    .await
+    .map_err(UvgError::introspection)
+}
+
+/// Introspect a SQLite database through an existing pool. `schemas` and
+/// `concurrency` do not apply.
+pub async fn introspect_sqlite(
+    pool: &SqlitePool,
+    options: &IntrospectOptions,
+) -> Result<IntrospectedSchema, UvgError> {
+    sqlite::introspect(
+        pool,
+        &options.table_filter,
+        options.noviews,
+        &GeneratorOptions::default(),
+    )
+    .await
+    .map_err(UvgError::introspection)
+}
+
+/// The generators whose whole output is one string of text. `ddl`,
+/// `seed`, and `json` take more than [`GeneratorOptions`]; use
+/// [`crate::codegen::ddl::DdlGenerator`], [`crate::codegen::seed::generate`],
+/// and [`crate::snapshot::to_json`] for those.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratorKind {
+    Tables,
+    Declarative,
+    Repositories,
+    Fastapi,
+    Django,
+    Dbml,
+    Proto,
+    Report,
+    TestFixtures,
+    /// A Graphviz diagram of every table.
+    Dot,
+}
+
+impl FromStr for GeneratorKind {
+    type Err = UvgError;
+
+    /// The `--generator` name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "tables" => GeneratorKind::Tables,
+            "declarative" => GeneratorKind::Declarative,
+            "repositories" => GeneratorKind::Repositories,
+            "fastapi" => GeneratorKind::Fastapi,
+            "django" => GeneratorKind::Django,
+            "dbml" => GeneratorKind::Dbml,
+            "proto" => GeneratorKind::Proto,
+            "report" => GeneratorKind::Report,
+            "test-fixtures" => GeneratorKind::TestFixtures,
+            "dot" => GeneratorKind::Dot,
+            other => return Err(UvgError::UnknownGenerator(other.to_string())),
+        })
+    }
+}
+
+impl GeneratorKind {
+    /// Whether the generator maps tables to ORM classes, which need a
+    /// primary key; only these get the stand-in keys of
+    /// [`crate::primary_keys`].
+    pub fn maps_orm_classes(self) -> bool {
+        matches!(
+            self,
+            GeneratorKind::Declarative
+                | GeneratorKind::Repositories
+                | GeneratorKind::Fastapi
+                | GeneratorKind::Django
+                | GeneratorKind::TestFixtures
+        )
+    }
+}
+
+/// `schema` with the stand-in primary keys `options` ask for, when `kind`
+/// maps ORM classes.
+fn keyed<'a>(
+    schema: &'a IntrospectedSchema,
+    kind: GeneratorKind,
+    options: &GeneratorOptions,
+) -> Cow<'a, IntrospectedSchema> {
+    if !kind.maps_orm_classes() || !primary_keys::requested(options) {
+        return Cow::Borrowed(schema);
+    }
+    let mut schema = schema.clone();
+    primary_keys::apply(&mut schema, options);
+    Cow::Owned(schema)
+}
+
+/// Render `schema` with one generator. The ORM generators first get the
+/// stand-in primary keys `options` ask for (`synthesize_pk`,
+/// `infer_view_pk`, `view_pks`).
+pub fn generate(
+    schema: &IntrospectedSchema,
+    kind: GeneratorKind,
+    options: &GeneratorOptions,
+) -> String {
+    let schema = &*keyed(schema, kind, options);
+    match kind {
+        GeneratorKind::Tables => tables::generate(schema, options),
+        GeneratorKind::Declarative => declarative::generate(schema, options),
+        GeneratorKind::Repositories => repositories::generate(schema, options),
+        GeneratorKind::Fastapi => fastapi::generate(schema, options),
+        GeneratorKind::Django => django::generate(schema, options),
+        GeneratorKind::Dbml => dbml::generate(schema, options),
+        GeneratorKind::Proto => proto::generate(schema, options),
+        GeneratorKind::Report => report::generate(schema),
+        GeneratorKind::TestFixtures => fixtures::generate(schema, options),
+        GeneratorKind::Dot => dot::generate(schema, &[], 0),
+    }
+}
+
+/// Render `schema` as a Python package, `(path, content)` per file: one
+/// module per table, a shared `base.py`, and an `__init__.py`. `None` for
+/// generators without a package layout. Stand-in primary keys apply as in
+/// [`generate`].
+pub fn generate_split(
+    schema: &IntrospectedSchema,
+    kind: GeneratorKind,
+    options: &GeneratorOptions,
+) -> Option<Vec<(String, String)>> {
+    let schema = &*keyed(schema, kind, options);
+    match kind {
+        GeneratorKind::Tables => Some(tables::generate_split(schema, options)),
+        GeneratorKind::Declarative => Some(declarative::generate_split(schema, options)),
+        GeneratorKind::Repositories => Some(repositories::generate_split(schema, options)),
+        GeneratorKind::Fastapi => Some(fastapi::generate_split(schema, options)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "api_tests.rs"]
+mod tests;
@@ -0,0 +1,87 @@
+use super::*;
+use crate::testutil::*;
+
+fn users() -> IntrospectedSchema {
+    schema_pg(vec![table("users")
+        .column(col("id").build())
+        .column(col("email").udt("varchar").max_length(255).build())
+        .pk("users_pkey", &["id"])
+        .build()])
+}
+
+#[test]
+fn generator_names_parse_to_kinds() {
+    assert_eq!(
+        "declarative".parse::<GeneratorKind>().unwrap(),
+        GeneratorKind::Declarative
+    );
+    assert_eq!(
+        "test-fixtures".parse::<GeneratorKind>().unwrap(),
+        GeneratorKind::TestFixtures
+    );
+    assert!(matches!(
+        "ddl".parse::<GeneratorKind>(),
+        Err(UvgError::UnknownGenerator(name)) if name == "ddl"
+    ));
+}
+
+#[test]
+fn generate_matches_the_generator_module() {
+    let schema = users();
+    let options = GeneratorOptions::default();
+    assert_eq!(
+        generate(&schema, GeneratorKind::Declarative, &options),
+        declarative::generate(&schema, &options)
+    );
+    assert_eq!(
+        generate(&schema, GeneratorKind::Dot, &options),
+        dot::generate(&schema, &[], 0)
+    );
+}
+
+#[test]
+fn split_layout_only_for_python_packages() {
+    let schema = users();
+    let options = GeneratorOptions::default();
+    let files = generate_split(&schema, GeneratorKind::Tables, &options).unwrap();
+    assert!(files.iter().any(|(path, _)| path == "__init__.py"));
+    assert!(generate_split(&schema, GeneratorKind::Dbml, &options).is_none());
+}
+
+#[test]
+fn stand_in_keys_apply_to_orm_generators_only() {
+    let schema = schema_pg(vec![table("logs")
+        .column(col("msg").udt("text").build())
+        .build()]);
+    let options = GeneratorOptions {
+        synthesize_pk: true,
+        ..GeneratorOptions::default()
+    };
+    let models = generate(&schema, GeneratorKind::Declarative, &options);
+    assert!(models.contains("class Logs(Base):"), "{models}");
+    let files = generate_split(&schema, GeneratorKind::Declarative, &options).unwrap();
+    assert!(files
+        .iter()
+        .any(|(_, content)| content.contains("class Logs(Base):")));
+    let tables = generate(&schema, GeneratorKind::Tables, &options);
+    assert!(!tables.contains("logs_pkey"), "{tables}");
+    assert!(!tables.contains("primary_key=True"), "{tables}");
+    assert!(schema.tables[0].constraints.is_empty());
+}
+
+#[tokio::test]
+async fn introspects_through_a_caller_owned_sqlite_pool() {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let schema = introspect_sqlite(&pool, &IntrospectOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(schema.tables.len(), 1);
+    let output = generate(&schema, GeneratorKind::Tables, &GeneratorOptions::default());
+    assert!(output.contains("t_users = Table("));
+}
@@ -0,0 +1,70 @@
+//! On-disk introspection cache for `--from-cache` / `--refresh`.
+//!
+//! Each entry is a regular snapshot file, so a cached schema can also be
+//! inspected or passed back as `@<path>`. Entries hold the unfiltered
+//! introspection of a URL + schema list; table filters and `--noviews` are
+//! applied after loading, so changing them reuses the same entry.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+
+use crate::redaction::redact_connection_url;
+use crate::schema::IntrospectedSchema;
+use crate::snapshot;
+
+/// Resolve the cache directory, in order: `$XDG_CACHE_HOME/uvg/`,
+/// `$HOME/.cache/uvg/` (Unix), then `%LOCALAPPDATA%\uvg\cache\` (Windows).
+pub fn default_cache_dir() -> Result<PathBuf> {
+    if let Some(cache_home) = std::env::var_os("XDG_CACHE_HOME") {
+        return Ok(PathBuf::from(cache_home).join("uvg"));
+    }
+    if let Some(home) = std::env::var_os("HOME") {
+        return Ok(PathBuf::from(home).join(".cache").join("uvg"));
+    }
+    if let Some(local) = std::env::var_os("LOCALAPPDATA") {
+        return Ok(PathBuf::from(local).join("uvg").join("cache"));
+    }
+    bail!("cannot locate a cache directory: set XDG_CACHE_HOME, HOME, or LOCALAPPDATA")
+}
+
+/// File name of the entry for `url` + `schemas`. Credentials are redacted
+/// before hashing so passwords never reach the key; schema order does not
+/// matter.
+pub fn entry_name(url: &str, schemas: &[String]) -> String {
+    let mut schemas = schemas.to_vec();
+    schemas.sort();
+    schemas.dedup();
+    let key = format!("{}\n{}", redact_connection_url(url), schemas.join("\n"));
+    format!("{:016x}.yaml", fnv1a(key.as_bytes()))
+}
+
+/// Path of the entry for `url` + `schemas` under `dir`.
+pub fn entry_path(dir: &Path, url: &str, schemas: &[String]) -> PathBuf {
+    dir.join(entry_name(url, schemas))
+}
+
+/// Load a cached schema, or `None` when there is no entry yet.
+pub fn load(path: &Path) -> Result<Option<IntrospectedSchema>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    snapshot::load(path).map(Some)
+}
+
+/// Write (or overwrite) a cache entry.
+pub fn store(path: &Path, schema: &IntrospectedSchema) -> Result<()> {
+    snapshot::write(path, schema)
+}
+
+/// 64-bit FNV-1a. Stable across Rust releases, unlike `DefaultHasher`, so
+/// cache keys survive a toolchain upgrade.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+#[path = "cache_tests.rs"]
+mod tests;
@@ -0,0 +1,52 @@
+use super::*;
+use crate::testutil::{col, schema_pg, table};
+
+fn schemas(names: &[&str]) -> Vec<String> {
+    names.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn entry_name_ignores_credentials_and_schema_order() {
+    let a = entry_name(
+        "postgresql://alice:secret@db/app",
+        &schemas(&["public", "sales"]),
+    );
+    let b = entry_name(
+        "postgresql://bob:other@db/app",
+        &schemas(&["sales", "public"]),
+    );
+
+    assert_eq!(a, b);
+    assert!(a.ends_with(".yaml"));
+}
+
+#[test]
+fn entry_name_distinguishes_urls_and_schemas() {
+    let base = entry_name("postgresql://db/app", &schemas(&["public"]));
+
+    assert_ne!(
+        base,
+        entry_name("postgresql://db/other", &schemas(&["public"]))
+    );
+    assert_ne!(
+        base,
+        entry_name("postgresql://db/app", &schemas(&["public", "sales"]))
+    );
+}
+
+#[test]
+fn store_then_load_round_trips() {
+    let dir = std::env::temp_dir().join(format!("uvg-cache-test-{}", std::process::id()));
+    let path = entry_path(&dir, "postgresql://db/app", &schemas(&["public"]));
+    let schema = schema_pg(vec![table("users")
+        .column(col("id").udt("int4").build())
+        .build()]);
+
+    assert!(load(&path).unwrap().is_none());
+    store(&path, &schema).unwrap();
+    let loaded = load(&path).unwrap().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(loaded.tables.len(), 1);
+    assert_eq!(loaded.tables[0].name, "users");
+}
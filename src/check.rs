@@ -0,0 +1,35 @@
+//! Drift check (`--check`): compare regenerated output with what is on disk.
+
+use similar::TextDiff;
+
+/// Unified diff from `existing` to `generated`, labelled with `path`, or
+/// `None` when they are identical.
+pub fn unified_diff(path: &str, existing: &str, generated: &str) -> Option<String> {
+    if existing == generated {
+        return None;
+    }
+    Some(
+        TextDiff::from_lines(existing, generated)
+            .unified_diff()
+            .header(&format!("a/{path}"), &format!("b/{path}"))
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_output_has_no_diff() {
+        assert_eq!(unified_diff("models.py", "a\nb\n", "a\nb\n"), None);
+    }
+
+    #[test]
+    fn changed_line_is_reported_as_unified_diff() {
+        let diff = unified_diff("models.py", "a\nb\nc\n", "a\nB\nc\n").unwrap();
+        assert!(diff.starts_with("--- a/models.py\n+++ b/models.py\n"));
+        assert!(diff.contains("@@ -1,3 +1,3 @@"));
+        assert!(diff.contains("-b\n+B\n"));
+    }
+}
@@ -4,6 +4,7 @@ use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand};
 
 pub use crate::connection::ConnectionConfig;
 use crate::dialect::Dialect;
+use crate::naming::{is_python_identifier, RenameMap};
 
 pub const DEFAULT_INTROSPECT_CONCURRENCY: usize = 8;
 
@@ -20,13 +21,14 @@ pub struct Cli {
     #[arg(long, env = "UVG_PROFILE")]
     pub profile: Option<String>,
 
-    /// Source database URL (e.g. postgresql://, mysql://, sqlite:///path, mssql://)
+    /// Source database URL (e.g. postgresql://, mysql://, sqlite:///path, mssql://),
+    /// snapshot (@file.yaml, file.json), or SQL DDL script (file.sql)
     pub url: Option<String>,
 
     /// Target database URL for DDL generation/migration (optional)
     pub target_url: Option<String>,
 
-    /// Code generator to use (declarative, tables, ddl)
+    /// Code generator to use (declarative, tables, ddl, seed, repositories, fastapi, test-fixtures, json, dot, dbml, django, proto, report, template)
     #[arg(long, default_value = "declarative")]
     pub generator: String,
 
@@ -38,6 +40,13 @@ pub struct Cli {
     #[arg(long)]
     pub split_tables: bool,
 
+    /// With `--generator ddl`: write one file per table under a
+    /// subdirectory per schema (`<schema>/<table>.sql`). With the Python
+    /// generators: write one module per schema (`sales.py`, `billing.py`)
+    /// next to the shared `base.py`. Implies `--split-tables`.
+    #[arg(long)]
+    pub split_by_schema: bool,
+
     /// Execute generated DDL against the target database after rendering it.
     /// Requires a target URL. Combines naturally with `--out-dir`: the
     /// per-table files are written first, then applied in manifest order.
@@ -50,6 +59,11 @@ pub struct Cli {
     #[arg(long, value_enum, default_value_t = crate::apply_progress::ProgressMode::Auto)]
     pub progress: crate::apply_progress::ProgressMode,
 
+    /// How errors are written to stderr: `text`, or `json` for one object
+    /// with the error kind, exit code, message and causes
+    #[arg(long, value_enum, global = true, default_value_t = crate::error::ErrorFormat::Text)]
+    pub error_format: crate::error::ErrorFormat,
+
     /// Maximum retry attempts per statement on CLI or interactive apply for transient
     /// errors (deadlock, lock-wait timeout, brief connection drops).
     /// Logical errors (constraint, syntax, missing column) fail
@@ -74,30 +88,131 @@ pub struct Cli {
     #[arg(long)]
     pub risk_classify: bool,
 
-    /// Concurrent table metadata queries for PostgreSQL/MySQL introspection
-    #[arg(long, env = "UVG_INTROSPECT_CONCURRENCY", default_value_t = DEFAULT_INTROSPECT_CONCURRENCY, value_parser = parse_positive_usize)]
+    /// Rows per table for `--generator seed`
+    #[arg(long, value_name = "N", default_value_t = 10, value_parser = parse_positive_usize)]
+    pub seed_rows: usize,
+
+    /// Script `--generator seed` writes: `sql`, or `python` to run the
+    /// same statements through SQLAlchemy
+    #[arg(long, value_enum, value_name = "FORMAT", default_value_t = crate::codegen::seed::SeedFormat::Sql)]
+    pub seed_format: crate::codegen::seed::SeedFormat,
+
+    /// With `--generator dot`: draw only these tables (comma-delimited,
+    /// `table` or `schema.table`) and their FK neighbors
+    #[arg(long, value_name = "TABLES")]
+    pub focus: Option<String>,
+
+    /// FK hops of neighbors to include around `--focus` tables
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    pub focus_depth: usize,
+
+    /// With `--generator template`: the Tera template to render the schema
+    /// through
+    #[arg(long, value_name = "FILE")]
+    pub template: Option<PathBuf>,
+
+    /// Sample up to N rows per table and annotate columns whose declared
+    /// nullability or length looks wrong (advisory comments plus a stderr
+    /// report). Off by default; reads table data.
+    #[arg(long, value_name = "N", value_parser = parse_positive_usize)]
+    pub sample: Option<usize>,
+
+    /// Capture optimizer statistics (null fraction, distinct estimate,
+    /// most common values) from pg_stats / SQL Server histograms into the
+    /// schema model, e.g. for snapshots. Off by default
+    #[arg(long)]
+    pub stats: bool,
+
+    /// Column extended properties (MSSQL) to copy into `info={...}` on
+    /// generated columns (comma-delimited property names)
+    #[arg(long, value_name = "KEYS")]
+    pub info_properties: Option<String>,
+
+    /// Flag columns that look like personal data (by name and type) with
+    /// `info={'pii': True, ...}` in the generated models
+    #[arg(long)]
+    pub pii: bool,
+
+    /// Write a CSV report of likely personal-data columns to FILE
+    #[arg(long, value_name = "FILE")]
+    pub pii_report: Option<PathBuf>,
+
+    /// Concurrent metadata queries during introspection: the PostgreSQL and
+    /// MySQL connection pool size and the number of MSSQL connections
+    #[arg(long, visible_alias = "jobs", env = "UVG_INTROSPECT_CONCURRENCY", default_value_t = DEFAULT_INTROSPECT_CONCURRENCY, value_parser = parse_positive_usize)]
     pub introspect_concurrency: usize,
 
+    /// Reuse the cached introspection of this URL and schema list instead
+    /// of querying the database; introspects and fills the cache on a miss
+    #[arg(long)]
+    pub from_cache: bool,
+
+    /// Re-introspect and overwrite the cache entry `--from-cache` reads
+    #[arg(long)]
+    pub refresh: bool,
+
     /// Tables to process (comma-delimited). Each item is a glob pattern
-    /// (`*`, `?`, `[abc]`); bare names with no metacharacters match
-    /// exactly. Default: all tables.
+    /// (`*`, `?`, `[abc]`, `%` as a synonym for `*`) or, with a `re:`
+    /// prefix, a regular expression; bare names with no metacharacters
+    /// match exactly. Default: all tables.
     #[arg(long)]
     pub tables: Option<String>,
 
     /// Tables to exclude (comma-delimited), evaluated after `--tables`.
-    /// Same glob syntax as `--tables`.
+    /// Same pattern syntax as `--tables`.
     #[arg(long)]
     pub exclude_tables: Option<String>,
 
-    /// Schemas to load (comma-delimited)
+    /// Add every table the `--tables` selection reaches through foreign
+    /// keys, up to DEPTH hops (no limit when bare), so generated models
+    /// reference no missing tables
+    #[arg(long, value_name = "DEPTH", num_args = 0..=1, require_equals = true)]
+    pub follow_fks: Option<Option<usize>>,
+
+    /// With `--follow-fks`: also add tables that reference the selection
+    #[arg(long, requires = "follow_fks")]
+    pub follow_fks_both: bool,
+
+    /// Foreign keys to tables outside the selection: keep them (with a
+    /// warning), drop them (with a warning), or include the referenced
+    /// tables
+    #[arg(long, value_enum, value_name = "MODE", default_value_t = crate::table_filter::DanglingFks::Keep)]
+    pub dangling_fks: crate::table_filter::DanglingFks,
+
+    /// Table kinds to exclude (comma-delimited): temp, partitions,
+    /// history, migrations, tables, foreign. Applied after `--tables`/`--exclude-tables`.
+    #[arg(long)]
+    pub exclude_kinds: Option<String>,
+
+    /// Schemas to load (comma-delimited). Items may be patterns, with the
+    /// same syntax as `--tables` (`tenant_%`, `*`, `re:^shard\d+$`),
+    /// matched against every non-system schema
     #[arg(long)]
     pub schemas: Option<String>,
 
+    /// Load every non-system schema; same as `--schemas '*'`
+    #[arg(long, conflicts_with = "schemas")]
+    pub all_schemas: bool,
+
     /// Ignore views
     #[arg(long)]
     pub noviews: bool,
 
-    /// Generator options (comma-delimited): noindexes, noconstraints, nocomments, nobidi, nofknames, noidsuffix, nosyntheticenums, nonativeenums, keep_dialect_types
+    /// Generate views only, e.g. to document a reporting layer
+    #[arg(long, conflicts_with = "noviews")]
+    pub views_only: bool,
+
+    /// Keep PostgreSQL partition children, which are skipped by default
+    /// in favour of their partitioned parent
+    #[arg(long)]
+    pub include_partitions: bool,
+
+    /// Include PostgreSQL foreign (FDW) tables, emitted as read-only
+    /// `Table()` objects naming their server
+    #[arg(long)]
+    pub include_foreign_tables: bool,
+
+    /// Generator options (comma-delimited): noindexes, noconstraints, nocomments, notablecomments, nocolumncomments, nobidi, nofknames, noidsuffix, nosyntheticenums, enum_from_check, nonativeenums, keep_dialect_types, noassociations, use_inflect, managed, proto_wrappers, async, pep604, future_annotations, schema_prefix, citext, geoalchemy, func_defaults, naming_convention, repr, infer_view_pk, synthesize_pk, mixins, extensions_header, noheader
     #[arg(long)]
     pub options: Option<String>,
 
@@ -105,12 +220,26 @@ pub struct Cli {
     #[arg(long)]
     pub outfile: Option<String>,
 
+    /// Compare generated output with FILE (or `--outfile`) instead of
+    /// writing it; print a unified diff and exit non-zero on drift
+    #[arg(long, value_name = "FILE", num_args = 0..=1, conflicts_with_all = ["apply", "interactive"])]
+    pub check: Option<Option<PathBuf>>,
+
+    /// Exit non-zero when the run reports any warning (type fallbacks,
+    /// dropped defaults, skipped objects, renamed columns); the output is
+    /// still written
+    #[arg(long)]
+    pub strict: bool,
+
     /// Write per-table DDL diff into this directory. One subdir per
     /// modified table plus `_schema/` for non-table-scoped DDL and
     /// `_runs/` for the manifest. Empty diffs write nothing.
-    /// Only meaningful for the `ddl` generator with a target URL.
-    /// `--outfile` takes precedence if both are set.
-    #[arg(long)]
+    /// For the `ddl` generator this needs a target URL; `--outfile` takes
+    /// precedence if both are set. For the Python generators (declarative,
+    /// tables, repositories, fastapi) it writes a package instead: one
+    /// module per table, a shared `base.py` and an `__init__.py`
+    /// re-exporting everything.
+    #[arg(long, alias = "outdir")]
     pub out_dir: Option<PathBuf>,
 
     /// Slug used in `--out-dir` filenames. Defaults to
@@ -118,10 +247,67 @@ pub struct Cli {
     #[arg(long)]
     pub name: Option<String>,
 
+    /// TOML file overriding the generated SQLAlchemy type for udt names or
+    /// `schema.table.column` paths
+    #[arg(long, value_name = "FILE")]
+    pub typemap: Option<PathBuf>,
+
+    /// Import the declarative Base from an existing module instead of
+    /// declaring one, e.g. `myapp.db:Base`
+    #[arg(long, value_name = "MODULE:NAME", value_parser = parse_base_class, conflicts_with = "base_name")]
+    pub base_class: Option<BaseClass>,
+
+    /// Name of the generated declarative Base class (default `Base`)
+    #[arg(long, value_name = "NAME", value_parser = parse_python_identifier)]
+    pub base_name: Option<String>,
+
+    /// TOML file mapping table names to class names (`[tables]`) and
+    /// `table.column` paths to attribute names (`[columns]`)
+    #[arg(long, value_name = "FILE")]
+    pub rename_map: Option<PathBuf>,
+
+    /// Comma-delimited table-name prefixes to drop from generated class and
+    /// variable names, e.g. `tbl_`
+    #[arg(long, value_name = "LIST")]
+    pub strip_prefix: Option<String>,
+
+    /// Comma-delimited `view.column` (or `schema.view.column`) entries
+    /// naming the primary key of views, so they map as classes. Several
+    /// entries for one view make a composite key.
+    #[arg(long, value_name = "LIST")]
+    pub view_pk: Option<String>,
+
     /// Trust the server certificate (MSSQL only)
     #[arg(long)]
     pub trust_cert: bool,
 
+    /// MSSQL authentication: `sql` (URL user/password), `windows`
+    /// (NTLM/Kerberos; current login when the URL has no user), or
+    /// `azure-ad` (access token as the URL password)
+    #[arg(long, value_enum, default_value_t = crate::connection::MssqlAuth::Sql)]
+    pub auth: crate::connection::MssqlAuth,
+
+    /// MSSQL encryption: `required` (whole session), `login` (login packet
+    /// only, for older servers), or `off`
+    #[arg(long, value_enum, default_value_t = crate::connection::MssqlEncrypt::Required)]
+    pub encrypt: crate::connection::MssqlEncrypt,
+
+    /// PostgreSQL `sslmode`; a `sslmode` already in the URL wins
+    #[arg(long, value_parser = ["disable", "allow", "prefer", "require", "verify-ca", "verify-full"])]
+    pub sslmode: Option<String>,
+
+    /// PostgreSQL CA certificate used to verify the server (`sslrootcert`)
+    #[arg(long)]
+    pub sslrootcert: Option<PathBuf>,
+
+    /// PostgreSQL client certificate (`sslcert`)
+    #[arg(long)]
+    pub sslcert: Option<PathBuf>,
+
+    /// PostgreSQL client private key (`sslkey`)
+    #[arg(long)]
+    pub sslkey: Option<PathBuf>,
+
     /// Launch interactive TUI for DDL diff and apply
     #[arg(long, short = 'i')]
     pub interactive: bool,
@@ -155,6 +341,9 @@ pub enum Command {
 
     /// Capture an introspected schema snapshot as YAML
     Snapshot(SnapshotCommand),
+
+    /// Report structural differences between two schemas
+    Diff(DiffCommand),
 }
 
 #[derive(Args, Debug, Clone)]
@@ -267,17 +456,133 @@ pub struct SnapshotCommand {
     pub output: PathBuf,
 }
 
+#[derive(Args, Debug, Clone)]
+pub struct DiffCommand {
+    /// Schema A: database URL, `@snapshot.yaml`, or `.sql` file
+    pub source: String,
+
+    /// Schema B, compared against A
+    pub target: String,
+
+    /// Report format
+    #[arg(long, value_parser = ["text", "json"], default_value = "text")]
+    pub format: String,
+
+    /// Write the report to a file instead of stdout
+    #[arg(long, short = 'o')]
+    pub output: Option<PathBuf>,
+}
+
 #[derive(Debug, Default)]
 pub struct GeneratorOptions {
     pub noindexes: bool,
     pub noconstraints: bool,
     pub nocomments: bool,
+    /// Leave out table comments but keep the rest (`nocomments` implies it).
+    pub notablecomments: bool,
+    /// Leave out column comments but keep the rest (`nocomments` implies it).
+    pub nocolumncomments: bool,
     pub nobidi: bool,
     pub nofknames: bool,
     pub noidsuffix: bool,
     pub nosyntheticenums: bool,
+    /// Also read PostgreSQL `= ANY (ARRAY[...])`, SQL Server `OR` chains, and
+    /// MySQL `_charset'...'` literals as CHECK value lists for synthetic enums.
+    pub enum_from_check: bool,
     pub nonativeenums: bool,
     pub keep_dialect_types: bool,
+    pub noassociations: bool,
+    /// Singularize table names into class names (`users` -> `User`).
+    pub use_inflect: bool,
+    /// Django models own their tables (omit `managed = False`).
+    pub managed: bool,
+    /// Proto messages wrap nullable scalars in `google.protobuf.*Value`.
+    pub proto_wrappers: bool,
+    /// Declarative models for `AsyncSession`: `AsyncAttrs` on the Base and
+    /// `lazy='selectin'` relationships.
+    pub asyncio: bool,
+    /// Declarative annotations use `X | None` instead of `Optional[X]`.
+    pub pep604: bool,
+    /// Start declarative output with `from __future__ import annotations`.
+    pub future_annotations: bool,
+    /// Prefix class and variable names of every table outside the default
+    /// schema with its schema, not only tables whose names collide.
+    pub schema_prefix: bool,
+    /// Map PostgreSQL `citext` columns to `CIText()` from the
+    /// `sqlalchemy-citext` package instead of plain `Text`.
+    pub citext: bool,
+    /// Map PostGIS `geometry`/`geography` columns to GeoAlchemy2 types.
+    pub geoalchemy: bool,
+    /// Render well-known server default functions (`now()`, `getdate()`,
+    /// `gen_random_uuid()`, ...) as `func.*` calls instead of `text()`.
+    pub func_defaults: bool,
+    /// Declare `MetaData(naming_convention=...)` inferred from the schema's
+    /// constraint names and drop `name=` where a name follows it.
+    pub naming_convention: bool,
+    /// Give every model class a `__repr__` showing its key and a few
+    /// identifying columns.
+    pub repr: bool,
+    /// Promote a unique NOT NULL column (or every column) of each table
+    /// without a primary key to one, so it maps as a class. Applied by
+    /// [`crate::generate`] for the ORM generators only.
+    pub synthesize_pk: bool,
+    /// Guess the primary key of views from their base table or an `id`
+    /// column.
+    pub infer_view_pk: bool,
+    /// `--view-pk` entries, `view.column` or `schema.view.column`.
+    pub view_pks: Vec<String>,
+    /// Move `created_at`/`updated_at`/`deleted_at` columns that several
+    /// classes share into a generated `TimestampMixin`.
+    pub mixins: bool,
+    /// Leave the provenance header off generated files.
+    pub noheader: bool,
+    /// List the PostgreSQL extensions the schema needs in the provenance
+    /// header.
+    pub extensions_header: bool,
+    /// Column extended-property names copied into `info={...}`.
+    pub info_properties: Vec<String>,
+    /// Existing declarative Base to import instead of declaring one.
+    pub base_class: Option<BaseClass>,
+    /// Name of the generated declarative Base class.
+    pub base_name: Option<String>,
+    /// Explicit class and attribute names (`--rename-map`).
+    pub rename_map: RenameMap,
+    /// Table-name prefixes dropped from generated names (`--strip-prefix`).
+    pub strip_prefixes: Vec<String>,
+    /// Split Python output into one module per schema rather than one per
+    /// model (`--split-by-schema`).
+    pub split_by_schema: bool,
+}
+
+impl GeneratorOptions {
+    /// Whether table comments are rendered.
+    pub fn table_comments(&self) -> bool {
+        !self.nocomments && !self.notablecomments
+    }
+
+    /// Whether column comments are rendered.
+    pub fn column_comments(&self) -> bool {
+        !self.nocomments && !self.nocolumncomments
+    }
+
+    /// Name the models inherit from: the imported Base, `--base-name`, or
+    /// `Base`.
+    pub fn base_name(&self) -> &str {
+        match (&self.base_class, &self.base_name) {
+            (Some(base), _) => &base.name,
+            (None, Some(name)) => name,
+            (None, None) => "Base",
+        }
+    }
+}
+
+/// A declarative Base living in the user's project (`--base-class`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseClass {
+    /// Dotted module path, e.g. `myapp.db`.
+    pub module: String,
+    /// Class name within the module, e.g. `Base`.
+    pub name: String,
 }
 
 /// Options specific to the DDL generator.
@@ -285,10 +590,27 @@ pub struct GeneratorOptions {
 pub struct DdlOptions {
     pub target_dialect: Dialect,
     pub split_tables: bool,
+    /// Lay split files out as `<schema>/<table>.sql`.
+    pub split_by_schema: bool,
     pub apply: bool,
     pub noindexes: bool,
     pub noconstraints: bool,
     pub nocomments: bool,
+    pub notablecomments: bool,
+    pub nocolumncomments: bool,
+}
+
+impl DdlOptions {
+    /// Whether `COMMENT ON TABLE` (or MySQL's inline table `COMMENT`) is
+    /// emitted.
+    pub fn table_comments(&self) -> bool {
+        !self.nocomments && !self.notablecomments
+    }
+
+    /// Whether column comments are emitted.
+    pub fn column_comments(&self) -> bool {
+        !self.nocomments && !self.nocolumncomments
+    }
 }
 
 /// Split a comma-delimited CLI value, trimming whitespace and dropping
@@ -301,6 +623,35 @@ fn split_csv(raw: Option<&str>) -> Vec<String> {
         .collect()
 }
 
+fn parse_base_class(raw: &str) -> Result<BaseClass, String> {
+    let (module, name) = raw
+        .split_once(':')
+        .ok_or_else(|| "expected MODULE:NAME, e.g. myapp.db:Base".to_string())?;
+    if module.split('.').any(|part| !is_python_identifier(part)) {
+        return Err(format!("invalid module path '{module}'"));
+    }
+    Ok(BaseClass {
+        module: module.to_string(),
+        name: parse_python_identifier(name)?,
+    })
+}
+
+fn parse_python_identifier(raw: &str) -> Result<String, String> {
+    if is_python_identifier(raw) {
+        Ok(raw.to_string())
+    } else {
+        Err(format!("'{raw}' is not a valid Python identifier"))
+    }
+}
+
+/// A clap parse error as a usage error: its first line, without clap's
+/// `error: ` prefix and usage tail.
+fn clap_usage_error(err: &clap::Error) -> crate::error::UvgError {
+    let rendered = err.render().to_string();
+    let line = rendered.lines().next().unwrap_or_default();
+    crate::error::UvgError::Usage(line.strip_prefix("error: ").unwrap_or(line).to_string())
+}
+
 fn parse_positive_usize(raw: &str) -> Result<usize, String> {
     let value = raw
         .parse::<usize>()
@@ -317,11 +668,22 @@ impl Cli {
     /// clap's derive parser gives us final values, but profile merging needs
     /// to know which values came from the command line so explicit flags can
     /// win over profile defaults.
+    ///
+    /// Failures are [`UvgError::Usage`](crate::error::UvgError::Usage)
+    /// errors. `--help` and `--version` print and exit here, as do clap's own
+    /// parse errors unless `--error-format json` asks for a JSON report.
     pub fn parse_with_profile() -> anyhow::Result<Self> {
-        let matches = Self::command().get_matches();
-        let mut cli =
-            Self::from_arg_matches(&matches).map_err(|err| anyhow::anyhow!(err.to_string()))?;
-        crate::profile::apply_requested_profile(&mut cli, &matches)?;
+        let args: Vec<std::ffi::OsString> = std::env::args_os().collect();
+        let usage = |err: clap::Error| {
+            if !err.use_stderr() || crate::error::ErrorFormat::requested(&args).is_text() {
+                err.exit();
+            }
+            clap_usage_error(&err)
+        };
+        let matches = Self::command().try_get_matches_from(&args).map_err(usage)?;
+        let mut cli = Self::from_arg_matches(&matches).map_err(usage)?;
+        crate::profile::apply_requested_profile(&mut cli, &matches)
+            .map_err(|err| crate::error::UvgError::Usage(format!("{err:#}")))?;
         Ok(cli)
     }
 
@@ -332,21 +694,69 @@ impl Cli {
         split_csv(self.tables.as_deref())
     }
 
+    /// Parse the comma-delimited --focus flag.
+    pub fn focus_list(&self) -> Vec<String> {
+        split_csv(self.focus.as_deref())
+    }
+
     /// Parse the comma-delimited --exclude-tables flag into a Vec of glob
     /// patterns. Same syntax and degeneration rule as `table_list`.
     pub fn exclude_table_list(&self) -> Vec<String> {
         split_csv(self.exclude_tables.as_deref())
     }
 
-    /// Build a `TableFilter` from `--tables` and `--exclude-tables`.
-    /// Validates every glob pattern up front so bad input surfaces
-    /// before any DB connection is opened.
+    /// Parse the comma-delimited --exclude-kinds flag. Unknown kinds are
+    /// rejected so typos don't silently keep tables in the output.
+    pub fn exclude_kind_list(
+        &self,
+    ) -> Result<Vec<crate::table_filter::TableKind>, crate::error::UvgError> {
+        split_csv(self.exclude_kinds.as_deref())
+            .iter()
+            .map(|k| k.parse())
+            .collect()
+    }
+
+    /// Build a `TableFilter` from `--tables`, `--exclude-tables` and
+    /// `--exclude-kinds`. Partition children and foreign tables are excluded
+    /// unless `--include-partitions` / `--include-foreign-tables` is given. Validates every glob pattern and
+    /// kind up front so bad input surfaces before any DB connection is
+    /// opened.
     pub fn table_filter(&self) -> Result<crate::table_filter::TableFilter, crate::error::UvgError> {
-        crate::table_filter::TableFilter::new(&self.table_list(), &self.exclude_table_list())
+        Ok(
+            crate::table_filter::TableFilter::new(&self.table_list(), &self.exclude_table_list())?
+                .with_excluded_kinds(self.exclude_kind_list()?)
+                .with_excluded_kinds(
+                    self.views_only
+                        .then_some(crate::table_filter::TableKind::Tables),
+                )
+                .with_excluded_kinds(
+                    (!self.include_partitions)
+                        .then_some(crate::table_filter::TableKind::Partitions),
+                )
+                .with_excluded_kinds(
+                    (!self.include_foreign_tables)
+                        .then_some(crate::table_filter::TableKind::Foreign),
+                )
+                .with_follow_fks(self.follow_fks_or_include().map(|depth| {
+                    crate::table_filter::FollowFks {
+                        depth,
+                        both: self.follow_fks_both,
+                    }
+                })),
+        )
+    }
+
+    /// `--follow-fks`, or an unlimited one for `--dangling-fks include`.
+    fn follow_fks_or_include(&self) -> Option<Option<usize>> {
+        self.follow_fks
+            .or((self.dangling_fks == crate::table_filter::DanglingFks::Include).then_some(None))
     }
 
     /// Parse the comma-delimited --schemas flag, falling back to the given default.
     pub fn schema_list_or(&self, default: &str) -> Vec<String> {
+        if self.all_schemas {
+            return vec!["*".to_string()];
+        }
         let raw = self.schemas.as_deref().unwrap_or(default);
         raw.split(',').map(|s| s.trim().to_string()).collect()
     }
@@ -360,16 +770,49 @@ impl Cli {
                     "noindexes" => opts.noindexes = true,
                     "noconstraints" => opts.noconstraints = true,
                     "nocomments" => opts.nocomments = true,
+                    "notablecomments" => opts.notablecomments = true,
+                    "nocolumncomments" => opts.nocolumncomments = true,
                     "nobidi" => opts.nobidi = true,
                     "nofknames" => opts.nofknames = true,
                     "noidsuffix" => opts.noidsuffix = true,
                     "nosyntheticenums" => opts.nosyntheticenums = true,
+                    "enum_from_check" | "enum-from-check" => opts.enum_from_check = true,
                     "nonativeenums" => opts.nonativeenums = true,
                     "keep_dialect_types" => opts.keep_dialect_types = true,
+                    "noassociations" => opts.noassociations = true,
+                    "use_inflect" => opts.use_inflect = true,
+                    "managed" => opts.managed = true,
+                    "proto_wrappers" => opts.proto_wrappers = true,
+                    "async" => opts.asyncio = true,
+                    "pep604" => opts.pep604 = true,
+                    "future_annotations" => opts.future_annotations = true,
+                    "schema_prefix" => opts.schema_prefix = true,
+                    "citext" => opts.citext = true,
+                    "geoalchemy" => opts.geoalchemy = true,
+                    "func_defaults" | "func-defaults" => opts.func_defaults = true,
+                    "naming_convention" | "naming-convention" => opts.naming_convention = true,
+                    "repr" => opts.repr = true,
+                    "synthesize_pk" | "synthesize-pk" => opts.synthesize_pk = true,
+                    "infer_view_pk" | "infer-view-pk" => opts.infer_view_pk = true,
+                    "mixins" => opts.mixins = true,
+                    "noheader" => opts.noheader = true,
+                    "extensions_header" | "extensions-header" => opts.extensions_header = true,
                     _ => tracing::warn!("Unknown generator option: {}", opt),
                 }
             }
         }
+        opts.base_class = self.base_class.clone();
+        opts.base_name = self.base_name.clone();
+        opts.strip_prefixes = split_csv(self.strip_prefix.as_deref());
+        opts.view_pks = split_csv(self.view_pk.as_deref());
+        opts.split_by_schema = self.split_by_schema;
+        if let Some(ref keys) = self.info_properties {
+            opts.info_properties = keys
+                .split(',')
+                .map(|k| k.trim().to_string())
+                .filter(|k| !k.is_empty())
+                .collect();
+        }
         opts
     }
 
@@ -405,11 +848,14 @@ impl Cli {
         let gen_opts = self.generator_options();
         Ok(DdlOptions {
             target_dialect,
-            split_tables: self.split_tables,
+            split_tables: self.split_tables || self.split_by_schema,
+            split_by_schema: self.split_by_schema,
             apply: self.apply,
             noindexes: gen_opts.noindexes,
             noconstraints: gen_opts.noconstraints,
             nocomments: gen_opts.nocomments,
+            notablecomments: gen_opts.notablecomments,
+            nocolumncomments: gen_opts.nocolumncomments,
         })
     }
 
@@ -436,7 +882,21 @@ impl Cli {
         &self,
         url: &str,
     ) -> Result<ConnectionConfig, crate::error::UvgError> {
-        crate::connection::parse_connection_url(url, self.trust_cert)
+        Ok(
+            crate::connection::parse_connection_url(url, self.auth, self.trust_cert)?
+                .with_pg_ssl(&self.pg_ssl())
+                .with_mssql_encrypt(self.encrypt),
+        )
+    }
+
+    /// PostgreSQL TLS settings from the `--ssl*` flags.
+    pub fn pg_ssl(&self) -> crate::connection::PgSslOptions {
+        crate::connection::PgSslOptions {
+            mode: self.sslmode.clone(),
+            root_cert: self.sslrootcert.clone(),
+            cert: self.sslcert.clone(),
+            key: self.sslkey.clone(),
+        }
     }
 }
 
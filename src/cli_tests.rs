@@ -12,21 +12,57 @@ fn cli_with_url(url: &str) -> Cli {
         generator: "declarative".to_string(),
         target_dialect: None,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         progress: crate::apply_progress::ProgressMode::Auto,
+        error_format: crate::error::ErrorFormat::Text,
         apply_retries: 3,
         no_parse_check: false,
         risk_classify: false,
+        seed_rows: 10,
+        seed_format: crate::codegen::seed::SeedFormat::Sql,
+        focus: None,
+        focus_depth: 1,
+        template: None,
+        sample: None,
+        stats: false,
+        info_properties: None,
+        pii: false,
+        pii_report: None,
         introspect_concurrency: DEFAULT_INTROSPECT_CONCURRENCY,
+        from_cache: false,
+        refresh: false,
         tables: None,
         exclude_tables: None,
+        exclude_kinds: None,
+        follow_fks: None,
+        follow_fks_both: false,
+        dangling_fks: crate::table_filter::DanglingFks::Keep,
         schemas: None,
+        all_schemas: false,
         noviews: false,
+        views_only: false,
+        include_partitions: false,
+        include_foreign_tables: false,
         options: None,
         outfile: None,
+        check: None,
+        strict: false,
         out_dir: None,
         name: None,
+        typemap: None,
+        base_class: None,
+        base_name: None,
+        rename_map: None,
+        strip_prefix: None,
+        view_pk: None,
         trust_cert: false,
+        encrypt: crate::connection::MssqlEncrypt::Required,
+        auth: crate::connection::MssqlAuth::Sql,
+        sslmode: None,
+        sslrootcert: None,
+        sslcert: None,
+        sslkey: None,
         interactive: false,
     }
 }
@@ -49,6 +85,13 @@ fn introspect_concurrency_flag_overrides_default() {
     assert_eq!(cli.introspect_concurrency, 3);
 }
 
+#[test]
+fn jobs_is_an_alias_for_introspect_concurrency() {
+    let cli = Cli::try_parse_from(["uvg", "--jobs", "4", "sqlite:///tmp.db"]).unwrap();
+
+    assert_eq!(cli.introspect_concurrency, 4);
+}
+
 #[test]
 fn introspect_concurrency_env_is_supported() {
     let _guard = ENV_LOCK.lock().unwrap();
@@ -67,6 +110,33 @@ fn introspect_concurrency_rejects_zero() {
     assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
 }
 
+#[test]
+fn encrypt_flag_reaches_mssql_config() {
+    use crate::connection::{ConnectionConfig, MssqlEncrypt};
+
+    let cli = Cli::try_parse_from(["uvg", "mssql://sa:p@host/app"]).unwrap();
+    assert_eq!(cli.encrypt, MssqlEncrypt::Required);
+
+    let cli = Cli::try_parse_from(["uvg", "--encrypt", "login", "mssql://sa:p@host/app"]).unwrap();
+    let config = cli.parse_connection_url("mssql://sa:p@host/app").unwrap();
+    assert!(matches!(
+        config,
+        ConnectionConfig::Mssql {
+            encrypt: MssqlEncrypt::Login,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn cache_flags_parse() {
+    let cli =
+        Cli::try_parse_from(["uvg", "--from-cache", "--refresh", "sqlite:///tmp.db"]).unwrap();
+
+    assert!(cli.from_cache);
+    assert!(cli.refresh);
+}
+
 #[test]
 fn risk_classify_flag_parses() {
     let cli = Cli::try_parse_from(["uvg", "--risk-classify", "sqlite:///tmp.db"]).unwrap();
@@ -181,3 +251,250 @@ fn test_mysql_empty_database_name() {
     let config = cli.parse_connection().unwrap();
     assert_eq!(config.database_name(), None);
 }
+
+#[test]
+fn exclude_kinds_flag_builds_filter() {
+    let cli = Cli::try_parse_from([
+        "uvg",
+        "--exclude-kinds",
+        "migrations,partitions",
+        "sqlite:///tmp.db",
+    ])
+    .unwrap();
+    let filter = cli.table_filter().unwrap();
+
+    assert!(!filter.admits(&crate::schema::TableInfo::new(
+        "main",
+        "alembic_version",
+        crate::schema::TableType::Table,
+    )));
+    assert!(filter.admits(&crate::schema::TableInfo::new(
+        "main",
+        "users",
+        crate::schema::TableType::Table,
+    )));
+}
+
+#[test]
+fn follow_fks_takes_an_optional_depth() {
+    let parse = |args: &[&str]| {
+        let mut argv = vec!["uvg"];
+        argv.extend_from_slice(args);
+        argv.push("sqlite:///tmp.db");
+        Cli::try_parse_from(argv)
+    };
+
+    assert_eq!(parse(&[]).unwrap().follow_fks, None);
+    assert_eq!(parse(&["--follow-fks"]).unwrap().follow_fks, Some(None));
+    assert_eq!(
+        parse(&["--follow-fks=2"]).unwrap().follow_fks,
+        Some(Some(2))
+    );
+    assert!(parse(&["--follow-fks-both"]).is_err());
+    assert!(
+        parse(&["--follow-fks", "--follow-fks-both"])
+            .unwrap()
+            .follow_fks_both
+    );
+}
+
+#[test]
+fn all_schemas_is_a_wildcard_schema_list() {
+    let cli = Cli::try_parse_from(["uvg", "--all-schemas", "postgresql://h/db"]).unwrap();
+    assert_eq!(cli.schema_list_or("public"), ["*"]);
+
+    assert!(Cli::try_parse_from([
+        "uvg",
+        "--all-schemas",
+        "--schemas",
+        "a",
+        "postgresql://h/db"
+    ])
+    .is_err());
+}
+
+#[test]
+fn exclude_kinds_rejects_unknown_kind() {
+    let cli = Cli::try_parse_from(["uvg", "--exclude-kinds", "bogus", "sqlite:///tmp.db"]).unwrap();
+
+    assert!(cli.table_filter().is_err());
+}
+
+#[test]
+fn comment_options_split_table_and_column_comments() {
+    let cli =
+        Cli::try_parse_from(["uvg", "--options", "notablecomments", "sqlite:///tmp.db"]).unwrap();
+    let options = cli.generator_options();
+    assert!(!options.table_comments());
+    assert!(options.column_comments());
+
+    let cli = Cli::try_parse_from(["uvg", "--options", "nocomments", "sqlite:///tmp.db"]).unwrap();
+    let options = cli.generator_options();
+    assert!(!options.table_comments());
+    assert!(!options.column_comments());
+
+    let cli =
+        Cli::try_parse_from(["uvg", "--options", "nocolumncomments", "sqlite:///tmp.db"]).unwrap();
+    let ddl = cli.ddl_options(Dialect::Sqlite).unwrap();
+    assert!(ddl.table_comments());
+    assert!(!ddl.column_comments());
+}
+
+#[test]
+fn base_class_flag_parses_module_and_name() {
+    let cli =
+        Cli::try_parse_from(["uvg", "--base-class", "myapp.db:Base", "sqlite:///tmp.db"]).unwrap();
+    let options = cli.generator_options();
+
+    assert_eq!(
+        options.base_class,
+        Some(BaseClass {
+            module: "myapp.db".to_string(),
+            name: "Base".to_string(),
+        })
+    );
+    assert_eq!(options.base_name(), "Base");
+}
+
+#[test]
+fn base_class_flag_rejects_malformed_values() {
+    for raw in [
+        "myapp.db",
+        "myapp.db:",
+        ":Base",
+        "myapp.db:2Base",
+        "my app:Base",
+    ] {
+        assert!(
+            Cli::try_parse_from(["uvg", "--base-class", raw, "sqlite:///tmp.db"]).is_err(),
+            "{raw} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn base_name_flag_renames_generated_base() {
+    let cli = Cli::try_parse_from(["uvg", "--base-name", "Model", "sqlite:///tmp.db"]).unwrap();
+    assert_eq!(cli.generator_options().base_name(), "Model");
+
+    assert!(Cli::try_parse_from(["uvg", "--base-name", "not-valid", "sqlite:///tmp.db"]).is_err());
+    assert!(Cli::try_parse_from([
+        "uvg",
+        "--base-name",
+        "Model",
+        "--base-class",
+        "myapp.db:Base",
+        "sqlite:///tmp.db",
+    ])
+    .is_err());
+}
+
+#[test]
+fn strip_prefix_flag_splits_prefixes() {
+    let cli =
+        Cli::try_parse_from(["uvg", "--strip-prefix", "tbl_, t_", "sqlite:///tmp.db"]).unwrap();
+
+    assert_eq!(cli.generator_options().strip_prefixes, ["tbl_", "t_"]);
+}
+
+#[test]
+fn view_pk_flag_and_option() {
+    let cli = Cli::try_parse_from([
+        "uvg",
+        "--view-pk",
+        "v_orders.id, sales.v_lines.order_id",
+        "--options",
+        "infer_view_pk",
+        "sqlite:///tmp.db",
+    ])
+    .unwrap();
+
+    let options = cli.generator_options();
+    assert_eq!(options.view_pks, ["v_orders.id", "sales.v_lines.order_id"]);
+    assert!(options.infer_view_pk);
+}
+
+#[test]
+fn partition_children_are_skipped_unless_included() {
+    let mut child =
+        crate::schema::TableInfo::new("public", "orders_2024_01", crate::schema::TableType::Table);
+    child.partition_of = Some("orders".to_string());
+    let parent = crate::schema::TableInfo::new("public", "orders", crate::schema::TableType::Table);
+
+    let cli = Cli::try_parse_from(["uvg", "postgresql://localhost/db"]).unwrap();
+    let filter = cli.table_filter().unwrap();
+    assert!(!filter.admits(&child));
+    assert!(filter.admits(&parent));
+
+    let cli =
+        Cli::try_parse_from(["uvg", "--include-partitions", "postgresql://localhost/db"]).unwrap();
+    assert!(cli.table_filter().unwrap().admits(&child));
+}
+
+#[test]
+fn foreign_tables_are_skipped_unless_included() {
+    let mut remote =
+        crate::schema::TableInfo::new("public", "remote_orders", crate::schema::TableType::Table);
+    remote.foreign_server = Some("warehouse".to_string());
+
+    let cli = Cli::try_parse_from(["uvg", "postgresql://localhost/db"]).unwrap();
+    assert!(!cli.table_filter().unwrap().admits(&remote));
+
+    let cli = Cli::try_parse_from([
+        "uvg",
+        "--include-foreign-tables",
+        "postgresql://localhost/db",
+    ])
+    .unwrap();
+    assert!(cli.table_filter().unwrap().admits(&remote));
+}
+
+#[test]
+fn views_only_flag_drops_base_tables() {
+    let cli = Cli::try_parse_from(["uvg", "--views-only", "sqlite:///tmp.db"]).unwrap();
+    let filter = cli.table_filter().unwrap();
+
+    assert!(filter.admits(&crate::schema::TableInfo::new(
+        "main",
+        "active_users",
+        crate::schema::TableType::View,
+    )));
+    assert!(!filter.admits(&crate::schema::TableInfo::new(
+        "main",
+        "users",
+        crate::schema::TableType::Table,
+    )));
+    assert!(Cli::try_parse_from(["uvg", "--views-only", "--noviews", "sqlite:///tmp.db"]).is_err());
+}
+
+#[test]
+fn options_help_lists_every_generator_option() {
+    use clap::CommandFactory;
+
+    let command = Cli::command();
+    let help = command
+        .get_arguments()
+        .find(|arg| arg.get_id() == "options")
+        .and_then(|arg| arg.get_help())
+        .unwrap()
+        .to_string();
+    let listed: Vec<&str> = help.split_once(": ").unwrap().1.split(", ").collect();
+    for option in &listed {
+        let cli = Cli::try_parse_from(["uvg", "--options", option, "sqlite:///tmp.db"]).unwrap();
+        assert_ne!(
+            format!("{:?}", cli.generator_options()),
+            format!("{:?}", GeneratorOptions::default()),
+            "{option} is listed but not recognized"
+        );
+    }
+    for option in [
+        "repr",
+        "infer_view_pk",
+        "synthesize_pk",
+        "mixins",
+        "extensions_header",
+        "noheader",
+    ] {
+        assert!(listed.contains(&option), "{option} missing from: {help}");
+    }
+}
@@ -0,0 +1,233 @@
+//! `--options naming_convention`: a `MetaData(naming_convention=...)`
+//! inferred from the constraint names the database already uses.
+//!
+//! For each constraint kind the candidate template matching the most
+//! observed names wins, with the standard Alembic convention as the
+//! fallback. Constraints whose name SQLAlchemy would derive from the
+//! convention anyway are rendered without `name=`.
+//!
+//! Check constraints are left out: a `ck` convention has to be keyed on
+//! `%(constraint_name)s`, which would rewrite every explicit check name.
+
+use crate::dialect::Dialect;
+use crate::schema::{ConstraintInfo, ConstraintType, IntrospectedSchema, TableInfo};
+
+use super::{format_python_string_literal, is_unique_constraint_index};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Index,
+    Unique,
+    ForeignKey,
+    PrimaryKey,
+}
+
+impl Kind {
+    const ALL: [Kind; 4] = [
+        Kind::Index,
+        Kind::Unique,
+        Kind::ForeignKey,
+        Kind::PrimaryKey,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            Kind::Index => "ix",
+            Kind::Unique => "uq",
+            Kind::ForeignKey => "fk",
+            Kind::PrimaryKey => "pk",
+        }
+    }
+
+    /// Candidate templates; the first is the Alembic-documented one,
+    /// followed by the PostgreSQL defaults and the common SQL Server style.
+    fn candidates(self) -> &'static [&'static str] {
+        match self {
+            Kind::Index => &[
+                "ix_%(column_0_label)s",
+                "%(table_name)s_%(column_0_N_name)s_idx",
+                "IX_%(table_name)s_%(column_0_N_name)s",
+            ],
+            Kind::Unique => &[
+                "uq_%(table_name)s_%(column_0_name)s",
+                "%(table_name)s_%(column_0_N_name)s_key",
+                "UQ_%(table_name)s_%(column_0_N_name)s",
+            ],
+            Kind::ForeignKey => &[
+                "fk_%(table_name)s_%(column_0_name)s_%(referred_table_name)s",
+                "%(table_name)s_%(column_0_N_name)s_fkey",
+                "FK_%(table_name)s_%(referred_table_name)s",
+            ],
+            Kind::PrimaryKey => &[
+                "pk_%(table_name)s",
+                "%(table_name)s_pkey",
+                "PK_%(table_name)s",
+            ],
+        }
+    }
+
+    fn of(constraint_type: &ConstraintType) -> Option<Kind> {
+        match constraint_type {
+            ConstraintType::PrimaryKey => Some(Kind::PrimaryKey),
+            ConstraintType::Unique => Some(Kind::Unique),
+            ConstraintType::ForeignKey => Some(Kind::ForeignKey),
+            _ => None,
+        }
+    }
+}
+
+/// One named index or constraint, as the convention tokens see it.
+struct Named<'a> {
+    kind: Kind,
+    table: &'a str,
+    columns: &'a [String],
+    referred_table: Option<&'a str>,
+    name: &'a str,
+}
+
+impl<'a> Named<'a> {
+    fn constraint(table: &'a TableInfo, constraint: &'a ConstraintInfo) -> Option<Self> {
+        Some(Named {
+            kind: Kind::of(&constraint.constraint_type)?,
+            table: &table.name,
+            columns: &constraint.columns,
+            referred_table: constraint
+                .foreign_key
+                .as_ref()
+                .map(|fk| fk.ref_table.as_str()),
+            name: &constraint.name,
+        })
+    }
+}
+
+/// The templates chosen for one schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamingConvention {
+    templates: Vec<(Kind, &'static str)>,
+    dialect: Dialect,
+}
+
+impl NamingConvention {
+    /// Pick, per kind, the candidate template that reproduces the most
+    /// names in `schema`.
+    pub fn infer(schema: &IntrospectedSchema) -> Self {
+        let mut named: Vec<Named> = Vec::new();
+        for table in &schema.tables {
+            named.extend(
+                table
+                    .constraints
+                    .iter()
+                    .filter_map(|c| Named::constraint(table, c)),
+            );
+            for index in &table.indexes {
+                if index.has_expressions() || is_unique_constraint_index(index, &table.constraints)
+                {
+                    continue;
+                }
+                named.push(Named {
+                    kind: Kind::Index,
+                    table: &table.name,
+                    columns: &index.columns,
+                    referred_table: None,
+                    name: &index.name,
+                });
+            }
+        }
+
+        let templates = Kind::ALL
+            .iter()
+            .map(|&kind| {
+                let candidates = kind.candidates();
+                let mut best = (candidates[0], 0);
+                for &template in candidates {
+                    let hits = named
+                        .iter()
+                        .filter(|n| n.kind == kind && matches(template, n, schema.dialect))
+                        .count();
+                    if hits > best.1 {
+                        best = (template, hits);
+                    }
+                }
+                (kind, best.0)
+            })
+            .collect();
+        NamingConvention {
+            templates,
+            dialect: schema.dialect,
+        }
+    }
+
+    /// Whether SQLAlchemy would give `constraint` its introspected name
+    /// without an explicit `name=`.
+    pub fn covers(&self, table: &TableInfo, constraint: &ConstraintInfo) -> bool {
+        let Some(named) = Named::constraint(table, constraint) else {
+            return false;
+        };
+        self.templates
+            .iter()
+            .any(|&(kind, template)| kind == named.kind && matches(template, &named, self.dialect))
+    }
+
+    /// `MetaData(naming_convention={...})`, one entry per line, with the
+    /// closing brace at `indent`.
+    pub fn metadata_call(&self, indent: &str) -> String {
+        let entries: Vec<String> = self
+            .templates
+            .iter()
+            .map(|(kind, template)| format!("{indent}    '{}': '{template}',", kind.key()))
+            .collect();
+        format!(
+            "MetaData(naming_convention={{\n{}\n{indent}}})",
+            entries.join("\n")
+        )
+    }
+}
+
+/// The `, name='...'` argument for a constraint, or nothing when the
+/// naming convention already produces that name.
+pub fn name_arg(
+    convention: Option<&NamingConvention>,
+    table: &TableInfo,
+    constraint: &ConstraintInfo,
+) -> String {
+    if convention.is_some_and(|c| c.covers(table, constraint)) {
+        String::new()
+    } else {
+        format!(", name={}", format_python_string_literal(&constraint.name))
+    }
+}
+
+fn matches(template: &str, named: &Named, dialect: Dialect) -> bool {
+    // SQLAlchemy truncates over-long conventional names with a hash suffix,
+    // so only names within the dialect's limit can round-trip.
+    !named.name.is_empty()
+        && named.name.len() <= max_identifier_length(dialect)
+        && render(template, named).as_deref() == Some(named.name)
+}
+
+/// Expand the convention tokens SQLAlchemy would substitute.
+fn render(template: &str, named: &Named) -> Option<String> {
+    let first = named.columns.first()?;
+    let mut name = template
+        .replace("%(table_name)s", named.table)
+        .replace("%(column_0_label)s", &format!("{}_{first}", named.table))
+        .replace("%(column_0_N_name)s", &named.columns.join("_"))
+        .replace("%(column_0_name)s", first);
+    if template.contains("%(referred_table_name)s") {
+        name = name.replace("%(referred_table_name)s", named.referred_table?);
+    }
+    Some(name)
+}
+
+fn max_identifier_length(dialect: Dialect) -> usize {
+    match dialect {
+        Dialect::Postgres => 63,
+        Dialect::Mysql => 64,
+        Dialect::Mssql => 128,
+        Dialect::Sqlite => usize::MAX,
+    }
+}
+
+#[cfg(test)]
+#[path = "conventions_tests.rs"]
+mod tests;
@@ -0,0 +1,90 @@
+use super::*;
+use crate::testutil::*;
+
+fn pg_default_names() -> IntrospectedSchema {
+    schema_pg(vec![
+        table("users")
+            .column(col("id").build())
+            .column(col("email").udt("varchar").build())
+            .pk("users_pkey", &["id"])
+            .unique("users_email_key", &["email"])
+            .build(),
+        table("posts")
+            .column(col("id").build())
+            .column(col("user_id").build())
+            .pk("posts_pkey", &["id"])
+            .fk("posts_user_id_fkey", &["user_id"], "users", &["id"])
+            .index("posts_user_id_idx", &["user_id"], false)
+            .build(),
+    ])
+}
+
+#[test]
+fn infers_postgres_default_names() {
+    let convention = NamingConvention::infer(&pg_default_names());
+    assert_eq!(
+        convention.metadata_call(""),
+        "MetaData(naming_convention={\n    \
+         'ix': '%(table_name)s_%(column_0_N_name)s_idx',\n    \
+         'uq': '%(table_name)s_%(column_0_N_name)s_key',\n    \
+         'fk': '%(table_name)s_%(column_0_N_name)s_fkey',\n    \
+         'pk': '%(table_name)s_pkey',\n\
+         })"
+    );
+}
+
+#[test]
+fn falls_back_to_alembic_convention() {
+    let schema = schema_mssql(vec![table("users")
+        .schema("dbo")
+        .column(col("id").udt("int").build())
+        .pk("PK__users__3213E83F", &["id"])
+        .build()]);
+    let convention = NamingConvention::infer(&schema);
+    let rendered = convention.metadata_call("    ");
+    assert!(rendered.contains("        'ix': 'ix_%(column_0_label)s',\n"));
+    assert!(rendered.contains("        'pk': 'pk_%(table_name)s',\n    })"));
+    assert!(!convention.covers(&schema.tables[0], &schema.tables[0].constraints[0]));
+}
+
+#[test]
+fn covers_only_names_the_convention_reproduces() {
+    let schema = schema_pg(vec![table("orders")
+        .column(col("id").build())
+        .column(col("a").build())
+        .column(col("b").build())
+        .pk("orders_pkey", &["id"])
+        .unique("orders_a_b_key", &["a", "b"])
+        .unique("orders_custom_uq", &["b"])
+        .check("orders_a_check", "a > 0")
+        .build()]);
+    let convention = NamingConvention::infer(&schema);
+    let orders = &schema.tables[0];
+    let covered: Vec<&str> = orders
+        .constraints
+        .iter()
+        .filter(|c| convention.covers(orders, c))
+        .map(|c| c.name.as_str())
+        .collect();
+    assert_eq!(covered, ["orders_pkey", "orders_a_b_key"]);
+    assert_eq!(
+        name_arg(Some(&convention), orders, &orders.constraints[2]),
+        ", name='orders_custom_uq'"
+    );
+    assert_eq!(
+        name_arg(None, orders, &orders.constraints[1]),
+        ", name='orders_a_b_key'"
+    );
+}
+
+#[test]
+fn overlong_names_are_not_covered() {
+    let long_table = "t".repeat(60);
+    let pk_name = format!("{long_table}_pkey");
+    let schema = schema_pg(vec![table(&long_table)
+        .column(col("id").build())
+        .pk(&pk_name, &["id"])
+        .build()]);
+    let convention = NamingConvention::infer(&schema);
+    assert!(!convention.covers(&schema.tables[0], &schema.tables[0].constraints[0]));
+}
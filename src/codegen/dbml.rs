@@ -0,0 +1,257 @@
+//! DBML generator (`--generator dbml`).
+//!
+//! Emits dbdiagram.io-compatible DBML: an `Enum` block per named enum, a
+//! `Table` block per table with column settings, an `indexes` block for
+//! indexes and multi-column keys, table and column notes from comments, and
+//! a `Ref` per foreign key. DBML has no notion of views, so they are left
+//! out.
+
+use crate::cli::GeneratorOptions;
+use crate::schema::{
+    ColumnInfo, ConstraintType, IndexKey, IntrospectedSchema, TableInfo, TableType,
+};
+
+/// Generate the DBML document for `schema`.
+pub fn generate(schema: &IntrospectedSchema, options: &GeneratorOptions) -> String {
+    let mut out = vec!["// DBML generated by uvg".to_string()];
+
+    for enum_info in &schema.enums {
+        out.push(String::new());
+        out.push(format!(
+            "Enum {} {{",
+            qualified(enum_info.schema.as_deref().unwrap_or(""), &enum_info.name)
+        ));
+        for value in &enum_info.values {
+            out.push(format!("  {}", quote_ident(value)));
+        }
+        out.push("}".to_string());
+    }
+
+    let tables: Vec<&TableInfo> = schema
+        .tables
+        .iter()
+        .filter(|t| t.table_type == TableType::Table)
+        .collect();
+    for table in &tables {
+        out.push(String::new());
+        render_table(&mut out, table, options);
+    }
+
+    let refs: Vec<String> = tables
+        .iter()
+        .flat_map(|table| {
+            table
+                .constraints
+                .iter()
+                .filter(|c| c.constraint_type == ConstraintType::ForeignKey)
+                .filter_map(move |c| c.foreign_key.as_ref().map(|fk| (table, c, fk)))
+        })
+        .map(|(table, constraint, fk)| {
+            let settings: Vec<String> = [("delete", &fk.delete_rule), ("update", &fk.update_rule)]
+                .into_iter()
+                .filter(|(_, rule)| !rule.is_empty() && !rule.eq_ignore_ascii_case("NO ACTION"))
+                .map(|(action, rule)| format!("{action}: {}", rule.to_ascii_lowercase()))
+                .collect();
+            let suffix = if settings.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", settings.join(", "))
+            };
+            format!(
+                "Ref {}: {} > {}{suffix}",
+                quote_ident(&constraint.name),
+                ref_endpoint(&table.schema, &table.name, &constraint.columns),
+                ref_endpoint(&fk.ref_schema, &fk.ref_table, &fk.ref_columns),
+            )
+        })
+        .collect();
+    if !refs.is_empty() {
+        out.push(String::new());
+        out.extend(refs);
+    }
+
+    out.push(String::new());
+    out.join("\n")
+}
+
+fn render_table(out: &mut Vec<String>, table: &TableInfo, options: &GeneratorOptions) {
+    let pk_columns: Vec<&String> = table
+        .constraints
+        .iter()
+        .filter(|c| c.constraint_type == ConstraintType::PrimaryKey)
+        .flat_map(|c| &c.columns)
+        .collect();
+    let single_pk = pk_columns.len() == 1;
+    let single_unique: Vec<&String> = table
+        .constraints
+        .iter()
+        .filter(|c| c.constraint_type == ConstraintType::Unique && c.columns.len() == 1)
+        .map(|c| &c.columns[0])
+        .collect();
+
+    out.push(format!(
+        "Table {} {{",
+        qualified(&table.schema, &table.name)
+    ));
+    for col in &table.columns {
+        let is_pk = single_pk && pk_columns[0] == &col.name;
+        let is_unique = single_unique.contains(&&col.name);
+        out.push(format!(
+            "  {} {}{}",
+            quote_ident(&col.name),
+            column_type(col),
+            column_settings(col, is_pk, is_unique, options)
+        ));
+    }
+
+    let mut index_lines = Vec::new();
+    if pk_columns.len() > 1 {
+        index_lines.push(format!("    {} [pk]", key_list(&pk_columns)));
+    }
+    if !options.noconstraints {
+        for constraint in &table.constraints {
+            if constraint.constraint_type == ConstraintType::Unique && constraint.columns.len() > 1
+            {
+                let columns: Vec<&String> = constraint.columns.iter().collect();
+                index_lines.push(format!(
+                    "    {} [unique, name: {}]",
+                    key_list(&columns),
+                    quote_string(&constraint.name)
+                ));
+            }
+        }
+    }
+    if !options.noindexes {
+        for index in &table.indexes {
+            let keys: Vec<String> = index
+                .keys()
+                .into_iter()
+                .map(|key| match key {
+                    IndexKey::Column(name) => quote_ident(name),
+                    IndexKey::Expression(expr) => format!("`{expr}`"),
+                })
+                .collect();
+            let unique = if index.is_unique { "unique, " } else { "" };
+            index_lines.push(format!(
+                "    ({}) [{unique}name: {}]",
+                keys.join(", "),
+                quote_string(&index.name)
+            ));
+        }
+    }
+    if !index_lines.is_empty() {
+        out.push(String::new());
+        out.push("  indexes {".to_string());
+        out.extend(index_lines);
+        out.push("  }".to_string());
+    }
+
+    if let Some(comment) = table
+        .comment
+        .as_deref()
+        .filter(|_| options.table_comments())
+    {
+        out.push(String::new());
+        out.push(format!("  Note: {}", quote_string(comment)));
+    }
+    out.push("}".to_string());
+}
+
+fn column_settings(
+    col: &ColumnInfo,
+    is_pk: bool,
+    is_unique: bool,
+    options: &GeneratorOptions,
+) -> String {
+    let mut settings = Vec::new();
+    if is_pk {
+        settings.push("pk".to_string());
+    }
+    if col.is_identity || col.autoincrement == Some(true) {
+        settings.push("increment".to_string());
+    }
+    if !col.is_nullable && !is_pk {
+        settings.push("not null".to_string());
+    }
+    if is_unique && !options.noconstraints {
+        settings.push("unique".to_string());
+    }
+    if let Some(ref default) = col.column_default {
+        settings.push(format!("default: {}", default_value(default)));
+    }
+    if let Some(comment) = col.comment.as_deref().filter(|_| options.column_comments()) {
+        settings.push(format!("note: {}", quote_string(comment)));
+    }
+    if settings.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", settings.join(", "))
+    }
+}
+
+/// DBML type name; quoted when it is not a bare word plus an optional
+/// parenthesised size.
+fn column_type(col: &ColumnInfo) -> String {
+    let label = col.type_label();
+    let base = label.split('(').next().unwrap_or_default();
+    if !base.is_empty() && base.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        label
+    } else {
+        quote_ident(&label)
+    }
+}
+
+/// Numbers and booleans stay bare; everything else is an expression.
+fn default_value(default: &str) -> String {
+    let trimmed = default.trim();
+    let numeric = trimmed.parse::<f64>().is_ok()
+        && trimmed
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'));
+    if numeric || trimmed.eq_ignore_ascii_case("true") || trimmed.eq_ignore_ascii_case("false") {
+        trimmed.to_ascii_lowercase()
+    } else {
+        format!("`{}`", trimmed.replace('`', "\\`"))
+    }
+}
+
+fn qualified(schema: &str, name: &str) -> String {
+    if schema.is_empty() {
+        quote_ident(name)
+    } else {
+        format!("{}.{}", quote_ident(schema), quote_ident(name))
+    }
+}
+
+fn ref_endpoint(schema: &str, table: &str, columns: &[String]) -> String {
+    let table = qualified(schema, table);
+    match columns {
+        [column] => format!("{table}.{}", quote_ident(column)),
+        _ => {
+            let columns: Vec<&String> = columns.iter().collect();
+            format!("{table}.{}", key_list(&columns))
+        }
+    }
+}
+
+fn key_list(columns: &[&String]) -> String {
+    let quoted: Vec<String> = columns.iter().map(|c| quote_ident(c)).collect();
+    format!("({})", quoted.join(", "))
+}
+
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn quote_string(text: &str) -> String {
+    format!(
+        "'{}'",
+        text.replace('\\', "\\\\")
+            .replace('\'', "\\'")
+            .replace('\n', "\\n")
+    )
+}
+
+#[cfg(test)]
+#[path = "dbml_tests.rs"]
+mod tests;
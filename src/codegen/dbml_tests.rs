@@ -0,0 +1,103 @@
+use super::*;
+use crate::schema::EnumInfo;
+use crate::testutil::*;
+
+fn shop_schema() -> IntrospectedSchema {
+    schema_pg_with_enums(
+        vec![
+            table("orders")
+                .column(col("id").identity().build())
+                .column(col("user_id").build())
+                .column(col("status").udt("order_status").build())
+                .column(
+                    col("total")
+                        .udt("numeric")
+                        .precision(10, 2)
+                        .default_val("0")
+                        .build(),
+                )
+                .pk("orders_pkey", &["id"])
+                .fk_full(
+                    "orders_user_id_fkey",
+                    &["user_id"],
+                    "public",
+                    "users",
+                    &["id"],
+                    "NO ACTION",
+                    "CASCADE",
+                )
+                .index("ix_orders_status", &["status"], false)
+                .comment("Customer's orders")
+                .build(),
+            table("users")
+                .column(col("id").build())
+                .column(
+                    col("email")
+                        .udt("varchar")
+                        .max_length(320)
+                        .comment("Login")
+                        .build(),
+                )
+                .column(
+                    col("created_at")
+                        .udt("timestamp with time zone")
+                        .nullable()
+                        .default_val("now()")
+                        .build(),
+                )
+                .pk("users_pkey", &["id"])
+                .unique("users_email_key", &["email"])
+                .build(),
+        ],
+        vec![EnumInfo {
+            name: "order_status".to_string(),
+            schema: Some("public".to_string()),
+            values: vec!["open".to_string(), "shipped".to_string()],
+        }],
+    )
+}
+
+#[test]
+fn dbml_renders_enums_tables_and_refs() {
+    let output = generate(&shop_schema(), &GeneratorOptions::default());
+    assert!(output.contains("Enum \"public\".\"order_status\" {\n  \"open\"\n  \"shipped\"\n}"));
+    assert!(output.contains(
+        "Table \"public\".\"orders\" {\n  \
+         \"id\" int4 [pk, increment]\n  \
+         \"user_id\" int4 [not null]\n  \
+         \"status\" order_status [not null]\n  \
+         \"total\" numeric(10,2) [not null, default: 0]\n\n  \
+         indexes {\n    (\"status\") [name: 'ix_orders_status']\n  }\n\n  \
+         Note: 'Customer\\'s orders'\n}"
+    ));
+    assert!(output.contains(
+        "  \"email\" varchar(320) [not null, unique, note: 'Login']\n  \
+         \"created_at\" \"timestamp with time zone\" [default: `now()`]\n"
+    ));
+    assert!(output.contains(
+        "Ref \"orders_user_id_fkey\": \"public\".\"orders\".\"user_id\" > \
+         \"public\".\"users\".\"id\" [delete: cascade]"
+    ));
+}
+
+#[test]
+fn dbml_composite_keys_go_in_indexes_block() {
+    let schema = schema_pg(vec![table("memberships")
+        .column(col("group_id").build())
+        .column(col("user_id").build())
+        .pk("memberships_pkey", &["group_id", "user_id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("  \"group_id\" int4 [not null]\n"));
+    assert!(output.contains("  indexes {\n    (\"group_id\", \"user_id\") [pk]\n  }"));
+}
+
+#[test]
+fn dbml_omits_views() {
+    let schema = schema_pg(vec![table("active_users")
+        .column(col("id").build())
+        .table_type(TableType::View)
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert_eq!(output, "// DBML generated by uvg\n");
+}
@@ -1,13 +1,13 @@
 mod comments;
-mod cycles;
 mod enums;
 mod sequences;
 
 use crate::cli::DdlOptions;
-use crate::codegen::topo_sort_tables;
+use crate::codegen::{find_enum_for_ddl_column, order_tables};
 #[cfg(test)]
 use crate::dialect::Dialect;
-use crate::schema::{IntrospectedSchema, TableInfo, TableType};
+use crate::schema::{ConstraintType, IntrospectedSchema, TableInfo, TableType};
+use crate::warnings::{column_path, table_path, WarningKind, Warnings};
 
 #[cfg(test)]
 use super::render::checks::strip_pg_casts_in_predicate;
@@ -16,14 +16,55 @@ use super::render::defaults::{
     ensure_default_quoting, reattach_now_family_precision, strip_precision_suffix,
     translate_default_function,
 };
-use super::render::{generate_create_table, generate_indexes};
+use super::render::{
+    check_predicate_is_portable, column_def_warnings, generate_create_table, generate_indexes,
+};
 use comments::generate_comments;
-use cycles::detect_fk_cycles;
 use enums::generate_enum_types;
 pub(super) use enums::{generate_enum_type, referenced_enums};
 use sequences::generate_sequences;
 pub(super) use sequences::{generate_sequence, referenced_sequences, shared_sequences};
 
+/// What full-schema DDL for `options.target_dialect` loses: approximate
+/// column types, defaults the target cannot hold, and CHECK constraints
+/// left out as non-portable. Diff output is not covered.
+pub fn collect_warnings(
+    schema: &IntrospectedSchema,
+    options: &DdlOptions,
+    warnings: &mut Warnings,
+) {
+    let (source, target) = (schema.dialect, options.target_dialect);
+    for table in schema
+        .tables
+        .iter()
+        .filter(|t| t.table_type == TableType::Table)
+    {
+        for col in &table.columns {
+            let enum_info = find_enum_for_ddl_column(col, &table.schema, &schema.enums);
+            for (kind, message) in column_def_warnings(col, source, target, enum_info) {
+                warnings.push(kind, column_path(table, &col.name), message);
+            }
+        }
+        if options.noconstraints || source == target {
+            continue;
+        }
+        for c in &table.constraints {
+            let Some(expr) = c.check_expression.as_deref() else {
+                continue;
+            };
+            if c.constraint_type == ConstraintType::Check
+                && !check_predicate_is_portable(expr, source, target)
+            {
+                warnings.push(
+                    WarningKind::SkippedConstruct,
+                    table_path(table),
+                    format!("CHECK {} uses syntax {target} cannot run", c.name),
+                );
+            }
+        }
+    }
+}
+
 /// Output from DDL generation.
 pub enum DdlOutput {
     Single(String),
@@ -51,11 +92,12 @@ impl DdlGenerator {
     fn generate_full(&self, schema: &IntrospectedSchema, options: &DdlOptions) -> DdlOutput {
         let source_dialect = schema.dialect;
         let target_dialect = options.target_dialect;
-        let sorted = topo_sort_tables(&schema.tables);
+        let order = order_tables(&schema.tables);
         let shared_sequences = shared_sequences(schema);
 
         // Filter to tables only (skip views for DDL)
-        let tables: Vec<&&TableInfo> = sorted
+        let tables: Vec<&&TableInfo> = order
+            .tables
             .iter()
             .filter(|t| t.table_type == TableType::Table)
             .collect();
@@ -63,14 +105,20 @@ impl DdlGenerator {
         let mut all_stmts: Vec<String> = Vec::new();
         let mut per_table: Vec<(String, String)> = Vec::new();
 
-        // Detect circular FK dependencies — warn if cycles exist
-        let has_cycles = detect_fk_cycles(&schema.tables);
-
-        // Header
-        let mut header =
-            format!("-- Generated by uvg\n-- Source: {source_dialect}, Target: {target_dialect}\n");
-        if has_cycles {
-            header.push_str("-- WARNING: Circular foreign key dependencies detected.\n-- Some FK constraints may reference tables not yet created.\n-- Consider applying FK constraints separately via ALTER TABLE.\n");
+        // Cycle notes; the provenance header says what was generated from
+        // what.
+        let mut header = String::new();
+        if !order.cycles.is_empty() {
+            header.push_str("-- WARNING: Circular foreign key dependencies detected.\n");
+            for cycle in &order.cycles {
+                header.push_str(&format!(
+                    "--   {} (break at {}.{})\n",
+                    cycle.path(),
+                    cycle.table,
+                    cycle.constraint
+                ));
+            }
+            header.push_str("-- Some FK constraints may reference tables not yet created.\n-- Consider applying FK constraints separately via ALTER TABLE.\n\n");
         }
 
         // Schema-scoped dependencies (PG target only). Sequences precede
@@ -101,19 +149,33 @@ impl DdlGenerator {
             }
 
             if !options.nocomments {
-                let comments = generate_comments(table, source_dialect, target_dialect);
+                let comments = generate_comments(table, source_dialect, target_dialect, options);
                 table_stmts.extend(comments);
             }
 
             let table_ddl = table_stmts.join("\n\n");
             all_stmts.push(table_ddl.clone());
             // Disambiguate filenames by schema for multi-schema introspection
-            let filename =
-                if table.schema.is_empty() || table.schema == target_dialect.default_schema() {
-                    format!("{}.sql", table.name)
+            let is_default_schema =
+                table.schema.is_empty() || table.schema == target_dialect.default_schema();
+            let filename = if options.split_by_schema {
+                // Skeema-style tree: every table lives under its schema's
+                // directory, including the default schema.
+                let dir = if table.schema.is_empty() {
+                    target_dialect.default_schema()
                 } else {
-                    format!("{}_{}.sql", table.schema, table.name)
+                    table.schema.as_str()
                 };
+                if dir.is_empty() {
+                    format!("{}.sql", table.name)
+                } else {
+                    format!("{dir}/{}.sql", table.name)
+                }
+            } else if is_default_schema {
+                format!("{}.sql", table.name)
+            } else {
+                format!("{}_{}.sql", table.schema, table.name)
+            };
             per_table.push((filename, table_ddl));
         }
 
@@ -123,12 +185,12 @@ impl DdlGenerator {
             if !schema_stmts.is_empty() {
                 files.push((
                     "_types.sql".to_string(),
-                    format!("{header}\n{}", schema_stmts.join("\n\n")),
+                    format!("{header}{}", schema_stmts.join("\n\n")),
                 ));
             }
 
             for (filename, content) in per_table {
-                files.push((filename, format!("{header}\n{content}\n")));
+                files.push((filename, format!("{header}{content}\n")));
             }
 
             // Order file uses the actual filenames from per_table (already schema-qualified)
@@ -143,11 +205,9 @@ impl DdlGenerator {
         } else {
             let mut full = header;
             if !schema_stmts.is_empty() {
-                full.push('\n');
                 full.push_str(&schema_stmts.join("\n\n"));
                 full.push_str("\n\n");
             }
-            full.push('\n');
             full.push_str(&all_stmts.join("\n\n"));
             full.push('\n');
             DdlOutput::Single(full)
@@ -1,6 +1,8 @@
+use crate::cli::DdlOptions;
 use crate::dialect::Dialect;
-use crate::schema::TableInfo;
+use crate::schema::{ConstraintType, TableInfo};
 
+use crate::codegen::render::check_predicate_is_portable;
 use crate::codegen::render::ident::{qualified_table_name, quote_identifier};
 
 /// Generate COMMENT ON statements (PG only; MySQL is inline).
@@ -8,6 +10,7 @@ pub(super) fn generate_comments(
     table: &TableInfo,
     source_dialect: Dialect,
     target_dialect: Dialect,
+    options: &DdlOptions,
 ) -> Vec<String> {
     // Only PG uses separate COMMENT ON statements
     if !target_dialect.supports_comment_on() {
@@ -17,14 +20,14 @@ pub(super) fn generate_comments(
     let mut stmts = Vec::new();
     let tname = qualified_table_name(&table.schema, &table.name, source_dialect, target_dialect);
 
-    if let Some(ref comment) = table.comment {
+    if let Some(comment) = table.comment.as_ref().filter(|_| options.table_comments()) {
         stmts.push(format!(
             "COMMENT ON TABLE {tname} IS '{}';",
             comment.replace('\'', "''")
         ));
     }
 
-    for col in &table.columns {
+    for col in table.columns.iter().filter(|_| options.column_comments()) {
         if let Some(ref comment) = col.comment {
             stmts.push(format!(
                 "COMMENT ON COLUMN {tname}.{} IS '{}';",
@@ -34,5 +37,43 @@ pub(super) fn generate_comments(
         }
     }
 
+    if !options.noconstraints {
+        for constraint in &table.constraints {
+            let Some(ref comment) = constraint.comment else {
+                continue;
+            };
+            // Non-portable checks are dropped from CREATE TABLE.
+            let dropped = constraint.constraint_type == ConstraintType::Check
+                && source_dialect != target_dialect
+                && !constraint.check_expression.as_deref().is_some_and(|expr| {
+                    check_predicate_is_portable(expr, source_dialect, target_dialect)
+                });
+            if !dropped {
+                stmts.push(format!(
+                    "COMMENT ON CONSTRAINT {} ON {tname} IS '{}';",
+                    quote_identifier(&constraint.name, target_dialect),
+                    comment.replace('\'', "''")
+                ));
+            }
+        }
+    }
+
+    if !options.noindexes {
+        for index in table.indexes.iter().filter(|index| !index.columnstore) {
+            if let Some(ref comment) = index.comment {
+                stmts.push(format!(
+                    "COMMENT ON INDEX {} IS '{}';",
+                    qualified_table_name(
+                        &table.schema,
+                        &index.name,
+                        source_dialect,
+                        target_dialect
+                    ),
+                    comment.replace('\'', "''")
+                ));
+            }
+        }
+    }
+
     stmts
 }
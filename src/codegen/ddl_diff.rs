@@ -19,9 +19,10 @@ use super::ddl::{
     generate_enum_type, generate_sequence, referenced_enums, referenced_sequences, shared_sequences,
 };
 use super::render::{
-    check_predicate_is_portable, format_ddl_default_typed, generate_column_def,
-    generate_create_table, generate_indexes, postgres_index_method, qualified_object_name,
-    qualified_table_name, quote_identifier, translate_check_predicate,
+    check_predicate_is_portable, create_index_sql, exclude_constraint_sql, fk_deferral,
+    format_ddl_default_typed, generate_column_def, generate_create_table, generate_indexes,
+    nulls_not_distinct_sql, qualified_object_name, qualified_table_name, quote_identifier,
+    translate_check_predicate,
 };
 
 /// Compute the schema diff as a stream of tagged `Change` records.
@@ -356,19 +357,12 @@ pub fn compute_changes(
 /// format that `diff_schemas()` returns. Empty input yields the
 /// "no schema changes detected" sentinel so existing string-grep callers
 /// (e.g. the TUI's empty-check at `src/tui/mod.rs:307`) keep working.
-pub fn render_changes(
-    changes: &[Change],
-    source_dialect: Dialect,
-    target_dialect: Dialect,
-) -> String {
+pub fn render_changes(changes: &[Change]) -> String {
     if changes.is_empty() {
         return "-- No schema changes detected.\n".to_string();
     }
-    let header = format!(
-        "-- Generated by uvg (diff)\n-- Source: {source_dialect}, Target: {target_dialect}\n\n"
-    );
     let stmts: Vec<&str> = changes.iter().map(|c| c.sql.as_str()).collect();
-    format!("{header}{}\n", stmts.join("\n\n"))
+    format!("{}\n", stmts.join("\n\n"))
 }
 
 /// Diff two schemas and emit ALTER statements.
@@ -378,10 +372,7 @@ pub fn diff_schemas(
     target: &IntrospectedSchema,
     options: &DdlOptions,
 ) -> String {
-    let source_dialect = source.dialect;
-    let target_dialect = options.target_dialect;
-    let changes = compute_changes(source, target, options);
-    render_changes(&changes, source_dialect, target_dialect)
+    render_changes(&compute_changes(source, target, options))
 }
 
 fn enum_identity_schema(enum_info: &EnumInfo, dialect: Dialect) -> &str {
@@ -535,6 +526,7 @@ fn diff_table_columns(
                 target_dialect,
                 shared_sequences,
                 crate::codegen::find_enum_for_ddl_column(col, &source.schema, source_enums),
+                true,
             );
             let col_def = col_def.trim();
             let add_clause = match target_dialect {
@@ -572,6 +564,20 @@ fn diff_table_columns(
     dropped.sort();
     for name in dropped {
         let qcol = quote_identifier(name, target_dialect);
+        // MSSQL also rejects DROP COLUMN while a default constraint exists.
+        if let Some(constraint) = target_cols[name]
+            .default_constraint
+            .as_deref()
+            .filter(|_| target_dialect == Dialect::Mssql)
+        {
+            stmts.push((
+                ChangeKind::DropColumn,
+                format!(
+                    "ALTER TABLE {tname} DROP CONSTRAINT {};",
+                    quote_identifier(constraint, target_dialect)
+                ),
+            ));
+        }
         stmts.push((
             ChangeKind::DropColumn,
             format!("-- WARNING: destructive operation\nALTER TABLE {tname} DROP COLUMN {qcol};"),
@@ -774,7 +780,13 @@ fn constraints_content_match(
     if source.constraint_type != target.constraint_type {
         return false;
     }
-    if source.columns != target.columns {
+    if source.columns != target.columns || source.exclude != target.exclude {
+        return false;
+    }
+    if source_dialect == Dialect::Postgres
+        && target_dialect == Dialect::Postgres
+        && source.nulls_not_distinct != target.nulls_not_distinct
+    {
         return false;
     }
     match (&source.foreign_key, &target.foreign_key) {
@@ -788,7 +800,9 @@ fn constraints_content_match(
                 && (normalize_fk_rule(&source_fk.update_rule, source_dialect)
                     != normalize_fk_rule(&target_fk.update_rule, source_dialect)
                     || normalize_fk_rule(&source_fk.delete_rule, source_dialect)
-                        != normalize_fk_rule(&target_fk.delete_rule, source_dialect))
+                        != normalize_fk_rule(&target_fk.delete_rule, source_dialect)
+                    || source_fk.deferrable != target_fk.deferrable
+                    || source_fk.initially_deferred != target_fk.initially_deferred)
             {
                 return false;
             }
@@ -951,6 +965,8 @@ fn render_dropped_constraint(
             ConstraintType::PrimaryKey => format!("ALTER TABLE {tname} DROP PRIMARY KEY;"),
             ConstraintType::Unique => format!("ALTER TABLE {tname} DROP INDEX {cname};"),
             ConstraintType::Check => format!("ALTER TABLE {tname} DROP CHECK {cname};"),
+            // Never created on MySQL; see render_added_constraint.
+            ConstraintType::Exclude => format!("ALTER TABLE {tname} DROP INDEX {cname};"),
         },
         Dialect::Sqlite => format!(
             "-- WARNING: SQLite cannot drop constraint {} without rebuilding table {}",
@@ -984,7 +1000,8 @@ fn render_added_constraint(
             cols.join(", ")
         )),
         ConstraintType::Unique => Some(format!(
-            "ALTER TABLE {tname} ADD CONSTRAINT {cname} UNIQUE ({});",
+            "ALTER TABLE {tname} ADD CONSTRAINT {cname} UNIQUE{} ({});",
+            nulls_not_distinct_sql(constraint.nulls_not_distinct, target_dialect),
             cols.join(", ")
         )),
         ConstraintType::ForeignKey => {
@@ -1011,6 +1028,7 @@ fn render_added_constraint(
             if fk.update_rule != "NO ACTION" {
                 sql.insert_str(sql.len() - 1, &format!(" ON UPDATE {}", fk.update_rule));
             }
+            sql.insert_str(sql.len() - 1, fk_deferral(fk, target_dialect));
             Some(sql)
         }
         ConstraintType::Check => {
@@ -1029,6 +1047,16 @@ fn render_added_constraint(
                 "ALTER TABLE {tname} ADD CONSTRAINT {cname} CHECK ({translated});"
             ))
         }
+        ConstraintType::Exclude => {
+            let exclude = constraint.exclude.as_ref()?;
+            match exclude_constraint_sql(exclude, target_dialect) {
+                Some(sql) => Some(format!("ALTER TABLE {tname} ADD CONSTRAINT {cname} {sql};")),
+                None => Some(format!(
+                    "-- DROPPED EXCLUDE {}: exclusion constraints are PostgreSQL-only",
+                    constraint.name
+                )),
+            }
+        }
     }
 }
 
@@ -1075,7 +1103,7 @@ fn diff_table_indexes(
                 })
         })
         .filter(|idx| !is_unique_constraint_index(idx, &source.constraints))
-        .map(|idx| render_added_index(source, idx, source_dialect, target_dialect))
+        .filter_map(|idx| render_added_index(source, idx, source_dialect, target_dialect))
         .collect();
     (drops, adds)
 }
@@ -1086,7 +1114,12 @@ fn indexes_equivalent(
     source_dialect: Dialect,
     target_dialect: Dialect,
 ) -> bool {
-    if source.is_unique != target.is_unique || source.columns != target.columns {
+    if source.is_unique != target.is_unique
+        || source.columns != target.columns
+        || source.expressions != target.expressions
+        || source.orderings != target.orderings
+        || source.include != target.include
+    {
         return false;
     }
 
@@ -1103,7 +1136,13 @@ fn indexes_equivalent(
             .filter(|method| !method.is_empty())
             .map(String::as_str)
             .unwrap_or("btree");
-        return source_method == target_method;
+        return source_method == target_method
+            && source.opclasses == target.opclasses
+            && source.nulls_not_distinct == target.nulls_not_distinct;
+    }
+
+    if source_dialect == Dialect::Mssql && target_dialect == Dialect::Mssql {
+        return source.clustered == target.clustered && source.columnstore == target.columnstore;
     }
 
     true
@@ -1117,6 +1156,9 @@ fn is_constraint_backing_index(
     if is_unique_constraint_index(index, constraints) {
         return true;
     }
+    if index.has_expressions() {
+        return false;
+    }
     if index.is_unique
         && constraints.iter().any(|constraint| {
             matches!(constraint.constraint_type, ConstraintType::PrimaryKey)
@@ -1158,20 +1200,9 @@ fn render_added_index(
     index: &IndexInfo,
     source_dialect: Dialect,
     target_dialect: Dialect,
-) -> String {
+) -> Option<String> {
     let tname = qualified_table_name(&table.schema, &table.name, source_dialect, target_dialect);
-    let unique = if index.is_unique { "UNIQUE " } else { "" };
-    let cols: Vec<String> = index
-        .columns
-        .iter()
-        .map(|col| quote_identifier(col, target_dialect))
-        .collect();
-    let using = postgres_index_method(index, target_dialect);
-    format!(
-        "CREATE {unique}INDEX {} ON {tname}{using} ({});",
-        quote_identifier(&index.name, target_dialect),
-        cols.join(", ")
-    )
+    create_index_sql(index, &tname, target_dialect)
 }
 
 /// Compare a single column and emit ALTER statements if different.
@@ -1328,13 +1359,29 @@ fn diff_column(
                 ));
             }
             if default_changed {
-                stmts.push(format!(
-                    "-- NOTE: MSSQL requires dropping the named default constraint first.\n-- Run: SELECT name FROM sys.default_constraints WHERE parent_object_id = OBJECT_ID('{tname_raw}') AND col_name(parent_object_id, parent_column_id) = '{col_name}'\n-- Then: ALTER TABLE {tname} DROP CONSTRAINT <name>;",
-                    tname_raw = table_name,
-                    col_name = source.name
-                ));
+                match target.default_constraint.as_deref() {
+                    Some(name) if target.column_default.is_some() => stmts.push(format!(
+                        "ALTER TABLE {tname} DROP CONSTRAINT {};",
+                        quote_identifier(name, target_dialect)
+                    )),
+                    _ => stmts.push(format!(
+                        "-- NOTE: MSSQL requires dropping the named default constraint first.\n-- Run: SELECT name FROM sys.default_constraints WHERE parent_object_id = OBJECT_ID('{tname_raw}') AND col_name(parent_object_id, parent_column_id) = '{col_name}'\n-- Then: ALTER TABLE {tname} DROP CONSTRAINT <name>;",
+                        tname_raw = table_name,
+                        col_name = source.name
+                    )),
+                }
                 if let Some(ref d) = source_default {
-                    stmts.push(format!("ALTER TABLE {tname} ADD DEFAULT {d} FOR {cname};"));
+                    let constraint = source
+                        .default_constraint
+                        .as_deref()
+                        .filter(|_| source_dialect == Dialect::Mssql)
+                        .map(|name| {
+                            format!("CONSTRAINT {} ", quote_identifier(name, target_dialect))
+                        })
+                        .unwrap_or_default();
+                    stmts.push(format!(
+                        "ALTER TABLE {tname} ADD {constraint}DEFAULT {d} FOR {cname};"
+                    ));
                 }
             }
         }
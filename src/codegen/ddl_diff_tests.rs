@@ -9,10 +9,13 @@ fn default_options(target: Dialect) -> DdlOptions {
     DdlOptions {
         target_dialect: target,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     }
 }
 
@@ -371,7 +374,7 @@ fn test_diff_blocks_all_sql_when_postgres_enum_labels_drift() {
         );
         assert!(!changes[0].sql.contains("CREATE TABLE"));
 
-        let rendered = render_changes(&changes, Dialect::Postgres, Dialect::Postgres);
+        let rendered = render_changes(&changes);
         assert!(rendered.contains("UVG-BLOCKED"));
         assert!(!rendered.contains("No schema changes detected"));
         assert!(!rendered.contains("CREATE TABLE \"audit_log\""));
@@ -888,6 +891,41 @@ fn test_diff_drops_dependent_objects_before_column_drop() {
     );
 }
 
+#[test]
+fn test_diff_mssql_named_default_constraints() {
+    let mut source = schema_mssql(vec![table("Orders")
+        .schema("dbo")
+        .column(col("Id").udt("int").build())
+        .column(
+            col("Status")
+                .udt("varchar")
+                .default_val("('shipped')")
+                .build(),
+        )
+        .build()]);
+    source.tables[0].columns[1].default_constraint = Some("DF_Orders_Status".to_string());
+    let mut target = schema_mssql(vec![table("Orders")
+        .schema("dbo")
+        .column(col("Id").udt("int").build())
+        .column(col("Status").udt("varchar").default_val("('new')").build())
+        .column(col("Notes").udt("varchar").default_val("('')").build())
+        .build()]);
+    target.tables[0].columns[1].default_constraint = Some("DF_Orders_Status".to_string());
+    target.tables[0].columns[2].default_constraint = Some("DF_Orders_Notes".to_string());
+
+    let ddl = diff_schemas(&source, &target, &default_options(Dialect::Mssql));
+
+    assert!(ddl.contains("ALTER TABLE [Orders] DROP CONSTRAINT [DF_Orders_Status];"));
+    assert!(ddl.contains(
+        "ALTER TABLE [Orders] ADD CONSTRAINT [DF_Orders_Status] DEFAULT 'shipped' FOR [Status];"
+    ));
+    assert!(!ddl.contains("sys.default_constraints"));
+    let constraint_drop = ddl
+        .find("DROP CONSTRAINT [DF_Orders_Notes];")
+        .expect("default constraint drop should be emitted");
+    assert!(constraint_drop < ddl.find("DROP COLUMN [Notes];").unwrap());
+}
+
 #[test]
 fn test_diff_multi_schema_preserved() {
     let source = schema_pg(vec![table("users")
@@ -1048,14 +1086,14 @@ fn test_compute_changes_new_table_with_index_tagged_together() {
 
 #[test]
 fn test_render_changes_empty_returns_sentinel() {
-    let out = render_changes(&[], Dialect::Postgres, Dialect::Postgres);
+    let out = render_changes(&[]);
     assert_eq!(out, "-- No schema changes detected.\n");
 }
 
 #[test]
 fn test_render_changes_round_trip_with_diff_schemas() {
     // diff_schemas() must produce byte-identical output to
-    // render_changes(&compute_changes(...), ...). This protects the
+    // render_changes(&compute_changes(...)). This protects the
     // CRM matrix and all existing string-grep tests.
     let source = schema_pg(vec![
         table("users")
@@ -1075,11 +1113,7 @@ fn test_render_changes_round_trip_with_diff_schemas() {
     let options = default_options(Dialect::Postgres);
 
     let direct = diff_schemas(&source, &target, &options);
-    let via_changes = render_changes(
-        &compute_changes(&source, &target, &options),
-        Dialect::Postgres,
-        Dialect::Postgres,
-    );
+    let via_changes = render_changes(&compute_changes(&source, &target, &options));
     assert_eq!(direct, via_changes);
 }
 
@@ -4,7 +4,7 @@ use crate::codegen::render::{
     translate_check_predicate,
 };
 use crate::schema::EnumInfo;
-use crate::testutil::{col, schema_pg, schema_pg_with_enums, table};
+use crate::testutil::{col, schema_mssql, schema_pg, schema_pg_with_enums, table};
 
 #[test]
 fn test_full_postgres_ddl_qualifies_and_filters_enum_dependencies() {
@@ -43,10 +43,13 @@ fn test_full_postgres_ddl_qualifies_and_filters_enum_dependencies() {
     let options = DdlOptions {
         target_dialect: Dialect::Postgres,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     };
 
     let output = match DdlGenerator.generate(&schema, None, &options) {
@@ -92,10 +95,13 @@ fn test_postgres_shared_sequence_is_created_once_and_preserved() {
         &crate::cli::DdlOptions {
             target_dialect: Dialect::Postgres,
             split_tables: false,
+            split_by_schema: false,
             apply: false,
             noindexes: false,
             noconstraints: false,
             nocomments: false,
+            notablecomments: false,
+            nocolumncomments: false,
         },
     ) {
         DdlOutput::Single(output) => output,
@@ -134,10 +140,13 @@ fn test_postgres_single_owner_sequence_remains_serial() {
     let options = DdlOptions {
         target_dialect: Dialect::Postgres,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     };
 
     let output = match DdlGenerator.generate(&schema, None, &options) {
@@ -165,10 +174,13 @@ fn test_postgres_non_btree_index_method_is_preserved() {
     let options = DdlOptions {
         target_dialect: Dialect::Postgres,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     };
 
     let output = match DdlGenerator.generate(&schema, None, &options) {
@@ -180,6 +192,535 @@ fn test_postgres_non_btree_index_method_is_preserved() {
         .contains("CREATE INDEX \"film_fulltext_idx\" ON \"film\" USING gist (\"fulltext\");"));
 }
 
+#[test]
+fn test_deferrable_foreign_key_only_on_supporting_targets() {
+    let schema = schema_pg(vec![
+        table("parents")
+            .column(col("id").udt("int4").build())
+            .pk("parents_pkey", &["id"])
+            .build(),
+        table("children")
+            .column(col("parent_id").udt("int4").build())
+            .fk_deferrable(
+                "children_parent_id_fkey",
+                &["parent_id"],
+                "parents",
+                &["id"],
+                true,
+            )
+            .build(),
+    ]);
+    let mut options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains("REFERENCES \"parents\" (\"id\") DEFERRABLE INITIALLY DEFERRED"));
+
+    options.target_dialect = Dialect::Mssql;
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(!output.contains("DEFERRABLE"));
+}
+
+#[test]
+fn test_fk_cycle_is_listed_in_header() {
+    let schema = schema_pg(vec![
+        table("a")
+            .column(col("id").udt("int4").build())
+            .column(col("b_id").udt("int4").build())
+            .fk("a_b_id_fkey", &["b_id"], "b", &["id"])
+            .build(),
+        table("b")
+            .column(col("id").udt("int4").build())
+            .column(col("a_id").udt("int4").build())
+            .fk("b_a_id_fkey", &["a_id"], "a", &["id"])
+            .build(),
+    ]);
+    let options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains("--   a -> b -> a (break at b.b_a_id_fkey)\n"));
+}
+
+#[test]
+fn test_postgres_index_operator_class_is_preserved() {
+    let schema = schema_pg(vec![table("docs")
+        .column(col("title").udt("text").build())
+        .index_with_opclasses(
+            "docs_title_idx",
+            &["title"],
+            "gin",
+            &[("title", "gin_trgm_ops")],
+        )
+        .build()]);
+    let mut options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains(
+        "CREATE INDEX \"docs_title_idx\" ON \"docs\" USING gin (\"title\" gin_trgm_ops);"
+    ));
+
+    // Operator classes are PostgreSQL-only.
+    options.target_dialect = Dialect::Sqlite;
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains("CREATE INDEX \"docs_title_idx\" ON \"docs\" (\"title\");"));
+}
+
+#[test]
+fn test_index_sort_order_is_preserved() {
+    let mut schema = schema_pg(vec![table("events")
+        .column(col("created_at").udt("timestamptz").build())
+        .index("events_created_at_idx", &["created_at"], false)
+        .build()]);
+    schema.tables[0].indexes[0].orderings.insert(
+        "created_at".to_string(),
+        crate::schema::SortOrder {
+            descending: true,
+            nulls_first: false,
+        },
+    );
+    let mut options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains("ON \"events\" (\"created_at\" DESC NULLS LAST);"));
+
+    // SQL Server has no NULLS FIRST/LAST; the direction survives.
+    options.target_dialect = Dialect::Mssql;
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains("([created_at] DESC);"));
+}
+
+#[test]
+fn test_index_include_columns_are_preserved() {
+    let mut schema = schema_pg(vec![table("orders")
+        .column(col("customer_id").build())
+        .column(col("total").udt("numeric").build())
+        .index("orders_customer_idx", &["customer_id"], false)
+        .build()]);
+    schema.tables[0].indexes[0].include = vec!["total".to_string()];
+    let mut options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains("ON \"orders\" (\"customer_id\") INCLUDE (\"total\");"));
+
+    // MySQL has no covering indexes; the key columns still make an index.
+    options.target_dialect = Dialect::Mysql;
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains("ON `orders` (`customer_id`);"));
+}
+
+#[test]
+fn test_nulls_not_distinct_is_preserved() {
+    let mut schema = schema_pg(vec![table("accounts")
+        .column(col("email").udt("text").build())
+        .column(col("phone").udt("text").build())
+        .unique("accounts_email_key", &["email"])
+        .index("accounts_phone_idx", &["phone"], true)
+        .build()]);
+    schema.tables[0].constraints[0].nulls_not_distinct = true;
+    schema.tables[0].indexes[0].nulls_not_distinct = true;
+    let mut options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(
+        output.contains("CONSTRAINT \"accounts_email_key\" UNIQUE NULLS NOT DISTINCT (\"email\")")
+    );
+    assert!(output.contains("ON \"accounts\" (\"phone\") NULLS NOT DISTINCT;"));
+
+    options.target_dialect = Dialect::Sqlite;
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(!output.contains("NULLS NOT DISTINCT"));
+}
+
+#[test]
+fn test_exclusion_constraint_is_postgres_only() {
+    let mut schema = schema_pg(vec![table("bookings")
+        .column(col("room_id").build())
+        .column(col("starts").udt("timestamp").build())
+        .column(col("ends").udt("timestamp").build())
+        .build()]);
+    schema.tables[0]
+        .constraints
+        .push(crate::schema::ConstraintInfo::exclude(
+            "bookings_no_overlap",
+            crate::schema::ExcludeInfo {
+                using: "gist".to_string(),
+                elements: vec![
+                    crate::schema::ExcludeElement {
+                        key: "room_id".to_string(),
+                        is_expression: false,
+                        operator: "=".to_string(),
+                    },
+                    crate::schema::ExcludeElement {
+                        key: "tsrange(starts, ends)".to_string(),
+                        is_expression: true,
+                        operator: "&&".to_string(),
+                    },
+                ],
+                predicate: Some("room_id > 0".to_string()),
+            },
+        ));
+    let mut options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains(
+        "CONSTRAINT \"bookings_no_overlap\" EXCLUDE USING gist (\"room_id\" WITH =, (tsrange(starts, ends)) WITH &&) WHERE (room_id > 0)"
+    ));
+
+    options.target_dialect = Dialect::Mysql;
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains(
+        "-- DROPPED EXCLUDE bookings_no_overlap: exclusion constraints are PostgreSQL-only"
+    ));
+    assert!(!output.contains("EXCLUDE USING"));
+}
+
+#[test]
+fn test_index_and_constraint_comments_are_preserved() {
+    let mut schema = schema_pg(vec![table("orders")
+        .schema("public")
+        .column(col("total").udt("numeric").build())
+        .index("orders_total", &["total"], false)
+        .check("orders_total_check", "total >= 0")
+        .build()]);
+    schema.tables[0].indexes[0].comment = Some("Reporting lookups".to_string());
+    schema.tables[0].constraints[0].comment = Some("Totals can't go negative".to_string());
+    let mut options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains(
+        "COMMENT ON CONSTRAINT \"orders_total_check\" ON \"orders\" \
+         IS 'Totals can''t go negative';"
+    ));
+    assert!(output.contains("COMMENT ON INDEX \"orders_total\" IS 'Reporting lookups';"));
+
+    options.target_dialect = Dialect::Mysql;
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains("(`total`) COMMENT 'Reporting lookups';"));
+    assert!(!output.contains("COMMENT ON"));
+}
+
+#[test]
+fn test_table_and_column_comments_are_controlled_separately() {
+    let schema = schema_pg(vec![table("orders")
+        .schema("public")
+        .column(col("total").udt("numeric").comment("Gross").build())
+        .comment("Customer orders")
+        .build()]);
+    let mut options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: true,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(!output.contains("COMMENT ON TABLE"));
+    assert!(output.contains("COMMENT ON COLUMN \"orders\".\"total\" IS 'Gross';"));
+
+    options.target_dialect = Dialect::Mysql;
+    options.notablecomments = false;
+    options.nocolumncomments = true;
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains(") COMMENT 'Customer orders';"));
+    assert!(!output.contains("'Gross'"));
+}
+
+#[test]
+fn test_mssql_default_constraint_name_is_kept() {
+    let mut schema = schema_mssql(vec![table("orders")
+        .schema("dbo")
+        .column(col("status").udt("varchar").default_val("('new')").build())
+        .build()]);
+    schema.tables[0].columns[0].default_constraint = Some("DF_orders_status".to_string());
+    let mut options = DdlOptions {
+        target_dialect: Dialect::Mssql,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains("CONSTRAINT [DF_orders_status] DEFAULT 'new'"));
+
+    options.target_dialect = Dialect::Postgres;
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(!output.contains("DF_orders_status"));
+}
+
+#[test]
+fn test_cross_dialect_losses_are_collected_as_warnings() {
+    let schema = schema_pg(vec![table("events")
+        .schema("public")
+        .column(col("id").udt("int4").build())
+        .column(
+            col("payload")
+                .udt("jsonb")
+                .default_val("'{}'::jsonb")
+                .build(),
+        )
+        .column(col("code").udt("text").build())
+        .check("ck_events_code", "code ~ '^[A-Z]+$'")
+        .build()]);
+    let options = DdlOptions {
+        target_dialect: Dialect::Mysql,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let mut warnings = crate::warnings::Warnings::new();
+    collect_warnings(&schema, &options, &mut warnings);
+    let found: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        found,
+        [
+            "type fallback: public.events.payload: JSONB binary indexing not available in MySQL; rendered as JSON",
+            "dropped default: public.events.payload: DEFAULT '{}'::jsonb dropped: MySQL allows no default on TEXT, BLOB, or JSON columns",
+            "skipped: public.events: CHECK ck_events_code uses syntax mysql cannot run",
+        ]
+    );
+
+    // Same-dialect output loses nothing.
+    let options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        ..options
+    };
+    let mut warnings = crate::warnings::Warnings::new();
+    collect_warnings(&schema, &options, &mut warnings);
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_mssql_clustered_and_columnstore_indexes() {
+    let mut schema = schema_mssql(vec![table("orders")
+        .schema("dbo")
+        .column(col("customer_id").udt("int").build())
+        .column(col("total").udt("decimal").build())
+        .index("cix_orders_customer", &["customer_id"], false)
+        .index("ncci_orders", &["customer_id", "total"], false)
+        .index("cci_orders", &["customer_id", "total"], false)
+        .build()]);
+    schema.tables[0].indexes[0].clustered = true;
+    schema.tables[0].indexes[1].columnstore = true;
+    schema.tables[0].indexes[2].clustered = true;
+    schema.tables[0].indexes[2].columnstore = true;
+    let mut options = DdlOptions {
+        target_dialect: Dialect::Mssql,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output
+        .contains("CREATE CLUSTERED INDEX [cix_orders_customer] ON [orders] ([customer_id]);"));
+    assert!(output.contains(
+        "CREATE NONCLUSTERED COLUMNSTORE INDEX [ncci_orders] ON [orders] ([customer_id], [total]);"
+    ));
+    assert!(output.contains("CREATE CLUSTERED COLUMNSTORE INDEX [cci_orders] ON [orders];"));
+
+    // Columnstore indexes have no PostgreSQL equivalent.
+    options.target_dialect = Dialect::Postgres;
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+    assert!(output.contains("CREATE INDEX \"cix_orders_customer\""));
+    assert!(!output.contains("cci_orders"));
+}
+
+#[test]
+fn test_postgres_expression_index_keeps_expression_keys() {
+    let schema = schema_pg(vec![table("users")
+        .column(col("tenant_id").build())
+        .column(col("email").udt("varchar").build())
+        .expression_index(
+            "users_tenant_lower_email_idx",
+            &["tenant_id"],
+            &["tenant_id", "lower((email)::text)"],
+        )
+        .build()]);
+    let options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: false,
+        split_by_schema: false,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+
+    let output = match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Single(output) => output,
+        DdlOutput::Split(_) => panic!("expected single DDL output"),
+    };
+
+    assert!(output.contains(
+        "CREATE INDEX \"users_tenant_lower_email_idx\" ON \"users\" (\"tenant_id\", (lower((email)::text)));"
+    ));
+}
+
 #[test]
 fn test_quote_identifier_pg() {
     assert_eq!(quote_identifier("users", Dialect::Postgres), "\"users\"");
@@ -542,10 +1083,13 @@ fn test_json_default_dropped_on_mysql_target() {
     let options = DdlOptions {
         target_dialect: Dialect::Mysql,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     };
     let ddl = generate_create_table(
         &t,
@@ -590,10 +1134,13 @@ fn test_json_default_kept_on_pg_target() {
     let options = DdlOptions {
         target_dialect: Dialect::Postgres,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     };
     let ddl = generate_create_table(
         &t,
@@ -621,10 +1168,13 @@ fn test_generate_create_table_pg_to_mysql() {
     let options = DdlOptions {
         target_dialect: Dialect::Mysql,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     };
     let ddl = generate_create_table(
         &t,
@@ -654,10 +1204,13 @@ fn test_generate_create_table_pg_to_pg() {
     let options = DdlOptions {
         target_dialect: Dialect::Postgres,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     };
     let ddl = generate_create_table(
         &t,
@@ -682,16 +1235,19 @@ fn test_full_generate_single() {
     let options = DdlOptions {
         target_dialect: Dialect::Mysql,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     };
     let gen = DdlGenerator;
     match gen.generate(&schema, None, &options) {
         DdlOutput::Single(ddl) => {
             assert!(ddl.contains("CREATE TABLE `users`"));
-            assert!(ddl.contains("Source: postgres, Target: mysql"));
+            assert!(!ddl.contains("Generated by uvg"), "{ddl}");
         }
         DdlOutput::Split(_) => panic!("Expected single output"),
     }
@@ -712,10 +1268,13 @@ fn test_full_generate_split() {
     let options = DdlOptions {
         target_dialect: Dialect::Postgres,
         split_tables: true,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     };
     match DdlGenerator.generate(&schema, None, &options) {
         DdlOutput::Split(files) => {
@@ -793,3 +1352,39 @@ fn test_ensure_default_quoting() {
     );
     assert_eq!(ensure_default_quoting("it's"), "'it''s'");
 }
+
+#[test]
+fn test_full_generate_split_by_schema() {
+    let schema = schema_pg(vec![
+        table("users")
+            .column(col("id").build())
+            .pk("pk_users", &["id"])
+            .build(),
+        table("ledger")
+            .schema("billing")
+            .column(col("id").build())
+            .pk("pk_ledger", &["id"])
+            .build(),
+    ]);
+    let options = DdlOptions {
+        target_dialect: Dialect::Postgres,
+        split_tables: true,
+        split_by_schema: true,
+        apply: false,
+        noindexes: false,
+        noconstraints: false,
+        nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
+    };
+    match DdlGenerator.generate(&schema, None, &options) {
+        DdlOutput::Split(files) => {
+            let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
+            assert!(names.contains(&"public/users.sql"), "{names:?}");
+            assert!(names.contains(&"billing/ledger.sql"), "{names:?}");
+            let order = &files.iter().find(|(n, _)| n == "_order.txt").unwrap().1;
+            assert!(order.contains("billing/ledger.sql\n"));
+        }
+        DdlOutput::Single(_) => panic!("Expected split output"),
+    }
+}
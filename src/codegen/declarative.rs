@@ -2,20 +2,25 @@ mod association;
 mod attrs;
 mod class;
 mod fallback;
+mod mixins;
+mod repr;
 mod table_args;
 
+pub(crate) use self::attrs::class_attr_names;
+
 use self::association::generate_association_table;
 use self::class::generate_class;
 use self::fallback::generate_table_fallback;
+use self::mixins::TimestampMixin;
 use crate::cli::GeneratorOptions;
 use crate::codegen::imports::ImportCollector;
-use crate::codegen::python::PythonOutput;
-use crate::codegen::relationships::is_association_table;
+use crate::codegen::python::{ModelLinks, PythonOutput};
+use crate::codegen::relationships::renders_as_association;
 use crate::codegen::{
-    enum_class_name, find_enum_for_column, generate_enum_class, has_primary_key, parse_check_enum,
-    topo_sort_tables,
+    enum_class_name, find_column_enum, generate_enum_class, generate_typed_dict, has_primary_key,
+    order_tables, parse_check_enum, sequence_definition, used_sequences, NamingConvention,
 };
-use crate::naming::{table_to_class_name, table_to_variable_name};
+use crate::naming::{table_class_name, table_variable_name, typed_dict_name};
 use crate::schema::EnumInfo;
 use crate::schema::{ConstraintType, IntrospectedSchema};
 use std::collections::{HashMap, HashSet};
@@ -30,7 +35,7 @@ pub fn generate_split(
     schema: &IntrospectedSchema,
     options: &GeneratorOptions,
 ) -> Vec<(String, String)> {
-    parts(schema, options).split()
+    parts(schema, options).split_with(options)
 }
 
 /// Build the structured output: prelude (imports, enum classes, Base or
@@ -38,6 +43,8 @@ pub fn generate_split(
 fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutput {
     let mut imports = ImportCollector::new();
     let mut blocks: Vec<(String, String)> = Vec::new();
+    let mut links: HashMap<String, ModelLinks> = HashMap::new();
+    let mut schemas: HashMap<String, String> = HashMap::new();
     let mut needs_optional = false;
     let mut needs_datetime = false;
     let mut needs_decimal = false;
@@ -47,26 +54,36 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
         .tables
         .iter()
         .any(|t| has_primary_key(&t.constraints));
-    let has_any_no_pk = schema
+    // Association tables and tables without a primary key render as
+    // `Table(...)` blocks, even when every table has a primary key.
+    let has_any_table_block = schema
         .tables
         .iter()
-        .any(|t| !has_primary_key(&t.constraints));
+        .any(|t| renders_as_association(t, options) || !has_primary_key(&t.constraints));
 
     if has_any_pk {
-        imports.add("sqlalchemy.orm", "DeclarativeBase");
+        if let Some(ref base) = options.base_class {
+            imports.add(&base.module, &base.name);
+        } else {
+            imports.add("sqlalchemy.orm", "DeclarativeBase");
+            if options.asyncio {
+                imports.add("sqlalchemy.ext.asyncio", "AsyncAttrs");
+            }
+        }
         imports.add("sqlalchemy.orm", "Mapped");
         imports.add("sqlalchemy.orm", "mapped_column");
     } else {
         imports.add("sqlalchemy", "MetaData");
     }
 
-    if has_any_no_pk {
+    if has_any_table_block {
         imports.add("sqlalchemy", "Table");
         imports.add("sqlalchemy", "Column");
     }
 
+    let base_metadata = format!("{}.metadata", options.base_name());
     let metadata_ref = if has_any_pk {
-        "Base.metadata"
+        base_metadata.as_str()
     } else {
         "metadata"
     };
@@ -75,14 +92,22 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
     let mut all_enums: Vec<EnumInfo> = schema.enums.clone();
     let mut synthetic_enum_cols: HashMap<(String, String), String> = HashMap::new();
 
-    let sorted_tables = topo_sort_tables(&schema.tables);
+    let order = order_tables(&schema.tables);
+    // An imported `--base-class` Base brings its own MetaData.
+    let convention = (options.naming_convention && !(has_any_pk && options.base_class.is_some()))
+        .then(|| NamingConvention::infer(schema));
+    if convention.is_some() {
+        imports.add("sqlalchemy", "MetaData");
+    }
 
     if !options.nosyntheticenums {
-        for table_ref in &sorted_tables {
+        for table_ref in &order.tables {
             for constraint in &table_ref.constraints {
                 if constraint.constraint_type == ConstraintType::Check {
                     if let Some(ref expr) = constraint.check_expression {
-                        if let Some((col_name, values)) = parse_check_enum(expr) {
+                        if let Some((col_name, values)) =
+                            parse_check_enum(expr, options.enum_from_check)
+                        {
                             let key = (table_ref.name.clone(), col_name.clone());
                             if let std::collections::hash_map::Entry::Vacant(entry) =
                                 synthetic_enum_cols.entry(key)
@@ -105,16 +130,46 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
         }
     }
 
+    // A first rendering pass finds the timestamp columns classes share.
+    let mixin = if options.mixins {
+        let mut scratch = ImportCollector::new();
+        let classes: Vec<Vec<(String, String)>> = order
+            .tables_then_views()
+            .filter(|table| {
+                !renders_as_association(table, options) && has_primary_key(&table.constraints)
+            })
+            .map(|table| {
+                let (_, meta) = generate_class(
+                    table,
+                    &mut scratch,
+                    options,
+                    schema.dialect,
+                    schema,
+                    &all_enums,
+                    &synthetic_enum_cols,
+                    &order,
+                    convention.as_ref(),
+                    None,
+                );
+                meta.timestamp_columns
+            })
+            .collect();
+        TimestampMixin::detect(&classes)
+    } else {
+        None
+    };
+
     let mut used_enum_names: HashSet<String> = HashSet::new();
+    let mut typed_dicts: Vec<String> = Vec::new();
 
-    for table in &sorted_tables {
+    for table in order.tables_then_views() {
         // ORM classes and no-PK Table() fallbacks both render Enum() types.
         // Association tables use their own renderer, which does not yet do so.
-        let renders_enums = !is_association_table(table);
+        let renders_enums = !renders_as_association(table, options);
         if renders_enums {
             for col_info in &table.columns {
-                if find_enum_for_column(&col_info.udt_name, &all_enums).is_some() {
-                    used_enum_names.insert(col_info.udt_name.clone());
+                if let Some((enum_info, _)) = find_column_enum(col_info, &all_enums) {
+                    used_enum_names.insert(enum_info.name.clone());
                 }
                 let key = (table.name.clone(), col_info.name.clone());
                 if let Some(class_name) = synthetic_enum_cols.get(&key) {
@@ -123,16 +178,36 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
             }
         }
 
-        if is_association_table(table) {
+        if renders_as_association(table, options) {
+            let var_name = table_variable_name(schema, table, options);
             let block = generate_association_table(
                 table,
+                &var_name,
                 &mut imports,
                 options,
                 schema.dialect,
                 metadata_ref,
             );
-            blocks.push((table_to_variable_name(&table.name), block));
+            schemas.insert(var_name.clone(), table.schema.clone());
+            blocks.push((var_name, block));
         } else if has_primary_key(&table.constraints) {
+            // JSON columns whose sampled values share one object shape get a
+            // TypedDict annotation (see `sample::infer_json_fields`).
+            for col_info in &table.columns {
+                if let Some(fields) = col_info
+                    .sample
+                    .as_ref()
+                    .and_then(|s| s.json_fields.as_ref())
+                {
+                    if fields.values().any(|ty| ty.starts_with("Optional[")) {
+                        needs_optional = true;
+                    }
+                    typed_dicts.push(generate_typed_dict(
+                        &typed_dict_name(schema, table, &col_info.name, options),
+                        fields,
+                    ));
+                }
+            }
             let (block, meta) = generate_class(
                 table,
                 &mut imports,
@@ -141,6 +216,9 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
                 schema,
                 &all_enums,
                 &synthetic_enum_cols,
+                &order,
+                convention.as_ref(),
+                mixin.as_ref(),
             );
             if meta.needs_optional {
                 needs_optional = true;
@@ -157,18 +235,26 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
             // Module name matches the historical text-splitter output:
             // snake_case of the generated class name.
             use heck::ToSnakeCase;
-            blocks.push((table_to_class_name(&table.name).to_snake_case(), block));
+            let module = table_class_name(schema, table, options).to_snake_case();
+            links.insert(module.clone(), meta.links);
+            schemas.insert(module.clone(), table.schema.clone());
+            blocks.push((module, block));
         } else {
+            let var_name = table_variable_name(schema, table, options);
             let block = generate_table_fallback(
                 table,
+                &var_name,
                 &mut imports,
                 options,
-                schema.dialect,
+                schema,
                 metadata_ref,
                 &all_enums,
                 &synthetic_enum_cols,
+                &order,
+                convention.as_ref(),
             );
-            blocks.push((table_to_variable_name(&table.name), block));
+            schemas.insert(var_name.clone(), table.schema.clone());
+            blocks.push((var_name, block));
         }
     }
 
@@ -188,6 +274,12 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
     if needs_optional {
         imports.add("typing", "Optional");
     }
+    if options.future_annotations {
+        imports.add("__future__", "annotations");
+    }
+    if !typed_dicts.is_empty() {
+        imports.add("typing", "TypedDict");
+    }
     if needs_datetime {
         imports.add_bare("datetime");
     }
@@ -197,6 +289,10 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
     if needs_uuid {
         imports.add_bare("uuid");
     }
+    let sequences = used_sequences(schema, order.tables_then_views());
+    if !sequences.is_empty() {
+        imports.add("sqlalchemy", "Sequence");
+    }
 
     let mut prelude = imports.render();
 
@@ -205,16 +301,55 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
         prelude.push_str(&generate_enum_class(ei));
     }
 
-    if has_any_pk {
-        prelude.push_str("\n\nclass Base(DeclarativeBase):\n    pass");
-    } else {
-        prelude.push_str("\n\nmetadata = MetaData()");
+    for typed_dict in &typed_dicts {
+        prelude.push_str("\n\n");
+        prelude.push_str(typed_dict);
+    }
+
+    // A `--base-class` Base is imported above instead of declared.
+    if !has_any_pk {
+        match &convention {
+            Some(convention) => {
+                prelude.push_str(&format!("\n\nmetadata = {}", convention.metadata_call("")))
+            }
+            None => prelude.push_str("\n\nmetadata = MetaData()"),
+        }
+    } else if options.base_class.is_none() {
+        let parents = if options.asyncio {
+            "AsyncAttrs, DeclarativeBase"
+        } else {
+            "DeclarativeBase"
+        };
+        let body = match &convention {
+            Some(convention) => format!("metadata = {}", convention.metadata_call("    ")),
+            None => "pass".to_string(),
+        };
+        prelude.push_str(&format!(
+            "\n\nclass {}({parents}):\n    {body}",
+            options.base_name()
+        ));
+    }
+
+    if let Some(mixin) = &mixin {
+        prelude.push_str("\n\n\n");
+        prelude.push_str(&mixin.render());
+    }
+
+    if !sequences.is_empty() {
+        let definitions: Vec<String> = sequences
+            .iter()
+            .map(|sequence| sequence_definition(schema, sequence, metadata_ref))
+            .collect();
+        prelude.push_str("\n\n\n");
+        prelude.push_str(&definitions.join("\n"));
     }
 
     PythonOutput {
         prelude,
         models: blocks,
         separator: "\n\n\n",
+        links,
+        schemas,
     }
 }
 
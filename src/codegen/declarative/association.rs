@@ -1,25 +1,28 @@
 use crate::cli::GeneratorOptions;
 use crate::codegen::imports::ImportCollector;
+use crate::codegen::map_column_type_with;
 use crate::codegen::relationships::find_inline_fk;
+use crate::codegen::{fk_target, format_fk_options, format_python_string_literal};
 use crate::dialect::Dialect;
-use crate::naming::table_to_variable_name;
 use crate::schema::TableInfo;
-use crate::typemap::{map_column_type, map_column_type_dialect};
 
 /// Generate a Table() for M2M association tables.
 /// Columns use ForeignKey() inline (not ForeignKeyConstraint).
 pub(super) fn generate_association_table(
     table: &TableInfo,
+    var_name: &str,
     imports: &mut ImportCollector,
     options: &GeneratorOptions,
     dialect: Dialect,
     metadata_ref: &str,
 ) -> String {
-    let var_name = table_to_variable_name(&table.name);
     let mut lines: Vec<String> = Vec::new();
 
     lines.push(format!("{var_name} = Table("));
-    lines.push(format!("    '{}', {metadata_ref},", table.name));
+    lines.push(format!(
+        "    {}, {metadata_ref},",
+        format_python_string_literal(&table.name)
+    ));
 
     let mut body_items: Vec<String> = Vec::new();
 
@@ -28,32 +31,30 @@ pub(super) fn generate_association_table(
         if let Some(fk_constraint) = fk {
             if let Some(ref fk_info) = fk_constraint.foreign_key {
                 imports.add("sqlalchemy", "ForeignKey");
-                let target = if fk_info.ref_schema != dialect.default_schema() {
-                    format!(
-                        "{}.{}.{}",
-                        fk_info.ref_schema, fk_info.ref_table, fk_info.ref_columns[0]
-                    )
-                } else {
-                    format!("{}.{}", fk_info.ref_table, fk_info.ref_columns[0])
-                };
+                let target = fk_target(fk_info, &fk_info.ref_columns[0], dialect);
                 body_items.push(format!(
-                    "Column('{}', ForeignKey('{}'))",
-                    col_info.name, target
+                    "Column({}, ForeignKey({}{}))",
+                    format_python_string_literal(&col_info.name),
+                    format_python_string_literal(&target),
+                    format_fk_options(fk_info, false)
                 ));
             }
         } else {
-            let mapped = if options.keep_dialect_types {
-                map_column_type_dialect(col_info, dialect)
-            } else {
-                map_column_type(col_info, dialect)
-            };
+            let mapped = map_column_type_with(col_info, dialect, options);
             imports.add(&mapped.import_module, &mapped.import_name);
-            body_items.push(format!("Column('{}', {})", col_info.name, mapped.sa_type));
+            body_items.push(format!(
+                "Column({}, {})",
+                format_python_string_literal(&col_info.name),
+                mapped.sa_type
+            ));
         }
     }
 
     if table.schema != dialect.default_schema() {
-        body_items.push(format!("schema='{}'", table.schema));
+        body_items.push(format!(
+            "schema={}",
+            format_python_string_literal(&table.schema)
+        ));
     }
 
     let last = body_items.len().saturating_sub(1);
@@ -1,22 +1,94 @@
+use std::collections::HashSet;
+
+use crate::cli::GeneratorOptions;
+use crate::codegen::is_serial_default;
+use crate::dialect::Dialect;
 use crate::naming::column_to_attr_name;
-use crate::schema::ColumnInfo;
+use crate::schema::TableInfo;
+use crate::typemap::map_column_type;
+
+/// Names a declarative class body may reference after some of its own
+/// attributes are bound. A column attribute with one of these names would
+/// shadow the import for every later line of the class.
+const CLASS_BODY_NAMES: &[&str] = &[
+    "Mapped",
+    "mapped_column",
+    "relationship",
+    "Optional",
+    "ForeignKey",
+    "Identity",
+    "Computed",
+    "datetime",
+    "decimal",
+    "uuid",
+    "enum",
+];
 
-/// Pre-compute sanitized attribute names for all columns, resolving collisions.
-/// When two columns sanitize to the same name, the later one gets a trailing `_`.
-pub(super) fn resolve_attr_names(columns: &[ColumnInfo]) -> Vec<String> {
-    let mut names: Vec<String> = columns
+/// Mapped attribute names for a model class, in column order. Column names
+/// are taken from `--rename-map` or sanitized by `column_to_attr_name`; names that would shadow something
+/// the class body uses (`relationship`, `datetime`, the column type classes,
+/// and `text` whenever a server default imports `sqlalchemy.text`) get a
+/// trailing `_`. On a collision, a column that keeps its own name or a
+/// `--rename-map` name wins; the others take trailing `_`s in column order.
+pub(crate) fn class_attr_names(
+    table: &TableInfo,
+    dialect: Dialect,
+    options: &GeneratorOptions,
+) -> Vec<String> {
+    let will_import_text = table.columns.iter().any(|c| {
+        c.column_default
+            .as_ref()
+            .is_some_and(|d| !is_serial_default(d, dialect))
+    });
+    let type_names: Vec<String> = table
+        .columns
         .iter()
-        .map(|c| column_to_attr_name(&c.name))
+        .map(|c| map_column_type(c, dialect).import_name)
         .collect();
+    let shadows = |name: &str| {
+        CLASS_BODY_NAMES.contains(&name)
+            || type_names.iter().any(|t| t == name)
+            || (will_import_text && name == "text")
+    };
 
-    // Resolve collisions: if name[i] == name[j] where j > i, append _ to name[j].
-    for i in 0..names.len() {
-        for j in (i + 1)..names.len() {
-            if names[j] == names[i] {
-                names[j].push('_');
+    let sanitized: Vec<(String, bool)> = table
+        .columns
+        .iter()
+        .map(|c| {
+            let (mut name, explicit) = match options.rename_map.attr_name(table, c) {
+                Some(renamed) => (renamed.to_string(), true),
+                None => (column_to_attr_name(&c.name), false),
+            };
+            if shadows(&name) {
+                name.push('_');
             }
+            let kept = explicit || name == c.name;
+            (name, kept)
+        })
+        .collect();
+    dedupe(sanitized)
+}
+
+/// Make every name unique by appending `_`. Names flagged as kept claim
+/// theirs first, so a column whose name is already a valid attribute never
+/// yields it to a sanitized one; later columns never clobber earlier ones.
+fn dedupe(names: Vec<(String, bool)>) -> Vec<String> {
+    let mut taken: HashSet<String> = HashSet::new();
+    let mut claim = |mut name: String| {
+        while taken.contains(&name) {
+            name.push('_');
+        }
+        taken.insert(name.clone());
+        name
+    };
+    let mut result: Vec<Option<String>> = names
+        .iter()
+        .map(|(name, kept)| kept.then(|| claim(name.clone())))
+        .collect();
+    for (slot, (name, _)) in result.iter_mut().zip(names) {
+        if slot.is_none() {
+            *slot = Some(claim(name));
         }
     }
-
-    names
+    result.into_iter().flatten().collect()
 }
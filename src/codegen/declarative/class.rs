@@ -1,20 +1,26 @@
-use super::attrs::resolve_attr_names;
+use super::attrs::class_attr_names;
+use super::mixins::{is_timestamp_column, TimestampMixin, MIXIN_NAME};
+use super::repr::generate_repr;
 use super::table_args::build_table_args;
 use crate::cli::GeneratorOptions;
 use crate::codegen::imports::ImportCollector;
+use crate::codegen::map_column_type_with;
+use crate::codegen::python::ModelLinks;
 use crate::codegen::relationships::{
     find_inheritance_parent, find_inline_fk, generate_child_relationships,
     generate_m2m_relationships, generate_parent_relationships, has_unique_constraint,
-    render_relationship,
+    render_relationship, RelationshipInfo,
 };
 use crate::codegen::{
-    enum_class_name, find_enum_for_column, format_python_string_literal, format_server_default,
-    is_primary_key_column, is_serial_default,
+    column_sequence, enum_class_name, find_column_enum, fk_target, format_fk_options,
+    format_python_string_literal, is_primary_key_column, is_serial_default, object_comments,
+    period_column_args, server_default_arg, table_note, NamingConvention, TableOrder,
 };
 use crate::dialect::Dialect;
-use crate::naming::table_to_class_name;
+use crate::naming::{sequence_variable_name, table_class_name, typed_dict_name};
 use crate::schema::{EnumInfo, IntrospectedSchema, TableInfo};
-use crate::typemap::{map_column_type, map_column_type_dialect};
+use crate::typemap::array_type;
+use heck::ToSnakeCase;
 use std::collections::{HashMap, HashSet};
 
 pub(super) struct ClassMeta {
@@ -22,8 +28,47 @@ pub(super) struct ClassMeta {
     pub(super) needs_datetime: bool,
     pub(super) needs_decimal: bool,
     pub(super) needs_uuid: bool,
+    pub(super) links: ModelLinks,
+    /// Rendered audit timestamp columns a `TimestampMixin` could take over,
+    /// as column name and line. Empty for joined-inheritance subclasses.
+    pub(super) timestamp_columns: Vec<(String, String)>,
 }
 
+/// The class docstring: the table comment, the table note, then any index
+/// and constraint comments, one per line.
+fn class_docstring(table: &TableInfo, options: &GeneratorOptions) -> Option<String> {
+    let escape = |text: &str| text.replace('\\', "\\\\").replace("\"\"\"", "\\\"\"\"");
+    let comments: Vec<String> = object_comments(table, options)
+        .iter()
+        .map(|line| escape(line))
+        .collect();
+    let mut paragraphs: Vec<String> = table
+        .comment
+        .as_deref()
+        .filter(|comment| options.table_comments() && !comment.trim().is_empty())
+        .map(|comment| {
+            let lines: Vec<&str> = comment.trim().lines().map(str::trim_end).collect();
+            escape(&lines.join("\n    "))
+        })
+        .into_iter()
+        .collect();
+    paragraphs.extend(table_note(table));
+    if !comments.is_empty() {
+        paragraphs.push(comments.join("\n    "));
+    }
+    if paragraphs.is_empty() {
+        return None;
+    }
+    let body = paragraphs.join("\n\n    ");
+    // A closing quote next to the delimiter would end the string early.
+    Some(if body.contains('\n') || body.ends_with('"') {
+        format!("\"\"\"{body}\n    \"\"\"")
+    } else {
+        format!("\"\"\"{body}\"\"\"")
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 pub(super) fn generate_class(
     table: &TableInfo,
     imports: &mut ImportCollector,
@@ -32,28 +77,45 @@ pub(super) fn generate_class(
     schema: &IntrospectedSchema,
     all_enums: &[EnumInfo],
     synthetic_enum_cols: &HashMap<(String, String), String>,
+    order: &TableOrder,
+    convention: Option<&NamingConvention>,
+    mixin: Option<&TimestampMixin>,
 ) -> (String, ClassMeta) {
-    let class_name = table_to_class_name(&table.name);
+    let class_name = table_class_name(schema, table, options);
     let mut lines: Vec<String> = Vec::new();
     let mut meta = ClassMeta {
         needs_optional: false,
         needs_datetime: false,
         needs_decimal: false,
         needs_uuid: false,
+        links: ModelLinks::default(),
+        timestamp_columns: Vec::new(),
     };
 
     // Check for joined table inheritance.
-    let parent_table_name = find_inheritance_parent(table, schema);
-    let base_class = if let Some(parent_name) = parent_table_name {
-        table_to_class_name(parent_name)
+    let (base_class, inherits) = if let Some(parent) = find_inheritance_parent(table, schema) {
+        let parent_class = table_class_name(schema, parent, options);
+        meta.links
+            .runtime
+            .insert((parent_class.to_snake_case(), parent_class.clone()));
+        (parent_class, true)
     } else {
-        "Base".to_string()
+        (options.base_name().to_string(), false)
     };
 
-    lines.push(format!("class {class_name}({base_class}):"));
-    lines.push(format!("    __tablename__ = '{}'", table.name));
+    lines.extend(crate::codegen::skipped_warnings(table));
+    // The bases are filled in once the columns show whether the mixin fits.
+    let header = lines.len();
+    lines.push(String::new());
+    if let Some(docstring) = class_docstring(table, options) {
+        lines.push(format!("    {docstring}\n"));
+    }
+    lines.push(format!(
+        "    __tablename__ = {}",
+        format_python_string_literal(&table.name)
+    ));
 
-    let table_args = build_table_args(table, imports, options, dialect);
+    let table_args = build_table_args(table, imports, options, dialect, order, convention);
     if let Some(args_str) = table_args {
         if args_str.starts_with('{') {
             lines.push(format!("    __table_args__ = {args_str}"));
@@ -67,24 +129,12 @@ pub(super) fn generate_class(
     struct ColLine {
         is_pk: bool,
         is_nullable: bool,
+        timestamp: bool,
         line: String,
     }
     let mut col_lines: Vec<ColLine> = Vec::new();
 
-    let will_import_text = table.columns.iter().any(|c| {
-        c.column_default
-            .as_ref()
-            .is_some_and(|d| !is_serial_default(d, dialect))
-    });
-
-    let mut attr_names = resolve_attr_names(&table.columns);
-    if will_import_text {
-        for name in &mut attr_names {
-            if name == "text" {
-                name.push('_');
-            }
-        }
-    }
+    let attr_names = class_attr_names(table, dialect, options);
 
     for (idx, col) in table.columns.iter().enumerate() {
         let attr_name = &attr_names[idx];
@@ -94,14 +144,15 @@ pub(super) fn generate_class(
         let enum_info = if synthetic_class.is_some() {
             None
         } else {
-            find_enum_for_column(&col.udt_name, all_enums)
+            find_column_enum(col, all_enums)
         };
+        let mut rendered = None;
         let (sa_type_str, python_type) = if let Some(cls) = synthetic_class {
             let sa = format!(
                 "Enum({cls}, values_callable=lambda cls: [member.value for member in cls])"
             );
             (sa, cls.clone())
-        } else if let Some(ei) = enum_info {
+        } else if let Some((ei, is_array)) = enum_info {
             let cls = enum_class_name(&ei.name);
             let mut enum_parts = vec![
                 cls.clone(),
@@ -114,32 +165,44 @@ pub(super) fn generate_class(
                 }
             }
             let sa = format!("Enum({})", enum_parts.join(", "));
-            (sa, cls)
-        } else {
-            let mapped = if options.keep_dialect_types {
-                map_column_type_dialect(col, dialect)
+            if is_array {
+                imports.add("sqlalchemy", "ARRAY");
+                (
+                    array_type(&sa, col.array_dimensions),
+                    format!("list[{cls}]"),
+                )
             } else {
-                map_column_type(col, dialect)
-            };
+                (sa, cls)
+            }
+        } else {
+            let mapped = map_column_type_with(col, dialect, options);
             imports.add(&mapped.import_module, &mapped.import_name);
-            if let Some((ref elem_mod, ref elem_name)) = mapped.element_import {
+            for (elem_mod, elem_name) in &mapped.extra_imports {
                 imports.add(elem_mod, elem_name);
             }
-            if mapped.python_type.starts_with("datetime.") {
+            if mapped.python_type.contains("datetime.") {
                 meta.needs_datetime = true;
             }
-            if mapped.python_type.starts_with("decimal.") {
+            if mapped.python_type.contains("decimal.") {
                 meta.needs_decimal = true;
             }
-            if mapped.python_type.starts_with("uuid.") {
+            if mapped.python_type.contains("uuid.") {
                 meta.needs_uuid = true;
             }
-            (mapped.sa_type.clone(), mapped.python_type.clone())
+            let python_type = match col.sample.as_ref().and_then(|s| s.json_fields.as_ref()) {
+                Some(_) => typed_dict_name(schema, table, &col.name, options),
+                None => mapped.python_type.clone(),
+            };
+            let sa_type = mapped.sa_type.clone();
+            rendered = Some(mapped);
+            (sa_type, python_type)
         };
 
         let is_pk = is_primary_key_column(&col.name, &table.constraints);
 
-        let type_annotation = if col.is_nullable {
+        let type_annotation = if col.is_nullable && options.pep604 {
+            format!("{python_type} | None")
+        } else if col.is_nullable {
             meta.needs_optional = true;
             format!("Optional[{python_type}]")
         } else {
@@ -160,12 +223,10 @@ pub(super) fn generate_class(
         if let Some(fk_constraint) = inline_fk {
             if let Some(ref fk) = fk_constraint.foreign_key {
                 imports.add("sqlalchemy", "ForeignKey");
-                let target = if fk.ref_schema != dialect.default_schema() {
-                    format!("'{}.{}.{}'", fk.ref_schema, fk.ref_table, fk.ref_columns[0])
-                } else {
-                    format!("'{}.{}'", fk.ref_table, fk.ref_columns[0])
-                };
-                mc_args.push(format!("ForeignKey({target})"));
+                let target =
+                    format_python_string_literal(&fk_target(fk, &fk.ref_columns[0], dialect));
+                let fk_opts = format_fk_options(fk, order.defers(table, &fk_constraint.name));
+                mc_args.push(format!("ForeignKey({target}{fk_opts})"));
             }
             if has_unique_constraint(&col.name, &table.constraints) {
                 mc_args.push("unique=True".to_string());
@@ -174,22 +235,18 @@ pub(super) fn generate_class(
             mc_args.push(sa_type_str.clone());
         }
 
-        if let Some(ref identity) = col.identity {
+        if let Some(identity) = crate::codegen::format_identity(col, dialect) {
             imports.add("sqlalchemy", "Identity");
-            match dialect {
-                Dialect::Postgres => {
-                    mc_args.push(format!(
-                        "Identity(start={}, increment={}, minvalue={}, maxvalue={}, cycle=False, cache={})",
-                        identity.start, identity.increment, identity.min_value, identity.max_value, identity.cache
-                    ));
-                }
-                Dialect::Mssql | Dialect::Mysql | Dialect::Sqlite => {
-                    mc_args.push(format!(
-                        "Identity(start={}, increment={})",
-                        identity.start, identity.increment
-                    ));
-                }
-            }
+            mc_args.push(identity);
+        }
+
+        if let Some(ref computed) = col.computed {
+            imports.add("sqlalchemy", "Computed");
+            mc_args.push(crate::codegen::format_computed(computed));
+        }
+
+        if let Some(sequence) = column_sequence(schema, table, col) {
+            mc_args.push(sequence_variable_name(schema, sequence));
         }
 
         if !col.is_nullable && !is_pk {
@@ -208,27 +265,53 @@ pub(super) fn generate_class(
 
         if let Some(ref default) = col.column_default {
             if !is_serial_default(default, dialect) {
-                imports.add("sqlalchemy", "text");
-                let formatted = format_server_default(default, dialect);
+                let formatted = server_default_arg(col, default, dialect, options, imports);
                 mc_args.push(format!("server_default={formatted}"));
             }
         }
+        if let Some(args) = period_column_args(col, imports) {
+            mc_args.push(args.to_string());
+        }
 
-        if !options.nocomments {
+        if options.column_comments() {
             if let Some(ref comment) = col.comment {
                 mc_args.push(format!("comment={}", format_python_string_literal(comment)));
             }
         }
 
+        if let Some(info) = crate::codegen::format_column_info(col, options) {
+            mc_args.push(info);
+        }
+
         let mc_str = mc_args.join(", ");
-        let line = format!("    {attr_name}: Mapped[{type_annotation}] = mapped_column({mc_str})");
+        let mut line =
+            format!("    {attr_name}: Mapped[{type_annotation}] = mapped_column({mc_str})");
+        if let Some(advisory) =
+            crate::codegen::column_comment(col, dialect, rendered.as_ref(), "    ")
+        {
+            line = format!("{advisory}\n{line}");
+        }
+        let timestamp = !inherits && inline_fk.is_none() && is_timestamp_column(&col.name);
+        if timestamp {
+            meta.timestamp_columns
+                .push((col.name.clone(), line.clone()));
+        }
         col_lines.push(ColLine {
             is_pk,
             is_nullable: col.is_nullable,
+            timestamp,
             line,
         });
     }
 
+    let uses_mixin = mixin.is_some_and(|mixin| mixin.fits(&meta.timestamp_columns));
+    if uses_mixin {
+        col_lines.retain(|c| !c.timestamp);
+        lines[header] = format!("class {class_name}({MIXIN_NAME}, {base_class}):");
+    } else {
+        lines[header] = format!("class {class_name}({base_class}):");
+    }
+
     let pk_cols: Vec<&ColLine> = col_lines.iter().filter(|c| c.is_pk).collect();
     let non_nullable: Vec<&ColLine> = col_lines
         .iter()
@@ -247,15 +330,100 @@ pub(super) fn generate_class(
         lines.push(col_line.line.clone());
     }
 
+    let ClassRelationships {
+        parent: mut parent_rels,
+        child: mut child_rels,
+        m2m: mut m2m_rels,
+    } = class_relationships(table, schema, options, &attr_names);
+    // Each relationship back-populates the one following the same foreign
+    // key from the other end, under whatever name it ended up with, or
+    // nothing when the target class has no such relationship.
+    let mut target_rels: HashMap<String, Vec<RelationshipInfo>> = HashMap::new();
+    for rel in parent_rels
+        .iter_mut()
+        .chain(child_rels.iter_mut())
+        .chain(m2m_rels.iter_mut())
+        .filter(|rel| !rel.back_populates.is_empty())
+    {
+        let rels = target_rels
+            .entry(rel.target_class.clone())
+            .or_insert_with(|| {
+                schema
+                    .tables
+                    .iter()
+                    .find(|t| table_class_name(schema, t, options) == rel.target_class)
+                    .map(|target| {
+                        let target_attrs = class_attr_names(target, dialect, options);
+                        let rels = class_relationships(target, schema, options, &target_attrs);
+                        [rels.parent, rels.child, rels.m2m].concat()
+                    })
+                    .unwrap_or_default()
+            });
+        let reverse = rel.path.reverse();
+        rel.back_populates = rels
+            .iter()
+            .find(|other| other.path == reverse)
+            .map(|other| other.attr_name.clone())
+            .unwrap_or_default();
+    }
+
+    let all_rels_empty = parent_rels.is_empty() && child_rels.is_empty() && m2m_rels.is_empty();
+    if !all_rels_empty {
+        imports.add("sqlalchemy.orm", "relationship");
+        lines.push(String::new());
+
+        for rel in parent_rels
+            .iter()
+            .chain(m2m_rels.iter())
+            .chain(child_rels.iter())
+        {
+            if rel.is_nullable && !rel.is_collection && !options.pep604 {
+                meta.needs_optional = true;
+            }
+            meta.links
+                .typing
+                .insert((rel.target_class.to_snake_case(), rel.target_class.clone()));
+            lines.push(render_relationship(rel, options));
+        }
+    }
+
+    if options.repr {
+        lines.push(String::new());
+        lines.push(generate_repr(table, &class_name, &attr_names));
+    }
+
+    (lines.join("\n"), meta)
+}
+
+/// The relationships of a class, named apart from its column attributes
+/// and from each other.
+struct ClassRelationships {
+    parent: Vec<RelationshipInfo>,
+    child: Vec<RelationshipInfo>,
+    m2m: Vec<RelationshipInfo>,
+}
+
+/// The parent, child and many-to-many relationships of `table`'s class. A
+/// name taken by a column attribute (`attr_names`) or an earlier
+/// relationship gets a trailing `_`.
+fn class_relationships(
+    table: &TableInfo,
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
+    attr_names: &[String],
+) -> ClassRelationships {
     let (mut parent_rels, mut child_rels, mut m2m_rels) = if !options.noconstraints {
         let parent = if !options.nobidi {
-            generate_parent_relationships(table, schema, options.noidsuffix)
+            generate_parent_relationships(table, schema, options)
+        } else {
+            vec![]
+        };
+        let child = generate_child_relationships(table, schema, options);
+        let m2m = if !options.noassociations {
+            generate_m2m_relationships(table, schema, options)
         } else {
             vec![]
         };
-        let child = generate_child_relationships(table, schema, options.noidsuffix);
-        let m2m =
-            generate_m2m_relationships(table, schema, dialect.default_schema(), options.noidsuffix);
         (parent, child, m2m)
     } else {
         (vec![], vec![], vec![])
@@ -272,53 +440,23 @@ pub(super) fn generate_class(
 
     let col_attr_names: HashSet<&str> = attr_names.iter().map(|s| s.as_str()).collect();
     let mut rel_attr_names: HashSet<String> = HashSet::new();
-    let mut renames: HashMap<String, String> = HashMap::new();
 
     for rel in parent_rels
         .iter_mut()
         .chain(child_rels.iter_mut())
         .chain(m2m_rels.iter_mut())
     {
-        let original = rel.attr_name.clone();
         while col_attr_names.contains(rel.attr_name.as_str())
             || rel_attr_names.contains(&rel.attr_name)
         {
             rel.attr_name.push('_');
         }
-        if rel.attr_name != original {
-            renames.insert(original, rel.attr_name.clone());
-        }
         rel_attr_names.insert(rel.attr_name.clone());
     }
 
-    if !renames.is_empty() {
-        for rel in parent_rels
-            .iter_mut()
-            .chain(child_rels.iter_mut())
-            .chain(m2m_rels.iter_mut())
-        {
-            if let Some(new_name) = renames.get(&rel.back_populates) {
-                rel.back_populates = new_name.clone();
-            }
-        }
+    ClassRelationships {
+        parent: parent_rels,
+        child: child_rels,
+        m2m: m2m_rels,
     }
-
-    let all_rels_empty = parent_rels.is_empty() && child_rels.is_empty() && m2m_rels.is_empty();
-    if !all_rels_empty {
-        imports.add("sqlalchemy.orm", "relationship");
-        lines.push(String::new());
-
-        for rel in parent_rels
-            .iter()
-            .chain(m2m_rels.iter())
-            .chain(child_rels.iter())
-        {
-            if rel.is_nullable && !rel.is_collection {
-                meta.needs_optional = true;
-            }
-            lines.push(render_relationship(rel));
-        }
-    }
-
-    (lines.join("\n"), meta)
 }
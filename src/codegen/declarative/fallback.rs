@@ -1,42 +1,61 @@
 use crate::cli::GeneratorOptions;
 use crate::codegen::imports::ImportCollector;
+use crate::codegen::map_column_type_with;
 use crate::codegen::{
-    enum_class_name, escape_python_string, find_enum_for_column, format_fk_options,
-    format_index_kwargs, format_python_string_literal, format_server_default, is_serial_default,
-    is_unique_constraint_index, quote_constraint_columns,
+    column_sequence, enum_class_name, exclude_constraint, find_column_enum, fk_target,
+    format_fk_options, format_index_kwargs, format_python_string_literal, index_needs_text,
+    is_serial_default, is_unique_constraint_index, name_arg, nulls_not_distinct_kwarg,
+    object_comments, period_column_args, quote_constraint_columns, quote_index_keys,
+    server_default_arg, skipped_warnings, table_note, view_definition_comment, NamingConvention,
+    TableOrder,
 };
-use crate::dialect::Dialect;
-use crate::naming::table_to_variable_name;
-use crate::schema::{ConstraintType, EnumInfo, TableInfo};
-use crate::typemap::{map_column_type, map_column_type_dialect};
+use crate::naming::sequence_variable_name;
+use crate::schema::{ConstraintType, EnumInfo, IntrospectedSchema, TableInfo};
+use crate::typemap::array_type;
 use std::collections::HashMap;
 
 /// Generate a Table() assignment for a table without a primary key.
 /// Uses the provided `metadata_ref` (e.g. `Base.metadata` or standalone `metadata`).
+#[allow(clippy::too_many_arguments)]
 pub(super) fn generate_table_fallback(
     table: &TableInfo,
+    var_name: &str,
     imports: &mut ImportCollector,
     options: &GeneratorOptions,
-    dialect: Dialect,
+    schema: &IntrospectedSchema,
     metadata_ref: &str,
     enums: &[EnumInfo],
     synthetic_enum_cols: &HashMap<(String, String), String>,
+    order: &TableOrder,
+    convention: Option<&NamingConvention>,
 ) -> String {
-    let var_name = table_to_variable_name(&table.name);
+    let dialect = schema.dialect;
     let mut lines: Vec<String> = Vec::new();
 
+    lines.extend(skipped_warnings(table));
+    lines.extend(view_definition_comment(table));
+    lines.extend(table_note(table).map(|note| format!("# {note}")));
+    lines.extend(
+        object_comments(table, options)
+            .iter()
+            .map(|line| format!("# {line}")),
+    );
     lines.push(format!("{var_name} = Table("));
-    lines.push(format!("    '{}', {metadata_ref},", table.name));
+    lines.push(format!(
+        "    {}, {metadata_ref},",
+        format_python_string_literal(&table.name)
+    ));
 
     let mut body_items: Vec<String> = Vec::new();
 
     for col in &table.columns {
         let enum_key = (table.name.clone(), col.name.clone());
+        let mut rendered = None;
         let sa_type = if let Some(class_name) = synthetic_enum_cols.get(&enum_key) {
             format!(
                 "Enum({class_name}, values_callable=lambda cls: [member.value for member in cls])"
             )
-        } else if let Some(enum_info) = find_enum_for_column(&col.udt_name, enums) {
+        } else if let Some((enum_info, is_array)) = find_column_enum(col, enums) {
             let mut enum_parts = vec![
                 enum_class_name(&enum_info.name),
                 "values_callable=lambda cls: [member.value for member in cls]".to_string(),
@@ -47,40 +66,40 @@ pub(super) fn generate_table_fallback(
                     enum_parts.push(format!("schema={}", format_python_string_literal(schema)));
                 }
             }
-            format!("Enum({})", enum_parts.join(", "))
-        } else {
-            let mapped = if options.keep_dialect_types {
-                map_column_type_dialect(col, dialect)
+            let enum_type = format!("Enum({})", enum_parts.join(", "));
+            if is_array {
+                imports.add("sqlalchemy", "ARRAY");
+                array_type(&enum_type, col.array_dimensions)
             } else {
-                map_column_type(col, dialect)
-            };
+                enum_type
+            }
+        } else {
+            let mapped = map_column_type_with(col, dialect, options);
             imports.add(&mapped.import_module, &mapped.import_name);
-            if let Some((ref elem_mod, ref elem_name)) = mapped.element_import {
+            for (elem_mod, elem_name) in &mapped.extra_imports {
                 imports.add(elem_mod, elem_name);
             }
-            mapped.sa_type
+            let sa_type = mapped.sa_type.clone();
+            rendered = Some(mapped);
+            sa_type
         };
 
         let mut col_args: Vec<String> = Vec::new();
-        col_args.push(format!("'{}'", col.name));
+        col_args.push(format_python_string_literal(&col.name));
         col_args.push(sa_type);
 
-        if let Some(ref identity) = col.identity {
+        if let Some(identity) = crate::codegen::format_identity(col, dialect) {
             imports.add("sqlalchemy", "Identity");
-            match dialect {
-                Dialect::Postgres => {
-                    col_args.push(format!(
-                        "Identity(start={}, increment={}, minvalue={}, maxvalue={}, cycle=False, cache={})",
-                        identity.start, identity.increment, identity.min_value, identity.max_value, identity.cache
-                    ));
-                }
-                Dialect::Mssql | Dialect::Mysql | Dialect::Sqlite => {
-                    col_args.push(format!(
-                        "Identity(start={}, increment={})",
-                        identity.start, identity.increment
-                    ));
-                }
-            }
+            col_args.push(identity);
+        }
+
+        if let Some(ref computed) = col.computed {
+            imports.add("sqlalchemy", "Computed");
+            col_args.push(crate::codegen::format_computed(computed));
+        }
+
+        if let Some(sequence) = column_sequence(schema, table, col) {
+            col_args.push(sequence_variable_name(schema, sequence));
         }
 
         if !col.is_nullable {
@@ -89,19 +108,29 @@ pub(super) fn generate_table_fallback(
 
         if let Some(ref default) = col.column_default {
             if !is_serial_default(default, dialect) {
-                imports.add("sqlalchemy", "text");
-                let formatted = format_server_default(default, dialect);
+                let formatted = server_default_arg(col, default, dialect, options, imports);
                 col_args.push(format!("server_default={formatted}"));
             }
         }
+        if let Some(args) = period_column_args(col, imports) {
+            col_args.push(args.to_string());
+        }
 
-        if !options.nocomments {
+        if options.column_comments() {
             if let Some(ref comment) = col.comment {
-                col_args.push(format!("comment='{}'", escape_python_string(comment)));
+                col_args.push(format!("comment={}", format_python_string_literal(comment)));
             }
         }
 
-        body_items.push(format!("Column({})", col_args.join(", ")));
+        if let Some(info) = crate::codegen::format_column_info(col, options) {
+            col_args.push(info);
+        }
+
+        let column = format!("Column({})", col_args.join(", "));
+        match crate::codegen::column_comment(col, dialect, rendered.as_ref(), "    ") {
+            Some(advisory) => body_items.push(format!("{}\n    {column}", advisory.trim_start())),
+            None => body_items.push(column),
+        }
     }
 
     if !options.noconstraints {
@@ -112,16 +141,16 @@ pub(super) fn generate_table_fallback(
                     let local_cols: Vec<String> = constraint
                         .columns
                         .iter()
-                        .map(|c| format!("'{c}'"))
+                        .map(|c| format_python_string_literal(c))
                         .collect();
                     let ref_cols: Vec<String> = fk
                         .ref_columns
                         .iter()
-                        .map(|c| format!("'{}.{c}'", fk.ref_table))
+                        .map(|c| format_python_string_literal(&fk_target(fk, c, dialect)))
                         .collect();
-                    let fk_opts = format_fk_options(fk);
+                    let fk_opts = format_fk_options(fk, order.defers(table, &constraint.name));
                     let name_part = if !options.nofknames {
-                        format!(", name='{}'", constraint.name)
+                        name_arg(convention, table, constraint)
                     } else {
                         String::new()
                     };
@@ -143,26 +172,36 @@ pub(super) fn generate_table_fallback(
                 imports.add("sqlalchemy", "UniqueConstraint");
                 let cols = quote_constraint_columns(&constraint.columns);
                 body_items.push(format!(
-                    "UniqueConstraint({}, name='{}')",
+                    "UniqueConstraint({}{}{})",
                     cols.join(", "),
-                    constraint.name
+                    name_arg(convention, table, constraint),
+                    nulls_not_distinct_kwarg(constraint.nulls_not_distinct, dialect)
                 ));
             }
         }
+        for constraint in &table.constraints {
+            if let Some(ref exclude) = constraint.exclude {
+                let name = name_arg(convention, table, constraint);
+                body_items.push(exclude_constraint(exclude, &name, imports));
+            }
+        }
     }
 
     if !options.noindexes {
         for index in &table.indexes {
-            if is_unique_constraint_index(index, &table.constraints) {
+            if index.columnstore || is_unique_constraint_index(index, &table.constraints) {
                 continue;
             }
             imports.add("sqlalchemy", "Index");
-            let cols = quote_constraint_columns(&index.columns);
+            if index_needs_text(index) {
+                imports.add("sqlalchemy", "text");
+            }
+            let cols = quote_index_keys(index, dialect);
             let unique_str = if index.is_unique { ", unique=True" } else { "" };
-            let kwargs_str = format_index_kwargs(&index.kwargs);
+            let kwargs_str = format_index_kwargs(index, dialect);
             body_items.push(format!(
-                "Index('{}', {}{}{})",
-                index.name,
+                "Index({}, {}{}{})",
+                format_python_string_literal(&index.name),
                 cols.join(", "),
                 unique_str,
                 kwargs_str
@@ -171,7 +210,10 @@ pub(super) fn generate_table_fallback(
     }
 
     if table.schema != dialect.default_schema() {
-        body_items.push(format!("schema='{}'", table.schema));
+        body_items.push(format!(
+            "schema={}",
+            format_python_string_literal(&table.schema)
+        ));
     }
 
     let last = body_items.len().saturating_sub(1);
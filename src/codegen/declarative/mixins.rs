@@ -0,0 +1,59 @@
+//! `--options mixins`: audit timestamp columns that many classes declare
+//! identically move into a generated `TimestampMixin` the classes inherit.
+
+/// Class name of the generated mixin.
+pub(super) const MIXIN_NAME: &str = "TimestampMixin";
+
+/// Columns a `TimestampMixin` may take over.
+const TIMESTAMP_COLUMNS: [&str; 3] = ["created_at", "updated_at", "deleted_at"];
+
+/// Whether the column named `name` is an audit timestamp column.
+pub(super) fn is_timestamp_column(name: &str) -> bool {
+    TIMESTAMP_COLUMNS
+        .iter()
+        .any(|column| column.eq_ignore_ascii_case(name))
+}
+
+/// The timestamp columns of a mixin, each as its column name and the
+/// rendered `mapped_column()` line.
+#[derive(Debug, PartialEq)]
+pub(super) struct TimestampMixin {
+    pub(super) columns: Vec<(String, String)>,
+}
+
+impl TimestampMixin {
+    /// The column set most classes share, given each class's rendered
+    /// timestamp columns, provided at least two classes share it. A tie
+    /// goes to the set seen first.
+    pub(super) fn detect(classes: &[Vec<(String, String)>]) -> Option<Self> {
+        let mut counts: Vec<(&Vec<(String, String)>, usize)> = Vec::new();
+        for columns in classes.iter().filter(|columns| !columns.is_empty()) {
+            match counts.iter_mut().find(|(seen, _)| *seen == columns) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((columns, 1)),
+            }
+        }
+        let mut best: Option<(&Vec<(String, String)>, usize)> = None;
+        for (columns, count) in counts {
+            if count >= 2 && best.is_none_or(|(_, most)| count > most) {
+                best = Some((columns, count));
+            }
+        }
+        best.map(|(columns, _)| Self {
+            columns: columns.clone(),
+        })
+    }
+
+    /// Whether a class with these rendered timestamp columns inherits the
+    /// mixin: only when they match it exactly.
+    pub(super) fn fits(&self, columns: &[(String, String)]) -> bool {
+        self.columns == columns
+    }
+
+    /// The `class TimestampMixin:` definition.
+    pub(super) fn render(&self) -> String {
+        let mut lines = vec![format!("class {MIXIN_NAME}:")];
+        lines.extend(self.columns.iter().map(|(_, line)| line.clone()));
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,56 @@
+use crate::schema::{ConstraintType, TableInfo};
+
+/// Column names that usually identify a row to a human reading a log.
+const IDENTIFYING_NAMES: &[&str] = &[
+    "name", "title", "email", "username", "code", "slug", "label", "key",
+];
+
+/// Non-key columns shown after the primary key.
+const MAX_IDENTIFYING: usize = 3;
+
+/// `__repr__` for a model class (`--options repr`): the primary key, then
+/// up to three identifying columns — single-column unique constraints and
+/// conventional names like `name` or `email` — in column order.
+pub(super) fn generate_repr(table: &TableInfo, class_name: &str, attr_names: &[String]) -> String {
+    let pk_cols: Vec<&str> = table
+        .constraints
+        .iter()
+        .find(|c| c.constraint_type == ConstraintType::PrimaryKey)
+        .map(|pk| pk.columns.iter().map(String::as_str).collect())
+        .unwrap_or_default();
+    let unique_cols: Vec<&str> = table
+        .constraints
+        .iter()
+        .filter(|c| c.constraint_type == ConstraintType::Unique && c.columns.len() == 1)
+        .map(|c| c.columns[0].as_str())
+        .collect();
+
+    let attr = |col_name: &str| {
+        table
+            .columns
+            .iter()
+            .position(|c| c.name == col_name)
+            .map(|idx| attr_names[idx].as_str())
+    };
+    let mut fields: Vec<&str> = pk_cols.iter().filter_map(|c| attr(c)).collect();
+    fields.extend(
+        table
+            .columns
+            .iter()
+            .zip(attr_names)
+            .filter(|(c, _)| {
+                !pk_cols.contains(&c.name.as_str())
+                    && (unique_cols.contains(&c.name.as_str())
+                        || IDENTIFYING_NAMES.contains(&c.name.to_lowercase().as_str()))
+            })
+            .map(|(_, a)| a.as_str())
+            .take(MAX_IDENTIFYING),
+    );
+
+    let body = fields
+        .iter()
+        .map(|a| format!("{a}={{self.{a}!r}}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("    def __repr__(self) -> str:\n        return f'{class_name}({body})'")
+}
@@ -1,8 +1,10 @@
 use crate::cli::GeneratorOptions;
 use crate::codegen::imports::ImportCollector;
 use crate::codegen::{
-    format_fk_options, format_index_kwargs, format_python_string_literal,
-    is_unique_constraint_index, quote_constraint_columns,
+    exclude_constraint, fk_target, format_fk_options, format_index_kwargs,
+    format_python_string_literal, index_needs_text, is_unique_constraint_index, name_arg,
+    nulls_not_distinct_kwarg, quote_constraint_columns, quote_index_keys, NamingConvention,
+    TableOrder,
 };
 use crate::dialect::Dialect;
 use crate::schema::{ConstraintType, TableInfo};
@@ -12,6 +14,8 @@ pub(super) fn build_table_args(
     imports: &mut ImportCollector,
     options: &GeneratorOptions,
     dialect: Dialect,
+    order: &TableOrder,
+    convention: Option<&NamingConvention>,
 ) -> Option<String> {
     let mut positional_args: Vec<String> = Vec::new();
     let mut kwargs: Vec<String> = Vec::new();
@@ -27,16 +31,16 @@ pub(super) fn build_table_args(
                     let local_cols: Vec<String> = constraint
                         .columns
                         .iter()
-                        .map(|c| format!("'{c}'"))
+                        .map(|c| format_python_string_literal(c))
                         .collect();
                     let ref_cols: Vec<String> = fk
                         .ref_columns
                         .iter()
-                        .map(|c| format!("'{}.{c}'", fk.ref_table))
+                        .map(|c| format_python_string_literal(&fk_target(fk, c, dialect)))
                         .collect();
-                    let fk_opts = format_fk_options(fk);
+                    let fk_opts = format_fk_options(fk, order.defers(table, &constraint.name));
                     let name_part = if !options.nofknames {
-                        format!(", name='{}'", constraint.name)
+                        name_arg(convention, table, constraint)
                     } else {
                         String::new()
                     };
@@ -63,8 +67,8 @@ pub(super) fn build_table_args(
                         positional_args.push(format!("CheckConstraint({expr_literal})"));
                     } else {
                         positional_args.push(format!(
-                            "CheckConstraint({expr_literal}, name='{}')",
-                            constraint.name
+                            "CheckConstraint({expr_literal}, name={})",
+                            format_python_string_literal(&constraint.name)
                         ));
                     }
                 }
@@ -82,27 +86,37 @@ pub(super) fn build_table_args(
                 imports.add("sqlalchemy", "UniqueConstraint");
                 let cols = quote_constraint_columns(&constraint.columns);
                 positional_args.push(format!(
-                    "UniqueConstraint({}, name='{}')",
+                    "UniqueConstraint({}{}{})",
                     cols.join(", "),
-                    constraint.name
+                    name_arg(convention, table, constraint),
+                    nulls_not_distinct_kwarg(constraint.nulls_not_distinct, dialect)
                 ));
             }
         }
+        for constraint in &table.constraints {
+            if let Some(ref exclude) = constraint.exclude {
+                let name = name_arg(convention, table, constraint);
+                positional_args.push(exclude_constraint(exclude, &name, imports));
+            }
+        }
     }
 
     // Indexes.
     if !options.noindexes {
         for index in &table.indexes {
-            if is_unique_constraint_index(index, &table.constraints) {
+            if index.columnstore || is_unique_constraint_index(index, &table.constraints) {
                 continue;
             }
             imports.add("sqlalchemy", "Index");
-            let cols = quote_constraint_columns(&index.columns);
+            if index_needs_text(index) {
+                imports.add("sqlalchemy", "text");
+            }
+            let cols = quote_index_keys(index, dialect);
             let unique_str = if index.is_unique { ", unique=True" } else { "" };
-            let kwargs_str = format_index_kwargs(&index.kwargs);
+            let kwargs_str = format_index_kwargs(index, dialect);
             positional_args.push(format!(
-                "Index('{}', {}{}{})",
-                index.name,
+                "Index({}, {}{}{})",
+                format_python_string_literal(&index.name),
                 cols.join(", "),
                 unique_str,
                 kwargs_str
@@ -111,7 +125,7 @@ pub(super) fn build_table_args(
     }
 
     // Table comment (kwarg).
-    if !options.nocomments {
+    if options.table_comments() {
         if let Some(ref comment) = table.comment {
             let lit = format_python_string_literal(comment);
             kwargs.push(format!("'comment': {lit}"));
@@ -120,7 +134,10 @@ pub(super) fn build_table_args(
 
     // Schema (kwarg, if not default).
     if table.schema != dialect.default_schema() {
-        kwargs.push(format!("'schema': '{}'", table.schema));
+        kwargs.push(format!(
+            "'schema': {}",
+            format_python_string_literal(&table.schema)
+        ));
     }
 
     if positional_args.is_empty() && kwargs.is_empty() {
@@ -199,6 +199,38 @@ fn test_declarative_indexes() {
     assert!(output.contains("text: Mapped[Optional[str]] = mapped_column(String)"));
 }
 
+#[test]
+fn test_declarative_index_method_and_operator_class() {
+    let schema = schema_pg(vec![table("docs")
+        .column(col("id").build())
+        .column(col("title").udt("text").build())
+        .pk("docs_pkey", &["id"])
+        .index_with_opclasses(
+            "ix_docs_title",
+            &["title"],
+            "gist",
+            &[("title", "gist_trgm_ops")],
+        )
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "Index('ix_docs_title', 'title', postgresql_using='gist', postgresql_ops={'title': 'gist_trgm_ops'})"
+    ));
+}
+
+#[test]
+fn test_declarative_expression_index() {
+    let schema = schema_pg(vec![table("users")
+        .column(col("id").build())
+        .column(col("email").udt("varchar").build())
+        .pk("users_pkey", &["id"])
+        .expression_index("ix_users_lower_email", &[], &["lower((email)::text)"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("Index('ix_users_lower_email', text('lower((email)::text)'))"));
+    assert!(output.contains("from sqlalchemy import Index, Integer, String, text"));
+}
+
 #[test]
 fn test_declarative_single_index_table_args_is_a_tuple() {
     let schema = schema_pg(vec![table("items")
@@ -293,9 +325,45 @@ fn test_declarative_table_comment() {
         .comment("this is a 'comment'")
         .build()]);
     let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "class Simple(Base):\n    \
+         \"\"\"this is a 'comment'\"\"\"\n\n    \
+         __tablename__ = 'simple'"
+    ));
     assert!(output.contains("__table_args__ = {'comment': \"this is a 'comment'\"}"));
 }
 
+#[test]
+fn test_declarative_comment_granularity() {
+    let schema = schema_pg(vec![table("simple")
+        .column(col("id").comment("row key").build())
+        .pk("simple_pkey", &["id"])
+        .comment("Line one\nline two")
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "class Simple(Base):\n    \
+         \"\"\"Line one\n    line two\n    \"\"\"\n\n    \
+         __tablename__ = 'simple'"
+    ));
+
+    let opts = GeneratorOptions {
+        notablecomments: true,
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &opts);
+    assert!(!output.contains("Line one"));
+    assert!(output.contains("primary_key=True, comment='row key')"));
+
+    let opts = GeneratorOptions {
+        nocolumncomments: true,
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &opts);
+    assert!(output.contains("Line one"));
+    assert!(!output.contains("row key"));
+}
+
 /// Adapted from sqlacodegen test_pascal.
 #[test]
 fn test_declarative_pascal() {
@@ -351,10 +419,10 @@ fn test_declarative_invalid_attribute_names() {
     // Hyphens replaced with underscores, explicit column name
     assert!(output
         .contains("id_test: Mapped[int] = mapped_column('id-test', Integer, primary_key=True)"));
-    // Leading digit gets underscore prefix
-    assert!(output.contains("_4test: Mapped[Optional[int]] = mapped_column('4test', Integer)"));
-    // _4test collides with sanitized '4test', so gets trailing underscore
-    assert!(output.contains("_4test_: Mapped[Optional[int]] = mapped_column('_4test', Integer)"));
+    // Leading digit gets underscore prefix; it collides with the real
+    // `_4test` column, which keeps its name (sqlacodegen renames `_4test`)
+    assert!(output.contains("_4test_: Mapped[Optional[int]] = mapped_column('4test', Integer)"));
+    assert!(output.contains("_4test: Mapped[Optional[int]] = mapped_column(Integer)"));
     // Python keyword gets trailing underscore
     assert!(output.contains("def_: Mapped[Optional[int]] = mapped_column('def', Integer)"));
 }
@@ -372,6 +440,105 @@ fn test_declarative_metadata_column() {
     assert!(output.contains("metadata_: Mapped[Optional[str]] = mapped_column('metadata', String)"));
 }
 
+#[test]
+fn test_declarative_reserved_and_invalid_column_names() {
+    let schema = schema_pg(vec![table("odd")
+        .column(col("id").build())
+        .column(col("class").nullable().build())
+        .column(col("global").nullable().build())
+        .column(col("from").nullable().build())
+        .column(col("1st_value").nullable().build())
+        .column(col("unit price").nullable().build())
+        .column(col("registry").nullable().build())
+        .pk("odd_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("class_: Mapped[Optional[int]] = mapped_column('class', Integer)"));
+    assert!(output.contains("global_: Mapped[Optional[int]] = mapped_column('global', Integer)"));
+    assert!(output.contains("from_: Mapped[Optional[int]] = mapped_column('from', Integer)"));
+    assert!(
+        output.contains("_1st_value: Mapped[Optional[int]] = mapped_column('1st_value', Integer)")
+    );
+    assert!(
+        output.contains("unit_price: Mapped[Optional[int]] = mapped_column('unit price', Integer)")
+    );
+    assert!(
+        output.contains("registry_: Mapped[Optional[int]] = mapped_column('registry', Integer)")
+    );
+}
+
+#[test]
+fn test_declarative_renamed_column_does_not_clobber_later_column() {
+    // `class` would become `class_`, but a real `class_` column keeps its
+    // own name, so the sanitized ones yield.
+    let schema = schema_pg(vec![table("odd")
+        .column(col("id").build())
+        .column(col("class").nullable().build())
+        .column(col("class_").nullable().build())
+        .column(col("class-").nullable().build())
+        .pk("odd_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("class__: Mapped[Optional[int]] = mapped_column('class', Integer)"));
+    assert!(output.contains("class_: Mapped[Optional[int]] = mapped_column(Integer)"));
+    assert!(output.contains("class___: Mapped[Optional[int]] = mapped_column('class-', Integer)"));
+}
+
+#[test]
+fn test_declarative_valid_column_name_wins_collision() {
+    let schema = schema_pg(vec![table("odd")
+        .column(col("id").build())
+        .column(col("a-b").nullable().build())
+        .column(col("a_b").nullable().build())
+        .pk("odd_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("a_b_: Mapped[Optional[int]] = mapped_column('a-b', Integer)"));
+    assert!(output.contains("a_b: Mapped[Optional[int]] = mapped_column(Integer)"));
+}
+
+#[test]
+fn test_declarative_dunder_column_names_are_renamed() {
+    let schema = schema_pg(vec![table("odd")
+        .column(col("id").build())
+        .column(col("__tablename__").nullable().build())
+        .column(col("__table_args__").nullable().build())
+        .column(col("__table__").nullable().build())
+        .column(col("__mapper_args__").nullable().build())
+        .pk("odd_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("__tablename__ = 'odd'\n"), "{output}");
+    for name in [
+        "__tablename__",
+        "__table_args__",
+        "__table__",
+        "__mapper_args__",
+    ] {
+        assert!(
+            output.contains(&format!(
+                "{name}_: Mapped[Optional[int]] = mapped_column('{name}', Integer)"
+            )),
+            "{output}"
+        );
+    }
+}
+
+#[test]
+fn test_declarative_column_shadowing_import_is_renamed() {
+    let schema = schema_pg(vec![table("events")
+        .column(col("id").build())
+        .column(col("datetime").udt("timestamp").nullable().build())
+        .column(col("Integer").nullable().build())
+        .pk("events_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "datetime_: Mapped[Optional[datetime.datetime]] = mapped_column('datetime', DateTime)"
+    ));
+    assert!(output.contains("Integer_: Mapped[Optional[int]] = mapped_column('Integer', Integer)"));
+}
+
 /// Adapted from sqlacodegen test_invalid_variable_name_from_column.
 #[test]
 fn test_declarative_invalid_variable_name_from_column() {
@@ -467,4 +634,522 @@ fn test_declarative_pascal_underscore() {
     assert!(output.contains("__tablename__ = 'customer_API_Preference'"));
 }
 
+/// Adapted from sqlacodegen test_computed_column.
+#[test]
+fn test_declarative_computed_column() {
+    let schema = schema_pg(vec![table("computed")
+        .column(col("id").build())
+        .column(
+            col("computed")
+                .nullable()
+                .computed("(id * 2)", Some(true))
+                .build(),
+        )
+        .pk("computed_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("from sqlalchemy import Computed, Integer"));
+    assert!(output.contains(
+        "computed: Mapped[Optional[int]] = mapped_column(Integer, Computed('(id * 2)', persisted=True))"
+    ));
+}
+
+#[test]
+fn test_declarative_identity_by_default() {
+    use crate::schema::IdentityInfo;
+    let mut id = col("id")
+        .udt("int8")
+        .identity_info(IdentityInfo::new(1, 1, 1, i64::MAX, false, 1))
+        .build();
+    id.identity_generation = Some("BY DEFAULT".to_string());
+    let schema = schema_pg(vec![table("events")
+        .column(id)
+        .pk("events_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output
+        .contains("id: Mapped[int] = mapped_column(BigInteger, Identity(), primary_key=True)"));
+}
+
+#[test]
+fn test_declarative_mssql_computed_column() {
+    let schema = schema_mssql(vec![table("Orders")
+        .schema("dbo")
+        .column(col("OrderID").udt("int").build())
+        .column(col("Qty").udt("int").build())
+        .column(
+            col("Total")
+                .udt("int")
+                .nullable()
+                .computed("([Qty]*(2))", Some(false))
+                .build(),
+        )
+        .pk("PK_Orders", &["OrderID"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "Total: Mapped[Optional[int]] = mapped_column(Integer, Computed('([Qty]*(2))', persisted=False))"
+    ));
+}
+
 // --- Tier 3: Relationship tests adapted from sqlacodegen ---
+
+#[test]
+fn test_declarative_func_defaults() {
+    let schema = schema_pg(vec![table("events")
+        .column(
+            col("id")
+                .udt("uuid")
+                .default_val("gen_random_uuid()")
+                .build(),
+        )
+        .column(
+            col("created_at")
+                .udt("timestamptz")
+                .default_val("now()")
+                .build(),
+        )
+        .column(col("kind").udt("text").default_val("'misc'::text").build())
+        .pk("events_pkey", &["id"])
+        .build()]);
+    let options = GeneratorOptions {
+        func_defaults: true,
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &options);
+    assert!(output.contains("from sqlalchemy import DateTime, Text, func, text"));
+    assert!(output.contains("server_default=func.gen_random_uuid()"));
+    assert!(output.contains(
+        "created_at: Mapped[datetime.datetime] = mapped_column(DateTime(True), nullable=False, server_default=func.now())"
+    ));
+    assert!(output.contains("server_default=text(\"'misc'\")"));
+
+    let plain = generate(&schema, &GeneratorOptions::default());
+    assert!(plain.contains("server_default=text('now()')"));
+    assert!(!plain.contains("func"));
+}
+
+#[test]
+fn test_declarative_mssql_bit_defaults() {
+    let schema = schema_mssql(vec![table("flags")
+        .schema("dbo")
+        .column(col("id").udt("int").build())
+        .column(col("active").udt("bit").default_val("((1))").build())
+        .column(col("hidden").udt("bit").default_val("('0')").build())
+        .column(col("level").udt("int").default_val("((1))").build())
+        .pk("PK_flags", &["id"])
+        .build()]);
+
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "active: Mapped[bool] = mapped_column(Boolean, nullable=False, server_default=text('1'))"
+    ));
+    assert!(output.contains(
+        "hidden: Mapped[bool] = mapped_column(Boolean, nullable=False, server_default=text('0'))"
+    ));
+
+    let options = GeneratorOptions {
+        func_defaults: true,
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &options);
+    assert!(output.contains("from sqlalchemy import Boolean, Integer, false, text, true"));
+    assert!(output.contains(
+        "active: Mapped[bool] = mapped_column(Boolean, nullable=False, server_default=true())"
+    ));
+    assert!(output.contains(
+        "hidden: Mapped[bool] = mapped_column(Boolean, nullable=False, server_default=false())"
+    ));
+    assert!(output.contains(
+        "level: Mapped[int] = mapped_column(Integer, nullable=False, server_default=text('1'))"
+    ));
+}
+
+#[test]
+fn test_declarative_comments_escape_backslashes_and_newlines() {
+    let schema = schema_pg(vec![
+        table("notes")
+            .column(col("id").build())
+            .column(
+                col("path")
+                    .udt("text")
+                    .comment("C:\\notes\nsecond line")
+                    .build(),
+            )
+            .pk("notes_pkey", &["id"])
+            .build(),
+        table("audit")
+            .column(col("path").udt("text").comment("it's C:\\audit").build())
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("comment='C:\\\\notes\\nsecond line'"));
+    assert!(output.contains("comment=\"it's C:\\\\audit\""));
+}
+
+#[test]
+fn test_declarative_escapes_quotes_and_backslashes_in_names() {
+    let schema = schema_pg(vec![table("it's")
+        .column(col("id").build())
+        .column(col("we'ird").udt("text").build())
+        .column(col("back\\slash").udt("text").build())
+        .pk("pk_it's", &["id"])
+        .unique("uq\\x", &["back\\slash"])
+        .check("ck'q", "length(\"we'ird\") > 0")
+        .index("ix'q", &["we'ird"], false)
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("    __tablename__ = \"it's\"\n"));
+    assert!(output.contains("CheckConstraint('length(\"we\\'ird\") > 0', name=\"ck'q\")"));
+    assert!(output.contains("UniqueConstraint('back\\\\slash', name='uq\\\\x')"));
+    assert!(output.contains("Index(\"ix'q\", \"we'ird\")"));
+    assert!(output.contains("mapped_column(\"we'ird\", Text, nullable=False)"));
+}
+
+#[test]
+fn test_declarative_imports_existing_base() {
+    let options = GeneratorOptions {
+        base_class: Some(crate::cli::BaseClass {
+            module: "myapp.db".to_string(),
+            name: "Base".to_string(),
+        }),
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&make_simple_schema(), &options);
+    assert!(output.contains("from myapp.db import Base\n"));
+    assert!(!output.contains("DeclarativeBase"));
+    assert!(!output.contains("class Base("));
+    assert!(output.contains("class Users(Base):"));
+}
+
+#[test]
+fn test_declarative_renamed_base() {
+    let mut schema = make_simple_schema();
+    schema.tables.push(
+        table("audit_log")
+            .column(col("msg").udt("text").build())
+            .build(),
+    );
+    let options = GeneratorOptions {
+        base_name: Some("Model".to_string()),
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &options);
+    assert!(output.contains("class Model(DeclarativeBase):\n    pass"));
+    assert!(output.contains("class Users(Model):"));
+    assert!(output.contains("Model.metadata"));
+    assert!(!output.contains("class Base("));
+}
+
+#[test]
+fn test_declarative_naming_convention() {
+    let options = GeneratorOptions {
+        naming_convention: true,
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&make_simple_schema(), &options);
+    assert!(output.contains("Integer, MetaData, String"));
+    assert!(output.contains(
+        "class Base(DeclarativeBase):\n    metadata = MetaData(naming_convention={\n        'ix': 'ix_%(column_0_label)s',\n"
+    ));
+    assert!(output.contains("        'pk': '%(table_name)s_pkey',\n    })\n"));
+    assert!(output.contains("UniqueConstraint('email'),"));
+    assert!(!output.contains("name='users_email_key'"));
+
+    // An imported Base owns its MetaData, so names stay explicit.
+    let options = GeneratorOptions {
+        naming_convention: true,
+        base_class: Some(crate::cli::BaseClass {
+            module: "myapp.db".to_string(),
+            name: "Base".to_string(),
+        }),
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&make_simple_schema(), &options);
+    assert!(!output.contains("naming_convention"));
+    assert!(output.contains("UniqueConstraint('email', name='users_email_key')"));
+}
+
+#[test]
+fn test_declarative_repr() {
+    let options = GeneratorOptions {
+        repr: true,
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&make_simple_schema(), &options);
+    assert!(output.contains(
+        "    posts: Mapped[list['Posts']] = relationship('Posts', back_populates='user')\n\n    \
+         def __repr__(self) -> str:\n        \
+         return f'Users(id={self.id!r}, name={self.name!r}, email={self.email!r})'"
+    ));
+    assert!(output.contains("return f'Posts(id={self.id!r}, title={self.title!r})'"));
+
+    let plain = generate(&make_simple_schema(), &GeneratorOptions::default());
+    assert!(!plain.contains("__repr__"));
+}
+
+#[test]
+fn test_declarative_views_follow_tables_with_definition() {
+    let schema = schema_pg(vec![
+        table("active_users")
+            .view(" SELECT users.id,\n    users.name\n   FROM users\n  WHERE users.active;")
+            .column(col("id").nullable().build())
+            .column(col("name").udt("text").nullable().build())
+            .build(),
+        table("users")
+            .column(col("id").build())
+            .column(col("name").udt("text").build())
+            .pk("users_pkey", &["id"])
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    let class_pos = output.find("class Users(Base):").unwrap();
+    let view_pos = output
+        .find(
+            "# View public.active_users:\n\
+             #    SELECT users.id,\n\
+             #       users.name\n\
+             #      FROM users\n\
+             #     WHERE users.active;\n\
+             t_active_users = Table(\n",
+        )
+        .expect("view block with its definition");
+    assert!(class_pos < view_pos);
+}
+
+fn make_temporal_schema() -> IntrospectedSchema {
+    use crate::schema::PeriodColumn;
+
+    let mut schema = schema_mssql(vec![
+        table("Orders")
+            .schema("dbo")
+            .column(col("id").udt("int").build())
+            .column(
+                col("ValidFrom")
+                    .udt("datetime2")
+                    .period(PeriodColumn::RowStart)
+                    .build(),
+            )
+            .column(
+                col("ValidTo")
+                    .udt("datetime2")
+                    .period(PeriodColumn::RowEnd)
+                    .build(),
+            )
+            .pk("PK_Orders", &["id"])
+            .build(),
+        table("OrdersHistory")
+            .schema("history")
+            .column(col("id").udt("int").build())
+            .column(col("ValidFrom").udt("datetime2").build())
+            .column(col("ValidTo").udt("datetime2").build())
+            .build(),
+    ]);
+    schema.tables[0].history_table = Some("history.OrdersHistory".to_string());
+    schema.tables[1].history_of = Some("Orders".to_string());
+    schema
+}
+
+#[test]
+fn test_declarative_temporal_tables() {
+    let output = generate(&make_temporal_schema(), &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "class Orders(Base):\n    \
+         \"\"\"System-versioned temporal table; row history is kept in history.OrdersHistory.\"\"\"\n\n    \
+         __tablename__ = 'Orders'"
+    ));
+    assert!(output.contains(
+        "ValidFrom: Mapped[datetime.datetime] = mapped_column(DateTime, nullable=False, \
+         server_default=FetchedValue(), server_onupdate=FetchedValue())"
+    ));
+    assert!(output.contains(
+        "# History table of system-versioned table Orders; SQL Server writes its rows.\n\
+         t_OrdersHistory = Table("
+    ));
+    assert!(output.contains("    Column('ValidFrom', DateTime, nullable=False),\n"));
+}
+
+#[test]
+fn test_declarative_inherits_child_names_parent_and_inherited_columns() {
+    let schema = schema_pg(vec![
+        table("cities")
+            .column(col("id").build())
+            .column(col("name").udt("text").build())
+            .pk("cities_pkey", &["id"])
+            .build(),
+        table("capitals")
+            .column(col("id").inherited().build())
+            .column(col("name").udt("text").inherited().build())
+            .column(col("state").udt("text").build())
+            .pk("capitals_pkey", &["id"])
+            .inherits("cities")
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "class Capitals(Base):\n    \
+         \"\"\"Inherits cities (PostgreSQL INHERITS); columns id, name are inherited.\"\"\"\n\n    \
+         __tablename__ = 'capitals'"
+    ));
+    assert!(output.contains("class Cities(Base):\n    __tablename__ = 'cities'"));
+}
+
+#[test]
+fn test_declarative_index_comment_goes_in_docstring() {
+    let mut schema = schema_pg(vec![table("orders")
+        .column(col("id").build())
+        .column(col("total").udt("numeric").build())
+        .pk("orders_pkey", &["id"])
+        .index("orders_total", &["total"], false)
+        .build()]);
+    schema.tables[0].indexes[0].comment = Some("Reporting \"hot\" path".to_string());
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "class Orders(Base):\n    \
+         \"\"\"Index orders_total: Reporting \"hot\" path\"\"\"\n\n    \
+         __tablename__ = 'orders'"
+    ));
+}
+
+#[test]
+fn test_declarative_row_level_security_goes_in_docstring() {
+    use crate::schema::{PolicyInfo, RowSecurity};
+
+    let mut schema = schema_pg(vec![table("orders")
+        .column(col("id").build())
+        .column(col("tenant_id").build())
+        .pk("orders_pkey", &["id"])
+        .build()]);
+    schema.tables[0].row_security = Some(RowSecurity {
+        enabled: true,
+        forced: true,
+        policies: vec![PolicyInfo {
+            name: "tenant_isolation".to_string(),
+            command: "ALL".to_string(),
+            permissive: true,
+            roles: vec!["app".to_string()],
+            using: Some("(tenant_id = current_tenant())".to_string()),
+            with_check: Some("(tenant_id = current_tenant())".to_string()),
+        }],
+    });
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(
+        output.contains(
+            "class Orders(Base):\n    \
+             \"\"\"Row-level security is enabled and forced, so it binds the table owner too; \
+             queries see only the rows these policies allow: tenant_isolation (ALL to app, \
+             USING (tenant_id = current_tenant()), WITH CHECK (tenant_id = current_tenant())).\"\"\"\n\n    \
+             __tablename__ = 'orders'"
+        ),
+        "{output}"
+    );
+
+    schema.tables[0].row_security.as_mut().unwrap().enabled = false;
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(!output.contains("Row-level security"), "{output}");
+}
+
+#[test]
+fn test_declarative_standalone_sequence_wires_column_default() {
+    let mut schema = schema_pg(vec![table("invoices")
+        .column(col("id").build())
+        .column(
+            col("number")
+                .udt("int8")
+                .default_val("nextval('billing.invoice_seq'::regclass)")
+                .build(),
+        )
+        .pk("invoices_pkey", &["id"])
+        .build()]);
+    schema.sequences = vec![crate::schema::SequenceInfo {
+        schema: "billing".to_string(),
+        name: "invoice_seq".to_string(),
+        start: 1,
+        increment: 10,
+        min_value: 1,
+        max_value: i64::MAX,
+        cycle: false,
+        cache: 1,
+    }];
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "class Base(DeclarativeBase):\n    pass\n\n\n\
+         billing_invoice_seq = Sequence('invoice_seq', start=1, increment=10, \
+         schema='billing', metadata=Base.metadata)\n\n\n"
+    ));
+    assert!(output.contains(
+        "number: Mapped[int] = mapped_column(BigInteger, billing_invoice_seq, nullable=False)"
+    ));
+}
+
+fn audited(name: &str) -> crate::schema::TableInfo {
+    table(name)
+        .column(col("id").build())
+        .column(
+            col("created_at")
+                .udt("timestamptz")
+                .default_val("now()")
+                .build(),
+        )
+        .column(col("updated_at").udt("timestamptz").nullable().build())
+        .pk(&format!("{name}_pkey"), &["id"])
+        .build()
+}
+
+#[test]
+fn test_declarative_mixins_factor_out_shared_timestamps() {
+    let schema = schema_pg(vec![
+        audited("users"),
+        audited("orders"),
+        table("notes")
+            .column(col("id").build())
+            .column(col("created_at").udt("timestamp").nullable().build())
+            .pk("notes_pkey", &["id"])
+            .build(),
+    ]);
+    let options = GeneratorOptions {
+        mixins: true,
+        ..Default::default()
+    };
+    let output = generate(&schema, &options);
+
+    assert!(
+        output.contains(
+            "class Base(DeclarativeBase):\n    pass\n\n\n\
+             class TimestampMixin:\n    \
+             created_at: Mapped[datetime.datetime] = mapped_column(DateTime(True), nullable=False, server_default=text('now()'))\n    \
+             updated_at: Mapped[Optional[datetime.datetime]] = mapped_column(DateTime(True))\n\n\n"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "class Users(TimestampMixin, Base):\n    __tablename__ = 'users'\n\n    \
+         id: Mapped[int] = mapped_column(Integer, primary_key=True)"
+        ),
+        "{output}"
+    );
+    assert!(output.contains("class Orders(TimestampMixin, Base):"));
+    // A differing column set keeps its own columns.
+    assert!(output.contains("class Notes(Base):"));
+    assert!(output
+        .contains("created_at: Mapped[Optional[datetime.datetime]] = mapped_column(DateTime)"));
+}
+
+#[test]
+fn test_declarative_mixins_need_two_classes() {
+    let schema = schema_pg(vec![audited("users")]);
+    let options = GeneratorOptions {
+        mixins: true,
+        ..Default::default()
+    };
+    let output = generate(&schema, &options);
+    assert!(!output.contains("TimestampMixin"), "{output}");
+    assert!(output.contains("class Users(Base):"));
+}
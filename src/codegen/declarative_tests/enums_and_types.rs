@@ -1,6 +1,7 @@
 //! Enum, domain, array, dialect option, and type tests.
 
 use super::super::*;
+use crate::schema::SpatialInfo;
 use crate::testutil::*;
 
 /// Adapted from sqlacodegen test_synthetic_enum_generation (declarative).
@@ -21,6 +22,33 @@ fn test_declarative_synthetic_enum() {
     assert!(output.contains("ACTIVE = 'active'"));
 }
 
+#[test]
+fn test_declarative_synthetic_enum_from_pg_any_array() {
+    let schema = schema_pg(vec![table("invoices")
+        .column(col("id").build())
+        .column(col("status").udt("varchar").build())
+        .pk("invoices_pkey", &["id"])
+        .check(
+            "invoices_status_check",
+            "((status)::text = ANY ((ARRAY['new'::character varying, 'paid'::character varying, 'void'::character varying])::text[]))",
+        )
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(!output.contains("enum.Enum"), "{output}");
+    assert!(
+        output.contains("status: Mapped[str] = mapped_column(String, nullable=False)"),
+        "{output}"
+    );
+
+    let options = GeneratorOptions {
+        enum_from_check: true,
+        ..Default::default()
+    };
+    let output = generate(&schema, &options);
+    assert!(output.contains("class InvoicesStatus(str, enum.Enum):\n    NEW = 'new'\n    PAID = 'paid'\n    VOID = 'void'"), "{output}");
+    assert!(output.contains("status: Mapped[InvoicesStatus] = mapped_column(Enum(InvoicesStatus, values_callable=lambda cls: [member.value for member in cls]), nullable=False)"), "{output}");
+}
+
 /// Adapted from sqlacodegen test_onetomany_multiref_composite.
 #[test]
 fn test_declarative_onetomany_multiref_composite() {
@@ -279,6 +307,8 @@ fn test_declarative_domain_json() {
             .pk("simple_items_pkey", &["id"])
             .build()],
         enums: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
         domains: vec![DomainInfo {
             name: "json_domain".to_string(),
             schema: None,
@@ -295,6 +325,22 @@ fn test_declarative_domain_json() {
     assert!(output.contains("data:"));
 }
 
+/// Introspected domain columns carry the base type in `udt_name`, so the
+/// declarative model maps the base type instead of an unimportable name.
+#[test]
+fn test_declarative_domain_column_maps_base_type() {
+    let mut email = col("email").udt("varchar").max_length(255).build();
+    email.domain = Some("email_address".to_string());
+    let schema = schema_pg(vec![table("people")
+        .column(col("id").build())
+        .column(email)
+        .pk("people_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("email: Mapped[str] = mapped_column(String(255)"));
+    assert!(!output.contains("EMAIL_ADDRESS"));
+}
+
 /// Adapted from sqlacodegen test_named_constraints.
 /// PrimaryKeyConstraint emitted in __table_args__ when CheckConstraint present.
 #[test]
@@ -565,8 +611,10 @@ fn test_declarative_array_enum_named() {
         }],
     );
     let output = generate(&schema, &GeneratorOptions::default());
-    // Array column rendered (array-of-enum is complex; for now just test it doesn't panic)
-    assert!(output.contains("roles:"));
+    assert!(output.contains("from sqlalchemy import ARRAY, Enum, Integer"));
+    assert!(output.contains(
+        "roles: Mapped[Optional[list[RoleEnum]]] = mapped_column(ARRAY(Enum(RoleEnum, values_callable=lambda cls: [member.value for member in cls], name='role_enum')))"
+    ));
 }
 
 /// Adapted from sqlacodegen test_domain_non_default_json (declarative).
@@ -581,6 +629,8 @@ fn test_declarative_domain_non_default_json() {
             .pk("si_pkey", &["id"])
             .build()],
         enums: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
         domains: vec![DomainInfo {
             name: "custom_json".to_string(),
             schema: None,
@@ -692,8 +742,9 @@ fn test_declarative_array_enum_named_with_schema() {
         }],
     );
     let output = generate(&schema, &GeneratorOptions::default());
-    // Schema enum with array — renders the column
-    assert!(output.contains("roles:"));
+    assert!(output.contains(
+        "roles: Mapped[Optional[list[RoleEnum]]] = mapped_column(ARRAY(Enum(RoleEnum, values_callable=lambda cls: [member.value for member in cls], name='role_enum', schema='someschema')))"
+    ));
 }
 
 /// Adapted from sqlacodegen test_include_dialect_options tests.
@@ -727,17 +778,19 @@ fn test_declarative_array_enum_nullable() {
         }],
     );
     let output = generate(&schema, &GeneratorOptions::default());
-    assert!(output.contains("tags: Mapped[Optional[list]]"));
+    assert!(output.contains("tags: Mapped[Optional[list[TagEnum]]]"));
 }
 
 /// Adapted from sqlacodegen test_array_enum_with_dimensions.
 #[test]
 fn test_declarative_array_enum_with_dimensions() {
     use crate::schema::EnumInfo;
+    let mut matrix = col("matrix").udt("_status_enum").nullable().build();
+    matrix.array_dimensions = Some(2);
     let schema = schema_pg_with_enums(
         vec![table("users")
             .column(col("id").build())
-            .column(col("matrix").udt("_status_enum").nullable().build())
+            .column(matrix)
             .pk("users_pkey", &["id"])
             .build()],
         vec![EnumInfo {
@@ -747,8 +800,9 @@ fn test_declarative_array_enum_with_dimensions() {
         }],
     );
     let output = generate(&schema, &GeneratorOptions::default());
-    // Array column with enum renders
-    assert!(output.contains("matrix:"));
+    assert!(output.contains(
+        "matrix: Mapped[Optional[list[StatusEnum]]] = mapped_column(ARRAY(Enum(StatusEnum, values_callable=lambda cls: [member.value for member in cls], name='status_enum'), dimensions=2))"
+    ));
 }
 
 /// Adapted from sqlacodegen test_array_enum_nonativeenums_option.
@@ -797,15 +851,13 @@ fn test_declarative_array_enum_shared() {
     // Enum class used for both regular and array columns
     assert!(output.contains("class RoleEnum(str, enum.Enum):"));
     assert!(output.contains("role: Mapped[RoleEnum]"));
+    assert!(output.contains("prev_roles: Mapped[Optional[list[RoleEnum]]]"));
 }
 
-/// Adapted from sqlacodegen test_use_inflect.
-/// NOTE: use_inflect requires an inflections crate and is not yet implemented.
-/// This test documents the intended behavior: with use_inflect, collection
-/// relationship names would be pluralized and scalar names singularized.
-/// When implemented, update assertions to verify inflected names.
+/// Adapted from sqlacodegen test_use_inflect: with use_inflect, class names
+/// are singularized while attribute names keep the table names.
 #[test]
-fn test_declarative_use_inflect_placeholder() {
+fn test_declarative_use_inflect() {
     let schema = schema_pg(vec![
         table("simple_containers")
             .column(col("id").build())
@@ -821,8 +873,45 @@ fn test_declarative_use_inflect_placeholder() {
     let output = generate(&schema, &GeneratorOptions::default());
     // Without inflect: collection uses table name "simple_items"
     assert!(output.contains("simple_items: Mapped[list['SimpleItems']]"));
-    // TODO: With use_inflect, parent side would use singularized/pluralized names
-    // e.g. "simple_item: Mapped[list['SimpleItems']]" → pluralized collection
+
+    let options = GeneratorOptions {
+        use_inflect: true,
+        ..Default::default()
+    };
+    let output = generate(&schema, &options);
+    assert!(output.contains("class SimpleContainer(Base):"));
+    assert!(output.contains("class SimpleItem(Base):"));
+    assert!(output.contains("simple_items: Mapped[list['SimpleItem']] = relationship('SimpleItem'"));
+    assert!(output.contains("container: Mapped[Optional['SimpleContainer']]"));
+}
+
+/// A table whose singular name belongs to another table keeps its plural
+/// class name, and relationships point at the right class.
+#[test]
+fn test_declarative_use_inflect_keeps_colliding_plurals() {
+    let schema = schema_pg(vec![
+        table("user")
+            .column(col("id").build())
+            .pk("user_pkey", &["id"])
+            .build(),
+        table("users")
+            .column(col("id").build())
+            .column(col("owner_id").nullable().build())
+            .pk("users_pkey", &["id"])
+            .fk("users_owner_fkey", &["owner_id"], "user", &["id"])
+            .build(),
+    ]);
+    let options = GeneratorOptions {
+        use_inflect: true,
+        ..Default::default()
+    };
+    let output = generate(&schema, &options);
+    assert_eq!(output.matches("class User(Base):").count(), 1, "{output}");
+    assert!(output.contains("class Users(Base):"), "{output}");
+    assert!(
+        output.contains("owner: Mapped[Optional['User']] = relationship('User'"),
+        "{output}"
+    );
 }
 
 /// Test keep_dialect_types in declarative mode for PostgreSQL.
@@ -851,3 +940,141 @@ fn test_declarative_keep_dialect_types_pg() {
     assert!(output.contains("DOUBLE_PRECISION"));
     assert!(output.contains("from sqlalchemy.dialects.postgresql import"));
 }
+
+#[test]
+fn test_declarative_sampled_json_typed_dict() {
+    let mut payload = col("payload").udt("jsonb").nullable().build();
+    payload.sample = Some(crate::schema::ColumnSample {
+        rows: 3,
+        nulls: 0,
+        json_fields: Some(
+            [
+                ("sku".to_string(), "str".to_string()),
+                ("qty".to_string(), "Optional[int]".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+        ),
+        ..Default::default()
+    });
+    let mut raw = col("raw").udt("json").nullable().build();
+    raw.sample = Some(crate::schema::ColumnSample {
+        rows: 3,
+        nulls: 0,
+        json_fields: Some(
+            [("x-id".to_string(), "int".to_string())]
+                .into_iter()
+                .collect(),
+        ),
+        ..Default::default()
+    });
+    let schema = schema_pg(vec![table("orders")
+        .column(col("id").build())
+        .column(payload)
+        .column(raw)
+        .column(col("extra").udt("jsonb").nullable().build())
+        .pk("orders_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("from typing import Optional, TypedDict"));
+    assert!(
+        output.contains("class OrdersPayload(TypedDict):\n    qty: Optional[int]\n    sku: str")
+    );
+    assert!(output.contains("OrdersRaw = TypedDict('OrdersRaw', {'x-id': int})"));
+    assert!(output.contains("payload: Mapped[Optional[OrdersPayload]] = mapped_column(JSONB)"));
+    assert!(output.contains("extra: Mapped[Optional[dict]] = mapped_column(JSONB)"));
+}
+
+#[test]
+fn test_declarative_typemap_override() {
+    let mut schema = schema_pg(vec![table("users")
+        .column(col("id").build())
+        .column(col("email").udt("citext").build())
+        .pk("users_pkey", &["id"])
+        .build()]);
+    crate::typemap::overrides::TypeOverrides::parse(
+        "[types.citext]\nsa_type = \"CITEXT\"\npython_type = \"str\"\nimport = \"sqlalchemy.dialects.postgresql.CITEXT\"\n",
+    )
+    .unwrap()
+    .apply(&mut schema);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains("from sqlalchemy.dialects.postgresql import CITEXT"));
+    assert!(output.contains("email: Mapped[str] = mapped_column(CITEXT, nullable=False)"));
+}
+
+#[test]
+fn test_declarative_range_columns() {
+    let schema = schema_pg(vec![table("bookings")
+        .column(col("id").build())
+        .column(col("during").udt("tstzrange").nullable().build())
+        .column(col("seats").udt("int4range").build())
+        .pk("bookings_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(
+        output.contains("from sqlalchemy.dialects.postgresql import INT4RANGE, Range, TSTZRANGE")
+    );
+    assert!(output.contains("import datetime"));
+    assert!(output
+        .contains("during: Mapped[Optional[Range[datetime.datetime]]] = mapped_column(TSTZRANGE)"));
+    assert!(output.contains("seats: Mapped[Range[int]] = mapped_column(INT4RANGE"));
+}
+
+#[test]
+fn test_declarative_geoalchemy_columns() {
+    let mut location = col("location").udt("geometry").nullable().build();
+    location.spatial = Some(SpatialInfo::from_type_modifier("geometry(Point,4326)"));
+    let schema = schema_pg(vec![table("places")
+        .column(col("id").build())
+        .column(location)
+        .column(col("area").udt("geography").build())
+        .pk("places_pkey", &["id"])
+        .build()]);
+    let options = GeneratorOptions {
+        geoalchemy: true,
+        ..Default::default()
+    };
+    let output = generate(&schema, &options);
+
+    assert!(output.contains("from geoalchemy2 import Geography, Geometry, WKBElement"));
+    assert!(output.contains(
+        "location: Mapped[Optional[WKBElement]] = mapped_column(Geometry(geometry_type='POINT', srid=4326))"
+    ));
+    assert!(
+        output.contains("area: Mapped[WKBElement] = mapped_column(Geography(), nullable=False)")
+    );
+}
+
+#[test]
+fn test_declarative_spatial_columns_without_geoalchemy() {
+    let schema = schema_pg(vec![table("places")
+        .column(col("id").build())
+        .column(col("location").udt("geometry").build())
+        .pk("places_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(!output.contains("geoalchemy2"));
+}
+
+#[test]
+fn test_declarative_mssql_exotic_types() {
+    let schema = schema_mssql(vec![table("documents")
+        .schema("dbo")
+        .column(col("id").udt("int").build())
+        .column(col("body").udt("xml").nullable().build())
+        .column(col("tag").udt("sql_variant").nullable().build())
+        .column(col("region").udt("geography").nullable().build())
+        .pk("PK_documents", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains("from sqlalchemy.dialects.mssql import SQL_VARIANT, XML"));
+    assert!(output.contains("body: Mapped[Optional[str]] = mapped_column(XML)"));
+    assert!(output.contains("tag: Mapped[Optional[str]] = mapped_column(SQL_VARIANT)"));
+    assert!(output.contains(
+        "    # WARNING: geography has no SQLAlchemy type; mapped to LargeBinary\n    \
+         region: Mapped[Optional[bytes]] = mapped_column(LargeBinary)"
+    ));
+}
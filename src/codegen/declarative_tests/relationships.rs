@@ -41,6 +41,42 @@ fn test_declarative_onetomany() {
     assert!(!output.contains("ForeignKeyConstraint"));
 }
 
+#[test]
+fn test_declarative_fk_cycle_uses_use_alter() {
+    let schema = schema_pg(vec![
+        table("departments")
+            .column(col("id").build())
+            .column(col("manager_id").nullable().build())
+            .pk("departments_pkey", &["id"])
+            .fk(
+                "departments_manager_id_fkey",
+                &["manager_id"],
+                "employees",
+                &["id"],
+            )
+            .build(),
+        table("employees")
+            .column(col("id").build())
+            .column(col("department_id").nullable().build())
+            .pk("employees_pkey", &["id"])
+            .fk(
+                "employees_department_id_fkey",
+                &["department_id"],
+                "departments",
+                &["id"],
+            )
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "department_id: Mapped[Optional[int]] = mapped_column(ForeignKey('departments.id', use_alter=True))"
+    ));
+    assert!(output
+        .contains("manager_id: Mapped[Optional[int]] = mapped_column(ForeignKey('employees.id'))"));
+    assert!(output.find("class Employees").unwrap() < output.find("class Departments").unwrap());
+}
+
 /// Adapted from sqlacodegen test_onetomany_selfref.
 #[test]
 fn test_declarative_onetomany_selfref() {
@@ -339,6 +375,78 @@ fn test_declarative_foreign_key_schema() {
     assert!(output.contains("__table_args__ = {'schema': 'otherschema'}"));
 }
 
+#[test]
+fn test_declarative_composite_foreign_key_schema() {
+    let schema = schema_pg(vec![
+        table("regions")
+            .schema("geo")
+            .column(col("country").udt("varchar").build())
+            .column(col("code").udt("varchar").build())
+            .pk("regions_pkey", &["country", "code"])
+            .build(),
+        table("sales")
+            .schema("geo")
+            .column(col("id").build())
+            .column(col("country").udt("varchar").build())
+            .column(col("region_code").udt("varchar").build())
+            .pk("sales_pkey", &["id"])
+            .fk_full(
+                "sales_region_fkey",
+                &["country", "region_code"],
+                "geo",
+                "regions",
+                &["country", "code"],
+                "NO ACTION",
+                "NO ACTION",
+            )
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    // Same non-default schema on both sides still needs the qualified key.
+    assert!(output.contains(
+        "ForeignKeyConstraint(['country', 'region_code'], ['geo.regions.country', 'geo.regions.code'], name='sales_region_fkey')"
+    ));
+}
+
+#[test]
+fn test_declarative_same_table_name_in_two_schemas() {
+    let orders = |schema_name: &str| {
+        table("orders")
+            .schema(schema_name)
+            .column(col("id").build())
+            .pk(&format!("{schema_name}_orders_pkey"), &["id"])
+            .build()
+    };
+    let schema = schema_pg(vec![
+        orders("sales"),
+        orders("archive"),
+        table("items")
+            .schema("sales")
+            .column(col("id").build())
+            .column(col("order_id").build())
+            .pk("items_pkey", &["id"])
+            .fk_full(
+                "items_order_fkey",
+                &["order_id"],
+                "sales",
+                "orders",
+                &["id"],
+                "NO ACTION",
+                "NO ACTION",
+            )
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains("class SalesOrders(Base):"));
+    assert!(output.contains("class ArchiveOrders(Base):"));
+    assert!(output.contains("order: Mapped['SalesOrders'] = relationship('SalesOrders'"));
+    assert!(output.contains("items: Mapped[list['Items']] = relationship('Items'"));
+    // Only the referenced `orders` gets the reverse relationship.
+    assert_eq!(output.matches("items: Mapped[list['Items']]").count(), 1);
+}
+
 /// Adapted from sqlacodegen test_manytomany.
 #[test]
 fn test_declarative_manytomany() {
@@ -374,6 +482,37 @@ fn test_declarative_manytomany() {
     assert!(!output.contains("class AssociationTable"));
 }
 
+#[test]
+fn test_declarative_noassociations_renders_association_class() {
+    let schema = schema_pg(vec![
+        table("left_table")
+            .column(col("id").build())
+            .pk("left_table_pkey", &["id"])
+            .build(),
+        table("right_table")
+            .column(col("id").build())
+            .pk("right_table_pkey", &["id"])
+            .build(),
+        table("association_table")
+            .column(col("left_id").build())
+            .column(col("right_id").build())
+            .pk("association_table_pkey", &["left_id", "right_id"])
+            .fk("assoc_left_fkey", &["left_id"], "left_table", &["id"])
+            .fk("assoc_right_fkey", &["right_id"], "right_table", &["id"])
+            .build(),
+    ]);
+    let opts = GeneratorOptions {
+        noassociations: true,
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &opts);
+
+    assert!(output.contains("class AssociationTable(Base):"));
+    assert!(!output.contains("secondary="));
+    assert!(!output.contains("t_association_table = Table("));
+    assert!(output.contains("association_table: Mapped[list['AssociationTable']] = relationship('AssociationTable', back_populates='left')"));
+}
+
 /// Adapted from sqlacodegen test_joined_inheritance.
 #[test]
 fn test_declarative_joined_inheritance() {
@@ -482,12 +621,9 @@ fn test_declarative_onetomany_conflicting_column() {
             .build(),
     ]);
     let output = generate(&schema, &GeneratorOptions::default());
-    // "relationship" is not in PYTHON_RESERVED or import conflicts currently,
-    // so it passes through as-is. The relationship() calls still work.
-    assert!(
-        output.contains("relationship: Mapped[Optional[str]]")
-            || output.contains("relationship_: Mapped[Optional[str]]")
-    );
+    // A `relationship` attribute would shadow the relationship() calls below it.
+    assert!(output
+        .contains("relationship_: Mapped[Optional[str]] = mapped_column('relationship', Text)"));
     assert!(output.contains("relationship('SimpleItems'"));
 }
 
@@ -547,3 +683,334 @@ fn test_declarative_joined_inheritance_same_table_name() {
     // FK on PK column
     assert!(output.contains("simple_items_id: Mapped[int] = mapped_column(ForeignKey('simple_items.id'), primary_key=True)"));
 }
+
+#[test]
+fn test_declarative_async_option() {
+    let schema = schema_pg(vec![
+        table("simple_containers")
+            .column(col("id").build())
+            .pk("sc_pkey", &["id"])
+            .build(),
+        table("simple_items")
+            .column(col("id").build())
+            .column(col("container_id").nullable().build())
+            .pk("si_pkey", &["id"])
+            .fk("si_fkey", &["container_id"], "simple_containers", &["id"])
+            .build(),
+    ]);
+    let opts = GeneratorOptions {
+        asyncio: true,
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &opts);
+    assert!(output.contains("from sqlalchemy.ext.asyncio import AsyncAttrs\n"));
+    assert!(output.contains("class Base(AsyncAttrs, DeclarativeBase):\n    pass"));
+    assert!(output.contains(
+        "simple_items: Mapped[list['SimpleItems']] = relationship('SimpleItems', back_populates='container', lazy='selectin')"
+    ));
+    assert!(output.contains(
+        "container: Mapped[Optional['SimpleContainers']] = relationship('SimpleContainers', back_populates='simple_items', lazy='selectin')"
+    ));
+
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(!output.contains("AsyncAttrs"));
+    assert!(!output.contains("lazy="));
+}
+
+#[test]
+fn test_declarative_pep604_and_future_annotations() {
+    let schema = schema_pg(vec![
+        table("simple_containers")
+            .column(col("id").build())
+            .column(col("name").udt("text").nullable().build())
+            .pk("sc_pkey", &["id"])
+            .build(),
+        table("simple_items")
+            .column(col("id").build())
+            .column(col("container_id").nullable().build())
+            .pk("si_pkey", &["id"])
+            .fk("si_fkey", &["container_id"], "simple_containers", &["id"])
+            .build(),
+    ]);
+    let opts = GeneratorOptions {
+        pep604: true,
+        future_annotations: true,
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &opts);
+    assert!(output.starts_with("from __future__ import annotations\n\nfrom sqlalchemy import "));
+    assert!(!output.contains("Optional"), "{output}");
+    assert!(output.contains("name: Mapped[str | None] = mapped_column(Text)"));
+    assert!(output.contains(
+        "container: Mapped['SimpleContainers | None'] = relationship('SimpleContainers', back_populates='simple_items')"
+    ));
+
+    let files = generate_split(&schema, &opts);
+    let items = &files
+        .iter()
+        .find(|(name, _)| name == "simple_items.py")
+        .unwrap()
+        .1;
+    assert!(
+        items.starts_with(
+            "from __future__ import annotations\n\nfrom typing import TYPE_CHECKING\n"
+        ),
+        "{items}"
+    );
+}
+
+#[test]
+fn test_declarative_relationships_use_renamed_attributes() {
+    let schema = schema_pg(vec![
+        table("tbl_cnt")
+            .column(col("id").build())
+            .pk("tbl_cnt_pkey", &["id"])
+            .build(),
+        table("tbl_itm")
+            .column(col("id").build())
+            .column(col("par_cnt").nullable().build())
+            .column(col("top_cnt").build())
+            .pk("tbl_itm_pkey", &["id"])
+            .fk("itm_par_fkey", &["par_cnt"], "tbl_cnt", &["id"])
+            .fk("itm_top_fkey", &["top_cnt"], "tbl_cnt", &["id"])
+            .build(),
+    ]);
+    let options = GeneratorOptions {
+        rename_map: crate::naming::RenameMap::parse(
+            "[tables]\ntbl_cnt = \"Container\"\n\n\
+             [columns]\n\"tbl_itm.par_cnt\" = \"parent_id\"\n\"tbl_itm.top_cnt\" = \"top_id\"\n",
+        )
+        .unwrap(),
+        strip_prefixes: vec!["tbl_".to_string()],
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &options);
+
+    assert!(output.contains("class Container(Base):"));
+    assert!(output.contains("class Itm(Base):"));
+    assert!(output.contains(
+        "parent_id: Mapped[Optional[int]] = mapped_column('par_cnt', ForeignKey('tbl_cnt.id'))"
+    ));
+    assert!(output.contains("relationship('Container', foreign_keys=[parent_id],"));
+    assert!(output.contains("relationship('Container', foreign_keys=[top_id],"));
+    assert!(output.contains("foreign_keys='[Itm.parent_id]'"));
+}
+
+#[test]
+fn test_declarative_relationship_names_are_sanitized() {
+    let schema = schema_pg(vec![
+        table("orders")
+            .column(col("id").build())
+            .pk("orders_pkey", &["id"])
+            .build(),
+        table("class")
+            .column(col("id").build())
+            .pk("class_pkey", &["id"])
+            .build(),
+        table("metadata")
+            .column(col("id").build())
+            .pk("metadata_pkey", &["id"])
+            .build(),
+        table("items")
+            .column(col("id").build())
+            .column(col("order-id").nullable().build())
+            .column(col("class_id").nullable().build())
+            .column(col("metadata_id").nullable().build())
+            .pk("items_pkey", &["id"])
+            .fk("items_order_fkey", &["order-id"], "orders", &["id"])
+            .fk("items_class_fkey", &["class_id"], "class", &["id"])
+            .fk("items_metadata_fkey", &["metadata_id"], "metadata", &["id"])
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "order: Mapped[Optional['Orders']] = relationship('Orders', back_populates='items')"
+    ));
+    assert!(
+        output.contains(
+            "class_: Mapped[Optional['Class']] = relationship('Class', back_populates='items')"
+        ),
+        "{output}"
+    );
+    assert!(output.contains(
+        "metadata_: Mapped[Optional['Metadata']] = relationship('Metadata', back_populates='items')"
+    ), "{output}");
+    assert!(output
+        .contains("items: Mapped[list['Items']] = relationship('Items', back_populates='class_')"));
+    assert!(output.contains(
+        "items: Mapped[list['Items']] = relationship('Items', back_populates='metadata_')"
+    ));
+}
+
+#[test]
+fn test_declarative_back_populates_follows_renamed_relationship() {
+    let schema = schema_pg(vec![
+        table("simple_containers")
+            .column(col("id").build())
+            .column(col("simple_items").udt("varchar").nullable().build())
+            .pk("sc_pkey", &["id"])
+            .build(),
+        table("simple_items")
+            .column(col("id").build())
+            .column(col("container_id").nullable().build())
+            .pk("si_pkey", &["id"])
+            .fk("si_fkey", &["container_id"], "simple_containers", &["id"])
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "simple_items_: Mapped[list['SimpleItems']] = relationship('SimpleItems', back_populates='container')"
+    ), "{output}");
+    assert!(output.contains("relationship('SimpleContainers', back_populates='simple_items_')"));
+}
+
+#[test]
+fn test_declarative_mutual_foreign_keys_pair_by_constraint() {
+    let schema = schema_pg(vec![
+        table("a")
+            .column(col("id").build())
+            .column(col("b_id").nullable().build())
+            .pk("a_pkey", &["id"])
+            .fk("a_b_fkey", &["b_id"], "b", &["id"])
+            .build(),
+        table("b")
+            .column(col("id").build())
+            .column(col("a_id").nullable().build())
+            .pk("b_pkey", &["id"])
+            .fk("b_a_fkey", &["a_id"], "a", &["id"])
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "a: Mapped[list['A']] = relationship('A', foreign_keys='[A.b_id]', back_populates='b_')"
+    ), "{output}");
+    assert!(output.contains(
+        "a_: Mapped[Optional['A']] = relationship('A', foreign_keys=[a_id], back_populates='b')"
+    ));
+    assert!(output.contains(
+        "b: Mapped[list['B']] = relationship('B', foreign_keys='[B.a_id]', back_populates='a_')"
+    ));
+    assert!(output.contains(
+        "b_: Mapped[Optional['B']] = relationship('B', foreign_keys=[b_id], back_populates='a')"
+    ));
+}
+
+#[test]
+fn test_declarative_self_referential_association_joins_both_directions() {
+    let schema = schema_sqlite(vec![
+        table("t")
+            .schema("main")
+            .column(col("id").build())
+            .pk("t_pkey", &["id"])
+            .build(),
+        table("u")
+            .schema("main")
+            .column(col("t_id").build())
+            .column(col("t_id2").build())
+            .fk("u_t_fkey", &["t_id"], "t", &["id"])
+            .fk("u_t2_fkey", &["t_id2"], "t", &["id"])
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(
+        output.contains(
+            "t_id2: Mapped[list['T']] = relationship('T', secondary='u', \
+         primaryjoin='T.id == u.c.t_id', secondaryjoin='T.id == u.c.t_id2', back_populates='t')"
+        ),
+        "{output}"
+    );
+    assert!(output.contains(
+        "t: Mapped[list['T']] = relationship('T', secondary='u', \
+         primaryjoin='T.id == u.c.t_id2', secondaryjoin='T.id == u.c.t_id', back_populates='t_id2')"
+    ));
+}
+
+#[test]
+fn test_declarative_dangling_fk_keeps_column_without_relationship() {
+    // `--tables items` left `containers` out of the schema.
+    let schema = schema_pg(vec![table("items")
+        .column(col("id").build())
+        .column(col("container_id").nullable().build())
+        .pk("items_pkey", &["id"])
+        .fk(
+            "items_container_fkey",
+            &["container_id"],
+            "containers",
+            &["id"],
+        )
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "container_id: Mapped[Optional[int]] = mapped_column(ForeignKey('containers.id'))"
+    ));
+    assert!(!output.contains("relationship"), "{output}");
+}
+
+#[test]
+fn test_declarative_inline_fk_keeps_options() {
+    let schema = schema_pg(vec![
+        table("containers")
+            .column(col("id").build())
+            .pk("containers_pkey", &["id"])
+            .build(),
+        table("items")
+            .column(col("id").build())
+            .column(col("container_id").nullable().build())
+            .column(col("owner_id").nullable().build())
+            .pk("items_pkey", &["id"])
+            .fk_full(
+                "items_container_fkey",
+                &["container_id"],
+                "public",
+                "containers",
+                &["id"],
+                "CASCADE",
+                "SET NULL",
+            )
+            .fk_deferrable(
+                "items_owner_fkey",
+                &["owner_id"],
+                "containers",
+                &["id"],
+                true,
+            )
+            .build(),
+        table("tags")
+            .column(col("id").build())
+            .pk("tags_pkey", &["id"])
+            .build(),
+        table("item_tags")
+            .column(col("item_id").build())
+            .column(col("tag_id").build())
+            .fk_full(
+                "item_tags_item_fkey",
+                &["item_id"],
+                "public",
+                "items",
+                &["id"],
+                "NO ACTION",
+                "CASCADE",
+            )
+            .fk("item_tags_tag_fkey", &["tag_id"], "tags", &["id"])
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(
+        output.contains(
+            "mapped_column(ForeignKey('containers.id', ondelete='SET NULL', onupdate='CASCADE'))"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains(
+            "mapped_column(ForeignKey('containers.id', deferrable=True, initially='DEFERRED'))"
+        ),
+        "{output}"
+    );
+    assert!(output.contains("Column('item_id', ForeignKey('items.id', ondelete='CASCADE'))"));
+    assert!(output.contains("Column('tag_id', ForeignKey('tags.id'))"));
+}
@@ -0,0 +1,461 @@
+//! Django model generator (`--generator django`).
+//!
+//! Emits a `models.py` in the style of `manage.py inspectdb`: one
+//! `models.Model` per table or view, `ForeignKey` fields whose `on_delete`
+//! follows the database's delete rule, explicit `db_column` wherever
+//! Django's default column name would differ, and a `Meta` with `db_table`
+//! and `managed = False`. `--options managed` drops the `managed = False`
+//! line for tables (views always keep it).
+
+use std::collections::HashMap;
+
+use crate::cli::GeneratorOptions;
+use crate::codegen::python::format_python_string_literal;
+use crate::codegen::{has_primary_key, is_auto_increment_column, is_unique_constraint_index};
+use crate::ddl_typemap::{to_canonical, CanonicalType};
+use crate::dialect::Dialect;
+use crate::naming::{is_python_keyword, model_class_name, table_class_name};
+use crate::schema::{
+    ColumnInfo, ConstraintInfo, ConstraintType, IntrospectedSchema, TableInfo, TableType,
+};
+
+/// Generate `models.py` for `schema`.
+pub fn generate(schema: &IntrospectedSchema, options: &GeneratorOptions) -> String {
+    let uses_array_field = schema.dialect == Dialect::Postgres
+        && schema.tables.iter().any(|t| {
+            t.columns
+                .iter()
+                .any(|c| matches!(to_canonical(c, schema.dialect), CanonicalType::Array { .. }))
+        });
+
+    let mut out = vec!["# Review field types and on_delete behavior before use.".to_string()];
+    if uses_array_field {
+        out.push("from django.contrib.postgres.fields import ArrayField".to_string());
+    }
+    out.push("from django.db import models".to_string());
+
+    for table in &schema.tables {
+        out.push(String::new());
+        out.push(String::new());
+        render_model(&mut out, schema, table, options);
+    }
+
+    out.push(String::new());
+    out.join("\n")
+}
+
+fn render_model(
+    out: &mut Vec<String>,
+    schema: &IntrospectedSchema,
+    table: &TableInfo,
+    options: &GeneratorOptions,
+) {
+    let class_name = table_class_name(schema, table, options);
+    out.push(format!("class {class_name}(models.Model):"));
+
+    let pk_columns: Vec<&str> = table
+        .constraints
+        .iter()
+        .filter(|c| c.constraint_type == ConstraintType::PrimaryKey)
+        .flat_map(|c| c.columns.iter().map(String::as_str))
+        .collect();
+    let foreign_keys = single_column_foreign_keys(table);
+    let mut target_counts: HashMap<&str, usize> = HashMap::new();
+    for constraint in foreign_keys.values() {
+        if let Some(ref fk) = constraint.foreign_key {
+            *target_counts.entry(fk.ref_table.as_str()).or_default() += 1;
+        }
+    }
+
+    let attr_names: HashMap<&str, String> = table
+        .columns
+        .iter()
+        .map(|c| {
+            let is_relation = foreign_keys.contains_key(c.name.as_str());
+            (c.name.as_str(), field_name(&c.name, is_relation))
+        })
+        .collect();
+
+    if pk_columns.len() > 1 {
+        let fields: Vec<String> = pk_columns
+            .iter()
+            .map(|c| format_python_string_literal(&attr_names[c]))
+            .collect();
+        out.push(format!(
+            "    pk = models.CompositePrimaryKey({})",
+            fields.join(", ")
+        ));
+    }
+
+    for col in &table.columns {
+        let attr = &attr_names[col.name.as_str()];
+        let is_pk = pk_columns.len() == 1 && pk_columns[0] == col.name;
+        let line = match foreign_keys.get(col.name.as_str()) {
+            Some(constraint) => {
+                let fk = constraint.foreign_key.as_ref().expect("foreign key");
+                let target = match schema.referenced_table(fk) {
+                    Some(target) if std::ptr::eq(target, table) => "'self'".to_string(),
+                    Some(target) => {
+                        format_python_string_literal(&table_class_name(schema, target, options))
+                    }
+                    None => format_python_string_literal(&model_class_name(
+                        &fk.ref_table,
+                        options.use_inflect,
+                    )),
+                };
+                let mut args = vec![target, on_delete(&fk.delete_rule).to_string()];
+                if fk
+                    .ref_columns
+                    .first()
+                    .is_some_and(|c| !table_pk_is(schema, &fk.ref_schema, &fk.ref_table, c))
+                {
+                    args.push(format!(
+                        "to_field={}",
+                        format_python_string_literal(&fk.ref_columns[0])
+                    ));
+                }
+                if target_counts[fk.ref_table.as_str()] > 1 {
+                    args.push(format!(
+                        "related_name={}",
+                        format_python_string_literal(&related_name(&table.name, attr))
+                    ));
+                }
+                if format!("{attr}_id") != col.name {
+                    args.push(format!(
+                        "db_column={}",
+                        format_python_string_literal(&col.name)
+                    ));
+                }
+                args.extend(common_kwargs(table, col, is_pk, options));
+                let field = if is_pk { "OneToOneField" } else { "ForeignKey" };
+                format!("    {attr} = models.{field}({})", args.join(", "))
+            }
+            None => {
+                let (field, mut args, guessed) = field_type(schema, col, is_pk);
+                if *attr != col.name {
+                    args.push(format!(
+                        "db_column={}",
+                        format_python_string_literal(&col.name)
+                    ));
+                }
+                args.extend(common_kwargs(table, col, is_pk, options));
+                let comment = if guessed {
+                    "  # This field type is a guess."
+                } else {
+                    ""
+                };
+                format!("    {attr} = {field}({}){comment}", args.join(", "))
+            }
+        };
+        out.push(line);
+    }
+    if !has_primary_key(&table.constraints) {
+        out.push(
+            "    # No primary key in the database: Django will assume an `id` column.".to_string(),
+        );
+    }
+
+    out.push(String::new());
+    out.push("    class Meta:".to_string());
+    if table.table_type == TableType::View || !options.managed {
+        out.push("        managed = False".to_string());
+    }
+    out.push(format!(
+        "        db_table = {}",
+        format_python_string_literal(&db_table(schema, table))
+    ));
+    if let Some(comment) = table
+        .comment
+        .as_deref()
+        .filter(|_| options.table_comments())
+    {
+        out.push(format!(
+            "        db_table_comment = {}",
+            format_python_string_literal(comment)
+        ));
+    }
+
+    let field_names = |columns: &[String]| -> String {
+        columns
+            .iter()
+            .map(|c| {
+                let attr = attr_names.get(c.as_str()).map(String::as_str);
+                format_python_string_literal(attr.unwrap_or(c))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    if !options.noconstraints {
+        let unique_together: Vec<String> = table
+            .constraints
+            .iter()
+            .filter(|c| c.constraint_type == ConstraintType::Unique && c.columns.len() > 1)
+            .map(|c| format!("({})", field_names(&c.columns)))
+            .collect();
+        if !unique_together.is_empty() {
+            out.push(format!(
+                "        unique_together = ({},)",
+                unique_together.join(", ")
+            ));
+        }
+    }
+
+    if !options.noindexes {
+        let indexes: Vec<String> = table
+            .indexes
+            .iter()
+            .filter(|i| {
+                !i.has_expressions()
+                    && !i.columnstore
+                    && !is_unique_constraint_index(i, &table.constraints)
+            })
+            .map(|i| {
+                let name = format_python_string_literal(&i.name);
+                if i.is_unique {
+                    format!(
+                        "models.UniqueConstraint(fields=[{}], name={name})",
+                        field_names(&i.columns)
+                    )
+                } else {
+                    format!(
+                        "models.Index(fields=[{}], name={name})",
+                        field_names(&i.columns)
+                    )
+                }
+            })
+            .collect();
+        let (constraints, indexes): (Vec<String>, Vec<String>) = indexes
+            .into_iter()
+            .partition(|i| i.starts_with("models.UniqueConstraint"));
+        if !indexes.is_empty() {
+            out.push("        indexes = [".to_string());
+            out.extend(indexes.iter().map(|i| format!("            {i},")));
+            out.push("        ]".to_string());
+        }
+        if !constraints.is_empty() {
+            out.push("        constraints = [".to_string());
+            out.extend(constraints.iter().map(|c| format!("            {c},")));
+            out.push("        ]".to_string());
+        }
+    }
+}
+
+/// Foreign keys that Django can model as a `ForeignKey`: single-column
+/// ones, keyed by their local column. The first constraint on a column wins.
+fn single_column_foreign_keys(table: &TableInfo) -> HashMap<&str, &ConstraintInfo> {
+    let mut keys = HashMap::new();
+    for constraint in &table.constraints {
+        if constraint.constraint_type == ConstraintType::ForeignKey
+            && constraint.columns.len() == 1
+            && constraint.foreign_key.is_some()
+        {
+            keys.entry(constraint.columns[0].as_str())
+                .or_insert(constraint);
+        }
+    }
+    keys
+}
+
+/// `author_id` → `author`, as Django appends `_id` to the column itself.
+/// The field name for `column`, following `inspectdb`: a foreign key
+/// loses its `_id` suffix, characters outside identifiers become `_`,
+/// runs of `_` collapse (Django rejects `__` in field names), a leading
+/// `_` gets a `field` prefix, a trailing `_` a `field` suffix, a keyword
+/// gets `_field`, and a leading digit gets `number_`.
+fn field_name(column: &str, is_relation: bool) -> String {
+    let base = if is_relation {
+        column
+            .strip_suffix("_id")
+            .filter(|base| !base.is_empty())
+            .unwrap_or(column)
+    } else {
+        column
+    };
+    let mut name = collapse_underscores(base);
+    if name.is_empty() {
+        return "field".to_string();
+    }
+    if name.starts_with('_') {
+        name = format!("field{name}");
+    }
+    if name.ends_with('_') {
+        name.push_str("field");
+    }
+    if is_python_keyword(&name) {
+        name.push_str("_field");
+    }
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name = format!("number_{name}");
+    }
+    name
+}
+
+/// `related_name` for one of several foreign keys from `table` to the
+/// same model. Django rejects one containing `__` or ending in `_`.
+fn related_name(table: &str, field: &str) -> String {
+    collapse_underscores(&format!("{table}_{field}_set"))
+}
+
+/// `s` with characters outside identifiers replaced by `_` and runs of
+/// `_` collapsed to one.
+fn collapse_underscores(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.trim().chars() {
+        let c = if c.is_alphanumeric() { c } else { '_' };
+        if !(c == '_' && out.ends_with('_')) {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn table_pk_is(schema: &IntrospectedSchema, ref_schema: &str, ref_table: &str, col: &str) -> bool {
+    schema
+        .tables
+        .iter()
+        .find(|t| t.name == ref_table && (t.schema == ref_schema || ref_schema.is_empty()))
+        .is_none_or(|t| {
+            t.constraints.iter().any(|c| {
+                c.constraint_type == ConstraintType::PrimaryKey && c.columns == [col.to_string()]
+            })
+        })
+}
+
+fn on_delete(rule: &str) -> &'static str {
+    match rule.to_ascii_uppercase().as_str() {
+        "CASCADE" => "models.CASCADE",
+        "SET NULL" => "models.SET_NULL",
+        "SET DEFAULT" => "models.SET_DEFAULT",
+        "RESTRICT" => "models.RESTRICT",
+        _ => "models.DO_NOTHING",
+    }
+}
+
+/// `primary_key`, `unique`, `null`/`blank`, and `db_comment`.
+fn common_kwargs(
+    table: &TableInfo,
+    col: &ColumnInfo,
+    is_pk: bool,
+    options: &GeneratorOptions,
+) -> Vec<String> {
+    let mut kwargs = Vec::new();
+    if is_pk {
+        kwargs.push("primary_key=True".to_string());
+    } else if !options.noconstraints
+        && table
+            .constraints
+            .iter()
+            .any(|c| c.constraint_type == ConstraintType::Unique && c.columns == [col.name.clone()])
+    {
+        kwargs.push("unique=True".to_string());
+    }
+    if col.is_nullable {
+        kwargs.push("blank=True".to_string());
+        kwargs.push("null=True".to_string());
+    }
+    if let Some(comment) = col.comment.as_deref().filter(|_| options.column_comments()) {
+        kwargs.push(format!(
+            "db_comment={}",
+            format_python_string_literal(comment)
+        ));
+    }
+    kwargs
+}
+
+/// Django field class, its type-specific arguments, and whether the
+/// mapping is a guess.
+fn field_type(
+    schema: &IntrospectedSchema,
+    col: &ColumnInfo,
+    is_pk: bool,
+) -> (String, Vec<String>, bool) {
+    let auto = is_pk && is_auto_increment_column(col, schema.dialect);
+    let canonical = to_canonical(col, schema.dialect);
+    if let CanonicalType::Array { ref element } = canonical {
+        if schema.dialect == Dialect::Postgres {
+            let (base, args, guessed) = canonical_field(element, false);
+            return (
+                "ArrayField".to_string(),
+                vec![format!("{base}({})", args.join(", "))],
+                guessed,
+            );
+        }
+    }
+    canonical_field(&canonical, auto)
+}
+
+fn canonical_field(canonical: &CanonicalType, auto: bool) -> (String, Vec<String>, bool) {
+    let field = |name: &str| format!("models.{name}");
+    let (name, args, guessed): (&str, Vec<String>, bool) = match canonical {
+        CanonicalType::Boolean => ("BooleanField", vec![], false),
+        CanonicalType::SmallInt if auto => ("SmallAutoField", vec![], false),
+        CanonicalType::SmallInt => ("SmallIntegerField", vec![], false),
+        CanonicalType::Integer if auto => ("AutoField", vec![], false),
+        CanonicalType::Integer => ("IntegerField", vec![], false),
+        CanonicalType::BigInt if auto => ("BigAutoField", vec![], false),
+        CanonicalType::BigInt => ("BigIntegerField", vec![], false),
+        CanonicalType::Float | CanonicalType::Double => ("FloatField", vec![], false),
+        CanonicalType::Decimal { precision, scale } => (
+            "DecimalField",
+            vec![
+                format!("max_digits={}", precision.unwrap_or(65535)),
+                format!("decimal_places={}", scale.unwrap_or(65535)),
+            ],
+            precision.is_none(),
+        ),
+        CanonicalType::Varchar { length: Some(n) } | CanonicalType::Char { length: Some(n) } => {
+            ("CharField", vec![format!("max_length={n}")], false)
+        }
+        CanonicalType::Varchar { length: None } | CanonicalType::Text => {
+            ("TextField", vec![], false)
+        }
+        CanonicalType::Char { length: None } => ("CharField", vec!["max_length=1".into()], false),
+        CanonicalType::Bytes { .. } => ("BinaryField", vec![], false),
+        CanonicalType::Date => ("DateField", vec![], false),
+        CanonicalType::Time { .. } => ("TimeField", vec![], false),
+        CanonicalType::Timestamp { .. } => ("DateTimeField", vec![], false),
+        CanonicalType::Interval => ("DurationField", vec![], false),
+        CanonicalType::Uuid => ("UUIDField", vec![], false),
+        CanonicalType::Json | CanonicalType::Jsonb => ("JSONField", vec![], false),
+        CanonicalType::Enum { values } | CanonicalType::Set { values } => {
+            let max_length = if matches!(canonical, CanonicalType::Set { .. }) {
+                values.iter().map(String::len).sum::<usize>() + values.len().saturating_sub(1)
+            } else {
+                values.iter().map(String::len).max().unwrap_or(1)
+            };
+            let choices: Vec<String> = values
+                .iter()
+                .map(|v| {
+                    let literal = format_python_string_literal(v);
+                    format!("({literal}, {literal})")
+                })
+                .collect();
+            let mut args = vec![format!("max_length={}", max_length.max(1))];
+            if matches!(canonical, CanonicalType::Enum { .. }) {
+                args.push(format!("choices=[{}]", choices.join(", ")));
+            }
+            ("CharField", args, false)
+        }
+        CanonicalType::Array { .. } | CanonicalType::Raw { .. } => ("TextField", vec![], true),
+    };
+    (field(name), args, guessed)
+}
+
+/// `db_table` value. Tables outside PostgreSQL's default schema use the
+/// `schema"."table` form, which Django's identifier quoting turns into
+/// `"schema"."table"`.
+fn db_table(schema: &IntrospectedSchema, table: &TableInfo) -> String {
+    if schema.dialect == Dialect::Postgres
+        && !table.schema.is_empty()
+        && table.schema != schema.dialect.default_schema()
+    {
+        format!("{}\".\"{}", table.schema, table.name)
+    } else {
+        table.name.clone()
+    }
+}
+
+#[cfg(test)]
+#[path = "django_tests.rs"]
+mod tests;
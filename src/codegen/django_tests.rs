@@ -0,0 +1,157 @@
+use super::*;
+use crate::testutil::*;
+
+fn blog_schema() -> IntrospectedSchema {
+    schema_pg(vec![
+        table("users")
+            .column(col("id").identity().build())
+            .column(col("email").udt("varchar").max_length(320).build())
+            .column(col("bio").udt("text").nullable().comment("Profile").build())
+            .pk("users_pkey", &["id"])
+            .unique("users_email_key", &["email"])
+            .build(),
+        table("posts")
+            .column(col("id").udt("int8").identity().build())
+            .column(col("author_id").build())
+            .column(col("editor").nullable().build())
+            .column(col("class").udt("varchar").max_length(20).build())
+            .pk("posts_pkey", &["id"])
+            .fk_full(
+                "posts_author_id_fkey",
+                &["author_id"],
+                "public",
+                "users",
+                &["id"],
+                "NO ACTION",
+                "CASCADE",
+            )
+            .fk_full(
+                "posts_editor_fkey",
+                &["editor"],
+                "public",
+                "users",
+                &["id"],
+                "NO ACTION",
+                "SET NULL",
+            )
+            .index("ix_posts_class", &["class"], false)
+            .comment("Blog posts")
+            .build(),
+    ])
+}
+
+#[test]
+fn django_models_map_fields_and_foreign_keys() {
+    let output = generate(&blog_schema(), &GeneratorOptions::default());
+    assert!(output.starts_with(
+        "# Review field types and on_delete behavior before use.\n\
+         from django.db import models\n\n\nclass Users(models.Model):\n"
+    ));
+    assert!(output.contains(
+        "    id = models.AutoField(primary_key=True)\n    \
+         email = models.CharField(max_length=320, unique=True)\n    \
+         bio = models.TextField(blank=True, null=True, db_comment='Profile')\n\n    \
+         class Meta:\n        managed = False\n        db_table = 'users'\n"
+    ));
+    assert!(output.contains(
+        "    author = models.ForeignKey('Users', models.CASCADE, \
+         related_name='posts_author_set')\n"
+    ));
+    assert!(output.contains(
+        "    editor = models.ForeignKey('Users', models.SET_NULL, \
+         related_name='posts_editor_set', db_column='editor', blank=True, null=True)\n"
+    ));
+    assert!(
+        output.contains("    class_field = models.CharField(max_length=20, db_column='class')\n")
+    );
+    assert!(output.contains(
+        "        db_table_comment = 'Blog posts'\n        indexes = [\n            \
+         models.Index(fields=['class_field'], name='ix_posts_class'),\n        ]\n"
+    ));
+}
+
+#[test]
+fn django_managed_option_and_views() {
+    let schema = schema_pg(vec![
+        table("tags")
+            .column(col("id").build())
+            .pk("tags_pkey", &["id"])
+            .build(),
+        table("tag_counts")
+            .column(col("n").build())
+            .table_type(TableType::View)
+            .build(),
+    ]);
+    let options = GeneratorOptions {
+        managed: true,
+        ..Default::default()
+    };
+    let output = generate(&schema, &options);
+    assert!(output.contains("    class Meta:\n        db_table = 'tags'\n"));
+    assert!(output.contains(
+        "    # No primary key in the database: Django will assume an `id` column.\n\n    \
+         class Meta:\n        managed = False\n        db_table = 'tag_counts'\n"
+    ));
+}
+
+#[test]
+fn django_composite_keys_and_schema_qualified_tables() {
+    let schema = schema_pg(vec![table("memberships")
+        .schema("auth")
+        .column(col("group_id").build())
+        .column(col("user_id").build())
+        .column(col("role").udt("varchar").max_length(10).build())
+        .column(col("scope").udt("varchar").max_length(10).build())
+        .pk("memberships_pkey", &["group_id", "user_id"])
+        .unique("memberships_role_scope_key", &["role", "scope"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("    pk = models.CompositePrimaryKey('group_id', 'user_id')\n"));
+    assert!(output.contains("    group_id = models.IntegerField()\n"));
+    assert!(output.contains("        db_table = 'auth\".\"memberships'\n"));
+    assert!(output.contains("        unique_together = (('role', 'scope'),)\n"));
+}
+
+#[test]
+fn django_field_names_follow_inspectdb() {
+    let schema = schema_pg(vec![
+        table("classes")
+            .column(col("id").build())
+            .pk("classes_pkey", &["id"])
+            .build(),
+        table("items")
+            .column(col("id").build())
+            .column(col("from").udt("text").build())
+            .column(col("metadata").udt("text").build())
+            .column(col("total_").udt("text").build())
+            .column(col("_hidden").udt("text").build())
+            .column(col("unit__price").udt("text").build())
+            .column(col("2fa").udt("text").build())
+            .column(col("class_id").build())
+            .column(col("backup_class_id").build())
+            .pk("items_pkey", &["id"])
+            .fk("items_class_fkey", &["class_id"], "classes", &["id"])
+            .fk(
+                "items_backup_fkey",
+                &["backup_class_id"],
+                "classes",
+                &["id"],
+            )
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains("    from_field = models.TextField(db_column='from')\n"));
+    assert!(output.contains("    metadata = models.TextField()\n"));
+    assert!(output.contains("    total_field = models.TextField(db_column='total_')\n"));
+    assert!(output.contains("    field_hidden = models.TextField(db_column='_hidden')\n"));
+    assert!(output.contains("    unit_price = models.TextField(db_column='unit__price')\n"));
+    assert!(output.contains("    number_2fa = models.TextField(db_column='2fa')\n"));
+    assert!(
+        output.contains(
+            "    class_field = models.ForeignKey('Classes', models.DO_NOTHING, \
+             related_name='items_class_field_set', db_column='class_id')\n"
+        ),
+        "{output}"
+    );
+}
@@ -0,0 +1,175 @@
+//! Graphviz ER diagram generator (`--generator dot`).
+//!
+//! Each table becomes a record-shaped node listing its columns and types;
+//! each foreign key becomes an edge from the referencing column to the
+//! referenced one. `--focus` limits the diagram to the named tables plus
+//! every table within `--focus-depth` FK hops of them, in either direction.
+
+use std::collections::BTreeSet;
+
+use crate::codegen::graph::{fk_closure, TableLookup};
+use crate::codegen::is_primary_key_column;
+use crate::error::UvgError;
+use crate::schema::{ConstraintType, IntrospectedSchema, TableInfo, TableType};
+
+/// Reject `--focus` entries that name no table of `schema`.
+pub fn check_focus(schema: &IntrospectedSchema, focus: &[String]) -> Result<(), UvgError> {
+    match focus
+        .iter()
+        .find(|name| !schema.tables.iter().any(|t| focus_matches(t, name)))
+    {
+        Some(name) => Err(UvgError::Usage(format!(
+            "--focus table `{name}` not found in the schema"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Generate a DOT digraph for `schema`. An empty `focus` draws every table.
+pub fn generate(schema: &IntrospectedSchema, focus: &[String], depth: usize) -> String {
+    let lookup = TableLookup::new(&schema.tables);
+    let drawn = if focus.is_empty() {
+        (0..schema.tables.len()).collect()
+    } else {
+        neighborhood(&schema.tables, focus, depth)
+    };
+
+    let mut out = vec![
+        "digraph schema {".to_string(),
+        "    graph [rankdir=LR];".to_string(),
+        "    node [shape=record, fontname=\"Helvetica\", fontsize=10];".to_string(),
+        "    edge [fontname=\"Helvetica\", fontsize=9];".to_string(),
+    ];
+
+    for &idx in &drawn {
+        out.push(String::new());
+        out.push(table_node(&schema.tables[idx]));
+    }
+
+    let mut edges = Vec::new();
+    for &idx in &drawn {
+        let table = &schema.tables[idx];
+        for constraint in &table.constraints {
+            if constraint.constraint_type != ConstraintType::ForeignKey {
+                continue;
+            }
+            let Some(ref fk) = constraint.foreign_key else {
+                continue;
+            };
+            let Some(ref_idx) = lookup.get(fk) else {
+                continue;
+            };
+            if !drawn.contains(&ref_idx) {
+                continue;
+            }
+            let referenced = &schema.tables[ref_idx];
+            let from = port(table, constraint.columns.first());
+            let to = port(referenced, fk.ref_columns.first());
+            edges.push(format!(
+                "    {from} -> {to} [label=\"{}\"];",
+                escape_quoted(&constraint.name)
+            ));
+        }
+    }
+    if !edges.is_empty() {
+        out.push(String::new());
+        out.extend(edges);
+    }
+
+    out.push("}".to_string());
+    out.push(String::new());
+    out.join("\n")
+}
+
+/// Indexes of the focused tables plus everything within `depth` FK hops,
+/// in schema order. Focus entries match `table` or `schema.table`.
+fn neighborhood(tables: &[TableInfo], focus: &[String], depth: usize) -> BTreeSet<usize> {
+    let seeds = tables
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| focus.iter().any(|name| focus_matches(t, name)))
+        .map(|(i, _)| i);
+    fk_closure(tables, seeds, Some(depth), true)
+}
+
+fn focus_matches(table: &TableInfo, name: &str) -> bool {
+    table.name == name || format!("{}.{}", table.schema, table.name) == name
+}
+
+fn node_id(table: &TableInfo) -> String {
+    let name = if table.schema.is_empty() {
+        table.name.clone()
+    } else {
+        format!("{}.{}", table.schema, table.name)
+    };
+    format!("\"{}\"", escape_quoted(&name))
+}
+
+/// Edge endpoint: the column's record port when known, else the node.
+fn port(table: &TableInfo, column: Option<&String>) -> String {
+    let id = node_id(table);
+    match column.and_then(|name| table.columns.iter().position(|c| &c.name == name)) {
+        Some(pos) => format!("{id}:c{pos}"),
+        None => id,
+    }
+}
+
+fn table_node(table: &TableInfo) -> String {
+    let fk_columns: BTreeSet<&str> = table
+        .constraints
+        .iter()
+        .filter(|c| c.constraint_type == ConstraintType::ForeignKey)
+        .flat_map(|c| c.columns.iter().map(String::as_str))
+        .collect();
+    let fields: Vec<String> = table
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(pos, col)| {
+            let mut field = format!("{} : {}", col.name, col.type_label());
+            if is_primary_key_column(&col.name, &table.constraints) {
+                field.push_str(" PK");
+            }
+            if fk_columns.contains(col.name.as_str()) {
+                field.push_str(" FK");
+            }
+            format!("<c{pos}> {}\\l", escape_record(&field))
+        })
+        .collect();
+
+    let mut label = escape_record(&table.name);
+    if !fields.is_empty() {
+        label = format!("{label}|{}", fields.join("|"));
+    }
+    let style = match table.table_type {
+        TableType::Table => "",
+        TableType::View => ", style=dashed",
+    };
+    format!(
+        "    {} [label=\"{{{}}}\"{style}];",
+        node_id(table),
+        escape_quoted(&label)
+    )
+}
+
+/// Escape characters that structure a record label.
+fn escape_record(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '{' | '}' | '|' | '<' | '>' | '\\') {
+            out.push('\\');
+        }
+        out.push(ch);
+    }
+    out
+}
+
+/// Escape a DOT double-quoted string. Backslashes are left alone so record
+/// escapes and `\l` line endings survive.
+fn escape_quoted(text: &str) -> String {
+    text.replace('"', "\\\"")
+}
+
+#[cfg(test)]
+#[path = "dot_tests.rs"]
+mod tests;
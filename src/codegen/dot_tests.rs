@@ -0,0 +1,100 @@
+use super::*;
+use crate::testutil::*;
+use indoc::indoc;
+
+fn shop_schema() -> IntrospectedSchema {
+    schema_pg(vec![
+        table("categories")
+            .column(col("id").build())
+            .pk("categories_pkey", &["id"])
+            .build(),
+        table("orders")
+            .column(col("id").build())
+            .column(col("user_id").build())
+            .pk("orders_pkey", &["id"])
+            .fk("orders_user_id_fkey", &["user_id"], "users", &["id"])
+            .build(),
+        table("products")
+            .column(col("id").build())
+            .column(col("category_id").build())
+            .pk("products_pkey", &["id"])
+            .fk(
+                "products_category_id_fkey",
+                &["category_id"],
+                "categories",
+                &["id"],
+            )
+            .build(),
+        table("users")
+            .column(col("id").build())
+            .column(col("name").udt("varchar").max_length(50).build())
+            .pk("users_pkey", &["id"])
+            .build(),
+    ])
+}
+
+#[test]
+fn dot_renders_record_nodes_and_fk_edges() {
+    assert_eq!(
+        generate(&shop_schema(), &[], 1),
+        indoc! {r#"
+            digraph schema {
+                graph [rankdir=LR];
+                node [shape=record, fontname="Helvetica", fontsize=10];
+                edge [fontname="Helvetica", fontsize=9];
+
+                "public.categories" [label="{categories|<c0> id : int4 PK\l}"];
+
+                "public.orders" [label="{orders|<c0> id : int4 PK\l|<c1> user_id : int4 FK\l}"];
+
+                "public.products" [label="{products|<c0> id : int4 PK\l|<c1> category_id : int4 FK\l}"];
+
+                "public.users" [label="{users|<c0> id : int4 PK\l|<c1> name : varchar(50)\l}"];
+
+                "public.orders":c1 -> "public.users":c0 [label="orders_user_id_fkey"];
+                "public.products":c1 -> "public.categories":c0 [label="products_category_id_fkey"];
+            }
+        "#}
+    );
+}
+
+#[test]
+fn dot_focus_on_missing_table_is_a_usage_error() {
+    let schema = shop_schema();
+    assert!(check_focus(&schema, &["users".to_string(), "public.orders".to_string()]).is_ok());
+    let err = check_focus(&schema, &["nosuch".to_string()]).unwrap_err();
+    assert_eq!(err.kind(), crate::error::ErrorKind::Usage);
+    assert_eq!(
+        err.to_string(),
+        "--focus table `nosuch` not found in the schema"
+    );
+}
+
+#[test]
+fn dot_focus_limits_to_fk_neighborhood() {
+    let focus = vec!["users".to_string()];
+    let output = generate(&shop_schema(), &focus, 1);
+    assert!(output.contains("\"public.users\" [label"));
+    assert!(output.contains("\"public.orders\" [label"));
+    assert!(!output.contains("\"public.products\""), "{output}");
+    assert!(!output.contains("\"public.categories\""), "{output}");
+
+    let output = generate(&shop_schema(), &focus, 0);
+    assert!(!output.contains("\"public.orders\""), "{output}");
+    assert!(!output.contains(" -> "), "{output}");
+}
+
+#[test]
+fn dot_escapes_record_metacharacters_and_dashes_views() {
+    let schema = schema_pg(vec![table("odd|name")
+        .column(col("a{b}").build())
+        .table_type(TableType::View)
+        .build()]);
+    let output = generate(&schema, &[], 1);
+    assert!(
+        output.contains(
+            "    \"public.odd|name\" [label=\"{odd\\|name|<c0> a\\{b\\} : int4\\l}\", style=dashed];"
+        ),
+        "{output}"
+    );
+}
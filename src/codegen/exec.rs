@@ -0,0 +1,60 @@
+//! External generator plugins (`--generator exec:PROGRAM [ARGS...]`).
+//!
+//! The program gets the schema on stdin, as the JSON document
+//! `--generator json` writes, and its stdout becomes the output. Its stderr
+//! passes through to uvg's. A non-zero exit fails the run. The command is
+//! split on whitespace and run directly, not through a shell.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::error::UvgError;
+
+/// The plugin command named by a `--generator` value, if it is one.
+pub fn plugin_command(generator: &str) -> Option<&str> {
+    generator.strip_prefix("exec:")
+}
+
+/// Run `command` with `schema_json` on its stdin and return its stdout.
+pub fn generate(command: &str, schema_json: &str) -> Result<String, UvgError> {
+    let fail = |reason: String| UvgError::Plugin {
+        command: command.to_string(),
+        reason,
+    };
+    let mut words = command.split_whitespace();
+    let program = words
+        .next()
+        .ok_or_else(|| fail("no program given".to_string()))?;
+    let mut child = Command::new(program)
+        .args(words)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| fail(format!("cannot start: {e}")))?;
+
+    // Feed stdin from another thread: a plugin that writes output before it
+    // has read all of its input would otherwise deadlock against us.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let input = schema_json.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| fail(format!("cannot read output: {e}")))?;
+    // A plugin may legitimately exit without reading stdin; only its exit
+    // status decides success.
+    let _ = writer.join();
+
+    if !output.status.success() {
+        return Err(fail(match output.status.code() {
+            Some(code) => format!("exited with status {code}"),
+            None => "killed by a signal".to_string(),
+        }));
+    }
+    String::from_utf8(output.stdout).map_err(|_| fail("wrote output that is not UTF-8".to_string()))
+}
+
+#[cfg(test)]
+#[path = "exec_tests.rs"]
+mod tests;
@@ -0,0 +1,30 @@
+use super::*;
+
+#[test]
+fn plugin_command_is_the_text_after_exec() {
+    assert_eq!(plugin_command("exec:./gen --flag"), Some("./gen --flag"));
+    assert_eq!(plugin_command("declarative"), None);
+}
+
+#[cfg(unix)]
+#[test]
+fn stdin_is_piped_through_to_stdout() {
+    let output = generate("cat", "{\"tables\":[]}").unwrap();
+    assert_eq!(output, "{\"tables\":[]}");
+}
+
+#[cfg(unix)]
+#[test]
+fn failing_plugins_report_their_exit_status() {
+    let err = generate("false", "{}").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Generator plugin `false` failed: exited with status 1"
+    );
+}
+
+#[test]
+fn missing_programs_fail_to_start() {
+    let err = generate("./no-such-uvg-plugin", "{}").unwrap_err();
+    assert!(err.to_string().contains("cannot start"), "{err}");
+}
@@ -0,0 +1,271 @@
+//! FastAPI scaffolding generator (`--generator fastapi`).
+//!
+//! For every declarative model, emits Pydantic `Create`/`Read` schemas and
+//! an `APIRouter` with list / get / create / update / delete endpoints. The
+//! output imports the models from a sibling `models` module (the
+//! declarative generator's output) and expects the application to provide
+//! a `get_session` dependency in `database`; a module-level `router`
+//! includes every per-model router.
+
+use std::collections::HashMap;
+
+use heck::ToSnakeCase;
+
+use crate::cli::GeneratorOptions;
+use crate::codegen::declarative::class_attr_names;
+use crate::codegen::imports::ImportCollector;
+use crate::codegen::python::PythonOutput;
+use crate::codegen::relationships::renders_as_association;
+use crate::codegen::{
+    column_python_type, format_python_string_literal, has_primary_key, is_auto_increment_column,
+    topo_sort_tables,
+};
+use crate::naming::table_class_name;
+use crate::schema::{ConstraintType, IntrospectedSchema, TableInfo};
+
+/// Block name of the module-level `router` that includes every model router.
+const AGGREGATE_MODULE: &str = "router";
+
+/// Generate the FastAPI scaffold as a single file.
+pub fn generate(schema: &IntrospectedSchema, options: &GeneratorOptions) -> String {
+    parts(schema, options).render()
+}
+
+/// Generate the FastAPI scaffold split one file per model router. The
+/// aggregate `router` is left out: each module's `<model>_router` is
+/// re-exported from the package `__init__.py` instead.
+pub fn generate_split(
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
+) -> Vec<(String, String)> {
+    let mut output = parts(schema, options);
+    output
+        .models
+        .retain(|(module, _)| module != AGGREGATE_MODULE);
+    output.split_with(options)
+}
+
+fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutput {
+    let mut imports = ImportCollector::new();
+    imports.add("typing", "List");
+    imports.add("sqlalchemy", "select");
+    imports.add("sqlalchemy.orm", "Session");
+
+    let mut model_names: Vec<String> = Vec::new();
+    let mut blocks: Vec<(String, String)> = Vec::new();
+    let mut schemas: HashMap<String, String> = HashMap::new();
+
+    for table in topo_sort_tables(&schema.tables) {
+        if !has_primary_key(&table.constraints) || renders_as_association(table, options) {
+            continue;
+        }
+        let class_name = table_class_name(schema, table, options);
+        let block = generate_model_api(table, &class_name, schema, options, &mut imports);
+        let module = format!("{}_api", class_name.to_snake_case());
+        schemas.insert(module.clone(), table.schema.clone());
+        blocks.push((module, block));
+        model_names.push(class_name);
+    }
+
+    if !model_names.is_empty() {
+        let mut lines = vec!["router = APIRouter()".to_string()];
+        for name in &model_names {
+            lines.push(format!(
+                "router.include_router({}_router)",
+                name.to_snake_case()
+            ));
+        }
+        blocks.push((AGGREGATE_MODULE.to_string(), lines.join("\n")));
+    }
+
+    // Third-party imports stay alphabetical: fastapi and pydantic go ahead
+    // of the sqlalchemy block the collector renders.
+    let rendered = imports.render();
+    let mut import_lines: Vec<&str> = rendered.lines().collect();
+    let third_party = import_lines
+        .iter()
+        .position(|l| l.starts_with("from sqlalchemy"))
+        .unwrap_or(import_lines.len());
+    import_lines.insert(third_party, "from pydantic import BaseModel, ConfigDict");
+    import_lines.insert(
+        third_party,
+        "from fastapi import APIRouter, Depends, HTTPException",
+    );
+    let mut prelude = import_lines.join("\n");
+    prelude.push_str("\n\nfrom database import get_session");
+    if !model_names.is_empty() {
+        model_names.sort();
+        prelude.push_str("\nfrom models import (\n");
+        for name in &model_names {
+            prelude.push_str(&format!("    {name},\n"));
+        }
+        prelude.push(')');
+    }
+
+    PythonOutput {
+        prelude,
+        models: blocks,
+        separator: "\n\n\n",
+        links: Default::default(),
+        schemas,
+    }
+}
+
+fn generate_model_api(
+    table: &TableInfo,
+    class_name: &str,
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
+    imports: &mut ImportCollector,
+) -> String {
+    let dialect = schema.dialect;
+    let attr_names = class_attr_names(table, dialect, options);
+    let snake = class_name.to_snake_case();
+    let router = format!("{snake}_router");
+
+    // Pydantic field lines for the read and create schemas.
+    let mut create_fields: Vec<String> = Vec::new();
+    let mut read_fields: Vec<String> = Vec::new();
+    for (idx, col) in table.columns.iter().enumerate() {
+        let python_type = column_python_type(col, dialect, options, imports);
+        let attr = &attr_names[idx];
+        let field = if col.is_nullable {
+            imports.add("typing", "Optional");
+            format!("    {attr}: Optional[{python_type}] = None")
+        } else {
+            format!("    {attr}: {python_type}")
+        };
+        read_fields.push(field.clone());
+        if is_auto_increment_column(col, dialect) {
+            continue;
+        }
+        // Server-side defaults may be omitted by the client; `exclude_unset`
+        // below keeps them out of the INSERT.
+        if col.column_default.is_some() && !col.is_nullable {
+            imports.add("typing", "Optional");
+            create_fields.push(format!("    {attr}: Optional[{python_type}] = None"));
+        } else {
+            create_fields.push(field);
+        }
+    }
+    if create_fields.is_empty() {
+        create_fields.push("    pass".to_string());
+    }
+
+    let pk_params: Vec<(String, String)> = table
+        .constraints
+        .iter()
+        .find(|c| c.constraint_type == ConstraintType::PrimaryKey)
+        .map(|c| c.columns.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|pk| {
+            let idx = table.columns.iter().position(|c| c.name == *pk)?;
+            let python_type = column_python_type(&table.columns[idx], dialect, options, imports);
+            Some((attr_names[idx].clone(), python_type))
+        })
+        .collect();
+    let path = format_python_string_literal(
+        &pk_params
+            .iter()
+            .map(|(name, _)| format!("/{{{name}}}"))
+            .collect::<String>(),
+    );
+    let pk_args = pk_params
+        .iter()
+        .map(|(name, ty)| format!("{name}: {ty}, "))
+        .collect::<String>();
+    let ident = match pk_params.as_slice() {
+        [(name, _)] => name.clone(),
+        many => format!(
+            "({})",
+            many.iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let order_by = pk_params
+        .iter()
+        .map(|(name, _)| format!("{class_name}.{name}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let session = "session: Session = Depends(get_session)";
+    let not_found = format!(
+        "        raise HTTPException(status_code=404, detail={})",
+        format_python_string_literal(&format!("{class_name} not found"))
+    );
+
+    let mut lines = vec![format!("class {class_name}Create(BaseModel):")];
+    lines.extend(create_fields);
+    lines.extend([
+        String::new(),
+        String::new(),
+        format!("class {class_name}Read(BaseModel):"),
+        "    model_config = ConfigDict(from_attributes=True)".to_string(),
+        String::new(),
+    ]);
+    lines.extend(read_fields);
+    lines.extend([
+        String::new(),
+        String::new(),
+        format!(
+            "{router} = APIRouter(prefix={}, tags=[{}])",
+            format_python_string_literal(&format!("/{}", table.name)),
+            format_python_string_literal(&table.name)
+        ),
+        String::new(),
+        String::new(),
+        format!("@{router}.get('', response_model=List[{class_name}Read])"),
+        format!(
+            "def list_{snake}(limit: int = 100, offset: int = 0, {session}) -> List[{class_name}]:"
+        ),
+        format!("    stmt = select({class_name}).order_by({order_by}).limit(limit).offset(offset)"),
+        "    return list(session.scalars(stmt))".to_string(),
+        String::new(),
+        String::new(),
+        format!("@{router}.get({path}, response_model={class_name}Read)"),
+        format!("def get_{snake}({pk_args}{session}) -> {class_name}:"),
+        format!("    obj = session.get({class_name}, {ident})"),
+        "    if obj is None:".to_string(),
+        not_found.clone(),
+        "    return obj".to_string(),
+        String::new(),
+        String::new(),
+        format!("@{router}.post('', response_model={class_name}Read, status_code=201)"),
+        format!("def create_{snake}(payload: {class_name}Create, {session}) -> {class_name}:"),
+        format!("    obj = {class_name}(**payload.model_dump(exclude_unset=True))"),
+        "    session.add(obj)".to_string(),
+        "    session.commit()".to_string(),
+        "    session.refresh(obj)".to_string(),
+        "    return obj".to_string(),
+        String::new(),
+        String::new(),
+        format!("@{router}.put({path}, response_model={class_name}Read)"),
+        format!(
+            "def update_{snake}({pk_args}payload: {class_name}Create, {session}) -> {class_name}:"
+        ),
+        format!("    obj = session.get({class_name}, {ident})"),
+        "    if obj is None:".to_string(),
+        not_found.clone(),
+        "    for key, value in payload.model_dump(exclude_unset=True).items():".to_string(),
+        "        setattr(obj, key, value)".to_string(),
+        "    session.commit()".to_string(),
+        "    session.refresh(obj)".to_string(),
+        "    return obj".to_string(),
+        String::new(),
+        String::new(),
+        format!("@{router}.delete({path}, status_code=204)"),
+        format!("def delete_{snake}({pk_args}{session}) -> None:"),
+        format!("    obj = session.get({class_name}, {ident})"),
+        "    if obj is None:".to_string(),
+        not_found,
+        "    session.delete(obj)".to_string(),
+        "    session.commit()".to_string(),
+    ]);
+    lines.join("\n")
+}
+
+#[cfg(test)]
+#[path = "fastapi_tests.rs"]
+mod tests;
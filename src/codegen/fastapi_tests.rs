@@ -0,0 +1,83 @@
+use super::*;
+use crate::testutil::*;
+
+fn users_schema() -> IntrospectedSchema {
+    schema_pg(vec![table("users")
+        .column(col("id").identity().build())
+        .column(col("email").udt("varchar").max_length(255).build())
+        .column(col("bio").udt("text").nullable().build())
+        .column(
+            col("created_at")
+                .udt("timestamptz")
+                .default_val("now()")
+                .build(),
+        )
+        .pk("users_pkey", &["id"])
+        .build()])
+}
+
+#[test]
+fn fastapi_imports_and_dependencies() {
+    let output = generate(&users_schema(), &GeneratorOptions::default());
+    assert!(output.starts_with(
+        "from typing import List, Optional\nimport datetime\n\n\
+         from fastapi import APIRouter, Depends, HTTPException\n\
+         from pydantic import BaseModel, ConfigDict\n\
+         from sqlalchemy import select\n\
+         from sqlalchemy.orm import Session\n\n\
+         from database import get_session\n\
+         from models import (\n    Users,\n)"
+    ));
+}
+
+#[test]
+fn fastapi_create_schema_skips_identity_and_relaxes_defaults() {
+    let output = generate(&users_schema(), &GeneratorOptions::default());
+    assert!(output.contains(
+        "class UsersCreate(BaseModel):\n    email: str\n    bio: Optional[str] = None\n    \
+         created_at: Optional[datetime.datetime] = None\n"
+    ));
+    assert!(output.contains(
+        "class UsersRead(BaseModel):\n    model_config = ConfigDict(from_attributes=True)\n\n    \
+         id: int\n    email: str\n"
+    ));
+}
+
+#[test]
+fn fastapi_router_has_crud_endpoints() {
+    let output = generate(&users_schema(), &GeneratorOptions::default());
+    assert!(output.contains("users_router = APIRouter(prefix='/users', tags=['users'])"));
+    assert!(output.contains("@users_router.get('', response_model=List[UsersRead])"));
+    assert!(output.contains(
+        "@users_router.get('/{id}', response_model=UsersRead)\n\
+         def get_users(id: int, session: Session = Depends(get_session)) -> Users:"
+    ));
+    assert!(output.contains("@users_router.post('', response_model=UsersRead, status_code=201)"));
+    assert!(output.contains("    obj = Users(**payload.model_dump(exclude_unset=True))"));
+    assert!(output.contains("@users_router.put('/{id}', response_model=UsersRead)"));
+    assert!(output.contains("@users_router.delete('/{id}', status_code=204)"));
+    assert!(
+        output.contains("        raise HTTPException(status_code=404, detail='Users not found')")
+    );
+    assert!(output.ends_with("router = APIRouter()\nrouter.include_router(users_router)\n"));
+}
+
+#[test]
+fn fastapi_composite_key_path() {
+    let schema = schema_pg(vec![table("order_lines")
+        .column(col("order_id").build())
+        .column(col("line_no").build())
+        .pk("order_lines_pkey", &["order_id", "line_no"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("@order_lines_router.get('/{order_id}/{line_no}'"));
+    assert!(output.contains("    obj = session.get(OrderLines, (order_id, line_no))"));
+}
+
+#[test]
+fn fastapi_split_omits_aggregate_router() {
+    let files = generate_split(&users_schema(), &GeneratorOptions::default());
+    let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
+    // The prelude is only imports, so there is no base.py.
+    assert_eq!(names, vec!["users_api.py", "__init__.py"]);
+}
@@ -0,0 +1,181 @@
+//! Pytest fixture generator (`--generator test-fixtures`).
+//!
+//! Emits a `conftest.py` that builds an engine, creates every generated
+//! table, and yields a transaction-scoped `session` fixture. By default the
+//! engine is in-memory SQLite; `TEST_DATABASE_URL` points it at a real
+//! server (e.g. a PostgreSQL test container) instead.
+//!
+//! For SQLite, dialect-specific column types get `@compiles` substitutions,
+//! non-default schemas are translated away, and server defaults are
+//! dropped, since those are the three things that stop a PostgreSQL/MSSQL
+//! model from running `create_all` on SQLite.
+
+use std::collections::BTreeSet;
+
+use crate::cli::GeneratorOptions;
+use crate::codegen::has_primary_key;
+use crate::codegen::imports::ImportCollector;
+use crate::codegen::map_column_type_with;
+use crate::codegen::python::format_python_string_literal;
+use crate::schema::IntrospectedSchema;
+
+/// Generate `conftest.py` for the models generated from `schema`.
+pub fn generate(schema: &IntrospectedSchema, options: &GeneratorOptions) -> String {
+    let mut imports = ImportCollector::new();
+    imports.add_bare("os");
+    imports.add("typing", "Iterator");
+    imports.add("sqlalchemy", "create_engine");
+    imports.add("sqlalchemy.engine", "Engine");
+    imports.add("sqlalchemy.orm", "Session");
+    imports.add("sqlalchemy.pool", "StaticPool");
+
+    let dialect_types = dialect_types(schema, options);
+    if !dialect_types.is_empty() {
+        imports.add("sqlalchemy.ext.compiler", "compiles");
+    }
+    for (module, name) in &dialect_types {
+        imports.add(module, name);
+    }
+
+    let rendered = imports.render();
+    let mut import_lines: Vec<&str> = rendered.lines().collect();
+    let third_party = import_lines
+        .iter()
+        .position(|l| l.starts_with("from sqlalchemy"))
+        .unwrap_or(import_lines.len());
+    import_lines.insert(third_party, "import pytest");
+
+    let mut out = vec![import_lines.join("\n"), String::new()];
+
+    let has_any_pk = schema
+        .tables
+        .iter()
+        .any(|t| has_primary_key(&t.constraints));
+    if has_any_pk {
+        // Import through `models` even for `--base-class`, so the tables
+        // are registered on the Base's metadata before `create_all`.
+        let base = options.base_name();
+        out.push(format!("from models import {base}"));
+        out.push(String::new());
+        out.push(format!("metadata = {base}.metadata"));
+    } else {
+        out.push("from models import metadata".to_string());
+    }
+    out.push("TEST_DATABASE_URL = os.environ.get('TEST_DATABASE_URL', 'sqlite://')".to_string());
+
+    let default_schema = schema.dialect.default_schema();
+    let schemas: BTreeSet<&str> = schema
+        .tables
+        .iter()
+        .map(|t| t.schema.as_str())
+        .filter(|s| !s.is_empty() && *s != default_schema)
+        .collect();
+    let translate_map = schemas
+        .iter()
+        .map(|s| format!("{}: None", format_python_string_literal(s)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    for (_, name) in &dialect_types {
+        out.push(String::new());
+        out.push(String::new());
+        out.push(format!("@compiles({name}, 'sqlite')"));
+        out.push(format!(
+            "def _compile_{}_sqlite(type_, compiler, **kw):",
+            name.to_lowercase()
+        ));
+        out.push(format!("    return '{}'", sqlite_substitute(name)));
+    }
+
+    out.extend(
+        [
+            "",
+            "",
+            "@pytest.fixture(scope='session')",
+            "def engine() -> Iterator[Engine]:",
+            "    if TEST_DATABASE_URL.startswith('sqlite'):",
+            "        engine = create_engine(",
+            "            TEST_DATABASE_URL,",
+            "            connect_args={'check_same_thread': False},",
+            "            poolclass=StaticPool,",
+        ]
+        .map(String::from),
+    );
+    if !translate_map.is_empty() {
+        out.push(format!(
+            "            execution_options={{'schema_translate_map': {{{translate_map}}}}},"
+        ));
+    }
+    out.extend(
+        [
+            "        )",
+            "        # Server defaults are written in the source dialect's SQL.",
+            "        for table in metadata.tables.values():",
+            "            for column in table.columns:",
+            "                column.server_default = None",
+            "    else:",
+            "        engine = create_engine(TEST_DATABASE_URL)",
+            "    metadata.create_all(engine)",
+            "    yield engine",
+            "    metadata.drop_all(engine)",
+            "    engine.dispose()",
+            "",
+            "",
+            "@pytest.fixture()",
+            "def session(engine: Engine) -> Iterator[Session]:",
+            "    connection = engine.connect()",
+            "    transaction = connection.begin()",
+            "    session = Session(bind=connection)",
+            "    try:",
+            "        yield session",
+            "    finally:",
+            "        session.close()",
+            "        transaction.rollback()",
+            "        connection.close()",
+            "",
+        ]
+        .map(String::from),
+    );
+    out.join("\n")
+}
+
+/// Dialect-module types (`sqlalchemy.dialects.*`) referenced by the models,
+/// which the SQLite compiler may not know how to render.
+fn dialect_types(
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
+) -> BTreeSet<(String, String)> {
+    let mut types = BTreeSet::new();
+    for table in &schema.tables {
+        for col in &table.columns {
+            let mapped = map_column_type_with(col, schema.dialect, options);
+            for (module, name) in std::iter::once((mapped.import_module, mapped.import_name))
+                .chain(mapped.extra_imports)
+            {
+                if module.starts_with("sqlalchemy.dialects.") {
+                    types.insert((module, name));
+                }
+            }
+        }
+    }
+    types
+}
+
+/// SQLite column type used in place of a dialect-specific type.
+fn sqlite_substitute(type_name: &str) -> &'static str {
+    match type_name {
+        "JSON" | "JSONB" | "ARRAY" | "HSTORE" => "JSON",
+        "UUID" | "UNIQUEIDENTIFIER" => "CHAR(36)",
+        "BIT" | "BOOLEAN" | "TINYINT" | "SMALLINT" | "MEDIUMINT" | "INTEGER" | "BIGINT"
+        | "YEAR" => "INTEGER",
+        "DOUBLE" | "DOUBLE_PRECISION" | "FLOAT" | "REAL" => "REAL",
+        "MONEY" | "SMALLMONEY" | "NUMERIC" | "DECIMAL" => "NUMERIC",
+        "BYTEA" | "BINARY" | "VARBINARY" | "IMAGE" | "BLOB" | "TINYBLOB" | "MEDIUMBLOB"
+        | "LONGBLOB" => "BLOB",
+        _ => "TEXT",
+    }
+}
+
+#[cfg(test)]
+#[path = "fixtures_tests.rs"]
+mod tests;
@@ -0,0 +1,82 @@
+use super::*;
+use crate::testutil::*;
+
+fn orders_schema() -> IntrospectedSchema {
+    schema_pg(vec![table("orders")
+        .schema("sales")
+        .column(col("id").udt("uuid").build())
+        .column(col("payload").udt("jsonb").nullable().build())
+        .column(col("note").udt("text").nullable().build())
+        .pk("orders_pkey", &["id"])
+        .build()])
+}
+
+#[test]
+fn fixtures_imports_models_and_dialect_types() {
+    let output = generate(&orders_schema(), &GeneratorOptions::default());
+    assert!(output.starts_with(
+        "from typing import Iterator\nimport os\n\n\
+         import pytest\n\
+         from sqlalchemy import create_engine\n"
+    ));
+    assert!(output.contains("from sqlalchemy.dialects.postgresql import JSONB, UUID\n"));
+    assert!(output.contains("from sqlalchemy.ext.compiler import compiles\n"));
+    assert!(output.contains("from models import Base\n\nmetadata = Base.metadata\n"));
+}
+
+#[test]
+fn fixtures_compile_dialect_types_for_sqlite() {
+    let output = generate(&orders_schema(), &GeneratorOptions::default());
+    assert!(output.contains(
+        "@compiles(JSONB, 'sqlite')\ndef _compile_jsonb_sqlite(type_, compiler, **kw):\n    \
+         return 'JSON'"
+    ));
+    assert!(output.contains(
+        "@compiles(UUID, 'sqlite')\ndef _compile_uuid_sqlite(type_, compiler, **kw):\n    \
+         return 'CHAR(36)'"
+    ));
+}
+
+#[test]
+fn fixtures_translate_non_default_schemas() {
+    let output = generate(&orders_schema(), &GeneratorOptions::default());
+    assert!(output.contains("execution_options={'schema_translate_map': {'sales': None}},"));
+
+    let public = schema_pg(vec![table("users")
+        .column(col("id").build())
+        .pk("users_pkey", &["id"])
+        .build()]);
+    let output = generate(&public, &GeneratorOptions::default());
+    assert!(!output.contains("schema_translate_map"));
+    assert!(!output.contains("@compiles"));
+}
+
+#[test]
+fn fixtures_session_rolls_back() {
+    let output = generate(&orders_schema(), &GeneratorOptions::default());
+    assert!(
+        output.contains("@pytest.fixture()\ndef session(engine: Engine) -> Iterator[Session]:\n")
+    );
+    assert!(output.contains("        transaction.rollback()\n"));
+    assert!(output.contains("    metadata.create_all(engine)\n    yield engine\n"));
+}
+
+#[test]
+fn fixtures_without_primary_keys_import_metadata() {
+    let schema = schema_pg(vec![table("log")
+        .column(col("msg").udt("text").build())
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("from models import metadata\n"));
+    assert!(!output.contains("Base"));
+}
+
+#[test]
+fn fixtures_use_renamed_base() {
+    let options = GeneratorOptions {
+        base_name: Some("Model".to_string()),
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&orders_schema(), &options);
+    assert!(output.contains("from models import Model\n\nmetadata = Model.metadata\n"));
+}
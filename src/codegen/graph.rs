@@ -1,30 +1,170 @@
 //! Dialect-neutral graph algorithms over the introspected schema.
 
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use crate::schema::{ConstraintType, ForeignKeyInfo, TableInfo, TableType};
+
+/// Tables in FK dependency order, plus the cycles broken to get there.
+pub struct TableOrder<'a> {
+    pub tables: Vec<&'a TableInfo>,
+    pub cycles: Vec<FkCycle>,
+}
+
+/// A circular FK dependency and the constraint deferred to break it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FkCycle {
+    /// Tables along the cycle in FK direction, starting and ending with the
+    /// alphabetically first one (`a -> b -> a`).
+    pub tables: Vec<String>,
+    /// Schema of the table owning the deferred constraint.
+    pub schema: String,
+    /// Table owning the deferred constraint (the FK back to `tables[0]`).
+    pub table: String,
+    pub constraint: String,
+}
+
+impl FkCycle {
+    pub fn path(&self) -> String {
+        self.tables.join(" -> ")
+    }
+}
+
+impl<'a> TableOrder<'a> {
+    /// Tables in dependency order, then views, so generated models list
+    /// views in a section of their own after every table.
+    pub fn tables_then_views(&self) -> impl Iterator<Item = &'a TableInfo> + '_ {
+        let is_view = |t: &&&'a TableInfo| t.table_type == TableType::View;
+        self.tables
+            .iter()
+            .filter(move |t| !is_view(t))
+            .chain(self.tables.iter().filter(is_view))
+            .copied()
+    }
+
+    /// Whether `constraint` on `table` was deferred to break a cycle, i.e. it
+    /// must be created after both tables exist (`use_alter=True`).
+    pub fn defers(&self, table: &TableInfo, constraint: &str) -> bool {
+        self.cycles.iter().any(|cycle| {
+            cycle.schema == table.schema
+                && cycle.table == table.name
+                && cycle.constraint == constraint
+        })
+    }
+}
+
+/// Resolve FK targets by schema-qualified name, falling back to the bare
+/// table name when the referenced schema is not recorded.
+pub(crate) struct TableLookup<'a> {
+    qualified: HashMap<(&'a str, &'a str), usize>,
+    by_name: HashMap<&'a str, usize>,
+}
+
+impl<'a> TableLookup<'a> {
+    pub(crate) fn new(tables: &'a [TableInfo]) -> Self {
+        let mut lookup = Self {
+            qualified: HashMap::new(),
+            by_name: HashMap::new(),
+        };
+        for (i, table) in tables.iter().enumerate() {
+            lookup
+                .qualified
+                .insert((table.schema.as_str(), table.name.as_str()), i);
+            lookup.by_name.entry(table.name.as_str()).or_insert(i);
+        }
+        lookup
+    }
+
+    /// The table `fk` references, like
+    /// [`crate::schema::IntrospectedSchema::referenced_table`].
+    pub(crate) fn get(&self, fk: &ForeignKeyInfo) -> Option<usize> {
+        if fk.ref_database.is_some() {
+            return None;
+        }
+        self.qualified
+            .get(&(fk.ref_schema.as_str(), fk.ref_table.as_str()))
+            .or_else(|| self.by_name.get(fk.ref_table.as_str()))
+            .copied()
+    }
+}
+
+/// Indexes of `seeds` plus every table within `depth` FK hops of them
+/// (no limit when `None`), in schema order. Hops follow foreign keys to the
+/// tables they reference and, with `both`, back to the tables referencing
+/// them.
+pub(crate) fn fk_closure(
+    tables: &[TableInfo],
+    seeds: impl IntoIterator<Item = usize>,
+    depth: Option<usize>,
+    both: bool,
+) -> BTreeSet<usize> {
+    let lookup = TableLookup::new(tables);
+    let mut adjacent: Vec<Vec<usize>> = vec![Vec::new(); tables.len()];
+    for (i, table) in tables.iter().enumerate() {
+        for fk in table
+            .constraints
+            .iter()
+            .filter_map(|c| c.foreign_key.as_ref())
+        {
+            if let Some(ref_idx) = lookup.get(fk) {
+                adjacent[i].push(ref_idx);
+                if both {
+                    adjacent[ref_idx].push(i);
+                }
+            }
+        }
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut queue = VecDeque::new();
+    for i in seeds {
+        if seen.insert(i) {
+            queue.push_back((i, 0));
+        }
+    }
+    while let Some((i, hops)) = queue.pop_front() {
+        if depth == Some(hops) {
+            continue;
+        }
+        for &next in &adjacent[i] {
+            if seen.insert(next) {
+                queue.push_back((next, hops + 1));
+            }
+        }
+    }
+    seen
+}
+
 /// Sort tables in topological order by FK dependencies (Kahn's algorithm).
 /// Referenced tables come before referencing tables. Alphabetical tiebreak.
-pub fn topo_sort_tables(tables: &[crate::schema::TableInfo]) -> Vec<&crate::schema::TableInfo> {
-    use std::collections::{BTreeSet, HashMap};
+pub fn topo_sort_tables(tables: &[TableInfo]) -> Vec<&TableInfo> {
+    order_tables(tables).tables
+}
 
-    // Build name→index map and adjacency / in-degree structures
-    let name_to_idx: HashMap<&str, usize> = tables
-        .iter()
-        .enumerate()
-        .map(|(i, t)| (t.name.as_str(), i))
-        .collect();
+/// Topologically sort tables, breaking FK cycles deterministically.
+///
+/// When no table is free to emit, the cycle reached from the alphabetically
+/// first remaining table is broken by deferring the FK that points back at
+/// the cycle's alphabetically first table (lowest constraint name on ties).
+/// The sort then resumes, so every table is placed and the result is stable
+/// for a given schema.
+pub fn order_tables(tables: &[TableInfo]) -> TableOrder<'_> {
+    let lookup = TableLookup::new(tables);
 
     let n = tables.len();
     let mut in_degree = vec![0usize; n];
     let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); n]; // ref_table → [referencing tables]
+    let mut references: Vec<Vec<(usize, &str)>> = vec![Vec::new(); n]; // table → [(ref_table, constraint)]
 
     for (i, table) in tables.iter().enumerate() {
         for constraint in &table.constraints {
-            if constraint.constraint_type == crate::schema::ConstraintType::ForeignKey {
+            if constraint.constraint_type == ConstraintType::ForeignKey {
                 if let Some(ref fk) = constraint.foreign_key {
-                    if let Some(&ref_idx) = name_to_idx.get(fk.ref_table.as_str()) {
+                    if let Some(ref_idx) = lookup.get(fk) {
                         if ref_idx != i {
                             // self-references don't count
                             in_degree[i] += 1;
                             dependents[ref_idx].push(i);
+                            references[i].push((ref_idx, constraint.name.as_str()));
                         }
                     }
                 }
@@ -33,40 +173,101 @@ pub fn topo_sort_tables(tables: &[crate::schema::TableInfo]) -> Vec<&crate::sche
     }
 
     // Kahn's: start with nodes that have no incoming FK edges, sorted alphabetically
-    let mut queue: BTreeSet<(String, usize)> = BTreeSet::new();
+    let mut queue: BTreeSet<(&str, usize)> = BTreeSet::new();
     for (i, &deg) in in_degree.iter().enumerate() {
         if deg == 0 {
-            queue.insert((tables[i].name.clone(), i));
+            queue.insert((tables[i].name.as_str(), i));
         }
     }
 
-    let mut result: Vec<&crate::schema::TableInfo> = Vec::with_capacity(n);
-    while let Some((_, idx)) = queue.iter().next().cloned() {
-        queue.remove(&(tables[idx].name.clone(), idx));
-        result.push(&tables[idx]);
-        for &dep in &dependents[idx] {
-            in_degree[dep] -= 1;
-            if in_degree[dep] == 0 {
-                queue.insert((tables[dep].name.clone(), dep));
+    let mut placed = vec![false; n];
+    let mut result: Vec<&TableInfo> = Vec::with_capacity(n);
+    let mut cycles: Vec<FkCycle> = Vec::new();
+    loop {
+        while let Some((_, idx)) = queue.pop_first() {
+            placed[idx] = true;
+            result.push(&tables[idx]);
+            for &dep in &dependents[idx] {
+                in_degree[dep] -= 1;
+                if in_degree[dep] == 0 {
+                    queue.insert((tables[dep].name.as_str(), dep));
+                }
             }
         }
-    }
+        if result.len() == n {
+            break;
+        }
 
-    // If there's a cycle, append remaining tables alphabetically
-    if result.len() < n {
-        let in_result: std::collections::HashSet<usize> = result
+        let cycle = find_cycle(tables, &references, &placed);
+        let first = cycle[0];
+        let last = cycle[cycle.len() - 1];
+        let (pos, &(_, constraint)) = references[last]
+            .iter()
+            .enumerate()
+            .filter(|(_, &(ref_idx, _))| ref_idx == first)
+            .min_by_key(|(_, &(_, name))| name)
+            .expect("cycle edge back to its first table");
+        references[last].remove(pos);
+        let dep_pos = dependents[first]
             .iter()
-            .map(|t| name_to_idx[t.name.as_str()])
-            .collect();
-        let mut remaining: Vec<(usize, &str)> = (0..n)
-            .filter(|i| !in_result.contains(i))
-            .map(|i| (i, tables[i].name.as_str()))
-            .collect();
-        remaining.sort_by_key(|&(_, name)| name.to_string());
-        for (i, _) in remaining {
-            result.push(&tables[i]);
+            .position(|&dep| dep == last)
+            .expect("dependents mirror references");
+        dependents[first].remove(dep_pos);
+        in_degree[last] -= 1;
+        if in_degree[last] == 0 {
+            queue.insert((tables[last].name.as_str(), last));
         }
+
+        let mut names: Vec<String> = cycle.iter().map(|&i| tables[i].name.clone()).collect();
+        names.push(tables[first].name.clone());
+        cycles.push(FkCycle {
+            tables: names,
+            schema: tables[last].schema.clone(),
+            table: tables[last].name.clone(),
+            constraint: constraint.to_string(),
+        });
     }
 
-    result
+    TableOrder {
+        tables: result,
+        cycles,
+    }
+}
+
+/// Find a cycle among unplaced tables, rotated to start at its alphabetically
+/// first table. Every unplaced table still references another unplaced one,
+/// so walking those references from any of them must revisit a table.
+fn find_cycle(
+    tables: &[TableInfo],
+    references: &[Vec<(usize, &str)>],
+    placed: &[bool],
+) -> Vec<usize> {
+    let key = |i: usize| (tables[i].name.as_str(), i);
+    let mut current = (0..tables.len())
+        .filter(|&i| !placed[i])
+        .min_by_key(|&i| key(i))
+        .expect("an unplaced table");
+    let mut path: Vec<usize> = Vec::new();
+    let mut seen: HashMap<usize, usize> = HashMap::new();
+    while !seen.contains_key(&current) {
+        seen.insert(current, path.len());
+        path.push(current);
+        current = references[current]
+            .iter()
+            .filter(|&&(ref_idx, _)| !placed[ref_idx])
+            .map(|&(ref_idx, name)| (key(ref_idx), name))
+            .min()
+            .map(|((_, ref_idx), _)| ref_idx)
+            .expect("unplaced table references another unplaced table");
+    }
+    let mut cycle = path.split_off(seen[&current]);
+    let start = (0..cycle.len())
+        .min_by_key(|&i| key(cycle[i]))
+        .expect("non-empty cycle");
+    cycle.rotate_left(start);
+    cycle
 }
+
+#[cfg(test)]
+#[path = "graph_tests.rs"]
+mod tests;
@@ -0,0 +1,125 @@
+//! Tests for FK dependency ordering.
+
+use super::*;
+use crate::testutil::*;
+
+fn names(order: &TableOrder) -> Vec<String> {
+    order.tables.iter().map(|t| t.name.clone()).collect()
+}
+
+fn node(name: &str) -> TableInfoBuilder {
+    table(name)
+        .column(col("id").build())
+        .pk(&format!("{name}_pkey"), &["id"])
+}
+
+#[test]
+fn acyclic_schema_orders_parents_first_alphabetically() {
+    let tables = vec![
+        node("orders")
+            .fk("orders_user_fkey", &["id"], "users", &["id"])
+            .build(),
+        node("users").build(),
+        node("audit").build(),
+    ];
+    let order = order_tables(&tables);
+    assert_eq!(names(&order), ["audit", "users", "orders"]);
+    assert!(order.cycles.is_empty());
+}
+
+#[test]
+fn same_named_tables_in_different_schemas_are_distinct_nodes() {
+    // Keyed on the bare name, `archive.orders -> sales.items -> orders`
+    // would look like a cycle.
+    let tables = vec![
+        node("orders")
+            .schema("archive")
+            .fk_full(
+                "archive_orders_item_fkey",
+                &["id"],
+                "sales",
+                "items",
+                &["id"],
+                "NO ACTION",
+                "NO ACTION",
+            )
+            .build(),
+        node("items")
+            .schema("sales")
+            .fk_full(
+                "items_order_fkey",
+                &["id"],
+                "sales",
+                "orders",
+                &["id"],
+                "NO ACTION",
+                "NO ACTION",
+            )
+            .build(),
+        node("orders").schema("sales").build(),
+    ];
+    let order = order_tables(&tables);
+    let qualified: Vec<String> = order
+        .tables
+        .iter()
+        .map(|t| format!("{}.{}", t.schema, t.name))
+        .collect();
+    assert_eq!(qualified, ["sales.orders", "sales.items", "archive.orders"]);
+    assert!(order.cycles.is_empty());
+}
+
+#[test]
+fn two_table_cycle_defers_fk_back_to_first_table() {
+    let tables = vec![
+        node("b").fk("b_a_fkey", &["id"], "a", &["id"]).build(),
+        node("a").fk("a_b_fkey", &["id"], "b", &["id"]).build(),
+    ];
+    let order = order_tables(&tables);
+
+    assert_eq!(names(&order), ["b", "a"]);
+    assert_eq!(order.cycles.len(), 1);
+    let cycle = &order.cycles[0];
+    assert_eq!(cycle.path(), "a -> b -> a");
+    assert_eq!(
+        (cycle.table.as_str(), cycle.constraint.as_str()),
+        ("b", "b_a_fkey")
+    );
+    assert!(order.defers(&tables[0], "b_a_fkey"));
+    assert!(!order.defers(&tables[1], "a_b_fkey"));
+}
+
+#[test]
+fn cycle_breaking_is_independent_of_input_order() {
+    let build = |names: &[&str]| -> Vec<TableInfo> {
+        names
+            .iter()
+            .map(|&name| match name {
+                "x" => node("x").fk("x_y_fkey", &["id"], "y", &["id"]).build(),
+                "y" => node("y").fk("y_z_fkey", &["id"], "z", &["id"]).build(),
+                "z" => node("z").fk("z_x_fkey", &["id"], "x", &["id"]).build(),
+                _ => node(name).fk("w_y_fkey", &["id"], "y", &["id"]).build(),
+            })
+            .collect()
+    };
+    let forward = build(&["w", "x", "y", "z"]);
+    let backward = build(&["z", "y", "x", "w"]);
+
+    let a = order_tables(&forward);
+    let b = order_tables(&backward);
+    assert_eq!(names(&a), names(&b));
+    assert_eq!(a.cycles, b.cycles);
+    assert_eq!(a.cycles.len(), 1);
+    assert_eq!(a.cycles[0].path(), "x -> y -> z -> x");
+    assert_eq!(a.cycles[0].constraint, "z_x_fkey");
+    assert_eq!(names(&a), ["z", "y", "w", "x"]);
+}
+
+#[test]
+fn self_reference_is_not_a_cycle() {
+    let tables = vec![node("tree")
+        .fk("tree_parent_fkey", &["id"], "tree", &["id"])
+        .build()];
+    let order = order_tables(&tables);
+    assert_eq!(names(&order), ["tree"]);
+    assert!(order.cycles.is_empty());
+}
@@ -37,20 +37,27 @@ impl ImportCollector {
     /// 1. `from __future__` imports
     /// 2. Standard library `from` imports
     /// 3. Bare `import` statements for stdlib modules
-    /// 4. Third-party `from` imports (sqlalchemy, etc.)
+    /// 4. Third-party `from` imports: sqlalchemy's, with any other
+    ///    package (e.g. a `--typemap` import) placed alphabetically around
+    ///    them
     pub fn render(&self) -> String {
         let mut lines: Vec<String> = Vec::new();
 
         // Separate bare imports, typing imports, stdlib imports, and third-party imports
+        let mut future_imports: Vec<String> = Vec::new();
         let mut bare_imports: Vec<String> = Vec::new();
         let mut typing_imports: Vec<(String, Vec<String>)> = Vec::new();
         let mut sqlalchemy_imports: Vec<(String, Vec<String>)> = Vec::new();
         let mut sqlalchemy_dialect_imports: Vec<(String, Vec<String>)> = Vec::new();
         let mut sqlalchemy_other_imports: Vec<(String, Vec<String>)> = Vec::new();
         let mut sqlalchemy_orm_imports: Vec<(String, Vec<String>)> = Vec::new();
+        let mut third_party_before: Vec<(String, Vec<String>)> = Vec::new();
+        let mut third_party_after: Vec<(String, Vec<String>)> = Vec::new();
 
         for (module, names) in &self.imports {
-            if let Some(bare_module) = module.strip_prefix("__bare__") {
+            if module == "__future__" {
+                future_imports.extend(names.iter().cloned());
+            } else if let Some(bare_module) = module.strip_prefix("__bare__") {
                 bare_imports.push(bare_module.to_string());
             } else if module == "typing" {
                 let sorted_names: Vec<String> = names.iter().cloned().collect();
@@ -67,9 +74,25 @@ impl ImportCollector {
             } else if module.starts_with("sqlalchemy.") {
                 let sorted_names: Vec<String> = names.iter().cloned().collect();
                 sqlalchemy_other_imports.push((module.clone(), sorted_names));
+            } else {
+                let sorted_names: Vec<String> = names.iter().cloned().collect();
+                if module.as_str() < "sqlalchemy" {
+                    third_party_before.push((module.clone(), sorted_names));
+                } else {
+                    third_party_after.push((module.clone(), sorted_names));
+                }
             }
         }
 
+        // 0. `from __future__` must precede every other statement
+        if !future_imports.is_empty() {
+            lines.push(format!(
+                "from __future__ import {}",
+                future_imports.join(", ")
+            ));
+            lines.push(String::new());
+        }
+
         // 1. typing imports
         for (module, names) in &typing_imports {
             lines.push(format!("from {} import {}", module, names.join(", ")));
@@ -86,11 +109,18 @@ impl ImportCollector {
             && (!sqlalchemy_imports.is_empty()
                 || !sqlalchemy_other_imports.is_empty()
                 || !sqlalchemy_dialect_imports.is_empty()
-                || !sqlalchemy_orm_imports.is_empty())
+                || !sqlalchemy_orm_imports.is_empty()
+                || !third_party_before.is_empty()
+                || !third_party_after.is_empty())
         {
             lines.push(String::new());
         }
 
+        // 4. other third-party packages that sort ahead of sqlalchemy
+        for (module, names) in &third_party_before {
+            lines.push(format!("from {} import {}", module, names.join(", ")));
+        }
+
         // 4. sqlalchemy core imports
         for (module, names) in &sqlalchemy_imports {
             lines.push(format!("from {} import {}", module, names.join(", ")));
@@ -111,10 +141,237 @@ impl ImportCollector {
             lines.push(format!("from {} import {}", module, names.join(", ")));
         }
 
+        // 7. other third-party packages that sort after sqlalchemy
+        for (module, names) in &third_party_after {
+            lines.push(format!("from {} import {}", module, names.join(", ")));
+        }
+
         lines.join("\n")
     }
 }
 
+/// The import statements at the top of a rendered prelude, for giving each
+/// module of a split package only the imports it uses.
+#[derive(Debug, Default, Clone)]
+pub struct PreludeImports {
+    /// Blank-line separated groups of statements, in prelude order.
+    groups: Vec<Vec<ImportStatement>>,
+}
+
+#[derive(Debug, Clone)]
+struct ImportStatement {
+    module: String,
+    /// Imported names; empty for a bare `import module`.
+    names: Vec<String>,
+    /// Rendered as `from module import (\n    name,\n)`.
+    parenthesized: bool,
+}
+
+impl PreludeImports {
+    /// Split `prelude` into its leading import statements and the code
+    /// after them.
+    pub fn parse(prelude: &str) -> (Self, String) {
+        let mut imports = PreludeImports::default();
+        let mut group: Vec<ImportStatement> = Vec::new();
+        let mut lines = prelude.lines().peekable();
+        while let Some(line) = lines.peek() {
+            let line = line.trim_end();
+            let statement = if let Some(rest) = line.strip_prefix("from ") {
+                let Some((module, names)) = rest.split_once(" import ") else {
+                    break;
+                };
+                let parenthesized = names == "(";
+                let names = if parenthesized {
+                    lines.next();
+                    let mut names = Vec::new();
+                    while let Some(name) = lines.next_if(|l| l.trim() != ")") {
+                        names.push(name.trim().trim_end_matches(',').to_string());
+                    }
+                    names
+                } else {
+                    names.split(',').map(|n| n.trim().to_string()).collect()
+                };
+                ImportStatement {
+                    module: module.to_string(),
+                    names,
+                    parenthesized,
+                }
+            } else if let Some(module) = line.strip_prefix("import ") {
+                ImportStatement {
+                    module: module.to_string(),
+                    names: Vec::new(),
+                    parenthesized: false,
+                }
+            } else if line.is_empty() {
+                if !group.is_empty() {
+                    imports.groups.push(std::mem::take(&mut group));
+                }
+                lines.next();
+                continue;
+            } else {
+                break;
+            };
+            group.push(statement);
+            lines.next();
+        }
+        if !group.is_empty() {
+            imports.groups.push(group);
+        }
+        let body: Vec<&str> = lines.collect();
+        (imports, body.join("\n"))
+    }
+
+    /// Add `name` to the `from module import` statement, or start one in
+    /// the first group after `__future__`.
+    pub fn add(&mut self, module: &str, name: &str) {
+        let existing = self
+            .groups
+            .iter_mut()
+            .flatten()
+            .find(|s| s.module == module && !s.names.is_empty());
+        match existing {
+            Some(statement) => {
+                if !statement.names.iter().any(|n| n == name) {
+                    statement.names.push(name.to_string());
+                    statement.names.sort();
+                }
+            }
+            None => {
+                let statement = ImportStatement {
+                    module: module.to_string(),
+                    names: vec![name.to_string()],
+                    parenthesized: false,
+                };
+                let future = self
+                    .groups
+                    .first()
+                    .is_some_and(|g| g.iter().all(|s| s.module == "__future__"));
+                let at = usize::from(future);
+                match self.groups.get_mut(at) {
+                    Some(group) => group.insert(0, statement),
+                    None => self.groups.push(vec![statement]),
+                }
+            }
+        }
+    }
+
+    /// The statements, keeping only the `used` names. `from __future__`
+    /// imports are kept either way.
+    pub fn render_for(&self, used: &BTreeSet<&str>) -> String {
+        let groups: Vec<String> = self
+            .groups
+            .iter()
+            .filter_map(|group| {
+                let lines: Vec<String> = group
+                    .iter()
+                    .filter_map(|statement| {
+                        if statement.names.is_empty() {
+                            return used
+                                .contains(statement.module.as_str())
+                                .then(|| format!("import {}", statement.module));
+                        }
+                        let names: Vec<&str> = statement
+                            .names
+                            .iter()
+                            .map(String::as_str)
+                            .filter(|n| statement.module == "__future__" || used.contains(n))
+                            .collect();
+                        if names.is_empty() {
+                            None
+                        } else if statement.parenthesized {
+                            let body: String =
+                                names.iter().map(|n| format!("    {n},\n")).collect();
+                            Some(format!("from {} import (\n{body})", statement.module))
+                        } else {
+                            Some(format!(
+                                "from {} import {}",
+                                statement.module,
+                                names.join(", ")
+                            ))
+                        }
+                    })
+                    .collect();
+                (!lines.is_empty()).then(|| lines.join("\n"))
+            })
+            .collect();
+        groups.join("\n\n")
+    }
+}
+
+/// Names `code` refers to: identifiers outside string literals and
+/// comments, not counting attribute names after a `.`.
+pub fn python_identifiers(code: &str) -> BTreeSet<&str> {
+    let mut names = BTreeSet::new();
+    let mut chars = code.char_indices().peekable();
+    let mut after_dot = false;
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                let triple = code[start..].starts_with(&c.to_string().repeat(3));
+                if triple {
+                    chars.nth(1);
+                }
+                let mut closing = 0;
+                while let Some((_, ch)) = chars.next() {
+                    if ch == '\\' {
+                        chars.next();
+                        closing = 0;
+                    } else if ch == c {
+                        closing += 1;
+                        if !triple || closing == 3 {
+                            break;
+                        }
+                    } else {
+                        closing = 0;
+                    }
+                }
+            }
+            '#' => while chars.next_if(|&(_, ch)| ch != '\n').is_some() {},
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, ch)) =
+                    chars.next_if(|&(_, ch)| ch.is_alphanumeric() || ch == '_')
+                {
+                    end = i + ch.len_utf8();
+                }
+                if !after_dot {
+                    names.insert(&code[start..end]);
+                }
+            }
+            _ => {}
+        }
+        after_dot = c == '.';
+    }
+    names
+}
+
+/// Names a module defines at top level: `class Name`, `def name`, and
+/// `name = ...` assignments.
+pub fn top_level_names(code: &str) -> Vec<&str> {
+    code.lines()
+        .filter_map(|line| {
+            let keyword = line
+                .strip_prefix("class ")
+                .or_else(|| line.strip_prefix("def "));
+            let rest = keyword.unwrap_or(line);
+            let end = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let (name, after) = rest.split_at(end);
+            if name.is_empty()
+                || name.starts_with(|c: char| c.is_ascii_digit())
+                || crate::naming::is_python_keyword(name)
+            {
+                return None;
+            }
+            let after = after.trim_start();
+            let assignment =
+                after.starts_with(':') || (after.starts_with('=') && !after.starts_with("=="));
+            (keyword.is_some() || assignment).then_some(name)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 #[path = "imports_tests.rs"]
 mod tests;
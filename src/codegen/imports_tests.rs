@@ -33,3 +33,51 @@ fn test_dialect_imports() {
         "from sqlalchemy import Integer\nfrom sqlalchemy.dialects.postgresql import JSONB"
     );
 }
+
+#[test]
+fn test_future_imports_come_first() {
+    let mut ic = ImportCollector::new();
+    ic.add("typing", "Optional");
+    ic.add("__future__", "annotations");
+    ic.add("sqlalchemy", "Integer");
+    let result = ic.render();
+    let expected = "from __future__ import annotations\n\nfrom typing import Optional\n\nfrom sqlalchemy import Integer";
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_third_party_imports_sort_around_sqlalchemy() {
+    let mut ic = ImportCollector::new();
+    ic.add("typing", "Optional");
+    ic.add("sqlalchemy", "Integer");
+    ic.add("sqlalchemy.orm", "Mapped");
+    ic.add("citext", "CIText");
+    ic.add("sqlmodel", "Field");
+    let result = ic.render();
+    let expected = "from typing import Optional\n\nfrom citext import CIText\nfrom sqlalchemy import Integer\nfrom sqlalchemy.orm import Mapped\nfrom sqlmodel import Field";
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_prelude_imports_keep_used_names() {
+    let prelude = "from __future__ import annotations\n\nfrom typing import Optional\nimport datetime\n\nfrom sqlalchemy import Integer, String\n\nfrom models import (\n    Posts,\n    Users,\n)\n\nclass Base(DeclarativeBase):\n    pass";
+    let (mut imports, body) = PreludeImports::parse(prelude);
+    assert_eq!(body, "class Base(DeclarativeBase):\n    pass");
+
+    imports.add("typing", "TYPE_CHECKING");
+    let code = "# Optional String\nclass Users(Base):\n    \"\"\"Integer \"quoted\" docs\"\"\"\n    name: Mapped[str] = mapped_column('datetime', sa.Integer)\n    created = datetime.datetime\n\nif TYPE_CHECKING:\n    pass";
+    assert_eq!(
+        imports.render_for(&python_identifiers(code)),
+        "from __future__ import annotations\n\nfrom typing import TYPE_CHECKING\nimport datetime\n\nfrom models import (\n    Users,\n)"
+    );
+    assert_eq!(top_level_names(code), ["Users"]);
+}
+
+#[test]
+fn test_top_level_names() {
+    let code = "metadata = MetaData()\n\nclass Status(str, enum.Enum):\n    A = 'a'\n\nt_users = Table(\n    'users', metadata,\n)\n\ndef get_users(id: int) -> None:\n    pass\n\n@router.get('')\nif TYPE_CHECKING:\n    pass";
+    assert_eq!(
+        top_level_names(code),
+        ["metadata", "Status", "t_users", "get_users"]
+    );
+}
@@ -1,28 +1,51 @@
+mod conventions;
+pub mod dbml;
 pub mod ddl;
 pub mod ddl_diff;
 pub mod declarative;
+pub mod django;
+pub mod dot;
+pub mod exec;
+pub mod fastapi;
+pub mod fixtures;
 mod graph;
 pub mod imports;
+pub mod proto;
 pub mod python;
 pub mod relationships;
 mod render;
+pub mod report;
+pub mod repositories;
 mod schema_info;
+pub mod seed;
+mod sequences;
 mod sql_text;
 pub mod tables;
+pub mod template;
 
-pub use graph::topo_sort_tables;
+pub(crate) use conventions::{name_arg, NamingConvention};
+pub(crate) use graph::fk_closure;
+pub use graph::{order_tables, topo_sort_tables, FkCycle, TableOrder};
+pub(crate) use python::{
+    column_comment, column_python_type, exclude_constraint, map_column_type_with,
+    nulls_not_distinct_kwarg, object_comments, period_column_args, server_default_arg,
+    skipped_warnings, table_note, view_definition_comment,
+};
 pub use python::{
-    enum_class_name, escape_python_string, format_fk_options, format_index_kwargs,
-    format_python_string_literal, format_server_default, generate_enum_class,
-    quote_constraint_columns,
+    enum_class_name, escape_python_string, fk_target, format_column_info, format_computed,
+    format_fk_options, format_identity, format_index_kwargs, format_python_string_literal,
+    format_server_default, generate_enum_class, generate_typed_dict, index_needs_text,
+    quote_constraint_columns, quote_index_keys,
 };
+pub(crate) use render::ident::quote_identifier;
+pub(crate) use schema_info::{find_column_enum, find_enum_for_ddl_column, is_enum_array_column};
 pub use schema_info::{
     find_enum_for_column, has_primary_key, is_primary_key_column, is_unique_constraint_index,
 };
-pub(crate) use schema_info::{find_enum_for_ddl_column, is_enum_array_column};
+pub(crate) use sequences::{column_sequence, sequence_definition, used_sequences};
 pub use sql_text::{
-    is_auto_increment_column, is_serial_default, is_standard_sequence_name, parse_check_boolean,
-    parse_check_enum, parse_sequence_name,
+    is_auto_increment_column, is_next_value_default, is_serial_default, is_standard_sequence_name,
+    parse_check_boolean, parse_check_enum, parse_sequence_name,
 };
 
 #[cfg(test)]
@@ -0,0 +1,180 @@
+//! Protocol Buffers generator (`--generator proto`).
+//!
+//! Emits a proto3 file with one message per table or view, fields numbered
+//! in column order. Named enum types become proto enums with a zero
+//! `*_UNSPECIFIED` member. Nullable scalar columns are `optional` fields,
+//! or `google.protobuf.*Value` wrappers with `--options proto_wrappers`.
+//! Types without a lossless proto scalar (decimals, dates, JSON, ...) are
+//! carried as strings.
+
+use std::collections::BTreeSet;
+
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+
+use crate::cli::GeneratorOptions;
+use crate::codegen::find_enum_for_column;
+use crate::ddl_typemap::{to_canonical, CanonicalType};
+use crate::naming::table_class_name;
+use crate::schema::{ColumnInfo, EnumInfo, IntrospectedSchema, TableInfo};
+
+const TIMESTAMP_IMPORT: &str = "google/protobuf/timestamp.proto";
+const DURATION_IMPORT: &str = "google/protobuf/duration.proto";
+const WRAPPERS_IMPORT: &str = "google/protobuf/wrappers.proto";
+
+/// Generate the `.proto` file for `schema`.
+pub fn generate(schema: &IntrospectedSchema, options: &GeneratorOptions) -> String {
+    let mut imports = BTreeSet::new();
+    let mut messages = Vec::new();
+    for table in &schema.tables {
+        messages.push(render_message(schema, table, options, &mut imports));
+    }
+
+    let mut out = vec!["syntax = \"proto3\";".to_string()];
+    if !imports.is_empty() {
+        out.push(String::new());
+        out.extend(imports.iter().map(|i| format!("import \"{i}\";")));
+    }
+    for enum_info in &schema.enums {
+        out.push(String::new());
+        out.push(render_enum(enum_info));
+    }
+    for message in messages {
+        out.push(String::new());
+        out.push(message);
+    }
+    out.push(String::new());
+    out.join("\n")
+}
+
+fn render_enum(enum_info: &EnumInfo) -> String {
+    let name = identifier(&enum_info.name.to_upper_camel_case());
+    let prefix = enum_info.name.to_shouty_snake_case();
+    let mut lines = vec![
+        format!("enum {name} {{"),
+        format!("  {prefix}_UNSPECIFIED = 0;"),
+    ];
+    for (i, value) in enum_info.values.iter().enumerate() {
+        let member: String = value
+            .to_shouty_snake_case()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+            .collect();
+        lines.push(format!("  {prefix}_{member} = {};", i + 1));
+    }
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+fn render_message(
+    schema: &IntrospectedSchema,
+    table: &TableInfo,
+    options: &GeneratorOptions,
+    imports: &mut BTreeSet<&'static str>,
+) -> String {
+    let mut lines = Vec::new();
+    if let Some(comment) = table
+        .comment
+        .as_deref()
+        .filter(|_| options.table_comments())
+    {
+        lines.extend(comment.lines().map(|l| format!("// {l}")));
+    }
+    lines.push(format!(
+        "message {} {{",
+        identifier(&table_class_name(schema, table, options))
+    ));
+    for (i, col) in table.columns.iter().enumerate() {
+        if let Some(comment) = col.comment.as_deref().filter(|_| options.column_comments()) {
+            lines.extend(comment.lines().map(|l| format!("  // {l}")));
+        }
+        lines.push(format!(
+            "  {} {} = {};",
+            field_type(schema, col, options, imports),
+            identifier(&col.name.to_snake_case()),
+            i + 1
+        ));
+    }
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+/// Proto type for a column, with its `optional`/`repeated` label.
+fn field_type(
+    schema: &IntrospectedSchema,
+    col: &ColumnInfo,
+    options: &GeneratorOptions,
+    imports: &mut BTreeSet<&'static str>,
+) -> String {
+    if let Some(enum_info) = find_enum_for_column(&col.udt_name, &schema.enums) {
+        let name = identifier(&enum_info.name.to_upper_camel_case());
+        return if col.is_nullable {
+            format!("optional {name}")
+        } else {
+            name
+        };
+    }
+
+    let canonical = to_canonical(col, schema.dialect);
+    if let CanonicalType::Array { ref element } = canonical {
+        return format!("repeated {}", scalar(element, imports));
+    }
+    let proto = scalar(&canonical, imports);
+    if !col.is_nullable || proto.starts_with("google.protobuf.") {
+        // Message types already track presence.
+        proto.to_string()
+    } else if options.proto_wrappers {
+        imports.insert(WRAPPERS_IMPORT);
+        format!("google.protobuf.{}", wrapper(proto))
+    } else {
+        format!("optional {proto}")
+    }
+}
+
+fn scalar(canonical: &CanonicalType, imports: &mut BTreeSet<&'static str>) -> &'static str {
+    match canonical {
+        CanonicalType::Boolean => "bool",
+        CanonicalType::SmallInt | CanonicalType::Integer => "int32",
+        CanonicalType::BigInt => "int64",
+        CanonicalType::Float => "float",
+        CanonicalType::Double => "double",
+        CanonicalType::Bytes { .. } => "bytes",
+        CanonicalType::Timestamp { .. } => {
+            imports.insert(TIMESTAMP_IMPORT);
+            "google.protobuf.Timestamp"
+        }
+        CanonicalType::Interval => {
+            imports.insert(DURATION_IMPORT);
+            "google.protobuf.Duration"
+        }
+        _ => "string",
+    }
+}
+
+fn wrapper(scalar: &str) -> &'static str {
+    match scalar {
+        "bool" => "BoolValue",
+        "int32" => "Int32Value",
+        "int64" => "Int64Value",
+        "float" => "FloatValue",
+        "double" => "DoubleValue",
+        "bytes" => "BytesValue",
+        _ => "StringValue",
+    }
+}
+
+/// Replace characters proto identifiers cannot contain and make sure the
+/// name starts with a letter.
+fn identifier(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if !ident.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        ident = format!("x{ident}");
+    }
+    ident
+}
+
+#[cfg(test)]
+#[path = "proto_tests.rs"]
+mod tests;
@@ -0,0 +1,83 @@
+use super::*;
+use crate::testutil::*;
+
+fn orders_schema() -> IntrospectedSchema {
+    schema_pg_with_enums(
+        vec![table("orders")
+            .column(col("id").udt("int8").build())
+            .column(col("status").udt("order_status").nullable().build())
+            .column(
+                col("note")
+                    .udt("text")
+                    .nullable()
+                    .comment("Free text")
+                    .build(),
+            )
+            .column(col("placed_at").udt("timestamptz").nullable().build())
+            .column(col("tags").udt("_text").build())
+            .column(col("total").udt("numeric").precision(10, 2).build())
+            .comment("Customer orders")
+            .build()],
+        vec![EnumInfo {
+            name: "order_status".to_string(),
+            schema: Some("public".to_string()),
+            values: vec!["open".to_string(), "in-transit".to_string()],
+        }],
+    )
+}
+
+#[test]
+fn proto_messages_use_optional_for_nullable_scalars() {
+    let output = generate(&orders_schema(), &GeneratorOptions::default());
+    assert_eq!(
+        output,
+        "\
+syntax = \"proto3\";
+
+import \"google/protobuf/timestamp.proto\";
+
+enum OrderStatus {
+  ORDER_STATUS_UNSPECIFIED = 0;
+  ORDER_STATUS_OPEN = 1;
+  ORDER_STATUS_IN_TRANSIT = 2;
+}
+
+// Customer orders
+message Orders {
+  int64 id = 1;
+  optional OrderStatus status = 2;
+  // Free text
+  optional string note = 3;
+  google.protobuf.Timestamp placed_at = 4;
+  repeated string tags = 5;
+  string total = 6;
+}
+"
+    );
+}
+
+#[test]
+fn proto_wrappers_option_uses_wrapper_messages() {
+    let options = GeneratorOptions {
+        proto_wrappers: true,
+        ..Default::default()
+    };
+    let output = generate(&orders_schema(), &options);
+    assert!(output.contains(
+        "import \"google/protobuf/timestamp.proto\";\nimport \"google/protobuf/wrappers.proto\";\n"
+    ));
+    assert!(output.contains("  google.protobuf.StringValue note = 3;\n"));
+    assert!(output.contains("  optional OrderStatus status = 2;\n"));
+}
+
+#[test]
+fn proto_identifiers_are_sanitized() {
+    let schema = schema_pg(vec![table("2fa codes")
+        .column(col("Code-Value").udt("text").build())
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(
+        output.contains("message x2faCodes {\n  string code_value = 1;\n}"),
+        "{output}"
+    );
+}
@@ -2,36 +2,207 @@
 //! generators: string-literal formatting, kwargs rendering, and enum class
 //! generation.
 
+use std::collections::{BTreeSet, HashMap};
+
 use crate::dialect::Dialect;
 
+use super::imports::{python_identifiers, top_level_names, PreludeImports};
 use super::sql_text::{strip_mssql_parens, strip_pg_typecast};
 
 /// Format a server_default expression. Wraps raw SQL in text('...').
 /// Delegates escaping to format_python_string_literal for proper handling of
 /// backslashes, newlines, and quote characters.
 pub fn format_server_default(default: &str, dialect: Dialect) -> String {
-    let cleaned = match dialect {
+    format!(
+        "text({})",
+        format_python_string_literal(clean_default(default, dialect))
+    )
+}
+
+/// A default expression without the dialect's decoration: PG typecasts,
+/// MSSQL's wrapping parentheses.
+fn clean_default(default: &str, dialect: Dialect) -> &str {
+    match dialect {
         Dialect::Postgres => strip_pg_typecast(default),
         Dialect::Mssql => strip_mssql_parens(default),
         Dialect::Mysql | Dialect::Sqlite => default.trim(),
-    };
+    }
+}
 
-    format!("text({})", format_python_string_literal(cleaned))
+/// Truth value of a boolean column's default, however the server spells
+/// it: `1`, `'0'`, `true`, `b'1'`.
+fn boolean_default(cleaned: &str) -> Option<bool> {
+    let value = cleaned
+        .strip_prefix(['b', 'B'])
+        .filter(|v| v.starts_with('\''))
+        .unwrap_or(cleaned);
+    match value.trim_matches('\'').to_ascii_lowercase().as_str() {
+        "1" | "true" => Some(true),
+        "0" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// `func.*` equivalent of a well-known server default function, for the
+/// `func_defaults` option. `getdate()` becomes the portable `func.now()`;
+/// anything unrecognized returns `None` and stays `text('...')`.
+pub fn func_server_default(default: &str, dialect: Dialect) -> Option<&'static str> {
+    match clean_default(default, dialect)
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "now()" | "getdate()" => Some("func.now()"),
+        "current_timestamp" | "current_timestamp()" => Some("func.current_timestamp()"),
+        "gen_random_uuid()" => Some("func.gen_random_uuid()"),
+        "newid()" => Some("func.newid()"),
+        _ => None,
+    }
+}
+
+/// The `server_default=` value for a column default, adding its import:
+/// `func.now()` and friends under `func_defaults`, otherwise `text('...')`.
+/// An MSSQL `NEXT VALUE FOR` sequence default becomes `FetchedValue()`:
+/// the value is generated by the server, and the raw text would not parse
+/// on other dialects.
+/// Defaults of columns that map to a `bool` are normalized whatever their
+/// spelling (`((1))`, `'1'`, `b'1'`): `text('1')` (`text('true')` on
+/// PostgreSQL), or the portable `true()`/`false()` under `func_defaults`.
+pub(crate) fn server_default_arg(
+    col: &crate::schema::ColumnInfo,
+    default: &str,
+    dialect: Dialect,
+    options: &crate::cli::GeneratorOptions,
+    imports: &mut super::imports::ImportCollector,
+) -> String {
+    if super::sql_text::is_next_value_default(default, dialect) {
+        imports.add("sqlalchemy", "FetchedValue");
+        return "FetchedValue()".to_string();
+    }
+    if map_column_type_with(col, dialect, options).python_type == "bool" {
+        if let Some(value) = boolean_default(clean_default(default, dialect)) {
+            if options.func_defaults {
+                let name = if value { "true" } else { "false" };
+                imports.add("sqlalchemy", name);
+                return format!("{name}()");
+            }
+            let literal = match (dialect, value) {
+                (Dialect::Postgres, true) => "true",
+                (Dialect::Postgres, false) => "false",
+                (_, true) => "1",
+                (_, false) => "0",
+            };
+            imports.add("sqlalchemy", "text");
+            return format!("text('{literal}')");
+        }
+    }
+    if options.func_defaults {
+        if let Some(func) = func_server_default(default, dialect) {
+            imports.add("sqlalchemy", "func");
+            return func.to_string();
+        }
+    }
+    imports.add("sqlalchemy", "text");
+    format_server_default(default, dialect)
 }
 
 /// Quote a list of column names for use in constraint arguments.
 pub fn quote_constraint_columns(cols: &[String]) -> Vec<String> {
-    cols.iter().map(|c| format!("'{c}'")).collect()
+    cols.iter()
+        .map(|c| format_python_string_literal(c))
+        .collect()
 }
 
-/// Escape single quotes in a string for Python string literals.
+/// Index keys as `Index(...)` arguments: quoted column names, and
+/// `text('...')` for expressions and for keys with a non-default sort
+/// order, e.g. `text('"created_at" DESC NULLS LAST')` (the caller imports
+/// `text`, see [`index_needs_text`]). An ordered column's operator class
+/// moves into its `text()` since `postgresql_ops` can no longer name it.
+pub fn quote_index_keys(index: &crate::schema::IndexInfo, dialect: Dialect) -> Vec<String> {
+    index
+        .keys()
+        .into_iter()
+        .map(|key| match key {
+            crate::schema::IndexKey::Column(column) => match index.orderings.get(column) {
+                Some(order) => {
+                    let mut sql = super::quote_identifier(column, dialect);
+                    if let Some(opclass) = index.opclasses.get(column) {
+                        sql.push(' ');
+                        sql.push_str(opclass);
+                    }
+                    sql.push_str(&order.sql_suffix(dialect));
+                    format!("text({})", format_python_string_literal(&sql))
+                }
+                None => format_python_string_literal(column),
+            },
+            crate::schema::IndexKey::Expression(expression) => {
+                let suffix = index
+                    .orderings
+                    .get(expression)
+                    .map(|order| order.sql_suffix(dialect))
+                    .unwrap_or_default();
+                format!(
+                    "text({})",
+                    format_python_string_literal(&format!("{expression}{suffix}"))
+                )
+            }
+        })
+        .collect()
+}
+
+/// Whether [`quote_index_keys`] renders any key as `text(...)`.
+pub fn index_needs_text(index: &crate::schema::IndexInfo) -> bool {
+    index.has_expressions() || !index.orderings.is_empty()
+}
+
+/// Escape a string for the body of a single-quoted Python string literal.
 pub fn escape_python_string(s: &str) -> String {
-    s.replace('\'', "\\'")
+    escape_literal_body(s, '\'')
+}
+
+/// Escape a literal body the way Python's `repr` does: backslashes, the
+/// enclosing quote, `\n`/`\r`/`\t`, and other control characters as
+/// `\xNN`. Printable non-ASCII text is kept as is.
+fn escape_literal_body(s: &str, quote: char) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c == quote => {
+                out.push('\\');
+                out.push(c);
+            }
+            c if (c as u32) < 0x20 || c as u32 == 0x7f => {
+                out.push_str(&format!("\\x{:02x}", c as u32));
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// `ForeignKey` target for one referenced column: `table.column`, or
+/// `schema.table.column` when the referenced table lives outside the
+/// dialect's default schema (it is then declared with `schema=` and keyed
+/// by its qualified name in the `MetaData`). A cross-database reference
+/// keeps its database: `database.schema.table.column`.
+pub fn fk_target(fk: &crate::schema::ForeignKeyInfo, column: &str, dialect: Dialect) -> String {
+    if fk.ref_database.is_some() {
+        format!("{}.{column}", fk.qualified_target())
+    } else if fk.ref_schema.is_empty() || fk.ref_schema == dialect.default_schema() {
+        format!("{}.{column}", fk.ref_table)
+    } else {
+        format!("{}.{}.{column}", fk.ref_schema, fk.ref_table)
+    }
 }
 
-/// Format FK option kwargs (ondelete, onupdate) for ForeignKeyConstraint.
-/// Returns empty string if both rules are NO ACTION (the default).
-pub fn format_fk_options(fk: &crate::schema::ForeignKeyInfo) -> String {
+/// Format FK option kwargs (ondelete, onupdate, deferrable, initially,
+/// use_alter) for ForeignKeyConstraint. Returns empty string if both rules are
+/// NO ACTION (the default), the constraint is checked immediately, and it is
+/// not deferred to break a dependency cycle.
+pub fn format_fk_options(fk: &crate::schema::ForeignKeyInfo, use_alter: bool) -> String {
     let mut opts = Vec::new();
     if fk.delete_rule != "NO ACTION" {
         opts.push(format!("ondelete='{}'", fk.delete_rule));
@@ -39,6 +210,15 @@ pub fn format_fk_options(fk: &crate::schema::ForeignKeyInfo) -> String {
     if fk.update_rule != "NO ACTION" {
         opts.push(format!("onupdate='{}'", fk.update_rule));
     }
+    if fk.deferrable {
+        opts.push("deferrable=True".to_string());
+    }
+    if fk.initially_deferred {
+        opts.push("initially='DEFERRED'".to_string());
+    }
+    if use_alter {
+        opts.push("use_alter=True".to_string());
+    }
     if opts.is_empty() {
         String::new()
     } else {
@@ -46,26 +226,226 @@ pub fn format_fk_options(fk: &crate::schema::ForeignKeyInfo) -> String {
     }
 }
 
-/// Format a string as a Python string literal, choosing quote style and escaping properly.
-/// Uses double quotes if the string contains single quotes (and no double quotes),
-/// otherwise uses single quotes with escaping. Newlines are always escaped.
+/// Format a string as a Python string literal, matching Python's `repr`:
+/// double quotes if the string contains single quotes (and no double
+/// quotes), otherwise single quotes; backslashes, quotes, and control
+/// characters are escaped so the literal always stays on one line.
 pub fn format_python_string_literal(s: &str) -> String {
-    let escaped = s.replace('\\', "\\\\").replace('\n', "\\n");
-    if escaped.contains('\'') && !escaped.contains('"') {
-        format!("\"{}\"", escaped)
+    if s.contains('\'') && !s.contains('"') {
+        format!("\"{}\"", escape_literal_body(s, '"'))
     } else {
-        format!("'{}'", escaped.replace('\'', "\\'"))
+        format!("'{}'", escape_literal_body(s, '\''))
     }
 }
 
-/// Format index kwargs as a string of ", key='value'" pairs.
-/// Empty values are skipped.
-pub fn format_index_kwargs(kwargs: &std::collections::BTreeMap<String, String>) -> String {
-    kwargs
+/// The `info={...}` keyword argument for a generated column: PII flags from
+/// `--pii` plus the extended properties selected by `--info-properties`.
+pub fn format_column_info(
+    col: &crate::schema::ColumnInfo,
+    options: &crate::cli::GeneratorOptions,
+) -> Option<String> {
+    let mut entries: Vec<String> = Vec::new();
+    if let Some(category) = col.pii {
+        entries.push("'pii': True".to_string());
+        entries.push(format!("'pii_category': '{}'", category.as_str()));
+    }
+    for key in &options.info_properties {
+        if let Some(value) = col.properties.get(key) {
+            entries.push(format!(
+                "{}: {}",
+                format_python_string_literal(key),
+                format_python_string_literal(value)
+            ));
+        }
+    }
+    if entries.is_empty() {
+        None
+    } else {
+        Some(format!("info={{{}}}", entries.join(", ")))
+    }
+}
+
+/// The `Identity(...)` argument for an identity column, naming only what
+/// differs from the defaults: PostgreSQL's `GENERATED ALWAYS` (`always=True`)
+/// and sequence options other than what a bare `GENERATED ... AS IDENTITY`
+/// would get, elsewhere a seed or increment other than 1. A default
+/// identity renders as `Identity()`.
+pub fn format_identity(col: &crate::schema::ColumnInfo, dialect: Dialect) -> Option<String> {
+    let identity = col.identity.as_ref()?;
+    let mut args = Vec::new();
+    if dialect == Dialect::Postgres
+        && col
+            .identity_generation
+            .as_deref()
+            .is_some_and(|generation| generation.eq_ignore_ascii_case("ALWAYS"))
+    {
+        args.push("always=True".to_string());
+    }
+    if dialect != Dialect::Postgres {
+        if identity.start != 1 {
+            args.push(format!("start={}", identity.start));
+        }
+        if identity.increment != 1 {
+            args.push(format!("increment={}", identity.increment));
+        }
+        return Some(format!("Identity({})", args.join(", ")));
+    }
+
+    // PostgreSQL defaults: an ascending sequence runs from 1 to the type's
+    // maximum, a descending one from the type's minimum to -1, and either
+    // starts at the end it counts away from.
+    let type_max = match col.udt_name.as_str() {
+        "int2" => Some(i16::MAX as i64),
+        "int4" => Some(i32::MAX as i64),
+        "int8" => Some(i64::MAX),
+        _ => None,
+    };
+    let ascending = identity.increment > 0;
+    let (default_min, default_max) = if ascending {
+        (Some(1), type_max)
+    } else {
+        (type_max.map(|max| -max - 1), Some(-1))
+    };
+    let default_start = if ascending {
+        identity.min_value.or(default_min)
+    } else {
+        identity.max_value.or(default_max)
+    };
+    if default_start != Some(identity.start) {
+        args.push(format!("start={}", identity.start));
+    }
+    if identity.increment != 1 {
+        args.push(format!("increment={}", identity.increment));
+    }
+    if let Some(min) = identity.min_value.filter(|&min| Some(min) != default_min) {
+        args.push(format!("minvalue={min}"));
+    }
+    if let Some(max) = identity.max_value.filter(|&max| Some(max) != default_max) {
+        args.push(format!("maxvalue={max}"));
+    }
+    if identity.cycle == Some(true) {
+        args.push("cycle=True".to_string());
+    }
+    if let Some(cache) = identity.cache.filter(|&cache| cache != 1) {
+        args.push(format!("cache={cache}"));
+    }
+    Some(format!("Identity({})", args.join(", ")))
+}
+
+/// The `Computed(...)` argument for a generated column.
+pub fn format_computed(computed: &crate::schema::ComputedInfo) -> String {
+    let mut args = vec![format_python_string_literal(&computed.expression)];
+    if let Some(persisted) = computed.persisted {
+        args.push(format!(
+            "persisted={}",
+            if persisted { "True" } else { "False" }
+        ));
+    }
+    format!("Computed({})", args.join(", "))
+}
+
+/// `ExcludeConstraint((key, op), ..., name=..., using=..., where=...)` for
+/// a PostgreSQL exclusion constraint. Expression keys and the predicate
+/// are wrapped in `text()`. `name` is the rendered `, name='...'` part.
+pub(crate) fn exclude_constraint(
+    exclude: &crate::schema::ExcludeInfo,
+    name: &str,
+    imports: &mut super::imports::ImportCollector,
+) -> String {
+    imports.add("sqlalchemy.dialects.postgresql", "ExcludeConstraint");
+    let args: Vec<String> = exclude
+        .elements
+        .iter()
+        .map(|element| {
+            let key = if element.is_expression {
+                imports.add("sqlalchemy", "text");
+                format!("text({})", format_python_string_literal(&element.key))
+            } else {
+                format_python_string_literal(&element.key)
+            };
+            format!(
+                "({key}, {})",
+                format_python_string_literal(&element.operator)
+            )
+        })
+        .collect();
+    let mut out = format!("ExcludeConstraint({}{name}", args.join(", "));
+    // SQLAlchemy's default access method is GiST.
+    if exclude.using != "gist" {
+        out.push_str(&format!(
+            ", using={}",
+            format_python_string_literal(&exclude.using)
+        ));
+    }
+    if let Some(ref predicate) = exclude.predicate {
+        imports.add("sqlalchemy", "text");
+        out.push_str(&format!(
+            ", where=text({})",
+            format_python_string_literal(predicate)
+        ));
+    }
+    out.push(')');
+    out
+}
+
+/// `, postgresql_nulls_not_distinct=True` for a PostgreSQL unique
+/// constraint or index declared `NULLS NOT DISTINCT`.
+pub(crate) fn nulls_not_distinct_kwarg(nulls_not_distinct: bool, dialect: Dialect) -> &'static str {
+    if nulls_not_distinct && dialect == Dialect::Postgres {
+        ", postgresql_nulls_not_distinct=True"
+    } else {
+        ""
+    }
+}
+
+/// Format index kwargs as a string of ", key='value'" pairs, followed by
+/// `postgresql_ops={...}` for non-default operator classes,
+/// `mssql_clustered=True` for clustered SQL Server indexes,
+/// `postgresql_nulls_not_distinct=True` and the dialect's
+/// `*_include=[...]` for covering-index columns. Empty values are skipped.
+pub fn format_index_kwargs(index: &crate::schema::IndexInfo, dialect: Dialect) -> String {
+    let mut out: String = index
+        .kwargs
         .iter()
         .filter(|(_, v)| !v.is_empty())
         .map(|(k, v)| format!(", {k}={}", format_python_string_literal(v)))
-        .collect()
+        .collect();
+    let opclasses: Vec<(&String, &String)> = index
+        .opclasses
+        .iter()
+        .filter(|(column, _)| !index.orderings.contains_key(*column))
+        .collect();
+    if !opclasses.is_empty() {
+        let ops: Vec<String> = opclasses
+            .iter()
+            .map(|(column, opclass)| {
+                format!(
+                    "{}: {}",
+                    format_python_string_literal(column),
+                    format_python_string_literal(opclass)
+                )
+            })
+            .collect();
+        out.push_str(&format!(", postgresql_ops={{{}}}", ops.join(", ")));
+    }
+    if dialect == Dialect::Mssql && index.clustered {
+        out.push_str(", mssql_clustered=True");
+    }
+    out.push_str(nulls_not_distinct_kwarg(index.nulls_not_distinct, dialect));
+    let include_kwarg = match dialect {
+        Dialect::Postgres => Some("postgresql_include"),
+        Dialect::Mssql => Some("mssql_include"),
+        Dialect::Mysql | Dialect::Sqlite => None,
+    };
+    if let (Some(kwarg), false) = (include_kwarg, index.include.is_empty()) {
+        let cols: Vec<String> = index
+            .include
+            .iter()
+            .map(|c| format_python_string_literal(c))
+            .collect();
+        out.push_str(&format!(", {kwarg}=[{}]", cols.join(", ")));
+    }
+    out
 }
 
 /// Generate a Python enum class from an EnumInfo.
@@ -103,12 +483,321 @@ pub fn generate_enum_class(enum_info: &crate::schema::EnumInfo) -> String {
     lines.join("\n")
 }
 
+/// SQLAlchemy mapping for a column under the generator options:
+/// GeoAlchemy2 types for PostGIS columns with `geoalchemy`, and the
+/// dialect-specific tables with `keep_dialect_types`. A `--typemap`
+/// override still wins over both.
+pub(crate) fn map_column_type_with(
+    col: &crate::schema::ColumnInfo,
+    dialect: Dialect,
+    options: &crate::cli::GeneratorOptions,
+) -> crate::typemap::MappedType {
+    if options.geoalchemy && dialect == Dialect::Postgres && col.type_override.is_none() {
+        if let Some(mapped) = crate::typemap::pg::map_spatial_type(col) {
+            return mapped;
+        }
+    }
+    if options.keep_dialect_types {
+        crate::typemap::map_column_type_dialect(col, dialect)
+    } else {
+        crate::typemap::map_column_type(col, dialect)
+    }
+}
+
+/// Comment lines to emit above a column definition: a `# WARNING:` when
+/// its type (`mapped`, as rendered) fell back to a stand-in, the name of its default constraint
+/// (which `server_default` cannot carry), then any `--sample` advisories.
+pub(crate) fn column_comment(
+    col: &crate::schema::ColumnInfo,
+    dialect: Dialect,
+    mapped: Option<&crate::typemap::MappedType>,
+    indent: &str,
+) -> Option<String> {
+    let lines: Vec<String> = [
+        crate::typemap::fallback_note(col, dialect, mapped)
+            .map(|n| format!("{indent}# WARNING: {n}")),
+        col.default_constraint
+            .as_deref()
+            .filter(|_| col.column_default.is_some())
+            .map(|name| format!("{indent}# Default constraint: {name}")),
+        crate::sample::advisory_comment(col, indent),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// `# WARNING:` lines for objects introspection skipped on a table and for
+/// a primary key uvg chose for it, emitted above its `Table()` or class.
+pub(crate) fn skipped_warnings(table: &crate::schema::TableInfo) -> Vec<String> {
+    table
+        .skipped
+        .iter()
+        .chain(&table.inferred_keys)
+        .map(|note| format!("# WARNING: {note}"))
+        .collect()
+}
+
+/// Comment block quoting a view's defining SQL, emitted above its
+/// `Table()` so the view reads as a view in generated output.
+pub(crate) fn view_definition_comment(table: &crate::schema::TableInfo) -> Option<String> {
+    // Keep the first line's indentation: pg_get_viewdef aligns the rest
+    // of the statement against it.
+    let sql = table
+        .view_definition
+        .as_deref()?
+        .trim_start_matches(['\r', '\n'])
+        .trim_end();
+    if sql.trim().is_empty() {
+        return None;
+    }
+    let mut lines = vec![format!("# View {}.{}:", table.schema, table.name)];
+    lines.extend(sql.lines().map(|l| match l.trim_end() {
+        "" => "#".to_string(),
+        l => format!("#   {l}"),
+    }));
+    Some(lines.join("\n"))
+}
+
+/// Docstring/comment text for a table the database maintains specially: a
+/// foreign table, a system-versioned temporal table or its history table,
+/// or a PostgreSQL `INHERITS` child, whose inherited columns are named
+/// since the model repeats them. Columnstore indexes, which the model
+/// cannot declare, are named as well, and so are the row-level security
+/// policies that filter what queries on the table see.
+pub(crate) fn table_note(table: &crate::schema::TableInfo) -> Option<String> {
+    let columnstore: Vec<String> = table
+        .indexes
+        .iter()
+        .filter(|index| index.columnstore)
+        .map(|index| {
+            let kind = if index.clustered {
+                "clustered"
+            } else {
+                "nonclustered"
+            };
+            format!("{} ({kind})", index.name)
+        })
+        .collect();
+    let columnstore = match columnstore.as_slice() {
+        [] => None,
+        [one] => Some(format!(
+            "Columnstore index {one} is not declared here; SQLAlchemy cannot create it."
+        )),
+        many => Some(format!(
+            "Columnstore indexes {} are not declared here; SQLAlchemy cannot create them.",
+            many.join(", ")
+        )),
+    };
+    let notes: Vec<String> = [
+        special_table_note(table),
+        columnstore,
+        row_security_note(table),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    (!notes.is_empty()).then(|| notes.join(" "))
+}
+
+/// Row-level security of a table, when enabled: each policy with its
+/// command, roles, and expressions, e.g. `tenant_isolation (ALL to app,
+/// USING tenant_id = current_tenant())`.
+fn row_security_note(table: &crate::schema::TableInfo) -> Option<String> {
+    let security = table.row_security.as_ref().filter(|rls| rls.enabled)?;
+    let scope = if security.forced {
+        "enabled and forced, so it binds the table owner too"
+    } else {
+        "enabled"
+    };
+    if security.policies.is_empty() {
+        return Some(format!(
+            "Row-level security is {scope}, with no policies: queries see no rows."
+        ));
+    }
+    let policies: Vec<String> = security
+        .policies
+        .iter()
+        .map(|policy| {
+            let mut head = policy.command.clone();
+            if !policy.permissive {
+                head.push_str(" restrictive");
+            }
+            if policy.roles.iter().any(|role| role != "public") {
+                head.push_str(&format!(" to {}", policy.roles.join(", ")));
+            }
+            let mut parts = vec![head];
+            if let Some(ref using) = policy.using {
+                parts.push(format!("USING {using}"));
+            }
+            if let Some(ref check) = policy.with_check {
+                parts.push(format!("WITH CHECK {check}"));
+            }
+            format!("{} ({})", policy.name, parts.join(", "))
+        })
+        .collect();
+    Some(format!(
+        "Row-level security is {scope}; queries see only the rows these policies allow: {}.",
+        policies.join("; ")
+    ))
+}
+
+/// Comments on a table's constraints and indexes, one `Index name: text`
+/// line each. SQLAlchemy's `Index` and constraint objects take no comment,
+/// so generators carry these in the class docstring or above `Table()`.
+pub(crate) fn object_comments(
+    table: &crate::schema::TableInfo,
+    options: &crate::cli::GeneratorOptions,
+) -> Vec<String> {
+    if options.nocomments {
+        return Vec::new();
+    }
+    let one_line = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut lines = Vec::new();
+    if !options.noconstraints {
+        for constraint in &table.constraints {
+            if let Some(ref comment) = constraint.comment {
+                lines.push(format!(
+                    "Constraint {}: {}",
+                    constraint.name,
+                    one_line(comment)
+                ));
+            }
+        }
+    }
+    if !options.noindexes {
+        for index in &table.indexes {
+            if let Some(ref comment) = index.comment {
+                lines.push(format!("Index {}: {}", index.name, one_line(comment)));
+            }
+        }
+    }
+    lines
+}
+
+fn special_table_note(table: &crate::schema::TableInfo) -> Option<String> {
+    if let Some(ref server) = table.foreign_server {
+        return Some(format!(
+            "Foreign table on server {server}; treat as read-only."
+        ));
+    }
+    if !table.inherits.is_empty() {
+        let inherited: Vec<&str> = table
+            .columns
+            .iter()
+            .filter(|c| c.inherited)
+            .map(|c| c.name.as_str())
+            .collect();
+        let parents = table.inherits.join(", ");
+        return Some(match inherited.as_slice() {
+            [] => format!("Inherits {parents} (PostgreSQL INHERITS)."),
+            [one] => {
+                format!("Inherits {parents} (PostgreSQL INHERITS); column {one} is inherited.")
+            }
+            many => format!(
+                "Inherits {parents} (PostgreSQL INHERITS); columns {} are inherited.",
+                many.join(", ")
+            ),
+        });
+    }
+    if let Some(ref history) = table.history_table {
+        Some(format!(
+            "System-versioned temporal table; row history is kept in {history}."
+        ))
+    } else {
+        table.history_of.as_ref().map(|current| {
+            format!(
+                "History table of system-versioned table {current}; SQL Server writes its rows."
+            )
+        })
+    }
+}
+
+/// `server_default`/`server_onupdate` marking a period column of a
+/// system-versioned table as set by the server on every write, so the ORM
+/// never sends a value and refreshes it afterwards.
+pub(crate) fn period_column_args(
+    col: &crate::schema::ColumnInfo,
+    imports: &mut super::imports::ImportCollector,
+) -> Option<&'static str> {
+    col.period?;
+    imports.add("sqlalchemy", "FetchedValue");
+    Some("server_default=FetchedValue(), server_onupdate=FetchedValue()")
+}
+
+/// Plain Python annotation type for a column (no `Optional[...]` wrapper),
+/// registering the stdlib module import it needs. Used by the generators
+/// that sit on top of the models rather than declaring them.
+pub(crate) fn column_python_type(
+    col: &crate::schema::ColumnInfo,
+    dialect: Dialect,
+    options: &crate::cli::GeneratorOptions,
+    imports: &mut super::imports::ImportCollector,
+) -> String {
+    let mapped = map_column_type_with(col, dialect, options);
+    if let Some(module) = ["datetime", "decimal", "uuid"]
+        .into_iter()
+        .find(|m| mapped.python_type.contains(&format!("{m}.")))
+    {
+        imports.add_bare(module);
+    }
+    // Range and GeoAlchemy2 annotations name their secondary import; an
+    // ARRAY's element type does not appear in its `list` annotation.
+    for (module, name) in &mapped.extra_imports {
+        if mapped.python_type.contains(name.as_str()) {
+            imports.add(module, name);
+        }
+    }
+    mapped.python_type
+}
+
+/// Render a `TypedDict` for sampled JSON keys. Uses class syntax when every
+/// key is a usable Python identifier, and the functional form otherwise.
+pub fn generate_typed_dict(
+    class_name: &str,
+    fields: &std::collections::BTreeMap<String, String>,
+) -> String {
+    let class_syntax = fields
+        .keys()
+        .all(|k| crate::naming::column_to_attr_name(k) == *k);
+    if class_syntax {
+        let mut lines = vec![format!("class {class_name}(TypedDict):")];
+        for (key, ty) in fields {
+            lines.push(format!("    {key}: {ty}"));
+        }
+        lines.join("\n")
+    } else {
+        let entries = fields
+            .iter()
+            .map(|(key, ty)| format!("{}: {ty}", format_python_string_literal(key)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "{class_name} = TypedDict({}, {{{entries}}})",
+            format_python_string_literal(class_name)
+        )
+    }
+}
+
 /// Get the Python class name for an enum.
 pub fn enum_class_name(enum_name: &str) -> String {
     use heck::ToUpperCamelCase;
     enum_name.to_upper_camel_case()
 }
 
+/// Module name for a schema's models under `--split-by-schema`. Names
+/// that would clash with the package's own `base.py` / `__init__.py` get
+/// a `_schema` suffix.
+fn schema_module(schema: &str) -> String {
+    use heck::ToSnakeCase;
+    let name = schema.to_snake_case();
+    match name.as_str() {
+        "" | "base" | "init" => format!("{name}_schema"),
+        _ => name,
+    }
+}
+
 /// Structured output of a Python code generator (#116): a shared prelude
 /// plus one named block per model. Both output modes derive from this —
 /// single-file rendering concatenates, `--split-tables` writes one file per
@@ -125,6 +814,27 @@ pub struct PythonOutput {
     /// Separator between model blocks in single-file mode: the declarative
     /// generator uses two blank lines (PEP 8 top-level), tables uses one.
     pub separator: &'static str,
+    /// Classes each model block references from other blocks, keyed by
+    /// module name. Only the split layout needs them; a single file
+    /// defines everything in one namespace.
+    pub links: HashMap<String, ModelLinks>,
+    /// Schema of the table behind each model block, keyed by module name.
+    /// `--split-by-schema` groups blocks by it; a block without one (the
+    /// FastAPI aggregate router) keeps a module of its own.
+    pub schemas: HashMap<String, String>,
+}
+
+/// Cross-module class references of one model block, as
+/// `(module, class)` pairs.
+#[derive(Debug, Default)]
+pub struct ModelLinks {
+    /// Classes needed when the module runs, e.g. a joined-inheritance
+    /// parent class.
+    pub runtime: BTreeSet<(String, String)>,
+    /// Classes named only in annotations (`relationship()` targets),
+    /// imported under `TYPE_CHECKING` so the modules don't import each
+    /// other in a cycle.
+    pub typing: BTreeSet<(String, String)>,
 }
 
 impl PythonOutput {
@@ -139,31 +849,149 @@ impl PythonOutput {
         out
     }
 
-    /// Render the split layout: `base.py` (prelude), one file per model
-    /// (prefixed with `from .base import *` so each is independently
-    /// importable), and an `__init__.py` re-exporting everything.
+    /// Render the split layout `options` asks for: one module per schema
+    /// with `--split-by-schema`, one per model otherwise.
+    pub fn split_with(self, options: &crate::cli::GeneratorOptions) -> Vec<(String, String)> {
+        if options.split_by_schema {
+            self.by_schema().split()
+        } else {
+            self.split()
+        }
+    }
+
+    /// Merge the model blocks into one module per schema, in order of
+    /// first appearance. Links between blocks that land in the same module
+    /// drop out in `split()`; the rest now point at the schema module.
+    pub fn by_schema(self) -> PythonOutput {
+        let group = |module: &str| match self.schemas.get(module) {
+            Some(schema) => schema_module(schema),
+            None => module.to_string(),
+        };
+
+        let mut models: Vec<(String, String)> = Vec::new();
+        let mut links: HashMap<String, ModelLinks> = HashMap::new();
+        for (module, code) in &self.models {
+            let name = group(module);
+            match models.iter_mut().find(|(existing, _)| *existing == name) {
+                Some((_, merged)) => {
+                    merged.push_str(self.separator);
+                    merged.push_str(code);
+                }
+                None => models.push((name.clone(), code.clone())),
+            }
+            if let Some(model_links) = self.links.get(module) {
+                let merged = links.entry(name).or_default();
+                let regroup = |(from, class): &(String, String)| (group(from), class.clone());
+                merged
+                    .runtime
+                    .extend(model_links.runtime.iter().map(regroup));
+                merged.typing.extend(model_links.typing.iter().map(regroup));
+            }
+        }
+
+        PythonOutput {
+            prelude: self.prelude,
+            models,
+            separator: self.separator,
+            links,
+            schemas: HashMap::new(),
+        }
+    }
+
+    /// Render the split layout: `base.py` (the prelude's definitions, when
+    /// it has any), one file per model, and an `__init__.py` re-exporting
+    /// every class. Each module imports
+    /// by name exactly what it uses: the prelude's imports, what `base.py`
+    /// defines (`Base`, enums, `metadata`), and the classes it references
+    /// from sibling modules, so each is independently importable.
     pub fn split(&self) -> Vec<(String, String)> {
         let mut files: Vec<(String, String)> = Vec::new();
 
-        let base_blocks: Vec<&str> = self
-            .prelude
+        let (imports, base_code) = PreludeImports::parse(&self.prelude);
+        let base_blocks: Vec<&str> = base_code
             .split("\n\n")
             .map(str::trim)
             .filter(|block| !block.is_empty())
             .collect();
-        files.push(("base.py".to_string(), base_blocks.join("\n\n") + "\n"));
+        let base_code = base_blocks.join("\n\n");
+        let base_imports = imports.render_for(&python_identifiers(&base_code));
+        let base_names = top_level_names(&base_code);
+        let base_file = [base_imports.as_str(), base_code.as_str()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        // The repository and FastAPI preludes are only imports.
+        if !base_file.is_empty() {
+            files.push(("base.py".to_string(), base_file + "\n"));
+        }
+
+        let mut exports: Vec<(String, Vec<&str>)> = Vec::new();
+        let mut base_exports: Vec<&str> = base_names.clone();
+        base_exports.sort_unstable();
+        exports.push(("base".to_string(), base_exports));
 
+        let empty = ModelLinks::default();
         for (module, code) in &self.models {
+            let code = code.trim();
+            let links = self.links.get(module).unwrap_or(&empty);
+            let import_lines = |classes: &BTreeSet<(String, String)>, skip: &BTreeSet<_>| {
+                classes
+                    .iter()
+                    .filter(|link| link.0 != *module && !skip.contains(*link))
+                    .map(|(from, class)| format!("from .{from} import {class}"))
+                    .collect::<Vec<_>>()
+            };
+            let runtime = import_lines(&links.runtime, &BTreeSet::new());
+            let typing = import_lines(&links.typing, &links.runtime);
+
+            let mut module_imports = imports.clone();
+            if !typing.is_empty() {
+                module_imports.add("typing", "TYPE_CHECKING");
+            }
+            let mut used = python_identifiers(code);
+            let mut from_base: Vec<&str> = base_names
+                .iter()
+                .copied()
+                .filter(|name| used.contains(name))
+                .collect();
+            from_base.sort_unstable();
+
+            let mut header = Vec::new();
+            if !typing.is_empty() {
+                used.insert("TYPE_CHECKING");
+            }
+            let rendered = module_imports.render_for(&used);
+            if !rendered.is_empty() {
+                header.push(rendered);
+            }
+            let mut local = Vec::new();
+            if !from_base.is_empty() {
+                local.push(format!("from .base import {}", from_base.join(", ")));
+            }
+            local.extend(runtime);
+            if !local.is_empty() {
+                header.push(local.join("\n"));
+            }
+            if !typing.is_empty() {
+                let lines: Vec<String> = typing.iter().map(|line| format!("    {line}")).collect();
+                header.push(format!("if TYPE_CHECKING:\n{}", lines.join("\n")));
+            }
             files.push((
                 format!("{module}.py"),
-                format!("from .base import *  # noqa\n\n{}\n", code.trim()),
+                format!("{}\n\n{code}\n", header.join("\n\n")),
             ));
-        }
 
-        let mut init_lines = vec!["from .base import *  # noqa".to_string()];
-        for (module, _) in &self.models {
-            init_lines.push(format!("from .{module} import *  # noqa"));
+            let mut names = top_level_names(code);
+            names.sort_unstable();
+            exports.push((module.clone(), names));
         }
+
+        let mut init_lines: Vec<String> = exports
+            .iter()
+            .filter(|(_, names)| !names.is_empty())
+            .map(|(module, names)| format!("from .{module} import {}", names.join(", ")))
+            .collect();
         init_lines.push(String::new());
         files.push(("__init__.py".to_string(), init_lines.join("\n")));
 
@@ -4,8 +4,15 @@
 //! - Which columns should use inline `ForeignKey()` vs `ForeignKeyConstraint` in `__table_args__`
 //! - What `relationship()` calls to generate on each class
 
-use crate::naming::table_to_class_name;
-use crate::schema::{ConstraintInfo, ConstraintType, IntrospectedSchema, TableInfo};
+use crate::cli::GeneratorOptions;
+use crate::codegen::declarative::class_attr_names;
+use crate::codegen::python::format_python_string_literal;
+use crate::naming::{
+    column_to_attr_name, is_python_identifier, is_python_keyword, table_class_name,
+};
+use crate::schema::{
+    ConstraintInfo, ConstraintType, ForeignKeyInfo, IntrospectedSchema, TableInfo,
+};
 
 /// A relationship() call to generate on a class.
 #[derive(Debug, Clone)]
@@ -21,6 +28,48 @@ pub struct RelationshipInfo {
     pub uselist_false: bool,
     /// For M2M: the secondary (association) table name
     pub secondary: Option<String>,
+    /// For a self-referential M2M: the `primaryjoin` and `secondaryjoin`
+    /// expressions, since both foreign keys of the association table
+    /// point at the same class.
+    pub joins: Option<(String, String)>,
+    /// The foreign key this relationship follows, which pairs it with its
+    /// reverse on the target class.
+    pub path: RelationshipPath,
+}
+
+/// A foreign key seen from one end. A relationship and its reverse share
+/// `via` and differ in `fk_side`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationshipPath {
+    /// `schema.table.constraint(columns)` of the foreign key, or
+    /// `schema.table` of a many-to-many association table.
+    pub via: String,
+    /// Whether the class maps the table holding the foreign key (for an
+    /// association table: the target of its first foreign key).
+    pub fk_side: bool,
+}
+
+impl RelationshipPath {
+    fn of(table: &TableInfo, constraint: &ConstraintInfo, fk_side: bool) -> Self {
+        RelationshipPath {
+            via: format!(
+                "{}.{}.{}({})",
+                table.schema,
+                table.name,
+                constraint.name,
+                constraint.columns.join(", ")
+            ),
+            fk_side,
+        }
+    }
+
+    /// The same foreign key seen from its other end.
+    pub fn reverse(&self) -> Self {
+        RelationshipPath {
+            via: self.via.clone(),
+            fk_side: !self.fk_side,
+        }
+    }
 }
 
 /// Find the single-column FK constraint for a given column, if any.
@@ -51,38 +100,84 @@ pub fn has_unique_constraint(col_name: &str, constraints: &[ConstraintInfo]) ->
 
 /// Derive the relationship attribute name on the child side.
 /// Strips `_id` suffix from FK column name, also handles uppercase `ID` suffix.
-/// When `noidsuffix` is true, keeps the full column name.
+/// When `noidsuffix` is true, keeps the full column name. The result is
+/// sanitized like a column attribute, so `order-id` gives `order` and
+/// `class_id` gives `class_`.
 fn fk_col_to_relationship_name(col_name: &str, noidsuffix: bool) -> String {
+    let sanitized = column_to_attr_name(col_name);
     if noidsuffix {
-        return col_name.to_string();
+        return sanitized;
     }
-    col_name
+    let stripped = sanitized
         .strip_suffix("_id")
-        .or_else(|| col_name.strip_suffix("ID"))
-        .unwrap_or(col_name)
-        .to_string()
+        .or_else(|| sanitized.strip_suffix("ID"))
+        .filter(|rest| !rest.is_empty())
+        .unwrap_or(&sanitized);
+    column_to_attr_name(stripped)
 }
 
-/// Count how many FK constraints from `table` reference `target_table`.
-fn count_fks_to_table(table: &TableInfo, target_table: &str) -> usize {
+/// A relationship attribute named after a table, sanitized like a column
+/// attribute.
+fn table_relationship_name(table_name: &str) -> String {
+    column_to_attr_name(table_name)
+}
+
+/// Count how many FK constraints from `table` reference the same table as `fk`.
+fn count_fks_to_table(
+    table: &TableInfo,
+    schema: &IntrospectedSchema,
+    fk: &ForeignKeyInfo,
+) -> usize {
+    let target = schema.referenced_table(fk);
     table
         .constraints
         .iter()
         .filter(|c| {
+            c.constraint_type == ConstraintType::ForeignKey
+                && c.foreign_key.as_ref().is_some_and(|other| match target {
+                    Some(target) => schema.references(other, target),
+                    None => other.ref_table == fk.ref_table,
+                })
+        })
+        .count()
+}
+
+/// Whether `target` has a foreign key back to `table`, so the two classes
+/// are joined along more than one path and each relationship needs
+/// `foreign_keys=`.
+fn references_back(table: &TableInfo, target: &TableInfo, schema: &IntrospectedSchema) -> bool {
+    !std::ptr::eq(table, target)
+        && target.constraints.iter().any(|c| {
             c.constraint_type == ConstraintType::ForeignKey
                 && c.foreign_key
                     .as_ref()
-                    .is_some_and(|fk| fk.ref_table == target_table)
+                    .is_some_and(|fk| schema.references(fk, table))
         })
-        .count()
+}
+
+/// The mapped attribute a column of `table` is bound to, which differs
+/// from the column name when it is sanitized or renamed (`--rename-map`).
+fn column_attr(
+    table: &TableInfo,
+    col_name: &str,
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
+) -> String {
+    table
+        .columns
+        .iter()
+        .position(|c| c.name == col_name)
+        .map(|idx| class_attr_names(table, schema.dialect, options).swap_remove(idx))
+        .unwrap_or_else(|| col_name.to_string())
 }
 
 /// Generate relationships for a table based on its FK constraints (child/many side).
 pub fn generate_child_relationships(
     table: &TableInfo,
-    _schema: &IntrospectedSchema,
-    noidsuffix: bool,
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
 ) -> Vec<RelationshipInfo> {
+    let noidsuffix = options.noidsuffix;
     let mut rels = Vec::new();
 
     let fk_constraints: Vec<&ConstraintInfo> = table
@@ -92,7 +187,7 @@ pub fn generate_child_relationships(
         .collect();
 
     // Check if this table uses inheritance (skip the inheritance FK for relationships)
-    let inheritance_parent = find_inheritance_parent(table, _schema);
+    let inheritance_parent = find_inheritance_parent(table, schema);
     let pk_col_name = table
         .constraints
         .iter()
@@ -109,19 +204,27 @@ pub fn generate_child_relationships(
         // Only skip the FK where the local column IS the table's PK column.
         if inheritance_parent.is_some()
             && is_single_column_fk(constraint)
-            && fk.ref_table == inheritance_parent.unwrap()
+            && inheritance_parent.is_some_and(|parent| schema.references(fk, parent))
             && pk_col_name.as_deref() == Some(&constraint.columns[0])
         {
             continue;
         }
 
-        let target_class = table_to_class_name(&fk.ref_table);
-        let is_selfref = fk.ref_table == table.name;
-        let multi_ref = count_fks_to_table(table, &fk.ref_table) > 1;
+        // A table left out of the output (`--tables`) has no class to
+        // relate to; the column keeps its `ForeignKey()`.
+        let Some(target) = schema.referenced_table(fk) else {
+            continue;
+        };
+        let target_class = table_class_name(schema, target, options);
+        let is_selfref = schema.references(fk, table);
+        let multi_ref = count_fks_to_table(table, schema, fk) > 1;
+        let ambiguous = multi_ref || references_back(table, target, schema);
+        let path = RelationshipPath::of(table, constraint, true);
 
         if is_single_column_fk(constraint) {
             let col_name = &constraint.columns[0];
             let rel_name = fk_col_to_relationship_name(col_name, noidsuffix);
+            let col_attr = column_attr(table, col_name, schema, options);
 
             let is_nullable = table
                 .columns
@@ -139,14 +242,16 @@ pub fn generate_child_relationships(
                     is_collection: false,
                     is_nullable,
                     back_populates: reverse_name.clone(),
-                    remote_side: Some(ref_col.to_string()),
+                    remote_side: Some(column_attr(table, ref_col, schema, options)),
                     foreign_keys: if multi_ref {
-                        Some(format!("[{col_name}]"))
+                        Some(format!("[{col_attr}]"))
                     } else {
                         None
                     },
                     uselist_false: false,
                     secondary: None,
+                    joins: None,
+                    path: path.clone(),
                 });
                 rels.push(RelationshipInfo {
                     attr_name: reverse_name,
@@ -154,20 +259,22 @@ pub fn generate_child_relationships(
                     is_collection: true,
                     is_nullable: false,
                     back_populates: rel_name,
-                    remote_side: Some(col_name.to_string()),
+                    remote_side: Some(col_attr.clone()),
                     foreign_keys: if multi_ref {
-                        Some(format!("[{col_name}]"))
+                        Some(format!("[{col_attr}]"))
                     } else {
                         None
                     },
                     uselist_false: false,
                     secondary: None,
+                    joins: None,
+                    path: path.reverse(),
                 });
             } else {
                 let back_pop = if multi_ref {
-                    format!("{}_{}", table.name, rel_name)
+                    table_relationship_name(&format!("{}_{}", table.name, rel_name))
                 } else {
-                    table.name.clone()
+                    table_relationship_name(&table.name)
                 };
 
                 rels.push(RelationshipInfo {
@@ -177,13 +284,15 @@ pub fn generate_child_relationships(
                     is_nullable,
                     back_populates: back_pop,
                     remote_side: None,
-                    foreign_keys: if multi_ref {
-                        Some(format!("[{col_name}]"))
+                    foreign_keys: if ambiguous {
+                        Some(format!("[{col_attr}]"))
                     } else {
                         None
                     },
                     uselist_false: false,
                     secondary: None,
+                    joins: None,
+                    path,
                 });
             }
         } else {
@@ -196,8 +305,16 @@ pub fn generate_child_relationships(
                     .is_none_or(|c| c.is_nullable)
             });
 
-            let rel_name = fk.ref_table.clone();
-            let back_pop = table.name.clone();
+            let rel_name = table_relationship_name(&fk.ref_table);
+            let back_pop = table_relationship_name(&table.name);
+            let foreign_keys = ambiguous.then(|| {
+                let attrs: Vec<String> = constraint
+                    .columns
+                    .iter()
+                    .map(|col| column_attr(table, col, schema, options))
+                    .collect();
+                format!("[{}]", attrs.join(", "))
+            });
 
             rels.push(RelationshipInfo {
                 attr_name: rel_name,
@@ -206,9 +323,11 @@ pub fn generate_child_relationships(
                 is_nullable,
                 back_populates: back_pop,
                 remote_side: None,
-                foreign_keys: None,
+                foreign_keys,
                 uselist_false: false,
                 secondary: None,
+                joins: None,
+                path,
             });
         }
     }
@@ -220,17 +339,18 @@ pub fn generate_child_relationships(
 pub fn generate_parent_relationships(
     parent_table: &TableInfo,
     schema: &IntrospectedSchema,
-    noidsuffix: bool,
+    options: &GeneratorOptions,
 ) -> Vec<RelationshipInfo> {
+    let noidsuffix = options.noidsuffix;
     let mut rels = Vec::new();
 
     for child_table in &schema.tables {
-        if child_table.name == parent_table.name {
+        if std::ptr::eq(child_table, parent_table) {
             continue;
         }
 
         // Skip association tables — they generate M2M relationships instead
-        if is_association_table(child_table) {
+        if renders_as_association(child_table, options) {
             continue;
         }
 
@@ -246,23 +366,26 @@ pub fn generate_parent_relationships(
                 c.constraint_type == ConstraintType::ForeignKey
                     && c.foreign_key
                         .as_ref()
-                        .is_some_and(|fk| fk.ref_table == parent_table.name)
+                        .is_some_and(|fk| schema.references(fk, parent_table))
             })
             .collect();
 
         let multi_ref = fk_constraints.len() > 1;
-        let child_class = table_to_class_name(&child_table.name);
+        let ambiguous = multi_ref || references_back(child_table, parent_table, schema);
+        let child_class = table_class_name(schema, child_table, options);
 
         for constraint in &fk_constraints {
+            let path = RelationshipPath::of(child_table, constraint, false);
             if is_single_column_fk(constraint) {
                 let col_name = &constraint.columns[0];
                 let child_rel_name = fk_col_to_relationship_name(col_name, noidsuffix);
+                let col_attr = column_attr(child_table, col_name, schema, options);
                 let is_onetoone = has_unique_constraint(col_name, &child_table.constraints);
 
                 let attr_name = if multi_ref {
-                    format!("{}_{}", child_table.name, child_rel_name)
+                    table_relationship_name(&format!("{}_{}", child_table.name, child_rel_name))
                 } else {
-                    child_table.name.clone()
+                    table_relationship_name(&child_table.name)
                 };
 
                 if is_onetoone {
@@ -274,13 +397,15 @@ pub fn generate_parent_relationships(
                         is_nullable: true,
                         back_populates: child_rel_name,
                         remote_side: None,
-                        foreign_keys: if multi_ref {
-                            Some(format!("'[{}.{}]'", child_class, col_name))
+                        foreign_keys: if ambiguous {
+                            Some(format!("'[{child_class}.{col_attr}]'"))
                         } else {
                             None
                         },
                         uselist_false: true,
                         secondary: None,
+                        joins: None,
+                        path,
                     });
                 } else {
                     // One-to-many: list on parent side
@@ -291,19 +416,34 @@ pub fn generate_parent_relationships(
                         is_nullable: false,
                         back_populates: child_rel_name,
                         remote_side: None,
-                        foreign_keys: if multi_ref {
-                            Some(format!("'[{}.{}]'", child_class, col_name))
+                        foreign_keys: if ambiguous {
+                            Some(format!("'[{child_class}.{col_attr}]'"))
                         } else {
                             None
                         },
                         uselist_false: false,
                         secondary: None,
+                        joins: None,
+                        path,
                     });
                 }
             } else {
                 // Composite FK reverse
-                let attr_name = child_table.name.clone();
-                let back_pop = parent_table.name.clone();
+                let attr_name = table_relationship_name(&child_table.name);
+                let back_pop = table_relationship_name(&parent_table.name);
+                let foreign_keys = ambiguous.then(|| {
+                    let attrs: Vec<String> = constraint
+                        .columns
+                        .iter()
+                        .map(|col| {
+                            format!(
+                                "{child_class}.{}",
+                                column_attr(child_table, col, schema, options)
+                            )
+                        })
+                        .collect();
+                    format!("'[{}]'", attrs.join(", "))
+                });
 
                 rels.push(RelationshipInfo {
                     attr_name,
@@ -312,9 +452,11 @@ pub fn generate_parent_relationships(
                     is_nullable: false,
                     back_populates: back_pop,
                     remote_side: None,
-                    foreign_keys: None,
+                    foreign_keys,
                     uselist_false: false,
                     secondary: None,
+                    joins: None,
+                    path,
                 });
             }
         }
@@ -348,9 +490,16 @@ pub fn is_association_table(table: &TableInfo) -> bool {
         .all(|c| fk_cols.contains(c.name.as_str()))
 }
 
-/// For a many-to-many association table, get the two target table names and
-/// the relationship info for each side.
-pub fn get_m2m_targets(assoc_table: &TableInfo) -> Option<(String, String)> {
+/// Whether `table` is rendered as a bare association `Table()` linked by
+/// `secondary=` relationships. The `noassociations` option turns detection
+/// off, so such tables become ordinary classes.
+pub fn renders_as_association(table: &TableInfo, options: &GeneratorOptions) -> bool {
+    !options.noassociations && is_association_table(table)
+}
+
+/// For a many-to-many association table, the foreign keys to its two
+/// target tables.
+pub fn get_m2m_targets(assoc_table: &TableInfo) -> Option<(&ForeignKeyInfo, &ForeignKeyInfo)> {
     let fk_constraints: Vec<&ConstraintInfo> = assoc_table
         .constraints
         .iter()
@@ -361,25 +510,23 @@ pub fn get_m2m_targets(assoc_table: &TableInfo) -> Option<(String, String)> {
         return None;
     }
 
-    let t1 = fk_constraints[0]
-        .foreign_key
-        .as_ref()
-        .map(|fk| fk.ref_table.clone())?;
-    let t2 = fk_constraints[1]
-        .foreign_key
-        .as_ref()
-        .map(|fk| fk.ref_table.clone())?;
+    let fk1 = fk_constraints[0].foreign_key.as_ref()?;
+    let fk2 = fk_constraints[1].foreign_key.as_ref()?;
 
-    Some((t1, t2))
+    Some((fk1, fk2))
 }
 
 /// Generate M2M relationships for a table based on association tables pointing to it.
+/// An association table whose foreign keys both point at `table` gives one
+/// relationship per direction, joined explicitly.
 pub fn generate_m2m_relationships(
     table: &TableInfo,
     schema: &IntrospectedSchema,
-    default_schema: &str,
-    noidsuffix: bool,
+    options: &GeneratorOptions,
 ) -> Vec<RelationshipInfo> {
+    let noidsuffix = options.noidsuffix;
+    let default_schema = schema.dialect.default_schema();
+    let class_name = table_class_name(schema, table, options);
     let mut rels = Vec::new();
 
     for assoc_table in &schema.tables {
@@ -387,18 +534,17 @@ pub fn generate_m2m_relationships(
             continue;
         }
 
-        let (t1, t2) = match get_m2m_targets(assoc_table) {
+        let (fk1, fk2) = match get_m2m_targets(assoc_table) {
             Some(targets) => targets,
             None => continue,
         };
 
         // Check if this table is one of the M2M targets
-        if table.name != t1 && table.name != t2 {
-            continue;
-        }
-
-        let other_table = if table.name == t1 { &t2 } else { &t1 };
-        let other_class = table_to_class_name(other_table);
+        let directions: Vec<(&ForeignKeyInfo, &ForeignKeyInfo)> = [(fk1, fk2), (fk2, fk1)]
+            .into_iter()
+            .filter(|(own_fk, _)| schema.references(own_fk, table))
+            .collect();
+        let self_ref = directions.len() == 2;
 
         // Determine the secondary table reference
         let secondary = if assoc_table.schema != default_schema && !assoc_table.schema.is_empty() {
@@ -407,52 +553,92 @@ pub fn generate_m2m_relationships(
             assoc_table.name.clone()
         };
 
-        // Derive relationship name from the FK column targeting the other table
-        let rel_name = derive_m2m_rel_name(assoc_table, other_table, noidsuffix);
-
-        // back_populates: the other table's relationship name for this table
-        let back_pop = derive_m2m_rel_name(assoc_table, &table.name, noidsuffix);
-
-        rels.push(RelationshipInfo {
-            attr_name: rel_name,
-            target_class: other_class,
-            is_collection: true,
-            is_nullable: false,
-            back_populates: back_pop,
-            remote_side: None,
-            foreign_keys: None,
-            uselist_false: false,
-            secondary: Some(secondary),
-        });
+        for (own_fk, other_fk) in directions {
+            let Some(other) = schema.referenced_table(other_fk) else {
+                continue;
+            };
+            let other_class = table_class_name(schema, other, options);
+
+            // Derive relationship name from the FK column targeting the other table
+            let rel_name = derive_m2m_rel_name(assoc_table, other_fk, noidsuffix);
+
+            // back_populates: the other table's relationship name for this table
+            let back_pop = derive_m2m_rel_name(assoc_table, own_fk, noidsuffix);
+
+            let joins = self_ref.then(|| {
+                let join = |fk: &ForeignKeyInfo| {
+                    let ref_attr = column_attr(table, &fk.ref_columns[0], schema, options);
+                    let column = match fk_column(assoc_table, fk) {
+                        Some(col) if is_python_identifier(col) && !is_python_keyword(col) => {
+                            format!(".c.{col}")
+                        }
+                        Some(col) => format!(".c[{}]", format_python_string_literal(col)),
+                        None => String::new(),
+                    };
+                    format_python_string_literal(&format!(
+                        "{class_name}.{ref_attr} == {secondary}{column}"
+                    ))
+                };
+                (join(own_fk), join(other_fk))
+            });
+
+            rels.push(RelationshipInfo {
+                attr_name: rel_name,
+                target_class: other_class,
+                is_collection: true,
+                is_nullable: false,
+                back_populates: back_pop,
+                remote_side: None,
+                foreign_keys: None,
+                uselist_false: false,
+                secondary: Some(secondary.clone()),
+                joins,
+                path: RelationshipPath {
+                    via: format!("{}.{}", assoc_table.schema, assoc_table.name),
+                    fk_side: std::ptr::eq(own_fk, fk1),
+                },
+            });
+        }
     }
 
     rels
 }
 
-/// Derive the M2M relationship name from the FK column targeting the OTHER table.
+/// The column of the single-column foreign key `fk` of `assoc_table`.
+fn fk_column<'a>(assoc_table: &'a TableInfo, fk: &ForeignKeyInfo) -> Option<&'a str> {
+    assoc_table
+        .constraints
+        .iter()
+        .find(|c| {
+            c.foreign_key
+                .as_ref()
+                .is_some_and(|other| std::ptr::eq(other, fk))
+                && c.columns.len() == 1
+        })
+        .map(|c| c.columns[0].as_str())
+}
+
+/// Derive the M2M relationship name from the association table's FK `target`.
 /// E.g., for LeftTable looking through assoc with left_id/right_id FK columns,
 /// the relationship name is "right" (from right_id pointing to RightTable).
-fn derive_m2m_rel_name(assoc_table: &TableInfo, other_table: &str, noidsuffix: bool) -> String {
-    // Find the FK column that points TO other_table
-    for constraint in &assoc_table.constraints {
-        if constraint.constraint_type == ConstraintType::ForeignKey {
-            if let Some(ref fk) = constraint.foreign_key {
-                if fk.ref_table == other_table && constraint.columns.len() == 1 {
-                    return fk_col_to_relationship_name(&constraint.columns[0], noidsuffix);
-                }
-            }
-        }
+fn derive_m2m_rel_name(
+    assoc_table: &TableInfo,
+    target: &ForeignKeyInfo,
+    noidsuffix: bool,
+) -> String {
+    match fk_column(assoc_table, target) {
+        Some(col) => fk_col_to_relationship_name(col, noidsuffix),
+        // Fallback: use the other table name
+        None => table_relationship_name(&target.ref_table),
     }
-    // Fallback: use the other table name
-    other_table.to_string()
 }
 
-/// Detect joined table inheritance: returns the parent table name if this table's
+/// Detect joined table inheritance: returns the parent table if this table's
 /// PK column is also a single-column FK to another table's PK.
 pub fn find_inheritance_parent<'a>(
     table: &TableInfo,
     schema: &'a IntrospectedSchema,
-) -> Option<&'a str> {
+) -> Option<&'a TableInfo> {
     // Get PK columns
     let pk_constraint = table
         .constraints
@@ -480,10 +666,7 @@ pub fn find_inheritance_parent<'a>(
     }
 
     // Verify the target is a PK in the parent table
-    let parent = schema
-        .tables
-        .iter()
-        .find(|t| t.name == fk_info.ref_table && t.schema == fk_info.ref_schema)?;
+    let parent = schema.referenced_table(fk_info)?;
 
     let parent_pk = parent
         .constraints
@@ -491,16 +674,19 @@ pub fn find_inheritance_parent<'a>(
         .find(|c| c.constraint_type == ConstraintType::PrimaryKey)?;
 
     if parent_pk.columns.len() == 1 && parent_pk.columns[0] == fk_info.ref_columns[0] {
-        Some(&parent.name)
+        Some(parent)
     } else {
         None
     }
 }
 
-/// Render a relationship line.
-pub fn render_relationship(rel: &RelationshipInfo) -> String {
+/// Render a relationship line. With the `async` option relationships load
+/// with `selectin`, since async sessions cannot lazy-load on attribute access.
+pub fn render_relationship(rel: &RelationshipInfo, options: &GeneratorOptions) -> String {
     let type_annotation = if rel.is_collection {
         format!("list['{}']", rel.target_class)
+    } else if rel.is_nullable && options.pep604 {
+        format!("'{} | None'", rel.target_class)
     } else if rel.is_nullable {
         format!("Optional['{}']", rel.target_class)
     } else {
@@ -522,6 +708,11 @@ pub fn render_relationship(rel: &RelationshipInfo) -> String {
         args.push(format!("secondary='{sec}'"));
     }
 
+    if let Some((ref primary, ref secondary)) = rel.joins {
+        args.push(format!("primaryjoin={primary}"));
+        args.push(format!("secondaryjoin={secondary}"));
+    }
+
     if let Some(ref fk) = rel.foreign_keys {
         args.push(format!("foreign_keys={fk}"));
     }
@@ -530,6 +721,10 @@ pub fn render_relationship(rel: &RelationshipInfo) -> String {
         args.push(format!("back_populates='{}'", rel.back_populates));
     }
 
+    if options.asyncio {
+        args.push("lazy='selectin'".to_string());
+    }
+
     let args_str = args.join(", ");
     format!(
         "    {}: Mapped[{type_annotation}] = relationship({args_str})",
@@ -14,8 +14,10 @@ pub(in crate::codegen) mod ident;
 pub(in crate::codegen) mod indexes;
 
 pub(in crate::codegen) use checks::{check_predicate_is_portable, translate_check_predicate};
-pub(in crate::codegen) use column::generate_column_def;
-pub(in crate::codegen) use create_table::generate_create_table;
+pub(in crate::codegen) use column::{column_def_warnings, generate_column_def};
+pub(in crate::codegen) use create_table::{
+    exclude_constraint_sql, fk_deferral, generate_create_table,
+};
 pub(in crate::codegen) use defaults::format_ddl_default_typed;
 pub(in crate::codegen) use ident::{qualified_object_name, qualified_table_name, quote_identifier};
-pub(in crate::codegen) use indexes::{generate_indexes, postgres_index_method};
+pub(in crate::codegen) use indexes::{create_index_sql, generate_indexes, nulls_not_distinct_sql};
@@ -7,6 +7,7 @@ use crate::codegen::{
 use crate::ddl_typemap;
 use crate::dialect::Dialect;
 use crate::schema::{ColumnInfo, ConstraintInfo, EnumInfo};
+use crate::warnings::WarningKind;
 
 use super::defaults::{
     format_ddl_default_typed, reattach_now_family_precision, temporal_precision,
@@ -21,6 +22,7 @@ pub(in crate::codegen) fn generate_column_def(
     target_dialect: Dialect,
     shared_sequences: &BTreeSet<String>,
     enum_info: Option<&EnumInfo>,
+    with_comment: bool,
 ) -> String {
     let qname = quote_identifier(&col.name, target_dialect);
 
@@ -50,21 +52,13 @@ pub(in crate::codegen) fn generate_column_def(
     // Type
     let type_str = if render_as_auto {
         format_autoincrement_type(col, source_dialect, target_dialect, is_pk)
-    } else if source_dialect.supports_native_enums() && target_dialect.supports_native_enums() {
-        match enum_info {
-            Some(enum_info) => {
-                let mut enum_type = qualified_object_name(
-                    enum_info.schema.as_deref(),
-                    &enum_info.name,
-                    target_dialect,
-                );
-                if is_enum_array_column(col) {
-                    enum_type.push_str("[]");
-                }
-                enum_type
-            }
-            None => ddl_typemap::from_canonical(&canonical, target_dialect).sql_type,
+    } else if let Some(enum_info) = native_enum(enum_info, source_dialect, target_dialect) {
+        let mut enum_type =
+            qualified_object_name(enum_info.schema.as_deref(), &enum_info.name, target_dialect);
+        if is_enum_array_column(col) {
+            enum_type.push_str("[]");
         }
+        enum_type
     } else {
         ddl_typemap::from_canonical(&canonical, target_dialect).sql_type
     };
@@ -76,28 +70,10 @@ pub(in crate::codegen) fn generate_column_def(
         parts.push("NOT NULL".to_string());
     }
 
-    // DEFAULT (skip for auto-increment columns).
-    // MySQL <8.0.13 rejects DEFAULT on BLOB/TEXT/GEOMETRY/JSON columns with
-    // ERROR 1101; >=8.0.13 only accepts expression defaults wrapped in
-    // parens, which would still drop a literal default like '{}'. Conservative
-    // behavior here: drop the default when targeting MySQL on a column whose
-    // canonical type is in that no-default class. The column still gets
-    // created, just without the default. See #34.
+    // DEFAULT (skip for auto-increment columns and where the target cannot
+    // hold one; see `drops_default`).
     let mysql_target = target_dialect == Dialect::Mysql;
-    // CanonicalType::Array also lands here — pg `text[]` serializes to MySQL
-    // `JSON` (see mysql::from_canonical), so it inherits the same "no
-    // DEFAULT on JSON" rule. Without this, pg→mysql arrays with `'{}'`
-    // defaults trip ERROR 1101 the same way native JSON columns do.
-    let no_default_on_mysql = mysql_target
-        && matches!(
-            canonical,
-            crate::ddl_typemap::CanonicalType::Json
-                | crate::ddl_typemap::CanonicalType::Jsonb
-                | crate::ddl_typemap::CanonicalType::Text
-                | crate::ddl_typemap::CanonicalType::Bytes { length: None }
-                | crate::ddl_typemap::CanonicalType::Array { .. }
-        );
-    if !render_as_auto && !no_default_on_mysql {
+    if !render_as_auto && !drops_default(&canonical, target_dialect) {
         if let Some(ref default) = col.column_default {
             let mut ddl_default =
                 format_ddl_default_typed(default, source_dialect, target_dialect, is_boolean);
@@ -114,6 +90,17 @@ pub(in crate::codegen) fn generate_column_def(
                     ddl_default = reattach_now_family_precision(&ddl_default, p);
                 }
             }
+            // Keep MSSQL default-constraint names so later drops can name them.
+            if let Some(name) = col
+                .default_constraint
+                .as_deref()
+                .filter(|_| target_dialect == Dialect::Mssql)
+            {
+                parts.push(format!(
+                    "CONSTRAINT {}",
+                    quote_identifier(name, target_dialect)
+                ));
+            }
             parts.push(format!("DEFAULT {ddl_default}"));
         }
     }
@@ -127,7 +114,7 @@ pub(in crate::codegen) fn generate_column_def(
     }
 
     // MySQL inline column comment
-    if target_dialect == Dialect::Mysql {
+    if target_dialect == Dialect::Mysql && with_comment {
         if let Some(ref comment) = col.comment {
             parts.push(format!("COMMENT '{}'", comment.replace('\'', "''")));
         }
@@ -136,6 +123,79 @@ pub(in crate::codegen) fn generate_column_def(
     parts.join(" ")
 }
 
+/// The named enum type a column keeps, when both dialects have native
+/// enums; otherwise the column gets the canonical mapping.
+fn native_enum(
+    enum_info: Option<&EnumInfo>,
+    source_dialect: Dialect,
+    target_dialect: Dialect,
+) -> Option<&EnumInfo> {
+    enum_info.filter(|_| {
+        source_dialect.supports_native_enums() && target_dialect.supports_native_enums()
+    })
+}
+
+/// Whether a column of this type loses its default on `target_dialect`.
+/// MySQL <8.0.13 rejects DEFAULT on BLOB/TEXT/GEOMETRY/JSON columns with
+/// ERROR 1101; >=8.0.13 only accepts expression defaults wrapped in
+/// parens, which would still drop a literal default like '{}'. Conservative
+/// behavior here: drop the default when targeting MySQL on a column whose
+/// canonical type is in that no-default class. The column still gets
+/// created, just without the default. See #34.
+///
+/// CanonicalType::Array also lands here — pg `text[]` serializes to MySQL
+/// `JSON` (see mysql::from_canonical), so it inherits the same "no
+/// DEFAULT on JSON" rule. Without this, pg→mysql arrays with `'{}'`
+/// defaults trip ERROR 1101 the same way native JSON columns do.
+fn drops_default(canonical: &ddl_typemap::CanonicalType, target_dialect: Dialect) -> bool {
+    target_dialect == Dialect::Mysql
+        && matches!(
+            canonical,
+            ddl_typemap::CanonicalType::Json
+                | ddl_typemap::CanonicalType::Jsonb
+                | ddl_typemap::CanonicalType::Text
+                | ddl_typemap::CanonicalType::Bytes { length: None }
+                | ddl_typemap::CanonicalType::Array { .. }
+        )
+}
+
+/// What rendering `col` for `target_dialect` loses: a type with no exact
+/// equivalent, and a default the target cannot hold.
+pub(in crate::codegen) fn column_def_warnings(
+    col: &ColumnInfo,
+    source_dialect: Dialect,
+    target_dialect: Dialect,
+    enum_info: Option<&EnumInfo>,
+) -> Vec<(WarningKind, String)> {
+    if is_auto_increment_column(col, source_dialect) {
+        return Vec::new();
+    }
+    let canonical = ddl_typemap::to_canonical(col, source_dialect);
+    let mut warnings = Vec::new();
+    if native_enum(enum_info, source_dialect, target_dialect).is_none() {
+        let ddl_type = ddl_typemap::from_canonical(&canonical, target_dialect);
+        if let Some(warning) = ddl_type.warning {
+            warnings.push((
+                WarningKind::TypeFallback,
+                format!("{warning}; rendered as {}", ddl_type.sql_type),
+            ));
+        }
+    }
+    if let Some(default) = col
+        .column_default
+        .as_deref()
+        .filter(|_| drops_default(&canonical, target_dialect))
+    {
+        warnings.push((
+            WarningKind::DroppedDefault,
+            format!(
+                "DEFAULT {default} dropped: MySQL allows no default on TEXT, BLOB, or JSON columns"
+            ),
+        ));
+    }
+    warnings
+}
+
 /// Get the type string for an auto-increment column, potentially overriding the base type.
 fn format_autoincrement_type(
     col: &ColumnInfo,
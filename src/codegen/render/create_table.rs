@@ -3,11 +3,12 @@ use std::collections::BTreeSet;
 use crate::cli::DdlOptions;
 use crate::codegen::{find_enum_for_ddl_column, is_auto_increment_column, is_primary_key_column};
 use crate::dialect::Dialect;
-use crate::schema::{ConstraintType, EnumInfo, TableInfo};
+use crate::schema::{ConstraintType, EnumInfo, ExcludeInfo, ForeignKeyInfo, TableInfo};
 
 use super::checks::{check_predicate_is_portable, translate_check_predicate};
 use super::column::generate_column_def;
 use super::ident::{qualified_table_name, quote_identifier};
+use super::indexes::nulls_not_distinct_sql;
 
 /// Generate a CREATE TABLE statement.
 pub(in crate::codegen) fn generate_create_table(
@@ -42,6 +43,7 @@ pub(in crate::codegen) fn generate_create_table(
             target_dialect,
             shared_sequences,
             find_enum_for_ddl_column(col, &table.schema, enums),
+            options.column_comments(),
         ));
     }
 
@@ -90,8 +92,9 @@ pub(in crate::codegen) fn generate_create_table(
                     .map(|col| quote_identifier(col, target_dialect))
                     .collect();
                 parts.push(format!(
-                    "    CONSTRAINT {} UNIQUE ({})",
+                    "    CONSTRAINT {} UNIQUE{} ({})",
                     quote_identifier(&c.name, target_dialect),
+                    nulls_not_distinct_sql(c.nulls_not_distinct, target_dialect),
                     cols.join(", ")
                 ));
             }
@@ -130,6 +133,7 @@ pub(in crate::codegen) fn generate_create_table(
                     if fk.update_rule != "NO ACTION" {
                         fk_str.push_str(&format!(" ON UPDATE {}", fk.update_rule));
                     }
+                    fk_str.push_str(fk_deferral(fk, target_dialect));
                     parts.push(fk_str);
                 }
             }
@@ -177,12 +181,30 @@ pub(in crate::codegen) fn generate_create_table(
                 }
             }
         }
+
+        // Exclusion constraints exist only on PostgreSQL; elsewhere they
+        // are dropped with a comment, like non-portable checks.
+        for c in &table.constraints {
+            let Some(ref exclude) = c.exclude else {
+                continue;
+            };
+            match exclude_constraint_sql(exclude, target_dialect) {
+                Some(sql) => parts.push(format!(
+                    "    CONSTRAINT {} {sql}",
+                    quote_identifier(&c.name, target_dialect)
+                )),
+                None => dropped_check_comments.push(format!(
+                    "-- DROPPED EXCLUDE {}: exclusion constraints are PostgreSQL-only",
+                    c.name
+                )),
+            }
+        }
     }
 
     let body = parts.join(",\n");
 
     // MySQL table comment is inline
-    let table_comment = if !options.nocomments && target_dialect == Dialect::Mysql {
+    let table_comment = if options.table_comments() && target_dialect == Dialect::Mysql {
         table
             .comment
             .as_ref()
@@ -205,3 +227,44 @@ pub(in crate::codegen) fn generate_create_table(
     }
     output
 }
+
+/// `DEFERRABLE [INITIALLY DEFERRED]` suffix for a foreign key, on targets
+/// that support deferred constraint checking.
+pub(in crate::codegen) fn fk_deferral(fk: &ForeignKeyInfo, target: Dialect) -> &'static str {
+    if !matches!(target, Dialect::Postgres | Dialect::Sqlite) {
+        return "";
+    }
+    match (fk.deferrable, fk.initially_deferred) {
+        (_, true) => " DEFERRABLE INITIALLY DEFERRED",
+        (true, false) => " DEFERRABLE",
+        (false, false) => "",
+    }
+}
+
+/// `EXCLUDE USING method (key WITH op, ...) [WHERE (predicate)]`, or
+/// `None` when the target is not PostgreSQL.
+pub(in crate::codegen) fn exclude_constraint_sql(
+    exclude: &ExcludeInfo,
+    target_dialect: Dialect,
+) -> Option<String> {
+    if target_dialect != Dialect::Postgres {
+        return None;
+    }
+    let elements: Vec<String> = exclude
+        .elements
+        .iter()
+        .map(|element| {
+            let key = if element.is_expression {
+                format!("({})", element.key)
+            } else {
+                quote_identifier(&element.key, target_dialect)
+            };
+            format!("{key} WITH {}", element.operator)
+        })
+        .collect();
+    let mut sql = format!("EXCLUDE USING {} ({})", exclude.using, elements.join(", "));
+    if let Some(ref predicate) = exclude.predicate {
+        sql.push_str(&format!(" WHERE ({predicate})"));
+    }
+    Some(sql)
+}
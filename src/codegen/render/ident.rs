@@ -1,7 +1,7 @@
 use crate::dialect::Dialect;
 
 /// Quote an identifier for the target dialect.
-pub(in crate::codegen) fn quote_identifier(name: &str, dialect: Dialect) -> String {
+pub(crate) fn quote_identifier(name: &str, dialect: Dialect) -> String {
     match dialect {
         Dialect::Postgres | Dialect::Sqlite => format!("\"{}\"", name.replace('"', "\"\"")),
         Dialect::Mysql => format!("`{}`", name.replace('`', "``")),
@@ -1,6 +1,6 @@
 use crate::codegen::is_unique_constraint_index;
 use crate::dialect::Dialect;
-use crate::schema::TableInfo;
+use crate::schema::{IndexInfo, IndexKey, TableInfo};
 
 use super::ident::{qualified_table_name, quote_identifier};
 
@@ -18,25 +18,105 @@ pub(in crate::codegen) fn generate_indexes(
             continue;
         }
 
-        let unique = if idx.is_unique { "UNIQUE " } else { "" };
-        let cols: Vec<String> = idx
+        stmts.extend(create_index_sql(idx, &tname, target_dialect));
+    }
+
+    stmts
+}
+
+/// The `CREATE INDEX` statement for one index on `tname` (already quoted).
+/// SQL Server indexes keep `CLUSTERED` and `COLUMNSTORE`; columnstore
+/// indexes have no equivalent elsewhere and yield `None`.
+pub(in crate::codegen) fn create_index_sql(
+    index: &IndexInfo,
+    tname: &str,
+    target_dialect: Dialect,
+) -> Option<String> {
+    let name = quote_identifier(&index.name, target_dialect);
+    let unique = if index.is_unique { "UNIQUE " } else { "" };
+    let clustered = match (target_dialect, index.clustered) {
+        (Dialect::Mssql, true) => "CLUSTERED ",
+        (Dialect::Mssql, false) if index.columnstore => "NONCLUSTERED ",
+        _ => "",
+    };
+    if index.columnstore {
+        if target_dialect != Dialect::Mssql {
+            return None;
+        }
+        // A clustered columnstore index covers every column.
+        if index.clustered {
+            return Some(format!(
+                "CREATE CLUSTERED COLUMNSTORE INDEX {name} ON {tname};"
+            ));
+        }
+        let cols: Vec<String> = index
             .columns
             .iter()
-            .map(|c| quote_identifier(c, target_dialect))
+            .map(|column| quote_identifier(column, target_dialect))
             .collect();
-        let using = postgres_index_method(idx, target_dialect);
-        stmts.push(format!(
-            "CREATE {unique}INDEX {} ON {tname}{using} ({});",
-            quote_identifier(&idx.name, target_dialect),
+        return Some(format!(
+            "CREATE {clustered}COLUMNSTORE INDEX {name} ON {tname} ({});",
             cols.join(", ")
         ));
     }
+    let cols = index_key_sql(index, target_dialect);
+    let using = postgres_index_method(index, target_dialect);
+    let include = index_include_sql(index, target_dialect);
+    let nulls = nulls_not_distinct_sql(index.nulls_not_distinct, target_dialect);
+    // MySQL keeps index comments inline; PostgreSQL uses COMMENT ON INDEX.
+    let comment = match (&index.comment, target_dialect) {
+        (Some(comment), Dialect::Mysql) => format!(" COMMENT '{}'", comment.replace('\'', "''")),
+        _ => String::new(),
+    };
+    Some(format!(
+        "CREATE {unique}{clustered}INDEX {name} ON {tname}{using} ({}){include}{nulls}{comment};",
+        cols.join(", ")
+    ))
+}
 
-    stmts
+/// ` NULLS NOT DISTINCT` for a unique constraint or index on PostgreSQL;
+/// other targets treat NULLs as distinct and have no way to say otherwise.
+pub(in crate::codegen) fn nulls_not_distinct_sql(
+    nulls_not_distinct: bool,
+    target_dialect: Dialect,
+) -> &'static str {
+    if nulls_not_distinct && target_dialect == Dialect::Postgres {
+        " NULLS NOT DISTINCT"
+    } else {
+        ""
+    }
+}
+
+/// Index keys as SQL: quoted column names (with their operator class on
+/// PostgreSQL), parenthesized expressions, each followed by its sort order.
+pub(in crate::codegen) fn index_key_sql(index: &IndexInfo, target_dialect: Dialect) -> Vec<String> {
+    index
+        .keys()
+        .into_iter()
+        .map(|key| {
+            let (mut sql, key_text) = match key {
+                IndexKey::Column(column) => {
+                    let quoted = quote_identifier(column, target_dialect);
+                    let sql = match index.opclasses.get(column) {
+                        Some(opclass) if target_dialect == Dialect::Postgres => {
+                            format!("{quoted} {opclass}")
+                        }
+                        _ => quoted,
+                    };
+                    (sql, column)
+                }
+                IndexKey::Expression(expression) => (format!("({expression})"), expression),
+            };
+            if let Some(order) = index.orderings.get(key_text) {
+                sql.push_str(&order.sql_suffix(target_dialect));
+            }
+            sql
+        })
+        .collect()
 }
 
 pub(in crate::codegen) fn postgres_index_method(
-    index: &crate::schema::IndexInfo,
+    index: &IndexInfo,
     target_dialect: Dialect,
 ) -> String {
     if target_dialect != Dialect::Postgres {
@@ -49,3 +129,16 @@ pub(in crate::codegen) fn postgres_index_method(
         .map(|method| format!(" USING {method}"))
         .unwrap_or_default()
 }
+
+/// ` INCLUDE (...)` for covering-index columns on targets that support it.
+pub(in crate::codegen) fn index_include_sql(index: &IndexInfo, target_dialect: Dialect) -> String {
+    if index.include.is_empty() || !matches!(target_dialect, Dialect::Postgres | Dialect::Mssql) {
+        return String::new();
+    }
+    let cols: Vec<String> = index
+        .include
+        .iter()
+        .map(|column| quote_identifier(column, target_dialect))
+        .collect();
+    format!(" INCLUDE ({})", cols.join(", "))
+}
@@ -0,0 +1,264 @@
+//! Schema-health report generator (`--generator report`).
+//!
+//! Lints the introspected schema rather than generating code: tables
+//! without a primary key, foreign keys with no index leading on their
+//! columns, nullable foreign-key columns, indexes that duplicate another
+//! index, constraints the server named on its own, tables that take part
+//! in no relationship, and tables whose row-level security policies filter
+//! what queries see. Views are skipped.
+
+use std::fmt::Write;
+
+use regex::Regex;
+
+use crate::schema::{
+    ConstraintInfo, ConstraintType, IndexInfo, IntrospectedSchema, TableInfo, TableType,
+};
+
+/// Generate the report for `schema`.
+pub fn generate(schema: &IntrospectedSchema) -> String {
+    let tables: Vec<&TableInfo> = schema
+        .tables
+        .iter()
+        .filter(|t| t.table_type == TableType::Table)
+        .collect();
+
+    let sections = [
+        (
+            "Tables without a primary key",
+            missing_primary_keys(&tables),
+        ),
+        (
+            "Foreign keys without a backing index",
+            unindexed_foreign_keys(&tables),
+        ),
+        ("Nullable foreign-key columns", nullable_fk_columns(&tables)),
+        ("Duplicate indexes", duplicate_indexes(&tables)),
+        ("Unnamed constraints", unnamed_constraints(&tables)),
+        ("Orphan tables", orphan_tables(schema, &tables)),
+        ("Row-level security", row_security(&tables)),
+    ];
+    let total: usize = sections.iter().map(|(_, items)| items.len()).sum();
+
+    let mut out = String::new();
+    let _ = writeln!(out, "# Schema health report generated by uvg");
+    let _ = writeln!(out, "# {} table(s), {total} finding(s)", tables.len());
+    if total == 0 {
+        let _ = writeln!(out, "\nNo findings.");
+        return out;
+    }
+    for (title, items) in sections.iter().filter(|(_, items)| !items.is_empty()) {
+        let _ = writeln!(out, "\n{title} ({}):", items.len());
+        for item in items {
+            let _ = writeln!(out, "  - {item}");
+        }
+    }
+    out
+}
+
+fn missing_primary_keys(tables: &[&TableInfo]) -> Vec<String> {
+    tables
+        .iter()
+        .filter(|t| {
+            !t.constraints
+                .iter()
+                .any(|c| c.constraint_type == ConstraintType::PrimaryKey)
+        })
+        .map(|t| table_label(t))
+        .collect()
+}
+
+/// A foreign key is backed when some index, primary key, or unique
+/// constraint leads with exactly its columns, in any order.
+fn unindexed_foreign_keys(tables: &[&TableInfo]) -> Vec<String> {
+    let mut items = Vec::new();
+    for table in tables {
+        let leading_columns: Vec<&[String]> = table
+            .constraints
+            .iter()
+            .filter(|c| {
+                matches!(
+                    c.constraint_type,
+                    ConstraintType::PrimaryKey | ConstraintType::Unique
+                )
+            })
+            .map(|c| c.columns.as_slice())
+            .chain(
+                table
+                    .indexes
+                    .iter()
+                    .filter(|i| !i.has_expressions())
+                    .map(|i| i.columns.as_slice()),
+            )
+            .collect();
+        for fk in foreign_keys(table) {
+            let backed = leading_columns.iter().any(|columns| {
+                columns.len() >= fk.columns.len()
+                    && fk
+                        .columns
+                        .iter()
+                        .all(|c| columns[..fk.columns.len()].contains(c))
+            });
+            if !backed {
+                items.push(format!(
+                    "{}.{} ({})",
+                    table_label(table),
+                    fk.name,
+                    fk.columns.join(", ")
+                ));
+            }
+        }
+    }
+    items
+}
+
+fn nullable_fk_columns(tables: &[&TableInfo]) -> Vec<String> {
+    let mut items = Vec::new();
+    for table in tables {
+        for column in &table.columns {
+            let in_fk = foreign_keys(table).any(|fk| fk.columns.contains(&column.name));
+            if in_fk && column.is_nullable {
+                items.push(format!("{}.{}", table_label(table), column.name));
+            }
+        }
+    }
+    items
+}
+
+/// Indexes with the same keys, operator classes, and options as an earlier
+/// index on the same table. Uniqueness is ignored: a plain index over the
+/// keys of a unique one adds nothing.
+fn duplicate_indexes(tables: &[&TableInfo]) -> Vec<String> {
+    let mut items = Vec::new();
+    for table in tables {
+        for (i, index) in table.indexes.iter().enumerate() {
+            if let Some(original) = table.indexes[..i].iter().find(|o| same_index(o, index)) {
+                items.push(format!(
+                    "{}.{} duplicates {}",
+                    table_label(table),
+                    index.name,
+                    original.name
+                ));
+            }
+        }
+    }
+    items
+}
+
+fn same_index(a: &IndexInfo, b: &IndexInfo) -> bool {
+    a.keys() == b.keys()
+        && a.opclasses == b.opclasses
+        && a.orderings == b.orderings
+        && a.include == b.include
+        && a.clustered == b.clustered
+        && a.columnstore == b.columnstore
+        && a.nulls_not_distinct == b.nulls_not_distinct
+        && a.kwargs == b.kwargs
+}
+
+/// Constraints with no name, or with a name SQL Server generated
+/// (`PK__users__3213E83F0E6C8C4D`). Generated names differ between
+/// databases, so migrations that refer to them do not replay cleanly.
+fn unnamed_constraints(tables: &[&TableInfo]) -> Vec<String> {
+    let generated = Regex::new(r"^(PK|FK|UQ|CK|DF)__.+__[0-9A-F]{8,}$").expect("valid regex");
+    let mut items = Vec::new();
+    for table in tables {
+        for constraint in &table.constraints {
+            if constraint.name.is_empty() {
+                let mut item = format!(
+                    "{} {}",
+                    table_label(table),
+                    constraint_kind(&constraint.constraint_type)
+                );
+                if !constraint.columns.is_empty() {
+                    let _ = write!(item, " ({})", constraint.columns.join(", "));
+                }
+                items.push(item);
+            } else if generated.is_match(&constraint.name) {
+                items.push(format!("{}.{}", table_label(table), constraint.name));
+            }
+        }
+    }
+    items
+}
+
+/// Tables with no foreign key of their own that no other table references.
+fn orphan_tables(schema: &IntrospectedSchema, tables: &[&TableInfo]) -> Vec<String> {
+    tables
+        .iter()
+        .filter(|table| foreign_keys(table).next().is_none())
+        .filter(|table| {
+            !tables.iter().any(|other| {
+                foreign_keys(other)
+                    .filter_map(|c| c.foreign_key.as_ref())
+                    .any(|fk| schema.references(fk, table))
+            })
+        })
+        .map(|t| table_label(t))
+        .collect()
+}
+
+/// Tables with PostgreSQL row-level security enabled, and their policies;
+/// also tables whose policies have no effect because it is disabled.
+fn row_security(tables: &[&TableInfo]) -> Vec<String> {
+    let mut items = Vec::new();
+    for table in tables {
+        let Some(ref security) = table.row_security else {
+            continue;
+        };
+        let policies: Vec<String> = security
+            .policies
+            .iter()
+            .map(|policy| {
+                let restrictive = if policy.permissive {
+                    ""
+                } else {
+                    ", restrictive"
+                };
+                format!("{} ({}{restrictive})", policy.name, policy.command)
+            })
+            .collect();
+        let mode = if security.forced { "forced" } else { "enabled" };
+        let state = if !security.enabled {
+            format!(
+                "row-level security disabled; policies {} have no effect",
+                policies.join(", ")
+            )
+        } else if policies.is_empty() {
+            format!("{mode}; no policies, so queries see no rows")
+        } else {
+            format!("{mode}; policies {}", policies.join(", "))
+        };
+        items.push(format!("{}: {state}", table_label(table)));
+    }
+    items
+}
+
+fn foreign_keys(table: &TableInfo) -> impl Iterator<Item = &ConstraintInfo> {
+    table
+        .constraints
+        .iter()
+        .filter(|c| c.constraint_type == ConstraintType::ForeignKey)
+}
+
+fn constraint_kind(kind: &ConstraintType) -> &'static str {
+    match kind {
+        ConstraintType::PrimaryKey => "primary key",
+        ConstraintType::ForeignKey => "foreign key",
+        ConstraintType::Unique => "unique constraint",
+        ConstraintType::Check => "check constraint",
+        ConstraintType::Exclude => "exclusion constraint",
+    }
+}
+
+fn table_label(table: &TableInfo) -> String {
+    if table.schema.is_empty() {
+        table.name.clone()
+    } else {
+        format!("{}.{}", table.schema, table.name)
+    }
+}
+
+#[cfg(test)]
+#[path = "report_tests.rs"]
+mod tests;
@@ -0,0 +1,193 @@
+use super::*;
+use crate::schema::ConstraintInfo;
+use crate::testutil::*;
+
+#[test]
+fn clean_schema_has_no_findings() {
+    let schema = schema_pg(vec![
+        table("users")
+            .column(col("id").build())
+            .pk("users_pkey", &["id"])
+            .build(),
+        table("orders")
+            .column(col("id").build())
+            .column(col("user_id").build())
+            .pk("orders_pkey", &["id"])
+            .fk("orders_user_id_fkey", &["user_id"], "users", &["id"])
+            .index("ix_orders_user_id", &["user_id"], false)
+            .build(),
+    ]);
+
+    let output = generate(&schema);
+
+    assert!(output.contains("# 2 table(s), 0 finding(s)"));
+    assert!(output.contains("No findings."));
+}
+
+#[test]
+fn reports_missing_primary_keys_and_orphans() {
+    let schema = schema_pg(vec![table("audit_log")
+        .column(col("message").udt("text").build())
+        .build()]);
+
+    let output = generate(&schema);
+
+    assert!(output.contains("Tables without a primary key (1):\n  - public.audit_log"));
+    assert!(output.contains("Orphan tables (1):\n  - public.audit_log"));
+}
+
+#[test]
+fn reports_unindexed_and_nullable_foreign_keys() {
+    let schema = schema_pg(vec![
+        table("users")
+            .column(col("id").build())
+            .pk("users_pkey", &["id"])
+            .build(),
+        table("orders")
+            .column(col("id").build())
+            .column(col("user_id").nullable().build())
+            .pk("orders_pkey", &["id"])
+            .fk("orders_user_id_fkey", &["user_id"], "users", &["id"])
+            .build(),
+    ]);
+
+    let output = generate(&schema);
+
+    assert!(output.contains(
+        "Foreign keys without a backing index (1):\n  - public.orders.orders_user_id_fkey (user_id)"
+    ));
+    assert!(output.contains("Nullable foreign-key columns (1):\n  - public.orders.user_id"));
+    assert!(!output.contains("Orphan tables"));
+}
+
+#[test]
+fn composite_index_backs_fk_only_when_leading() {
+    let schema = schema_pg(vec![
+        table("users")
+            .column(col("id").build())
+            .pk("users_pkey", &["id"])
+            .build(),
+        table("orders")
+            .column(col("id").build())
+            .column(col("user_id").build())
+            .column(col("editor_id").build())
+            .pk("orders_pkey", &["id"])
+            .fk("orders_user_id_fkey", &["user_id"], "users", &["id"])
+            .fk("orders_editor_id_fkey", &["editor_id"], "users", &["id"])
+            .index("ix_orders_user_editor", &["user_id", "editor_id"], false)
+            .build(),
+    ]);
+
+    let output = generate(&schema);
+
+    assert!(output.contains("public.orders.orders_editor_id_fkey (editor_id)"));
+    assert!(!output.contains("orders_user_id_fkey"));
+}
+
+#[test]
+fn reports_duplicate_indexes_ignoring_uniqueness() {
+    let schema = schema_pg(vec![table("users")
+        .column(col("id").build())
+        .column(col("email").udt("text").build())
+        .pk("users_pkey", &["id"])
+        .index("ix_users_email", &["email"], false)
+        .index("uq_users_email", &["email"], true)
+        .index("ix_users_id_email", &["id", "email"], false)
+        .build()]);
+
+    let output = generate(&schema);
+
+    assert!(output.contains(
+        "Duplicate indexes (1):\n  - public.users.uq_users_email duplicates ix_users_email"
+    ));
+}
+
+#[test]
+fn reports_server_generated_and_empty_constraint_names() {
+    let mut users = table("users")
+        .schema("dbo")
+        .column(col("id").build())
+        .column(col("code").build())
+        .pk("PK__users__3213E83F0E6C8C4D", &["id"])
+        .build();
+    users
+        .constraints
+        .push(ConstraintInfo::check("", "code > 0"));
+    let schema = schema_mssql(vec![users]);
+
+    let output = generate(&schema);
+
+    assert!(output.contains("Unnamed constraints (2):"));
+    assert!(output.contains("  - dbo.users.PK__users__3213E83F0E6C8C4D"));
+    assert!(output.contains("  - dbo.users check constraint\n"));
+}
+
+#[test]
+fn views_are_skipped() {
+    let schema = schema_pg(vec![table("active_users")
+        .column(col("id").build())
+        .table_type(TableType::View)
+        .build()]);
+
+    let output = generate(&schema);
+
+    assert!(output.contains("# 0 table(s), 0 finding(s)"));
+}
+
+#[test]
+fn reports_row_level_security() {
+    use crate::schema::{PolicyInfo, RowSecurity};
+
+    let policy = |name: &str, command: &str, permissive: bool| PolicyInfo {
+        name: name.to_string(),
+        command: command.to_string(),
+        permissive,
+        roles: vec!["public".to_string()],
+        using: Some("tenant_id = current_tenant()".to_string()),
+        with_check: None,
+    };
+    let mut schema = schema_pg(vec![
+        table("orders")
+            .column(col("id").build())
+            .pk("orders_pkey", &["id"])
+            .build(),
+        table("secrets")
+            .column(col("id").build())
+            .pk("secrets_pkey", &["id"])
+            .build(),
+        table("legacy")
+            .column(col("id").build())
+            .pk("legacy_pkey", &["id"])
+            .build(),
+    ]);
+    schema.tables[0].row_security = Some(RowSecurity {
+        enabled: true,
+        forced: false,
+        policies: vec![
+            policy("tenant_isolation", "ALL", true),
+            policy("not_archived", "SELECT", false),
+        ],
+    });
+    schema.tables[1].row_security = Some(RowSecurity {
+        enabled: true,
+        forced: true,
+        policies: Vec::new(),
+    });
+    schema.tables[2].row_security = Some(RowSecurity {
+        enabled: false,
+        forced: false,
+        policies: vec![policy("tenant_isolation", "ALL", true)],
+    });
+
+    let output = generate(&schema);
+
+    assert!(
+        output.contains(
+            "Row-level security (3):\n  \
+             - public.orders: enabled; policies tenant_isolation (ALL), not_archived (SELECT, restrictive)\n  \
+             - public.secrets: forced; no policies, so queries see no rows\n  \
+             - public.legacy: row-level security disabled; policies tenant_isolation (ALL) have no effect\n"
+        ),
+        "{output}"
+    );
+}
@@ -0,0 +1,161 @@
+//! Repository-layer generator (`--generator repositories`).
+//!
+//! Emits one repository class per declarative model — get by primary key,
+//! paginated list, add, update, delete — over a SQLAlchemy `Session`. The
+//! classes import the models from a sibling `models` module, so the
+//! intended layout is `models.py` from `--generator declarative` next to
+//! `repositories.py` from this generator. Tables the declarative generator
+//! renders as `Table()` (no primary key, or pure association tables) get no
+//! repository.
+
+use std::collections::HashMap;
+
+use heck::ToSnakeCase;
+
+use crate::cli::GeneratorOptions;
+use crate::codegen::declarative::class_attr_names;
+use crate::codegen::imports::ImportCollector;
+use crate::codegen::python::PythonOutput;
+use crate::codegen::relationships::renders_as_association;
+use crate::codegen::{column_python_type, has_primary_key, topo_sort_tables};
+use crate::naming::table_class_name;
+use crate::schema::{ConstraintType, IntrospectedSchema, TableInfo};
+
+/// Generate the repository layer as a single file.
+pub fn generate(schema: &IntrospectedSchema, options: &GeneratorOptions) -> String {
+    parts(schema, options).render()
+}
+
+/// Generate the repository layer split one file per repository.
+pub fn generate_split(
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
+) -> Vec<(String, String)> {
+    parts(schema, options).split_with(options)
+}
+
+fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutput {
+    let mut imports = ImportCollector::new();
+    imports.add("typing", "Any");
+    imports.add("typing", "List");
+    imports.add("typing", "Optional");
+    imports.add("sqlalchemy", "select");
+    imports.add("sqlalchemy.orm", "Session");
+
+    let mut model_names: Vec<String> = Vec::new();
+    let mut blocks: Vec<(String, String)> = Vec::new();
+    let mut schemas: HashMap<String, String> = HashMap::new();
+
+    for table in topo_sort_tables(&schema.tables) {
+        if !has_primary_key(&table.constraints) || renders_as_association(table, options) {
+            continue;
+        }
+        let class_name = table_class_name(schema, table, options);
+        let block = generate_repository(table, &class_name, schema, options, &mut imports);
+        let module = format!("{}_repository", class_name.to_snake_case());
+        schemas.insert(module.clone(), table.schema.clone());
+        blocks.push((module, block));
+        model_names.push(class_name);
+    }
+
+    let mut prelude = imports.render();
+    if !model_names.is_empty() {
+        model_names.sort();
+        prelude.push_str("\n\nfrom models import (\n");
+        for name in &model_names {
+            prelude.push_str(&format!("    {name},\n"));
+        }
+        prelude.push(')');
+    }
+
+    PythonOutput {
+        prelude,
+        models: blocks,
+        separator: "\n\n\n",
+        links: Default::default(),
+        schemas,
+    }
+}
+
+fn generate_repository(
+    table: &TableInfo,
+    class_name: &str,
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
+    imports: &mut ImportCollector,
+) -> String {
+    let attr_names = class_attr_names(table, schema.dialect, options);
+    let pk_cols: Vec<&str> = table
+        .constraints
+        .iter()
+        .find(|c| c.constraint_type == ConstraintType::PrimaryKey)
+        .map(|c| c.columns.iter().map(String::as_str).collect())
+        .unwrap_or_default();
+
+    // (attribute name, python type) for each primary-key column.
+    let pk_params: Vec<(String, String)> = pk_cols
+        .iter()
+        .filter_map(|pk| {
+            let idx = table.columns.iter().position(|c| c.name == *pk)?;
+            let python_type =
+                column_python_type(&table.columns[idx], schema.dialect, options, imports);
+            Some((attr_names[idx].clone(), python_type))
+        })
+        .collect();
+
+    let params = pk_params
+        .iter()
+        .map(|(name, ty)| format!("{name}: {ty}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let ident = match pk_params.as_slice() {
+        [(name, _)] => name.clone(),
+        many => format!(
+            "({})",
+            many.iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let order_by = pk_params
+        .iter()
+        .map(|(name, _)| format!("{class_name}.{name}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let lines = [
+        format!("class {class_name}Repository:"),
+        "    def __init__(self, session: Session) -> None:".to_string(),
+        "        self.session = session".to_string(),
+        String::new(),
+        format!("    def get(self, {params}) -> Optional[{class_name}]:"),
+        format!("        return self.session.get({class_name}, {ident})"),
+        String::new(),
+        format!("    def list(self, *, limit: int = 100, offset: int = 0) -> List[{class_name}]:"),
+        format!(
+            "        stmt = select({class_name}).order_by({order_by}).limit(limit).offset(offset)"
+        ),
+        "        return list(self.session.scalars(stmt))".to_string(),
+        String::new(),
+        format!("    def add(self, obj: {class_name}) -> {class_name}:"),
+        "        self.session.add(obj)".to_string(),
+        "        self.session.flush()".to_string(),
+        "        return obj".to_string(),
+        String::new(),
+        format!("    def update(self, obj: {class_name}, **values: Any) -> {class_name}:"),
+        "        for key, value in values.items():".to_string(),
+        "            setattr(obj, key, value)".to_string(),
+        "        self.session.flush()".to_string(),
+        "        return obj".to_string(),
+        String::new(),
+        format!("    def delete(self, obj: {class_name}) -> None:"),
+        "        self.session.delete(obj)".to_string(),
+        "        self.session.flush()".to_string(),
+    ];
+    lines.join("\n")
+}
+
+#[cfg(test)]
+#[path = "repositories_tests.rs"]
+mod tests;
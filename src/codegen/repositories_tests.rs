@@ -0,0 +1,60 @@
+use super::*;
+use crate::testutil::*;
+
+fn shop_schema() -> IntrospectedSchema {
+    schema_pg(vec![
+        table("users")
+            .column(col("id").build())
+            .column(col("email").udt("text").build())
+            .pk("users_pkey", &["id"])
+            .build(),
+        table("order_lines")
+            .column(col("order_id").udt("uuid").build())
+            .column(col("line_no").build())
+            .pk("order_lines_pkey", &["order_id", "line_no"])
+            .build(),
+        table("audit_log")
+            .column(col("message").udt("text").build())
+            .build(),
+    ])
+}
+
+#[test]
+fn repository_per_model_with_crud_methods() {
+    let output = generate(&shop_schema(), &GeneratorOptions::default());
+    assert!(output.contains("from typing import Any, List, Optional\nimport uuid\n"));
+    assert!(output.contains("from sqlalchemy import select\nfrom sqlalchemy.orm import Session"));
+    assert!(output.contains("from models import (\n    OrderLines,\n    Users,\n)"));
+    assert!(output.contains("class UsersRepository:"));
+    assert!(output.contains(
+        "    def get(self, id: int) -> Optional[Users]:\n        return self.session.get(Users, id)"
+    ));
+    assert!(output
+        .contains("        stmt = select(Users).order_by(Users.id).limit(limit).offset(offset)"));
+    assert!(output.contains("    def update(self, obj: Users, **values: Any) -> Users:"));
+    assert!(output.contains("    def delete(self, obj: Users) -> None:"));
+}
+
+#[test]
+fn composite_primary_key_uses_tuple_identity() {
+    let output = generate(&shop_schema(), &GeneratorOptions::default());
+    assert!(output.contains(
+        "    def get(self, order_id: uuid.UUID, line_no: int) -> Optional[OrderLines]:\n        \
+         return self.session.get(OrderLines, (order_id, line_no))"
+    ));
+    assert!(output.contains("order_by(OrderLines.order_id, OrderLines.line_no)"));
+}
+
+#[test]
+fn tables_without_primary_key_get_no_repository() {
+    let output = generate(&shop_schema(), &GeneratorOptions::default());
+    assert!(!output.contains("AuditLog"));
+}
+
+#[test]
+fn split_writes_one_module_per_repository() {
+    let files = generate_split(&shop_schema(), &GeneratorOptions::default());
+    let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
+    assert!(names.contains(&"users_repository.py"));
+    assert!(names.contains(&"order_lines_repository.py"));
+}
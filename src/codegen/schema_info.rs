@@ -23,7 +23,7 @@ pub fn is_unique_constraint_index(
     index: &crate::schema::IndexInfo,
     constraints: &[crate::schema::ConstraintInfo],
 ) -> bool {
-    if !index.is_unique {
+    if !index.is_unique || index.has_expressions() {
         return false;
     }
     constraints.iter().any(|c| {
@@ -39,6 +39,19 @@ pub fn find_enum_for_column<'a>(
     enums.iter().find(|e| e.name == udt_name)
 }
 
+/// Find the enum a column is declared with, looking through a PostgreSQL
+/// array type (`_mood`) to its element. The flag is true for arrays.
+pub(crate) fn find_column_enum<'a>(
+    column: &crate::schema::ColumnInfo,
+    enums: &'a [crate::schema::EnumInfo],
+) -> Option<(&'a crate::schema::EnumInfo, bool)> {
+    if let Some(enum_info) = find_enum_for_column(&column.udt_name, enums) {
+        return Some((enum_info, false));
+    }
+    let element = column.udt_name.strip_prefix('_')?;
+    find_enum_for_column(element, enums).map(|enum_info| (enum_info, true))
+}
+
 /// Resolve a PostgreSQL enum column by its full type identity when that
 /// identity is available. Older snapshots do not carry `udt_schema`, so they
 /// fall back to the table schema and finally to an unambiguous name match.
@@ -0,0 +1,510 @@
+//! Seed-data generator (`--generator seed`).
+//!
+//! Emits a script that inserts `--seed-rows` synthetic rows into every
+//! table, in foreign-key dependency order: plain SQL, or with
+//! `--seed-format python` a Python script that runs the same statements
+//! through SQLAlchemy. Values are deterministic and derived from the row
+//! number, so integer and string columns are unique per table and foreign
+//! keys point at rows the script itself inserted.
+//!
+//! A table whose primary key or unique constraint cannot take `--seed-rows`
+//! distinct values (a `smallint`, a `varchar(2)`, a CHECK list) gets only as
+//! many rows as it can, with a comment saying so, rather than duplicates.
+//!
+//! Identity / auto-increment and generated columns are left to the database. Foreign keys
+//! onto such columns assume the parent table starts empty, so its N-th seed
+//! row receives `start + (N - 1) * increment`.
+
+use crate::codegen::python::format_python_string_literal;
+use crate::codegen::render::qualified_table_name;
+use crate::codegen::{
+    find_enum_for_column, is_auto_increment_column, parse_check_enum, quote_identifier,
+    topo_sort_tables,
+};
+use crate::ddl_typemap::{to_canonical, CanonicalType};
+use crate::dialect::Dialect;
+use crate::schema::{ColumnInfo, ConstraintType, IntrospectedSchema, TableInfo, TableType};
+
+/// SQL Server caps a table value constructor at 1000 rows.
+const MAX_ROWS_PER_INSERT: usize = 1000;
+
+/// Foreign-key chains deeper than this fall back to NULL / the row number
+/// instead of following the chain further.
+const MAX_FK_DEPTH: usize = 16;
+
+/// Seconds in a day: the distinct `time` values.
+const SECONDS_PER_DAY: usize = 86_400;
+
+/// What `--generator seed` writes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SeedFormat {
+    /// A SQL script. Default.
+    #[default]
+    Sql,
+    /// A Python script that runs the statements through SQLAlchemy.
+    Python,
+}
+
+/// Generate the seed script for `schema`, rendering literals and
+/// identifiers for `target`.
+pub fn generate(schema: &IntrospectedSchema, rows: usize, target: Dialect) -> String {
+    let mut out = vec![
+        format!("-- Seed data generated by uvg: {rows} row(s) per table"),
+        "-- Tables are listed in foreign-key dependency order.".to_string(),
+    ];
+    for table in Seeder::new(schema, rows, target).tables() {
+        out.push(String::new());
+        if let Some(note) = table.note {
+            out.push(format!("-- {note}"));
+        }
+        out.push(table.statements.join("\n\n"));
+    }
+    out.push(String::new());
+    out.join("\n")
+}
+
+/// Generate the seed script as a Python program that takes a database URL
+/// and runs the statements of [`generate`] in one transaction.
+pub fn generate_python(schema: &IntrospectedSchema, rows: usize, target: Dialect) -> String {
+    let mut out = vec![
+        format!("\"\"\"Seed data generated by uvg: {rows} row(s) per table."),
+        String::new(),
+        "Usage: python seed.py DATABASE_URL".to_string(),
+        "\"\"\"".to_string(),
+        String::new(),
+        "import sys".to_string(),
+        String::new(),
+        "from sqlalchemy import create_engine".to_string(),
+        String::new(),
+        "# Tables are listed in foreign-key dependency order.".to_string(),
+        "STATEMENTS = [".to_string(),
+    ];
+    for table in Seeder::new(schema, rows, target).tables() {
+        if let Some(note) = table.note {
+            out.push(format!("    # {note}"));
+        }
+        for statement in table.statements {
+            out.push("    (".to_string());
+            let lines: Vec<&str> = statement.lines().collect();
+            for (i, line) in lines.iter().enumerate() {
+                let line = if i + 1 < lines.len() {
+                    format!("{line}\n")
+                } else {
+                    line.to_string()
+                };
+                out.push(format!("        {}", format_python_string_literal(&line)));
+            }
+            out.push("    ),".to_string());
+        }
+    }
+    out.extend(
+        [
+            "]",
+            "",
+            "",
+            "def main(url: str) -> None:",
+            "    engine = create_engine(url)",
+            "    with engine.begin() as conn:",
+            "        for statement in STATEMENTS:",
+            "            conn.exec_driver_sql(statement)",
+            "",
+            "",
+            "if __name__ == \"__main__\":",
+            "    main(sys.argv[1])",
+            "",
+        ]
+        .map(String::from),
+    );
+    out.join("\n")
+}
+
+/// The INSERT statements for one table, with a note when it was seeded
+/// with fewer rows than asked for.
+struct TableSeed {
+    note: Option<String>,
+    statements: Vec<String>,
+}
+
+struct Seeder<'a> {
+    schema: &'a IntrospectedSchema,
+    target: Dialect,
+    rows: usize,
+    /// Rows seeded into each table of `schema.tables`, by index.
+    counts: Vec<usize>,
+}
+
+impl<'a> Seeder<'a> {
+    fn new(schema: &'a IntrospectedSchema, rows: usize, target: Dialect) -> Self {
+        let mut seeder = Seeder {
+            schema,
+            target,
+            rows,
+            counts: vec![rows; schema.tables.len()],
+        };
+        // Parents first, so a child's foreign keys see the rows its
+        // parents actually get.
+        for table in topo_sort_tables(&schema.tables) {
+            if let Some((count, _)) = seeder.capped_rows(table) {
+                if let Some(i) = seeder.index_of(table) {
+                    seeder.counts[i] = count;
+                }
+            }
+        }
+        seeder
+    }
+
+    fn tables(&self) -> Vec<TableSeed> {
+        let mut out = Vec::new();
+        for table in topo_sort_tables(&self.schema.tables) {
+            // SQL Server rejects writes to the history table of a temporal
+            // table; foreign tables live on another server.
+            if table.table_type != TableType::Table
+                || table.history_of.is_some()
+                || table.foreign_server.is_some()
+            {
+                continue;
+            }
+            let columns: Vec<&ColumnInfo> = table
+                .columns
+                .iter()
+                .filter(|c| {
+                    !is_auto_increment_column(c, self.schema.dialect)
+                        && c.computed.is_none()
+                        && c.period.is_none()
+                })
+                .collect();
+            if columns.is_empty() {
+                continue;
+            }
+
+            let name =
+                qualified_table_name(&table.schema, &table.name, self.schema.dialect, self.target);
+            let col_list = columns
+                .iter()
+                .map(|c| quote_identifier(&c.name, self.target))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let all_rows: Vec<String> = (1..=self.row_count(table))
+                .map(|row| {
+                    let values = columns
+                        .iter()
+                        .map(|c| self.value_for(table, c, row, 0))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!("    ({values})")
+                })
+                .collect();
+
+            let note = self.capped_rows(table).map(|(count, key)| {
+                format!(
+                    "{name}: {count} of {} row(s); {key} has no more distinct values",
+                    self.rows
+                )
+            });
+            let statements = all_rows
+                .chunks(MAX_ROWS_PER_INSERT)
+                .map(|chunk| {
+                    format!(
+                        "INSERT INTO {name} ({col_list}) VALUES\n{};",
+                        chunk.join(",\n")
+                    )
+                })
+                .collect();
+            out.push(TableSeed { note, statements });
+        }
+        out
+    }
+
+    fn index_of(&self, table: &TableInfo) -> Option<usize> {
+        self.schema
+            .tables
+            .iter()
+            .position(|t| std::ptr::eq(t, table))
+    }
+
+    fn row_count(&self, table: &TableInfo) -> usize {
+        self.index_of(table).map_or(self.rows, |i| self.counts[i])
+    }
+
+    /// The row count `table` is limited to and the unique key that limits
+    /// it, when one of its keys has fewer distinct seed values than rows.
+    fn capped_rows(&self, table: &TableInfo) -> Option<(usize, String)> {
+        let constraints = table
+            .constraints
+            .iter()
+            .filter(|c| {
+                matches!(
+                    c.constraint_type,
+                    ConstraintType::PrimaryKey | ConstraintType::Unique
+                )
+            })
+            .map(|c| (&c.name, &c.columns));
+        let indexes = table
+            .indexes
+            .iter()
+            .filter(|i| i.is_unique && !i.has_expressions())
+            .map(|i| (&i.name, &i.columns));
+        constraints
+            .chain(indexes)
+            .filter_map(|(name, columns)| {
+                // The key repeats only once every column has run out of
+                // distinct values.
+                let capacity = columns
+                    .iter()
+                    .map(|name| {
+                        table
+                            .columns
+                            .iter()
+                            .find(|c| c.name == *name)
+                            .and_then(|col| self.capacity(table, col, 0))
+                    })
+                    .try_fold(0, |max, c| c.map(|c| max.max(c)))?;
+                (capacity < self.rows).then(|| (capacity, name.clone()))
+            })
+            .min_by_key(|(capacity, _)| *capacity)
+    }
+
+    /// How many rows `col` takes distinct values for, counting from row 1;
+    /// `None` when it does not run out.
+    fn capacity(&self, table: &TableInfo, col: &ColumnInfo, depth: usize) -> Option<usize> {
+        if col.computed.is_some() || col.period.is_some() {
+            return None;
+        }
+        if let Some((ref_table, ref_col)) = referenced_column(self.schema, table, col) {
+            if ref_table.name == table.name && col.is_nullable {
+                return None;
+            }
+            if depth < MAX_FK_DEPTH {
+                let parent = self.capacity(ref_table, ref_col, depth + 1);
+                return if ref_table.name == table.name {
+                    parent
+                } else {
+                    let rows = self.row_count(ref_table);
+                    Some(parent.map_or(rows, |p| p.min(rows)))
+                };
+            }
+        }
+        if is_auto_increment_column(col, self.schema.dialect) {
+            return None;
+        }
+        if let Some(values) = allowed_values(self.schema, table, col) {
+            return Some(values.len());
+        }
+        let power = |base: usize, exp: i32| {
+            u32::try_from(exp)
+                .ok()
+                .and_then(|exp| base.checked_pow(exp))
+                .map(|p| p - 1)
+        };
+        match to_canonical(col, self.schema.dialect) {
+            CanonicalType::Boolean => Some(2),
+            CanonicalType::SmallInt => Some(i16::MAX as usize),
+            CanonicalType::Decimal { precision, scale } => {
+                match precision.unwrap_or(18) - scale.unwrap_or(0) {
+                    d if d <= 0 => Some(1),
+                    d if d < 18 => power(10, d),
+                    _ => None,
+                }
+            }
+            CanonicalType::Varchar { length: Some(len) }
+            | CanonicalType::Char { length: Some(len) }
+                if len > 0 =>
+            {
+                power(10, len)
+            }
+            CanonicalType::Bytes { length: Some(len) } if len > 0 => power(256, len),
+            CanonicalType::Time { .. } => Some(SECONDS_PER_DAY),
+            CanonicalType::Json | CanonicalType::Jsonb => Some(1),
+            CanonicalType::Enum { values } | CanonicalType::Set { values }
+                if !values.is_empty() =>
+            {
+                Some(values.len())
+            }
+            CanonicalType::Array { .. } if self.target == Dialect::Postgres => Some(1),
+            _ => None,
+        }
+    }
+
+    /// SQL literal for `col` in seed row `row` (1-based).
+    fn value_for(&self, table: &TableInfo, col: &ColumnInfo, row: usize, depth: usize) -> String {
+        let (schema, target) = (self.schema, self.target);
+        if let Some((ref_table, ref_col)) = referenced_column(schema, table, col) {
+            if ref_table.name == table.name && col.is_nullable {
+                return "NULL".to_string();
+            }
+            if depth < MAX_FK_DEPTH {
+                // A parent seeded with fewer rows is referenced round-robin.
+                let parent_row = if ref_table.name == table.name {
+                    row
+                } else {
+                    (row - 1) % self.row_count(ref_table).max(1) + 1
+                };
+                return self.value_for(ref_table, ref_col, parent_row, depth + 1);
+            }
+        }
+
+        if is_auto_increment_column(col, schema.dialect) {
+            let (start, increment) = col
+                .identity
+                .as_ref()
+                .map(|i| (i.start, i.increment))
+                .unwrap_or((1, 1));
+            return (start + (row as i64 - 1) * increment).to_string();
+        }
+
+        if let Some(values) = allowed_values(schema, table, col) {
+            return string_literal(&values[(row - 1) % values.len()]);
+        }
+
+        let n = row as i64;
+        match to_canonical(col, schema.dialect) {
+            CanonicalType::Boolean => match (row % 2 == 0, target) {
+                (true, Dialect::Postgres) => "TRUE".to_string(),
+                (false, Dialect::Postgres) => "FALSE".to_string(),
+                (true, _) => "1".to_string(),
+                (false, _) => "0".to_string(),
+            },
+            CanonicalType::SmallInt => ((n - 1) % i64::from(i16::MAX) + 1).to_string(),
+            CanonicalType::Integer | CanonicalType::BigInt => n.to_string(),
+            CanonicalType::Float | CanonicalType::Double => format!("{n}.5"),
+            CanonicalType::Decimal { precision, scale } => {
+                let integer_digits = precision.unwrap_or(18) - scale.unwrap_or(0);
+                match integer_digits {
+                    d if d <= 0 => "0".to_string(),
+                    d if d < 18 => (n % 10i64.pow(d as u32)).to_string(),
+                    _ => n.to_string(),
+                }
+            }
+            CanonicalType::Varchar { length } | CanonicalType::Char { length } => {
+                string_literal(&bounded_text(&col.name, row, length))
+            }
+            CanonicalType::Text => string_literal(&format!("{}_{row}", col.name)),
+            CanonicalType::Bytes { length } => {
+                let hex = seed_bytes(row, length);
+                match target {
+                    Dialect::Postgres => format!("'\\x{hex}'"),
+                    Dialect::Mssql => format!("0x{hex}"),
+                    Dialect::Mysql | Dialect::Sqlite => format!("X'{hex}'"),
+                }
+            }
+            CanonicalType::Date => string_literal(&seed_date(row)),
+            CanonicalType::Timestamp { .. } => {
+                string_literal(&format!("{} 00:00:00", seed_date(row)))
+            }
+            CanonicalType::Time { .. } => {
+                let second = row % SECONDS_PER_DAY;
+                string_literal(&format!(
+                    "{:02}:{:02}:{:02}",
+                    second / 3600,
+                    second / 60 % 60,
+                    second % 60
+                ))
+            }
+            CanonicalType::Interval => string_literal(&format!("{row} days")),
+            CanonicalType::Uuid => string_literal(&format!("00000000-0000-4000-8000-{row:012x}")),
+            CanonicalType::Json | CanonicalType::Jsonb => string_literal("{}"),
+            CanonicalType::Enum { values } | CanonicalType::Set { values }
+                if !values.is_empty() =>
+            {
+                string_literal(&values[(row - 1) % values.len()])
+            }
+            CanonicalType::Array { .. } if target == Dialect::Postgres => "'{}'".to_string(),
+            _ if col.is_nullable => "NULL".to_string(),
+            _ => string_literal(&format!("{}_{row}", col.name)),
+        }
+    }
+}
+
+/// The column `col` references through a foreign key (matched by position
+/// for composite keys), when the referenced table is part of the schema
+/// being seeded.
+fn referenced_column<'a>(
+    schema: &'a IntrospectedSchema,
+    table: &TableInfo,
+    col: &ColumnInfo,
+) -> Option<(&'a TableInfo, &'a ColumnInfo)> {
+    let (pos, fk) = table.constraints.iter().find_map(|c| {
+        if c.constraint_type != ConstraintType::ForeignKey {
+            return None;
+        }
+        let pos = c.columns.iter().position(|name| *name == col.name)?;
+        Some((pos, c.foreign_key.as_ref()?))
+    })?;
+    let ref_table = schema
+        .tables
+        .iter()
+        .find(|t| t.name == fk.ref_table && t.schema == fk.ref_schema)
+        .or_else(|| schema.tables.iter().find(|t| t.name == fk.ref_table))?;
+    let ref_col_name = fk.ref_columns.get(pos)?;
+    let ref_col = ref_table.columns.iter().find(|c| c.name == *ref_col_name)?;
+    Some((ref_table, ref_col))
+}
+
+/// Values from a named enum type or a CHECK constraint listing the column's
+/// strings, in any dialect's spelling: rows must pass the CHECK whatever
+/// `enum_from_check` says about the models.
+fn allowed_values(
+    schema: &IntrospectedSchema,
+    table: &TableInfo,
+    col: &ColumnInfo,
+) -> Option<Vec<String>> {
+    if let Some(enum_info) = find_enum_for_column(&col.udt_name, &schema.enums) {
+        if !enum_info.values.is_empty() {
+            return Some(enum_info.values.clone());
+        }
+    }
+    table
+        .constraints
+        .iter()
+        .filter(|c| c.constraint_type == ConstraintType::Check)
+        .filter_map(|c| parse_check_enum(c.check_expression.as_deref()?, true))
+        .find(|(name, values)| *name == col.name && !values.is_empty())
+        .map(|(_, values)| values)
+}
+
+/// `{column}_{row}`, trimmed from the left to fit a declared length so the
+/// row number — the part that keeps values unique — survives.
+fn bounded_text(column: &str, row: usize, length: Option<i32>) -> String {
+    let text = format!("{column}_{row}");
+    match length {
+        Some(len) if len > 0 && text.chars().count() > len as usize => {
+            let skip = text.chars().count() - len as usize;
+            text.chars().skip(skip).collect()
+        }
+        _ => text,
+    }
+}
+
+/// The row number as big-endian hex bytes, wrapped to fit a declared
+/// length.
+fn seed_bytes(row: usize, length: Option<i32>) -> String {
+    let value = match length {
+        Some(len @ 1..=7) => row % (1usize << (8 * len)),
+        _ => row,
+    };
+    let hex = format!("{value:x}");
+    if hex.len() % 2 == 1 {
+        format!("0{hex}")
+    } else {
+        hex
+    }
+}
+
+/// A distinct calendar date per row, starting at 2024-01-01 and stepping
+/// through days 1–28 of each month.
+fn seed_date(row: usize) -> String {
+    let i = row - 1;
+    let day = i % 28 + 1;
+    let month = (i / 28) % 12 + 1;
+    let year = 2024 + i / (28 * 12);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+fn string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(test)]
+#[path = "seed_tests.rs"]
+mod tests;
@@ -0,0 +1,197 @@
+use super::*;
+use crate::testutil::*;
+
+fn blog_schema() -> IntrospectedSchema {
+    schema_pg(vec![
+        table("posts")
+            .column(col("id").identity().build())
+            .column(col("author_id").build())
+            .column(col("title").udt("varchar").max_length(6).build())
+            .column(col("status").udt("varchar").build())
+            .pk("posts_pkey", &["id"])
+            .fk("posts_author_fk", &["author_id"], "users", &["id"])
+            .check("posts_status_check", "status IN ('draft', 'live')")
+            .build(),
+        table("users")
+            .column(col("id").build())
+            .column(col("email").udt("text").build())
+            .column(col("active").udt("bool").build())
+            .pk("users_pkey", &["id"])
+            .build(),
+    ])
+}
+
+#[test]
+fn seed_inserts_parents_first_and_skips_identity() {
+    let output = generate(&blog_schema(), 2, Dialect::Postgres);
+    let users = output.find("INSERT INTO \"users\"").unwrap();
+    let posts = output.find("INSERT INTO \"posts\"").unwrap();
+    assert!(users < posts, "{output}");
+    assert!(output.contains(
+        "INSERT INTO \"users\" (\"id\", \"email\", \"active\") VALUES\n    \
+         (1, 'email_1', FALSE),\n    (2, 'email_2', TRUE);"
+    ));
+    assert!(output.contains("INSERT INTO \"posts\" (\"author_id\", \"title\", \"status\") VALUES"));
+}
+
+#[test]
+fn seed_skips_generated_columns() {
+    let schema = schema_pg(vec![table("lines")
+        .column(col("qty").build())
+        .column(col("total").computed("(qty * 2)", Some(true)).build())
+        .build()]);
+    let output = generate(&schema, 1, Dialect::Postgres);
+    assert!(
+        output.contains("INSERT INTO \"lines\" (\"qty\") VALUES"),
+        "{output}"
+    );
+}
+
+#[test]
+fn seed_skips_temporal_period_columns_and_history_tables() {
+    let mut schema = schema_mssql(vec![
+        table("Orders")
+            .schema("dbo")
+            .column(col("qty").udt("int").build())
+            .column(
+                col("ValidFrom")
+                    .udt("datetime2")
+                    .period(crate::schema::PeriodColumn::RowStart)
+                    .build(),
+            )
+            .build(),
+        table("OrdersHistory")
+            .schema("dbo")
+            .column(col("qty").udt("int").build())
+            .build(),
+    ]);
+    schema.tables[1].history_of = Some("Orders".to_string());
+    let output = generate(&schema, 1, Dialect::Mssql);
+    assert!(output.contains("([qty]) VALUES"), "{output}");
+    assert!(!output.contains("OrdersHistory"), "{output}");
+}
+
+#[test]
+fn seed_respects_lengths_check_values_and_fk_targets() {
+    let output = generate(&blog_schema(), 3, Dialect::Postgres);
+    // title is varchar(6): `title_1` is trimmed from the left.
+    assert!(output.contains("    (1, 'itle_1', 'draft'),"), "{output}");
+    assert!(output.contains("    (3, 'itle_3', 'draft');"), "{output}");
+    assert!(output.contains("    (2, 'itle_2', 'live'),"), "{output}");
+}
+
+#[test]
+fn seed_fk_onto_identity_uses_identity_sequence() {
+    let schema = schema_pg(vec![
+        table("comments")
+            .column(col("id").build())
+            .column(col("post_id").build())
+            .pk("comments_pkey", &["id"])
+            .fk("comments_post_fk", &["post_id"], "posts", &["id"])
+            .build(),
+        table("posts")
+            .column(col("id").identity().build())
+            .column(col("body").udt("text").build())
+            .pk("posts_pkey", &["id"])
+            .build(),
+    ]);
+    let output = generate(&schema, 3, Dialect::Postgres);
+    assert!(output.contains("INSERT INTO \"posts\" (\"body\") VALUES"));
+    assert!(output.contains("    (3, 3);"), "{output}");
+}
+
+#[test]
+fn seed_renders_target_dialect_literals() {
+    let output = generate(&blog_schema(), 2, Dialect::Mssql);
+    assert!(output.contains("INSERT INTO [users] ([id], [email], [active]) VALUES"));
+    assert!(output.contains("(1, 'email_1', 0)"), "{output}");
+}
+
+#[test]
+fn seed_nullable_self_reference_is_null() {
+    let schema = schema_pg(vec![table("nodes")
+        .column(col("id").build())
+        .column(col("parent_id").nullable().build())
+        .pk("nodes_pkey", &["id"])
+        .fk("nodes_parent_fk", &["parent_id"], "nodes", &["id"])
+        .build()]);
+    let output = generate(&schema, 1, Dialect::Postgres);
+    assert!(output.contains("    (1, NULL);"), "{output}");
+}
+
+#[test]
+fn seed_chunks_large_row_counts() {
+    let output = generate(&blog_schema(), 1001, Dialect::Postgres);
+    assert_eq!(output.matches("INSERT INTO \"users\"").count(), 2);
+}
+
+#[test]
+fn seed_caps_rows_at_unique_key_values() {
+    let schema = schema_pg(vec![
+        table("statuses")
+            .column(col("code").udt("varchar").build())
+            .column(col("flag").udt("bool").build())
+            .unique("statuses_code_key", &["code"])
+            .check("statuses_code_check", "code IN ('new', 'done')")
+            .build(),
+        table("tasks")
+            .column(col("id").build())
+            .column(col("status").udt("varchar").build())
+            .pk("tasks_pkey", &["id"])
+            .fk("tasks_status_fk", &["status"], "statuses", &["code"])
+            .build(),
+    ]);
+    let output = generate(&schema, 3, Dialect::Postgres);
+    assert!(
+        output.contains(
+            "-- \"statuses\": 2 of 3 row(s); statuses_code_key has no more distinct values\n\
+             INSERT INTO \"statuses\" (\"code\", \"flag\") VALUES\n    \
+             ('new', FALSE),\n    ('done', TRUE);"
+        ),
+        "{output}"
+    );
+    // Foreign keys cycle through the parent rows that were inserted.
+    assert!(
+        output.contains("    (1, 'new'),\n    (2, 'done'),\n    (3, 'new');"),
+        "{output}"
+    );
+}
+
+#[test]
+fn seed_unique_key_with_an_unbounded_column_is_not_capped() {
+    let schema = schema_pg(vec![table("slots")
+        .column(col("id").build())
+        .column(col("rank").udt("int2").build())
+        .unique("slots_key", &["id", "rank"])
+        .build()]);
+    let output = generate(&schema, 3, Dialect::Postgres);
+    assert!(!output.contains("distinct values"), "{output}");
+    assert!(output.contains("    (3, 3);"), "{output}");
+}
+
+#[test]
+fn seed_times_and_bytes_do_not_wrap_early() {
+    let schema = schema_pg(vec![table("events")
+        .column(col("at").udt("time").build())
+        .column(col("tag").udt("bytea").build())
+        .build()]);
+    let output = generate(&schema, 1500, Dialect::Postgres);
+    // Row 1441 used to repeat row 1's time, and row 257 row 1's bytes.
+    assert!(output.contains("    ('00:24:01', '\\x05a1'),"), "{output}");
+}
+
+#[test]
+fn seed_python_runs_the_statements_through_sqlalchemy() {
+    let output = generate_python(&blog_schema(), 2, Dialect::Postgres);
+    assert!(output.contains("from sqlalchemy import create_engine\n"));
+    assert!(
+        output.contains(
+            "STATEMENTS = [\n    (\n        \
+             'INSERT INTO \"users\" (\"id\", \"email\", \"active\") VALUES\\n'\n        \
+             \"    (1, 'email_1', FALSE),\\n\"\n        \
+             \"    (2, 'email_2', TRUE);\"\n    ),\n"
+        ),
+        "{output}"
+    );
+    assert!(output.contains("            conn.exec_driver_sql(statement)\n"));
+}
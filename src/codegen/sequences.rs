@@ -0,0 +1,85 @@
+//! Standalone PostgreSQL sequences: module-level `Sequence()` objects for
+//! the sequences column defaults draw from, and the positional argument
+//! wiring each such column to its sequence.
+
+use crate::naming::sequence_variable_name;
+use crate::schema::{ColumnInfo, IntrospectedSchema, SequenceInfo, TableInfo};
+
+use super::format_python_string_literal;
+use super::parse_sequence_name;
+
+/// The standalone sequence `col`'s `nextval()` default draws from.
+pub fn column_sequence<'a>(
+    schema: &'a IntrospectedSchema,
+    table: &TableInfo,
+    col: &ColumnInfo,
+) -> Option<&'a SequenceInfo> {
+    let name = parse_sequence_name(col.column_default.as_deref()?)?;
+    schema.sequence(&name.replace('"', ""), &table.schema)
+}
+
+/// Standalone sequences drawn from by the columns of `tables`, once each,
+/// in first-use order.
+pub fn used_sequences<'a, 'b>(
+    schema: &'a IntrospectedSchema,
+    tables: impl IntoIterator<Item = &'b TableInfo>,
+) -> Vec<&'a SequenceInfo> {
+    let mut used: Vec<&SequenceInfo> = Vec::new();
+    for table in tables {
+        for col in &table.columns {
+            if let Some(sequence) = column_sequence(schema, table, col) {
+                if !used.contains(&sequence) {
+                    used.push(sequence);
+                }
+            }
+        }
+    }
+    used
+}
+
+/// `invoice_seq = Sequence('invoice_seq', start=1000, metadata=metadata)`,
+/// bound to `metadata_ref` so `create_all()` creates it. Options other
+/// than `start` are spelled out only where they differ from PostgreSQL's
+/// defaults for the sequence's direction.
+pub fn sequence_definition(
+    schema: &IntrospectedSchema,
+    sequence: &SequenceInfo,
+    metadata_ref: &str,
+) -> String {
+    let ascending = sequence.increment > 0;
+    let mut args = vec![
+        format_python_string_literal(&sequence.name),
+        format!("start={}", sequence.start),
+    ];
+    if sequence.increment != 1 {
+        args.push(format!("increment={}", sequence.increment));
+    }
+    if sequence.min_value != if ascending { 1 } else { i64::MIN } {
+        args.push(format!("minvalue={}", sequence.min_value));
+    }
+    if sequence.max_value != if ascending { i64::MAX } else { -1 } {
+        args.push(format!("maxvalue={}", sequence.max_value));
+    }
+    if sequence.cycle {
+        args.push("cycle=True".to_string());
+    }
+    if sequence.cache != 1 {
+        args.push(format!("cache={}", sequence.cache));
+    }
+    if !sequence.schema.is_empty() && sequence.schema != schema.dialect.default_schema() {
+        args.push(format!(
+            "schema={}",
+            format_python_string_literal(&sequence.schema)
+        ));
+    }
+    args.push(format!("metadata={metadata_ref}"));
+    format!(
+        "{} = Sequence({})",
+        sequence_variable_name(schema, sequence),
+        args.join(", ")
+    )
+}
+
+#[cfg(test)]
+#[path = "sequences_tests.rs"]
+mod tests;
@@ -0,0 +1,71 @@
+use super::*;
+use crate::testutil::*;
+
+fn invoice_seq(schema: &str) -> SequenceInfo {
+    SequenceInfo {
+        schema: schema.to_string(),
+        name: "invoice_seq".to_string(),
+        start: 1000,
+        increment: 1,
+        min_value: 1,
+        max_value: i64::MAX,
+        cycle: false,
+        cache: 1,
+    }
+}
+
+fn invoices(default: &str) -> IntrospectedSchema {
+    schema_pg(vec![table("invoices")
+        .column(col("number").udt("int8").default_val(default).build())
+        .column(col("note").udt("text").build())
+        .build()])
+}
+
+#[test]
+fn default_options_render_only_start() {
+    let schema = invoices("nextval('invoice_seq'::regclass)");
+    assert_eq!(
+        sequence_definition(&schema, &invoice_seq("public"), "metadata"),
+        "invoice_seq = Sequence('invoice_seq', start=1000, metadata=metadata)"
+    );
+}
+
+#[test]
+fn non_default_options_and_schema_are_spelled_out() {
+    let schema = invoices("nextval('billing.invoice_seq'::regclass)");
+    let sequence = SequenceInfo {
+        increment: -1,
+        min_value: -500,
+        max_value: -1,
+        cycle: true,
+        cache: 20,
+        ..invoice_seq("billing")
+    };
+    assert_eq!(
+        sequence_definition(&schema, &sequence, "Base.metadata"),
+        "billing_invoice_seq = Sequence('invoice_seq', start=1000, increment=-1, \
+         minvalue=-500, cycle=True, cache=20, schema='billing', metadata=Base.metadata)"
+    );
+}
+
+#[test]
+fn column_sequence_resolves_qualified_and_unqualified_names() {
+    let mut schema = invoices("nextval('invoice_seq'::regclass)");
+    schema.sequences = vec![invoice_seq("billing"), invoice_seq("public")];
+    let table = &schema.tables[0];
+
+    let found = column_sequence(&schema, table, &table.columns[0]).unwrap();
+    assert_eq!(found.schema, "public");
+    assert!(column_sequence(&schema, table, &table.columns[1]).is_none());
+
+    let mut schema = invoices("nextval('billing.invoice_seq'::regclass)");
+    schema.sequences = vec![invoice_seq("public"), invoice_seq("billing")];
+    let table = &schema.tables[0];
+    assert_eq!(
+        column_sequence(&schema, table, &table.columns[0])
+            .unwrap()
+            .schema,
+        "billing"
+    );
+    assert_eq!(used_sequences(&schema, &schema.tables).len(), 1);
+}
@@ -56,10 +56,27 @@ pub(crate) fn strip_mssql_parens(expr: &str) -> &str {
     s.trim()
 }
 
-/// Try to parse an IN-list from a check constraint expression.
-/// Returns (column_name, values) if the expression matches `[table.]column IN ('a', 'b', 'c')`.
-pub fn parse_check_enum(expression: &str) -> Option<(String, Vec<String>)> {
-    // Match pattern: [optional_table.]column IN ('val1', 'val2', ...)
+/// Try to parse a string-valued IN-list from a check constraint expression.
+/// Returns (column_name, values) if the expression matches
+/// `[table.]column IN ('a', 'b', 'c')`.
+///
+/// With `every_spelling` (`--options enum_from_check`) it also accepts the
+/// forms the dialects rewrite `IN` into:
+///
+/// - `(column IN (_utf8mb4'a', ...))` (MySQL charset introducers)
+/// - `(column)::text = ANY ((ARRAY['a'::character varying, ...])::text[])`
+///   (PostgreSQL)
+/// - `[column]='a' OR [column]='b'` (SQL Server)
+pub fn parse_check_enum(expression: &str, every_spelling: bool) -> Option<(String, Vec<String>)> {
+    if every_spelling {
+        parse_check_enum_any_dialect(expression)
+    } else {
+        parse_check_in_list(expression)
+    }
+}
+
+/// The plain `[table.]column IN ('a', 'b', 'c')` form.
+fn parse_check_in_list(expression: &str) -> Option<(String, Vec<String>)> {
     let expr = expression.trim();
 
     // Find " IN (" (case-insensitive) using byte-level search to avoid
@@ -110,6 +127,134 @@ pub fn parse_check_enum(expression: &str) -> Option<(String, Vec<String>)> {
     Some((col_name.to_string(), values))
 }
 
+/// A list of string literals limiting one column, however the dialect
+/// reports it.
+fn parse_check_enum_any_dialect(expression: &str) -> Option<(String, Vec<String>)> {
+    use CheckToken::{Ident, Literal, Punct, Word};
+
+    let tokens = check_tokens(expression);
+    let Some(Ident(column)) = tokens.first() else {
+        return None;
+    };
+    let keyword = |token: &CheckToken, word: &str| matches!(token, Word(w) if w == word);
+    // 'a', 'b', ...
+    let literals = |items: &[CheckToken]| -> Option<Vec<String>> {
+        items
+            .split(|token| *token == Punct(','))
+            .map(|item| match item {
+                [Literal(value)] => Some(value.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let values = match &tokens[1..] {
+        [kw, items @ ..] if keyword(kw, "in") => literals(items)?,
+        [Punct('='), any, array, Punct('['), items @ .., Punct(']')]
+            if keyword(any, "any") && keyword(array, "array") =>
+        {
+            literals(items)?
+        }
+        // column = 'a' OR column = 'b' ...; a lone `column = 'a'` is no list.
+        _ if tokens.iter().any(|token| keyword(token, "or")) => tokens
+            .split(|token| keyword(token, "or"))
+            .map(|term| match term {
+                [Ident(name), Punct('='), Literal(value)] if name == column => Some(value.clone()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+    let name = column.rsplit('.').next().unwrap_or(column);
+    Some((name.to_string(), values))
+}
+
+/// A token of a CHECK predicate, for [`parse_check_enum_any_dialect`].
+#[derive(Debug, PartialEq)]
+enum CheckToken {
+    /// A possibly dotted column reference, quotes removed.
+    Ident(String),
+    /// `in`, `any`, `array`, or `or`, lowercased.
+    Word(String),
+    /// A string literal, unescaped.
+    Literal(String),
+    Punct(char),
+}
+
+/// Tokenize a CHECK predicate with the noise the dialects add removed:
+/// parentheses, PostgreSQL `::type` casts, and `N` / `_charset` prefixes on
+/// string literals.
+fn check_tokens(expression: &str) -> Vec<CheckToken> {
+    use CheckToken::{Ident, Literal, Punct, Word};
+
+    let mut tokens: Vec<CheckToken> = Vec::new();
+    let mut chars = expression.chars().peekable();
+    let mut in_cast = false;
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '(' | ')' => continue,
+            c if c.is_whitespace() => continue,
+            ':' if chars.next_if_eq(&':').is_some() => {
+                in_cast = true;
+                continue;
+            }
+            // The `[]` of an array cast such as `::text[]`.
+            '[' if in_cast => {
+                chars.next_if_eq(&']');
+                continue;
+            }
+            '[' if matches!(tokens.last(), Some(Word(w)) if w == "array") => Punct('['),
+            '\'' => {
+                let mut value = String::new();
+                while let Some(ch) = chars.next() {
+                    if ch == '\'' && chars.next_if_eq(&'\'').is_none() {
+                        break;
+                    }
+                    value.push(ch);
+                }
+                // `N'...'` and MySQL's `_utf8mb4'...'` introducers.
+                if matches!(tokens.last(), Some(Ident(prefix)) if prefix == "N" || prefix.starts_with('_'))
+                {
+                    tokens.pop();
+                }
+                Literal(value)
+            }
+            '"' | '`' | '[' => {
+                let close = if c == '[' { ']' } else { c };
+                Ident(chars.by_ref().take_while(|&ch| ch != close).collect())
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut word = c.to_string();
+                while let Some(ch) = chars.next_if(|ch| ch.is_alphanumeric() || *ch == '_') {
+                    word.push(ch);
+                }
+                let lower = word.to_ascii_lowercase();
+                if matches!(lower.as_str(), "in" | "any" | "array" | "or") {
+                    Word(lower)
+                } else if in_cast {
+                    // Type names may run over several words (`character varying`).
+                    continue;
+                } else {
+                    Ident(word)
+                }
+            }
+            c => Punct(c),
+        };
+        in_cast = false;
+        // Join `table.column` into one reference.
+        if let Ident(part) = &token {
+            if let [.., Ident(path), Punct('.')] = tokens.as_slice() {
+                let joined = format!("{path}.{part}");
+                tokens.truncate(tokens.len() - 2);
+                tokens.push(Ident(joined));
+                continue;
+            }
+        }
+        tokens.push(token);
+    }
+    tokens
+}
+
 /// Check if a check constraint expression represents a boolean column.
 /// Returns the column name if the expression matches `[schema.][table.]column IN (0, 1)`.
 pub fn parse_check_boolean(expression: &str) -> Option<String> {
@@ -159,6 +304,15 @@ pub fn is_serial_default(default: &str, dialect: Dialect) -> bool {
     }
 }
 
+/// Check if a column default draws from an MSSQL sequence:
+/// `(NEXT VALUE FOR [dbo].[invoice_seq])`. Only the server can evaluate it.
+pub fn is_next_value_default(default: &str, dialect: Dialect) -> bool {
+    dialect == Dialect::Mssql
+        && strip_mssql_parens(default)
+            .get(..15)
+            .is_some_and(|head| head.eq_ignore_ascii_case("next value for "))
+}
+
 /// Check if a column is auto-increment in its source dialect.
 /// Unifies MSSQL `IDENTITY`, PG `GENERATED ... AS IDENTITY`, PG `SERIAL` (via
 /// `nextval(...)` default), MySQL `AUTO_INCREMENT`, and SQLite `AUTOINCREMENT`.
@@ -1,18 +1,25 @@
+use std::collections::HashMap;
+
 use crate::cli::GeneratorOptions;
 use crate::codegen::imports::ImportCollector;
 use crate::codegen::python::PythonOutput;
 use crate::codegen::{
-    enum_class_name, find_enum_for_column, format_fk_options, format_index_kwargs,
-    format_python_string_literal, format_server_default, generate_enum_class,
-    is_primary_key_column, is_serial_default, is_standard_sequence_name,
-    is_unique_constraint_index, parse_check_boolean, parse_check_enum, parse_sequence_name,
-    quote_constraint_columns, topo_sort_tables,
+    column_sequence, map_column_type_with, name_arg, object_comments, period_column_args,
+    sequence_definition, server_default_arg, skipped_warnings, table_note, used_sequences,
+    view_definition_comment, NamingConvention,
+};
+use crate::codegen::{
+    enum_class_name, exclude_constraint, find_column_enum, fk_target, format_fk_options,
+    format_index_kwargs, format_python_string_literal, format_server_default, generate_enum_class,
+    index_needs_text, is_primary_key_column, is_serial_default, is_standard_sequence_name,
+    is_unique_constraint_index, nulls_not_distinct_kwarg, order_tables, parse_check_boolean,
+    parse_check_enum, parse_sequence_name, quote_constraint_columns, quote_index_keys, TableOrder,
 };
 use crate::dialect::Dialect;
-use crate::naming::table_to_variable_name;
+use crate::naming::{sequence_variable_name, table_variable_name};
 use crate::schema::EnumInfo;
 use crate::schema::{ConstraintType, IntrospectedSchema, TableInfo};
-use crate::typemap::{map_column_type, map_column_type_dialect};
+use crate::typemap::array_type;
 
 /// Generate `Table()` metadata output as a single file.
 pub fn generate(schema: &IntrospectedSchema, options: &GeneratorOptions) -> String {
@@ -24,7 +31,7 @@ pub fn generate_split(
     schema: &IntrospectedSchema,
     options: &GeneratorOptions,
 ) -> Vec<(String, String)> {
-    parts(schema, options).split()
+    parts(schema, options).split_with(options)
 }
 
 /// Build the structured output: prelude (imports, metadata, enum classes)
@@ -32,6 +39,7 @@ pub fn generate_split(
 fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutput {
     let mut imports = ImportCollector::new();
     let mut table_blocks: Vec<(String, String)> = Vec::new();
+    let mut schemas: HashMap<String, String> = HashMap::new();
 
     // Always need MetaData and Table for tables generator
     imports.add("sqlalchemy", "MetaData");
@@ -46,10 +54,13 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
     let mut boolean_cols: std::collections::HashSet<(String, String)> =
         std::collections::HashSet::new();
 
-    let sorted_tables = topo_sort_tables(&schema.tables);
+    let order = order_tables(&schema.tables);
+    let convention = options
+        .naming_convention
+        .then(|| NamingConvention::infer(schema));
 
     // Detect boolean columns from check constraints
-    for table in &sorted_tables {
+    for table in &order.tables {
         for constraint in &table.constraints {
             if constraint.constraint_type == ConstraintType::Check {
                 if let Some(ref expr) = constraint.check_expression {
@@ -63,11 +74,13 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
 
     // Extract synthetic enums from check constraints (unless nosyntheticenums)
     if !options.nosyntheticenums {
-        for table in &sorted_tables {
+        for table in &order.tables {
             for constraint in &table.constraints {
                 if constraint.constraint_type == ConstraintType::Check {
                     if let Some(ref expr) = constraint.check_expression {
-                        if let Some((col_name, values)) = parse_check_enum(expr) {
+                        if let Some((col_name, values)) =
+                            parse_check_enum(expr, options.enum_from_check)
+                        {
                             let key = (table.name.clone(), col_name.clone());
                             if let std::collections::hash_map::Entry::Vacant(entry) =
                                 synthetic_enum_cols.entry(key)
@@ -93,11 +106,11 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
     // Track which enums are actually used
     let mut used_enum_names: std::collections::HashSet<String> = std::collections::HashSet::new();
 
-    for table in &sorted_tables {
+    for table in order.tables_then_views() {
         // Track named enum usage
         for col_info in &table.columns {
-            if find_enum_for_column(&col_info.udt_name, &all_enums).is_some() {
-                used_enum_names.insert(col_info.udt_name.clone());
+            if let Some((enum_info, _)) = find_column_enum(col_info, &all_enums) {
+                used_enum_names.insert(enum_info.name.clone());
             }
             // Track synthetic enum usage via direct lookup
             let key = (table.name.clone(), col_info.name.clone());
@@ -106,17 +119,22 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
             }
         }
 
+        let var_name = table_variable_name(schema, table, options);
         let block = generate_table(
             table,
+            &var_name,
             &mut imports,
             options,
             schema.dialect,
             &all_enums,
             &synthetic_enum_cols,
             &boolean_cols,
-            &schema.domains,
+            schema,
+            &order,
+            convention.as_ref(),
         );
-        table_blocks.push((table_to_variable_name(&table.name), block));
+        schemas.insert(var_name.clone(), table.schema.clone());
+        table_blocks.push((var_name, block));
     }
 
     // Collect used enum infos for class generation
@@ -133,8 +151,19 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
         imports.add("sqlalchemy", "Enum");
     }
 
+    let sequences = used_sequences(schema, order.tables_then_views());
+    if !sequences.is_empty() {
+        imports.add("sqlalchemy", "Sequence");
+    }
+
     let mut prelude = imports.render();
-    prelude.push_str("\n\nmetadata = MetaData()\n");
+    match &convention {
+        Some(convention) => prelude.push_str(&format!(
+            "\n\nmetadata = {}\n",
+            convention.metadata_call("")
+        )),
+        None => prelude.push_str("\n\nmetadata = MetaData()\n"),
+    }
 
     // Enum class definitions
     for ei in &used_enums {
@@ -142,29 +171,53 @@ fn parts(schema: &IntrospectedSchema, options: &GeneratorOptions) -> PythonOutpu
         prelude.push_str(&generate_enum_class(ei));
     }
 
+    if !sequences.is_empty() {
+        prelude.push('\n');
+        for sequence in &sequences {
+            prelude.push('\n');
+            prelude.push_str(&sequence_definition(schema, sequence, "metadata"));
+        }
+        prelude.push('\n');
+    }
+
     PythonOutput {
         prelude,
         models: table_blocks,
         separator: "\n\n",
+        links: Default::default(),
+        schemas,
     }
 }
 
 #[allow(clippy::too_many_arguments)]
 fn generate_table(
     table: &TableInfo,
+    var_name: &str,
     imports: &mut ImportCollector,
     options: &GeneratorOptions,
     dialect: Dialect,
     enums: &[EnumInfo],
     synthetic_enum_cols: &std::collections::HashMap<(String, String), String>,
     boolean_cols: &std::collections::HashSet<(String, String)>,
-    schema_domains: &[crate::schema::DomainInfo],
+    schema: &IntrospectedSchema,
+    order: &TableOrder,
+    convention: Option<&NamingConvention>,
 ) -> String {
-    let var_name = table_to_variable_name(&table.name);
     let mut lines: Vec<String> = Vec::new();
 
+    lines.extend(skipped_warnings(table));
+    lines.extend(view_definition_comment(table));
+    lines.extend(table_note(table).map(|note| format!("# {note}")));
+    lines.extend(
+        object_comments(table, options)
+            .iter()
+            .map(|line| format!("# {line}")),
+    );
     lines.push(format!("{var_name} = Table("));
-    lines.push(format!("    '{}', metadata,", table.name));
+    lines.push(format!(
+        "    {}, metadata,",
+        format_python_string_literal(&table.name)
+    ));
 
     // Collect all body items (columns, constraints, indexes, PK, schema)
     let mut body_items: Vec<String> = Vec::new();
@@ -172,7 +225,8 @@ fn generate_table(
     // Columns
     for col in &table.columns {
         let mut col_args: Vec<String> = Vec::new();
-        col_args.push(format!("'{}'", col.name));
+        col_args.push(format_python_string_literal(&col.name));
+        let mut rendered = None;
 
         // Check if column is a boolean (detected from IN (0, 1) check on integer types)
         let bool_key = (table.name.clone(), col.name.clone());
@@ -193,7 +247,7 @@ fn generate_table(
             // If needed for DDL correctness, add: native_enum=False, create_constraint=False
         }
         // Check if column type is a named enum
-        else if let Some(ei) = find_enum_for_column(&col.udt_name, enums) {
+        else if let Some((ei, is_array)) = find_column_enum(col, enums) {
             let cls = enum_class_name(&ei.name);
             let mut enum_parts = vec![
                 cls,
@@ -207,11 +261,18 @@ fn generate_table(
                     enum_parts.push(format!("schema={}", format_python_string_literal(schema)));
                 }
             }
-            col_args.push(format!("Enum({})", enum_parts.join(", ")));
+            let enum_type = format!("Enum({})", enum_parts.join(", "));
+            if is_array {
+                imports.add("sqlalchemy", "ARRAY");
+                col_args.push(array_type(&enum_type, col.array_dimensions));
+            } else {
+                col_args.push(enum_type);
+            }
         } else {
             // Check for domain type — resolve to DOMAIN('name', BaseType(), ...) (PG only)
             let domain = if dialect == Dialect::Postgres {
-                schema_domains.iter().find(|d| d.name == col.udt_name)
+                let domain_name = col.domain.as_deref().unwrap_or(&col.udt_name);
+                schema.domains.iter().find(|d| d.name == domain_name)
             } else {
                 None
             };
@@ -222,17 +283,16 @@ fn generate_table(
                     udt_name: di.base_type.clone(),
                     ..col.clone()
                 };
-                let base_mapped = if options.keep_dialect_types {
-                    map_column_type_dialect(&base_col, dialect)
-                } else {
-                    map_column_type(&base_col, dialect)
-                };
+                let base_mapped = map_column_type_with(&base_col, dialect, options);
                 imports.add(&base_mapped.import_module, &base_mapped.import_name);
 
-                let mut domain_args = vec![
-                    format_python_string_literal(&di.name),
-                    format!("{}()", base_mapped.sa_type),
-                ];
+                // Sized base types already render as a call (`String(255)`).
+                let base_type = if base_mapped.sa_type.ends_with(')') {
+                    base_mapped.sa_type.clone()
+                } else {
+                    format!("{}()", base_mapped.sa_type)
+                };
+                let mut domain_args = vec![format_python_string_literal(&di.name), base_type];
                 if let Some(ref cn) = di.constraint_name {
                     domain_args.push(format!(
                         "constraint_name={}",
@@ -249,41 +309,32 @@ fn generate_table(
                 }
                 col_args.push(format!("DOMAIN({})", domain_args.join(", ")));
             } else {
-                let mapped = if options.keep_dialect_types {
-                    map_column_type_dialect(col, dialect)
-                } else {
-                    map_column_type(col, dialect)
-                };
+                let mapped = map_column_type_with(col, dialect, options);
                 imports.add(&mapped.import_module, &mapped.import_name);
-                if let Some((ref elem_mod, ref elem_name)) = mapped.element_import {
+                for (elem_mod, elem_name) in &mapped.extra_imports {
                     imports.add(elem_mod, elem_name);
                 }
                 col_args.push(mapped.sa_type.clone());
+                rendered = Some(mapped);
             }
         }
 
         // Identity — dialect-aware output
-        if let Some(ref identity) = col.identity {
+        if let Some(identity) = crate::codegen::format_identity(col, dialect) {
             imports.add("sqlalchemy", "Identity");
-            match dialect {
-                Dialect::Postgres => {
-                    col_args.push(format!(
-                        "Identity(start={}, increment={}, minvalue={}, maxvalue={}, cycle=False, cache={})",
-                        identity.start, identity.increment, identity.min_value, identity.max_value, identity.cache
-                    ));
-                }
-                Dialect::Mssql | Dialect::Mysql | Dialect::Sqlite => {
-                    col_args.push(format!(
-                        "Identity(start={}, increment={})",
-                        identity.start, identity.increment
-                    ));
-                }
-            }
+            col_args.push(identity);
+        }
+
+        if let Some(ref computed) = col.computed {
+            imports.add("sqlalchemy", "Computed");
+            col_args.push(crate::codegen::format_computed(computed));
         }
 
         // Sequence is a positional Column() argument, so it must be emitted
         // before keyword arguments such as primary_key and nullable.
-        if let Some(ref default) = col.column_default {
+        if let Some(sequence) = column_sequence(schema, table, col) {
+            col_args.push(sequence_variable_name(schema, sequence));
+        } else if let Some(ref default) = col.column_default {
             if is_serial_default(default, dialect) {
                 // Check for non-standard sequence name → emit Sequence()
                 if let Some(full_seq_name) = parse_sequence_name(default) {
@@ -322,20 +373,31 @@ fn generate_table(
         // Non-sequence server default
         if let Some(ref default) = col.column_default {
             if !is_serial_default(default, dialect) {
-                imports.add("sqlalchemy", "text");
-                let formatted = format_server_default(default, dialect);
+                let formatted = server_default_arg(col, default, dialect, options, imports);
                 col_args.push(format!("server_default={formatted}"));
             }
         }
+        if let Some(args) = period_column_args(col, imports) {
+            col_args.push(args.to_string());
+        }
 
         // Comment
-        if !options.nocomments {
+        if options.column_comments() {
             if let Some(ref comment) = col.comment {
                 col_args.push(format!("comment={}", format_python_string_literal(comment)));
             }
         }
 
-        body_items.push(format!("Column({})", col_args.join(", ")));
+        // info={...}: --pii flags and --info-properties
+        if let Some(info) = crate::codegen::format_column_info(col, options) {
+            col_args.push(info);
+        }
+
+        let column = format!("Column({})", col_args.join(", "));
+        match crate::codegen::column_comment(col, dialect, rendered.as_ref(), "    ") {
+            Some(advisory) => body_items.push(format!("{}\n    {column}", advisory.trim_start())),
+            None => body_items.push(column),
+        }
     }
 
     // Foreign key constraints
@@ -347,19 +409,19 @@ fn generate_table(
                     let local_cols: Vec<String> = constraint
                         .columns
                         .iter()
-                        .map(|c| format!("'{c}'"))
+                        .map(|c| format_python_string_literal(c))
                         .collect();
                     let ref_cols: Vec<String> = fk
                         .ref_columns
                         .iter()
-                        .map(|c| format!("'{}.{c}'", fk.ref_table))
+                        .map(|c| format_python_string_literal(&fk_target(fk, c, dialect)))
                         .collect();
-                    let fk_opts = format_fk_options(fk);
+                    let fk_opts = format_fk_options(fk, order.defers(table, &constraint.name));
                     body_items.push(format!(
-                        "ForeignKeyConstraint([{}], [{}], name='{}'{})",
+                        "ForeignKeyConstraint([{}], [{}]{}{})",
                         local_cols.join(", "),
                         ref_cols.join(", "),
-                        constraint.name,
+                        name_arg(convention, table, constraint),
                         fk_opts
                     ));
                 }
@@ -382,8 +444,8 @@ fn generate_table(
                         body_items.push(format!("CheckConstraint({expr_literal})"));
                     } else {
                         body_items.push(format!(
-                            "CheckConstraint({expr_literal}, name='{}')",
-                            constraint.name
+                            "CheckConstraint({expr_literal}, name={})",
+                            format_python_string_literal(&constraint.name)
                         ));
                     }
                 }
@@ -398,9 +460,9 @@ fn generate_table(
                 imports.add("sqlalchemy", "PrimaryKeyConstraint");
                 let cols = quote_constraint_columns(&constraint.columns);
                 body_items.push(format!(
-                    "PrimaryKeyConstraint({}, name='{}')",
+                    "PrimaryKeyConstraint({}{})",
                     cols.join(", "),
-                    constraint.name
+                    name_arg(convention, table, constraint)
                 ));
             }
         }
@@ -413,28 +475,39 @@ fn generate_table(
                 imports.add("sqlalchemy", "UniqueConstraint");
                 let cols = quote_constraint_columns(&constraint.columns);
                 body_items.push(format!(
-                    "UniqueConstraint({}, name='{}')",
+                    "UniqueConstraint({}{}{})",
                     cols.join(", "),
-                    constraint.name
+                    name_arg(convention, table, constraint),
+                    nulls_not_distinct_kwarg(constraint.nulls_not_distinct, dialect)
                 ));
             }
         }
+        for constraint in &table.constraints {
+            if let Some(ref exclude) = constraint.exclude {
+                let name = name_arg(convention, table, constraint);
+                body_items.push(exclude_constraint(exclude, &name, imports));
+            }
+        }
     }
 
     // Indexes
     if !options.noindexes {
         for index in &table.indexes {
-            // Skip indexes that back unique constraints (already handled)
-            if is_unique_constraint_index(index, &table.constraints) {
+            // Skip indexes that back unique constraints (already handled) and
+            // columnstore indexes (named in the table note)
+            if index.columnstore || is_unique_constraint_index(index, &table.constraints) {
                 continue;
             }
             imports.add("sqlalchemy", "Index");
-            let cols = quote_constraint_columns(&index.columns);
+            if index_needs_text(index) {
+                imports.add("sqlalchemy", "text");
+            }
+            let cols = quote_index_keys(index, dialect);
             let unique_str = if index.is_unique { ", unique=True" } else { "" };
-            let kwargs_str = format_index_kwargs(&index.kwargs);
+            let kwargs_str = format_index_kwargs(index, dialect);
             body_items.push(format!(
-                "Index('{}', {}{}{})",
-                index.name,
+                "Index({}, {}{}{})",
+                format_python_string_literal(&index.name),
                 cols.join(", "),
                 unique_str,
                 kwargs_str
@@ -443,7 +516,7 @@ fn generate_table(
     }
 
     // Table comment
-    if !options.nocomments {
+    if options.table_comments() {
         if let Some(ref comment) = table.comment {
             body_items.push(format!("comment={}", format_python_string_literal(comment)));
         }
@@ -451,7 +524,10 @@ fn generate_table(
 
     // Schema (only if not default)
     if table.schema != dialect.default_schema() {
-        body_items.push(format!("schema='{}'", table.schema));
+        body_items.push(format!(
+            "schema={}",
+            format_python_string_literal(&table.schema)
+        ));
     }
 
     // Add body items with commas on all but the last
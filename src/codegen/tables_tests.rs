@@ -83,6 +83,163 @@ fn test_tables_indexes() {
     assert!(output.contains("Index('ix_text', 'text', unique=True)"));
 }
 
+#[test]
+fn test_tables_expression_index() {
+    let schema = schema_pg(vec![table("users")
+        .column(col("tenant_id").build())
+        .column(col("email").udt("varchar").build())
+        .expression_index(
+            "ix_users_tenant_lower_email",
+            &["tenant_id"],
+            &["tenant_id", "lower((email)::text)"],
+        )
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "Index('ix_users_tenant_lower_email', 'tenant_id', text('lower((email)::text)'))"
+    ));
+    assert!(output
+        .contains("from sqlalchemy import Column, Index, Integer, MetaData, String, Table, text"));
+}
+
+#[test]
+fn test_tables_index_sort_order() {
+    let mut schema = schema_pg(vec![table("events")
+        .column(col("tenant_id").build())
+        .column(col("created_at").udt("timestamptz").build())
+        .index("ix_events_recent", &["tenant_id", "created_at"], false)
+        .build()]);
+    schema.tables[0].indexes[0].orderings.insert(
+        "created_at".to_string(),
+        crate::schema::SortOrder {
+            descending: true,
+            nulls_first: false,
+        },
+    );
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "Index('ix_events_recent', 'tenant_id', text('\"created_at\" DESC NULLS LAST'))"
+    ));
+    assert!(output.contains(", Table, text\n"));
+}
+
+#[test]
+fn test_tables_index_include_columns() {
+    let mut schema = schema_pg(vec![table("orders")
+        .column(col("customer_id").build())
+        .column(col("total").udt("numeric").build())
+        .index("ix_orders_customer", &["customer_id"], false)
+        .build()]);
+    schema.tables[0].indexes[0].include = vec!["total".to_string()];
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(
+        output.contains("Index('ix_orders_customer', 'customer_id', postgresql_include=['total'])")
+    );
+
+    let mut schema = schema_mssql(vec![table("orders")
+        .schema("dbo")
+        .column(col("customer_id").udt("int").build())
+        .column(col("total").udt("decimal").build())
+        .index("ix_orders_customer", &["customer_id"], false)
+        .build()]);
+    schema.tables[0].indexes[0].include = vec!["total".to_string()];
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("Index('ix_orders_customer', 'customer_id', mssql_include=['total'])"));
+}
+
+#[test]
+fn test_tables_nulls_not_distinct() {
+    let mut schema = schema_pg(vec![table("accounts")
+        .column(col("email").udt("text").build())
+        .column(col("phone").udt("text").build())
+        .unique("accounts_email_key", &["email"])
+        .index("accounts_phone_idx", &["phone"], true)
+        .build()]);
+    schema.tables[0].constraints[0].nulls_not_distinct = true;
+    schema.tables[0].indexes[0].nulls_not_distinct = true;
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "UniqueConstraint('email', name='accounts_email_key', postgresql_nulls_not_distinct=True)"
+    ));
+    assert!(output.contains(
+        "Index('accounts_phone_idx', 'phone', unique=True, postgresql_nulls_not_distinct=True)"
+    ));
+}
+
+#[test]
+fn test_tables_exclusion_constraint() {
+    let mut schema = schema_pg(vec![table("bookings")
+        .column(col("room_id").build())
+        .column(col("during").udt("tsrange").build())
+        .build()]);
+    schema.tables[0]
+        .constraints
+        .push(crate::schema::ConstraintInfo::exclude(
+            "bookings_no_overlap",
+            crate::schema::ExcludeInfo {
+                using: "gist".to_string(),
+                elements: vec![
+                    crate::schema::ExcludeElement {
+                        key: "room_id".to_string(),
+                        is_expression: false,
+                        operator: "=".to_string(),
+                    },
+                    crate::schema::ExcludeElement {
+                        key: "during".to_string(),
+                        is_expression: false,
+                        operator: "&&".to_string(),
+                    },
+                ],
+                predicate: None,
+            },
+        ));
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains("from sqlalchemy.dialects.postgresql import ExcludeConstraint"));
+    assert!(output.contains(
+        "ExcludeConstraint(('room_id', '='), ('during', '&&'), name='bookings_no_overlap')"
+    ));
+}
+
+#[test]
+fn test_tables_mssql_clustered_and_columnstore_indexes() {
+    let mut schema = schema_mssql(vec![table("orders")
+        .schema("dbo")
+        .column(col("customer_id").udt("int").build())
+        .column(col("total").udt("decimal").build())
+        .index("cix_orders_customer", &["customer_id"], false)
+        .index("ncci_orders", &["customer_id", "total"], false)
+        .build()]);
+    schema.tables[0].indexes[0].clustered = true;
+    schema.tables[0].indexes[1].columnstore = true;
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains("Index('cix_orders_customer', 'customer_id', mssql_clustered=True)"));
+    assert!(!output.contains("Index('ncci_orders'"));
+    assert!(output.contains(
+        "# Columnstore index ncci_orders (nonclustered) is not declared here; SQLAlchemy cannot create it.\nt_orders = Table("
+    ));
+}
+
+#[test]
+fn test_tables_index_method_and_operator_class() {
+    let schema = schema_pg(vec![table("docs")
+        .column(col("title").udt("text").build())
+        .index_with_opclasses(
+            "ix_docs_title",
+            &["title"],
+            "gin",
+            &[("title", "gin_trgm_ops")],
+        )
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "Index('ix_docs_title', 'title', postgresql_using='gin', postgresql_ops={'title': 'gin_trgm_ops'})"
+    ));
+}
+
 /// Adapted from sqlacodegen test_constraints (UniqueConstraint portion).
 /// Note: CheckConstraint is not yet supported in uvg (Tier 2).
 #[test]
@@ -216,25 +373,78 @@ fn test_tables_identity_column() {
     let schema = schema_pg(vec![table("simple_items")
         .column(
             col("id")
-                .identity_info(IdentityInfo {
-                    start: 1,
-                    increment: 2,
-                    min_value: 1,
-                    max_value: 2147483647,
-                    cycle: false,
-                    cache: 1,
-                })
+                .identity_info(IdentityInfo::new(1, 2, 1, 2147483647, false, 1))
                 .build(),
         )
         .pk("simple_items_pkey", &["id"])
         .build()]);
     let output = generate(&schema, &GeneratorOptions::default());
-    assert!(output.contains("Identity("));
-    assert!(output.contains("start=1"));
-    assert!(output.contains("increment=2"));
+    assert!(
+        output.contains("Identity(always=True, increment=2)"),
+        "{output}"
+    );
     assert!(output.contains("primary_key=True"));
 }
 
+#[test]
+fn test_tables_identity_emits_non_default_sequence_options() {
+    use crate::schema::IdentityInfo;
+    let cycling = IdentityInfo::new(10, 5, 10, 100, true, 20);
+    let mut partial = IdentityInfo::new(1, 1, 1, 100, false, 1);
+    partial.max_value = None;
+    partial.cycle = None;
+    let schema = schema_pg(vec![table("counters")
+        .column(col("ring").identity_info(cycling).build())
+        .column(col("open").identity_info(partial).build())
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(
+        output.contains(
+            "Identity(always=True, increment=5, minvalue=10, maxvalue=100, cycle=True, cache=20)"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains("Column('open', Integer, Identity(always=True),"),
+        "{output}"
+    );
+}
+
+#[test]
+fn test_tables_identity_descending_defaults() {
+    use crate::schema::IdentityInfo;
+    let descending = IdentityInfo::new(-1, -1, i32::MIN as i64, -1, false, 1);
+    let shifted = IdentityInfo::new(-10, -1, i32::MIN as i64, -1, false, 1);
+    let schema = schema_pg(vec![table("countdown")
+        .column(col("down").identity_info(descending).build())
+        .column(col("shifted").identity_info(shifted).build())
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(
+        output.contains("Column('down', Integer, Identity(always=True, increment=-1),"),
+        "{output}"
+    );
+    assert!(
+        output.contains("Identity(always=True, start=-10, increment=-1)"),
+        "{output}"
+    );
+}
+
+#[test]
+fn test_tables_identity_generation_mode() {
+    use crate::schema::IdentityInfo;
+    let info = IdentityInfo::new(1, 1, 1, 2147483647, false, 1);
+    let mut by_default = col("by_default").identity_info(info.clone()).build();
+    by_default.identity_generation = Some("BY DEFAULT".to_string());
+    let schema = schema_pg(vec![table("simple_items")
+        .column(col("always").identity_info(info).build())
+        .column(by_default)
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("Column('always', Integer, Identity(always=True),"));
+    assert!(output.contains("Column('by_default', Integer, Identity(),"));
+}
+
 // --- Tier 2: Tests adapted from sqlacodegen test_generator_tables.py ---
 
 /// Adapted from sqlacodegen test_multiline_column_comment.
@@ -325,6 +535,132 @@ fn test_tables_foreign_key_options() {
     assert!(output.contains("onupdate='CASCADE'"));
 }
 
+#[test]
+fn test_tables_foreign_key_schema() {
+    let schema = schema_pg(vec![
+        table("other_items")
+            .schema("otherschema")
+            .column(col("id").build())
+            .pk("other_items_pkey", &["id"])
+            .build(),
+        table("simple_items")
+            .column(col("other_item_id").nullable().build())
+            .fk_full(
+                "si_other_fkey",
+                &["other_item_id"],
+                "otherschema",
+                "other_items",
+                &["id"],
+                "NO ACTION",
+                "NO ACTION",
+            )
+            .fk_full(
+                "si_self_fkey",
+                &["other_item_id"],
+                "public",
+                "simple_items",
+                &["other_item_id"],
+                "NO ACTION",
+                "NO ACTION",
+            )
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "ForeignKeyConstraint(['other_item_id'], ['otherschema.other_items.id'], name='si_other_fkey')"
+    ));
+    // References into the default schema stay unqualified.
+    assert!(output.contains(
+        "ForeignKeyConstraint(['other_item_id'], ['simple_items.other_item_id'], name='si_self_fkey')"
+    ));
+}
+
+#[test]
+fn test_tables_same_table_name_in_two_schemas() {
+    let schema = schema_pg(vec![
+        table("orders")
+            .schema("sales")
+            .column(col("id").build())
+            .build(),
+        table("orders")
+            .schema("archive")
+            .column(col("id").build())
+            .build(),
+        table("customers")
+            .schema("sales")
+            .column(col("id").build())
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("t_sales_orders = Table(\n    'orders', metadata,"));
+    assert!(output.contains("t_archive_orders = Table(\n    'orders', metadata,"));
+    assert!(output.contains("t_customers = Table("));
+
+    let options = GeneratorOptions {
+        schema_prefix: true,
+        ..Default::default()
+    };
+    let output = generate(&schema, &options);
+    assert!(output.contains("t_sales_customers = Table("));
+}
+
+#[test]
+fn test_tables_deferrable_foreign_key() {
+    let schema = schema_pg(vec![
+        table("parents")
+            .column(col("id").udt("int4").build())
+            .pk("parents_pkey", &["id"])
+            .build(),
+        table("children")
+            .column(col("id").udt("int4").build())
+            .column(col("parent_id").udt("int4").build())
+            .column(col("other_id").udt("int4").build())
+            .pk("children_pkey", &["id"])
+            .fk_deferrable(
+                "children_parent_id_fkey",
+                &["parent_id"],
+                "parents",
+                &["id"],
+                true,
+            )
+            .fk_deferrable(
+                "children_other_id_fkey",
+                &["other_id"],
+                "parents",
+                &["id"],
+                false,
+            )
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "ForeignKeyConstraint(['parent_id'], ['parents.id'], name='children_parent_id_fkey', deferrable=True, initially='DEFERRED')"
+    ));
+    assert!(output.contains(
+        "ForeignKeyConstraint(['other_id'], ['parents.id'], name='children_other_id_fkey', deferrable=True)"
+    ));
+}
+
+#[test]
+fn test_tables_fk_cycle_uses_use_alter() {
+    let schema = schema_pg(vec![
+        table("a")
+            .column(col("id").build())
+            .column(col("b_id").build())
+            .fk("a_b_id_fkey", &["b_id"], "b", &["id"])
+            .build(),
+        table("b")
+            .column(col("id").build())
+            .column(col("a_id").build())
+            .fk("b_a_id_fkey", &["a_id"], "a", &["id"])
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output
+        .contains("ForeignKeyConstraint(['a_id'], ['a.id'], name='b_a_id_fkey', use_alter=True)"));
+    assert!(output.contains("ForeignKeyConstraint(['b_id'], ['b.id'], name='a_b_id_fkey')"));
+}
+
 /// Adapted from sqlacodegen test_identity_column_decimal_values.
 /// MSSQL reflects Identity parameters as Decimal; uvg stores them as i64.
 /// The output should be identical to test_identity_column.
@@ -333,25 +669,36 @@ fn test_tables_identity_column_decimal_values() {
     use crate::schema::IdentityInfo;
     let schema = schema_mssql(vec![table("simple_items")
         .schema("dbo")
-        .column(
-            col("id")
-                .identity_info(IdentityInfo {
-                    start: 1,
-                    increment: 2,
-                    min_value: 1,
-                    max_value: 2147483647,
-                    cycle: false,
-                    cache: 1,
-                })
-                .build(),
-        )
+        .column(col("id").identity_info(IdentityInfo::seed(1, 2)).build())
         .pk("simple_items_pkey", &["id"])
         .build()]);
     let output = generate(&schema, &GeneratorOptions::default());
-    assert!(output.contains("Identity(start=1, increment=2)"));
+    assert!(output.contains("Identity(increment=2)"));
     assert!(output.contains("primary_key=True"));
 }
 
+#[test]
+fn test_tables_mssql_sequence_default_is_fetched_value() {
+    let mut invoice_no = col("invoice_no").udt("int").build();
+    invoice_no.column_default = Some("(NEXT VALUE FOR [dbo].[invoice_seq])".to_string());
+    let schema = schema_mssql(vec![table("invoices")
+        .schema("dbo")
+        .column(invoice_no)
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(
+        output.contains(
+            "Column('invoice_no', Integer, nullable=False, server_default=FetchedValue())"
+        ),
+        "{output}"
+    );
+    assert!(
+        output.contains("from sqlalchemy import Column, FetchedValue,"),
+        "{output}"
+    );
+    assert!(!output.contains("NEXT VALUE"), "{output}");
+}
+
 // --- Tier 4: Enum tests ---
 
 /// Adapted from sqlacodegen test_enum_shared_values (tables).
@@ -497,11 +844,30 @@ fn test_tables_computed_column() {
         .pk("computed_pkey", &["id"])
         .build()]);
     let output = generate(&schema, &GeneratorOptions::default());
-    // For now, computed columns render as server_default (full Computed() support is future work)
+    // A plain default is not a generation expression: it stays a server_default.
     assert!(output.contains("Column('id', Integer, primary_key=True)"));
     assert!(output.contains("server_default=text('1 + 2')"));
 }
 
+#[test]
+fn test_tables_computed_column_renders_computed() {
+    let schema = schema_pg(vec![table("computed")
+        .column(col("id").build())
+        .column(
+            col("computed")
+                .nullable()
+                .computed("(id * 2)", Some(true))
+                .build(),
+        )
+        .column(col("virtual").nullable().computed("id + 1", None).build())
+        .pk("computed_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("from sqlalchemy import Column, Computed, Integer"));
+    assert!(output.contains("Column('computed', Integer, Computed('(id * 2)', persisted=True))"));
+    assert!(output.contains("Column('virtual', Integer, Computed('id + 1'))"));
+}
+
 // --- PR 8: Misc feature tests ---
 
 /// Adapted from sqlacodegen test_column_adaptation.
@@ -689,6 +1055,8 @@ fn test_tables_domain_text() {
             .column(col("postal_code").udt("us_postal_code").build())
             .build()],
         enums: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
         domains: vec![DomainInfo {
             name: "us_postal_code".to_string(),
             schema: None,
@@ -716,6 +1084,8 @@ fn test_tables_domain_int() {
             .column(col("n").udt("positive_int").build())
             .build()],
         enums: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
         domains: vec![DomainInfo {
             name: "positive_int".to_string(),
             schema: None,
@@ -732,6 +1102,30 @@ fn test_tables_domain_int() {
     assert!(output.contains("constraint_name='positive'"));
 }
 
+#[test]
+fn test_tables_introspected_domain_column() {
+    use crate::schema::{DomainInfo, IntrospectedSchema};
+    let mut email = col("email").udt("varchar").max_length(255).build();
+    email.domain = Some("email_address".to_string());
+    let schema = IntrospectedSchema {
+        dialect: crate::dialect::Dialect::Postgres,
+        tables: vec![table("people").column(email).build()],
+        enums: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
+        domains: vec![DomainInfo {
+            name: "email_address".to_string(),
+            schema: Some("public".to_string()),
+            base_type: "varchar".to_string(),
+            constraint_name: None,
+            not_null: false,
+            check_expression: None,
+        }],
+    };
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("DOMAIN('email_address', String(255), not_null=False)"));
+}
+
 // --- PR 13: Sequence with schema ---
 
 /// Adapted from sqlacodegen test_postgresql_sequence_with_schema.
@@ -819,3 +1213,322 @@ fn test_tables_keep_dialect_types_mssql() {
     assert!(output.contains("UNIQUEIDENTIFIER"));
     assert!(output.contains("from sqlalchemy.dialects.mssql import"));
 }
+
+#[test]
+fn test_tables_generator_sample_advisory_comment() {
+    let mut email = col("email")
+        .udt("varchar")
+        .max_length(4000)
+        .nullable()
+        .build();
+    email.sample = Some(crate::schema::ColumnSample {
+        rows: 20,
+        nulls: 0,
+        max_length: Some(12),
+        ..Default::default()
+    });
+    let schema = schema_pg(vec![table("users")
+        .column(col("id").build())
+        .column(email)
+        .pk("users_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "    # sample: nullable, but no NULLs in 20 sampled rows\n    \
+         # sample: declared length 4000, longest sampled value is 12\n    \
+         Column('email', String(4000)),"
+    ));
+}
+
+#[test]
+fn test_tables_generator_info_properties() {
+    let mut email = col("email").udt("nvarchar").max_length(255).build();
+    email
+        .properties
+        .insert("Sensitivity".to_string(), "Confidential".to_string());
+    email
+        .properties
+        .insert("Owner".to_string(), "crm".to_string());
+    email.pii = Some(crate::schema::PiiCategory::Email);
+    let schema = schema_mssql(vec![table("customers").schema("dbo").column(email).build()]);
+    let opts = GeneratorOptions {
+        info_properties: vec!["Sensitivity".to_string(), "Missing".to_string()],
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &opts);
+    assert!(output
+        .contains("info={'pii': True, 'pii_category': 'email', 'Sensitivity': 'Confidential'})"));
+    assert!(!output.contains("Owner"));
+}
+
+#[test]
+fn test_tables_generator_mssql_clr_type_fallback_comment() {
+    let schema = schema_mssql(vec![table("nodes")
+        .schema("dbo")
+        .column(col("id").udt("int").build())
+        .column(col("path").udt("hierarchyid").nullable().build())
+        .pk("PK_nodes", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "    # WARNING: hierarchyid has no SQLAlchemy type; mapped to LargeBinary\n    \
+         Column('path', LargeBinary),"
+    ));
+}
+
+#[test]
+fn test_tables_generator_unknown_type_fallback_comment() {
+    let schema = schema_pg(vec![table("places")
+        .column(col("id").build())
+        .column(col("shape").udt("geometry").nullable().build())
+        .pk("places_pkey", &["id"])
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(
+        output.contains(
+            "    # WARNING: geometry has no SQLAlchemy type; mapped to GEOMETRY, which sqlalchemy \
+         does not export (add a --typemap override)\n    Column('shape', GEOMETRY),"
+        ),
+        "{output}"
+    );
+}
+
+#[test]
+fn test_tables_skipped_objects_are_flagged_above_the_table() {
+    let mut accounts = table("accounts")
+        .column(col("id").udt("int4").build())
+        .pk("accounts_pkey", &["id"])
+        .build();
+    accounts.skipped = vec!["index ix_lower_email has only expression keys and was skipped".into()];
+    let output = generate(&schema_pg(vec![accounts]), &GeneratorOptions::default());
+    assert!(output.contains(
+        "# WARNING: index ix_lower_email has only expression keys and was skipped\n\
+         t_accounts = Table("
+    ));
+}
+
+#[test]
+fn test_tables_escapes_quotes_and_backslashes_in_names() {
+    let schema = schema_pg(vec![table("it's")
+        .schema("we\\rd")
+        .column(col("id").build())
+        .column(col("we'ird").udt("text").build())
+        .pk("pk_it's", &["id"])
+        .index("ix'q", &["we'ird"], false)
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains(
+        "    \"it's\", metadata,\n    \
+         Column('id', Integer, primary_key=True),\n    \
+         Column(\"we'ird\", Text, nullable=False),\n    \
+         PrimaryKeyConstraint('id', name=\"pk_it's\"),\n    \
+         Index(\"ix'q\", \"we'ird\"),\n    \
+         schema='we\\\\rd'\n"
+    ));
+}
+
+#[test]
+fn test_tables_generator_enum_array_column() {
+    let mut moods = col("moods").udt("_mood").nullable().build();
+    moods.array_dimensions = Some(2);
+    let schema = schema_pg_with_enums(
+        vec![table("people")
+            .column(col("id").build())
+            .column(moods)
+            .pk("people_pkey", &["id"])
+            .build()],
+        vec![EnumInfo {
+            name: "mood".to_string(),
+            schema: None,
+            values: vec!["happy".to_string(), "sad".to_string()],
+        }],
+    );
+    let output = generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("class Mood(str, enum.Enum):"));
+    assert!(output.contains("from sqlalchemy import ARRAY, Column, Enum"));
+    assert!(output.contains(
+        "Column('moods', ARRAY(Enum(Mood, values_callable=lambda cls: [member.value for member in cls], name='mood'), dimensions=2))"
+    ));
+    assert!(!output.contains("MOOD"));
+}
+
+#[test]
+fn test_tables_naming_convention_elides_matching_names() {
+    let schema = schema_pg(vec![table("users")
+        .column(col("id").build())
+        .column(col("email").udt("text").build())
+        .column(col("handle").udt("text").build())
+        .pk("users_pkey", &["id"])
+        .unique("users_email_key", &["email"])
+        .unique("uq_handle", &["handle"])
+        .build()]);
+    let options = GeneratorOptions {
+        naming_convention: true,
+        ..GeneratorOptions::default()
+    };
+    let output = generate(&schema, &options);
+    assert!(output
+        .contains("metadata = MetaData(naming_convention={\n    'ix': 'ix_%(column_0_label)s',\n"));
+    assert!(output.contains("    'pk': '%(table_name)s_pkey',\n})\n"));
+    assert!(output.contains("PrimaryKeyConstraint('id'),"));
+    assert!(output.contains("UniqueConstraint('email'),"));
+    assert!(output.contains("UniqueConstraint('handle', name='uq_handle')"));
+}
+
+#[test]
+fn test_tables_views_follow_tables_with_definition() {
+    let schema = schema_mssql(vec![
+        table("ActiveUsers")
+            .schema("dbo")
+            .view("CREATE VIEW dbo.ActiveUsers AS\r\nSELECT id FROM dbo.Users\r\n\r\nWHERE active = 1")
+            .column(col("id").udt("int").nullable().build())
+            .build(),
+        table("Users")
+            .schema("dbo")
+            .column(col("id").udt("int").build())
+            .pk("PK_Users", &["id"])
+            .build(),
+    ]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    let table_pos = output.find("t_Users = Table(").unwrap();
+    let view_pos = output
+        .find(
+            "# View dbo.ActiveUsers:\n\
+             #   CREATE VIEW dbo.ActiveUsers AS\n\
+             #   SELECT id FROM dbo.Users\n\
+             #\n\
+             #   WHERE active = 1\n\
+             t_ActiveUsers = Table(\n",
+        )
+        .expect("view block with its definition");
+    assert!(table_pos < view_pos);
+}
+
+#[test]
+fn test_tables_temporal_period_columns() {
+    let mut schema = schema_mssql(vec![table("Orders")
+        .schema("dbo")
+        .column(col("id").udt("int").build())
+        .column(
+            col("ValidFrom")
+                .udt("datetime2")
+                .period(crate::schema::PeriodColumn::RowStart)
+                .build(),
+        )
+        .pk("PK_Orders", &["id"])
+        .build()]);
+    schema.tables[0].history_table = Some("dbo.OrdersHistory".to_string());
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains("from sqlalchemy import Column, DateTime, FetchedValue,"));
+    assert!(output.contains(
+        "# System-versioned temporal table; row history is kept in dbo.OrdersHistory.\n\
+         t_Orders = Table("
+    ));
+    assert!(output.contains(
+        "Column('ValidFrom', DateTime, nullable=False, \
+         server_default=FetchedValue(), server_onupdate=FetchedValue())"
+    ));
+}
+
+#[test]
+fn test_tables_inherits_comment() {
+    let schema = schema_pg(vec![table("capitals")
+        .column(col("name").udt("text").inherited().build())
+        .column(col("state").udt("text").build())
+        .inherits("cities")
+        .build()]);
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "# Inherits cities (PostgreSQL INHERITS); column name is inherited.\n\
+         t_capitals = Table("
+    ));
+}
+
+#[test]
+fn test_tables_foreign_table_names_its_server() {
+    let mut schema = schema_pg(vec![table("remote_orders")
+        .column(col("id").build())
+        .build()]);
+    schema.tables[0].foreign_server = Some("warehouse".to_string());
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "# Foreign table on server warehouse; treat as read-only.\n\
+         t_remote_orders = Table("
+    ));
+}
+
+#[test]
+fn test_tables_index_and_constraint_comments() {
+    let mut schema = schema_pg(vec![table("orders")
+        .column(col("total").udt("numeric").build())
+        .index("orders_total", &["total"], false)
+        .check("orders_total_check", "total >= 0")
+        .build()]);
+    schema.tables[0].indexes[0].comment = Some("Reporting lookups".to_string());
+    schema.tables[0].constraints[0].comment = Some("No refunds".to_string());
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains(
+        "# Constraint orders_total_check: No refunds\n\
+         # Index orders_total: Reporting lookups\n\
+         t_orders = Table("
+    ));
+
+    let options = GeneratorOptions {
+        nocomments: true,
+        ..Default::default()
+    };
+    assert!(!generate(&schema, &options).contains("# Index"));
+}
+
+#[test]
+fn test_tables_mssql_default_constraint_name() {
+    let mut schema = schema_mssql(vec![table("orders")
+        .column(
+            col("status")
+                .udt("varchar")
+                .max_length(10)
+                .default_val("('new')")
+                .build(),
+        )
+        .build()]);
+    schema.tables[0].columns[0].default_constraint = Some("DF_orders_status".to_string());
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output
+        .contains("    # Default constraint: DF_orders_status\n    Column('status', String(10), "));
+}
+
+#[test]
+fn test_tables_standalone_sequence_object() {
+    let mut schema = schema_pg(vec![table("invoices")
+        .column(
+            col("number")
+                .udt("int8")
+                .default_val("nextval('invoice_seq'::regclass)")
+                .build(),
+        )
+        .build()]);
+    schema.sequences = vec![crate::schema::SequenceInfo {
+        schema: "public".to_string(),
+        name: "invoice_seq".to_string(),
+        start: 1000,
+        increment: 1,
+        min_value: 1,
+        max_value: i64::MAX,
+        cycle: false,
+        cache: 1,
+    }];
+    let output = generate(&schema, &GeneratorOptions::default());
+
+    assert!(output.contains("import BigInteger, Column, MetaData, Sequence, Table\n"));
+    assert!(output.contains(
+        "metadata = MetaData()\n\n\n\
+         invoice_seq = Sequence('invoice_seq', start=1000, metadata=metadata)\n"
+    ));
+    assert!(output.contains("Column('number', BigInteger, invoice_seq"));
+}
@@ -0,0 +1,112 @@
+//! Template generator (`--generator template --template FILE`).
+//!
+//! Renders the schema through a user-supplied [Tera] template, for output
+//! formats uvg does not ship. The template sees:
+//!
+//! - `dialect`: `postgres`, `mysql`, `mssql`, or `sqlite`.
+//! - `tables`: each table as in the `json` generator (`schema`, `name`,
+//!   `table_type`, `comment`, `columns`, `constraints`, `indexes`, ...),
+//!   plus `class_name` and `variable_name` (the identifiers the Python
+//!   generators would use), `primary_key` (column names), and
+//!   `foreign_keys`, `unique_constraints`, and `check_constraints` (the
+//!   matching entries of `constraints`).
+//! - Each column additionally carries `sql_type` (its DDL type in the source
+//!   dialect), `sa_type` and `python_type` (its SQLAlchemy mapping under
+//!   `--options`), and `primary_key`.
+//! - `enums`, `domains`, `sequences`, and `extensions`, as in the `json`
+//!   generator.
+//!
+//! [Tera]: https://keats.github.io/tera/docs/
+
+use serde_json::{json, Value};
+use tera::{Context, Tera};
+
+use crate::cli::GeneratorOptions;
+use crate::codegen::python::map_column_type_with;
+use crate::ddl_typemap::map_ddl_type;
+use crate::error::UvgError;
+use crate::naming::{table_class_name, table_variable_name};
+use crate::schema::{ConstraintType, IntrospectedSchema, TableInfo};
+
+/// Render `template` (the contents of the file at `path`, which is only
+/// used in error messages) against `schema`.
+pub fn generate(
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
+    path: &str,
+    template: &str,
+) -> Result<String, UvgError> {
+    let context =
+        Context::from_value(context(schema, options)).map_err(|source| UvgError::Template {
+            path: path.to_string(),
+            source,
+        })?;
+    Tera::one_off(template, &context, false).map_err(|source| UvgError::Template {
+        path: path.to_string(),
+        source,
+    })
+}
+
+/// The template context described in the module docs.
+pub fn context(schema: &IntrospectedSchema, options: &GeneratorOptions) -> Value {
+    json!({
+        "dialect": schema.dialect.to_string(),
+        "tables": schema
+            .tables
+            .iter()
+            .map(|table| table_context(schema, table, options))
+            .collect::<Vec<_>>(),
+        "enums": schema.enums,
+        "domains": schema.domains,
+        "sequences": schema.sequences,
+        "extensions": schema.extensions,
+    })
+}
+
+fn table_context(
+    schema: &IntrospectedSchema,
+    table: &TableInfo,
+    options: &GeneratorOptions,
+) -> Value {
+    let primary_key: Vec<&str> = table
+        .constraints
+        .iter()
+        .filter(|c| c.constraint_type == ConstraintType::PrimaryKey)
+        .flat_map(|c| c.columns.iter().map(String::as_str))
+        .collect();
+    let of_type = |kind: ConstraintType| -> Vec<Value> {
+        table
+            .constraints
+            .iter()
+            .filter(|c| c.constraint_type == kind)
+            .map(|c| json!(c))
+            .collect()
+    };
+    let columns: Vec<Value> = table
+        .columns
+        .iter()
+        .map(|col| {
+            let mapped = map_column_type_with(col, schema.dialect, options);
+            let mut value = json!(col);
+            value["sql_type"] = json!(map_ddl_type(col, schema.dialect, schema.dialect).sql_type);
+            value["sa_type"] = json!(mapped.sa_type);
+            value["python_type"] = json!(mapped.python_type);
+            value["primary_key"] = json!(primary_key.contains(&col.name.as_str()));
+            value
+        })
+        .collect();
+
+    let mut value = json!(table);
+    value["columns"] = json!(columns);
+    value["class_name"] = json!(table_class_name(schema, table, options));
+    value["variable_name"] = json!(table_variable_name(schema, table, options));
+    value["primary_key"] = json!(primary_key);
+    value["foreign_keys"] = json!(of_type(ConstraintType::ForeignKey));
+    value["unique_constraints"] = json!(of_type(ConstraintType::Unique));
+    value["check_constraints"] = json!(of_type(ConstraintType::Check));
+    value
+}
+
+#[cfg(test)]
+#[path = "template_tests.rs"]
+mod tests;
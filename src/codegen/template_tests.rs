@@ -0,0 +1,55 @@
+use super::*;
+use crate::testutil::*;
+
+fn shop() -> IntrospectedSchema {
+    schema_pg(vec![
+        table("users")
+            .column(col("id").build())
+            .column(col("email").udt("varchar").max_length(255).build())
+            .pk("users_pkey", &["id"])
+            .build(),
+        table("orders")
+            .column(col("id").build())
+            .column(col("user_id").build())
+            .pk("orders_pkey", &["id"])
+            .fk("orders_user_id_fkey", &["user_id"], "users", &["id"])
+            .build(),
+    ])
+}
+
+fn render(template: &str) -> Result<String, UvgError> {
+    generate(&shop(), &GeneratorOptions::default(), "t.tera", template)
+}
+
+#[test]
+fn renders_tables_and_mapped_column_types() {
+    let output = render(
+        "{% for t in tables %}{{ t.class_name }}:\
+         {% for c in t.columns %} {{ c.name }}={{ c.sql_type }}/{{ c.sa_type }}/{{ c.python_type }}\
+         {% if c.primary_key %}*{% endif %}{% endfor %}\n{% endfor %}",
+    )
+    .unwrap();
+
+    assert!(output.contains("Users: id=INTEGER/Integer/int* email=VARCHAR(255)/String(255)/str"));
+    assert!(output.contains("Orders: id=INTEGER/Integer/int* user_id=INTEGER/Integer/int\n"));
+}
+
+#[test]
+fn constraint_lists_are_split_by_kind() {
+    let output = render(
+        "{{ dialect }}{% for t in tables %}|{{ t.name }} pk={{ t.primary_key | join(sep=\",\") }}\
+         {% for fk in t.foreign_keys %} {{ fk.columns.0 }}->{{ fk.foreign_key.ref_table }}{% endfor %}\
+         {% endfor %}",
+    )
+    .unwrap();
+
+    assert_eq!(output, "postgres|users pk=id|orders pk=id user_id->users");
+}
+
+#[test]
+fn template_errors_name_the_file() {
+    let err = render("{{ tables | no_such_filter }}").unwrap_err();
+
+    assert!(matches!(err, UvgError::Template { ref path, .. } if path == "t.tera"));
+    assert!(err.to_string().contains("t.tera"));
+}
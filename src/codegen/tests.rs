@@ -3,6 +3,7 @@ use super::*;
 use crate::cli::GeneratorOptions;
 use crate::dialect::Dialect;
 use crate::testutil::{col, schema_pg, table};
+use indoc::indoc;
 
 #[test]
 fn test_format_server_default_pg() {
@@ -55,6 +56,78 @@ fn test_is_serial_default() {
     assert!(!is_serial_default("((1))", Dialect::Mssql));
 }
 
+#[test]
+fn test_parse_check_enum_dialect_forms() {
+    let expected = Some((
+        "status".to_string(),
+        vec!["new".to_string(), "paid".to_string(), "void".to_string()],
+    ));
+    for expression in [
+        "status IN ('new', 'paid', 'void')",
+        "orders.status in ('new','paid','void')",
+        "(`status` in (_utf8mb4'new',_utf8mb4'paid',_utf8mb4'void'))",
+        "((status)::text = ANY ((ARRAY['new'::character varying, 'paid'::character varying, 'void'::character varying])::text[]))",
+        "(status = ANY (ARRAY['new'::text, 'paid'::text, 'void'::text]))",
+        "([status]='new' OR [status]='paid' OR [status]=N'void')",
+    ] {
+        assert_eq!(parse_check_enum(expression, true), expected, "{expression}");
+    }
+    assert_eq!(
+        parse_check_enum("kind IN ('it''s', 'other')", true)
+            .unwrap()
+            .1,
+        ["it's", "other"]
+    );
+}
+
+#[test]
+fn test_parse_check_enum_rejects_other_predicates() {
+    for expression in [
+        "flag IN (0, 1)",
+        "total >= 0",
+        "lower(status) IN ('a', 'b')",
+        "status IN ('a', 'b') AND total > 0",
+        "([status]='a' OR [kind]='b')",
+        "((status)::text = 'a'::text)",
+        "status = ANY (ARRAY[1, 2])",
+    ] {
+        assert_eq!(parse_check_enum(expression, true), None, "{expression}");
+    }
+}
+
+#[test]
+fn test_parse_check_enum_default_reads_only_in_lists() {
+    assert_eq!(
+        parse_check_enum("orders.status IN ('new', 'it''s')", false),
+        Some((
+            "status".to_string(),
+            vec!["new".to_string(), "it's".to_string()]
+        ))
+    );
+    for expression in [
+        "(`status` in (_utf8mb4'new',_utf8mb4'paid'))",
+        "(status = ANY (ARRAY['new'::text, 'paid'::text]))",
+        "([status]='new' OR [status]='paid')",
+        "flag IN (0, 1)",
+    ] {
+        assert_eq!(parse_check_enum(expression, false), None, "{expression}");
+    }
+}
+
+#[test]
+fn test_is_next_value_default() {
+    assert!(is_next_value_default(
+        "(NEXT VALUE FOR [dbo].[invoice_seq])",
+        Dialect::Mssql
+    ));
+    assert!(is_next_value_default("next value for seq", Dialect::Mssql));
+    assert!(!is_next_value_default("((0))", Dialect::Mssql));
+    assert!(!is_next_value_default(
+        "nextval('seq'::regclass)",
+        Dialect::Postgres
+    ));
+}
+
 #[test]
 fn test_split_python_declarative() {
     let schema = schema_pg(vec![
@@ -95,6 +168,141 @@ fn test_split_python_declarative() {
     );
 }
 
+#[test]
+fn test_split_python_imports_related_classes() {
+    let schema = schema_pg(vec![
+        table("users")
+            .column(col("id").build())
+            .pk("users_pk", &["id"])
+            .build(),
+        table("admins")
+            .column(col("id").build())
+            .pk("admins_pk", &["id"])
+            .fk("admins_id_fk", &["id"], "users", &["id"])
+            .build(),
+        table("posts")
+            .column(col("id").build())
+            .column(col("user_id").nullable().build())
+            .pk("posts_pk", &["id"])
+            .fk("posts_user_fk", &["user_id"], "users", &["id"])
+            .build(),
+    ]);
+    let files = declarative::generate_split(&schema, &GeneratorOptions::default());
+    let file = |name: &str| &files.iter().find(|(n, _)| n == name).unwrap().1;
+
+    // Joined-table inheritance needs the parent class when the module runs.
+    assert_eq!(
+        file("admins.py"),
+        indoc! {"
+            from sqlalchemy import ForeignKey
+            from sqlalchemy.orm import Mapped, mapped_column
+
+            from .users import Users
+
+            class Admins(Users):
+                __tablename__ = 'admins'
+
+                id: Mapped[int] = mapped_column(ForeignKey('users.id'), primary_key=True)
+        "}
+    );
+    // Relationship targets are only annotations: TYPE_CHECKING imports
+    // keep users.py and posts.py from importing each other.
+    assert_eq!(
+        file("posts.py"),
+        indoc! {"
+            from typing import Optional, TYPE_CHECKING
+
+            from sqlalchemy import ForeignKey, Integer
+            from sqlalchemy.orm import Mapped, mapped_column, relationship
+
+            from .base import Base
+
+            if TYPE_CHECKING:
+                from .users import Users
+
+            class Posts(Base):
+                __tablename__ = 'posts'
+
+                id: Mapped[int] = mapped_column(Integer, primary_key=True)
+                user_id: Mapped[Optional[int]] = mapped_column(ForeignKey('users.id'))
+
+                user: Mapped[Optional['Users']] = relationship('Users', back_populates='posts')
+        "}
+    );
+    assert!(file("users.py").contains("    from .posts import Posts"));
+}
+
+#[test]
+fn test_split_python_by_schema() {
+    let schema = schema_pg(vec![
+        table("users")
+            .column(col("id").build())
+            .pk("users_pk", &["id"])
+            .build(),
+        table("orders")
+            .schema("sales")
+            .column(col("id").build())
+            .column(col("user_id").nullable().build())
+            .pk("orders_pk", &["id"])
+            .fk("orders_user_fk", &["user_id"], "users", &["id"])
+            .build(),
+        table("invoices")
+            .schema("sales")
+            .column(col("id").build())
+            .pk("invoices_pk", &["id"])
+            .build(),
+    ]);
+    let options = GeneratorOptions {
+        split_by_schema: true,
+        ..Default::default()
+    };
+    let files = declarative::generate_split(&schema, &options);
+    let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
+    assert_eq!(
+        names,
+        ["base.py", "sales.py", "public.py", "__init__.py"],
+        "{names:?}"
+    );
+    let file = |name: &str| &files.iter().find(|(n, _)| n == name).unwrap().1;
+
+    let sales = file("sales.py");
+    assert!(sales.contains("class Orders(Base):"), "{sales}");
+    assert!(sales.contains("class Invoices(Base):"), "{sales}");
+    // The relationship target lives in another schema's module.
+    assert!(
+        sales.contains("if TYPE_CHECKING:\n    from .public import Users\n"),
+        "{sales}"
+    );
+    assert!(
+        file("public.py").contains("    from .sales import Orders"),
+        "{}",
+        file("public.py")
+    );
+    assert_eq!(
+        file("__init__.py"),
+        "from .base import Base\nfrom .sales import Invoices, Orders\nfrom .public import Users\n"
+    );
+
+    let tables_files = tables::generate_split(&schema, &options);
+    let names: Vec<&str> = tables_files.iter().map(|(n, _)| n.as_str()).collect();
+    assert!(names.contains(&"sales.py"), "{names:?}");
+}
+
+#[test]
+fn test_split_python_by_schema_avoids_package_names() {
+    let schema = schema_pg(vec![table("users")
+        .schema("base")
+        .column(col("id").build())
+        .build()]);
+    let options = GeneratorOptions {
+        split_by_schema: true,
+        ..Default::default()
+    };
+    let files = tables::generate_split(&schema, &options);
+    let names: Vec<&str> = files.iter().map(|(n, _)| n.as_str()).collect();
+    assert_eq!(names, ["base.py", "base_schema.py", "__init__.py"]);
+}
+
 #[test]
 fn test_split_python_enum_stays_in_base() {
     // A synthetic enum from a CHECK constraint renders as an enum class in
@@ -134,6 +342,81 @@ fn test_split_python_tables_generator() {
     );
     // __init__ re-exports base + both modules.
     let init = &files.iter().find(|(n, _)| n == "__init__.py").unwrap().1;
-    assert!(init.contains("from .base import *"));
-    assert!(init.contains("from .t_users import *"));
+    assert_eq!(
+        init,
+        "from .base import metadata\nfrom .t_posts import t_posts\nfrom .t_users import t_users\n"
+    );
+    let users = &files.iter().find(|(n, _)| n == "t_users.py").unwrap().1;
+    assert_eq!(
+        users,
+        indoc! {"
+            from sqlalchemy import Column, Integer, Table
+
+            from .base import metadata
+
+            t_users = Table(
+                'users', metadata,
+                Column('id', Integer, nullable=False)
+            )
+        "}
+    );
+}
+
+#[test]
+fn test_func_server_default() {
+    use super::python::func_server_default;
+    assert_eq!(
+        func_server_default("now()", Dialect::Postgres),
+        Some("func.now()")
+    );
+    assert_eq!(
+        func_server_default("(getdate())", Dialect::Mssql),
+        Some("func.now()")
+    );
+    assert_eq!(
+        func_server_default("CURRENT_TIMESTAMP", Dialect::Mysql),
+        Some("func.current_timestamp()")
+    );
+    assert_eq!(
+        func_server_default("gen_random_uuid()", Dialect::Postgres),
+        Some("func.gen_random_uuid()")
+    );
+    assert_eq!(
+        func_server_default("(newid())", Dialect::Mssql),
+        Some("func.newid()")
+    );
+    assert_eq!(func_server_default("0", Dialect::Postgres), None);
+    assert_eq!(
+        func_server_default("lower('x'::text)", Dialect::Postgres),
+        None
+    );
+}
+
+#[test]
+fn test_format_python_string_literal_escapes_like_repr() {
+    assert_eq!(format_python_string_literal("plain"), "'plain'");
+    assert_eq!(format_python_string_literal("it's"), "\"it's\"");
+    assert_eq!(
+        format_python_string_literal("it's \"quoted\""),
+        "'it\\'s \"quoted\"'"
+    );
+    assert_eq!(
+        format_python_string_literal("C:\\temp\nline\ttab\r"),
+        "'C:\\\\temp\\nline\\ttab\\r'"
+    );
+    assert_eq!(format_python_string_literal("bell\u{7}"), "'bell\\x07'");
+    assert_eq!(format_python_string_literal("café"), "'café'");
+    assert_eq!(escape_python_string("a\\b'c\n"), "a\\\\b\\'c\\n");
+}
+
+#[test]
+fn test_format_server_default_doubled_quotes() {
+    assert_eq!(
+        format_server_default("'O''Brien'::text", Dialect::Postgres),
+        "text(\"'O''Brien'\")"
+    );
+    assert_eq!(
+        format_server_default("('C:\\dir')", Dialect::Mssql),
+        "text(\"'C:\\\\dir'\")"
+    );
 }
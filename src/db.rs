@@ -5,11 +5,14 @@ use anyhow::Result;
 use crate::cli::GeneratorOptions;
 use crate::connection::ConnectionConfig;
 use crate::dialect::Dialect;
+use crate::error::UvgError;
 use crate::introspect;
 use crate::schema::IntrospectedSchema;
 use crate::table_filter::TableFilter;
 
-/// Introspect a database given a ConnectionConfig.
+/// Introspect a database given a ConnectionConfig. Failures to connect come
+/// back as [`UvgError::Connection`], later ones as
+/// [`UvgError::Introspection`].
 pub async fn introspect_with_config(
     config: ConnectionConfig,
     schemas: &[String],
@@ -17,13 +20,14 @@ pub async fn introspect_with_config(
     noviews: bool,
     options: &GeneratorOptions,
     concurrency: usize,
-) -> Result<IntrospectedSchema> {
+) -> Result<IntrospectedSchema, UvgError> {
     match config {
         ConnectionConfig::Postgres(url) => {
             let pool = sqlx::postgres::PgPoolOptions::new()
                 .max_connections(pool_size(concurrency))
                 .connect(&url)
-                .await?;
+                .await
+                .map_err(connection_error)?;
             let s = introspect::pg::introspect(
                 &pool,
                 schemas,
@@ -34,29 +38,45 @@ pub async fn introspect_with_config(
             )
             .await;
             pool.close().await;
-            Ok(s?)
+            s.map_err(UvgError::introspection)
         }
         ConnectionConfig::Mssql {
             host,
             port,
+            instance,
             database,
             user,
             password,
             trust_cert,
+            encrypt,
+            auth,
         } => {
-            let mut client =
-                introspect::mssql::connect(&host, port, &database, &user, &password, trust_cert)
-                    .await?;
-            Ok(
-                introspect::mssql::introspect(&mut client, schemas, table_filter, noviews, options)
-                    .await?,
-            )
+            let connections = introspect::mssql::connections_for(concurrency, schemas.len());
+            let mut clients = futures::future::try_join_all((0..connections).map(|_| {
+                introspect::mssql::connect(
+                    &host,
+                    port,
+                    instance.as_deref(),
+                    &database,
+                    &user,
+                    &password,
+                    auth,
+                    trust_cert,
+                    encrypt,
+                )
+            }))
+            .await
+            .map_err(connection_error)?;
+            introspect::mssql::introspect(&mut clients, schemas, table_filter, noviews, options)
+                .await
+                .map_err(UvgError::introspection)
         }
         ConnectionConfig::Mysql(url) => {
             let pool = sqlx::mysql::MySqlPoolOptions::new()
                 .max_connections(pool_size(concurrency))
                 .connect(&url)
-                .await?;
+                .await
+                .map_err(connection_error)?;
             let s = introspect::mysql::introspect(
                 &pool,
                 schemas,
@@ -67,20 +87,29 @@ pub async fn introspect_with_config(
             )
             .await;
             pool.close().await;
-            Ok(s?)
+            s.map_err(UvgError::introspection)
         }
         ConnectionConfig::Sqlite(url) => {
             let pool = sqlx::sqlite::SqlitePoolOptions::new()
                 .max_connections(1)
                 .connect(&url)
-                .await?;
+                .await
+                .map_err(connection_error)?;
             let s = introspect::sqlite::introspect(&pool, table_filter, noviews, options).await;
             pool.close().await;
-            Ok(s?)
+            s.map_err(UvgError::introspection)
         }
     }
 }
 
+/// A connect-time failure, whatever layer raised it.
+fn connection_error(err: impl Into<UvgError>) -> UvgError {
+    match err.into() {
+        err @ UvgError::Connection(_) => err,
+        err => UvgError::Connection(err.to_string()),
+    }
+}
+
 fn pool_size(concurrency: usize) -> u32 {
     concurrency.max(1).min(u32::MAX as usize) as u32
 }
@@ -447,14 +476,26 @@ pub async fn parse_check_ddl(config: &ConnectionConfig, ddl: &str) -> Result<Vec
         ConnectionConfig::Mssql {
             host,
             port,
+            instance,
             database,
             user,
             password,
             trust_cert,
+            encrypt,
+            auth,
         } => {
-            let mut client =
-                introspect::mssql::connect(host, *port, database, user, password, *trust_cert)
-                    .await?;
+            let mut client = introspect::mssql::connect(
+                host,
+                *port,
+                instance.as_deref(),
+                database,
+                user,
+                password,
+                *auth,
+                *trust_cert,
+                *encrypt,
+            )
+            .await?;
             // Switch the session to parse-only mode. Per MS docs,
             // PARSEONLY does pure T-SQL syntax checking — name
             // resolution (missing tables, FK targets, column types)
@@ -598,14 +639,26 @@ where
         ConnectionConfig::Mssql {
             host,
             port,
+            instance,
             database,
             user,
             password,
             trust_cert,
+            encrypt,
+            auth,
         } => {
-            let mut client =
-                introspect::mssql::connect(host, *port, database, user, password, *trust_cert)
-                    .await?;
+            let mut client = introspect::mssql::connect(
+                host,
+                *port,
+                instance.as_deref(),
+                database,
+                user,
+                password,
+                *auth,
+                *trust_cert,
+                *encrypt,
+            )
+            .await?;
             for (i, stmt) in statements.iter().enumerate() {
                 // MSSQL retry loop is inlined: `run_with_retry`'s
                 // `FnMut(u8) -> Fut` bound can't accept a closure that
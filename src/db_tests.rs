@@ -1,4 +1,5 @@
 use super::*;
+use crate::connection::{MssqlAuth, MssqlEncrypt};
 use crate::dialect::Dialect;
 
 #[test]
@@ -407,10 +408,13 @@ fn supports_parse_check_only_pg() {
     assert!(!supports_parse_check(&ConnectionConfig::Mssql {
         host: "x".to_string(),
         port: 1433,
+        instance: None,
         database: "x".to_string(),
         user: "x".to_string(),
         password: "x".to_string(),
+        auth: MssqlAuth::Sql,
         trust_cert: false,
+        encrypt: MssqlEncrypt::Required,
     }));
     assert!(!supports_parse_check(&ConnectionConfig::Mysql(
         "mysql://x".to_string()
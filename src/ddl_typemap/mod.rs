@@ -118,6 +118,12 @@ pub fn from_canonical(ct: &CanonicalType, target: Dialect) -> DdlType {
     }
 }
 
+/// Introspected fractional-seconds precision of a PG/MSSQL temporal
+/// column, in canonical form.
+fn temporal_precision(col: &ColumnInfo) -> Option<u8> {
+    col.datetime_precision.and_then(|p| u8::try_from(p).ok())
+}
+
 /// Map a source column type to a target DDL type string.
 pub fn map_ddl_type(col: &ColumnInfo, source: Dialect, target: Dialect) -> DdlType {
     let canonical = to_canonical(col, source);
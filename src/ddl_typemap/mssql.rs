@@ -1,6 +1,6 @@
 use crate::schema::ColumnInfo;
 
-use super::{CanonicalType, DdlType};
+use super::{temporal_precision, CanonicalType, DdlType};
 
 /// Normalize a MSSQL column type to canonical form.
 pub fn to_canonical(col: &ColumnInfo) -> CanonicalType {
@@ -26,15 +26,17 @@ pub fn to_canonical(col: &ColumnInfo) -> CanonicalType {
             precision: Some(10),
             scale: Some(4),
         },
-        "varchar" => CanonicalType::Varchar {
-            length: col.character_maximum_length,
+        // Introspection records varchar(max)/nvarchar(max) without a length;
+        // they hold up to 2 GB, so they are text rather than unbounded varchar.
+        "varchar" | "nvarchar" => match col.character_maximum_length {
+            Some(length) => CanonicalType::Varchar {
+                length: Some(length),
+            },
+            None => CanonicalType::Text,
         },
         "char" => CanonicalType::Char {
             length: col.character_maximum_length,
         },
-        "nvarchar" => CanonicalType::Varchar {
-            length: col.character_maximum_length,
-        },
         "nchar" => CanonicalType::Char {
             length: col.character_maximum_length,
         },
@@ -45,17 +47,19 @@ pub fn to_canonical(col: &ColumnInfo) -> CanonicalType {
         "date" => CanonicalType::Date,
         "time" => CanonicalType::Time {
             with_tz: false,
-            precision: None,
+            precision: temporal_precision(col),
         },
         "datetime" | "datetime2" | "smalldatetime" => CanonicalType::Timestamp {
             with_tz: false,
-            precision: None,
+            precision: temporal_precision(col),
         },
         "datetimeoffset" => CanonicalType::Timestamp {
             with_tz: true,
-            precision: None,
+            precision: temporal_precision(col),
         },
         "uniqueidentifier" => CanonicalType::Uuid,
+        // A computed column read from a SQL script declares no type.
+        "" => CanonicalType::Text,
         _ => CanonicalType::Raw {
             type_name: udt.to_uppercase(),
         },
@@ -65,9 +65,34 @@ fn test_mssql_text_to_nvarchar_max() {
     );
 }
 
+#[test]
+fn test_mssql_varchar_max_is_text() {
+    let c = col("body").udt("nvarchar").build();
+    assert_eq!(to_canonical(&c), CanonicalType::Text);
+    let c = col("name").udt("varchar").max_length(50).build();
+    assert_eq!(
+        to_canonical(&c),
+        CanonicalType::Varchar { length: Some(50) }
+    );
+}
+
 #[test]
 fn test_json_to_mssql() {
     let dt = from_canonical(&CanonicalType::Json);
     assert_eq!(dt.sql_type, "NVARCHAR(MAX)");
     assert!(dt.is_approximate);
 }
+
+#[test]
+fn test_mssql_datetime2_precision_round_trips() {
+    let c = col("ts").udt("datetime2").datetime_precision(3).build();
+    let ct = to_canonical(&c);
+    assert_eq!(
+        ct,
+        CanonicalType::Timestamp {
+            with_tz: false,
+            precision: Some(3)
+        }
+    );
+    assert_eq!(from_canonical(&ct).sql_type, "DATETIME2(3)");
+}
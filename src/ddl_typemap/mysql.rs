@@ -9,12 +9,13 @@ pub(crate) fn is_tinyint_bool(col: &ColumnInfo) -> bool {
     col.udt_name == "tinyint" && col.data_type.starts_with("tinyint(1)")
 }
 
-/// Check if a MySQL COLUMN_TYPE string carries the `unsigned` attribute.
-/// Canonical DDL translation drops unsignedness (no portable equivalent),
-/// but the SQLAlchemy typemap preserves it via dialect types (#114); this
-/// helper keeps that COLUMN_TYPE knowledge in the one MySQL parsing module.
+/// Check if a MySQL integer column is unsigned. Canonical DDL translation
+/// widens unsigned types to the next type that holds their range, and the
+/// SQLAlchemy typemap keeps the attribute via dialect types (#114).
+/// `ColumnInfo::signed` is authoritative; the COLUMN_TYPE check covers
+/// snapshots written before that field existed.
 pub(crate) fn is_unsigned(col: &ColumnInfo) -> bool {
-    col.data_type.contains("unsigned")
+    !col.signed || col.data_type.contains("unsigned")
 }
 
 /// Parse ENUM or SET values from a COLUMN_TYPE string like "enum('a','b','c')".
@@ -91,6 +92,11 @@ pub fn to_canonical(col: &ColumnInfo) -> CanonicalType {
 
     match udt {
         "tinyint" if is_tinyint_bool(col) => CanonicalType::Boolean,
+        // Unsigned types widen to keep their upper range: tinyint unsigned
+        // (255) still fits SmallInt and mediumint unsigned Integer. Nothing
+        // portable holds bigint unsigned, so it stays BigInt.
+        "smallint" if is_unsigned(col) => CanonicalType::Integer,
+        "int" if is_unsigned(col) => CanonicalType::BigInt,
         "tinyint" | "smallint" => CanonicalType::SmallInt,
         "mediumint" | "int" => CanonicalType::Integer,
         "bigint" => CanonicalType::BigInt,
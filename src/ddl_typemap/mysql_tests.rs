@@ -20,6 +20,20 @@ fn test_int() {
     assert_eq!(from_canonical(&CanonicalType::Integer).sql_type, "INT");
 }
 
+#[test]
+fn test_unsigned_widens() {
+    let c = mysql_col("int", "int unsigned");
+    assert_eq!(to_canonical(&c), CanonicalType::BigInt);
+    let c = mysql_col("smallint", "smallint unsigned");
+    assert_eq!(to_canonical(&c), CanonicalType::Integer);
+    let c = mysql_col("tinyint", "tinyint unsigned");
+    assert_eq!(to_canonical(&c), CanonicalType::SmallInt);
+
+    let mut c = mysql_col("int", "int");
+    c.signed = false;
+    assert_eq!(to_canonical(&c), CanonicalType::BigInt);
+}
+
 #[test]
 fn test_enum() {
     let c = mysql_col("enum", "enum('a','b','c')");
@@ -1,6 +1,6 @@
 use crate::schema::ColumnInfo;
 
-use super::{CanonicalType, DdlType};
+use super::{temporal_precision, CanonicalType, DdlType};
 
 /// Normalize a PostgreSQL column type to canonical form.
 pub fn to_canonical(col: &ColumnInfo) -> CanonicalType {
@@ -38,19 +38,19 @@ pub fn to_canonical(col: &ColumnInfo) -> CanonicalType {
         "date" => CanonicalType::Date,
         "time" | "time without time zone" => CanonicalType::Time {
             with_tz: false,
-            precision: None,
+            precision: temporal_precision(col),
         },
         "timetz" | "time with time zone" => CanonicalType::Time {
             with_tz: true,
-            precision: None,
+            precision: temporal_precision(col),
         },
         "timestamp" | "timestamp without time zone" => CanonicalType::Timestamp {
             with_tz: false,
-            precision: None,
+            precision: temporal_precision(col),
         },
         "timestamptz" | "timestamp with time zone" => CanonicalType::Timestamp {
             with_tz: true,
-            precision: None,
+            precision: temporal_precision(col),
         },
         "interval" => CanonicalType::Interval,
         "uuid" => CanonicalType::Uuid,
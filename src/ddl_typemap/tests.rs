@@ -73,6 +73,13 @@ fn test_mssql_money_to_pg() {
     assert_eq!(dt.sql_type, "NUMERIC(19, 4)");
 }
 
+#[test]
+fn test_mssql_undeclared_type_to_pg() {
+    let c = col("total").udt("").build();
+    let dt = map_ddl_type(&c, Dialect::Mssql, Dialect::Postgres);
+    assert_eq!(dt.sql_type, "TEXT");
+}
+
 #[test]
 fn test_same_dialect_passthrough() {
     let mut c = col("name").udt("varchar").build();
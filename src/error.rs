@@ -1,3 +1,4 @@
+use serde::Serialize;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -29,4 +30,180 @@ pub enum UvgError {
         pattern: String,
         reason: String,
     },
+
+    #[error("Invalid table kind `{0}` (expected temp, partitions, history, migrations, tables, or foreign)")]
+    InvalidTableKind(String),
+
+    #[error("Introspection failed: {0}")]
+    Introspection(#[source] Box<UvgError>),
+
+    #[error("No tables found in {0}; check --schemas, --tables and --exclude-tables")]
+    EmptySchema(String),
+
+    #[error("Cannot write {path}: {source}")]
+    Output {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Cannot render template {path}")]
+    Template {
+        path: String,
+        #[source]
+        source: tera::Error,
+    },
+
+    #[error("Generator plugin `{command}` failed: {reason}")]
+    Plugin { command: String, reason: String },
+
+    #[error("{0} warning(s) with --strict")]
+    Strict(usize),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+impl UvgError {
+    /// Wrap an error raised while reading the schema of a connected
+    /// database.
+    pub fn introspection(err: UvgError) -> Self {
+        match err {
+            UvgError::Connection(_) | UvgError::Introspection(_) => err,
+            other => UvgError::Introspection(Box::new(other)),
+        }
+    }
+
+    /// The failure category this error reports as.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            UvgError::Connection(_) | UvgError::UnsupportedScheme(_) => ErrorKind::Connection,
+            UvgError::Introspection(_) => ErrorKind::Introspection,
+            UvgError::EmptySchema(_) => ErrorKind::EmptySchema,
+            UvgError::Output { .. } | UvgError::Template { .. } | UvgError::Plugin { .. } => {
+                ErrorKind::Generation
+            }
+            UvgError::Strict(_) => ErrorKind::Warnings,
+            UvgError::UnknownGenerator(_)
+            | UvgError::InvalidDialect(_)
+            | UvgError::InvalidTablePattern { .. }
+            | UvgError::InvalidTableKind(_)
+            | UvgError::Usage(_) => ErrorKind::Usage,
+            UvgError::Database(_) | UvgError::Mssql(_) | UvgError::Io(_) => ErrorKind::Failure,
+        }
+    }
+}
+
+/// Why a run failed. Each kind has a stable process exit code so scripts
+/// can branch on it; see "Exit codes" in the README.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// Anything not covered below, including `--check` drift.
+    Failure,
+    /// Invalid flags or flag values.
+    Usage,
+    /// The database could not be reached or rejected the login.
+    Connection,
+    /// Connected, but reading the schema failed.
+    Introspection,
+    /// The source had no tables left after schema and table filters.
+    EmptySchema,
+    /// Output could not be generated or written.
+    Generation,
+    /// `--strict` and the run reported warnings.
+    Warnings,
 }
+
+impl ErrorKind {
+    pub fn exit_code(self) -> u8 {
+        match self {
+            ErrorKind::Failure => 1,
+            ErrorKind::Usage => 2,
+            ErrorKind::Connection => 3,
+            ErrorKind::Introspection => 4,
+            ErrorKind::EmptySchema => 5,
+            ErrorKind::Generation => 6,
+            ErrorKind::Warnings => 7,
+        }
+    }
+
+    /// The kind of the first [`UvgError`] in `err`'s cause chain.
+    pub fn of(err: &anyhow::Error) -> Self {
+        err.chain()
+            .find_map(|cause| cause.downcast_ref::<UvgError>())
+            .map_or(ErrorKind::Failure, UvgError::kind)
+    }
+}
+
+/// How a failed run reports its error on stderr (`--error-format`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ErrorFormat {
+    /// The error and its causes as text. Default.
+    #[default]
+    Text,
+    /// One JSON object with the error kind, exit code, message and causes.
+    Json,
+}
+
+impl ErrorFormat {
+    /// The `--error-format` given in `args`, read without the full parser
+    /// so that errors from parsing itself can be reported in it.
+    pub fn requested<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<std::ffi::OsStr>,
+    {
+        let mut format = ErrorFormat::Text;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref().to_string_lossy();
+            let value = match arg.strip_prefix("--error-format") {
+                Some("") => args
+                    .next()
+                    .map(|v| v.as_ref().to_string_lossy().into_owned()),
+                Some(rest) => rest.strip_prefix('=').map(str::to_string),
+                None => None,
+            };
+            if let Some(requested) = value.as_deref().and_then(Self::from_name) {
+                format = requested;
+            }
+        }
+        format
+    }
+
+    pub fn is_text(self) -> bool {
+        self == ErrorFormat::Text
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        <Self as clap::ValueEnum>::from_str(name, false).ok()
+    }
+}
+
+/// `--error-format json`: the error as one JSON object on stderr.
+#[derive(Debug, Serialize)]
+pub struct ErrorReport {
+    pub kind: ErrorKind,
+    pub exit_code: u8,
+    pub message: String,
+    /// Underlying causes, outermost first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<String>,
+}
+
+impl ErrorReport {
+    pub fn new(err: &anyhow::Error) -> Self {
+        let kind = ErrorKind::of(err);
+        Self {
+            kind,
+            exit_code: kind.exit_code(),
+            message: err.to_string(),
+            causes: err.chain().skip(1).map(ToString::to_string).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "error_tests.rs"]
+mod tests;
@@ -0,0 +1,70 @@
+use super::*;
+
+#[test]
+fn kinds_map_to_stable_exit_codes() {
+    let codes: Vec<u8> = [
+        ErrorKind::Failure,
+        ErrorKind::Usage,
+        ErrorKind::Connection,
+        ErrorKind::Introspection,
+        ErrorKind::EmptySchema,
+        ErrorKind::Generation,
+        ErrorKind::Warnings,
+    ]
+    .into_iter()
+    .map(ErrorKind::exit_code)
+    .collect();
+
+    assert_eq!(codes, [1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn kind_comes_from_the_first_uvg_error_in_the_chain() {
+    let err =
+        anyhow::Error::from(UvgError::Connection("refused".to_string())).context("loading source");
+    assert_eq!(ErrorKind::of(&err), ErrorKind::Connection);
+
+    let err = anyhow::anyhow!("--check found drift");
+    assert_eq!(ErrorKind::of(&err), ErrorKind::Failure);
+}
+
+#[test]
+fn introspection_wraps_query_errors_but_not_connection_errors() {
+    let io = UvgError::Io(std::io::Error::other("reset"));
+    assert_eq!(UvgError::introspection(io).kind(), ErrorKind::Introspection);
+
+    let refused = UvgError::Connection("refused".to_string());
+    assert_eq!(
+        UvgError::introspection(refused).kind(),
+        ErrorKind::Connection
+    );
+}
+
+#[test]
+fn json_report_carries_kind_code_and_causes() {
+    let err = anyhow::Error::from(UvgError::introspection(UvgError::Io(
+        std::io::Error::other("reset"),
+    )));
+    let json = serde_json::to_value(ErrorReport::new(&err)).unwrap();
+
+    assert_eq!(json["kind"], "introspection");
+    assert_eq!(json["exit_code"], 4);
+    assert_eq!(json["message"], "Introspection failed: IO error: reset");
+    assert_eq!(json["causes"][0], "IO error: reset");
+}
+
+#[test]
+fn requested_error_format_is_read_from_raw_args() {
+    assert_eq!(
+        ErrorFormat::requested(["uvg", "--bogus", "--error-format", "json"]),
+        ErrorFormat::Json
+    );
+    assert_eq!(
+        ErrorFormat::requested(["uvg", "--error-format=json", "--error-format=text"]),
+        ErrorFormat::Text
+    );
+    assert_eq!(
+        ErrorFormat::requested(["uvg", "--error-format", "yaml"]),
+        ErrorFormat::Text
+    );
+}
@@ -0,0 +1,71 @@
+//! PostgreSQL extensions a schema depends on.
+//!
+//! Some column types and default functions come from extensions rather
+//! than core PostgreSQL: `citext`, `hstore`, `ltree`, pgvector's `vector`,
+//! PostGIS `geometry` and `geography`, and `uuid-ossp`'s
+//! `uuid_generate_v4()`. A database the generated models are created in
+//! needs those extensions first, so the model and DDL generators warn about
+//! them and `--options extensions_header` lists them in the provenance
+//! header.
+
+use crate::dialect::Dialect;
+use crate::schema::{ColumnInfo, ExtensionInfo, IntrospectedSchema, TableInfo};
+
+/// An extension some columns of the schema depend on.
+#[derive(Debug)]
+pub struct RequiredExtension<'a> {
+    pub name: &'static str,
+    /// The installed extension, when the source reported an inventory.
+    pub installed: Option<&'a ExtensionInfo>,
+    /// The dependent columns, in schema order.
+    pub columns: Vec<(&'a TableInfo, &'a ColumnInfo)>,
+}
+
+/// Extensions the columns of `schema` depend on, in order of first use.
+/// Only PostgreSQL sources have any.
+pub fn required(schema: &IntrospectedSchema) -> Vec<RequiredExtension<'_>> {
+    let mut required: Vec<RequiredExtension> = Vec::new();
+    if schema.dialect != Dialect::Postgres {
+        return required;
+    }
+    for table in &schema.tables {
+        for col in &table.columns {
+            let Some(name) = column_extension(col) else {
+                continue;
+            };
+            match required.iter_mut().find(|ext| ext.name == name) {
+                Some(ext) => ext.columns.push((table, col)),
+                None => required.push(RequiredExtension {
+                    name,
+                    installed: schema.extensions.iter().find(|ext| ext.name == name),
+                    columns: vec![(table, col)],
+                }),
+            }
+        }
+    }
+    required
+}
+
+/// The extension providing `col`'s type (or element type) or the function
+/// its default calls.
+fn column_extension(col: &ColumnInfo) -> Option<&'static str> {
+    let udt = col.udt_name.trim_start_matches('_');
+    let by_type = match udt {
+        "citext" => Some("citext"),
+        "hstore" => Some("hstore"),
+        "ltree" | "lquery" | "ltxtquery" => Some("ltree"),
+        "vector" | "halfvec" | "sparsevec" => Some("vector"),
+        "geometry" | "geography" | "box2d" | "box3d" => Some("postgis"),
+        _ => None,
+    };
+    by_type.or_else(|| {
+        col.column_default
+            .as_deref()
+            .filter(|default| default.to_ascii_lowercase().contains("uuid_generate_v"))
+            .map(|_| "uuid-ossp")
+    })
+}
+
+#[cfg(test)]
+#[path = "extensions_tests.rs"]
+mod tests;
@@ -0,0 +1,52 @@
+use super::*;
+use crate::testutil::{col, schema_pg, schema_sqlite, table};
+
+fn places() -> TableInfo {
+    table("places")
+        .column(
+            col("id")
+                .udt("uuid")
+                .default_val("uuid_generate_v4()")
+                .build(),
+        )
+        .column(col("name").udt("citext").build())
+        .column(col("aliases").udt("_citext").nullable().build())
+        .column(col("location").udt("geometry").nullable().build())
+        .column(col("created_at").udt("timestamptz").build())
+        .build()
+}
+
+#[test]
+fn finds_extension_types_and_defaults_in_first_use_order() {
+    let mut schema = schema_pg(vec![places()]);
+    schema.extensions = vec![ExtensionInfo {
+        name: "postgis".to_string(),
+        schema: "public".to_string(),
+        version: "3.4.2".to_string(),
+    }];
+
+    let required = required(&schema);
+    let found: Vec<(&str, Vec<&str>)> = required
+        .iter()
+        .map(|ext| {
+            let columns = ext.columns.iter().map(|(_, c)| c.name.as_str()).collect();
+            (ext.name, columns)
+        })
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("uuid-ossp", vec!["id"]),
+            ("citext", vec!["name", "aliases"]),
+            ("postgis", vec!["location"]),
+        ]
+    );
+    assert!(required[1].installed.is_none());
+    assert_eq!(required[2].installed.unwrap().version, "3.4.2");
+}
+
+#[test]
+fn other_dialects_need_none() {
+    let schema = schema_sqlite(vec![places()]);
+    assert!(required(&schema).is_empty());
+}
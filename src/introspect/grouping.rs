@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use crate::schema::{ConstraintInfo, ConstraintType, ForeignKeyInfo, IndexInfo};
+use crate::schema::{ConstraintInfo, ConstraintType, ForeignKeyInfo, IndexInfo, SortOrder};
 
 pub(crate) struct ForeignKeyColumn {
     pub(crate) constraint_name: String,
@@ -16,6 +16,25 @@ pub(crate) struct IndexColumn {
     pub(crate) index_name: String,
     pub(crate) is_unique: bool,
     pub(crate) column: Option<String>,
+    pub(crate) descending: bool,
+    /// A non-key `INCLUDE` column.
+    pub(crate) included: bool,
+    /// The index comment, repeated on each of its rows.
+    pub(crate) comment: Option<String>,
+}
+
+/// Split rows from a schema-wide metadata query into per-table groups,
+/// preserving the row order within each table.
+pub(crate) fn group_by_table<R>(
+    rows: impl IntoIterator<Item = R>,
+    mut table: impl FnMut(&R) -> &str,
+) -> BTreeMap<String, Vec<R>> {
+    let mut groups: BTreeMap<String, Vec<R>> = BTreeMap::new();
+    for row in rows {
+        let name = table(&row).to_string();
+        groups.entry(name).or_default().push(row);
+    }
+    groups
 }
 
 pub(crate) fn primary_key_constraints<R>(
@@ -94,23 +113,75 @@ pub(crate) fn foreign_key_constraints(
 }
 
 pub(crate) fn grouped_indexes(rows: impl IntoIterator<Item = IndexColumn>) -> Vec<IndexInfo> {
-    let mut groups: BTreeMap<String, (bool, Vec<String>)> = BTreeMap::new();
+    let mut groups: BTreeMap<String, IndexInfo> = BTreeMap::new();
     for row in rows {
-        let entry = groups
-            .entry(row.index_name)
-            .or_insert_with(|| (row.is_unique, Vec::new()));
+        let index = groups
+            .entry(row.index_name.clone())
+            .or_insert_with(|| IndexInfo::new(row.index_name, row.is_unique, Vec::<String>::new()));
+        if index.comment.is_none() {
+            index.comment = row.comment.filter(|c| !c.is_empty());
+        }
         if let Some(column) = row.column {
-            entry.1.push(column);
+            if row.included {
+                index.include.push(column);
+                continue;
+            }
+            if row.descending {
+                index.orderings.insert(column.clone(), SortOrder::DESC);
+            }
+            index.columns.push(column);
         }
     }
 
     groups
-        .into_iter()
-        .filter(|(_, (_, columns))| !columns.is_empty())
-        .map(|(name, (is_unique, columns))| IndexInfo::new(name, is_unique, columns))
+        .into_values()
+        .filter(|index| !index.columns.is_empty())
+        .collect()
+}
+
+/// Notes for indexes with expression keys, which introspection cannot
+/// represent: an index of nothing but expressions is skipped, and a mixed
+/// one keeps only its column keys.
+pub(crate) fn expression_key_notes(rows: &[IndexColumn]) -> Vec<String> {
+    let mut keys: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    for row in rows.iter().filter(|row| !row.included) {
+        let (columns, expressions) = keys.entry(row.index_name.as_str()).or_default();
+        match row.column {
+            Some(_) => *columns += 1,
+            None => *expressions += 1,
+        }
+    }
+    keys.into_iter()
+        .filter(|(_, (_, expressions))| *expressions > 0)
+        .map(|(name, (columns, _))| expression_key_note(name, columns == 0))
         .collect()
 }
 
+/// Note for an index whose expression keys were not introspected.
+pub(crate) fn expression_key_note(index: &str, skipped: bool) -> String {
+    if skipped {
+        format!("index {index} has only expression keys and was skipped")
+    } else {
+        format!("index {index} lost its expression keys; only its column keys were kept")
+    }
+}
+
+/// Attach `(table, constraint, comment)` rows to the matching constraints.
+/// Rows for constraints not in `constraints` are ignored.
+pub(crate) fn apply_constraint_comments(
+    constraints: &mut BTreeMap<String, Vec<ConstraintInfo>>,
+    rows: impl IntoIterator<Item = (String, String, String)>,
+) {
+    for (table, name, comment) in rows {
+        let constraint = constraints
+            .get_mut(&table)
+            .and_then(|list| list.iter_mut().find(|c| c.name == name));
+        if let Some(constraint) = constraint {
+            constraint.comment = Some(comment);
+        }
+    }
+}
+
 fn simple_column_constraints<R>(
     rows: impl IntoIterator<Item = R>,
     mut split: impl FnMut(R) -> (String, String),
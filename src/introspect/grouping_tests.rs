@@ -1,8 +1,23 @@
+use std::collections::BTreeMap;
+
 use super::grouping::{
-    foreign_key_constraints, grouped_indexes, primary_key_constraints, typed_column_constraints,
-    ForeignKeyColumn, IndexColumn,
+    apply_constraint_comments, expression_key_notes, foreign_key_constraints, group_by_table,
+    grouped_indexes, primary_key_constraints, typed_column_constraints, ForeignKeyColumn,
+    IndexColumn,
 };
-use crate::schema::ConstraintType;
+use crate::schema::{ConstraintInfo, ConstraintType, SortOrder};
+
+#[test]
+fn group_by_table_keeps_row_order_per_table() {
+    let groups = group_by_table(
+        [("users", "id"), ("orders", "id"), ("users", "email")],
+        |(table, _)| table,
+    );
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups["users"], [("users", "id"), ("users", "email")]);
+    assert_eq!(groups["orders"], [("orders", "id")]);
+}
 
 #[test]
 fn primary_key_constraints_group_columns_by_name() {
@@ -103,6 +118,61 @@ fn grouped_indexes_skip_expression_only_indexes() {
     assert_eq!(indexes[0].columns, ["name", "tenant_id"]);
 }
 
+#[test]
+fn expression_keys_are_noted() {
+    let rows = [
+        index_part("idx_accounts_name", false, Some("name")),
+        index_part("idx_expression_only", true, None),
+        index_part("idx_mixed", false, Some("tenant_id")),
+        index_part("idx_mixed", false, None),
+    ];
+
+    assert_eq!(
+        expression_key_notes(&rows),
+        [
+            "index idx_expression_only has only expression keys and was skipped",
+            "index idx_mixed lost its expression keys; only its column keys were kept",
+        ]
+    );
+}
+
+#[test]
+fn grouped_indexes_record_descending_keys() {
+    let indexes = grouped_indexes([
+        index_part("idx_events_recent", false, Some("tenant_id")),
+        IndexColumn {
+            descending: true,
+            ..index_part("idx_events_recent", false, Some("created_at"))
+        },
+    ]);
+
+    assert_eq!(indexes[0].columns, ["tenant_id", "created_at"]);
+    assert_eq!(
+        indexes[0].orderings.get("created_at"),
+        Some(&SortOrder::DESC)
+    );
+    assert!(!indexes[0].orderings.contains_key("tenant_id"));
+}
+
+#[test]
+fn grouped_indexes_keep_include_columns_apart() {
+    let indexes = grouped_indexes([
+        index_part("ix_orders_customer", false, Some("customer_id")),
+        IndexColumn {
+            included: true,
+            ..index_part("ix_orders_customer", false, Some("total"))
+        },
+        IndexColumn {
+            included: true,
+            ..index_part("ix_columnstore", false, Some("total"))
+        },
+    ]);
+
+    assert_eq!(indexes.len(), 1);
+    assert_eq!(indexes[0].columns, ["customer_id"]);
+    assert_eq!(indexes[0].include, ["total"]);
+}
+
 fn fk_part(
     constraint_name: &str,
     column: &str,
@@ -126,5 +196,63 @@ fn index_part(index_name: &str, is_unique: bool, column: Option<&str>) -> IndexC
         index_name: index_name.to_string(),
         is_unique,
         column: column.map(str::to_string),
+        descending: false,
+        included: false,
+        comment: None,
     }
 }
+
+#[test]
+fn grouped_indexes_take_the_comment() {
+    let indexes = grouped_indexes([
+        IndexColumn {
+            comment: Some("Customer lookups".to_string()),
+            ..index_part("ix_orders_customer", false, Some("customer_id"))
+        },
+        IndexColumn {
+            comment: Some("Customer lookups".to_string()),
+            ..index_part("ix_orders_customer", false, Some("created_at"))
+        },
+        IndexColumn {
+            comment: Some(String::new()),
+            ..index_part("ix_orders_total", false, Some("total"))
+        },
+    ]);
+
+    assert_eq!(indexes[0].comment.as_deref(), Some("Customer lookups"));
+    assert_eq!(indexes[1].comment, None);
+}
+
+#[test]
+fn constraint_comments_attach_by_table_and_name() {
+    let mut constraints = BTreeMap::from([(
+        "orders".to_string(),
+        vec![
+            ConstraintInfo::primary_key("orders_pkey", ["id"]),
+            ConstraintInfo::check("orders_total_check", "total >= 0"),
+        ],
+    )]);
+
+    apply_constraint_comments(
+        &mut constraints,
+        [
+            (
+                "orders".to_string(),
+                "orders_total_check".to_string(),
+                "Refunds are separate rows".to_string(),
+            ),
+            (
+                "missing".to_string(),
+                "orders_total_check".to_string(),
+                "ignored".to_string(),
+            ),
+        ],
+    );
+
+    let orders = &constraints["orders"];
+    assert_eq!(orders[0].comment, None);
+    assert_eq!(
+        orders[1].comment.as_deref(),
+        Some("Refunds are separate rows")
+    );
+}
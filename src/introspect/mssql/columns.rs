@@ -1,17 +1,21 @@
+use std::collections::BTreeMap;
+
 use tiberius::Client;
 use tokio::net::TcpStream;
 use tokio_util::compat::Compat;
 
 use crate::error::UvgError;
-use crate::schema::{ColumnInfo, IdentityInfo};
+use crate::schema::{ColumnInfo, ComputedInfo, IdentityInfo, PeriodColumn};
 
+/// Query the columns of every table in `schema` in one round trip, keyed by
+/// table name.
 pub async fn query_columns(
     client: &mut Client<Compat<TcpStream>>,
     schema: &str,
-    table_name: &str,
-) -> Result<Vec<ColumnInfo>, UvgError> {
+) -> Result<BTreeMap<String, Vec<ColumnInfo>>, UvgError> {
     let query = r#"
         SELECT
+            c.TABLE_NAME,
             c.COLUMN_NAME,
             c.ORDINAL_POSITION,
             CASE WHEN c.IS_NULLABLE = 'YES' THEN 1 ELSE 0 END AS is_nullable,
@@ -19,12 +23,17 @@ pub async fn query_columns(
             c.CHARACTER_MAXIMUM_LENGTH,
             c.NUMERIC_PRECISION,
             c.NUMERIC_SCALE,
+            c.DATETIME_PRECISION,
             c.COLUMN_DEFAULT,
+            dc.name AS default_constraint,
             COLUMNPROPERTY(OBJECT_ID(QUOTENAME(c.TABLE_SCHEMA) + '.' + QUOTENAME(c.TABLE_NAME)), c.COLUMN_NAME, 'IsIdentity') AS is_identity,
             CAST(ic.seed_value AS BIGINT) AS seed_value,
             CAST(ic.increment_value AS BIGINT) AS increment_value,
             CAST(ep.value AS NVARCHAR(MAX)) AS comment,
-            c.COLLATION_NAME
+            c.COLLATION_NAME,
+            cc.definition AS computed_definition,
+            CAST(cc.is_persisted AS INT) AS is_persisted,
+            CAST(sc.generated_always_type AS INT) AS generated_always_type
         FROM INFORMATION_SCHEMA.COLUMNS c
         LEFT JOIN sys.identity_columns ic
             ON ic.object_id = OBJECT_ID(QUOTENAME(c.TABLE_SCHEMA) + '.' + QUOTENAME(c.TABLE_NAME))
@@ -36,15 +45,25 @@ pub async fn query_columns(
             ON ep.major_id = sc.object_id
             AND ep.minor_id = sc.column_id
             AND ep.name = 'MS_Description'
-        WHERE c.TABLE_SCHEMA = @P1 AND c.TABLE_NAME = @P2
-        ORDER BY c.ORDINAL_POSITION
+        -- System-generated names (DF__orders__statu__3B75D760) differ per
+        -- database, so only explicitly named defaults are kept.
+        LEFT JOIN sys.default_constraints dc
+            ON dc.object_id = sc.default_object_id
+            AND dc.is_system_named = 0
+        LEFT JOIN sys.computed_columns cc
+            ON cc.object_id = sc.object_id
+            AND cc.column_id = sc.column_id
+        WHERE c.TABLE_SCHEMA = @P1
+        ORDER BY c.TABLE_NAME, c.ORDINAL_POSITION
     "#;
 
-    let stream = client.query(query, &[&schema, &table_name]).await?;
+    let stream = client.query(query, &[&schema]).await?;
     let rows = stream.into_first_result().await?;
 
-    let mut columns = Vec::new();
+    let mut columns: BTreeMap<String, Vec<ColumnInfo>> = BTreeMap::new();
     for row in rows {
+        let table_name = row.get::<&str, _>("TABLE_NAME").unwrap_or("").to_string();
+
         let is_identity_val: i32 = row.get::<i32, _>("is_identity").unwrap_or(0);
         let is_identity = is_identity_val == 1;
 
@@ -58,19 +77,46 @@ pub async fn query_columns(
             row.get::<u8, _>("NUMERIC_PRECISION").map(|v| v as i32);
         let numeric_scale: Option<i32> = row.get::<i32, _>("NUMERIC_SCALE");
 
+        // DATETIME_PRECISION is always set for temporal types; 7 is the
+        // default scale of time/datetime2/datetimeoffset, and the others
+        // take no scale at all.
+        let datetime_precision = row
+            .get::<i16, _>("DATETIME_PRECISION")
+            .map(i32::from)
+            .filter(|&p| {
+                matches!(data_type.as_str(), "time" | "datetime2" | "datetimeoffset") && p != 7
+            });
+
         let identity = if is_identity {
             let seed: i64 = row.get::<i64, _>("seed_value").unwrap_or(1);
             let incr: i64 = row.get::<i64, _>("increment_value").unwrap_or(1);
-            Some(IdentityInfo::new(seed, incr, 0, 0, false, 0))
+            Some(IdentityInfo::seed(seed, incr))
         } else {
             None
         };
 
-        columns.push(ColumnInfo {
+        let computed = row
+            .get::<&str, _>("computed_definition")
+            .map(|definition| ComputedInfo {
+                expression: definition.to_string(),
+                persisted: Some(row.get::<i32, _>("is_persisted").unwrap_or(0) == 1),
+            });
+
+        let period = match row.get::<i32, _>("generated_always_type") {
+            Some(1) => Some(PeriodColumn::RowStart),
+            Some(2) => Some(PeriodColumn::RowEnd),
+            _ => None,
+        };
+
+        columns.entry(table_name).or_default().push(ColumnInfo {
             character_maximum_length,
             numeric_precision,
             numeric_scale,
+            datetime_precision,
             column_default: row.get::<&str, _>("COLUMN_DEFAULT").map(|s| s.to_string()),
+            default_constraint: row
+                .get::<&str, _>("default_constraint")
+                .map(|s| s.to_string()),
             is_identity,
             identity_generation: if is_identity {
                 Some("ALWAYS".to_string())
@@ -80,6 +126,8 @@ pub async fn query_columns(
             identity,
             comment: row.get::<&str, _>("comment").map(|s| s.to_string()),
             collation: row.get::<&str, _>("COLLATION_NAME").map(|s| s.to_string()),
+            computed,
+            period,
             ..ColumnInfo::new(
                 row.get::<&str, _>("COLUMN_NAME").unwrap_or(""),
                 row.get::<i32, _>("ORDINAL_POSITION").unwrap_or(0),
@@ -1,23 +1,28 @@
-use tiberius::Client;
+use std::collections::BTreeMap;
+
+use tiberius::{Client, Row};
 use tokio::net::TcpStream;
 use tokio_util::compat::Compat;
 
 use crate::error::UvgError;
 use crate::introspect::grouping::{
-    foreign_key_constraints, typed_column_constraints, ForeignKeyColumn,
+    apply_constraint_comments, foreign_key_constraints, group_by_table, typed_column_constraints,
+    ForeignKeyColumn,
 };
 use crate::schema::{ConstraintInfo, ConstraintType};
 
+/// Query the PK, UNIQUE, FK and CHECK constraints of every table in
+/// `schema` — one query per constraint kind — keyed by table name.
 pub async fn query_constraints(
     client: &mut Client<Compat<TcpStream>>,
     schema: &str,
-    table_name: &str,
-) -> Result<Vec<ConstraintInfo>, UvgError> {
-    let mut constraints: Vec<ConstraintInfo> = Vec::new();
+) -> Result<BTreeMap<String, Vec<ConstraintInfo>>, UvgError> {
+    let mut constraints: BTreeMap<String, Vec<ConstraintInfo>> = BTreeMap::new();
 
     // Primary keys and unique constraints via INFORMATION_SCHEMA
     let pk_uq_query = r#"
         SELECT
+            tc.TABLE_NAME AS table_name,
             tc.CONSTRAINT_NAME,
             tc.CONSTRAINT_TYPE,
             kcu.COLUMN_NAME,
@@ -28,34 +33,39 @@ pub async fn query_constraints(
             AND kcu.TABLE_SCHEMA = tc.TABLE_SCHEMA
             AND kcu.TABLE_NAME = tc.TABLE_NAME
         WHERE tc.TABLE_SCHEMA = @P1
-          AND tc.TABLE_NAME = @P2
           AND tc.CONSTRAINT_TYPE IN ('PRIMARY KEY', 'UNIQUE')
-        ORDER BY tc.CONSTRAINT_NAME, kcu.ORDINAL_POSITION
+        ORDER BY tc.TABLE_NAME, tc.CONSTRAINT_NAME, kcu.ORDINAL_POSITION
     "#;
 
-    let stream = client.query(pk_uq_query, &[&schema, &table_name]).await?;
+    let stream = client.query(pk_uq_query, &[&schema]).await?;
     let rows = stream.into_first_result().await?;
 
-    constraints.extend(typed_column_constraints(rows, |row| {
-        let name: String = row
-            .get::<&str, _>("CONSTRAINT_NAME")
-            .unwrap_or("")
-            .to_string();
-        let ctype_str: &str = row.get::<&str, _>("CONSTRAINT_TYPE").unwrap_or("");
-        let col: String = row.get::<&str, _>("COLUMN_NAME").unwrap_or("").to_string();
+    for (table, rows) in group_by_table(rows, table_name) {
+        constraints
+            .entry(table)
+            .or_default()
+            .extend(typed_column_constraints(rows, |row| {
+                let name: String = row
+                    .get::<&str, _>("CONSTRAINT_NAME")
+                    .unwrap_or("")
+                    .to_string();
+                let ctype_str: &str = row.get::<&str, _>("CONSTRAINT_TYPE").unwrap_or("");
+                let col: String = row.get::<&str, _>("COLUMN_NAME").unwrap_or("").to_string();
 
-        let ctype = match ctype_str {
-            "PRIMARY KEY" => ConstraintType::PrimaryKey,
-            "UNIQUE" => ConstraintType::Unique,
-            _ => return None,
-        };
+                let ctype = match ctype_str {
+                    "PRIMARY KEY" => ConstraintType::PrimaryKey,
+                    "UNIQUE" => ConstraintType::Unique,
+                    _ => return None,
+                };
 
-        Some((name, ctype, col))
-    }));
+                Some((name, ctype, col))
+            }));
+    }
 
     // Foreign keys via sys.foreign_keys + sys.foreign_key_columns
     let fk_query = r#"
         SELECT
+            OBJECT_NAME(fk.parent_object_id) AS table_name,
             fk.name AS constraint_name,
             COL_NAME(fkc.parent_object_id, fkc.parent_column_id) AS column_name,
             SCHEMA_NAME(ref_t.schema_id) AS ref_schema,
@@ -66,42 +76,47 @@ pub async fn query_constraints(
         FROM sys.foreign_keys fk
         JOIN sys.foreign_key_columns fkc ON fkc.constraint_object_id = fk.object_id
         JOIN sys.tables ref_t ON ref_t.object_id = fk.referenced_object_id
-        WHERE fk.parent_object_id = OBJECT_ID(QUOTENAME(@P1) + '.' + QUOTENAME(@P2))
-        ORDER BY fk.name, fkc.constraint_column_id
+        WHERE OBJECT_SCHEMA_NAME(fk.parent_object_id) = @P1
+        ORDER BY table_name, fk.name, fkc.constraint_column_id
     "#;
 
-    let stream = client.query(fk_query, &[&schema, &table_name]).await?;
+    let stream = client.query(fk_query, &[&schema]).await?;
     let fk_rows = stream.into_first_result().await?;
 
-    constraints.extend(foreign_key_constraints(fk_rows.into_iter().map(|row| {
-        let name: String = row
-            .get::<&str, _>("constraint_name")
-            .unwrap_or("")
-            .to_string();
-        let col: String = row.get::<&str, _>("column_name").unwrap_or("").to_string();
-        let ref_schema: String = row.get::<&str, _>("ref_schema").unwrap_or("").to_string();
-        let ref_table: String = row.get::<&str, _>("ref_table").unwrap_or("").to_string();
-        let ref_col: String = row.get::<&str, _>("ref_column").unwrap_or("").to_string();
-        // MSSQL uses underscores in action names: NO_ACTION -> NO ACTION
-        let update_rule: String = row
-            .get::<&str, _>("update_rule")
-            .unwrap_or("NO_ACTION")
-            .replace('_', " ");
-        let delete_rule: String = row
-            .get::<&str, _>("delete_rule")
-            .unwrap_or("NO_ACTION")
-            .replace('_', " ");
-
-        ForeignKeyColumn {
-            constraint_name: name,
-            column: col,
-            ref_schema,
-            ref_table,
-            ref_column: ref_col,
-            update_rule,
-            delete_rule,
-        }
-    })));
+    for (table, fk_rows) in group_by_table(fk_rows, table_name) {
+        constraints
+            .entry(table)
+            .or_default()
+            .extend(foreign_key_constraints(fk_rows.into_iter().map(|row| {
+                let name: String = row
+                    .get::<&str, _>("constraint_name")
+                    .unwrap_or("")
+                    .to_string();
+                let col: String = row.get::<&str, _>("column_name").unwrap_or("").to_string();
+                let ref_schema: String = row.get::<&str, _>("ref_schema").unwrap_or("").to_string();
+                let ref_table: String = row.get::<&str, _>("ref_table").unwrap_or("").to_string();
+                let ref_col: String = row.get::<&str, _>("ref_column").unwrap_or("").to_string();
+                // MSSQL uses underscores in action names: NO_ACTION -> NO ACTION
+                let update_rule: String = row
+                    .get::<&str, _>("update_rule")
+                    .unwrap_or("NO_ACTION")
+                    .replace('_', " ");
+                let delete_rule: String = row
+                    .get::<&str, _>("delete_rule")
+                    .unwrap_or("NO_ACTION")
+                    .replace('_', " ");
+
+                ForeignKeyColumn {
+                    constraint_name: name,
+                    column: col,
+                    ref_schema,
+                    ref_table,
+                    ref_column: ref_col,
+                    update_rule,
+                    delete_rule,
+                }
+            })));
+    }
 
     // CHECK constraints via sys.check_constraints. The `definition` column
     // carries the predicate text MSSQL stores after creation — typically
@@ -110,16 +125,17 @@ pub async fn query_constraints(
     // See #33.
     let chk_query = r#"
         SELECT
+            t.name AS table_name,
             cc.name AS constraint_name,
             cc.definition AS predicate
         FROM sys.check_constraints cc
         JOIN sys.tables t ON t.object_id = cc.parent_object_id
         JOIN sys.schemas s ON s.schema_id = t.schema_id
-        WHERE s.name = @P1 AND t.name = @P2
-        ORDER BY cc.name
+        WHERE s.name = @P1
+        ORDER BY t.name, cc.name
     "#;
 
-    let stream = client.query(chk_query, &[&schema, &table_name]).await?;
+    let stream = client.query(chk_query, &[&schema]).await?;
     let chk_rows = stream.into_first_result().await?;
 
     for row in chk_rows {
@@ -131,8 +147,45 @@ pub async fn query_constraints(
         if name.is_empty() || predicate.is_empty() {
             continue;
         }
-        constraints.push(ConstraintInfo::check(name, predicate));
+        constraints
+            .entry(table_name(&row).to_string())
+            .or_default()
+            .push(ConstraintInfo::check(name, predicate));
     }
 
+    // Constraint comments: MS_Description on the constraint object.
+    let comment_query = r#"
+        SELECT
+            OBJECT_NAME(o.parent_object_id) AS table_name,
+            o.name AS constraint_name,
+            CAST(ep.value AS NVARCHAR(MAX)) AS comment
+        FROM sys.extended_properties ep
+        JOIN sys.objects o ON o.object_id = ep.major_id
+        WHERE ep.class = 1
+          AND ep.minor_id = 0
+          AND ep.name = 'MS_Description'
+          AND o.type IN ('PK', 'UQ', 'F', 'C')
+          AND OBJECT_SCHEMA_NAME(o.object_id) = @P1
+    "#;
+
+    let stream = client.query(comment_query, &[&schema]).await?;
+    let comment_rows = stream.into_first_result().await?;
+    apply_constraint_comments(
+        &mut constraints,
+        comment_rows.iter().map(|row| {
+            (
+                table_name(row).to_string(),
+                row.get::<&str, _>("constraint_name")
+                    .unwrap_or("")
+                    .to_string(),
+                row.get::<&str, _>("comment").unwrap_or("").to_string(),
+            )
+        }),
+    );
+
     Ok(constraints)
 }
+
+fn table_name(row: &Row) -> &str {
+    row.get::<&str, _>("table_name").unwrap_or("")
+}
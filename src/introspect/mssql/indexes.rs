@@ -1,46 +1,76 @@
+use std::collections::BTreeMap;
+
 use tiberius::Client;
 use tokio::net::TcpStream;
 use tokio_util::compat::Compat;
 
 use crate::error::UvgError;
-use crate::introspect::grouping::{grouped_indexes, IndexColumn};
+use crate::introspect::grouping::{group_by_table, grouped_indexes, IndexColumn};
 use crate::schema::IndexInfo;
 
+/// Query the non-primary indexes of every table in `schema`, keyed by
+/// table name.
 pub async fn query_indexes(
     client: &mut Client<Compat<TcpStream>>,
     schema: &str,
-    table_name: &str,
-) -> Result<Vec<IndexInfo>, UvgError> {
+) -> Result<BTreeMap<String, Vec<IndexInfo>>, UvgError> {
     let query = r#"
         SELECT
+            OBJECT_NAME(i.object_id) AS table_name,
             i.name AS index_name,
             i.is_unique,
+            i.type AS index_type,
             COL_NAME(ic.object_id, ic.column_id) AS column_name,
-            ic.key_ordinal
+            ic.key_ordinal,
+            ic.is_descending_key,
+            CASE WHEN i.type IN (5, 6) THEN CAST(0 AS bit)
+                 ELSE ic.is_included_column END AS is_included_column,
+            CAST(ep.value AS NVARCHAR(MAX)) AS index_comment
         FROM sys.indexes i
         JOIN sys.index_columns ic
             ON ic.object_id = i.object_id AND ic.index_id = i.index_id
-        WHERE i.object_id = OBJECT_ID(QUOTENAME(@P1) + '.' + QUOTENAME(@P2))
+        LEFT JOIN sys.extended_properties ep
+            ON ep.class = 7 AND ep.major_id = i.object_id AND ep.minor_id = i.index_id
+            AND ep.name = 'MS_Description'
+        WHERE OBJECT_SCHEMA_NAME(i.object_id) = @P1
+          AND OBJECTPROPERTY(i.object_id, 'IsMSShipped') = 0
           AND i.is_primary_key = 0
           AND i.type <> 0
-          AND ic.key_ordinal > 0
-        ORDER BY i.name, ic.key_ordinal
+          AND (ic.key_ordinal > 0 OR ic.is_included_column = 1 OR i.type IN (5, 6))
+        ORDER BY table_name, i.name, ic.is_included_column, ic.key_ordinal, ic.index_column_id
     "#;
 
-    let stream = client.query(query, &[&schema, &table_name]).await?;
+    let stream = client.query(query, &[&schema]).await?;
     let rows = stream.into_first_result().await?;
 
-    let indexes = grouped_indexes(rows.into_iter().map(|row| {
-        let name: String = row.get::<&str, _>("index_name").unwrap_or("").to_string();
-        let is_unique: bool = row.get::<bool, _>("is_unique").unwrap_or(false);
-        let col: String = row.get::<&str, _>("column_name").unwrap_or("").to_string();
+    let mut indexes = BTreeMap::new();
+    for (table, rows) in group_by_table(rows, |row| row.get::<&str, _>("table_name").unwrap_or(""))
+    {
+        // Columnstore indexes list their columns without a key ordinal;
+        // they are read as keys here and flagged below.
+        let mut types: BTreeMap<String, u8> = BTreeMap::new();
+        let mut table_indexes = grouped_indexes(rows.into_iter().map(|row| {
+            let name: String = row.get::<&str, _>("index_name").unwrap_or("").to_string();
+            types.insert(name.clone(), row.get::<u8, _>("index_type").unwrap_or(2));
+            let is_unique: bool = row.get::<bool, _>("is_unique").unwrap_or(false);
+            let col: String = row.get::<&str, _>("column_name").unwrap_or("").to_string();
 
-        IndexColumn {
-            index_name: name,
-            is_unique,
-            column: Some(col),
+            IndexColumn {
+                index_name: name,
+                is_unique,
+                column: Some(col),
+                descending: row.get::<bool, _>("is_descending_key").unwrap_or(false),
+                included: row.get::<bool, _>("is_included_column").unwrap_or(false),
+                comment: row.get::<&str, _>("index_comment").map(str::to_string),
+            }
+        }));
+        for index in &mut table_indexes {
+            let index_type = types.get(&index.name).copied().unwrap_or(2);
+            index.clustered = matches!(index_type, 1 | 5);
+            index.columnstore = matches!(index_type, 5 | 6);
         }
-    }));
+        indexes.insert(table, table_indexes);
+    }
 
     Ok(indexes)
 }
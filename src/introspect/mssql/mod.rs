@@ -1,40 +1,101 @@
 mod columns;
 mod constraints;
 mod indexes;
+mod properties;
 mod tables;
 
-use tiberius::{Client, Config, EncryptionLevel};
+use std::collections::BTreeMap;
+
+use tiberius::{AuthMethod, Client, Config, EncryptionLevel, SqlBrowser};
 use tokio::net::TcpStream;
 use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
 
 use crate::cli::GeneratorOptions;
+use crate::connection::{MssqlAuth, MssqlEncrypt};
 use crate::dialect::Dialect;
 use crate::error::UvgError;
-use crate::schema::IntrospectedSchema;
-use crate::table_filter::TableFilter;
+use crate::schema::{ColumnInfo, ConstraintInfo, IndexInfo, IntrospectedSchema, TableInfo};
+use crate::table_filter::{expand_schema_patterns, has_schema_patterns, TableFilter};
+
+use super::restore_original_order;
+use properties::PropertyRow;
+
+type MssqlClient = Client<Compat<TcpStream>>;
+
+/// Catalog queries run for each schema. Every one is schema-wide, so they
+/// are independent and can run on separate connections.
+const SCHEMA_QUERIES: [SchemaQuery; 5] = [
+    SchemaQuery::Tables,
+    SchemaQuery::Columns,
+    SchemaQuery::Constraints,
+    SchemaQuery::Indexes,
+    SchemaQuery::Properties,
+];
+
+#[derive(Debug, Clone, Copy)]
+enum SchemaQuery {
+    Tables,
+    Columns,
+    Constraints,
+    Indexes,
+    Properties,
+}
 
-/// Establish a connection to a MSSQL server.
+enum SchemaQueryResult {
+    Tables(Vec<TableInfo>),
+    Columns(BTreeMap<String, Vec<ColumnInfo>>),
+    Constraints(BTreeMap<String, Vec<ConstraintInfo>>),
+    Indexes(BTreeMap<String, Vec<IndexInfo>>),
+    Properties(Vec<PropertyRow>),
+}
+
+/// How many connections `introspect` can keep busy for `schema_count`
+/// schemas, capped at `concurrency` (`--jobs`).
+pub fn connections_for(concurrency: usize, schema_count: usize) -> usize {
+    concurrency.clamp(1, (schema_count * SCHEMA_QUERIES.len()).max(1))
+}
+
+/// Establish a connection to a MSSQL server. With a named `instance`, `port`
+/// is the SQL Browser port used to look up the instance's TCP port.
+#[allow(clippy::too_many_arguments)]
 pub async fn connect(
     host: &str,
     port: u16,
+    instance: Option<&str>,
     database: &str,
     user: &str,
     password: &str,
+    auth: MssqlAuth,
     trust_cert: bool,
+    encrypt: MssqlEncrypt,
 ) -> Result<Client<Compat<TcpStream>>, UvgError> {
     let mut config = Config::new();
     config.host(host);
     config.port(port);
     config.database(database);
-    config.authentication(tiberius::AuthMethod::sql_server(user, password));
-    config.encryption(EncryptionLevel::Required);
+    config.authentication(auth_method(auth, user, password)?);
+    config.encryption(match encrypt {
+        MssqlEncrypt::Off => EncryptionLevel::NotSupported,
+        MssqlEncrypt::Login => EncryptionLevel::Off,
+        MssqlEncrypt::Required => EncryptionLevel::Required,
+    });
     if trust_cert {
         config.trust_cert();
     }
 
-    let tcp = TcpStream::connect(config.get_addr()).await.map_err(|e| {
-        UvgError::Connection(format!("TCP connection to {host}:{port} failed: {e}"))
-    })?;
+    let tcp = match instance {
+        Some(instance) => {
+            config.instance_name(instance);
+            TcpStream::connect_named(&config).await.map_err(|e| {
+                UvgError::Connection(format!(
+                    "Connection to instance {host}\\{instance} via SQL Browser on port {port} failed: {e}"
+                ))
+            })?
+        }
+        None => TcpStream::connect(config.get_addr()).await.map_err(|e| {
+            UvgError::Connection(format!("TCP connection to {host}:{port} failed: {e}"))
+        })?,
+    };
     tcp.set_nodelay(true)
         .map_err(|e| UvgError::Connection(format!("Failed to set TCP_NODELAY: {e}")))?;
 
@@ -42,38 +103,179 @@ pub async fn connect(
     Ok(client)
 }
 
+/// Map `--auth` and the URL credentials onto a tiberius auth method.
+fn auth_method(auth: MssqlAuth, user: &str, password: &str) -> Result<AuthMethod, UvgError> {
+    match auth {
+        MssqlAuth::Sql => Ok(AuthMethod::sql_server(user, password)),
+        MssqlAuth::Windows => windows_auth(user, password),
+        MssqlAuth::AzureAd if password.is_empty() => Err(UvgError::Connection(
+            "--auth azure-ad requires an access token as the URL password".to_string(),
+        )),
+        MssqlAuth::AzureAd => Ok(AuthMethod::aad_token(password)),
+    }
+}
+
+#[cfg(windows)]
+fn windows_auth(user: &str, password: &str) -> Result<AuthMethod, UvgError> {
+    if user.is_empty() {
+        Ok(AuthMethod::Integrated)
+    } else {
+        Ok(AuthMethod::windows(user, password))
+    }
+}
+
+#[cfg(not(windows))]
+fn windows_auth(_user: &str, _password: &str) -> Result<AuthMethod, UvgError> {
+    Err(UvgError::Connection(
+        "--auth windows is only supported on Windows builds".to_string(),
+    ))
+}
+
 /// Introspect a MSSQL database and return the full schema metadata.
+///
+/// The catalog queries of every schema are spread round-robin over
+/// `clients`, each connection working through its share in order, so one
+/// client reproduces the sequential behavior and more cut wall time on
+/// large databases.
 pub async fn introspect(
-    client: &mut Client<Compat<TcpStream>>,
+    clients: &mut [MssqlClient],
     schemas: &[String],
     table_filter: &TableFilter,
     noviews: bool,
     _options: &GeneratorOptions,
 ) -> Result<IntrospectedSchema, UvgError> {
-    let mut all_tables = Vec::new();
-
-    for schema in schemas {
-        let mut schema_tables = tables::query_tables(client, schema, noviews).await?;
+    let schemas = match clients.first_mut() {
+        Some(client) if has_schema_patterns(schemas) => {
+            expand_schema_patterns(schemas, &tables::query_schemas(client).await?)?
+        }
+        _ => schemas.to_vec(),
+    };
+    let jobs: Vec<(&str, SchemaQuery)> = schemas
+        .iter()
+        .flat_map(|schema| SCHEMA_QUERIES.map(|query| (schema.as_str(), query)))
+        .collect();
+    let mut results = run_schema_queries(clients, &jobs, noviews)
+        .await?
+        .into_iter();
 
-        schema_tables.retain(|t| table_filter.matches(&t.name));
+    let mut all_tables = Vec::new();
+    for _ in &schemas {
+        // One query per metadata kind for the whole schema, grouped by
+        // table in Rust, instead of a round trip per table.
+        let mut next = || results.next().expect("one result per schema query");
+        let (
+            SchemaQueryResult::Tables(mut schema_tables),
+            SchemaQueryResult::Columns(mut columns),
+            SchemaQueryResult::Constraints(mut constraints),
+            SchemaQueryResult::Indexes(mut indexes),
+            SchemaQueryResult::Properties(props),
+        ) = (next(), next(), next(), next(), next())
+        else {
+            unreachable!("results follow SCHEMA_QUERIES order");
+        };
 
+        schema_tables.retain(|t| table_filter.admits(t));
         for table in &mut schema_tables {
-            table.columns = columns::query_columns(client, &table.schema, &table.name).await?;
-            table.constraints =
-                constraints::query_constraints(client, &table.schema, &table.name).await?;
-            table.indexes = indexes::query_indexes(client, &table.schema, &table.name).await?;
+            table.columns = columns.remove(&table.name).unwrap_or_default();
+            table.constraints = constraints.remove(&table.name).unwrap_or_default();
+            table.indexes = indexes.remove(&table.name).unwrap_or_default();
         }
+        properties::apply_properties(&mut schema_tables, props);
 
         all_tables.extend(schema_tables);
     }
 
     // Sort by byte order (case-sensitive) to match sqlacodegen's Python sort
     all_tables.sort_by(|a, b| a.name.cmp(&b.name));
+    table_filter.close_over_fks(&mut all_tables);
 
     Ok(IntrospectedSchema {
         dialect: Dialect::Mssql,
         tables: all_tables,
         enums: vec![],
         domains: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
     })
 }
+
+/// Run `jobs` concurrently across `clients`; results come back in job order.
+async fn run_schema_queries(
+    clients: &mut [MssqlClient],
+    jobs: &[(&str, SchemaQuery)],
+    noviews: bool,
+) -> Result<Vec<SchemaQueryResult>, UvgError> {
+    let stride = clients.len().max(1);
+    let per_client = clients
+        .iter_mut()
+        .enumerate()
+        .map(|(offset, client)| async move {
+            let mut results = Vec::new();
+            for ordinal in (offset..jobs.len()).step_by(stride) {
+                let (schema, query) = jobs[ordinal];
+                results.push((
+                    ordinal,
+                    run_schema_query(client, schema, query, noviews).await?,
+                ));
+            }
+            Ok::<_, UvgError>(results)
+        });
+    let results = futures::future::try_join_all(per_client).await?;
+    Ok(restore_original_order(
+        results.into_iter().flatten().collect(),
+    ))
+}
+
+async fn run_schema_query(
+    client: &mut MssqlClient,
+    schema: &str,
+    query: SchemaQuery,
+    noviews: bool,
+) -> Result<SchemaQueryResult, UvgError> {
+    Ok(match query {
+        SchemaQuery::Tables => {
+            SchemaQueryResult::Tables(tables::query_tables(client, schema, noviews).await?)
+        }
+        SchemaQuery::Columns => {
+            SchemaQueryResult::Columns(columns::query_columns(client, schema).await?)
+        }
+        SchemaQuery::Constraints => {
+            SchemaQueryResult::Constraints(constraints::query_constraints(client, schema).await?)
+        }
+        SchemaQuery::Indexes => {
+            SchemaQueryResult::Indexes(indexes::query_indexes(client, schema).await?)
+        }
+        SchemaQuery::Properties => {
+            SchemaQueryResult::Properties(properties::query_properties(client, schema).await?)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn azure_ad_uses_password_as_token() {
+        assert!(matches!(
+            auth_method(MssqlAuth::AzureAd, "", "eyJ0eXAi"),
+            Ok(AuthMethod::AADToken(ref token)) if token == "eyJ0eXAi"
+        ));
+        assert!(auth_method(MssqlAuth::AzureAd, "", "").is_err());
+    }
+
+    #[test]
+    fn connections_are_capped_by_jobs_and_available_queries() {
+        assert_eq!(connections_for(8, 1), 5);
+        assert_eq!(connections_for(8, 3), 8);
+        assert_eq!(connections_for(1, 3), 1);
+        assert_eq!(connections_for(4, 0), 1);
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn windows_auth_is_rejected_off_windows() {
+        let err = auth_method(MssqlAuth::Windows, "", "").unwrap_err();
+        assert!(err.to_string().contains("only supported on Windows"));
+    }
+}
@@ -0,0 +1,83 @@
+use tiberius::Client;
+use tokio::net::TcpStream;
+use tokio_util::compat::Compat;
+
+use crate::error::UvgError;
+use crate::schema::TableInfo;
+
+/// One extended property on a table (`column` is `None`) or column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyRow {
+    pub table: String,
+    pub column: Option<String>,
+    pub name: String,
+    pub value: String,
+}
+
+/// Every extended property on the tables and views of `schema`, except
+/// `MS_Description`, which is already read as the comment.
+pub async fn query_properties(
+    client: &mut Client<Compat<TcpStream>>,
+    schema: &str,
+) -> Result<Vec<PropertyRow>, UvgError> {
+    let query = r#"
+        SELECT
+            o.name AS table_name,
+            c.name AS column_name,
+            ep.name AS property_name,
+            CAST(ep.value AS NVARCHAR(MAX)) AS property_value
+        FROM sys.extended_properties ep
+        JOIN sys.objects o ON o.object_id = ep.major_id
+        JOIN sys.schemas s ON s.schema_id = o.schema_id
+        LEFT JOIN sys.columns c
+            ON c.object_id = ep.major_id AND c.column_id = ep.minor_id
+        WHERE ep.class = 1
+          AND o.type IN ('U', 'V')
+          AND s.name = @P1
+          AND ep.name <> 'MS_Description'
+        ORDER BY o.name, ep.minor_id, ep.name
+    "#;
+
+    let stream = client.query(query, &[&schema]).await?;
+    let rows = stream.into_first_result().await?;
+
+    Ok(rows
+        .iter()
+        .map(|row| PropertyRow {
+            table: row.get::<&str, _>("table_name").unwrap_or("").to_string(),
+            column: row.get::<&str, _>("column_name").map(str::to_string),
+            name: row
+                .get::<&str, _>("property_name")
+                .unwrap_or("")
+                .to_string(),
+            value: row
+                .get::<&str, _>("property_value")
+                .unwrap_or("")
+                .to_string(),
+        })
+        .collect())
+}
+
+/// Attach properties to the matching tables and columns. Rows for tables
+/// or columns not in `tables` are ignored.
+pub fn apply_properties(tables: &mut [TableInfo], rows: Vec<PropertyRow>) {
+    for row in rows {
+        let Some(table) = tables.iter_mut().find(|t| t.name == row.table) else {
+            continue;
+        };
+        match row.column {
+            None => {
+                table.properties.insert(row.name, row.value);
+            }
+            Some(column) => {
+                if let Some(col) = table.columns.iter_mut().find(|c| c.name == column) {
+                    col.properties.insert(row.name, row.value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "properties_tests.rs"]
+mod tests;
@@ -0,0 +1,40 @@
+use super::*;
+use crate::testutil::*;
+
+fn row(table: &str, column: Option<&str>, name: &str, value: &str) -> PropertyRow {
+    PropertyRow {
+        table: table.to_string(),
+        column: column.map(str::to_string),
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn apply_properties_targets_tables_and_columns() {
+    let mut tables = vec![table("customers")
+        .schema("dbo")
+        .column(col("email").build())
+        .build()];
+    apply_properties(
+        &mut tables,
+        vec![
+            row("customers", None, "Owner", "crm-team"),
+            row("customers", Some("email"), "Sensitivity", "Confidential"),
+            row("customers", Some("dropped"), "Sensitivity", "Public"),
+            row("orders", None, "Owner", "sales"),
+        ],
+    );
+    assert_eq!(
+        tables[0].properties.get("Owner").map(String::as_str),
+        Some("crm-team")
+    );
+    assert_eq!(tables[0].properties.len(), 1);
+    assert_eq!(
+        tables[0].columns[0]
+            .properties
+            .get("Sensitivity")
+            .map(String::as_str),
+        Some("Confidential")
+    );
+}
@@ -5,6 +5,30 @@ use tokio_util::compat::Compat;
 use crate::error::UvgError;
 use crate::schema::{TableInfo, TableType};
 
+/// Every schema except the built-in ones (`sys`, `INFORMATION_SCHEMA`,
+/// `guest`, and the fixed database-role schemas such as `db_owner`), by
+/// name.
+pub async fn query_schemas(
+    client: &mut Client<Compat<TcpStream>>,
+) -> Result<Vec<String>, UvgError> {
+    let query = r#"
+        SELECT name
+        FROM sys.schemas
+        WHERE schema_id NOT IN (2, 3, 4)
+          AND schema_id NOT BETWEEN 16384 AND 16399
+        ORDER BY name
+    "#;
+    let rows = client
+        .simple_query(query)
+        .await?
+        .into_first_result()
+        .await?;
+    Ok(rows
+        .iter()
+        .filter_map(|row| row.get::<&str, _>("name").map(str::to_string))
+        .collect())
+}
+
 pub async fn query_tables(
     client: &mut Client<Compat<TcpStream>>,
     schema: &str,
@@ -15,12 +39,24 @@ pub async fn query_tables(
             t.TABLE_SCHEMA,
             t.TABLE_NAME,
             t.TABLE_TYPE,
-            CAST(ep.value AS NVARCHAR(MAX)) AS comment
+            CAST(ep.value AS NVARCHAR(MAX)) AS comment,
+            cur.name AS history_of,
+            m.definition AS view_definition,
+            SCHEMA_NAME(hist.schema_id) + '.' + hist.name AS history_table
         FROM INFORMATION_SCHEMA.TABLES t
         LEFT JOIN sys.extended_properties ep
             ON ep.major_id = OBJECT_ID(QUOTENAME(t.TABLE_SCHEMA) + '.' + QUOTENAME(t.TABLE_NAME))
             AND ep.minor_id = 0
             AND ep.name = 'MS_Description'
+        LEFT JOIN sys.tables cur
+            ON cur.history_table_id = OBJECT_ID(QUOTENAME(t.TABLE_SCHEMA) + '.' + QUOTENAME(t.TABLE_NAME))
+        LEFT JOIN sys.tables versioned
+            ON versioned.object_id = OBJECT_ID(QUOTENAME(t.TABLE_SCHEMA) + '.' + QUOTENAME(t.TABLE_NAME))
+            AND versioned.temporal_type = 2
+        LEFT JOIN sys.tables hist ON hist.object_id = versioned.history_table_id
+        LEFT JOIN sys.sql_modules m
+            ON t.TABLE_TYPE = 'VIEW'
+            AND m.object_id = OBJECT_ID(QUOTENAME(t.TABLE_SCHEMA) + '.' + QUOTENAME(t.TABLE_NAME))
         WHERE t.TABLE_SCHEMA = @P1
           AND t.TABLE_TYPE IN ('BASE TABLE', 'VIEW')
         ORDER BY t.TABLE_NAME
@@ -43,14 +79,17 @@ pub async fn query_tables(
             _ => continue,
         };
 
-        tables.push(
-            TableInfo::new(
+        tables.push(TableInfo {
+            history_of: row.get::<&str, _>("history_of").map(str::to_string),
+            view_definition: row.get::<&str, _>("view_definition").map(str::to_string),
+            history_table: row.get::<&str, _>("history_table").map(str::to_string),
+            ..TableInfo::new(
                 row.get::<&str, _>("TABLE_SCHEMA").unwrap_or(""),
                 row.get::<&str, _>("TABLE_NAME").unwrap_or(""),
                 table_type,
             )
-            .with_comment(row.get::<&str, _>("comment")),
-        );
+            .with_comment(row.get::<&str, _>("comment"))
+        });
     }
 
     Ok(tables)
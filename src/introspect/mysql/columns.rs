@@ -37,6 +37,7 @@ pub async fn query_columns(
         .into_iter()
         .map(|row| {
             let is_auto_increment = row.extra.contains("auto_increment");
+            let signed = !row.column_type.contains("unsigned");
             let comment = if row.column_comment.is_empty() {
                 None
             } else {
@@ -48,6 +49,7 @@ pub async fn query_columns(
                 numeric_precision: row.numeric_precision.map(|v| v as i32),
                 numeric_scale: row.numeric_scale.map(|v| v as i32),
                 column_default: row.column_default,
+                signed,
                 is_identity: is_auto_increment,
                 identity_generation: None,
                 identity: None,
@@ -1,21 +1,23 @@
 use sqlx::MySqlPool;
 
 use crate::error::UvgError;
-use crate::introspect::grouping::{grouped_indexes, IndexColumn};
+use crate::introspect::grouping::{expression_key_notes, grouped_indexes, IndexColumn};
 use crate::schema::IndexInfo;
 
 pub async fn query_indexes(
     pool: &MySqlPool,
     schema: &str,
     table_name: &str,
-) -> Result<Vec<IndexInfo>, UvgError> {
+) -> Result<(Vec<IndexInfo>, Vec<String>), UvgError> {
     let rows = sqlx::query_as::<_, IndexRow>(
         r#"
         SELECT
             CAST(INDEX_NAME AS CHAR) AS INDEX_NAME,
             NON_UNIQUE,
             CAST(COLUMN_NAME AS CHAR) AS COLUMN_NAME,
-            SEQ_IN_INDEX
+            SEQ_IN_INDEX,
+            CAST(COLLATION AS CHAR) AS COLLATION,
+            CAST(INDEX_COMMENT AS CHAR) AS INDEX_COMMENT
         FROM information_schema.STATISTICS
         WHERE TABLE_SCHEMA = ?
           AND TABLE_NAME = ?
@@ -28,15 +30,23 @@ pub async fn query_indexes(
     .fetch_all(pool)
     .await?;
 
-    let indexes = grouped_indexes(rows.into_iter().map(|row| IndexColumn {
-        index_name: row.index_name,
-        is_unique: !row.non_unique,
-        // COLUMN_NAME is NULL for functional/expression indexes (MySQL 8+);
-        // skip those columns rather than crashing.
-        column: row.column_name,
-    }));
+    let rows: Vec<IndexColumn> = rows
+        .into_iter()
+        .map(|row| IndexColumn {
+            index_name: row.index_name,
+            is_unique: !row.non_unique,
+            // COLUMN_NAME is NULL for functional/expression indexes (MySQL 8+);
+            // skip those columns rather than crashing.
+            column: row.column_name,
+            // 'D' for a descending key (MySQL 8+), 'A' ascending.
+            descending: row.collation.as_deref() == Some("D"),
+            included: false,
+            comment: row.index_comment,
+        })
+        .collect();
+    let skipped = expression_key_notes(&rows);
 
-    Ok(indexes)
+    Ok((grouped_indexes(rows), skipped))
 }
 
 #[derive(sqlx::FromRow)]
@@ -49,4 +59,8 @@ struct IndexRow {
     column_name: Option<String>,
     #[sqlx(rename = "SEQ_IN_INDEX")]
     _seq_in_index: u32,
+    #[sqlx(rename = "COLLATION")]
+    collation: Option<String>,
+    #[sqlx(rename = "INDEX_COMMENT")]
+    index_comment: Option<String>,
 }
@@ -10,7 +10,7 @@ use crate::dialect::Dialect;
 use crate::error::UvgError;
 use crate::introspect::populate_tables_concurrently;
 use crate::schema::IntrospectedSchema;
-use crate::table_filter::TableFilter;
+use crate::table_filter::{expand_schema_patterns, has_schema_patterns, TableFilter};
 
 /// Introspect a MySQL database and return the full schema metadata.
 pub async fn introspect(
@@ -21,19 +21,25 @@ pub async fn introspect(
     _options: &GeneratorOptions,
     concurrency: usize,
 ) -> Result<IntrospectedSchema, UvgError> {
+    let schemas = if has_schema_patterns(schemas) {
+        expand_schema_patterns(schemas, &tables::query_schemas(pool).await?)?
+    } else {
+        schemas.to_vec()
+    };
     let mut all_tables = Vec::new();
 
-    for schema in schemas {
+    for schema in &schemas {
         let mut schema_tables = tables::query_tables(pool, schema, noviews).await?;
 
-        schema_tables.retain(|t| table_filter.matches(&t.name));
+        schema_tables.retain(|t| table_filter.admits(t));
 
         let schema_tables =
             populate_tables_concurrently(schema_tables, concurrency, |mut table| async move {
                 table.columns = columns::query_columns(pool, &table.schema, &table.name).await?;
                 table.constraints =
                     constraints::query_constraints(pool, &table.schema, &table.name).await?;
-                table.indexes = indexes::query_indexes(pool, &table.schema, &table.name).await?;
+                (table.indexes, table.skipped) =
+                    indexes::query_indexes(pool, &table.schema, &table.name).await?;
                 Ok(table)
             })
             .await?;
@@ -43,11 +49,14 @@ pub async fn introspect(
 
     // Sort alphabetically to match sqlacodegen output
     all_tables.sort_by(|a, b| a.name.cmp(&b.name));
+    table_filter.close_over_fks(&mut all_tables);
 
     Ok(IntrospectedSchema {
         dialect: Dialect::Mysql,
         tables: all_tables,
         enums: vec![],
         domains: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
     })
 }
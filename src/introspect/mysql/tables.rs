@@ -3,6 +3,22 @@ use sqlx::MySqlPool;
 use crate::error::UvgError;
 use crate::schema::{TableInfo, TableType};
 
+/// Every database except the system ones (`mysql`, `information_schema`,
+/// `performance_schema`, `sys`), by name.
+pub async fn query_schemas(pool: &MySqlPool) -> Result<Vec<String>, UvgError> {
+    let schemas = sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT CAST(SCHEMA_NAME AS CHAR)
+        FROM information_schema.SCHEMATA
+        WHERE SCHEMA_NAME NOT IN ('mysql', 'information_schema', 'performance_schema', 'sys')
+        ORDER BY SCHEMA_NAME
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(schemas)
+}
+
 pub async fn query_tables(
     pool: &MySqlPool,
     schema: &str,
@@ -11,14 +27,17 @@ pub async fn query_tables(
     let rows = sqlx::query_as::<_, TableRow>(
         r#"
         SELECT
-            CAST(TABLE_SCHEMA AS CHAR) AS TABLE_SCHEMA,
-            CAST(TABLE_NAME AS CHAR) AS TABLE_NAME,
-            CAST(TABLE_TYPE AS CHAR) AS TABLE_TYPE,
-            CAST(TABLE_COMMENT AS CHAR) AS TABLE_COMMENT
-        FROM information_schema.TABLES
-        WHERE TABLE_SCHEMA = ?
-          AND TABLE_TYPE IN ('BASE TABLE', 'VIEW')
-        ORDER BY TABLE_NAME
+            CAST(t.TABLE_SCHEMA AS CHAR) AS TABLE_SCHEMA,
+            CAST(t.TABLE_NAME AS CHAR) AS TABLE_NAME,
+            CAST(t.TABLE_TYPE AS CHAR) AS TABLE_TYPE,
+            CAST(t.TABLE_COMMENT AS CHAR) AS TABLE_COMMENT,
+            CAST(v.VIEW_DEFINITION AS CHAR) AS VIEW_DEFINITION
+        FROM information_schema.TABLES t
+        LEFT JOIN information_schema.VIEWS v
+            ON v.TABLE_SCHEMA = t.TABLE_SCHEMA AND v.TABLE_NAME = t.TABLE_NAME
+        WHERE t.TABLE_SCHEMA = ?
+          AND t.TABLE_TYPE IN ('BASE TABLE', 'VIEW')
+        ORDER BY t.TABLE_NAME
         "#,
     )
     .bind(schema)
@@ -44,7 +63,10 @@ pub async fn query_tables(
             } else {
                 Some(row.table_comment)
             };
-            Some(TableInfo::new(row.table_schema, row.table_name, table_type).with_comment(comment))
+            Some(TableInfo {
+                view_definition: row.view_definition,
+                ..TableInfo::new(row.table_schema, row.table_name, table_type).with_comment(comment)
+            })
         })
         .collect();
 
@@ -61,4 +83,6 @@ struct TableRow {
     table_type: String,
     #[sqlx(rename = "TABLE_COMMENT")]
     table_comment: String,
+    #[sqlx(rename = "VIEW_DEFINITION")]
+    view_definition: Option<String>,
 }
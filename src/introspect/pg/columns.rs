@@ -1,119 +1,144 @@
+use std::collections::BTreeMap;
+
 use sqlx::PgPool;
 
 use crate::error::UvgError;
-use crate::schema::{ColumnInfo, IdentityInfo};
+use crate::introspect::grouping::group_by_table;
+use crate::schema::{ColumnInfo, ComputedInfo, IdentityInfo, SpatialInfo};
 
+/// Query the columns of every table in `schema` in one round trip, keyed by
+/// table name. Identity sequence parameters are joined in, so identity
+/// columns need no follow-up query.
 pub async fn query_columns(
     pool: &PgPool,
     schema: &str,
-    table_name: &str,
-) -> Result<Vec<ColumnInfo>, UvgError> {
+) -> Result<BTreeMap<String, Vec<ColumnInfo>>, UvgError> {
     let rows = sqlx::query_as::<_, ColumnRow>(
         r#"
-        SELECT c.column_name, c.ordinal_position::int4, c.is_nullable = 'YES' AS is_nullable,
-               c.data_type, c.udt_name, c.udt_schema, c.character_maximum_length::int4,
+        SELECT c.table_name, c.column_name, c.ordinal_position::int4,
+               c.is_nullable = 'YES' AS is_nullable,
+               c.data_type, c.udt_name, c.udt_schema, c.domain_name,
+               c.character_maximum_length::int4,
                c.numeric_precision::int4, c.numeric_scale::int4, c.column_default,
                c.is_identity = 'YES' AS is_identity, c.identity_generation,
+               c.is_generated = 'ALWAYS' AS is_generated, c.generation_expression,
                col_description(
                    (quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass,
                    c.ordinal_position
-               ) AS comment
+               ) AS comment,
+               CASE WHEN c.udt_name IN ('time', 'timetz', 'timestamp', 'timestamptz')
+                        AND a.atttypmod >= 0
+                   THEN a.atttypmod END AS datetime_precision,
+               a.attinhcount > 0 AS inherited,
+               CASE WHEN a.attndims > 1 THEN a.attndims::int4 END AS array_dimensions,
+               CASE WHEN c.udt_name IN ('geometry', 'geography')
+                   THEN format_type(a.atttypid, a.atttypmod) END AS spatial_type,
+               s.seqstart, s.seqincrement, s.seqmin, s.seqmax, s.seqcycle, s.seqcache
         FROM information_schema.columns c
-        WHERE c.table_schema = $1 AND c.table_name = $2
-        ORDER BY c.ordinal_position
+        JOIN pg_attribute a
+            ON a.attrelid = (
+                quote_ident(c.table_schema) || '.' || quote_ident(c.table_name)
+            )::regclass
+            AND a.attname = c.column_name
+        LEFT JOIN pg_sequence s
+            ON s.seqrelid = CASE WHEN c.is_identity = 'YES' THEN
+                pg_get_serial_sequence(
+                    quote_ident(c.table_schema) || '.' || quote_ident(c.table_name),
+                    c.column_name
+                )::regclass
+            END
+        WHERE c.table_schema = $1
+        ORDER BY c.table_name, c.ordinal_position
         "#,
     )
     .bind(schema)
-    .bind(table_name)
     .fetch_all(pool)
     .await?;
 
-    let mut columns = Vec::with_capacity(rows.len());
-    for row in rows {
-        let identity = if row.is_identity {
-            query_identity_info(pool, schema, table_name, &row.column_name).await?
-        } else {
-            None
-        };
-        columns.push(ColumnInfo {
-            udt_schema: row.udt_schema,
-            character_maximum_length: row.character_maximum_length,
-            numeric_precision: row.numeric_precision,
-            numeric_scale: row.numeric_scale,
-            column_default: row.column_default,
-            is_identity: row.is_identity,
-            identity_generation: row.identity_generation,
-            identity,
-            comment: row.comment,
-            ..ColumnInfo::new(
-                row.column_name,
-                row.ordinal_position,
-                row.is_nullable,
-                row.data_type,
-                row.udt_name,
-            )
-        });
-    }
-
-    Ok(columns)
+    Ok(group_by_table(rows, |row| &row.table_name)
+        .into_iter()
+        .map(|(table, rows)| (table, rows.into_iter().map(column_from_row).collect()))
+        .collect())
 }
 
-/// Query identity sequence parameters for an identity column.
-async fn query_identity_info(
-    pool: &PgPool,
-    schema: &str,
-    table_name: &str,
-    column_name: &str,
-) -> Result<Option<IdentityInfo>, UvgError> {
-    let qualified = format!("{schema}.{table_name}");
-    let row = sqlx::query_as::<_, IdentityRow>(
-        r#"
-        SELECT s.seqstart, s.seqincrement, s.seqmin, s.seqmax, s.seqcycle, s.seqcache
-        FROM pg_sequence s
-        JOIN pg_class c ON c.oid = s.seqrelid
-        WHERE c.oid = pg_get_serial_sequence($1, $2)::regclass
-        "#,
-    )
-    .bind(&qualified)
-    .bind(column_name)
-    .fetch_optional(pool)
-    .await?;
-
-    Ok(row.map(|r| {
-        IdentityInfo::new(
-            r.seqstart,
-            r.seqincrement,
-            r.seqmin,
-            r.seqmax,
-            r.seqcycle,
-            r.seqcache,
+fn column_from_row(row: ColumnRow) -> ColumnInfo {
+    let identity = match (
+        row.seqstart,
+        row.seqincrement,
+        row.seqmin,
+        row.seqmax,
+        row.seqcycle,
+        row.seqcache,
+    ) {
+        (Some(start), Some(increment), Some(min), Some(max), Some(cycle), Some(cache))
+            if row.is_identity =>
+        {
+            Some(IdentityInfo::new(start, increment, min, max, cycle, cache))
+        }
+        _ => None,
+    };
+    ColumnInfo {
+        udt_schema: row.udt_schema,
+        domain: row.domain_name,
+        character_maximum_length: row.character_maximum_length,
+        numeric_precision: row.numeric_precision,
+        numeric_scale: row.numeric_scale,
+        datetime_precision: row.datetime_precision,
+        column_default: row.column_default,
+        is_identity: row.is_identity,
+        identity_generation: row.identity_generation,
+        identity,
+        // PostgreSQL generated columns are always STORED.
+        computed: row
+            .generation_expression
+            .filter(|_| row.is_generated)
+            .map(|expression| ComputedInfo {
+                expression,
+                persisted: Some(true),
+            }),
+        comment: row.comment,
+        inherited: row.inherited,
+        array_dimensions: row.array_dimensions,
+        spatial: row
+            .spatial_type
+            .map(|formatted| SpatialInfo::from_type_modifier(&formatted)),
+        ..ColumnInfo::new(
+            row.column_name,
+            row.ordinal_position,
+            row.is_nullable,
+            row.data_type,
+            row.udt_name,
         )
-    }))
+    }
 }
 
 #[derive(sqlx::FromRow)]
 struct ColumnRow {
+    table_name: String,
     column_name: String,
     ordinal_position: i32,
     is_nullable: bool,
     data_type: String,
     udt_name: String,
     udt_schema: Option<String>,
+    domain_name: Option<String>,
     character_maximum_length: Option<i32>,
     numeric_precision: Option<i32>,
     numeric_scale: Option<i32>,
     column_default: Option<String>,
     is_identity: bool,
     identity_generation: Option<String>,
+    is_generated: bool,
+    generation_expression: Option<String>,
     comment: Option<String>,
-}
-
-#[derive(sqlx::FromRow)]
-struct IdentityRow {
-    seqstart: i64,
-    seqincrement: i64,
-    seqmin: i64,
-    seqmax: i64,
-    seqcycle: bool,
-    seqcache: i64,
+    datetime_precision: Option<i32>,
+    array_dimensions: Option<i32>,
+    spatial_type: Option<String>,
+    inherited: bool,
+    seqstart: Option<i64>,
+    seqincrement: Option<i64>,
+    seqmin: Option<i64>,
+    seqmax: Option<i64>,
+    seqcycle: Option<bool>,
+    seqcache: Option<i64>,
 }
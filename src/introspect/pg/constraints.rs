@@ -1,45 +1,55 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use sqlx::PgPool;
 
 use crate::error::UvgError;
 use crate::introspect::grouping::{
-    foreign_key_constraints, primary_key_constraints, unique_constraints, ForeignKeyColumn,
+    apply_constraint_comments, foreign_key_constraints, group_by_table, primary_key_constraints,
+    unique_constraints, ForeignKeyColumn,
 };
-use crate::schema::ConstraintInfo;
+use crate::schema::{ConstraintInfo, ExcludeElement, ExcludeInfo};
 
+/// Query the PK, FK, UNIQUE and CHECK constraints of every table in
+/// `schema` — one query per constraint kind — keyed by table name.
 pub async fn query_constraints(
     pool: &PgPool,
     schema: &str,
-    table_name: &str,
-) -> Result<Vec<ConstraintInfo>, UvgError> {
-    let mut constraints: Vec<ConstraintInfo> = Vec::new();
+) -> Result<BTreeMap<String, Vec<ConstraintInfo>>, UvgError> {
+    let mut constraints: BTreeMap<String, Vec<ConstraintInfo>> = BTreeMap::new();
 
     // Primary keys
     let pk_rows = sqlx::query_as::<_, PkRow>(
         r#"
-        SELECT kcu.column_name, tc.constraint_name
+        SELECT tc.table_name, kcu.column_name, tc.constraint_name
         FROM information_schema.table_constraints tc
         JOIN information_schema.key_column_usage kcu
             USING (constraint_name, table_schema, table_name)
-        WHERE tc.table_schema = $1 AND tc.table_name = $2
+        WHERE tc.table_schema = $1
             AND tc.constraint_type = 'PRIMARY KEY'
-        ORDER BY kcu.ordinal_position
+        ORDER BY tc.table_name, kcu.ordinal_position
         "#,
     )
     .bind(schema)
-    .bind(table_name)
     .fetch_all(pool)
     .await?;
 
-    constraints.extend(primary_key_constraints(pk_rows, |row| {
-        (row.constraint_name, row.column_name)
-    }));
+    for (table, rows) in group_by_table(pk_rows, |row| &row.table_name) {
+        constraints
+            .entry(table)
+            .or_default()
+            .extend(primary_key_constraints(rows, |row| {
+                (row.constraint_name, row.column_name)
+            }));
+    }
 
     // Foreign keys
     let fk_rows = sqlx::query_as::<_, FkRow>(
         r#"
-        SELECT kcu.column_name, ccu.table_schema AS ref_schema, ccu.table_name AS ref_table,
-               ccu.column_name AS ref_column, tc.constraint_name,
-               rc.update_rule, rc.delete_rule
+        SELECT tc.table_name, kcu.column_name, ccu.table_schema AS ref_schema,
+               ccu.table_name AS ref_table, ccu.column_name AS ref_column,
+               tc.constraint_name, rc.update_rule, rc.delete_rule,
+               tc.is_deferrable = 'YES' AS is_deferrable,
+               tc.initially_deferred = 'YES' AS initially_deferred
         FROM information_schema.table_constraints tc
         JOIN information_schema.key_column_usage kcu
             ON kcu.constraint_name = tc.constraint_name
@@ -51,18 +61,26 @@ pub async fn query_constraints(
         JOIN information_schema.referential_constraints rc
             ON rc.constraint_name = tc.constraint_name
             AND rc.constraint_schema = tc.constraint_schema
-        WHERE tc.table_schema = $1 AND tc.table_name = $2
+        WHERE tc.table_schema = $1
             AND tc.constraint_type = 'FOREIGN KEY'
-        ORDER BY tc.constraint_name, kcu.ordinal_position
+        ORDER BY tc.table_name, tc.constraint_name, kcu.ordinal_position
         "#,
     )
     .bind(schema)
-    .bind(table_name)
     .fetch_all(pool)
     .await?;
 
-    constraints.extend(foreign_key_constraints(fk_rows.into_iter().map(|row| {
-        ForeignKeyColumn {
+    for (table, rows) in group_by_table(fk_rows, |row| &row.table_name) {
+        let deferral: HashMap<String, (bool, bool)> = rows
+            .iter()
+            .map(|row| {
+                (
+                    row.constraint_name.clone(),
+                    (row.is_deferrable, row.initially_deferred),
+                )
+            })
+            .collect();
+        let mut fks = foreign_key_constraints(rows.into_iter().map(|row| ForeignKeyColumn {
             constraint_name: row.constraint_name,
             column: row.column_name,
             ref_schema: row.ref_schema,
@@ -70,29 +88,54 @@ pub async fn query_constraints(
             ref_column: row.ref_column,
             update_rule: row.update_rule,
             delete_rule: row.delete_rule,
+        }));
+        for constraint in &mut fks {
+            if let (Some(fk), Some(&(deferrable, deferred))) = (
+                constraint.foreign_key.as_mut(),
+                deferral.get(&constraint.name),
+            ) {
+                fk.deferrable = deferrable;
+                fk.initially_deferred = deferred;
+            }
         }
-    })));
+        constraints.entry(table).or_default().extend(fks);
+    }
 
-    // Unique constraints
+    // Unique constraints. `indnullsnotdistinct` only exists from PG 15, so
+    // it is read through to_jsonb() rather than by name.
     let uq_rows = sqlx::query_as::<_, UqRow>(
         r#"
-        SELECT tc.constraint_name, kcu.column_name
+        SELECT tc.table_name, tc.constraint_name, kcu.column_name,
+               COALESCE((to_jsonb(ix) ->> 'indnullsnotdistinct')::boolean, false)
+                   AS nulls_not_distinct
         FROM information_schema.table_constraints tc
         JOIN information_schema.key_column_usage kcu
             USING (constraint_name, table_schema, table_name)
-        WHERE tc.table_schema = $1 AND tc.table_name = $2
+        JOIN pg_namespace cn ON cn.nspname = tc.table_schema
+        JOIN pg_class cl ON cl.relnamespace = cn.oid AND cl.relname = tc.table_name
+        JOIN pg_constraint c ON c.conrelid = cl.oid AND c.conname = tc.constraint_name
+        LEFT JOIN pg_index ix ON ix.indexrelid = c.conindid
+        WHERE tc.table_schema = $1
             AND tc.constraint_type = 'UNIQUE'
-        ORDER BY tc.constraint_name, kcu.ordinal_position
+        ORDER BY tc.table_name, tc.constraint_name, kcu.ordinal_position
         "#,
     )
     .bind(schema)
-    .bind(table_name)
     .fetch_all(pool)
     .await?;
 
-    constraints.extend(unique_constraints(uq_rows, |row| {
-        (row.constraint_name, row.column_name)
-    }));
+    for (table, rows) in group_by_table(uq_rows, |row| &row.table_name) {
+        let nulls_not_distinct: HashSet<String> = rows
+            .iter()
+            .filter(|row| row.nulls_not_distinct)
+            .map(|row| row.constraint_name.clone())
+            .collect();
+        let mut uqs = unique_constraints(rows, |row| (row.constraint_name, row.column_name));
+        for constraint in &mut uqs {
+            constraint.nulls_not_distinct = nulls_not_distinct.contains(&constraint.name);
+        }
+        constraints.entry(table).or_default().extend(uqs);
+    }
 
     // CHECK constraints. pg_constraint.contype='c' is the catalog-side filter;
     // pg_get_constraintdef returns a readable predicate string like
@@ -101,19 +144,18 @@ pub async fn query_constraints(
     // it does for mssql/mysql sources. See #33.
     let chk_rows = sqlx::query_as::<_, ChkRow>(
         r#"
-        SELECT c.conname AS constraint_name,
+        SELECT cl.relname::text AS table_name,
+               c.conname::text AS constraint_name,
                pg_get_constraintdef(c.oid) AS predicate
         FROM pg_constraint c
         JOIN pg_namespace n ON n.oid = c.connamespace
         JOIN pg_class cl    ON cl.oid = c.conrelid
         WHERE c.contype = 'c'
           AND n.nspname = $1
-          AND cl.relname = $2
-        ORDER BY c.conname
+        ORDER BY cl.relname, c.conname
         "#,
     )
     .bind(schema)
-    .bind(table_name)
     .fetch_all(pool)
     .await?;
 
@@ -122,12 +164,91 @@ pub async fn query_constraints(
         // emitter doesn't double-wrap. Also strip any leading "NOT VALID"
         // suffix which constraint metadata can carry but isn't predicate.
         let predicate = strip_check_wrapper(&row.predicate);
-        constraints.push(ConstraintInfo::check(row.constraint_name, predicate));
+        constraints
+            .entry(row.table_name)
+            .or_default()
+            .push(ConstraintInfo::check(row.constraint_name, predicate));
+    }
+
+    // EXCLUDE constraints: each key of the backing index pairs with the
+    // operator at the same position in conexclop. Expression keys
+    // (indkey 0) are read back with pg_get_indexdef.
+    let excl_rows = sqlx::query_as::<_, ExclRow>(
+        r#"
+        SELECT cl.relname::text AS table_name,
+               c.conname::text AS constraint_name,
+               am.amname::text AS access_method,
+               array_agg(pg_get_indexdef(c.conindid, k.n, true) ORDER BY k.n) AS keys,
+               array_agg(ix.indkey[k.n - 1] = 0 ORDER BY k.n) AS expressions,
+               array_agg(op.oprname::text ORDER BY k.n) AS operators,
+               pg_get_expr(ix.indpred, ix.indrelid, true) AS predicate
+        FROM pg_constraint c
+        JOIN pg_namespace n ON n.oid = c.connamespace
+        JOIN pg_class cl    ON cl.oid = c.conrelid
+        JOIN pg_index ix    ON ix.indexrelid = c.conindid
+        JOIN pg_class ic    ON ic.oid = c.conindid
+        JOIN pg_am am       ON am.oid = ic.relam
+        CROSS JOIN LATERAL generate_series(1, ix.indnkeyatts) AS k(n)
+        JOIN pg_operator op ON op.oid = c.conexclop[k.n]
+        WHERE c.contype = 'x'
+          AND n.nspname = $1
+        GROUP BY cl.relname, c.conname, am.amname, pg_get_expr(ix.indpred, ix.indrelid, true)
+        ORDER BY cl.relname, c.conname
+        "#,
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await?;
+
+    for row in excl_rows {
+        let table = row.table_name.clone();
+        constraints
+            .entry(table)
+            .or_default()
+            .push(exclude_from_row(row));
     }
 
+    let comment_rows = sqlx::query_as::<_, (String, String, String)>(
+        r#"
+        SELECT cl.relname::text, c.conname::text, d.description
+        FROM pg_constraint c
+        JOIN pg_namespace n ON n.oid = c.connamespace
+        JOIN pg_class cl    ON cl.oid = c.conrelid
+        JOIN pg_description d
+            ON d.objoid = c.oid AND d.classoid = 'pg_constraint'::regclass
+        WHERE n.nspname = $1
+        "#,
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await?;
+    apply_constraint_comments(&mut constraints, comment_rows);
+
     Ok(constraints)
 }
 
+fn exclude_from_row(row: ExclRow) -> ConstraintInfo {
+    let elements = row
+        .keys
+        .into_iter()
+        .zip(row.expressions)
+        .zip(row.operators)
+        .map(|((key, is_expression), operator)| ExcludeElement {
+            key,
+            is_expression,
+            operator,
+        })
+        .collect();
+    ConstraintInfo::exclude(
+        row.constraint_name,
+        ExcludeInfo {
+            using: row.access_method,
+            elements,
+            predicate: row.predicate,
+        },
+    )
+}
+
 /// Strip the outer "CHECK (..)" envelope from a pg_get_constraintdef result.
 /// `CHECK ((x > 0))` → `(x > 0)` (kept inner parens — they're part of the
 /// expression). `CHECK (x > 0)` → `x > 0`. If the input doesn't start with
@@ -138,7 +259,7 @@ pub async fn query_constraints(
 /// the wrapper match below would miss (since the input would end with
 /// `... NOT VALID` rather than `)`), and the codegen emitter would
 /// double-wrap the result as `CHECK (CHECK (...) NOT VALID)`.
-fn strip_check_wrapper(def: &str) -> String {
+pub(super) fn strip_check_wrapper(def: &str) -> String {
     let mut trimmed = def.trim().to_string();
     // Strip optional trailing modifiers in any order. PG can emit
     // `... NOT VALID NO INHERIT` or `... NO INHERIT NOT VALID` depending
@@ -170,12 +291,14 @@ fn strip_check_wrapper(def: &str) -> String {
 
 #[derive(sqlx::FromRow)]
 struct PkRow {
+    table_name: String,
     column_name: String,
     constraint_name: String,
 }
 
 #[derive(sqlx::FromRow)]
 struct FkRow {
+    table_name: String,
     column_name: String,
     ref_schema: String,
     ref_table: String,
@@ -183,20 +306,37 @@ struct FkRow {
     constraint_name: String,
     update_rule: String,
     delete_rule: String,
+    is_deferrable: bool,
+    initially_deferred: bool,
 }
 
 #[derive(sqlx::FromRow)]
 struct UqRow {
+    table_name: String,
     constraint_name: String,
     column_name: String,
+    nulls_not_distinct: bool,
 }
 
 #[derive(sqlx::FromRow)]
 struct ChkRow {
+    table_name: String,
     constraint_name: String,
     predicate: String,
 }
 
+#[derive(sqlx::FromRow)]
+struct ExclRow {
+    table_name: String,
+    constraint_name: String,
+    access_method: String,
+    keys: Vec<String>,
+    /// Per key: `true` for an expression rather than a column.
+    expressions: Vec<bool>,
+    operators: Vec<String>,
+    predicate: Option<String>,
+}
+
 #[cfg(test)]
 #[path = "constraints_tests.rs"]
 mod tests;
@@ -1,4 +1,5 @@
-use super::strip_check_wrapper;
+use super::{exclude_from_row, strip_check_wrapper, ExclRow};
+use crate::schema::ConstraintType;
 
 #[test]
 fn strips_check_wrapper() {
@@ -30,3 +31,25 @@ fn strips_check_wrapper_with_trailing_modifiers() {
         "a IS NOT NULL"
     );
 }
+
+#[test]
+fn exclusion_constraint_pairs_keys_with_operators() {
+    let constraint = exclude_from_row(ExclRow {
+        table_name: "bookings".to_string(),
+        constraint_name: "bookings_no_overlap".to_string(),
+        access_method: "gist".to_string(),
+        keys: vec!["room_id".to_string(), "tsrange(starts, ends)".to_string()],
+        expressions: vec![false, true],
+        operators: vec!["=".to_string(), "&&".to_string()],
+        predicate: Some("NOT cancelled".to_string()),
+    });
+
+    assert_eq!(constraint.constraint_type, ConstraintType::Exclude);
+    assert_eq!(constraint.columns, ["room_id"]);
+    let exclude = constraint.exclude.expect("exclude info");
+    assert_eq!(exclude.using, "gist");
+    assert_eq!(exclude.elements[1].key, "tsrange(starts, ends)");
+    assert!(exclude.elements[1].is_expression);
+    assert_eq!(exclude.elements[1].operator, "&&");
+    assert_eq!(exclude.predicate.as_deref(), Some("NOT cancelled"));
+}
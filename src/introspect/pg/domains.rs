@@ -0,0 +1,96 @@
+use sqlx::PgPool;
+
+use crate::error::UvgError;
+use crate::schema::{DomainInfo, TableInfo};
+
+use super::constraints::strip_check_wrapper;
+
+/// Query the domain types of `schema`. `base_type` is the udt name of the
+/// underlying built-in type, following domains over domains to the end of
+/// the chain; the first CHECK constraint (by name) is kept.
+pub async fn query_domains(pool: &PgPool, schema: &str) -> Result<Vec<DomainInfo>, UvgError> {
+    let rows = sqlx::query_as::<_, DomainRow>(
+        r#"
+        WITH RECURSIVE chain AS (
+            SELECT t.oid AS domain_oid, t.typbasetype AS base_oid
+            FROM pg_type t
+            JOIN pg_namespace n ON n.oid = t.typnamespace
+            WHERE t.typtype = 'd' AND n.nspname = $1
+            UNION ALL
+            SELECT c.domain_oid, b.typbasetype
+            FROM chain c
+            JOIN pg_type b ON b.oid = c.base_oid
+            WHERE b.typtype = 'd'
+        )
+        SELECT t.typname AS domain_name, n.nspname AS domain_schema,
+               bt.typname AS base_type, t.typnotnull AS not_null,
+               con.conname AS constraint_name,
+               pg_get_constraintdef(con.oid) AS check_definition
+        FROM chain c
+        JOIN pg_type t ON t.oid = c.domain_oid
+        JOIN pg_namespace n ON n.oid = t.typnamespace
+        JOIN pg_type bt ON bt.oid = c.base_oid AND bt.typtype <> 'd'
+        LEFT JOIN LATERAL (
+            SELECT oid, conname FROM pg_constraint
+            WHERE contypid = t.oid AND contype = 'c'
+            ORDER BY conname
+            LIMIT 1
+        ) con ON true
+        ORDER BY t.typname
+        "#,
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| DomainInfo {
+            name: row.domain_name,
+            schema: Some(row.domain_schema),
+            base_type: row.base_type,
+            constraint_name: row.constraint_name,
+            not_null: row.not_null,
+            check_expression: row.check_definition.map(|def| strip_check_wrapper(&def)),
+        })
+        .collect())
+}
+
+/// Point domain-typed columns at their base type. `information_schema`
+/// resolves one level only, so a domain over a domain still names the inner
+/// domain, and an array of a domain names the domain's array type; both
+/// would otherwise reach the type mapper as unknown types.
+pub fn resolve_domain_columns(tables: &mut [TableInfo], domains: &[DomainInfo]) {
+    let base_of = |udt: &str| {
+        domains
+            .iter()
+            .find(|d| d.name == udt)
+            .map(|d| d.base_type.clone())
+    };
+    for column in tables.iter_mut().flat_map(|t| t.columns.iter_mut()) {
+        if let Some(element) = column.udt_name.strip_prefix('_') {
+            if let Some(base) = base_of(element) {
+                column.udt_name = format!("_{base}");
+                column.udt_schema = None;
+            }
+        } else if let Some(base) = base_of(&column.udt_name) {
+            column.domain.get_or_insert_with(|| column.udt_name.clone());
+            column.udt_name = base;
+            column.udt_schema = None;
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct DomainRow {
+    domain_name: String,
+    domain_schema: String,
+    base_type: String,
+    not_null: bool,
+    constraint_name: Option<String>,
+    check_definition: Option<String>,
+}
+
+#[cfg(test)]
+#[path = "domains_tests.rs"]
+mod tests;
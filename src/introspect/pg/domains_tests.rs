@@ -0,0 +1,72 @@
+use super::*;
+use crate::testutil::*;
+
+fn domain(name: &str, base_type: &str) -> DomainInfo {
+    DomainInfo {
+        name: name.to_string(),
+        schema: Some("public".to_string()),
+        base_type: base_type.to_string(),
+        constraint_name: None,
+        not_null: false,
+        check_expression: None,
+    }
+}
+
+#[test]
+fn nested_domain_resolves_to_final_base_type() {
+    // `information_schema` reports the outer domain as `domain_name` and the
+    // inner domain as `udt_name`.
+    let mut column = col("contact").udt("email_address").build();
+    column.domain = Some("work_email".to_string());
+    let mut tables = vec![table("people").column(column).build()];
+
+    resolve_domain_columns(
+        &mut tables,
+        &[
+            domain("email_address", "varchar"),
+            domain("work_email", "varchar"),
+        ],
+    );
+
+    let column = &tables[0].columns[0];
+    assert_eq!(column.udt_name, "varchar");
+    assert_eq!(column.domain.as_deref(), Some("work_email"));
+}
+
+#[test]
+fn domain_named_udt_records_the_domain() {
+    let mut tables = vec![table("people")
+        .column(col("email").udt("email_address").build())
+        .build()];
+
+    resolve_domain_columns(&mut tables, &[domain("email_address", "varchar")]);
+
+    let column = &tables[0].columns[0];
+    assert_eq!(column.udt_name, "varchar");
+    assert_eq!(column.domain.as_deref(), Some("email_address"));
+}
+
+#[test]
+fn domain_array_resolves_element_type() {
+    let mut tables = vec![table("people")
+        .column(col("emails").udt("_email_address").build())
+        .build()];
+
+    resolve_domain_columns(&mut tables, &[domain("email_address", "varchar")]);
+
+    let column = &tables[0].columns[0];
+    assert_eq!(column.udt_name, "_varchar");
+    assert_eq!(column.domain, None);
+}
+
+#[test]
+fn plain_columns_are_untouched() {
+    let mut tables = vec![table("people")
+        .column(col("name").udt("text").build())
+        .build()];
+
+    resolve_domain_columns(&mut tables, &[domain("email_address", "varchar")]);
+
+    assert_eq!(tables[0].columns[0].udt_name, "text");
+    assert_eq!(tables[0].columns[0].domain, None);
+}
@@ -0,0 +1,38 @@
+use sqlx::PgPool;
+
+use crate::error::UvgError;
+use crate::schema::ExtensionInfo;
+
+/// Query the extensions installed in the database. They are database-wide,
+/// so this runs once rather than per schema. `plpgsql`, present in every
+/// database, is left out.
+pub async fn query_extensions(pool: &PgPool) -> Result<Vec<ExtensionInfo>, UvgError> {
+    let rows = sqlx::query_as::<_, ExtensionRow>(
+        r#"
+        SELECT e.extname::text AS extension_name, n.nspname::text AS extension_schema,
+               e.extversion AS extension_version
+        FROM pg_catalog.pg_extension e
+        JOIN pg_catalog.pg_namespace n ON n.oid = e.extnamespace
+        WHERE e.extname <> 'plpgsql'
+        ORDER BY e.extname
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| ExtensionInfo {
+            name: r.extension_name,
+            schema: r.extension_schema,
+            version: r.extension_version,
+        })
+        .collect())
+}
+
+#[derive(sqlx::FromRow)]
+struct ExtensionRow {
+    extension_name: String,
+    extension_schema: String,
+    extension_version: String,
+}
@@ -1,49 +1,121 @@
+use std::collections::BTreeMap;
+
 use sqlx::PgPool;
 
 use crate::error::UvgError;
-use crate::schema::IndexInfo;
+use crate::introspect::grouping::group_by_table;
+use crate::schema::{IndexInfo, SortOrder};
 
+/// Query the non-primary indexes of every table in `schema`, keyed by
+/// table name. Expression keys (`indkey` entries of 0) are read back with
+/// `pg_get_indexdef`, so functional indexes keep every key in order.
 pub async fn query_indexes(
     pool: &PgPool,
     schema: &str,
-    table_name: &str,
-) -> Result<Vec<IndexInfo>, UvgError> {
+) -> Result<BTreeMap<String, Vec<IndexInfo>>, UvgError> {
     let rows = sqlx::query_as::<_, IndexRow>(
         r#"
-        SELECT i.relname AS index_name, ix.indisunique AS is_unique,
+        SELECT t.relname::text AS table_name, i.relname AS index_name, ix.indisunique AS is_unique,
                am.amname AS access_method,
-               array_agg(a.attname ORDER BY array_position(ix.indkey, a.attnum)) AS columns
+               array_remove(array_agg(a.attname::text ORDER BY k.n), NULL) AS columns,
+               array_agg(pg_get_indexdef(ix.indexrelid, k.n, true) ORDER BY k.n) AS keys,
+               bool_or(ix.indkey[k.n - 1] = 0) AS has_expressions,
+               array_agg(CASE WHEN NOT oc.opcdefault THEN oc.opcname::text END ORDER BY k.n)
+                   AS opclasses,
+               array_agg(ix.indoption[k.n - 1]::int4 ORDER BY k.n) AS options,
+               inc.include, inc.nulls_not_distinct,
+               obj_description(ix.indexrelid, 'pg_class') AS comment
         FROM pg_index ix
         JOIN pg_class t ON t.oid = ix.indrelid
         JOIN pg_class i ON i.oid = ix.indexrelid
         JOIN pg_am am ON am.oid = i.relam
         JOIN pg_namespace n ON n.oid = t.relnamespace
-        JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = ANY(ix.indkey)
-        WHERE n.nspname = $1 AND t.relname = $2 AND NOT ix.indisprimary
-        GROUP BY i.relname, ix.indisunique, am.amname
-        ORDER BY i.relname
+        CROSS JOIN LATERAL generate_series(1, ix.indnkeyatts) AS k(n)
+        CROSS JOIN LATERAL (
+            SELECT ARRAY(
+                SELECT ia.attname::text
+                FROM generate_series(ix.indnkeyatts + 1, ix.indnatts) AS j(n)
+                JOIN pg_attribute ia ON ia.attrelid = t.oid AND ia.attnum = ix.indkey[j.n - 1]
+                ORDER BY j.n
+            ) AS include,
+            -- PG 15+ only, so read without naming the column.
+            COALESCE((to_jsonb(ix) ->> 'indnullsnotdistinct')::boolean, false)
+                AS nulls_not_distinct
+        ) inc
+        LEFT JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = ix.indkey[k.n - 1]
+            AND ix.indkey[k.n - 1] <> 0
+        LEFT JOIN pg_opclass oc ON oc.oid = ix.indclass[k.n - 1]
+        WHERE n.nspname = $1 AND NOT ix.indisprimary
+          -- Exclusion constraints come with their own index; the
+          -- constraint is what gets emitted.
+          AND NOT EXISTS (
+              SELECT 1 FROM pg_constraint xc
+              WHERE xc.conindid = ix.indexrelid AND xc.contype = 'x'
+          )
+        GROUP BY t.relname, i.relname, ix.indisunique, am.amname, inc.include, inc.nulls_not_distinct,
+                 ix.indexrelid
+        ORDER BY t.relname, i.relname
         "#,
     )
     .bind(schema)
-    .bind(table_name)
     .fetch_all(pool)
     .await?;
 
-    let indexes = rows.into_iter().map(index_from_row).collect();
-
-    Ok(indexes)
+    Ok(group_by_table(rows, |row| &row.table_name)
+        .into_iter()
+        .map(|(table, rows)| (table, rows.into_iter().map(index_from_row).collect()))
+        .collect())
 }
 
 #[derive(sqlx::FromRow)]
 struct IndexRow {
+    table_name: String,
     index_name: String,
     is_unique: bool,
     access_method: String,
     columns: Vec<String>,
+    keys: Vec<String>,
+    has_expressions: bool,
+    /// Per key: the operator class when it is not the type's default.
+    opclasses: Vec<Option<String>>,
+    /// Per key: `indoption` bits (1 = DESC, 2 = NULLS FIRST); NULL past
+    /// the key columns.
+    options: Vec<Option<i32>>,
+    /// `INCLUDE` columns, after the key columns in `indkey`.
+    include: Vec<String>,
+    /// `indnullsnotdistinct`; always false before PostgreSQL 15.
+    nulls_not_distinct: bool,
+    comment: Option<String>,
 }
 
 fn index_from_row(row: IndexRow) -> IndexInfo {
     let mut index = IndexInfo::new(row.index_name, row.is_unique, row.columns);
+    index.include = row.include;
+    index.nulls_not_distinct = row.nulls_not_distinct;
+    index.comment = row.comment;
+    index.opclasses = row
+        .keys
+        .iter()
+        .zip(row.opclasses)
+        .filter(|(key, _)| index.columns.contains(key))
+        .filter_map(|(key, opclass)| Some((key.clone(), opclass?)))
+        .collect();
+    index.orderings = row
+        .keys
+        .iter()
+        .zip(row.options)
+        .filter_map(|(key, option)| {
+            let option = option?;
+            let order = SortOrder {
+                descending: option & 1 != 0,
+                nulls_first: option & 2 != 0,
+            };
+            (!order.is_default()).then(|| (key.clone(), order))
+        })
+        .collect();
+    if row.has_expressions {
+        index.expressions = row.keys;
+    }
     if row.access_method != "btree" {
         index
             .kwargs
@@ -55,14 +127,23 @@ fn index_from_row(row: IndexRow) -> IndexInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::schema::IndexKey;
 
     #[test]
     fn preserves_non_btree_access_method() {
         let index = index_from_row(IndexRow {
+            table_name: "film".to_string(),
             index_name: "film_fulltext_idx".to_string(),
             is_unique: false,
             access_method: "gist".to_string(),
             columns: vec!["fulltext".to_string()],
+            keys: vec!["fulltext".to_string()],
+            has_expressions: false,
+            opclasses: vec![None],
+            options: vec![Some(0)],
+            include: Vec::new(),
+            nulls_not_distinct: false,
+            comment: None,
         });
 
         assert_eq!(
@@ -74,12 +155,132 @@ mod tests {
     #[test]
     fn omits_default_btree_access_method() {
         let index = index_from_row(IndexRow {
+            table_name: "film".to_string(),
             index_name: "ix_title".to_string(),
             is_unique: false,
             access_method: "btree".to_string(),
             columns: vec!["title".to_string()],
+            keys: vec!["title".to_string()],
+            has_expressions: false,
+            opclasses: vec![None],
+            options: vec![Some(0)],
+            include: Vec::new(),
+            nulls_not_distinct: false,
+            comment: None,
         });
 
         assert!(!index.kwargs.contains_key("postgresql_using"));
     }
+
+    #[test]
+    fn records_non_default_operator_classes() {
+        let index = index_from_row(IndexRow {
+            table_name: "users".to_string(),
+            index_name: "users_name_trgm_idx".to_string(),
+            is_unique: false,
+            access_method: "gin".to_string(),
+            columns: vec!["name".to_string()],
+            keys: vec!["name".to_string()],
+            has_expressions: false,
+            opclasses: vec![Some("gin_trgm_ops".to_string())],
+            options: vec![Some(0)],
+            include: Vec::new(),
+            nulls_not_distinct: false,
+            comment: None,
+        });
+
+        assert_eq!(
+            index.opclasses.get("name").map(String::as_str),
+            Some("gin_trgm_ops")
+        );
+    }
+
+    #[test]
+    fn keeps_expression_keys_in_order() {
+        let index = index_from_row(IndexRow {
+            table_name: "users".to_string(),
+            index_name: "users_tenant_lower_email_idx".to_string(),
+            is_unique: true,
+            access_method: "btree".to_string(),
+            columns: vec!["tenant_id".to_string()],
+            keys: vec!["tenant_id".to_string(), "lower(email::text)".to_string()],
+            has_expressions: true,
+            opclasses: vec![None, None],
+            options: vec![Some(0), Some(0)],
+            include: Vec::new(),
+            nulls_not_distinct: false,
+            comment: None,
+        });
+
+        assert_eq!(
+            index.keys(),
+            [
+                IndexKey::Column("tenant_id"),
+                IndexKey::Expression("lower(email::text)")
+            ]
+        );
+    }
+
+    #[test]
+    fn records_descending_and_nulls_order() {
+        let index = index_from_row(IndexRow {
+            table_name: "events".to_string(),
+            index_name: "events_created_at_idx".to_string(),
+            is_unique: false,
+            access_method: "btree".to_string(),
+            columns: vec![
+                "created_at".to_string(),
+                "id".to_string(),
+                "kind".to_string(),
+            ],
+            keys: vec![
+                "created_at".to_string(),
+                "id".to_string(),
+                "kind".to_string(),
+            ],
+            has_expressions: false,
+            opclasses: vec![None, None, None],
+            options: vec![Some(1), Some(0), Some(2)],
+            include: Vec::new(),
+            nulls_not_distinct: false,
+            comment: None,
+        });
+
+        assert_eq!(
+            index.orderings.get("created_at"),
+            Some(&SortOrder {
+                descending: true,
+                nulls_first: false,
+            })
+        );
+        assert!(!index.orderings.contains_key("id"));
+        assert_eq!(
+            index
+                .orderings
+                .get("kind")
+                .map(|o| o.sql_suffix(crate::dialect::Dialect::Postgres)),
+            Some(" NULLS FIRST".to_string())
+        );
+    }
+
+    #[test]
+    fn keeps_include_columns_apart_from_keys() {
+        let index = index_from_row(IndexRow {
+            table_name: "orders".to_string(),
+            index_name: "orders_customer_idx".to_string(),
+            is_unique: false,
+            access_method: "btree".to_string(),
+            columns: vec!["customer_id".to_string()],
+            keys: vec!["customer_id".to_string()],
+            has_expressions: false,
+            opclasses: vec![None],
+            options: vec![Some(0)],
+            include: vec!["total".to_string(), "status".to_string()],
+            nulls_not_distinct: false,
+            comment: None,
+        });
+
+        assert_eq!(index.columns, ["customer_id"]);
+        assert_eq!(index.include, ["total", "status"]);
+    }
 }
@@ -1,18 +1,24 @@
 mod columns;
 mod constraints;
+mod domains;
+mod extensions;
 mod indexes;
+mod policies;
+mod sequences;
 mod tables;
 
+use futures::stream::{self, StreamExt, TryStreamExt};
 use sqlx::PgPool;
 
 use crate::cli::GeneratorOptions;
 use crate::dialect::Dialect;
 use crate::error::UvgError;
-use crate::introspect::populate_tables_concurrently;
-use crate::schema::{EnumInfo, IntrospectedSchema};
-use crate::table_filter::TableFilter;
+use crate::schema::{DomainInfo, EnumInfo, IntrospectedSchema, SequenceInfo, TableInfo};
+use crate::table_filter::{expand_schema_patterns, has_schema_patterns, TableFilter};
 
 /// Introspect a PostgreSQL database and return the full schema metadata.
+/// Up to `concurrency` schemas are introspected at once; the pool size
+/// bounds the number of queries actually in flight.
 pub async fn introspect(
     pool: &PgPool,
     schemas: &[String],
@@ -21,39 +27,93 @@ pub async fn introspect(
     _options: &GeneratorOptions,
     concurrency: usize,
 ) -> Result<IntrospectedSchema, UvgError> {
+    let schemas = if has_schema_patterns(schemas) {
+        expand_schema_patterns(schemas, &tables::query_schemas(pool).await?)?
+    } else {
+        schemas.to_vec()
+    };
+    let per_schema: Vec<SchemaObjects> = stream::iter(&schemas)
+        .map(|schema| introspect_schema(pool, schema, table_filter, noviews))
+        .buffered(concurrency.max(1))
+        .try_collect()
+        .await?;
+
     let mut all_tables = Vec::new();
     let mut all_enums = Vec::new();
-
-    for schema in schemas {
-        let mut schema_tables = tables::query_tables(pool, schema, noviews).await?;
-
-        schema_tables.retain(|t| table_filter.matches(&t.name));
-
-        let schema_tables =
-            populate_tables_concurrently(schema_tables, concurrency, |mut table| async move {
-                table.columns = columns::query_columns(pool, &table.schema, &table.name).await?;
-                table.constraints =
-                    constraints::query_constraints(pool, &table.schema, &table.name).await?;
-                table.indexes = indexes::query_indexes(pool, &table.schema, &table.name).await?;
-                Ok(table)
-            })
-            .await?;
-
-        all_tables.extend(schema_tables);
-
-        // Query enum types for this schema
-        let enums = query_enums(pool, schema).await?;
+    let mut all_domains = Vec::new();
+    let mut all_sequences = Vec::new();
+    for (tables, enums, domains, sequences) in per_schema {
+        all_tables.extend(tables);
         all_enums.extend(enums);
+        all_domains.extend(domains);
+        all_sequences.extend(sequences);
     }
+    domains::resolve_domain_columns(&mut all_tables, &all_domains);
+    table_filter.close_over_fks(&mut all_tables);
+    let extensions = extensions::query_extensions(pool).await?;
 
     Ok(IntrospectedSchema {
         dialect: Dialect::Postgres,
         tables: all_tables,
         enums: all_enums,
-        domains: vec![],
+        domains: all_domains,
+        sequences: all_sequences,
+        extensions,
     })
 }
 
+type SchemaObjects = (
+    Vec<TableInfo>,
+    Vec<EnumInfo>,
+    Vec<DomainInfo>,
+    Vec<SequenceInfo>,
+);
+
+/// Tables, enum types, domain types, and standalone sequences of one schema.
+async fn introspect_schema(
+    pool: &PgPool,
+    schema: &str,
+    table_filter: &TableFilter,
+    noviews: bool,
+) -> Result<SchemaObjects, UvgError> {
+    // One query per metadata kind for the whole schema, grouped by
+    // table in Rust, instead of a round trip per table.
+    let (
+        mut schema_tables,
+        mut columns,
+        mut constraints,
+        mut indexes,
+        mut policies,
+        enums,
+        domains,
+        sequences,
+    ) = tokio::try_join!(
+        tables::query_tables(pool, schema, noviews),
+        columns::query_columns(pool, schema),
+        constraints::query_constraints(pool, schema),
+        indexes::query_indexes(pool, schema),
+        policies::query_policies(pool, schema),
+        query_enums(pool, schema),
+        domains::query_domains(pool, schema),
+        sequences::query_sequences(pool, schema),
+    )?;
+
+    schema_tables.retain(|t| table_filter.admits(t));
+    for table in &mut schema_tables {
+        table.columns = columns.remove(&table.name).unwrap_or_default();
+        table.constraints = constraints.remove(&table.name).unwrap_or_default();
+        table.indexes = indexes.remove(&table.name).unwrap_or_default();
+        if let Some(policies) = policies.remove(&table.name) {
+            table
+                .row_security
+                .get_or_insert_with(Default::default)
+                .policies = policies;
+        }
+    }
+
+    Ok((schema_tables, enums, domains, sequences))
+}
+
 /// Query PostgreSQL enum types from pg_catalog.
 async fn query_enums(pool: &PgPool, schema: &str) -> Result<Vec<EnumInfo>, UvgError> {
     let rows = sqlx::query_as::<_, EnumRow>(
@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+
+use sqlx::PgPool;
+
+use crate::error::UvgError;
+use crate::introspect::grouping::group_by_table;
+use crate::schema::PolicyInfo;
+
+/// Query the row-level security policies of every table in `schema`,
+/// keyed by table name.
+pub async fn query_policies(
+    pool: &PgPool,
+    schema: &str,
+) -> Result<BTreeMap<String, Vec<PolicyInfo>>, UvgError> {
+    let rows = sqlx::query_as::<_, PolicyRow>(
+        r#"
+        SELECT p.tablename::text AS table_name, p.policyname::text AS policy_name,
+               p.cmd AS command, p.permissive = 'PERMISSIVE' AS permissive,
+               p.roles::text[] AS roles, p.qual AS using_expr,
+               p.with_check AS with_check_expr
+        FROM pg_catalog.pg_policies p
+        WHERE p.schemaname = $1
+        ORDER BY p.tablename, p.policyname
+        "#,
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(group_by_table(rows, |row| &row.table_name)
+        .into_iter()
+        .map(|(table, rows)| {
+            let policies = rows
+                .into_iter()
+                .map(|r| PolicyInfo {
+                    name: r.policy_name,
+                    command: r.command,
+                    permissive: r.permissive,
+                    roles: r.roles,
+                    using: r.using_expr,
+                    with_check: r.with_check_expr,
+                })
+                .collect();
+            (table, policies)
+        })
+        .collect())
+}
+
+#[derive(sqlx::FromRow)]
+struct PolicyRow {
+    table_name: String,
+    policy_name: String,
+    command: String,
+    permissive: bool,
+    roles: Vec<String>,
+    using_expr: Option<String>,
+    with_check_expr: Option<String>,
+}
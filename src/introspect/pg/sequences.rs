@@ -0,0 +1,57 @@
+use sqlx::PgPool;
+
+use crate::error::UvgError;
+use crate::schema::SequenceInfo;
+
+/// Query the standalone sequences of `schema`: those no column owns through
+/// `SERIAL` (`pg_depend` type `a`) or an identity column (type `i`).
+pub async fn query_sequences(pool: &PgPool, schema: &str) -> Result<Vec<SequenceInfo>, UvgError> {
+    let rows = sqlx::query_as::<_, SequenceRow>(
+        r#"
+        SELECT s.schemaname::text AS schema_name, s.sequencename::text AS sequence_name,
+               s.start_value, s.increment_by, s.min_value, s.max_value, s.cycle,
+               s.cache_size
+        FROM pg_catalog.pg_sequences s
+        JOIN pg_catalog.pg_namespace n ON n.nspname = s.schemaname
+        JOIN pg_catalog.pg_class c
+            ON c.relnamespace = n.oid AND c.relname = s.sequencename
+        WHERE s.schemaname = $1
+          AND NOT EXISTS (
+              SELECT 1 FROM pg_catalog.pg_depend d
+              WHERE d.classid = 'pg_catalog.pg_class'::regclass
+                AND d.objid = c.oid
+                AND d.deptype IN ('a', 'i')
+          )
+        ORDER BY s.sequencename
+        "#,
+    )
+    .bind(schema)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| SequenceInfo {
+            schema: r.schema_name,
+            name: r.sequence_name,
+            start: r.start_value.unwrap_or(1),
+            increment: r.increment_by.unwrap_or(1),
+            min_value: r.min_value.unwrap_or(1),
+            max_value: r.max_value.unwrap_or(i64::MAX),
+            cycle: r.cycle.unwrap_or(false),
+            cache: r.cache_size.unwrap_or(1),
+        })
+        .collect())
+}
+
+#[derive(sqlx::FromRow)]
+struct SequenceRow {
+    schema_name: String,
+    sequence_name: String,
+    start_value: Option<i64>,
+    increment_by: Option<i64>,
+    min_value: Option<i64>,
+    max_value: Option<i64>,
+    cycle: Option<bool>,
+    cache_size: Option<i64>,
+}
@@ -1,7 +1,25 @@
 use sqlx::PgPool;
 
 use crate::error::UvgError;
-use crate::schema::{TableInfo, TableType};
+use crate::schema::{ReplicaIdentity, RowSecurity, TableInfo, TableType};
+
+/// Every schema except the system ones (`pg_catalog`,
+/// `information_schema`, TOAST and temporary-table schemas), by name.
+pub async fn query_schemas(pool: &PgPool) -> Result<Vec<String>, UvgError> {
+    let schemas = sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT nspname::text
+        FROM pg_namespace
+        WHERE nspname NOT IN ('pg_catalog', 'information_schema')
+          AND nspname NOT LIKE 'pg\_toast%'
+          AND nspname NOT LIKE 'pg\_temp\_%'
+        ORDER BY nspname
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(schemas)
+}
 
 pub async fn query_tables(
     pool: &PgPool,
@@ -13,10 +31,38 @@ pub async fn query_tables(
         SELECT t.table_schema, t.table_name, t.table_type,
                obj_description(
                    (quote_ident(t.table_schema) || '.' || quote_ident(t.table_name))::regclass
-               ) AS comment
+               ) AS comment,
+               parent.relname AS partition_of,
+               srv.srvname::text AS foreign_server,
+               ARRAY(
+                   SELECT p.relname::text
+                   FROM pg_catalog.pg_inherits i
+                   JOIN pg_catalog.pg_class p ON p.oid = i.inhparent
+                   WHERE i.inhrelid = c.oid AND NOT c.relispartition
+                   ORDER BY i.inhseqno
+               ) AS inherits,
+               c.relreplident::text AS replica_identity,
+               ARRAY(
+                   SELECT p.pubname::text
+                   FROM pg_catalog.pg_publication_tables p
+                   WHERE p.schemaname = t.table_schema AND p.tablename = t.table_name
+                   ORDER BY p.pubname
+               ) AS publications,
+               COALESCE(c.relrowsecurity, false) AS row_security,
+               COALESCE(c.relforcerowsecurity, false) AS force_row_security,
+               CASE WHEN t.table_type = 'VIEW' THEN pg_get_viewdef(c.oid, true) END
+                   AS view_definition
         FROM information_schema.tables t
+        LEFT JOIN pg_catalog.pg_namespace n ON n.nspname = t.table_schema
+        LEFT JOIN pg_catalog.pg_class c
+            ON c.relnamespace = n.oid AND c.relname = t.table_name
+        LEFT JOIN pg_catalog.pg_inherits inh
+            ON inh.inhrelid = c.oid AND c.relispartition
+        LEFT JOIN pg_catalog.pg_class parent ON parent.oid = inh.inhparent
+        LEFT JOIN pg_catalog.pg_foreign_table ft ON ft.ftrelid = c.oid
+        LEFT JOIN pg_catalog.pg_foreign_server srv ON srv.oid = ft.ftserver
         WHERE t.table_schema = $1
-          AND t.table_type IN ('BASE TABLE', 'VIEW')
+          AND t.table_type IN ('BASE TABLE', 'FOREIGN', 'VIEW')
         ORDER BY t.table_name
         "#,
     )
@@ -28,7 +74,7 @@ pub async fn query_tables(
         .into_iter()
         .filter_map(|row| {
             let table_type = match row.table_type.as_str() {
-                "BASE TABLE" => TableType::Table,
+                "BASE TABLE" | "FOREIGN" => TableType::Table,
                 "VIEW" => {
                     if noviews {
                         return None;
@@ -37,10 +83,25 @@ pub async fn query_tables(
                 }
                 _ => return None,
             };
-            Some(
-                TableInfo::new(row.table_schema, row.table_name, table_type)
-                    .with_comment(row.comment),
-            )
+            Some(TableInfo {
+                partition_of: row.partition_of,
+                foreign_server: row.foreign_server,
+                inherits: row.inherits,
+                replica_identity: row
+                    .replica_identity
+                    .as_deref()
+                    .filter(|_| table_type == TableType::Table)
+                    .and_then(ReplicaIdentity::from_pg_code),
+                publications: row.publications,
+                row_security: row.row_security.then(|| RowSecurity {
+                    enabled: true,
+                    forced: row.force_row_security,
+                    policies: Vec::new(),
+                }),
+                view_definition: row.view_definition,
+                ..TableInfo::new(row.table_schema, row.table_name, table_type)
+                    .with_comment(row.comment)
+            })
         })
         .collect();
 
@@ -53,4 +114,16 @@ struct TableRow {
     table_name: String,
     table_type: String,
     comment: Option<String>,
+    partition_of: Option<String>,
+    foreign_server: Option<String>,
+    inherits: Vec<String>,
+    replica_identity: Option<String>,
+    publications: Vec<String>,
+    row_security: bool,
+    force_row_security: bool,
+    view_definition: Option<String>,
 }
+
+#[cfg(test)]
+#[path = "tables_tests.rs"]
+mod tests;
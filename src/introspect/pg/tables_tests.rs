@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn replica_identity_codes_parse() {
+    assert_eq!(
+        ReplicaIdentity::from_pg_code("d"),
+        Some(ReplicaIdentity::Default)
+    );
+    assert_eq!(
+        ReplicaIdentity::from_pg_code("n"),
+        Some(ReplicaIdentity::Nothing)
+    );
+    assert_eq!(
+        ReplicaIdentity::from_pg_code("f"),
+        Some(ReplicaIdentity::Full)
+    );
+    assert_eq!(
+        ReplicaIdentity::from_pg_code("i"),
+        Some(ReplicaIdentity::Index)
+    );
+    assert_eq!(ReplicaIdentity::from_pg_code("x"), None);
+}
@@ -1,12 +1,13 @@
 use sqlx::SqlitePool;
 
 use crate::error::UvgError;
-use crate::schema::IndexInfo;
+use crate::introspect::grouping::expression_key_note;
+use crate::schema::{IndexInfo, SortOrder};
 
 pub async fn query_indexes(
     pool: &SqlitePool,
     table_name: &str,
-) -> Result<Vec<IndexInfo>, UvgError> {
+) -> Result<(Vec<IndexInfo>, Vec<String>), UvgError> {
     // Get user-created indexes (origin = 'c') — excludes constraint-backing indexes
     let index_rows = sqlx::query_as::<_, IndexListRow>(
         r#"SELECT name, "unique", origin FROM pragma_index_list(?) WHERE origin = 'c'"#,
@@ -16,24 +17,39 @@ pub async fn query_indexes(
     .await?;
 
     let mut indexes = Vec::new();
+    let mut skipped = Vec::new();
     for idx in index_rows {
         let col_rows = sqlx::query_as::<_, IndexInfoRow>(
-            "SELECT name FROM pragma_index_info(?) ORDER BY seqno",
+            r#"SELECT name, "desc" FROM pragma_index_xinfo(?) WHERE key = 1 ORDER BY seqno"#,
         )
         .bind(&idx.name)
         .fetch_all(pool)
         .await?;
 
         // name is NULL for expression-based index terms; skip those
-        let columns: Vec<String> = col_rows.into_iter().filter_map(|r| r.name).collect();
-        if columns.is_empty() {
+        let mut index = IndexInfo::new(idx.name, idx.unique, Vec::<String>::new());
+        let mut has_expressions = false;
+        for row in col_rows {
+            let Some(name) = row.name else {
+                has_expressions = true;
+                continue;
+            };
+            if row.desc {
+                index.orderings.insert(name.clone(), SortOrder::DESC);
+            }
+            index.columns.push(name);
+        }
+        if has_expressions {
+            skipped.push(expression_key_note(&index.name, index.columns.is_empty()));
+        }
+        if index.columns.is_empty() {
             // Purely expression-based index — skip entirely
             continue;
         }
-        indexes.push(IndexInfo::new(idx.name, idx.unique, columns));
+        indexes.push(index);
     }
 
-    Ok(indexes)
+    Ok((indexes, skipped))
 }
 
 #[derive(sqlx::FromRow)]
@@ -47,4 +63,5 @@ struct IndexListRow {
 #[derive(sqlx::FromRow)]
 struct IndexInfoRow {
     name: Option<String>,
+    desc: bool,
 }
@@ -22,23 +22,26 @@ pub async fn introspect(
 ) -> Result<IntrospectedSchema, UvgError> {
     let mut all_tables = tables::query_tables(pool, noviews).await?;
 
-    all_tables.retain(|t| table_filter.matches(&t.name));
+    all_tables.retain(|t| table_filter.admits(t));
 
     for table in &mut all_tables {
         let create_sql = tables::query_create_sql(pool, &table.name).await?;
         table.columns = columns::query_columns(pool, &table.name, &create_sql).await?;
         table.constraints = constraints::query_constraints(pool, &table.name, &create_sql).await?;
-        table.indexes = indexes::query_indexes(pool, &table.name).await?;
+        (table.indexes, table.skipped) = indexes::query_indexes(pool, &table.name).await?;
     }
 
     // Sort alphabetically to match sqlacodegen output
     all_tables.sort_by(|a, b| a.name.cmp(&b.name));
+    table_filter.close_over_fks(&mut all_tables);
 
     Ok(IntrospectedSchema {
         dialect: Dialect::Sqlite,
         tables: all_tables,
         enums: vec![],
         domains: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
     })
 }
 
@@ -6,7 +6,7 @@ use crate::schema::{TableInfo, TableType};
 pub async fn query_tables(pool: &SqlitePool, noviews: bool) -> Result<Vec<TableInfo>, UvgError> {
     let rows = sqlx::query_as::<_, TableRow>(
         r#"
-        SELECT name, type
+        SELECT name, type, CASE WHEN type = 'view' THEN sql END AS view_sql
         FROM sqlite_master
         WHERE type IN ('table', 'view')
           AND name NOT LIKE 'sqlite_%'
@@ -29,7 +29,10 @@ pub async fn query_tables(pool: &SqlitePool, noviews: bool) -> Result<Vec<TableI
                 }
                 _ => return None,
             };
-            Some(TableInfo::new("main", row.name, table_type))
+            Some(TableInfo {
+                view_definition: row.view_sql,
+                ..TableInfo::new("main", row.name, table_type)
+            })
         })
         .collect();
 
@@ -53,4 +56,5 @@ struct TableRow {
     name: String,
     #[sqlx(rename = "type")]
     type_: String,
+    view_sql: Option<String>,
 }
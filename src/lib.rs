@@ -3,9 +3,16 @@
 //! The binary is intentionally a thin orchestration layer. Database
 //! connection parsing and guarded DDL application live here so every caller
 //! (including the interactive TUI) uses the same production-safety checks.
+//!
+//! To embed introspection and code generation, start from
+//! [`introspect_postgres`] (or its MySQL/SQLite siblings, or [`introspect`]
+//! with a [`connection::ConnectionConfig`]) and [`generate`].
 
+mod api;
 pub mod apply;
 pub mod apply_progress;
+pub mod cache;
+pub mod check;
 pub mod cli;
 pub mod codegen;
 pub mod connection;
@@ -13,18 +20,32 @@ pub mod db;
 pub mod ddl_typemap;
 pub mod dialect;
 pub mod error;
+pub mod extensions;
 pub mod init;
 pub mod introspect;
 pub mod migrations;
 pub mod naming;
 pub mod output;
+pub mod pii;
+pub mod primary_keys;
 pub mod profile;
+pub mod provenance;
 pub mod redaction;
 pub mod risk_classify;
+pub mod sample;
 pub mod schema;
+pub mod schema_diff;
 pub mod snapshot;
+pub mod sql_file;
+pub mod stats;
 pub mod table_filter;
 #[cfg(test)]
 mod testutil;
 pub mod tui;
 pub mod typemap;
+pub mod warnings;
+
+pub use api::{
+    generate, generate_split, introspect, introspect_mysql, introspect_postgres, introspect_sqlite,
+    GeneratorKind, IntrospectOptions,
+};
@@ -1,29 +1,62 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use tracing_subscriber::EnvFilter;
 
 use uvg::apply::{apply_inline, apply_manifest, ApplyOptions};
-use uvg::cli::{Cli, Command, ConnectionConfig, GeneratorOptions, SnapshotCommand};
+use uvg::cli::{Cli, Command, ConnectionConfig, DiffCommand, GeneratorOptions, SnapshotCommand};
 use uvg::codegen::ddl_diff::{compute_changes, render_changes};
-use uvg::codegen::{declarative, tables};
+use uvg::codegen::{ddl, dot, exec, seed, template};
+use uvg::error::{ErrorFormat, ErrorReport};
+use uvg::naming::RenameMap;
 use uvg::output::{write_split_changes, OutputContext};
+use uvg::redaction::redact_connection_url;
 use uvg::schema::{IntrospectedSchema, TableType};
-use uvg::table_filter::TableFilter;
-use uvg::{db, error, migrations, risk_classify, snapshot, tui};
+use uvg::table_filter::{drop_dangling_fks, expand_schema_patterns, DanglingFks, TableFilter};
+use uvg::typemap::overrides::TypeOverrides;
+use uvg::warnings::Warnings;
+use uvg::{
+    cache, db, error, extensions, migrations, pii, primary_keys, provenance, risk_classify, sample,
+    schema_diff, snapshot, sql_file, stats, tui, GeneratorKind,
+};
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> ExitCode {
     tracing_subscriber::fmt()
         .with_env_filter(EnvFilter::from_default_env())
         .init();
 
-    let cli = Cli::parse_with_profile()?;
+    let cli = match Cli::parse_with_profile() {
+        Ok(cli) => cli,
+        Err(err) => return report_error(&err, ErrorFormat::requested(std::env::args_os())),
+    };
+    let error_format = cli.error_format;
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => report_error(&err, error_format),
+    }
+}
+
+/// Print `err` in the requested format and map it to its exit code.
+fn report_error(err: &anyhow::Error, format: ErrorFormat) -> ExitCode {
+    let report = ErrorReport::new(err);
+    match format {
+        ErrorFormat::Text => eprintln!("Error: {err:?}"),
+        ErrorFormat::Json => match serde_json::to_string(&report) {
+            Ok(json) => eprintln!("{json}"),
+            Err(_) => eprintln!("Error: {err:?}"),
+        },
+    }
+    ExitCode::from(report.exit_code)
+}
 
+async fn run(cli: Cli) -> Result<()> {
     if let Some(command) = cli.command.as_ref() {
         return match command {
             Command::Snapshot(args) => run_snapshot(&cli, args).await,
+            Command::Diff(args) => run_diff(&cli, args).await,
             _ => migrations::run(&cli, command).await,
         };
     }
@@ -35,7 +68,10 @@ async fn main() -> Result<()> {
     validate_apply_cli(&cli)?;
 
     let table_filter = cli.table_filter()?;
-    let options = cli.generator_options();
+    let mut options = cli.generator_options();
+    if let Some(path) = &cli.rename_map {
+        options.rename_map = RenameMap::load(path)?;
+    }
     let source_input = cli
         .url
         .as_deref()
@@ -43,28 +79,80 @@ async fn main() -> Result<()> {
 
     tracing::debug!("Connecting to database...");
 
-    let schema =
+    let mut schema =
         load_schema_input(&cli, source_input, &table_filter, cli.noviews, &options).await?;
-    let dialect = schema.dialect;
+    // With a target, an empty source is a diff that drops everything.
+    if schema.tables.is_empty() && cli.target_url.is_none() {
+        return Err(error::UvgError::EmptySchema(redact_connection_url(source_input)).into());
+    }
+    sample_or_skip(&cli, source_input, &mut schema).await?;
+    stats_or_skip(&cli, source_input, &mut schema).await?;
+    pii_or_skip(&cli, &mut schema)?;
+    typemap_or_skip(&cli, &options, &mut schema)?;
+    // Stand-in keys exist only for the ORM mapping; DDL, JSON, and the
+    // diagram generators must keep describing the real database. Keyed
+    // here too so the warnings see them.
+    if cli
+        .generator
+        .parse::<GeneratorKind>()
+        .is_ok_and(GeneratorKind::maps_orm_classes)
+    {
+        primary_keys::apply(&mut schema, &options);
+    }
+    if cli.dangling_fks == DanglingFks::Drop {
+        drop_dangling_fks(&mut schema);
+    }
 
     tracing::debug!("Found {} tables/views", schema.tables.len());
 
+    let warnings = collect_warnings(&cli, &schema, &options)?;
+    generate(&cli, &schema, &options, &table_filter).await?;
+    report_warnings(&cli, &warnings)
+}
+
+/// Generate and write the output of `cli.generator` for `schema`.
+async fn generate(
+    cli: &Cli,
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
+    table_filter: &TableFilter,
+) -> Result<()> {
+    let dialect = schema.dialect;
+    let header = provenance_header(cli, options, schema, None);
+    let header = header.as_deref();
     match cli.generator.as_str() {
-        "tables" => {
-            if cli.split_tables {
-                let files = tables::generate_split(&schema, &options);
-                write_split_output(&files, &cli.outfile)?;
-            } else {
-                write_output(&tables::generate(&schema, &options), &cli.outfile)?;
-            }
+        "json" => {
+            write_output(cli, header, &snapshot::to_json(schema)?)?;
         }
-        "declarative" => {
-            if cli.split_tables {
-                let files = declarative::generate_split(&schema, &options);
-                write_split_output(&files, &cli.outfile)?;
-            } else {
-                write_output(&declarative::generate(&schema, &options), &cli.outfile)?;
-            }
+        "dot" => {
+            dot::check_focus(schema, &cli.focus_list())?;
+            write_output(
+                cli,
+                header,
+                &dot::generate(schema, &cli.focus_list(), cli.focus_depth),
+            )?;
+        }
+        "template" => {
+            let Some(ref path) = cli.template else {
+                return Err(anyhow::anyhow!(
+                    "--generator template requires --template FILE"
+                ));
+            };
+            let source = fs::read_to_string(path)
+                .with_context(|| format!("failed to read template {}", path.display()))?;
+            let content =
+                template::generate(schema, options, &path.display().to_string(), &source)?;
+            write_output(cli, header, &content)?;
+        }
+        "seed" => {
+            let target = cli.ddl_options(dialect)?.target_dialect;
+            let header = provenance_header(cli, options, schema, Some(target));
+            let header = header.as_deref();
+            let content = match cli.seed_format {
+                seed::SeedFormat::Sql => seed::generate(schema, cli.seed_rows, target),
+                seed::SeedFormat::Python => seed::generate_python(schema, cli.seed_rows, target),
+            };
+            write_output(cli, header, &content)?;
         }
         "ddl" => {
             use uvg::codegen::ddl::{DdlGenerator, DdlOutput};
@@ -74,18 +162,15 @@ async fn main() -> Result<()> {
             if cli.apply && cli.target_url.is_none() {
                 return Err(anyhow::anyhow!("--apply requires a target database URL"));
             }
-            if cli.apply && cli.target_url.as_deref().is_some_and(is_snapshot_input) {
+            if cli.apply && cli.target_url.as_deref().is_some_and(is_offline_input) {
                 return Err(anyhow::anyhow!(
-                    "--apply requires a live target database URL, not a snapshot"
+                    "--apply requires a live target database URL, not a snapshot or SQL file"
                 ));
             }
 
             // If a target URL or snapshot is provided, load it for diff.
             let target_schema = if let Some(ref target_url) = cli.target_url {
-                Some(
-                    load_schema_input(&cli, target_url, &table_filter, cli.noviews, &options)
-                        .await?,
-                )
+                Some(load_schema_input(cli, target_url, table_filter, cli.noviews, options).await?)
             } else {
                 None
             };
@@ -94,19 +179,26 @@ async fn main() -> Result<()> {
             } else {
                 cli.ddl_options(dialect)?
             };
+            let header = provenance_header(cli, options, schema, Some(ddl_opts.target_dialect));
+            let header = header.as_deref();
 
             // --out-dir: per-table diff layout. Only kicks in when there's
             // a target to diff against and --outfile is not set (--outfile
             // wins per docs/migration-output-layout.md).
             if cli.outfile.is_none() {
                 if let Some(ref out_dir) = cli.out_dir {
+                    if cli.check.is_some() {
+                        return Err(anyhow::anyhow!(
+                            "--check does not support the --out-dir migration layout"
+                        ));
+                    }
                     let Some(target) = target_schema.as_ref() else {
                         return Err(anyhow::anyhow!(
                             "--out-dir requires a target database URL to diff against"
                         ));
                     };
                     let changes =
-                        classify_or_warn(&cli, compute_changes(&schema, target, &ddl_opts)).await?;
+                        classify_or_warn(cli, compute_changes(schema, target, &ddl_opts)).await?;
                     let ctx = OutputContext::now(
                         out_dir.clone(),
                         cli.name.clone(),
@@ -157,9 +249,9 @@ async fn main() -> Result<()> {
                     ));
                 };
                 let changes =
-                    classify_or_warn(&cli, compute_changes(&schema, target, &ddl_opts)).await?;
-                let content = render_changes(&changes, dialect, ddl_opts.target_dialect);
-                write_output(&content, &cli.outfile)?;
+                    classify_or_warn(cli, compute_changes(schema, target, &ddl_opts)).await?;
+                let content = render_changes(&changes);
+                write_output(cli, header, &content)?;
                 if ddl_opts.apply {
                     let target_url = cli.target_url.as_deref().unwrap();
                     let target_config = cli.parse_target_connection(target_url)?;
@@ -179,11 +271,11 @@ async fn main() -> Result<()> {
             }
 
             let gen = DdlGenerator;
-            let ddl_output = gen.generate(&schema, target_schema.as_ref(), &ddl_opts);
+            let ddl_output = gen.generate(schema, target_schema.as_ref(), &ddl_opts);
 
             match ddl_output {
                 DdlOutput::Single(content) => {
-                    write_output(&content, &cli.outfile)?;
+                    write_output(cli, header, &content)?;
                     if ddl_opts.apply {
                         // target_url is Some: enforced by the early --apply
                         // guard at the top of this arm.
@@ -202,42 +294,130 @@ async fn main() -> Result<()> {
                         .await?;
                     }
                 }
+                DdlOutput::Split(files) if cli.check.is_some() => {
+                    write_split_output(cli, header, &files, &cli.outfile)?;
+                }
                 DdlOutput::Split(files) => match cli.outfile {
                     Some(ref dir) => {
                         let dir_path = std::path::PathBuf::from(dir);
-                        fs::create_dir_all(&dir_path)?;
+                        create_output_dir(&dir_path)?;
                         for (filename, content) in &files {
                             let path = dir_path.join(filename);
-                            fs::write(&path, content)?;
+                            if let Some(parent) = path.parent() {
+                                create_output_dir(parent)?;
+                            }
+                            write_output_file(&path, with_header(header, content))?;
                             tracing::info!("Written {}", path.display());
                         }
                     }
                     None => {
                         for (filename, content) in &files {
                             println!("-- File: {filename}");
-                            println!("{content}");
+                            println!("{}", with_header(header, content));
                         }
                     }
                 },
             }
         }
-        other => {
-            return Err(error::UvgError::UnknownGenerator(other.to_string()).into());
+        name => {
+            if let Some(command) = exec::plugin_command(name) {
+                let content = exec::generate(command, &snapshot::to_json(schema)?)?;
+                write_output(cli, header, &content)?;
+                return Ok(());
+            }
+            let kind: GeneratorKind = name.parse()?;
+            let split = package_dir(cli)
+                .and_then(|dir| Some((uvg::generate_split(schema, kind, options)?, dir)));
+            match split {
+                Some((files, dir)) => write_split_output(cli, header, &files, &dir)?,
+                None => write_output(cli, header, &uvg::generate(schema, kind, options))?,
+            }
         }
     };
 
     Ok(())
 }
 
+/// Everything `cli.generator` loses in rendering `schema`, plus what
+/// introspection skipped. Diffs (`ddl` with a target) only report the
+/// latter.
+fn collect_warnings(
+    cli: &Cli,
+    schema: &IntrospectedSchema,
+    options: &GeneratorOptions,
+) -> Result<Warnings> {
+    let mut warnings = Warnings::new();
+    warnings.introspection(schema);
+    warnings.inferred_keys(schema);
+    warnings.replica_identity(schema);
+    if cli.generator != "ddl" || cli.target_url.is_none() {
+        warnings.dangling_fks(schema);
+    }
+    if matches!(
+        cli.generator.as_str(),
+        "tables" | "declarative" | "repositories" | "fastapi" | "ddl"
+    ) {
+        warnings.extensions(schema);
+    }
+    if matches!(
+        cli.generator.as_str(),
+        "tables" | "declarative" | "repositories" | "fastapi"
+    ) {
+        warnings.fk_cycles(schema);
+    }
+    match cli.generator.as_str() {
+        "tables" => warnings.models(schema, options, false),
+        "declarative" | "repositories" | "fastapi" => warnings.models(schema, options, true),
+        "ddl" if cli.target_url.is_none() => {
+            ddl::collect_warnings(schema, &cli.ddl_options(schema.dialect)?, &mut warnings)
+        }
+        _ => {}
+    }
+    Ok(warnings)
+}
+
+/// Print the warning summary once the output is written; `--strict` turns
+/// any warning into a failed run.
+fn report_warnings(cli: &Cli, warnings: &Warnings) -> Result<()> {
+    if warnings.is_empty() {
+        return Ok(());
+    }
+    eprintln!("{}", warnings.summary());
+    if cli.strict {
+        return Err(error::UvgError::Strict(warnings.len()).into());
+    }
+    Ok(())
+}
+
 async fn run_snapshot(cli: &Cli, args: &SnapshotCommand) -> Result<()> {
     let table_filter = cli.table_filter()?;
     let options = cli.generator_options();
-    let schema = load_schema_input(cli, &args.url, &table_filter, cli.noviews, &options).await?;
+    let mut schema =
+        load_schema_input(cli, &args.url, &table_filter, cli.noviews, &options).await?;
+    sample_or_skip(cli, &args.url, &mut schema).await?;
+    stats_or_skip(cli, &args.url, &mut schema).await?;
     snapshot::write(&args.output, &schema)?;
     eprintln!("uvg: wrote snapshot {}", args.output.display());
     Ok(())
 }
 
+async fn run_diff(cli: &Cli, args: &DiffCommand) -> Result<()> {
+    let table_filter = cli.table_filter()?;
+    let options = cli.generator_options();
+    let source = load_schema_input(cli, &args.source, &table_filter, cli.noviews, &options).await?;
+    let target = load_schema_input(cli, &args.target, &table_filter, cli.noviews, &options).await?;
+    let diff = schema_diff::diff_schemas(&source, &target);
+    let report = match args.format.as_str() {
+        "json" => serde_json::to_string_pretty(&diff)? + "\n",
+        _ => schema_diff::render_text(&diff),
+    };
+    match args.output {
+        Some(ref path) => write_output_file(path, report)?,
+        None => print!("{report}"),
+    }
+    Ok(())
+}
+
 async fn load_schema_input(
     cli: &Cli,
     raw: &str,
@@ -245,19 +425,38 @@ async fn load_schema_input(
     noviews: bool,
     options: &GeneratorOptions,
 ) -> Result<IntrospectedSchema> {
-    if let Some(path) = raw.strip_prefix('@') {
-        if path.is_empty() {
+    if let Some(path) = snapshot::input_path(raw) {
+        if path.as_os_str().is_empty() {
             return Err(anyhow::anyhow!("snapshot input must be @<path>"));
         }
-        let mut schema = snapshot::load(Path::new(path))?;
+        let mut schema = snapshot::load(path)?;
+        retain_admitted(&mut schema, table_filter, noviews);
+        return Ok(schema);
+    }
+
+    if let Some(path) = sql_file::input_path(raw) {
+        let mut schema = sql_file::load(path)?;
+        let available: Vec<String> = schema.tables.iter().map(|t| t.schema.clone()).collect();
+        let schemas = expand_schema_patterns(
+            &cli.schema_list_or(schema.dialect.default_schema()),
+            &available,
+        )?;
         schema.tables.retain(|table| {
-            (!noviews || table.table_type != TableType::View) && table_filter.matches(&table.name)
+            schemas.contains(&table.schema)
+                && (!noviews || table.table_type != TableType::View)
+                && table_filter.admits(table)
         });
+        table_filter.close_over_fks(&mut schema.tables);
         return Ok(schema);
     }
 
     let config = cli.parse_connection_url(raw)?;
     let schemas = schemas_for_config(cli, &config);
+    if cli.from_cache || cli.refresh {
+        let mut schema = load_cached(cli, raw, config, &schemas, options).await?;
+        retain_admitted(&mut schema, table_filter, noviews);
+        return Ok(schema);
+    }
     tracing::debug!("Introspecting schema...");
     db::introspect_with_config(
         config,
@@ -268,10 +467,131 @@ async fn load_schema_input(
         cli.introspect_concurrency,
     )
     .await
+    .map_err(Into::into)
+}
+
+/// `--from-cache` / `--refresh`: serve the unfiltered introspection of
+/// `raw` + `schemas` from the cache, querying the database only on a miss or
+/// when refreshing. The caller applies table filters afterwards.
+async fn load_cached(
+    cli: &Cli,
+    raw: &str,
+    config: ConnectionConfig,
+    schemas: &[String],
+    options: &GeneratorOptions,
+) -> Result<IntrospectedSchema> {
+    let path = cache::entry_path(&cache::default_cache_dir()?, raw, schemas);
+    let cached = if cli.refresh {
+        None
+    } else {
+        cache::load(&path)?
+    };
+    if let Some(schema) = cached {
+        eprintln!("uvg: using cached introspection {}", path.display());
+        return Ok(schema);
+    }
+
+    tracing::debug!("Introspecting schema...");
+    let schema = db::introspect_with_config(
+        config,
+        schemas,
+        &TableFilter::allow_all(),
+        false,
+        options,
+        cli.introspect_concurrency,
+    )
+    .await?;
+    cache::store(&path, &schema)?;
+    eprintln!("uvg: cached introspection {}", path.display());
+    Ok(schema)
+}
+
+/// Drop tables excluded by the table filter or `--noviews` from a schema
+/// loaded without them applied (snapshots and cache entries), then apply
+/// `--follow-fks`.
+fn retain_admitted(schema: &mut IntrospectedSchema, table_filter: &TableFilter, noviews: bool) {
+    schema.tables.retain(|table| {
+        (!noviews || table.table_type != TableType::View) && table_filter.admits(table)
+    });
+    table_filter.close_over_fks(&mut schema.tables);
+}
+
+/// Run `--sample` against a live source and print the advisory report.
+/// Snapshots carry whatever samples they were written with.
+async fn sample_or_skip(cli: &Cli, raw: &str, schema: &mut IntrospectedSchema) -> Result<()> {
+    let Some(rows) = cli.sample else {
+        return Ok(());
+    };
+    if is_offline_input(raw) {
+        tracing::warn!("--sample ignored for offline input {raw}");
+        return Ok(());
+    }
+    let config = cli.parse_connection_url(raw)?;
+    sample::sample_schema(&config, schema, rows).await?;
+    let report = sample::report(schema);
+    if report.is_empty() {
+        eprintln!("uvg: sampled {rows} row(s) per table; nothing to flag");
+    } else {
+        eprint!("uvg: sample advisories ({rows} row(s) per table):\n{report}");
+    }
+    Ok(())
+}
+
+/// Run `--stats` against a live source. Snapshots carry whatever
+/// statistics they were written with.
+async fn stats_or_skip(cli: &Cli, raw: &str, schema: &mut IntrospectedSchema) -> Result<()> {
+    if !cli.stats {
+        return Ok(());
+    }
+    if is_offline_input(raw) {
+        tracing::warn!("--stats ignored for offline input {raw}");
+        return Ok(());
+    }
+    let config = cli.parse_connection_url(raw)?;
+    let columns = stats::collect_stats(&config, schema).await?;
+    eprintln!("uvg: captured statistics for {columns} column(s)");
+    Ok(())
 }
 
-fn is_snapshot_input(raw: &str) -> bool {
-    raw.starts_with('@')
+/// Apply `--pii` annotations and write the `--pii-report` file.
+fn pii_or_skip(cli: &Cli, schema: &mut IntrospectedSchema) -> Result<()> {
+    if cli.pii {
+        pii::annotate(schema);
+    }
+    if let Some(path) = &cli.pii_report {
+        let findings = pii::detect(schema);
+        fs::write(path, pii::report(&findings))?;
+        eprintln!(
+            "uvg: wrote PII report {} ({} column(s) flagged)",
+            path.display(),
+            findings.len()
+        );
+    }
+    Ok(())
+}
+
+/// Apply `--options citext` and `--typemap` overrides to the columns they
+/// match.
+fn typemap_or_skip(
+    cli: &Cli,
+    options: &GeneratorOptions,
+    schema: &mut IntrospectedSchema,
+) -> Result<()> {
+    // Applied first so a `--typemap` entry for `citext` still wins.
+    if options.citext {
+        TypeOverrides::citext_package().apply(schema);
+    }
+    if let Some(path) = &cli.typemap {
+        let overrides = TypeOverrides::load(path)?;
+        let count = overrides.apply(schema);
+        tracing::debug!("--typemap overrode {count} column type(s)");
+    }
+    Ok(())
+}
+
+/// Snapshot or SQL-file input: there is no database to query.
+fn is_offline_input(raw: &str) -> bool {
+    snapshot::input_path(raw).is_some() || sql_file::input_path(raw).is_some()
 }
 
 fn schemas_for_config(cli: &Cli, config: &ConnectionConfig) -> Vec<String> {
@@ -295,9 +615,9 @@ fn validate_apply_cli(cli: &Cli) -> Result<()> {
     let Some(target_url) = cli.target_url.as_deref() else {
         return Err(anyhow::anyhow!("--apply requires a target database URL"));
     };
-    if is_snapshot_input(target_url) {
+    if is_offline_input(target_url) {
         return Err(anyhow::anyhow!(
-            "--apply requires a live target database URL, not a snapshot"
+            "--apply requires a live target database URL, not a snapshot or SQL file"
         ));
     }
     if cli.split_tables {
@@ -342,31 +662,82 @@ async fn classify_or_warn(
     }
 }
 
-fn write_split_output(files: &[(String, String)], outfile: &Option<String>) -> anyhow::Result<()> {
+/// Where split Python output goes, if requested: `--split-tables` (or
+/// `--split-by-schema`) writes into `--outfile`, else `--out-dir`, else
+/// stdout; `--out-dir` alone writes into its directory.
+fn package_dir(cli: &Cli) -> Option<Option<String>> {
+    let out_dir = cli.out_dir.as_ref().map(|dir| dir.display().to_string());
+    if cli.split_tables || cli.split_by_schema {
+        return Some(cli.outfile.clone().or(out_dir));
+    }
+    out_dir.map(Some)
+}
+
+fn write_split_output(
+    cli: &Cli,
+    header: Option<&str>,
+    files: &[(String, String)],
+    outfile: &Option<String>,
+) -> anyhow::Result<()> {
+    if let Some(dir) = check_target(cli, outfile)? {
+        let mut drift = false;
+        for (filename, content) in files {
+            drift |= !check_file(&dir.join(filename), content)?;
+        }
+        return finish_check(drift, &dir);
+    }
     match outfile {
         Some(ref dir) => {
             let dir_path = std::path::PathBuf::from(dir);
-            fs::create_dir_all(&dir_path)?;
+            create_output_dir(&dir_path)?;
             for (filename, content) in files {
                 let path = dir_path.join(filename);
-                fs::write(&path, content)?;
+                write_output_file(&path, with_header(header, content))?;
                 tracing::info!("Written {}", path.display());
             }
         }
         None => {
             for (filename, content) in files {
                 println!("# --- {filename} ---");
-                print!("{content}");
+                print!("{}", with_header(header, content));
             }
         }
     }
     Ok(())
 }
 
-fn write_output(output: &str, outfile: &Option<String>) -> anyhow::Result<()> {
-    match outfile {
+/// Write one generated file; failures report as generation errors.
+fn write_output_file(path: impl AsRef<Path>, content: impl AsRef<[u8]>) -> Result<()> {
+    let path = path.as_ref();
+    fs::write(path, content).map_err(|source| {
+        error::UvgError::Output {
+            path: path.display().to_string(),
+            source,
+        }
+        .into()
+    })
+}
+
+fn create_output_dir(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    fs::create_dir_all(path).map_err(|source| {
+        error::UvgError::Output {
+            path: path.display().to_string(),
+            source,
+        }
+        .into()
+    })
+}
+
+fn write_output(cli: &Cli, header: Option<&str>, output: &str) -> anyhow::Result<()> {
+    if let Some(path) = check_target(cli, &cli.outfile)? {
+        let drift = !check_file(&path, output)?;
+        return finish_check(drift, &path);
+    }
+    let output = with_header(header, output);
+    match cli.outfile {
         Some(ref path) => {
-            fs::write(path, output)?;
+            write_output_file(path, &output)?;
             tracing::info!("Output written to {path}");
         }
         None => {
@@ -376,6 +747,85 @@ fn write_output(output: &str, outfile: &Option<String>) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// The provenance header for `cli.generator`'s output, unless the format
+/// has no comments or `--options noheader` is set. `--options
+/// extensions_header` adds the extensions `schema` needs; `target` is the
+/// dialect `ddl` and `seed` output is written for.
+fn provenance_header(
+    cli: &Cli,
+    options: &GeneratorOptions,
+    schema: &IntrospectedSchema,
+    target: Option<uvg::dialect::Dialect>,
+) -> Option<String> {
+    if options.noheader {
+        return None;
+    }
+    let prefix = if cli.generator == "seed" && cli.seed_format == seed::SeedFormat::Python {
+        "#"
+    } else {
+        provenance::comment_prefix(&cli.generator)?
+    };
+    let mut provenance = provenance::Provenance::now(cli, schema.dialect);
+    provenance.target = target;
+    if options.extensions_header {
+        provenance.extensions = extensions::required(schema)
+            .iter()
+            .map(|ext| match ext.installed {
+                Some(installed) => format!("{} {}", ext.name, installed.version),
+                None => ext.name.to_string(),
+            })
+            .collect();
+    }
+    Some(provenance.header(prefix))
+}
+
+fn with_header(header: Option<&str>, content: &str) -> String {
+    format!("{}{content}", header.unwrap_or_default())
+}
+
+/// Where `--check` compares: its FILE argument, else `--outfile` (or the
+/// package directory for split output). `None` when not checking.
+fn check_target(cli: &Cli, outfile: &Option<String>) -> anyhow::Result<Option<PathBuf>> {
+    match &cli.check {
+        None => Ok(None),
+        Some(Some(path)) => Ok(Some(path.clone())),
+        Some(None) => outfile
+            .as_ref()
+            .map(|path| Some(PathBuf::from(path)))
+            .ok_or_else(|| anyhow::anyhow!("--check needs a FILE or --outfile to compare against")),
+    }
+}
+
+/// Print a unified diff if `path` differs from `generated`. Returns whether
+/// they match; a missing file compares as empty.
+fn check_file(path: &Path, generated: &str) -> anyhow::Result<bool> {
+    let existing = match fs::read_to_string(path) {
+        Ok(existing) => existing,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    // The header changes on every run; only the body counts as drift.
+    let existing = provenance::strip_header(&existing);
+    match uvg::check::unified_diff(&path.display().to_string(), existing, generated) {
+        None => Ok(true),
+        Some(diff) => {
+            print!("{diff}");
+            Ok(false)
+        }
+    }
+}
+
+fn finish_check(drift: bool, path: &Path) -> anyhow::Result<()> {
+    if drift {
+        return Err(anyhow::anyhow!(
+            "{} is out of date with the schema",
+            path.display()
+        ));
+    }
+    eprintln!("uvg: {} is up to date", path.display());
+    Ok(())
+}
+
 #[cfg(test)]
 #[path = "main_tests.rs"]
 mod tests;
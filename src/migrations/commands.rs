@@ -54,10 +54,13 @@ pub(super) async fn run_revision(cli: &Cli, args: &RevisionCommand) -> Result<()
     let options = DdlOptions {
         target_dialect,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: gen_opts.noindexes,
         noconstraints: gen_opts.noconstraints,
         nocomments: gen_opts.nocomments,
+        notablecomments: gen_opts.notablecomments,
+        nocolumncomments: gen_opts.nocolumncomments,
     };
     let changes = compute_changes(&source, &target, &options);
     if changes.is_empty() {
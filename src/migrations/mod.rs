@@ -53,6 +53,7 @@ pub async fn run(cli: &Cli, command: &Command) -> Result<()> {
         }
         Command::History(args) => run_history(cli, args).await,
         Command::Snapshot(_) => unreachable!("snapshot is handled before migration dispatch"),
+        Command::Diff(_) => unreachable!("diff is handled before migration dispatch"),
     }
 }
 
@@ -88,18 +88,24 @@ pub(super) async fn ensure_version_table(config: &ConnectionConfig) -> Result<()
         ConnectionConfig::Mssql {
             host,
             port,
+            instance,
             database,
             user,
             password,
             trust_cert,
+            encrypt,
+            auth,
         } => {
             let mut client = crate::introspect::mssql::connect(
                 host,
                 *port,
+                instance.as_deref(),
                 database,
                 user,
                 password,
+                *auth,
                 *trust_cert,
+                *encrypt,
             )
             .await?;
             client
@@ -164,18 +170,24 @@ pub(super) async fn current_revision(config: &ConnectionConfig) -> Result<Option
         ConnectionConfig::Mssql {
             host,
             port,
+            instance,
             database,
             user,
             password,
             trust_cert,
+            encrypt,
+            auth,
         } => {
             let mut client = crate::introspect::mssql::connect(
                 host,
                 *port,
+                instance.as_deref(),
                 database,
                 user,
                 password,
+                *auth,
                 *trust_cert,
+                *encrypt,
             )
             .await?;
             let rows = client
@@ -238,18 +250,24 @@ pub(super) async fn version_table_exists(config: &ConnectionConfig) -> Result<bo
         ConnectionConfig::Mssql {
             host,
             port,
+            instance,
             database,
             user,
             password,
             trust_cert,
+            encrypt,
+            auth,
         } => {
             let mut client = crate::introspect::mssql::connect(
                 host,
                 *port,
+                instance.as_deref(),
                 database,
                 user,
                 password,
+                *auth,
                 *trust_cert,
+                *encrypt,
             )
             .await?;
             let rows = client
@@ -343,18 +361,24 @@ pub(super) async fn record_revision(
         ConnectionConfig::Mssql {
             host,
             port,
+            instance,
             database,
             user,
             password,
             trust_cert,
+            encrypt,
+            auth,
         } => {
             let mut client = crate::introspect::mssql::connect(
                 host,
                 *port,
+                instance.as_deref(),
                 database,
                 user,
                 password,
+                *auth,
                 *trust_cert,
+                *encrypt,
             )
             .await?;
             // Tiberius has no borrow-friendly transaction handle, so drive the
@@ -426,18 +450,24 @@ pub(super) async fn clear_revision(config: &ConnectionConfig) -> Result<()> {
         ConnectionConfig::Mssql {
             host,
             port,
+            instance,
             database,
             user,
             password,
             trust_cert,
+            encrypt,
+            auth,
         } => {
             let mut client = crate::introspect::mssql::connect(
                 host,
                 *port,
+                instance.as_deref(),
                 database,
                 user,
                 password,
+                *auth,
                 *trust_cert,
+                *encrypt,
             )
             .await?;
             client
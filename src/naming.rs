@@ -1,10 +1,207 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
 use heck::ToUpperCamelCase;
+use serde::Deserialize;
+
+use crate::cli::GeneratorOptions;
+use crate::schema::{ColumnInfo, IntrospectedSchema, SequenceInfo, TableInfo};
+
+/// Explicit names from a `--rename-map` file.
+///
+/// ```toml
+/// [tables]
+/// tbl_usr = "User"
+/// "legacy.tbl_ord" = "Order"
+///
+/// [columns]
+/// "tbl_usr.usr_nm" = "name"
+/// ```
+///
+/// `tables` maps `schema.table` or `table` to a model class name;
+/// `columns` maps `schema.table.column` or `table.column` to an attribute
+/// name. Schema-qualified keys win.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RenameMap {
+    #[serde(default)]
+    tables: BTreeMap<String, String>,
+    #[serde(default)]
+    columns: BTreeMap<String, String>,
+}
+
+impl RenameMap {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read rename map {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("failed to parse rename map {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let map: Self = toml::from_str(text)?;
+        for (key, name) in map.tables.iter().chain(&map.columns) {
+            if !is_python_identifier(name) {
+                bail!("`{key}`: `{name}` is not a valid Python identifier");
+            }
+        }
+        Ok(map)
+    }
+
+    /// The class name mapped for `table`, if any.
+    pub fn class_name(&self, table: &TableInfo) -> Option<&str> {
+        self.tables
+            .get(&format!("{}.{}", table.schema, table.name))
+            .or_else(|| self.tables.get(&table.name))
+            .map(String::as_str)
+    }
+
+    /// The attribute name mapped for `col` of `table`, if any.
+    pub fn attr_name(&self, table: &TableInfo, col: &ColumnInfo) -> Option<&str> {
+        self.columns
+            .get(&format!("{}.{}.{}", table.schema, table.name, col.name))
+            .or_else(|| self.columns.get(&format!("{}.{}", table.name, col.name)))
+            .map(String::as_str)
+    }
+}
+
+/// Whether `raw` is usable as a Python identifier.
+pub fn is_python_identifier(raw: &str) -> bool {
+    let mut chars = raw.chars();
+    chars.next().is_some_and(|c| c == '_' || c.is_alphabetic())
+        && chars.all(|c| c == '_' || c.is_alphanumeric())
+}
 
 /// Convert a table name to a Python class name (e.g. "user_profiles" -> "UserProfile").
 pub fn table_to_class_name(table_name: &str) -> String {
     table_name.to_upper_camel_case()
 }
 
+/// Class name for a table's model. With the `use_inflect` option the last
+/// word of the table name is singularized first ("users" -> "User",
+/// "order_items" -> "OrderItem"), as sqlacodegen does.
+pub fn model_class_name(table_name: &str, use_inflect: bool) -> String {
+    if use_inflect {
+        table_to_class_name(&singularize_last_word(table_name))
+    } else {
+        table_to_class_name(table_name)
+    }
+}
+
+/// Plural -> singular for words that differ from the regular `-s` rules.
+const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    ("people", "person"),
+    ("men", "man"),
+    ("women", "woman"),
+    ("children", "child"),
+    ("oxen", "ox"),
+    ("mice", "mouse"),
+    ("geese", "goose"),
+    ("feet", "foot"),
+    ("teeth", "tooth"),
+    ("indices", "index"),
+    ("vertices", "vertex"),
+    ("matrices", "matrix"),
+    ("criteria", "criterion"),
+    ("phenomena", "phenomenon"),
+    ("analyses", "analysis"),
+    ("crises", "crisis"),
+    ("theses", "thesis"),
+    ("quizzes", "quiz"),
+    ("statuses", "status"),
+    ("buses", "bus"),
+    ("campuses", "campus"),
+    ("viruses", "virus"),
+    ("bonuses", "bonus"),
+    ("aliases", "alias"),
+    ("caches", "cache"),
+    ("knives", "knife"),
+    ("wives", "wife"),
+    ("lives", "life"),
+    ("wolves", "wolf"),
+    ("leaves", "leaf"),
+    ("halves", "half"),
+    ("shelves", "shelf"),
+    ("thieves", "thief"),
+    ("movies", "movie"),
+    ("cookies", "cookie"),
+    ("shoes", "shoe"),
+    ("toes", "toe"),
+];
+
+/// Words whose plural and singular are the same.
+const UNCOUNTABLE: &[&str] = &[
+    "data",
+    "metadata",
+    "media",
+    "series",
+    "species",
+    "news",
+    "information",
+    "equipment",
+    "sheep",
+    "fish",
+    "deer",
+    "staff",
+];
+
+/// Singularize an English noun. Words that already look singular
+/// ("address", "status", "analysis") come back unchanged.
+pub fn singularize(word: &str) -> String {
+    let lower = word.to_lowercase();
+    let singular = if UNCOUNTABLE.contains(&lower.as_str()) {
+        return word.to_string();
+    } else if let Some((_, s)) = IRREGULAR_PLURALS.iter().find(|(p, _)| *p == lower) {
+        s.to_string()
+    } else if lower.len() > 3 && lower.ends_with("ies") {
+        format!("{}y", &lower[..lower.len() - 3])
+    } else if ["sses", "shes", "ches", "xes", "zzes", "oes"]
+        .iter()
+        .any(|suffix| lower.ends_with(suffix))
+    {
+        lower[..lower.len() - 2].to_string()
+    } else if lower.ends_with('s')
+        && !["ss", "us", "is"]
+            .iter()
+            .any(|suffix| lower.ends_with(suffix))
+    {
+        lower[..lower.len() - 1].to_string()
+    } else {
+        return word.to_string();
+    };
+    match_case(word, &singular)
+}
+
+/// Singularize only the last word of a snake_case or CamelCase name.
+fn singularize_last_word(name: &str) -> String {
+    let chars: Vec<(usize, char)> = name.char_indices().collect();
+    let split = chars
+        .windows(2)
+        .rev()
+        .find(|w| w[0].1 == '_' || (w[0].1.is_lowercase() && w[1].1.is_uppercase()))
+        .map_or(0, |w| w[1].0);
+    let (head, last) = name.split_at(split);
+    format!("{head}{}", singularize(last))
+}
+
+/// Give `singular` the capitalization of `original` (all-caps or leading
+/// capital).
+fn match_case(original: &str, singular: &str) -> String {
+    if original.len() > 1 && !original.chars().any(char::is_lowercase) {
+        singular.to_uppercase()
+    } else if original.starts_with(char::is_uppercase) {
+        let mut chars = singular.chars();
+        chars
+            .next()
+            .map(|c| c.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    } else {
+        singular.to_string()
+    }
+}
+
 /// Convert a table name to a variable name for the tables generator (e.g. "users" -> "t_users").
 /// Non-identifier characters (hyphens, spaces, etc.) are replaced with underscores.
 pub fn table_to_variable_name(table_name: &str) -> String {
@@ -21,16 +218,148 @@ pub fn table_to_variable_name(table_name: &str) -> String {
     format!("t_{sanitized}")
 }
 
-/// Python keywords and builtins that conflict with SQLAlchemy attribute names.
-const PYTHON_RESERVED: &[&str] = &[
-    // Python keywords
+/// Module-level variable holding a standalone sequence's `Sequence()`,
+/// schema-prefixed outside the dialect's default schema:
+/// `billing.invoice_seq` -> `billing_invoice_seq`.
+pub fn sequence_variable_name(schema: &IntrospectedSchema, sequence: &SequenceInfo) -> String {
+    if sequence.schema.is_empty() || sequence.schema == schema.dialect.default_schema() {
+        column_to_attr_name(&sequence.name)
+    } else {
+        column_to_attr_name(&format!("{}_{}", sequence.schema, sequence.name))
+    }
+}
+
+/// Class name for `table`'s model: the `--rename-map` entry if there is
+/// one, otherwise derived from the table name, schema-prefixed when needed
+/// (see `table_name_stem`): `archive.orders` -> `ArchiveOrders`. With
+/// `use_inflect`, a table keeps its plural name when the singular belongs
+/// to another table too (`user` and `users`).
+pub fn table_class_name(
+    schema: &IntrospectedSchema,
+    table: &TableInfo,
+    options: &GeneratorOptions,
+) -> String {
+    if let Some(name) = options.rename_map.class_name(table) {
+        return name.to_string();
+    }
+    let stem = table_name_stem(schema, table, options);
+    let plain = model_class_name(&stem, false);
+    if !options.use_inflect {
+        return plain;
+    }
+    let singular = model_class_name(&stem, true);
+    let collides = singular != plain
+        && schema
+            .tables
+            .iter()
+            .filter(|t| !std::ptr::eq(*t, table))
+            .any(|t| {
+                let stem = table_name_stem(schema, t, options);
+                model_class_name(&stem, false) == singular
+                    || model_class_name(&stem, true) == singular
+            });
+    if collides {
+        plain
+    } else {
+        singular
+    }
+}
+
+/// Class name for the `TypedDict` describing a sampled JSON column:
+/// `orders.payload` -> `OrdersPayload`, schema-prefixed like the table's
+/// other names. A name that another table's model class or an enum class
+/// already takes gets a `Json` suffix (`OrderItemsJson`).
+pub fn typed_dict_name(
+    schema: &IntrospectedSchema,
+    table: &TableInfo,
+    column: &str,
+    options: &GeneratorOptions,
+) -> String {
+    let stem = table_name_stem(schema, table, options);
+    let name = format!("{stem}_{column}").to_upper_camel_case();
+    let taken = schema
+        .tables
+        .iter()
+        .any(|t| table_class_name(schema, t, options) == name)
+        || schema
+            .enums
+            .iter()
+            .any(|e| e.name.to_upper_camel_case() == name);
+    if taken {
+        format!("{name}Json")
+    } else {
+        name
+    }
+}
+
+/// `Table()` variable name for `table`, schema-prefixed when needed (see
+/// `table_name_stem`): `archive.orders` -> `t_archive_orders`.
+pub fn table_variable_name(
+    schema: &IntrospectedSchema,
+    table: &TableInfo,
+    options: &GeneratorOptions,
+) -> String {
+    table_to_variable_name(&table_name_stem(schema, table, options))
+}
+
+/// The table name generated identifiers derive from, minus the first
+/// matching `--strip-prefix`. A table outside the dialect's default schema
+/// is prefixed with its schema (`archive_orders`) when another introspected
+/// table shares its name, or always with the `schema_prefix` option.
+/// Default-schema tables keep the bare name.
+fn table_name_stem<'a>(
+    schema: &IntrospectedSchema,
+    table: &'a TableInfo,
+    options: &GeneratorOptions,
+) -> Cow<'a, str> {
+    let in_default = table.schema.is_empty() || table.schema == schema.dialect.default_schema();
+    let prefixed = !in_default
+        && (options.schema_prefix
+            || schema
+                .tables
+                .iter()
+                .any(|t| t.name == table.name && t.schema != table.schema));
+    let name = strip_table_prefix(&table.name, &options.strip_prefixes);
+    if prefixed {
+        Cow::Owned(format!("{}_{name}", table.schema))
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// `name` without the first of `prefixes` it starts with. A name that is
+/// nothing but the prefix is kept whole.
+pub fn strip_table_prefix<'a>(name: &'a str, prefixes: &[String]) -> &'a str {
+    prefixes
+        .iter()
+        .filter_map(|p| name.strip_prefix(p.as_str()))
+        .find(|rest| !rest.is_empty())
+        .unwrap_or(name)
+}
+
+/// Python keywords, which cannot be attribute names.
+const PYTHON_KEYWORDS: &[&str] = &[
     "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
     "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
     "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
-    "with", "yield", // SQLAlchemy reserved attribute names
-    "metadata", "registry",
+    "with", "yield",
+];
+
+/// Attribute names SQLAlchemy's declarative base reserves.
+const SQLALCHEMY_RESERVED: &[&str] = &[
+    "metadata",
+    "registry",
+    "__tablename__",
+    "__table_args__",
+    "__table__",
+    "__mapper_args__",
 ];
 
+/// Whether `name` is a Python keyword.
+pub fn is_python_keyword(name: &str) -> bool {
+    PYTHON_KEYWORDS.contains(&name)
+}
+
 /// Sanitize a column name into a valid Python attribute name.
 /// Returns the sanitized name. If it differs from the input, the caller should
 /// emit the original column name as an explicit first argument to mapped_column().
@@ -60,7 +389,7 @@ pub fn column_to_attr_name(col_name: &str) -> String {
     }
 
     // Append underscore for Python reserved words and SQLAlchemy conflicts
-    if PYTHON_RESERVED.contains(&sanitized.as_str()) {
+    if is_python_keyword(&sanitized) || SQLALCHEMY_RESERVED.contains(&sanitized.as_str()) {
         sanitized.push('_');
     }
 
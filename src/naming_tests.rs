@@ -1,4 +1,5 @@
 use super::*;
+use crate::testutil::*;
 
 #[test]
 fn test_table_to_class_name() {
@@ -13,3 +14,159 @@ fn test_table_to_variable_name() {
     assert_eq!(table_to_variable_name("users"), "t_users");
     assert_eq!(table_to_variable_name("order_items"), "t_order_items");
 }
+
+#[test]
+fn test_table_names_prefix_schema_on_collision() {
+    let schema = schema_pg(vec![
+        table("orders").build(),
+        table("orders").schema("archive").build(),
+        table("invoices").schema("archive").build(),
+    ]);
+    let options = GeneratorOptions::default();
+    let [public_orders, archive_orders, invoices] = [0, 1, 2].map(|i| &schema.tables[i]);
+
+    assert_eq!(table_class_name(&schema, public_orders, &options), "Orders");
+    assert_eq!(
+        table_class_name(&schema, archive_orders, &options),
+        "ArchiveOrders"
+    );
+    assert_eq!(
+        table_variable_name(&schema, archive_orders, &options),
+        "t_archive_orders"
+    );
+    assert_eq!(table_class_name(&schema, invoices, &options), "Invoices");
+
+    let options = GeneratorOptions {
+        schema_prefix: true,
+        use_inflect: true,
+        ..Default::default()
+    };
+    assert_eq!(table_class_name(&schema, public_orders, &options), "Order");
+    assert_eq!(
+        table_class_name(&schema, invoices, &options),
+        "ArchiveInvoice"
+    );
+}
+
+#[test]
+fn test_typed_dict_name_avoids_class_names() {
+    let schema = schema_pg(vec![
+        table("order").build(),
+        table("order_items").build(),
+        table("orders").build(),
+        table("orders").schema("archive").build(),
+    ]);
+    let options = GeneratorOptions::default();
+    let [order, _, public_orders, archive_orders] = [0, 1, 2, 3].map(|i| &schema.tables[i]);
+
+    assert_eq!(
+        typed_dict_name(&schema, order, "items", &options),
+        "OrderItemsJson"
+    );
+    assert_eq!(
+        typed_dict_name(&schema, public_orders, "payload", &options),
+        "OrdersPayload"
+    );
+    assert_eq!(
+        typed_dict_name(&schema, archive_orders, "payload", &options),
+        "ArchiveOrdersPayload"
+    );
+}
+
+#[test]
+fn test_singularize() {
+    for (plural, singular) in [
+        ("users", "user"),
+        ("categories", "category"),
+        ("addresses", "address"),
+        ("boxes", "box"),
+        ("matches", "match"),
+        ("heroes", "hero"),
+        ("people", "person"),
+        ("children", "child"),
+        ("indices", "index"),
+        ("statuses", "status"),
+        ("status", "status"),
+        ("analysis", "analysis"),
+        ("data", "data"),
+        ("user", "user"),
+        ("Users", "User"),
+        ("PEOPLE", "PERSON"),
+    ] {
+        assert_eq!(singularize(plural), singular, "{plural}");
+    }
+}
+
+#[test]
+fn test_model_class_name_with_inflect() {
+    assert_eq!(model_class_name("users", false), "Users");
+    assert_eq!(model_class_name("users", true), "User");
+    assert_eq!(model_class_name("order_items", true), "OrderItem");
+    assert_eq!(model_class_name("OrderItems", true), "OrderItem");
+    assert_eq!(model_class_name("simple_people", true), "SimplePerson");
+    assert_eq!(model_class_name("status", true), "Status");
+}
+
+#[test]
+fn test_strip_prefix() {
+    let prefixes = ["tbl_".to_string(), "t_".to_string()];
+    assert_eq!(strip_table_prefix("tbl_users", &prefixes), "users");
+    assert_eq!(strip_table_prefix("t_orders", &prefixes), "orders");
+    assert_eq!(strip_table_prefix("tbl_", &prefixes), "tbl_");
+    assert_eq!(strip_table_prefix("accounts", &prefixes), "accounts");
+
+    let schema = schema_pg(vec![table("tbl_order_items").build()]);
+    let options = GeneratorOptions {
+        strip_prefixes: vec!["tbl_".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        table_class_name(&schema, &schema.tables[0], &options),
+        "OrderItems"
+    );
+    assert_eq!(
+        table_variable_name(&schema, &schema.tables[0], &options),
+        "t_order_items"
+    );
+}
+
+#[test]
+fn test_rename_map() {
+    let map = RenameMap::parse(
+        r#"
+        [tables]
+        tbl_usr = "User"
+        "legacy.tbl_usr" = "LegacyUser"
+
+        [columns]
+        "tbl_usr.usr_nm" = "name"
+        "#,
+    )
+    .unwrap();
+    let schema = schema_pg(vec![
+        table("tbl_usr")
+            .column(col("usr_nm").udt("text").build())
+            .build(),
+        table("tbl_usr").schema("legacy").build(),
+    ]);
+    let options = GeneratorOptions {
+        rename_map: map,
+        strip_prefixes: vec!["tbl_".to_string()],
+        ..Default::default()
+    };
+    let [current, legacy] = [0, 1].map(|i| &schema.tables[i]);
+
+    assert_eq!(table_class_name(&schema, current, &options), "User");
+    assert_eq!(table_class_name(&schema, legacy, &options), "LegacyUser");
+    assert_eq!(
+        options.rename_map.attr_name(current, &current.columns[0]),
+        Some("name")
+    );
+}
+
+#[test]
+fn test_rename_map_rejects_invalid_names() {
+    assert!(RenameMap::parse("[tables]\nusers = \"my-users\"\n").is_err());
+    assert!(RenameMap::parse("[columns]\n\"users.id\" = \"1d\"\n").is_err());
+    assert!(RenameMap::parse("[models]\nusers = \"User\"\n").is_err());
+}
@@ -0,0 +1,206 @@
+//! Personal-data detection (`--pii` / `--pii-report`).
+//!
+//! Flags columns that likely hold PII from their name and declared type
+//! only — no table data is read. A name match alone is not enough: the
+//! column type must also be able to hold that kind of value, so
+//! `email_verified BOOLEAN` or `birth_count INTEGER` are not flagged.
+//!
+//! Detection is a starting point for a compliance review, not a guarantee;
+//! the report lists the rule that matched so reviewers can judge each hit.
+
+use heck::ToSnakeCase;
+
+use crate::ddl_typemap::{to_canonical, CanonicalType};
+use crate::dialect::Dialect;
+use crate::schema::{ColumnInfo, IntrospectedSchema, PiiCategory};
+
+/// One flagged column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiiFinding {
+    pub schema: String,
+    pub table: String,
+    pub column: String,
+    pub category: PiiCategory,
+    /// Human-readable rule that matched, e.g. `name contains "email"`.
+    pub reason: String,
+}
+
+/// Name tokens (after snake-casing) that mark a category on their own.
+const TOKEN_RULES: &[(PiiCategory, &[&str])] = &[
+    (PiiCategory::Email, &["email", "mail"]),
+    (
+        PiiCategory::Phone,
+        &["phone", "telephone", "mobile", "cellphone", "fax", "msisdn"],
+    ),
+    (
+        PiiCategory::NationalId,
+        &["ssn", "nino", "passport", "nationalid", "taxid", "tin"],
+    ),
+    (
+        PiiCategory::DateOfBirth,
+        &["dob", "birthday", "birthdate", "birth"],
+    ),
+    (
+        PiiCategory::Name,
+        &[
+            "firstname",
+            "lastname",
+            "fullname",
+            "surname",
+            "givenname",
+            "middlename",
+            "familyname",
+            "maidenname",
+        ],
+    ),
+    (
+        PiiCategory::Address,
+        &["street", "address", "zip", "zipcode", "postcode", "postal"],
+    ),
+    (PiiCategory::IpAddress, &["ip", "ipaddr", "ipaddress"]),
+    (
+        PiiCategory::Financial,
+        &["iban", "ccn", "creditcard", "cardnumber", "bic", "swift"],
+    ),
+];
+
+/// Adjacent token pairs that mark a category (`first_name`, `social_security`).
+const PAIR_RULES: &[(PiiCategory, &str, &str)] = &[
+    (PiiCategory::Name, "first", "name"),
+    (PiiCategory::Name, "last", "name"),
+    (PiiCategory::Name, "full", "name"),
+    (PiiCategory::Name, "given", "name"),
+    (PiiCategory::Name, "middle", "name"),
+    (PiiCategory::Name, "family", "name"),
+    (PiiCategory::Name, "maiden", "name"),
+    (PiiCategory::Email, "e", "mail"),
+    (PiiCategory::NationalId, "social", "security"),
+    (PiiCategory::NationalId, "national", "id"),
+    (PiiCategory::NationalId, "tax", "id"),
+    (PiiCategory::NationalId, "drivers", "license"),
+    (PiiCategory::NationalId, "driver", "license"),
+    (PiiCategory::IpAddress, "ip", "address"),
+    (PiiCategory::Financial, "credit", "card"),
+    (PiiCategory::Financial, "card", "number"),
+    (PiiCategory::Financial, "account", "number"),
+    (PiiCategory::Financial, "bank", "account"),
+    (PiiCategory::Financial, "routing", "number"),
+];
+
+/// Classify one column, returning the category and the rule that matched.
+pub fn classify(col: &ColumnInfo, dialect: Dialect) -> Option<(PiiCategory, String)> {
+    let udt = col.udt_name.to_ascii_lowercase();
+    if dialect == Dialect::Postgres && matches!(udt.as_str(), "inet" | "cidr") {
+        return Some((PiiCategory::IpAddress, format!("type is {udt}")));
+    }
+
+    let snake = col.name.to_snake_case();
+    let tokens: Vec<&str> = snake.split('_').filter(|t| !t.is_empty()).collect();
+    // `ip_address` and `email_address` are not postal addresses.
+    let qualified_address = tokens
+        .windows(2)
+        .any(|w| w[1] == "address" && matches!(w[0], "ip" | "email" | "mail" | "mac"));
+
+    let mut hit = PAIR_RULES.iter().find_map(|&(category, first, second)| {
+        tokens
+            .windows(2)
+            .any(|w| w[0] == first && w[1] == second)
+            .then(|| (category, format!("name contains \"{first}_{second}\"")))
+    });
+    if hit.is_none() {
+        hit = TOKEN_RULES.iter().find_map(|&(category, words)| {
+            if category == PiiCategory::Address && qualified_address {
+                return None;
+            }
+            let word = words.iter().find(|w| tokens.contains(w))?;
+            Some((category, format!("name contains \"{word}\"")))
+        });
+    }
+
+    let (category, reason) = hit?;
+    type_fits(category, &to_canonical(col, dialect)).then_some((category, reason))
+}
+
+/// Whether a column of type `ty` can plausibly hold `category` data.
+fn type_fits(category: PiiCategory, ty: &CanonicalType) -> bool {
+    let text = matches!(
+        ty,
+        CanonicalType::Varchar { .. } | CanonicalType::Char { .. } | CanonicalType::Text
+    );
+    let number = matches!(
+        ty,
+        CanonicalType::Integer | CanonicalType::BigInt | CanonicalType::Decimal { .. }
+    );
+    match category {
+        PiiCategory::Email | PiiCategory::Name | PiiCategory::Address => text,
+        PiiCategory::Phone | PiiCategory::NationalId | PiiCategory::Financial => text || number,
+        PiiCategory::DateOfBirth => {
+            text || matches!(ty, CanonicalType::Date | CanonicalType::Timestamp { .. })
+        }
+        PiiCategory::IpAddress => text || matches!(ty, CanonicalType::Bytes { .. }),
+    }
+}
+
+/// Every flagged column in `schema`, in table and column order.
+pub fn detect(schema: &IntrospectedSchema) -> Vec<PiiFinding> {
+    let mut findings = Vec::new();
+    for table in &schema.tables {
+        for col in &table.columns {
+            if let Some((category, reason)) = classify(col, schema.dialect) {
+                findings.push(PiiFinding {
+                    schema: table.schema.clone(),
+                    table: table.name.clone(),
+                    column: col.name.clone(),
+                    category,
+                    reason,
+                });
+            }
+        }
+    }
+    findings
+}
+
+/// Set [`ColumnInfo::pii`] on every flagged column so the generators
+/// annotate it.
+pub fn annotate(schema: &mut IntrospectedSchema) {
+    let dialect = schema.dialect;
+    for table in &mut schema.tables {
+        for col in &mut table.columns {
+            col.pii = classify(col, dialect).map(|(category, _)| category);
+        }
+    }
+}
+
+/// Standalone CSV report: one row per flagged column.
+pub fn report(findings: &[PiiFinding]) -> String {
+    let mut out = String::from("schema,table,column,category,reason\n");
+    for f in findings {
+        let fields = [
+            f.schema.as_str(),
+            f.table.as_str(),
+            f.column.as_str(),
+            f.category.as_str(),
+            f.reason.as_str(),
+        ];
+        let row = fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        out.push_str(&row);
+        out.push('\n');
+    }
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+#[path = "pii_tests.rs"]
+mod tests;
@@ -0,0 +1,77 @@
+use super::*;
+use crate::testutil::*;
+
+fn category(col: ColumnInfo) -> Option<PiiCategory> {
+    classify(&col, Dialect::Postgres).map(|(category, _)| category)
+}
+
+#[test]
+fn classify_matches_names_with_fitting_types() {
+    let text = |name: &str| col(name).udt("varchar").max_length(255).build();
+    assert_eq!(category(text("email")), Some(PiiCategory::Email));
+    assert_eq!(category(text("EmailAddress")), Some(PiiCategory::Email));
+    assert_eq!(category(text("first_name")), Some(PiiCategory::Name));
+    assert_eq!(category(text("surname")), Some(PiiCategory::Name));
+    assert_eq!(category(text("mobile_phone")), Some(PiiCategory::Phone));
+    assert_eq!(category(text("ssn")), Some(PiiCategory::NationalId));
+    assert_eq!(category(text("ip_address")), Some(PiiCategory::IpAddress));
+    assert_eq!(category(text("street")), Some(PiiCategory::Address));
+    assert_eq!(category(text("iban")), Some(PiiCategory::Financial));
+    assert_eq!(
+        category(col("date_of_birth").udt("date").build()),
+        Some(PiiCategory::DateOfBirth)
+    );
+}
+
+#[test]
+fn classify_requires_a_fitting_type() {
+    assert_eq!(category(col("email_verified").udt("bool").build()), None);
+    assert_eq!(category(col("birth_count").udt("int4").build()), None);
+    assert_eq!(category(col("name").udt("text").build()), None);
+    assert_eq!(category(col("mac_address").udt("text").build()), None);
+}
+
+#[test]
+fn classify_uses_network_types() {
+    let hit = classify(
+        &col("last_seen_from").udt("inet").build(),
+        Dialect::Postgres,
+    );
+    assert_eq!(
+        hit,
+        Some((PiiCategory::IpAddress, "type is inet".to_string()))
+    );
+}
+
+#[test]
+fn annotate_flags_generated_columns() {
+    let mut schema = schema_pg(vec![table("users")
+        .column(col("id").build())
+        .column(col("email").udt("text").build())
+        .pk("users_pkey", &["id"])
+        .build()]);
+    annotate(&mut schema);
+    let cols = &schema.tables[0].columns;
+    assert_eq!(cols[0].pii, None);
+    assert_eq!(cols[1].pii, Some(PiiCategory::Email));
+
+    let output =
+        crate::codegen::declarative::generate(&schema, &crate::cli::GeneratorOptions::default());
+    assert!(output.contains(
+        "email: Mapped[str] = mapped_column(Text, nullable=False, \
+         info={'pii': True, 'pii_category': 'email'})"
+    ));
+}
+
+#[test]
+fn report_is_csv() {
+    let schema = schema_pg(vec![table("users")
+        .column(col("id").build())
+        .column(col("phone").udt("text").build())
+        .build()]);
+    assert_eq!(
+        report(&detect(&schema)),
+        "schema,table,column,category,reason\n\
+         public,users,phone,phone,\"name contains \"\"phone\"\"\"\n"
+    );
+}
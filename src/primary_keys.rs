@@ -0,0 +1,280 @@
+//! Primary keys the database does not declare.
+//!
+//! An ORM class needs a primary key, so the declarative generator renders
+//! tables without one, and every view, as `Table()`. Two opt-in steps give
+//! them a stand-in key instead:
+//!
+//! - `--options synthesize_pk` promotes a table's first single-column
+//!   unique constraint or unique index over a NOT NULL column, or failing
+//!   that every column as a composite key.
+//! - `--view-pk VIEW.COLUMN` names a view's key outright, and
+//!   `--options infer_view_pk` guesses it: the primary key of the one
+//!   table a simple view selects from, when the view keeps those columns,
+//!   or else a column named `id`.
+//!
+//! Both apply only to the generators that map ORM classes
+//! ([`crate::GeneratorKind::maps_orm_classes`]), which [`crate::generate`]
+//! keys through [`apply`]; `tables`, `ddl`, `json`, `dbml` and the rest
+//! describe the keys the database actually declares.
+//!
+//! Guesses are recorded in [`TableInfo::inferred_keys`], so they show up as
+//! a `# WARNING:` line above the class and in the warning summary.
+
+use crate::cli::GeneratorOptions;
+use crate::codegen::has_primary_key;
+use crate::schema::{ConstraintInfo, ConstraintType, IntrospectedSchema, TableInfo, TableType};
+
+/// Whether `options` ask for any stand-in key.
+pub fn requested(options: &GeneratorOptions) -> bool {
+    options.synthesize_pk || options.infer_view_pk || !options.view_pks.is_empty()
+}
+
+/// Apply the stand-in keys `options` ask for: [`synthesize`], then
+/// [`views`]. Keyed tables and views are left alone, so applying twice
+/// changes nothing.
+pub fn apply(schema: &mut IntrospectedSchema, options: &GeneratorOptions) {
+    if options.synthesize_pk {
+        synthesize(schema);
+    }
+    if options.infer_view_pk || !options.view_pks.is_empty() {
+        views(schema, &options.view_pks, options.infer_view_pk);
+    }
+}
+
+/// Give every table without a primary key a synthesized one
+/// (`synthesize_pk`). Views are left to [`views`].
+pub fn synthesize(schema: &mut IntrospectedSchema) {
+    for table in &mut schema.tables {
+        if table.table_type != TableType::Table || has_primary_key(&table.constraints) {
+            continue;
+        }
+        let Some((columns, source)) = candidate_key(table) else {
+            continue;
+        };
+        table.inferred_keys.push(format!(
+            "no primary key in the database; {} ({source}) promoted to primary key",
+            columns.join(", ")
+        ));
+        let name = format!("{}_pkey", table.name);
+        table
+            .constraints
+            .push(ConstraintInfo::primary_key(name, columns));
+    }
+}
+
+/// The columns to promote and a short note on why they were chosen.
+fn candidate_key(table: &TableInfo) -> Option<(Vec<String>, &'static str)> {
+    let not_null = |name: &str| {
+        table
+            .columns
+            .iter()
+            .any(|col| col.name == name && !col.is_nullable)
+    };
+    let unique_constraints = table
+        .constraints
+        .iter()
+        .filter(|c| c.constraint_type == ConstraintType::Unique)
+        .map(|c| &c.columns);
+    let unique_indexes = table
+        .indexes
+        .iter()
+        .filter(|index| index.is_unique && index.expressions.is_empty())
+        .map(|index| &index.columns);
+    let unique = unique_constraints
+        .chain(unique_indexes)
+        .find(|columns| matches!(columns.as_slice(), [only] if not_null(only)));
+    if let Some(columns) = unique {
+        return Some((columns.clone(), "unique, not null"));
+    }
+    if table.columns.is_empty() {
+        return None;
+    }
+    Some((
+        table.columns.iter().map(|col| col.name.clone()).collect(),
+        "all columns",
+    ))
+}
+
+/// Give views their primary keys: `overrides` (`--view-pk` entries, each
+/// `view.column` or `schema.view.column`; several entries for one view make
+/// a composite key) first, then, with `infer`, a guessed key for the rest.
+pub fn views(schema: &mut IntrospectedSchema, overrides: &[String], infer: bool) {
+    let mut named: Vec<(usize, Vec<String>)> = Vec::new();
+    for entry in overrides {
+        let target = entry.rsplit_once('.').and_then(|(view, column)| {
+            let index = schema.tables.iter().position(|t| {
+                t.table_type == TableType::View
+                    && (view == t.name || view == format!("{}.{}", t.schema, t.name))
+                    && t.columns.iter().any(|col| col.name == column)
+            })?;
+            Some((index, column.to_string()))
+        });
+        let Some((index, column)) = target else {
+            tracing::warn!("--view-pk {entry}: no such view column");
+            continue;
+        };
+        match named.iter_mut().find(|(i, _)| *i == index) {
+            Some((_, columns)) => columns.push(column),
+            None => named.push((index, vec![column])),
+        }
+    }
+    for (index, columns) in named {
+        let view = &mut schema.tables[index];
+        let name = format!("{}_pkey", view.name);
+        view.constraints
+            .retain(|c| c.constraint_type != ConstraintType::PrimaryKey);
+        view.constraints
+            .push(ConstraintInfo::primary_key(name, columns));
+    }
+    if !infer {
+        return;
+    }
+
+    let guesses: Vec<(usize, Vec<String>, String)> = schema
+        .tables
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| t.table_type == TableType::View && !has_primary_key(&t.constraints))
+        .filter_map(|(index, view)| {
+            let (columns, source) = guess_view_key(schema, view)?;
+            Some((index, columns, source))
+        })
+        .collect();
+    for (index, columns, source) in guesses {
+        let view = &mut schema.tables[index];
+        view.inferred_keys.push(format!(
+            "views have no primary key; {} ({source}) used as its key",
+            columns.join(", ")
+        ));
+        let name = format!("{}_pkey", view.name);
+        view.constraints
+            .push(ConstraintInfo::primary_key(name, columns));
+    }
+}
+
+/// The key of a view whose definition selects from one table and keeps
+/// that table's primary key columns, or else its `id` column.
+fn guess_view_key(schema: &IntrospectedSchema, view: &TableInfo) -> Option<(Vec<String>, String)> {
+    let has_column = |name: &str| view.columns.iter().any(|col| col.name == name);
+    let base = view
+        .view_definition
+        .as_deref()
+        .and_then(base_table)
+        .and_then(|path| {
+            let (table_schema, name) = match path.as_slice() {
+                [.., table_schema, name] => (Some(table_schema.as_str()), name.as_str()),
+                [name] => (None, name.as_str()),
+                [] => return None,
+            };
+            let matches = |t: &&TableInfo| {
+                t.table_type == TableType::Table && t.name.eq_ignore_ascii_case(name)
+            };
+            let same_schema = table_schema.unwrap_or(&view.schema);
+            schema
+                .tables
+                .iter()
+                .filter(matches)
+                .find(|t| t.schema.eq_ignore_ascii_case(same_schema))
+                .or_else(|| schema.tables.iter().find(matches))
+        });
+    if let Some(table) = base {
+        let key = table
+            .constraints
+            .iter()
+            .find(|c| c.constraint_type == ConstraintType::PrimaryKey)
+            .filter(|pk| pk.columns.iter().all(|col| has_column(col)));
+        if let Some(pk) = key {
+            return Some((pk.columns.clone(), format!("primary key of {}", table.name)));
+        }
+    }
+    view.columns
+        .iter()
+        .find(|col| col.name.eq_ignore_ascii_case("id"))
+        .map(|col| (vec![col.name.clone()], "named id".to_string()))
+}
+
+/// The dotted name of the only table a view reads, for definitions of the
+/// form `SELECT ... FROM name [[AS] alias] [WHERE ...]`. Joins, set
+/// operations, several `FROM` items, or a subquery source give `None`:
+/// their rows need not be unique per base-table key.
+fn base_table(definition: &str) -> Option<Vec<String>> {
+    let tokens = tokenize(definition);
+    let keyword =
+        |token: &(String, bool), word: &str| !token.1 && token.0.eq_ignore_ascii_case(word);
+    if tokens.iter().any(|t| {
+        ["join", "union", "intersect", "except"]
+            .iter()
+            .any(|word| keyword(t, word))
+    }) {
+        return None;
+    }
+    let mut depth = 0usize;
+    let mut from = None;
+    for (i, token) in tokens.iter().enumerate() {
+        match token.0.as_str() {
+            "(" if !token.1 => depth += 1,
+            ")" if !token.1 => depth = depth.saturating_sub(1),
+            _ if depth == 0 && keyword(token, "from") => {
+                from = Some(i + 1);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let mut rest = tokens[from?..].iter().peekable();
+    let mut path = Vec::new();
+    loop {
+        let (part, quoted) = rest.next()?;
+        if !quoted && !part.starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+        path.push(part.clone());
+        if rest.next_if(|t| t.0 == "." && !t.1).is_none() {
+            break;
+        }
+    }
+    // An alias may follow; a comma after it starts another FROM item.
+    rest.next_if(|t| keyword(t, "as"));
+    rest.next_if(|t| t.1 || t.0.starts_with(|c: char| c.is_alphabetic()));
+    if rest.next_if(|t| t.0 == "," && !t.1).is_some() {
+        return None;
+    }
+    Some(path)
+}
+
+/// Split SQL into identifiers and words (quoted ones unquoted and flagged
+/// `true`) and single punctuation characters. String literals are dropped.
+fn tokenize(sql: &str) -> Vec<(String, bool)> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        let close = match c {
+            '"' => Some('"'),
+            '`' => Some('`'),
+            '[' => Some(']'),
+            '\'' => Some('\''),
+            _ => None,
+        };
+        if let Some(close) = close {
+            let text: String = chars.by_ref().take_while(|&ch| ch != close).collect();
+            if c != '\'' {
+                tokens.push((text, true));
+            }
+        } else if c.is_alphanumeric() || c == '_' || c == '$' {
+            let mut word = c.to_string();
+            while let Some(ch) =
+                chars.next_if(|ch| ch.is_alphanumeric() || *ch == '_' || *ch == '$')
+            {
+                word.push(ch);
+            }
+            tokens.push((word, false));
+        } else if !c.is_whitespace() {
+            tokens.push((c.to_string(), false));
+        }
+    }
+    tokens
+}
+
+#[cfg(test)]
+#[path = "primary_keys_tests.rs"]
+mod tests;
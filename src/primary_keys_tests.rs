@@ -0,0 +1,259 @@
+use super::*;
+use crate::cli::GeneratorOptions;
+use crate::codegen::declarative;
+use crate::testutil::{col, schema_pg, table};
+
+fn primary_key(table: &TableInfo) -> Option<&Vec<String>> {
+    table
+        .constraints
+        .iter()
+        .find(|c| c.constraint_type == ConstraintType::PrimaryKey)
+        .map(|c| &c.columns)
+}
+
+#[test]
+fn promotes_a_unique_not_null_column() {
+    let mut schema = schema_pg(vec![table("events")
+        .column(col("code").nullable().build())
+        .column(col("event_id").build())
+        .column(col("payload").nullable().build())
+        .unique("events_code_key", &["code"])
+        .index("events_event_id_idx", &["event_id"], true)
+        .build()]);
+    synthesize(&mut schema);
+
+    let events = &schema.tables[0];
+    // `code` is unique but nullable, so the unique index wins.
+    assert_eq!(primary_key(events).unwrap(), &["event_id"]);
+    assert_eq!(
+        events.inferred_keys,
+        ["no primary key in the database; event_id (unique, not null) promoted to primary key"]
+    );
+}
+
+#[test]
+fn falls_back_to_every_column() {
+    let mut schema = schema_pg(vec![table("audit_log")
+        .column(col("at").build())
+        .column(col("message").nullable().build())
+        .build()]);
+    synthesize(&mut schema);
+
+    let log = &schema.tables[0];
+    assert_eq!(primary_key(log).unwrap(), &["at", "message"]);
+    assert!(
+        log.inferred_keys[0].contains("(all columns)"),
+        "{:?}",
+        log.inferred_keys
+    );
+}
+
+#[test]
+fn leaves_keyed_tables_and_views_alone() {
+    let mut schema = schema_pg(vec![
+        table("users")
+            .column(col("id").build())
+            .pk("users_pkey", &["id"])
+            .build(),
+        table("active_users")
+            .table_type(TableType::View)
+            .column(col("id").build())
+            .build(),
+    ]);
+    synthesize(&mut schema);
+
+    assert_eq!(schema.tables[0].constraints.len(), 1);
+    assert!(primary_key(&schema.tables[1]).is_none());
+    assert!(schema.tables.iter().all(|t| t.inferred_keys.is_empty()));
+}
+
+#[test]
+fn synthesized_keys_map_as_classes() {
+    let mut schema = schema_pg(vec![table("audit_log")
+        .column(col("id").build())
+        .column(col("message").nullable().build())
+        .unique("audit_log_id_key", &["id"])
+        .build()]);
+    synthesize(&mut schema);
+
+    let output = declarative::generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("# WARNING: no primary key in the database; id (unique, not null) promoted to primary key\nclass AuditLog(Base):"), "{output}");
+    assert!(
+        output.contains("id: Mapped[int] = mapped_column(Integer, primary_key=True)"),
+        "{output}"
+    );
+}
+
+fn orders() -> TableInfo {
+    table("orders")
+        .column(col("order_no").build())
+        .column(col("status").nullable().build())
+        .pk("orders_pkey", &["order_no"])
+        .build()
+}
+
+#[test]
+fn views_take_the_key_of_their_base_table() {
+    let mut schema = schema_pg(vec![
+        orders(),
+        table("open_orders")
+            .column(col("order_no").nullable().build())
+            .column(col("status").nullable().build())
+            .view(" SELECT o.order_no,\n    o.status\n   FROM public.orders o\n  WHERE o.status = 'open'::text;")
+            .build(),
+    ]);
+    views(&mut schema, &[], true);
+
+    let view = &schema.tables[1];
+    assert_eq!(primary_key(view).unwrap(), &["order_no"]);
+    assert_eq!(
+        view.inferred_keys,
+        ["views have no primary key; order_no (primary key of orders) used as its key"]
+    );
+}
+
+#[test]
+fn joined_views_fall_back_to_an_id_column() {
+    let mut schema = schema_pg(vec![
+        orders(),
+        table("order_lines")
+            .column(col("id").build())
+            .column(col("order_no").build())
+            .pk("order_lines_pkey", &["id"])
+            .build(),
+        table("line_report")
+            .column(col("id").nullable().build())
+            .column(col("order_no").nullable().build())
+            .view("SELECT l.id, o.order_no FROM order_lines l JOIN orders o ON o.order_no = l.order_no")
+            .build(),
+        table("status_counts")
+            .column(col("status").nullable().build())
+            .view("SELECT status FROM orders, order_lines")
+            .build(),
+    ]);
+    views(&mut schema, &[], true);
+
+    assert_eq!(primary_key(&schema.tables[2]).unwrap(), &["id"]);
+    assert!(schema.tables[2].inferred_keys[0].contains("(named id)"));
+    assert!(primary_key(&schema.tables[3]).is_none());
+}
+
+#[test]
+fn base_table_reads_simple_definitions_only() {
+    assert_eq!(
+        base_table("CREATE VIEW v AS SELECT [id] FROM [dbo].[users] WHERE [active] = 1").unwrap(),
+        ["dbo", "users"]
+    );
+    assert_eq!(
+        base_table("select `shop`.`users`.`id` AS `id` from `shop`.`users`").unwrap(),
+        ["shop", "users"]
+    );
+    assert_eq!(
+        base_table("SELECT (SELECT max(id) FROM b) AS m, x FROM a AS t").unwrap(),
+        ["a"]
+    );
+    assert!(base_table("SELECT id FROM (SELECT id FROM a) s").is_none());
+    assert!(base_table("SELECT id FROM a UNION SELECT id FROM b").is_none());
+}
+
+#[test]
+fn view_pk_overrides_name_the_key() {
+    let mut schema = schema_pg(vec![
+        orders(),
+        table("order_summary")
+            .column(col("order_no").nullable().build())
+            .column(col("status").nullable().build())
+            .view("SELECT order_no, status FROM orders")
+            .build(),
+    ]);
+    let overrides = [
+        "public.order_summary.order_no".to_string(),
+        "order_summary.status".to_string(),
+        "order_summary.missing".to_string(),
+    ];
+    views(&mut schema, &overrides, false);
+
+    let view = &schema.tables[1];
+    assert_eq!(primary_key(view).unwrap(), &["order_no", "status"]);
+    assert!(view.inferred_keys.is_empty());
+
+    let output = declarative::generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("class OrderSummary(Base):"), "{output}");
+}
+
+#[test]
+fn association_tables_keep_their_table_imports() {
+    let mut schema = schema_pg(vec![
+        table("students")
+            .column(col("id").build())
+            .pk("students_pkey", &["id"])
+            .build(),
+        table("courses")
+            .column(col("id").build())
+            .pk("courses_pkey", &["id"])
+            .build(),
+        table("enrollments")
+            .column(col("student_id").build())
+            .column(col("course_id").build())
+            .fk(
+                "enrollments_student_fkey",
+                &["student_id"],
+                "students",
+                &["id"],
+            )
+            .fk(
+                "enrollments_course_fkey",
+                &["course_id"],
+                "courses",
+                &["id"],
+            )
+            .build(),
+    ]);
+    synthesize(&mut schema);
+
+    // Every table now has a primary key, but the association table still
+    // renders as `Table(...)`.
+    let output = declarative::generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("t_enrollments = Table("), "{output}");
+    assert!(
+        output.contains("from sqlalchemy import Column, ForeignKey, Integer, Table"),
+        "{output}"
+    );
+}
+
+#[test]
+fn keyed_views_keep_association_table_imports() {
+    let mut schema = schema_pg(vec![
+        orders(),
+        table("tags")
+            .column(col("id").build())
+            .pk("tags_pkey", &["id"])
+            .build(),
+        table("order_tags")
+            .column(col("order_no").build())
+            .column(col("tag_id").build())
+            .pk("order_tags_pkey", &["order_no", "tag_id"])
+            .fk(
+                "order_tags_order_fkey",
+                &["order_no"],
+                "orders",
+                &["order_no"],
+            )
+            .fk("order_tags_tag_fkey", &["tag_id"], "tags", &["id"])
+            .build(),
+        table("open_orders")
+            .column(col("order_no").nullable().build())
+            .view("SELECT order_no FROM orders WHERE status = 'open'")
+            .build(),
+    ]);
+    views(&mut schema, &[], true);
+    assert!(schema.tables.iter().all(|t| primary_key(t).is_some()));
+
+    let output = declarative::generate(&schema, &GeneratorOptions::default());
+    assert!(output.contains("class OpenOrders(Base):"), "{output}");
+    assert!(output.contains("t_order_tags = Table("), "{output}");
+    assert!(
+        output.contains("from sqlalchemy import Column, ForeignKey, Integer, Table"),
+        "{output}"
+    );
+}
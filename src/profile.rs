@@ -15,10 +15,12 @@ const PROFILED_ARGS: &[&str] = &[
     "generator",
     "target_dialect",
     "split_tables",
+    "split_by_schema",
     "apply",
     "no_parse_check",
     "tables",
     "exclude_tables",
+    "exclude_kinds",
     "schemas",
     "noviews",
     "options",
@@ -42,12 +44,14 @@ struct ProfileDefaults {
     target_dialect: Option<String>,
     tables: Option<Vec<String>>,
     exclude_tables: Option<Vec<String>>,
+    exclude_kinds: Option<Vec<String>>,
     schemas: Option<Vec<String>>,
     options: Option<Vec<String>>,
     outfile: Option<String>,
     out_dir: Option<PathBuf>,
     name: Option<String>,
     split_tables: Option<bool>,
+    split_by_schema: Option<bool>,
     apply: Option<bool>,
     no_parse_check: Option<bool>,
     noviews: Option<bool>,
@@ -179,6 +183,12 @@ fn apply_requested_profile_from_path(
         sources,
         "exclude_tables",
     );
+    fill_option(
+        &mut cli.exclude_kinds,
+        profile.exclude_kinds.as_deref().map(csv),
+        sources,
+        "exclude_kinds",
+    );
     fill_option(
         &mut cli.schemas,
         profile.schemas.as_deref().map(csv),
@@ -210,6 +220,12 @@ fn apply_requested_profile_from_path(
         sources,
         "split_tables",
     );
+    fill_bool(
+        &mut cli.split_by_schema,
+        profile.split_by_schema,
+        sources,
+        "split_by_schema",
+    );
     fill_bool(&mut cli.apply, profile.apply, sources, "apply");
     fill_bool(
         &mut cli.no_parse_check,
@@ -10,21 +10,57 @@ fn default_cli(profile: &str) -> Cli {
         generator: "declarative".to_string(),
         target_dialect: None,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         progress: ProgressMode::Auto,
+        error_format: crate::error::ErrorFormat::Text,
         apply_retries: 3,
         no_parse_check: false,
         risk_classify: false,
+        seed_rows: 10,
+        seed_format: crate::codegen::seed::SeedFormat::Sql,
+        focus: None,
+        focus_depth: 1,
+        template: None,
+        sample: None,
+        stats: false,
+        info_properties: None,
+        pii: false,
+        pii_report: None,
         introspect_concurrency: crate::cli::DEFAULT_INTROSPECT_CONCURRENCY,
+        from_cache: false,
+        refresh: false,
         tables: None,
         exclude_tables: None,
+        exclude_kinds: None,
+        follow_fks: None,
+        follow_fks_both: false,
+        dangling_fks: crate::table_filter::DanglingFks::Keep,
         schemas: None,
+        all_schemas: false,
         noviews: false,
+        views_only: false,
+        include_partitions: false,
+        include_foreign_tables: false,
         options: None,
         outfile: None,
+        check: None,
+        strict: false,
         out_dir: None,
         name: None,
+        typemap: None,
+        base_class: None,
+        base_name: None,
+        rename_map: None,
+        strip_prefix: None,
+        view_pk: None,
         trust_cert: false,
+        encrypt: crate::connection::MssqlEncrypt::Required,
+        auth: crate::connection::MssqlAuth::Sql,
+        sslmode: None,
+        sslrootcert: None,
+        sslcert: None,
+        sslkey: None,
         interactive: false,
     }
 }
@@ -0,0 +1,189 @@
+//! Provenance header for generated files: the uvg version, when and from
+//! what the file was generated, and the command line that produced it
+//! with credentials redacted. `--options noheader` leaves it out.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::cli::Cli;
+use crate::dialect::Dialect;
+use crate::output::format_utc_iso8601;
+use crate::redaction::redact_connection_url;
+
+/// First-line marker of the header; `--check` skips the block it starts.
+const MARKER: &str = "Generated by uvg";
+
+/// Where and how a set of generated files was produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    pub version: String,
+    pub generated_at: String,
+    pub dialect: Dialect,
+    /// Dialect the output is written for, when it can differ from the
+    /// source (`ddl`, `seed`).
+    pub target: Option<Dialect>,
+    /// `--schemas` as given; empty for the dialect's default schema.
+    pub schemas: Vec<String>,
+    pub tables: Option<String>,
+    pub exclude_tables: Option<String>,
+    pub exclude_kinds: Option<String>,
+    /// The command line, credentials redacted.
+    pub command: String,
+    /// Extensions the schema needs (`--options extensions_header`), e.g.
+    /// `citext` or `postgis 3.4.2`.
+    pub extensions: Vec<String>,
+}
+
+impl Provenance {
+    /// Provenance for this process: its arguments, the current time.
+    pub fn now(cli: &Cli, dialect: Dialect) -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Self::at(cli, dialect, std::env::args(), secs)
+    }
+
+    /// Provenance at a fixed epoch second for the given arguments. Used by
+    /// tests; production callers use `now()`.
+    pub fn at(
+        cli: &Cli,
+        dialect: Dialect,
+        args: impl IntoIterator<Item = String>,
+        epoch_secs: u64,
+    ) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            generated_at: format_utc_iso8601(epoch_secs),
+            dialect,
+            target: None,
+            schemas: if cli.all_schemas {
+                vec!["*".to_string()]
+            } else {
+                cli.schemas.as_deref().map_or_else(Vec::new, |raw| {
+                    raw.split(',').map(|s| s.trim().to_string()).collect()
+                })
+            },
+            tables: cli.tables.clone(),
+            exclude_tables: cli.exclude_tables.clone(),
+            exclude_kinds: cli.exclude_kinds.clone(),
+            command: command_line(args),
+            extensions: Vec::new(),
+        }
+    }
+
+    /// The header as comment lines starting with `prefix` (`#`, `--`,
+    /// `//`), followed by a blank line.
+    pub fn header(&self, prefix: &str) -> String {
+        let schemas = if self.schemas.is_empty() {
+            "(default)".to_string()
+        } else {
+            self.schemas.join(", ")
+        };
+        let mut lines = vec![
+            format!("{MARKER} {} at {}", self.version, self.generated_at),
+            format!("Source: {}, schemas: {schemas}", self.dialect),
+        ];
+        if let Some(target) = self.target {
+            lines.push(format!("Target: {target}"));
+        }
+        let filters: Vec<String> = [
+            ("--tables", &self.tables),
+            ("--exclude-tables", &self.exclude_tables),
+            ("--exclude-kinds", &self.exclude_kinds),
+        ]
+        .into_iter()
+        .filter_map(|(flag, value)| value.as_ref().map(|v| format!("{flag} {v}")))
+        .collect();
+        if !filters.is_empty() {
+            lines.push(format!("Table filter: {}", filters.join(" ")));
+        }
+        if !self.extensions.is_empty() {
+            lines.push(format!(
+                "Requires extensions: {}",
+                self.extensions.join(", ")
+            ));
+        }
+        lines.push(format!("Command: {}", self.command));
+        let mut header: String = lines
+            .iter()
+            .map(|line| format!("{prefix} {}\n", flatten(line)))
+            .collect();
+        header.push('\n');
+        header
+    }
+}
+
+/// Comment prefix for a generator's output, or `None` when the format has
+/// no comments (`json`), the output should stay as-is (`report`), or the
+/// format is not known (`template`, `exec:` plugins).
+pub fn comment_prefix(generator: &str) -> Option<&'static str> {
+    match generator {
+        "tables" | "declarative" | "repositories" | "fastapi" | "django" | "test-fixtures" => {
+            Some("#")
+        }
+        "ddl" | "seed" => Some("--"),
+        "dot" | "dbml" | "proto" => Some("//"),
+        _ => None,
+    }
+}
+
+/// `text` without a leading provenance header, so `--check` compares only
+/// the generated body and a new timestamp is not drift.
+pub fn strip_header(text: &str) -> &str {
+    let Some(first) = text.lines().next() else {
+        return text;
+    };
+    let is_header = ["#", "--", "//"]
+        .iter()
+        .any(|prefix| first.starts_with(&format!("{prefix} {MARKER} ")));
+    if !is_header {
+        return text;
+    }
+    match text.find("\n\n") {
+        Some(end) => &text[end + 2..],
+        None => "",
+    }
+}
+
+/// The arguments as one line: URLs redacted (including `--flag=URL`), and
+/// arguments with spaces or quotes single-quoted.
+fn command_line(args: impl IntoIterator<Item = String>) -> String {
+    let mut args = args.into_iter();
+    let program = args.next().map(|_| "uvg".to_string());
+    program
+        .into_iter()
+        .chain(args.map(|arg| match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => {
+                format!("{flag}={}", redact_connection_url(value))
+            }
+            _ => redact_connection_url(&arg),
+        }))
+        .map(|arg| shell_quote(&arg))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn shell_quote(arg: &str) -> String {
+    let plain = |c: char| c.is_ascii_alphanumeric() || "-_./:=@,+%".contains(c);
+    if !arg.is_empty() && arg.chars().all(plain) {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+/// One header line: control characters (a newline in a table pattern,
+/// say) are escaped so they cannot end the comment.
+fn flatten(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            c if c.is_control() => format!("\\x{:02x}", c as u32),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "provenance_tests.rs"]
+mod tests;
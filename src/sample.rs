@@ -0,0 +1,384 @@
+//! Opt-in row sampling (`--sample N`).
+//!
+//! Reads the first N rows of every introspected table and records, per
+//! column, how many were NULL and (for length-bounded string columns) the
+//! longest observed value. The generators turn those observations into
+//! advisory comments next to the column, and `report` summarizes them for
+//! stderr. Nothing here changes the generated SQL types: a declared
+//! `varchar(4000)` stays `String(4000)` even when every sampled value is ten
+//! characters long — the advisory only points at it.
+//!
+//! JSON/JSONB columns additionally get their sampled values parsed so the
+//! declarative generator can annotate them with a `TypedDict` instead of
+//! `dict`.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use sqlx::Row;
+
+use crate::codegen::quote_identifier;
+use crate::connection::ConnectionConfig;
+use crate::ddl_typemap::{to_canonical, CanonicalType};
+use crate::dialect::Dialect;
+use crate::schema::{ColumnInfo, ColumnSample, IntrospectedSchema, TableInfo, TableType};
+
+/// A declared length is flagged when it is at least this many times the
+/// longest sampled value...
+const OVERSIZED_RATIO: u64 = 4;
+/// ...and the slack is at least this many characters, so `varchar(8)`
+/// holding two-character codes is left alone.
+const OVERSIZED_MIN_SLACK: u64 = 32;
+
+/// Sample up to `rows` rows from every base table in `schema` and attach the
+/// observations to each column's `sample` field. Views are skipped.
+pub async fn sample_schema(
+    config: &ConnectionConfig,
+    schema: &mut IntrospectedSchema,
+    rows: usize,
+) -> Result<()> {
+    let dialect = schema.dialect;
+    match config {
+        ConnectionConfig::Postgres(url) => {
+            sample_sqlx::<sqlx::Postgres>(url, schema, rows).await?;
+        }
+        ConnectionConfig::Mysql(url) => {
+            sample_sqlx::<sqlx::MySql>(url, schema, rows).await?;
+        }
+        ConnectionConfig::Sqlite(url) => {
+            sample_sqlx::<sqlx::Sqlite>(url, schema, rows).await?;
+        }
+        ConnectionConfig::Mssql {
+            host,
+            port,
+            instance,
+            database,
+            user,
+            password,
+            trust_cert,
+            encrypt,
+            auth,
+        } => {
+            let mut client = crate::introspect::mssql::connect(
+                host,
+                *port,
+                instance.as_deref(),
+                database,
+                user,
+                password,
+                *auth,
+                *trust_cert,
+                *encrypt,
+            )
+            .await?;
+            for table in sampled_tables(schema) {
+                let sql = sample_query(table, dialect, rows);
+                let row = client
+                    .simple_query(sql)
+                    .await?
+                    .into_row()
+                    .await?
+                    .ok_or_else(|| anyhow::anyhow!("sample query returned no rows"))?;
+                let values = (0..row.len())
+                    .map(|i| row.try_get::<i64, _>(i))
+                    .collect::<Result<Vec<_>, _>>()?;
+                apply_sample(table, dialect, &values);
+                for idx in json_columns(table) {
+                    let sql = json_values_query(table, &table.columns[idx], dialect, rows);
+                    let texts = client.simple_query(sql).await?.into_first_result().await?;
+                    let texts = texts
+                        .iter()
+                        .filter_map(|r| r.get::<&str, _>(0).map(str::to_string))
+                        .collect::<Vec<_>>();
+                    apply_json_sample(&mut table.columns[idx], texts.into_iter());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The sampling loop shared by the sqlx-backed dialects.
+async fn sample_sqlx<DB>(url: &str, schema: &mut IntrospectedSchema, rows: usize) -> Result<()>
+where
+    DB: sqlx::Database,
+    for<'c> &'c sqlx::Pool<DB>: sqlx::Executor<'c, Database = DB>,
+    for<'q> <DB as sqlx::Database>::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    usize: sqlx::ColumnIndex<DB::Row>,
+    i64: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    String: for<'r> sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+{
+    let dialect = schema.dialect;
+    let pool = sqlx::pool::PoolOptions::<DB>::new()
+        .max_connections(1)
+        .connect(url)
+        .await?;
+    for table in sampled_tables(schema) {
+        let row = sqlx::query(&sample_query(table, dialect, rows))
+            .fetch_one(&pool)
+            .await?;
+        let values = (0..row.len())
+            .map(|i| row.try_get::<Option<i64>, _>(i))
+            .collect::<Result<Vec<_>, _>>()?;
+        apply_sample(table, dialect, &values);
+        for idx in json_columns(table) {
+            let sql = json_values_query(table, &table.columns[idx], dialect, rows);
+            let texts = sqlx::query(&sql)
+                .fetch_all(&pool)
+                .await?
+                .iter()
+                .map(|r| r.try_get::<Option<String>, _>(0))
+                .collect::<Result<Vec<_>, _>>()?;
+            apply_json_sample(&mut table.columns[idx], texts.into_iter().flatten());
+        }
+    }
+    pool.close().await;
+    Ok(())
+}
+
+fn sampled_tables(schema: &mut IntrospectedSchema) -> impl Iterator<Item = &mut TableInfo> {
+    schema
+        .tables
+        .iter_mut()
+        .filter(|t| t.table_type == TableType::Table && !t.columns.is_empty())
+}
+
+/// Only length-bounded character columns get a `MAX(length)` probe;
+/// unbounded text has no declared length to second-guess. Large object
+/// and binary types report a length too (`2147483647` for MSSQL `text`),
+/// but the length functions reject them.
+fn measures_length(col: &ColumnInfo, dialect: Dialect) -> bool {
+    col.character_maximum_length.is_some_and(|n| n > 0)
+        && matches!(
+            to_canonical(col, dialect),
+            CanonicalType::Varchar { .. } | CanonicalType::Char { .. }
+        )
+}
+
+/// Build the single aggregate query that samples `table`. The result row is
+/// `COUNT(*)`, then per column `COUNT(col)` followed by `MAX(length(col))`
+/// when [`measures_length`] holds.
+pub fn sample_query(table: &TableInfo, dialect: Dialect, rows: usize) -> String {
+    let length_fn = match dialect {
+        Dialect::Postgres | Dialect::Mysql => "CHAR_LENGTH",
+        Dialect::Sqlite => "LENGTH",
+        Dialect::Mssql => "LEN",
+    };
+    let mut select = vec![as_bigint("COUNT(*)", dialect)];
+    for col in &table.columns {
+        let ident = quote_identifier(&col.name, dialect);
+        select.push(as_bigint(&format!("COUNT({ident})"), dialect));
+        if measures_length(col, dialect) {
+            select.push(as_bigint(&format!("MAX({length_fn}({ident}))"), dialect));
+        }
+    }
+
+    let inner = limited_select("*", table, dialect, rows, "");
+    format!("SELECT {} FROM ({inner}) sampled", select.join(", "))
+}
+
+/// Build the query that reads up to `rows` non-NULL values of a JSON column
+/// as text.
+pub fn json_values_query(
+    table: &TableInfo,
+    col: &ColumnInfo,
+    dialect: Dialect,
+    rows: usize,
+) -> String {
+    let ident = quote_identifier(&col.name, dialect);
+    let text_type = match dialect {
+        Dialect::Postgres | Dialect::Sqlite => "TEXT",
+        Dialect::Mysql => "CHAR",
+        Dialect::Mssql => "NVARCHAR(MAX)",
+    };
+    limited_select(
+        &format!("CAST({ident} AS {text_type})"),
+        table,
+        dialect,
+        rows,
+        &format!(" WHERE {ident} IS NOT NULL"),
+    )
+}
+
+fn limited_select(
+    projection: &str,
+    table: &TableInfo,
+    dialect: Dialect,
+    rows: usize,
+    filter: &str,
+) -> String {
+    let source = if table.schema.is_empty() {
+        quote_identifier(&table.name, dialect)
+    } else {
+        format!(
+            "{}.{}",
+            quote_identifier(&table.schema, dialect),
+            quote_identifier(&table.name, dialect)
+        )
+    };
+    match dialect {
+        Dialect::Mssql => format!("SELECT TOP ({rows}) {projection} FROM {source}{filter}"),
+        Dialect::Postgres | Dialect::Mysql | Dialect::Sqlite => {
+            format!("SELECT {projection} FROM {source}{filter} LIMIT {rows}")
+        }
+    }
+}
+
+fn is_json_column(col: &ColumnInfo) -> bool {
+    matches!(col.udt_name.to_lowercase().as_str(), "json" | "jsonb")
+        || col.data_type.eq_ignore_ascii_case("json")
+}
+
+fn json_columns(table: &TableInfo) -> Vec<usize> {
+    (0..table.columns.len())
+        .filter(|&i| is_json_column(&table.columns[i]))
+        .collect()
+}
+
+/// Parse sampled JSON text and record the inferred object shape on the
+/// column's sample. Unparseable text counts as heterogeneous.
+fn apply_json_sample(col: &mut ColumnInfo, texts: impl Iterator<Item = String>) {
+    let values: Option<Vec<serde_json::Value>> =
+        texts.map(|t| serde_json::from_str(&t).ok()).collect();
+    let fields = values.and_then(|v| infer_json_fields(&v));
+    if let Some(sample) = col.sample.as_mut() {
+        sample.json_fields = fields;
+    }
+}
+
+/// Infer `key → Python type` from sampled JSON values. Returns `None`
+/// (annotate as plain `dict`) unless every value is an object with the same
+/// key set and each key holds one JSON type, optionally mixed with `null`.
+pub fn infer_json_fields(values: &[serde_json::Value]) -> Option<BTreeMap<String, String>> {
+    let first = values.first()?.as_object()?;
+    if first.is_empty() {
+        return None;
+    }
+    let mut fields = BTreeMap::new();
+    for key in first.keys() {
+        let mut base: Option<&'static str> = None;
+        let mut nullable = false;
+        for value in values {
+            let object = value.as_object()?;
+            if object.len() != first.len() {
+                return None;
+            }
+            let ty = match object.get(key)? {
+                serde_json::Value::Null => {
+                    nullable = true;
+                    continue;
+                }
+                serde_json::Value::Bool(_) => "bool",
+                serde_json::Value::Number(n) if n.is_f64() => "float",
+                serde_json::Value::Number(_) => "int",
+                serde_json::Value::String(_) => "str",
+                serde_json::Value::Array(_) => "list",
+                serde_json::Value::Object(_) => "dict",
+            };
+            base = match (base, ty) {
+                (None, ty) => Some(ty),
+                (Some(a), b) if a == b => Some(a),
+                (Some("int"), "float") | (Some("float"), "int") => Some("float"),
+                _ => return None,
+            };
+        }
+        let ty = match (base, nullable) {
+            (Some(ty), false) => ty.to_string(),
+            (Some(ty), true) => format!("Optional[{ty}]"),
+            (None, _) => "None".to_string(),
+        };
+        fields.insert(key.clone(), ty);
+    }
+    Some(fields)
+}
+
+fn as_bigint(expr: &str, dialect: Dialect) -> String {
+    match dialect {
+        Dialect::Mysql => format!("CAST({expr} AS SIGNED)"),
+        Dialect::Postgres | Dialect::Mssql | Dialect::Sqlite => format!("CAST({expr} AS BIGINT)"),
+    }
+}
+
+/// Decode the row produced by [`sample_query`] onto the table's columns.
+fn apply_sample(table: &mut TableInfo, dialect: Dialect, values: &[Option<i64>]) {
+    let as_u64 = |v: Option<&Option<i64>>| v.copied().flatten().unwrap_or(0).max(0) as u64;
+    let rows = as_u64(values.first());
+    let mut idx = 1;
+    for col in &mut table.columns {
+        let non_null = as_u64(values.get(idx));
+        idx += 1;
+        let max_length = if measures_length(col, dialect) {
+            let v = values.get(idx).copied().flatten().map(|n| n.max(0) as u64);
+            idx += 1;
+            v
+        } else {
+            None
+        };
+        col.sample = Some(ColumnSample {
+            rows,
+            nulls: rows.saturating_sub(non_null),
+            max_length,
+            json_fields: None,
+        });
+    }
+}
+
+/// Advisory notes for one column, derived from its sample. Empty when the
+/// column was not sampled, the sample was empty, or nothing looks off.
+pub fn column_advisories(col: &ColumnInfo) -> Vec<String> {
+    let Some(sample) = col.sample.as_ref().filter(|s| s.rows > 0) else {
+        return Vec::new();
+    };
+    let mut notes = Vec::new();
+    if col.is_nullable && sample.nulls == 0 {
+        notes.push(format!(
+            "nullable, but no NULLs in {} sampled rows",
+            sample.rows
+        ));
+    }
+    if let (Some(declared), Some(observed)) = (col.character_maximum_length, sample.max_length) {
+        let declared = declared.max(0) as u64;
+        if declared >= observed.max(1) * OVERSIZED_RATIO
+            && declared - observed >= OVERSIZED_MIN_SLACK
+        {
+            notes.push(format!(
+                "declared length {declared}, longest sampled value is {observed}"
+            ));
+        }
+    }
+    notes
+}
+
+/// Python comment lines (`# sample: ...`) for a column's advisories,
+/// indented to sit directly above the column definition.
+pub fn advisory_comment(col: &ColumnInfo, indent: &str) -> Option<String> {
+    let notes = column_advisories(col);
+    if notes.is_empty() {
+        return None;
+    }
+    Some(
+        notes
+            .iter()
+            .map(|n| format!("{indent}# sample: {n}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// One line per advisory, `table.column: note`, for stderr. Empty when
+/// nothing was flagged.
+pub fn report(schema: &IntrospectedSchema) -> String {
+    let mut out = String::new();
+    for table in &schema.tables {
+        for col in &table.columns {
+            for note in column_advisories(col) {
+                out.push_str(&format!("{}.{}: {note}\n", table.name, col.name));
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "sample_tests.rs"]
+mod tests;
@@ -0,0 +1,167 @@
+use super::*;
+use crate::testutil::*;
+
+fn sampled(mut column: ColumnInfo, rows: u64, nulls: u64, max_length: Option<u64>) -> ColumnInfo {
+    column.sample = Some(ColumnSample {
+        rows,
+        nulls,
+        max_length,
+        ..Default::default()
+    });
+    column
+}
+
+fn users() -> TableInfo {
+    table("users")
+        .column(col("id").build())
+        .column(
+            col("email")
+                .udt("varchar")
+                .max_length(4000)
+                .nullable()
+                .build(),
+        )
+        .column(col("bio").udt("text").nullable().build())
+        .build()
+}
+
+#[test]
+fn pg_query_counts_every_column_and_measures_bounded_strings() {
+    let sql = sample_query(&users(), Dialect::Postgres, 500);
+    assert_eq!(
+        sql,
+        "SELECT CAST(COUNT(*) AS BIGINT), CAST(COUNT(\"id\") AS BIGINT), \
+         CAST(COUNT(\"email\") AS BIGINT), CAST(MAX(CHAR_LENGTH(\"email\")) AS BIGINT), \
+         CAST(COUNT(\"bio\") AS BIGINT) \
+         FROM (SELECT * FROM \"public\".\"users\" LIMIT 500) sampled"
+    );
+}
+
+#[test]
+fn mssql_query_uses_top_and_len() {
+    let mut t = users();
+    t.schema = "dbo".to_string();
+    let sql = sample_query(&t, Dialect::Mssql, 10);
+    assert!(sql.contains("CAST(MAX(LEN([email])) AS BIGINT)"), "{sql}");
+    assert!(
+        sql.ends_with("FROM (SELECT TOP (10) * FROM [dbo].[users]) sampled"),
+        "{sql}"
+    );
+}
+
+#[test]
+fn mssql_query_skips_large_object_and_binary_lengths() {
+    let t = table("docs")
+        .schema("dbo")
+        .column(col("title").udt("nvarchar").max_length(200).build())
+        .column(col("body").udt("ntext").max_length(1_073_741_823).build())
+        .column(col("scan").udt("image").max_length(2_147_483_647).build())
+        .column(col("hash").udt("varbinary").max_length(32).build())
+        .build();
+    let sql = sample_query(&t, Dialect::Mssql, 10);
+    assert_eq!(sql.matches("LEN(").count(), 1, "{sql}");
+    assert!(sql.contains("CAST(MAX(LEN([title])) AS BIGINT)"), "{sql}");
+}
+
+#[test]
+fn mysql_query_casts_to_signed() {
+    let sql = sample_query(&users(), Dialect::Mysql, 10);
+    assert!(sql.starts_with("SELECT CAST(COUNT(*) AS SIGNED)"), "{sql}");
+    assert!(sql.contains("`public`.`users` LIMIT 10"), "{sql}");
+}
+
+#[test]
+fn apply_sample_decodes_positional_row() {
+    let mut t = users();
+    apply_sample(
+        &mut t,
+        Dialect::Postgres,
+        &[Some(100), Some(100), Some(90), Some(12), Some(0)],
+    );
+
+    let id = t.columns[0].sample.as_ref().unwrap();
+    assert_eq!((id.rows, id.nulls, id.max_length), (100, 0, None));
+    let email = t.columns[1].sample.as_ref().unwrap();
+    assert_eq!((email.nulls, email.max_length), (10, Some(12)));
+    let bio = t.columns[2].sample.as_ref().unwrap();
+    assert_eq!(bio.nulls, 100);
+}
+
+#[test]
+fn never_null_nullable_column_is_flagged() {
+    let c = sampled(col("email").nullable().build(), 50, 0, None);
+    assert_eq!(
+        column_advisories(&c),
+        vec!["nullable, but no NULLs in 50 sampled rows"]
+    );
+}
+
+#[test]
+fn oversized_varchar_is_flagged_but_tight_codes_are_not() {
+    let wide = sampled(col("email").max_length(4000).build(), 50, 0, Some(10));
+    assert_eq!(
+        column_advisories(&wide),
+        vec!["declared length 4000, longest sampled value is 10"]
+    );
+
+    let code = sampled(col("code").max_length(8).build(), 50, 0, Some(2));
+    assert!(column_advisories(&code).is_empty());
+}
+
+#[test]
+fn empty_or_missing_sample_yields_no_advisories() {
+    assert!(column_advisories(&col("email").nullable().build()).is_empty());
+    let empty = sampled(col("email").nullable().build(), 0, 0, None);
+    assert!(column_advisories(&empty).is_empty());
+}
+
+#[test]
+fn report_lists_table_and_column() {
+    let schema = schema_pg(vec![table("users")
+        .column(sampled(col("email").nullable().build(), 5, 0, None))
+        .build()]);
+    assert_eq!(
+        report(&schema),
+        "users.email: nullable, but no NULLs in 5 sampled rows\n"
+    );
+}
+
+fn json(values: &[&str]) -> Vec<serde_json::Value> {
+    values
+        .iter()
+        .map(|v| serde_json::from_str(v).unwrap())
+        .collect()
+}
+
+#[test]
+fn json_fields_inferred_from_uniform_objects() {
+    let fields = infer_json_fields(&json(&[
+        r#"{"sku": "a", "qty": 1, "price": 2, "tags": []}"#,
+        r#"{"sku": "b", "qty": null, "price": 2.5, "tags": ["x"]}"#,
+    ]))
+    .unwrap();
+    assert_eq!(fields["sku"], "str");
+    assert_eq!(fields["qty"], "Optional[int]");
+    assert_eq!(fields["price"], "float");
+    assert_eq!(fields["tags"], "list");
+}
+
+#[test]
+fn json_fields_fall_back_when_heterogeneous() {
+    // Different key sets.
+    assert!(infer_json_fields(&json(&[r#"{"a": 1}"#, r#"{"b": 1}"#])).is_none());
+    // Conflicting value types.
+    assert!(infer_json_fields(&json(&[r#"{"a": 1}"#, r#"{"a": "x"}"#])).is_none());
+    // Not objects at all.
+    assert!(infer_json_fields(&json(&["[1, 2]"])).is_none());
+    assert!(infer_json_fields(&[]).is_none());
+}
+
+#[test]
+fn json_values_query_reads_non_null_text() {
+    let c = col("payload").udt("jsonb").build();
+    assert_eq!(
+        json_values_query(&users(), &c, Dialect::Postgres, 20),
+        "SELECT CAST(\"payload\" AS TEXT) FROM \"public\".\"users\" WHERE \"payload\" IS NOT NULL LIMIT 20"
+    );
+}
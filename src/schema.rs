@@ -10,6 +10,90 @@ pub struct IntrospectedSchema {
     pub enums: Vec<EnumInfo>,
     /// Domain types defined in the database.
     pub domains: Vec<DomainInfo>,
+    /// Standalone sequences (PostgreSQL).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sequences: Vec<SequenceInfo>,
+    /// Installed extensions (PostgreSQL `pg_extension`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<ExtensionInfo>,
+}
+
+impl IntrospectedSchema {
+    /// The table a foreign key points at, matched on schema and name. Falls
+    /// back to the first table with that name for sources that leave the
+    /// referenced schema unqualified or report it differently. A reference
+    /// into another database never resolves, even to a same-named table.
+    pub fn referenced_table(&self, fk: &ForeignKeyInfo) -> Option<&TableInfo> {
+        if fk.ref_database.is_some() {
+            return None;
+        }
+        self.tables
+            .iter()
+            .find(|t| t.name == fk.ref_table && t.schema == fk.ref_schema)
+            .or_else(|| self.tables.iter().find(|t| t.name == fk.ref_table))
+    }
+
+    /// Whether `fk` points at `table` (which must belong to this schema).
+    pub fn references(&self, fk: &ForeignKeyInfo, table: &TableInfo) -> bool {
+        self.referenced_table(fk)
+            .is_some_and(|target| std::ptr::eq(target, table))
+    }
+
+    /// Foreign keys whose referenced table is not in this schema, typically
+    /// because `--tables` or `--schemas` left it out, with their tables.
+    pub fn dangling_fks(&self) -> Vec<(&TableInfo, &ConstraintInfo)> {
+        self.tables
+            .iter()
+            .flat_map(|table| table.constraints.iter().map(move |c| (table, c)))
+            .filter(|(_, c)| {
+                c.foreign_key
+                    .as_ref()
+                    .is_some_and(|fk| self.referenced_table(fk).is_none())
+            })
+            .collect()
+    }
+
+    /// The standalone sequence a `nextval()` names, as `name` or
+    /// `schema.name`. An unqualified name is looked up in `default_schema`
+    /// (the referencing table's) first, then in any schema.
+    pub fn sequence(&self, name: &str, default_schema: &str) -> Option<&SequenceInfo> {
+        match name.rsplit_once('.') {
+            Some((schema, name)) => self
+                .sequences
+                .iter()
+                .find(|s| s.schema == schema && s.name == name),
+            None => self
+                .sequences
+                .iter()
+                .find(|s| s.schema == default_schema && s.name == name)
+                .or_else(|| self.sequences.iter().find(|s| s.name == name)),
+        }
+    }
+}
+
+/// A standalone PostgreSQL sequence: one no column owns through `SERIAL` or
+/// an identity column, typically drawn from by an explicit
+/// `nextval('invoice_seq')` default.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SequenceInfo {
+    pub schema: String,
+    pub name: String,
+    pub start: i64,
+    pub increment: i64,
+    pub min_value: i64,
+    pub max_value: i64,
+    pub cycle: bool,
+    pub cache: i64,
+}
+
+/// An extension installed in a PostgreSQL database, e.g. `citext` or
+/// `postgis`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtensionInfo {
+    pub name: String,
+    /// Schema holding the extension's objects.
+    pub schema: String,
+    pub version: String,
 }
 
 /// A PostgreSQL domain type wrapping a base type with constraints.
@@ -42,6 +126,117 @@ pub struct TableInfo {
     pub columns: Vec<ColumnInfo>,
     pub constraints: Vec<ConstraintInfo>,
     pub indexes: Vec<IndexInfo>,
+    /// True for session-scoped temporary tables (`CREATE TEMP TABLE` in a
+    /// SQL file; introspection never sees them).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_temporary: bool,
+    /// Parent table name when this table is a partition child (PG
+    /// `relispartition`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partition_of: Option<String>,
+    /// Foreign server of a PostgreSQL foreign (FDW) table.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub foreign_server: Option<String>,
+    /// Parent table names of a PostgreSQL `INHERITS` child, in declaration
+    /// order (partitions are recorded in `partition_of` instead).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inherits: Vec<String>,
+    /// Current table name when this table is the history table of a
+    /// system-versioned (temporal) table (MSSQL `temporal_type = 1`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history_of: Option<String>,
+    /// `schema.table` of the history table when this is a system-versioned
+    /// temporal table (MSSQL `temporal_type = 2`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history_table: Option<String>,
+    /// PostgreSQL `REPLICA IDENTITY` setting; `None` for views and other
+    /// dialects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replica_identity: Option<ReplicaIdentity>,
+    /// Logical-replication publications that include this table (PG
+    /// `pg_publication_tables`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub publications: Vec<String>,
+    /// PostgreSQL row-level security: set when it is enabled or the table
+    /// has policies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub row_security: Option<RowSecurity>,
+    /// Extended properties other than `MS_Description` (MSSQL), by name.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub properties: std::collections::BTreeMap<String, String>,
+    /// Defining SQL of a view, as the server reports it (`pg_get_viewdef`,
+    /// `sys.sql_modules`, `information_schema.VIEWS`, `sqlite_master`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_definition: Option<String>,
+    /// Objects introspection found but could not represent, one
+    /// description each (e.g. `index ix_lower_email: expression keys`).
+    /// Reported as warnings rather than dropped silently.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<String>,
+    /// Primary keys uvg stood in for one the database does not declare
+    /// (`synthesize_pk`, `infer_view_pk`), one description each.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inferred_keys: Vec<String>,
+}
+
+/// PostgreSQL row-level security of a table (`pg_class.relrowsecurity`,
+/// `pg_policies`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowSecurity {
+    /// `ENABLE ROW LEVEL SECURITY`; policies have no effect without it.
+    pub enabled: bool,
+    /// `FORCE ROW LEVEL SECURITY`: the policies bind the table owner too.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub forced: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub policies: Vec<PolicyInfo>,
+}
+
+/// A row-level security policy (`CREATE POLICY`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyInfo {
+    pub name: String,
+    /// `ALL`, `SELECT`, `INSERT`, `UPDATE`, or `DELETE`.
+    pub command: String,
+    /// False for `AS RESTRICTIVE`, which narrows what permissive policies
+    /// allow.
+    pub permissive: bool,
+    /// Roles the policy applies to; `public` means every role.
+    pub roles: Vec<String>,
+    /// `USING`: which existing rows are visible or affected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub using: Option<String>,
+    /// `WITH CHECK`: which new rows may be written.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub with_check: Option<String>,
+}
+
+/// Which old-row columns PostgreSQL logs for UPDATE/DELETE under logical
+/// replication (`pg_class.relreplident`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplicaIdentity {
+    /// Primary key columns (`d`).
+    Default,
+    /// No old-row information (`n`).
+    Nothing,
+    /// Every column (`f`).
+    Full,
+    /// The columns of a chosen unique index (`i`).
+    Index,
+}
+
+impl ReplicaIdentity {
+    /// Parse a `pg_class.relreplident` code.
+    pub fn from_pg_code(code: &str) -> Option<Self> {
+        match code {
+            "d" => Some(Self::Default),
+            "n" => Some(Self::Nothing),
+            "f" => Some(Self::Full),
+            "i" => Some(Self::Index),
+            _ => None,
+        }
+    }
 }
 
 impl TableInfo {
@@ -54,6 +249,19 @@ impl TableInfo {
             columns: Vec::new(),
             constraints: Vec::new(),
             indexes: Vec::new(),
+            is_temporary: false,
+            partition_of: None,
+            foreign_server: None,
+            inherits: Vec::new(),
+            history_of: None,
+            history_table: None,
+            replica_identity: None,
+            publications: Vec::new(),
+            row_security: None,
+            properties: std::collections::BTreeMap::new(),
+            view_definition: None,
+            skipped: Vec::new(),
+            inferred_keys: Vec::new(),
         }
     }
 
@@ -84,16 +292,150 @@ pub struct ColumnInfo {
     /// readable and non-PostgreSQL dialects do not invent a type schema.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub udt_schema: Option<String>,
+    /// PostgreSQL domain the column is declared with. `udt_name` then holds
+    /// the domain's underlying base type, so type mapping needs no lookup.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
     pub character_maximum_length: Option<i32>,
     pub numeric_precision: Option<i32>,
     pub numeric_scale: Option<i32>,
+    /// Declared fractional-seconds precision of a time/timestamp column,
+    /// e.g. 3 for `timestamp(3)` or `datetime2(3)`. `None` when the type
+    /// uses the server's default precision.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datetime_precision: Option<i32>,
     pub column_default: Option<String>,
+    /// False for an unsigned integer column (MySQL `int unsigned`), whose
+    /// range needs the next wider type elsewhere.
+    #[serde(default = "default_signed", skip_serializing_if = "is_signed")]
+    pub signed: bool,
+    /// Name of the constraint holding `column_default` (MSSQL
+    /// `sys.default_constraints`), when it was named explicitly rather than
+    /// left to the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_constraint: Option<String>,
     pub is_identity: bool,
     pub identity_generation: Option<String>,
     pub identity: Option<IdentityInfo>,
     pub comment: Option<String>,
     pub collation: Option<String>,
     pub autoincrement: Option<bool>,
+    /// Generation expression of a generated (computed) column. The database
+    /// fills these in, so generated models must never write to them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub computed: Option<ComputedInfo>,
+    /// The system-versioning period bound this column holds (MSSQL
+    /// `GENERATED ALWAYS AS ROW START|END`); the server sets it on every
+    /// write.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period: Option<PeriodColumn>,
+    /// True when the column comes from a PostgreSQL `INHERITS` parent
+    /// (`pg_attribute.attinhcount > 0`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub inherited: bool,
+    /// Declared dimensions of a PostgreSQL array column, when more than
+    /// one (`int4[][]`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub array_dimensions: Option<i32>,
+    /// PostGIS type modifiers of a `geometry`/`geography` column.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spatial: Option<SpatialInfo>,
+    /// Observed values from `--sample` row sampling, when requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sample: Option<ColumnSample>,
+    /// Likely personal data, set by `--pii` detection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pii: Option<PiiCategory>,
+    /// Planner statistics captured by `--stats`, when requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<ColumnStats>,
+    /// Extended properties other than `MS_Description` (MSSQL), by name.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub properties: std::collections::BTreeMap<String, String>,
+    /// Generated type from a `--typemap` file, replacing the dialect mapping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_override: Option<TypeOverride>,
+}
+
+/// A user-supplied SQLAlchemy type for a column (`--typemap`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeOverride {
+    /// SQLAlchemy type expression, e.g. `CITEXT` or `String(320)`.
+    pub sa_type: String,
+    /// Python annotation used in `Mapped[...]`, e.g. `str`.
+    pub python_type: String,
+    /// Dotted import path of the type, e.g.
+    /// `sqlalchemy.dialects.postgresql.CITEXT`. Defaults to the `sqlalchemy`
+    /// module and the type expression's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import: Option<String>,
+    /// Further dotted imports the type expression needs, e.g.
+    /// `sqlalchemy.String` for `ARRAY(String)`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imports: Vec<String>,
+}
+
+/// Optimizer statistics for one column, read from `pg_stats` (PostgreSQL)
+/// or the statistics histogram DMVs (SQL Server) by `--stats`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColumnStats {
+    /// Fraction of rows that are NULL (0.0–1.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null_frac: Option<f64>,
+    /// Estimated distinct non-NULL values. Negative values follow the
+    /// `pg_stats` convention: the negated fraction of the row count.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n_distinct: Option<f64>,
+    /// Most common values, most frequent first, rendered as text.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub most_common_values: Vec<String>,
+}
+
+/// Aggregates observed over the first N rows of a table by `--sample`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnSample {
+    /// Rows inspected (at most the requested sample size).
+    pub rows: u64,
+    /// Rows where this column was NULL.
+    pub nulls: u64,
+    /// Longest observed value in characters, for string columns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u64>,
+    /// Key → Python type inferred from sampled JSON objects. Only set when
+    /// every sampled value was an object with the same keys and
+    /// consistently-typed values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json_fields: Option<std::collections::BTreeMap<String, String>>,
+}
+
+/// Kind of personal data a column appears to hold. See [`crate::pii`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiCategory {
+    Email,
+    Name,
+    Phone,
+    NationalId,
+    DateOfBirth,
+    Address,
+    IpAddress,
+    Financial,
+}
+
+impl PiiCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Name => "name",
+            Self::Phone => "phone",
+            Self::NationalId => "national_id",
+            Self::DateOfBirth => "date_of_birth",
+            Self::Address => "address",
+            Self::IpAddress => "ip_address",
+            Self::Financial => "financial",
+        }
+    }
 }
 
 impl ColumnInfo {
@@ -111,33 +453,123 @@ impl ColumnInfo {
             data_type: data_type.into(),
             udt_name: udt_name.into(),
             udt_schema: None,
+            domain: None,
             character_maximum_length: None,
             numeric_precision: None,
             numeric_scale: None,
+            datetime_precision: None,
             column_default: None,
+            signed: true,
+            default_constraint: None,
             is_identity: false,
             identity_generation: None,
             identity: None,
             comment: None,
             collation: None,
             autoincrement: None,
+            computed: None,
+            period: None,
+            inherited: false,
+            array_dimensions: None,
+            spatial: None,
+            sample: None,
+            pii: None,
+            stats: None,
+            properties: std::collections::BTreeMap::new(),
+            type_override: None,
+        }
+    }
+
+    /// The udt name with its length or decimal precision, e.g. `varchar(50)`
+    /// or `numeric(10,2)`, for reports and diagrams.
+    pub fn type_label(&self) -> String {
+        let udt = &self.udt_name;
+        if let Some(length) = self.character_maximum_length {
+            return format!("{udt}({length})");
+        }
+        let is_decimal = matches!(udt.to_ascii_lowercase().as_str(), "numeric" | "decimal");
+        match (is_decimal, self.numeric_precision, self.numeric_scale) {
+            (true, Some(precision), Some(scale)) => format!("{udt}({precision},{scale})"),
+            (true, Some(precision), None) => format!("{udt}({precision})"),
+            _ => udt.clone(),
         }
     }
 }
 
-/// Parameters for an identity column's underlying sequence.
+/// Which bound of a system-versioning period a column holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PeriodColumn {
+    RowStart,
+    RowEnd,
+}
+
+/// A generated column's expression, as the database reports it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComputedInfo {
+    pub expression: String,
+    /// `Some(true)` for stored columns, `Some(false)` for virtual ones,
+    /// `None` when the database does not say.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub persisted: Option<bool>,
+}
+
+/// The geometry type and SRID a PostGIS column is constrained to, from its
+/// type modifier (`geometry(Point,4326)`). Both are `None` for an
+/// unconstrained `geometry`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpatialInfo {
+    /// Uppercased geometry type, e.g. `POINT` or `MULTIPOLYGONZ`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geometry_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub srid: Option<i32>,
+}
+
+impl SpatialInfo {
+    /// Parse `format_type` output: `geometry(Point,4326)`,
+    /// `geography(PointZ)`, or a bare `geometry`.
+    pub fn from_type_modifier(formatted: &str) -> Self {
+        let args = formatted
+            .split_once('(')
+            .and_then(|(_, rest)| rest.strip_suffix(')'))
+            .unwrap_or("");
+        let mut parts = args.split(',').map(str::trim);
+        let geometry_type = parts
+            .next()
+            .filter(|t| !t.is_empty())
+            .map(str::to_ascii_uppercase);
+        let srid = parts
+            .next()
+            .and_then(|s| s.parse::<i32>().ok())
+            .filter(|srid| *srid > 0);
+        Self {
+            geometry_type,
+            srid,
+        }
+    }
+}
+
+/// Parameters for an identity column's underlying sequence. Only
+/// PostgreSQL identities are backed by a sequence with bounds, cycling and
+/// a cache; other dialects leave those `None`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct IdentityInfo {
     pub start: i64,
     pub increment: i64,
-    pub min_value: i64,
-    pub max_value: i64,
-    pub cycle: bool,
-    pub cache: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<i64>,
 }
 
 impl IdentityInfo {
+    /// A sequence-backed (PostgreSQL) identity with every option known.
     pub fn new(
         start: i64,
         increment: i64,
@@ -149,10 +581,22 @@ impl IdentityInfo {
         Self {
             start,
             increment,
-            min_value,
-            max_value,
-            cycle,
-            cache,
+            min_value: Some(min_value),
+            max_value: Some(max_value),
+            cycle: Some(cycle),
+            cache: Some(cache),
+        }
+    }
+
+    /// An identity with only a seed and increment (SQL Server `IDENTITY`).
+    pub fn seed(start: i64, increment: i64) -> Self {
+        Self {
+            start,
+            increment,
+            min_value: None,
+            max_value: None,
+            cycle: None,
+            cache: None,
         }
     }
 }
@@ -168,6 +612,16 @@ pub struct ConstraintInfo {
     pub foreign_key: Option<ForeignKeyInfo>,
     /// For check constraints: the SQL expression.
     pub check_expression: Option<String>,
+    /// For exclusion constraints: the elements and access method.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<ExcludeInfo>,
+    /// For unique constraints: PostgreSQL 15+ `NULLS NOT DISTINCT`, under
+    /// which NULLs collide like any other value.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub nulls_not_distinct: bool,
+    /// `COMMENT ON CONSTRAINT` (PostgreSQL) or `MS_Description` (SQL Server).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
 }
 
 impl ConstraintInfo {
@@ -196,6 +650,9 @@ impl ConstraintInfo {
             columns: collect_strings(columns),
             foreign_key: Some(foreign_key),
             check_expression: None,
+            exclude: None,
+            nulls_not_distinct: false,
+            comment: None,
         }
     }
 
@@ -206,6 +663,29 @@ impl ConstraintInfo {
             columns: Vec::new(),
             foreign_key: None,
             check_expression: Some(expression.into()),
+            exclude: None,
+            nulls_not_distinct: false,
+            comment: None,
+        }
+    }
+
+    /// An exclusion constraint; `columns` are its plain-column elements.
+    pub fn exclude(name: impl Into<String>, exclude: ExcludeInfo) -> Self {
+        let columns = exclude
+            .elements
+            .iter()
+            .filter(|element| !element.is_expression)
+            .map(|element| element.key.clone())
+            .collect();
+        Self {
+            name: name.into(),
+            constraint_type: ConstraintType::Exclude,
+            columns,
+            foreign_key: None,
+            check_expression: None,
+            exclude: Some(exclude),
+            nulls_not_distinct: false,
+            comment: None,
         }
     }
 
@@ -220,6 +700,9 @@ impl ConstraintInfo {
             columns: collect_strings(columns),
             foreign_key: None,
             check_expression: None,
+            exclude: None,
+            nulls_not_distinct: false,
+            comment: None,
         }
     }
 }
@@ -231,6 +714,31 @@ pub enum ConstraintType {
     ForeignKey,
     Unique,
     Check,
+    /// PostgreSQL `EXCLUDE`; see [`ExcludeInfo`].
+    Exclude,
+}
+
+/// A PostgreSQL exclusion constraint: no two rows may have every element
+/// compare true under its operator (`EXCLUDE USING gist (room WITH =,
+/// during WITH &&)`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExcludeInfo {
+    /// Index access method of the backing index (`gist`, `btree`, ...).
+    pub using: String,
+    pub elements: Vec<ExcludeElement>,
+    /// `WHERE` predicate of a partial constraint, as SQL text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<String>,
+}
+
+/// One `key WITH operator` element of an exclusion constraint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExcludeElement {
+    /// Column name, or SQL text when `is_expression` is set.
+    pub key: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_expression: bool,
+    pub operator: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -241,6 +749,17 @@ pub struct ForeignKeyInfo {
     pub ref_columns: Vec<String>,
     pub update_rule: String,
     pub delete_rule: String,
+    /// `DEFERRABLE` (PostgreSQL, SQLite).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deferrable: bool,
+    /// `INITIALLY DEFERRED`: checked at commit rather than per statement.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub initially_deferred: bool,
+    /// Database of the referenced table when the reference names one (SQL
+    /// Server three-part `database.schema.table`). Such a table is never
+    /// part of the introspected schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ref_database: Option<String>,
 }
 
 impl ForeignKeyInfo {
@@ -257,6 +776,34 @@ impl ForeignKeyInfo {
             ref_columns: collect_strings(ref_columns),
             update_rule: update_rule.into(),
             delete_rule: delete_rule.into(),
+            deferrable: false,
+            initially_deferred: false,
+            ref_database: None,
+        }
+    }
+
+    /// `schema.table` of the referenced table, or just `table` when the
+    /// schema is empty, prefixed with the database of a cross-database
+    /// reference.
+    pub fn qualified_target(&self) -> String {
+        let target = if self.ref_schema.is_empty() {
+            self.ref_table.clone()
+        } else {
+            format!("{}.{}", self.ref_schema, self.ref_table)
+        };
+        match self.ref_database {
+            Some(ref database) => format!("{database}.{target}"),
+            None => target,
+        }
+    }
+
+    /// Why the referenced table is missing from the output, for warnings
+    /// about a dangling foreign key.
+    pub fn unresolved_reason(&self) -> &'static str {
+        if self.ref_database.is_some() {
+            "a table in another database"
+        } else {
+            "which is not generated"
         }
     }
 }
@@ -269,6 +816,87 @@ pub struct IndexInfo {
     pub columns: Vec<String>,
     /// Dialect-specific index kwargs (e.g. postgresql_using, mysql_length).
     pub kwargs: std::collections::BTreeMap<String, String>,
+    /// Every key of an expression index, in order: plain columns by name,
+    /// expressions as SQL text (`lower((email)::text)`). Empty when all
+    /// keys are plain columns, in which case `columns` is the whole story.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expressions: Vec<String>,
+    /// Non-default PostgreSQL operator classes, by key column
+    /// (`{"name": "gin_trgm_ops"}`).
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub opclasses: std::collections::BTreeMap<String, String>,
+    /// Non-default sort orders, by key (column name or expression text).
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub orderings: std::collections::BTreeMap<String, SortOrder>,
+    /// Non-key columns stored in the index leaf (`INCLUDE (...)`,
+    /// PostgreSQL 11+ and SQL Server).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// SQL Server clustered index (`sys.indexes.type` 1 or 5).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub clustered: bool,
+    /// SQL Server columnstore index (`sys.indexes.type` 5 or 6). SQLAlchemy
+    /// has no way to declare one, so model generators leave it out.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub columnstore: bool,
+    /// PostgreSQL 15+ unique index with `NULLS NOT DISTINCT`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub nulls_not_distinct: bool,
+    /// `COMMENT ON INDEX` (PostgreSQL), `MS_Description` (SQL Server) or
+    /// `INDEX_COMMENT` (MySQL).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Sort order of one index key, recorded when it is not plain ascending
+/// (`pg_index.indoption`, `sys.index_columns.is_descending_key`,
+/// `STATISTICS.COLLATION`, `index_xinfo.desc`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SortOrder {
+    pub descending: bool,
+    /// NULLs sort before other values. PostgreSQL defaults this to
+    /// `descending` (ASC NULLS LAST, DESC NULLS FIRST).
+    pub nulls_first: bool,
+}
+
+impl SortOrder {
+    /// `DESC` with the default NULLs placement.
+    pub const DESC: SortOrder = SortOrder {
+        descending: true,
+        nulls_first: true,
+    };
+
+    /// `true` for plain ascending order, which is never recorded.
+    pub fn is_default(self) -> bool {
+        !self.descending && !self.nulls_first
+    }
+
+    /// The key suffix: ` DESC`, ` NULLS FIRST`, ` DESC NULLS LAST`, or
+    /// nothing. NULLs placement is only spelled out on dialects that
+    /// accept `NULLS FIRST|LAST` in an index key.
+    pub fn sql_suffix(self, dialect: Dialect) -> String {
+        let mut suffix = String::new();
+        if self.descending {
+            suffix.push_str(" DESC");
+        }
+        if self.nulls_first != self.descending
+            && matches!(dialect, Dialect::Postgres | Dialect::Sqlite)
+        {
+            suffix.push_str(if self.nulls_first {
+                " NULLS FIRST"
+            } else {
+                " NULLS LAST"
+            });
+        }
+        suffix
+    }
+}
+
+/// One key of an index, as yielded by [`IndexInfo::keys`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexKey<'a> {
+    Column(&'a str),
+    Expression(&'a str),
 }
 
 impl IndexInfo {
@@ -282,10 +910,48 @@ impl IndexInfo {
             is_unique,
             columns: collect_strings(columns),
             kwargs: std::collections::BTreeMap::new(),
+            expressions: Vec::new(),
+            opclasses: std::collections::BTreeMap::new(),
+            orderings: std::collections::BTreeMap::new(),
+            include: Vec::new(),
+            clustered: false,
+            columnstore: false,
+            nulls_not_distinct: false,
+            comment: None,
+        }
+    }
+
+    /// `true` when at least one key is an expression rather than a column.
+    pub fn has_expressions(&self) -> bool {
+        !self.expressions.is_empty()
+    }
+
+    /// The index keys in order.
+    pub fn keys(&self) -> Vec<IndexKey<'_>> {
+        if !self.has_expressions() {
+            return self.columns.iter().map(|c| IndexKey::Column(c)).collect();
         }
+        self.expressions
+            .iter()
+            .map(|key| {
+                if self.columns.contains(key) {
+                    IndexKey::Column(key)
+                } else {
+                    IndexKey::Expression(key)
+                }
+            })
+            .collect()
     }
 }
 
+fn default_signed() -> bool {
+    true
+}
+
+fn is_signed(signed: &bool) -> bool {
+    *signed
+}
+
 fn collect_strings(values: impl IntoIterator<Item = impl Into<String>>) -> Vec<String> {
     values.into_iter().map(Into::into).collect()
 }
@@ -0,0 +1,393 @@
+//! Structural comparison of two introspected schemas (`uvg diff`).
+//!
+//! Unlike the DDL diff engine this does not plan statements; it reports
+//! which tables, columns, constraints, and indexes were added, removed, or
+//! changed going from schema A to schema B, for humans (text) or tools
+//! (JSON).
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::dialect::Dialect;
+use serde::Serialize;
+
+use crate::schema::{
+    ColumnInfo, ConstraintInfo, ConstraintType, IndexInfo, IndexKey, IntrospectedSchema, TableInfo,
+    TableType,
+};
+
+/// Everything that differs between two schemas, A → B.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct SchemaDiff {
+    /// Tables (and views) only in B.
+    pub tables_added: Vec<String>,
+    /// Tables (and views) only in A.
+    pub tables_removed: Vec<String>,
+    /// Tables in both whose definition differs.
+    pub tables_changed: Vec<TableDiff>,
+}
+
+/// Differences within one table present on both sides.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct TableDiff {
+    pub table: String,
+    /// Table-level properties (kind, comment).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<FieldChange>,
+    pub columns: ObjectDiff,
+    pub constraints: ObjectDiff,
+    pub indexes: ObjectDiff,
+}
+
+/// Added, removed, and changed objects of one kind, by name.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct ObjectDiff {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changed: Vec<ObjectChange>,
+}
+
+/// An object present on both sides with differing properties.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ObjectChange {
+    pub name: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// One property's value in A and in B. Absent values are empty strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub from: String,
+    pub to: String,
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        self.tables_added.is_empty()
+            && self.tables_removed.is_empty()
+            && self.tables_changed.is_empty()
+    }
+}
+
+impl ObjectDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compare schema `a` with schema `b`.
+pub fn diff_schemas(a: &IntrospectedSchema, b: &IntrospectedSchema) -> SchemaDiff {
+    let b_tables: HashMap<(&str, &str), &TableInfo> = b
+        .tables
+        .iter()
+        .map(|t| ((t.schema.as_str(), t.name.as_str()), t))
+        .collect();
+    let a_keys: HashSet<(&str, &str)> = a
+        .tables
+        .iter()
+        .map(|t| (t.schema.as_str(), t.name.as_str()))
+        .collect();
+
+    let mut diff = SchemaDiff::default();
+    for table in &a.tables {
+        match b_tables.get(&(table.schema.as_str(), table.name.as_str())) {
+            None => diff.tables_removed.push(table_label(table)),
+            Some(other) => {
+                let table_diff = diff_tables(table, other);
+                if !table_diff.changes.is_empty()
+                    || !table_diff.columns.is_empty()
+                    || !table_diff.constraints.is_empty()
+                    || !table_diff.indexes.is_empty()
+                {
+                    diff.tables_changed.push(table_diff);
+                }
+            }
+        }
+    }
+    for table in &b.tables {
+        if !a_keys.contains(&(table.schema.as_str(), table.name.as_str())) {
+            diff.tables_added.push(table_label(table));
+        }
+    }
+    diff
+}
+
+fn diff_tables(a: &TableInfo, b: &TableInfo) -> TableDiff {
+    TableDiff {
+        table: table_label(a),
+        changes: field_changes(&table_props(a), &table_props(b)),
+        columns: diff_objects(&a.columns, &b.columns, |c| &c.name, column_props),
+        constraints: diff_objects(
+            &a.constraints,
+            &b.constraints,
+            |c| &c.name,
+            constraint_props,
+        ),
+        indexes: diff_objects(&a.indexes, &b.indexes, |i| &i.name, index_props),
+    }
+}
+
+type Props = Vec<(&'static str, String)>;
+
+fn diff_objects<T>(
+    a: &[T],
+    b: &[T],
+    name: impl Fn(&T) -> &String,
+    props: impl Fn(&T) -> Props,
+) -> ObjectDiff {
+    let b_by_name: HashMap<&String, &T> = b.iter().map(|item| (name(item), item)).collect();
+    let a_names: HashSet<&String> = a.iter().map(&name).collect();
+
+    let mut diff = ObjectDiff::default();
+    for item in a {
+        match b_by_name.get(name(item)) {
+            None => diff.removed.push(name(item).clone()),
+            Some(other) => {
+                let changes = field_changes(&props(item), &props(other));
+                if !changes.is_empty() {
+                    diff.changed.push(ObjectChange {
+                        name: name(item).clone(),
+                        changes,
+                    });
+                }
+            }
+        }
+    }
+    for item in b {
+        if !a_names.contains(name(item)) {
+            diff.added.push(name(item).clone());
+        }
+    }
+    diff
+}
+
+/// Pair up properties by field; every field appears on both sides because
+/// the `*_props` functions always emit the same fields in the same order.
+fn field_changes(a: &Props, b: &Props) -> Vec<FieldChange> {
+    a.iter()
+        .zip(b)
+        .filter(|((_, from), (_, to))| from != to)
+        .map(|((field, from), (_, to))| FieldChange {
+            field,
+            from: from.clone(),
+            to: to.clone(),
+        })
+        .collect()
+}
+
+fn table_label(table: &TableInfo) -> String {
+    if table.schema.is_empty() {
+        table.name.clone()
+    } else {
+        format!("{}.{}", table.schema, table.name)
+    }
+}
+
+fn table_props(table: &TableInfo) -> Props {
+    let kind = match table.table_type {
+        TableType::Table => "table",
+        TableType::View => "view",
+    };
+    vec![
+        ("kind", kind.to_string()),
+        ("comment", table.comment.clone().unwrap_or_default()),
+    ]
+}
+
+fn column_props(col: &ColumnInfo) -> Props {
+    vec![
+        ("type", col.type_label()),
+        ("nullable", col.is_nullable.to_string()),
+        ("default", col.column_default.clone().unwrap_or_default()),
+        (
+            "default constraint",
+            col.default_constraint.clone().unwrap_or_default(),
+        ),
+        (
+            "identity",
+            col.identity_generation
+                .clone()
+                .or_else(|| col.is_identity.then(|| "true".to_string()))
+                .unwrap_or_default(),
+        ),
+        (
+            "computed",
+            col.computed
+                .as_ref()
+                .map(|c| c.expression.clone())
+                .unwrap_or_default(),
+        ),
+        ("collation", col.collation.clone().unwrap_or_default()),
+        ("comment", col.comment.clone().unwrap_or_default()),
+    ]
+}
+
+fn constraint_props(constraint: &ConstraintInfo) -> Props {
+    let kind = match constraint.constraint_type {
+        ConstraintType::PrimaryKey => "PRIMARY KEY",
+        ConstraintType::ForeignKey => "FOREIGN KEY",
+        ConstraintType::Unique => "UNIQUE",
+        ConstraintType::Check => "CHECK",
+        ConstraintType::Exclude => "EXCLUDE",
+    };
+    let fk = constraint.foreign_key.as_ref();
+    vec![
+        ("kind", kind.to_string()),
+        ("columns", constraint.columns.join(", ")),
+        (
+            "references",
+            fk.map(|fk| {
+                format!(
+                    "{}.{}({})",
+                    fk.ref_schema,
+                    fk.ref_table,
+                    fk.ref_columns.join(", ")
+                )
+            })
+            .unwrap_or_default(),
+        ),
+        (
+            "on_delete",
+            fk.map(|fk| fk.delete_rule.clone()).unwrap_or_default(),
+        ),
+        (
+            "on_update",
+            fk.map(|fk| fk.update_rule.clone()).unwrap_or_default(),
+        ),
+        (
+            "deferrable",
+            fk.map(|fk| match (fk.deferrable, fk.initially_deferred) {
+                (_, true) => "INITIALLY DEFERRED".to_string(),
+                (true, false) => "DEFERRABLE".to_string(),
+                (false, false) => String::new(),
+            })
+            .unwrap_or_default(),
+        ),
+        (
+            "check",
+            constraint.check_expression.clone().unwrap_or_default(),
+        ),
+        (
+            "nulls",
+            nulls_label(constraint.nulls_not_distinct).to_string(),
+        ),
+        ("comment", constraint.comment.clone().unwrap_or_default()),
+    ]
+}
+
+fn index_props(index: &IndexInfo) -> Props {
+    let keys: Vec<&str> = index
+        .keys()
+        .into_iter()
+        .map(|key| match key {
+            IndexKey::Column(name) | IndexKey::Expression(name) => name,
+        })
+        .collect();
+    vec![
+        ("unique", index.is_unique.to_string()),
+        ("keys", keys.join(", ")),
+        ("options", format_map(&index.kwargs)),
+        ("opclasses", format_map(&index.opclasses)),
+        ("order", format_orderings(index)),
+        ("include", index.include.join(", ")),
+        ("type", index_type(index).to_string()),
+        ("nulls", nulls_label(index.nulls_not_distinct).to_string()),
+        ("comment", index.comment.clone().unwrap_or_default()),
+    ]
+}
+
+fn nulls_label(nulls_not_distinct: bool) -> &'static str {
+    if nulls_not_distinct {
+        "NOT DISTINCT"
+    } else {
+        ""
+    }
+}
+
+fn index_type(index: &IndexInfo) -> &'static str {
+    match (index.clustered, index.columnstore) {
+        (false, false) => "",
+        (true, false) => "clustered",
+        (false, true) => "nonclustered columnstore",
+        (true, true) => "clustered columnstore",
+    }
+}
+
+fn format_orderings(index: &IndexInfo) -> String {
+    index
+        .orderings
+        .iter()
+        .map(|(key, order)| format!("{key}{}", order.sql_suffix(Dialect::Postgres)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_map(map: &BTreeMap<String, String>) -> String {
+    map.iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Human-readable report: `+` added, `-` removed, `~` changed.
+pub fn render_text(diff: &SchemaDiff) -> String {
+    if diff.is_empty() {
+        return "No differences.\n".to_string();
+    }
+    let mut out = String::new();
+    for table in &diff.tables_added {
+        let _ = writeln!(out, "+ table {table}");
+    }
+    for table in &diff.tables_removed {
+        let _ = writeln!(out, "- table {table}");
+    }
+    for table in &diff.tables_changed {
+        let _ = writeln!(out, "~ table {}", table.table);
+        render_fields(&mut out, "    ", &table.changes);
+        render_objects(&mut out, "column", &table.columns);
+        render_objects(&mut out, "constraint", &table.constraints);
+        render_objects(&mut out, "index", &table.indexes);
+    }
+    out
+}
+
+fn render_objects(out: &mut String, kind: &str, diff: &ObjectDiff) {
+    for name in &diff.added {
+        let _ = writeln!(out, "    + {kind} {name}");
+    }
+    for name in &diff.removed {
+        let _ = writeln!(out, "    - {kind} {name}");
+    }
+    for change in &diff.changed {
+        let _ = writeln!(out, "    ~ {kind} {}", change.name);
+        render_fields(out, "        ", &change.changes);
+    }
+}
+
+fn render_fields(out: &mut String, indent: &str, changes: &[FieldChange]) {
+    for change in changes {
+        let _ = writeln!(
+            out,
+            "{indent}{}: {} -> {}",
+            change.field,
+            display_value(&change.from),
+            display_value(&change.to)
+        );
+    }
+}
+
+fn display_value(value: &str) -> &str {
+    if value.is_empty() {
+        "(none)"
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+#[path = "schema_diff_tests.rs"]
+mod tests;
@@ -0,0 +1,133 @@
+use super::*;
+use crate::testutil::*;
+
+fn users_a() -> TableInfo {
+    table("users")
+        .column(col("id").build())
+        .column(col("name").udt("varchar").max_length(50).build())
+        .column(col("nickname").udt("text").nullable().build())
+        .pk("users_pkey", &["id"])
+        .index("ix_users_name", &["name"], false)
+        .build()
+}
+
+fn users_b() -> TableInfo {
+    table("users")
+        .column(col("id").build())
+        .column(
+            col("name")
+                .udt("varchar")
+                .max_length(100)
+                .nullable()
+                .build(),
+        )
+        .column(col("email").udt("text").build())
+        .pk("users_pkey", &["id"])
+        .unique("uq_users_email", &["email"])
+        .index("ix_users_name", &["name"], true)
+        .build()
+}
+
+#[test]
+fn identical_schemas_have_no_differences() {
+    let a = schema_pg(vec![users_a()]);
+    let diff = diff_schemas(&a, &a.clone());
+    assert!(diff.is_empty());
+    assert_eq!(render_text(&diff), "No differences.\n");
+}
+
+#[test]
+fn reports_added_removed_and_changed_objects() {
+    let a = schema_pg(vec![
+        users_a(),
+        table("legacy").column(col("id").build()).build(),
+    ]);
+    let b = schema_pg(vec![
+        users_b(),
+        table("orders").column(col("id").build()).build(),
+    ]);
+    let diff = diff_schemas(&a, &b);
+
+    assert_eq!(diff.tables_added, vec!["public.orders"]);
+    assert_eq!(diff.tables_removed, vec!["public.legacy"]);
+    assert_eq!(diff.tables_changed.len(), 1);
+
+    let users = &diff.tables_changed[0];
+    assert_eq!(users.table, "public.users");
+    assert_eq!(users.columns.added, vec!["email"]);
+    assert_eq!(users.columns.removed, vec!["nickname"]);
+    assert_eq!(users.columns.changed[0].name, "name");
+    assert_eq!(
+        users.columns.changed[0].changes,
+        vec![
+            FieldChange {
+                field: "type",
+                from: "varchar(50)".to_string(),
+                to: "varchar(100)".to_string(),
+            },
+            FieldChange {
+                field: "nullable",
+                from: "false".to_string(),
+                to: "true".to_string(),
+            },
+        ]
+    );
+    assert_eq!(users.constraints.added, vec!["uq_users_email"]);
+    assert!(users.constraints.changed.is_empty());
+    assert_eq!(users.indexes.changed[0].name, "ix_users_name");
+
+    assert_eq!(
+        render_text(&diff),
+        "\
++ table public.orders
+- table public.legacy
+~ table public.users
+    + column email
+    - column nickname
+    ~ column name
+        type: varchar(50) -> varchar(100)
+        nullable: false -> true
+    + constraint uq_users_email
+    ~ index ix_users_name
+        unique: false -> true
+"
+    );
+}
+
+#[test]
+fn foreign_key_changes_compare_target_and_rules() {
+    let a = schema_pg(vec![table("orders")
+        .column(col("user_id").build())
+        .fk("orders_user_id_fkey", &["user_id"], "users", &["id"])
+        .build()]);
+    let b = schema_pg(vec![table("orders")
+        .column(col("user_id").build())
+        .fk("orders_user_id_fkey", &["user_id"], "accounts", &["id"])
+        .comment("Customer orders")
+        .build()]);
+    let diff = diff_schemas(&a, &b);
+    let orders = &diff.tables_changed[0];
+
+    assert_eq!(orders.changes[0].field, "comment");
+    assert_eq!(orders.changes[0].from, "");
+    let fk = &orders.constraints.changed[0];
+    assert_eq!(fk.changes.len(), 1);
+    assert_eq!(fk.changes[0].from, "public.users(id)");
+    assert_eq!(fk.changes[0].to, "public.accounts(id)");
+    assert!(render_text(&diff).contains("    comment: (none) -> Customer orders\n"));
+}
+
+#[test]
+fn json_report_omits_empty_sections() {
+    let a = schema_pg(vec![users_a()]);
+    let b = schema_pg(vec![users_a(), table("orders").build()]);
+    let json = serde_json::to_value(diff_schemas(&a, &b)).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "tables_added": ["public.orders"],
+            "tables_removed": [],
+            "tables_changed": [],
+        })
+    );
+}
@@ -7,7 +7,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::dialect::Dialect;
 use crate::output::format_utc_iso8601;
-use crate::schema::{DomainInfo, EnumInfo, IntrospectedSchema, TableInfo};
+use crate::schema::{
+    DomainInfo, EnumInfo, ExtensionInfo, IntrospectedSchema, SequenceInfo, TableInfo,
+};
 
 const FORMAT_VERSION: u32 = 1;
 
@@ -15,11 +17,18 @@ const FORMAT_VERSION: u32 = 1;
 pub(crate) struct SnapshotFile {
     pub format_version: u32,
     pub uvg_version: String,
-    pub captured_at: String,
+    /// When a YAML snapshot was taken. Left out of the JSON dump, which
+    /// `--check` compares run to run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub captured_at: Option<String>,
     pub dialect: Dialect,
     pub tables: Vec<TableInfo>,
     pub enums: Vec<EnumInfo>,
     pub domains: Vec<DomainInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sequences: Vec<SequenceInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<ExtensionInfo>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -32,11 +41,13 @@ impl SnapshotFile {
         Self {
             format_version: FORMAT_VERSION,
             uvg_version: env!("CARGO_PKG_VERSION").to_string(),
-            captured_at: now_iso8601(),
+            captured_at: Some(now_iso8601()),
             dialect: schema.dialect,
             tables: schema.tables.clone(),
             enums: schema.enums.clone(),
             domains: schema.domains.clone(),
+            sequences: schema.sequences.clone(),
+            extensions: schema.extensions.clone(),
         }
     }
 
@@ -46,6 +57,8 @@ impl SnapshotFile {
             tables: self.tables,
             enums: self.enums,
             domains: self.domains,
+            sequences: self.sequences,
+            extensions: self.extensions,
         }
     }
 }
@@ -60,14 +73,41 @@ pub fn write(path: &Path, schema: &IntrospectedSchema) -> Result<()> {
     fs::write(path, raw).with_context(|| format!("failed to write snapshot {}", path.display()))
 }
 
+/// Render `schema` as a JSON dump (`--generator json`). Same versioned
+/// format as a YAML snapshot, so it loads back as an input source, but
+/// without `captured_at`: the same schema always dumps the same text.
+pub fn to_json(schema: &IntrospectedSchema) -> Result<String> {
+    let snapshot = SnapshotFile {
+        captured_at: None,
+        ..SnapshotFile::from_schema(schema)
+    };
+    let mut raw =
+        serde_json::to_string_pretty(&snapshot).context("failed to serialize schema JSON")?;
+    raw.push('\n');
+    Ok(raw)
+}
+
+/// The file an input argument names, if it is a snapshot rather than a
+/// database URL: `@<path>` (YAML or JSON), or a bare `*.json` path.
+pub fn input_path(raw: &str) -> Option<&Path> {
+    if let Some(path) = raw.strip_prefix('@') {
+        return Some(Path::new(path));
+    }
+    let path = Path::new(raw);
+    (!raw.contains("://") && path.extension().is_some_and(|ext| ext == "json")).then_some(path)
+}
+
 pub fn load(path: &Path) -> Result<IntrospectedSchema> {
     let raw = fs::read_to_string(path)
         .with_context(|| format!("failed to read snapshot {}", path.display()))?;
     load_str(&raw).with_context(|| format!("failed to load snapshot {}", path.display()))
 }
 
+/// Parse a YAML or JSON snapshot. JSON is valid YAML, so one parser serves
+/// both.
 fn load_str(raw: &str) -> Result<IntrospectedSchema> {
-    let header: SnapshotHeader = serde_yaml::from_str(raw).context("snapshot is not valid YAML")?;
+    let header: SnapshotHeader =
+        serde_yaml::from_str(raw).context("snapshot is not valid YAML or JSON")?;
     match header.format_version {
         Some(FORMAT_VERSION) => {}
         Some(other) => {
@@ -0,0 +1,212 @@
+//! Tokenizer for SQL DDL scripts.
+//!
+//! Only as much lexing as the DDL parser needs: identifiers (bare and
+//! quoted), string literals, numbers and punctuation. Every token keeps its
+//! byte span so expressions (defaults, CHECK predicates) can be copied from
+//! the source verbatim instead of re-rendered from tokens.
+
+use crate::dialect::Dialect;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TokenKind {
+    /// Bare word: keyword or unquoted identifier.
+    Word,
+    /// `"ident"`, `[ident]` (MSSQL) or `` `ident` ``.
+    QuotedIdent,
+    /// `'text'`, `N'text'`, `E'text'` or PostgreSQL `$tag$text$tag$`.
+    Str,
+    Number,
+    Punct,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Token {
+    pub kind: TokenKind,
+    /// Unquoted value: identifier name, string contents, or the punctuation.
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    /// First token on its line (used to recognize MSSQL `GO` separators).
+    pub line_start: bool,
+}
+
+impl Token {
+    pub fn is_word(&self, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.text.eq_ignore_ascii_case(keyword)
+    }
+
+    pub fn is_punct(&self, punct: &str) -> bool {
+        self.kind == TokenKind::Punct && self.text == punct
+    }
+}
+
+/// Split `sql` into statements of tokens. Statements end at `;` and, for
+/// MSSQL scripts, at a `GO` batch separator on its own line.
+pub(super) fn statements(sql: &str, dialect: Dialect) -> Vec<Vec<Token>> {
+    let mut statements = Vec::new();
+    let mut current = Vec::new();
+    for token in tokenize(sql, dialect) {
+        let separator = token.is_punct(";")
+            || (dialect == Dialect::Mssql && token.line_start && token.is_word("GO"));
+        if separator {
+            if !current.is_empty() {
+                statements.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(token);
+        }
+    }
+    if !current.is_empty() {
+        statements.push(current);
+    }
+    statements
+}
+
+fn tokenize(sql: &str, dialect: Dialect) -> Vec<Token> {
+    let bytes = sql.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    let mut line_start = true;
+
+    while pos < bytes.len() {
+        let c = bytes[pos];
+        let start = pos;
+        let (kind, text) = match c {
+            b'\n' => {
+                line_start = true;
+                pos += 1;
+                continue;
+            }
+            c if c.is_ascii_whitespace() => {
+                pos += 1;
+                continue;
+            }
+            b'-' if bytes.get(pos + 1) == Some(&b'-') => {
+                pos = sql[pos..].find('\n').map_or(bytes.len(), |n| pos + n);
+                continue;
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                pos = sql[pos + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |n| pos + 2 + n + 2);
+                line_start = false;
+                continue;
+            }
+            b'\'' => {
+                let (text, end) = quoted(sql, pos, b'\'', b'\'');
+                pos = end;
+                (TokenKind::Str, text)
+            }
+            b'N' | b'n' | b'E' | b'e' | b'X' | b'x' | b'B' | b'b'
+                if bytes.get(pos + 1) == Some(&b'\'') =>
+            {
+                let (text, end) = quoted(sql, pos + 1, b'\'', b'\'');
+                pos = end;
+                (TokenKind::Str, text)
+            }
+            b'"' => {
+                let (text, end) = quoted(sql, pos, b'"', b'"');
+                pos = end;
+                (TokenKind::QuotedIdent, text)
+            }
+            b'`' => {
+                let (text, end) = quoted(sql, pos, b'`', b'`');
+                pos = end;
+                (TokenKind::QuotedIdent, text)
+            }
+            b'[' if dialect == Dialect::Mssql => {
+                let (text, end) = quoted(sql, pos, b'[', b']');
+                pos = end;
+                (TokenKind::QuotedIdent, text)
+            }
+            b'$' if dialect == Dialect::Postgres => match dollar_quoted(sql, pos) {
+                Some((text, end)) => {
+                    pos = end;
+                    (TokenKind::Str, text)
+                }
+                None => {
+                    pos += 1;
+                    (TokenKind::Punct, "$".to_string())
+                }
+            },
+            c if c.is_ascii_digit() => {
+                pos += 1;
+                while pos < bytes.len() && (bytes[pos].is_ascii_digit() || bytes[pos] == b'.') {
+                    pos += 1;
+                }
+                (TokenKind::Number, sql[start..pos].to_string())
+            }
+            c if is_word_byte(c) => {
+                while pos < bytes.len() && (is_word_byte(bytes[pos]) || bytes[pos] == b'$') {
+                    pos += 1;
+                }
+                (TokenKind::Word, sql[start..pos].to_string())
+            }
+            b':' if bytes.get(pos + 1) == Some(&b':') => {
+                pos += 2;
+                (TokenKind::Punct, "::".to_string())
+            }
+            _ => {
+                let len = sql[pos..].chars().next().map_or(1, char::len_utf8);
+                pos += len;
+                (TokenKind::Punct, sql[start..pos].to_string())
+            }
+        };
+        tokens.push(Token {
+            kind,
+            text,
+            start,
+            end: pos,
+            line_start,
+        });
+        line_start = false;
+    }
+    tokens
+}
+
+fn is_word_byte(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_' || c == b'@' || c == b'#' || c >= 0x80
+}
+
+/// Read a literal opened at `pos` by `open` and closed by `close`, where a
+/// doubled `close` is an escaped one. Returns the contents and the end
+/// offset; an unterminated literal runs to the end of the input.
+fn quoted(sql: &str, pos: usize, open: u8, close: u8) -> (String, usize) {
+    debug_assert_eq!(sql.as_bytes()[pos], open);
+    let bytes = sql.as_bytes();
+    let mut text = String::new();
+    let mut i = pos + 1;
+    let mut run = i;
+    while i < bytes.len() {
+        if bytes[i] == close {
+            text.push_str(&sql[run..i]);
+            if bytes.get(i + 1) == Some(&close) {
+                text.push(close as char);
+                i += 2;
+                run = i;
+                continue;
+            }
+            return (text, i + 1);
+        }
+        i += 1;
+    }
+    text.push_str(&sql[run..]);
+    (text, bytes.len())
+}
+
+/// Read a PostgreSQL dollar-quoted string (`$$...$$`, `$fn$...$fn$`).
+fn dollar_quoted(sql: &str, pos: usize) -> Option<(String, usize)> {
+    let rest = &sql[pos + 1..];
+    let tag_len = rest.find('$')?;
+    let tag = &rest[..tag_len];
+    if !tag.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'_') {
+        return None;
+    }
+    let delimiter = format!("${tag}$");
+    let body_start = pos + delimiter.len();
+    let body_len = sql[body_start..].find(&delimiter)?;
+    Some((
+        sql[body_start..body_start + body_len].to_string(),
+        body_start + body_len + delimiter.len(),
+    ))
+}
@@ -0,0 +1,1860 @@
+//! Offline input: build an [`IntrospectedSchema`] from a SQL DDL script.
+//!
+//! Accepts a `pg_dump --schema-only` dump or an SSMS-style MSSQL script as
+//! the positional argument (any `*.sql` path), so models can be generated
+//! in CI without database credentials. The parser understands the
+//! statements those tools emit — `CREATE TABLE`, `ALTER TABLE ... ADD
+//! CONSTRAINT` / `ALTER COLUMN`, `CREATE INDEX`, `CREATE TYPE ... AS ENUM`,
+//! `CREATE DOMAIN`, `COMMENT ON` and `sp_addextendedproperty` — and skips
+//! everything else. Column types are normalized to the names the live
+//! introspectors report, so tables read from a dump generate the same
+//! models as ones read from the database.
+//!
+//! What a dump declares outside those statements is not read: views,
+//! standalone sequences, row-level security policies, triggers, functions
+//! and grants. Output generated from a SQL file leaves them out, where a
+//! live database would supply them.
+//!
+//! The dialect is detected from the script: MSSQL markers (`GO` batch
+//! separators, `[bracketed]` names, `IDENTITY(`, `NVARCHAR`) select SQL
+//! Server, anything else is read as PostgreSQL.
+
+mod lexer;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::dialect::Dialect;
+use crate::schema::{
+    ColumnInfo, ComputedInfo, ConstraintInfo, ConstraintType, DomainInfo, EnumInfo, ExcludeElement,
+    ExcludeInfo, ForeignKeyInfo, IdentityInfo, IndexInfo, IntrospectedSchema, SortOrder, TableInfo,
+    TableType,
+};
+use lexer::{Token, TokenKind};
+
+/// The file an input argument names, if it is a SQL script rather than a
+/// database URL: a bare `*.sql` path.
+pub fn input_path(raw: &str) -> Option<&Path> {
+    let path = Path::new(raw);
+    (!raw.contains("://") && path.extension().is_some_and(|ext| ext == "sql")).then_some(path)
+}
+
+/// Read and parse the DDL script at `path`.
+pub fn load(path: &Path) -> Result<IntrospectedSchema> {
+    let sql = fs::read_to_string(path)
+        .with_context(|| format!("failed to read SQL file {}", path.display()))?;
+    let schema = parse(&sql);
+    if schema.tables.is_empty() {
+        anyhow::bail!("no CREATE TABLE statements found in {}", path.display());
+    }
+    Ok(schema)
+}
+
+/// Parse a DDL script, detecting its dialect.
+pub fn parse(sql: &str) -> IntrospectedSchema {
+    parse_dialect(sql, detect_dialect(sql))
+}
+
+/// Parse a DDL script written for `dialect`. Statements the parser does not
+/// understand are skipped.
+pub fn parse_dialect(sql: &str, dialect: Dialect) -> IntrospectedSchema {
+    let mut builder = Builder::new(dialect);
+    for tokens in lexer::statements(sql, dialect) {
+        let mut cur = Cursor::new(&tokens, sql);
+        if !builder.statement(&mut cur) {
+            tracing::debug!(
+                "skipping SQL statement: {}",
+                cur.text(0, tokens.len()).lines().next().unwrap_or_default()
+            );
+        }
+    }
+    builder.finish()
+}
+
+/// MSSQL if the script has T-SQL markers, otherwise PostgreSQL.
+pub fn detect_dialect(sql: &str) -> Dialect {
+    let lower = sql.to_ascii_lowercase();
+    let mssql = lower.lines().any(|line| line.trim() == "go")
+        || ["[dbo]", "identity(", "nvarchar", "set ansi_nulls"]
+            .iter()
+            .any(|marker| lower.contains(marker));
+    if mssql {
+        Dialect::Mssql
+    } else {
+        Dialect::Postgres
+    }
+}
+
+/// Words that end a column `DEFAULT` or computed column expression.
+const COLUMN_CONSTRAINT_WORDS: &[&str] = &[
+    "NOT",
+    "NULL",
+    "CONSTRAINT",
+    "PRIMARY",
+    "UNIQUE",
+    "REFERENCES",
+    "CHECK",
+    "COLLATE",
+    "GENERATED",
+    "IDENTITY",
+    "ROWGUIDCOL",
+    "SPARSE",
+    "PERSISTED",
+];
+
+/// Words that end an index key expression.
+const INDEX_KEY_WORDS: &[&str] = &["ASC", "DESC", "NULLS", "COLLATE"];
+
+/// Position-tracking view over one statement's tokens.
+struct Cursor<'a> {
+    tokens: &'a [Token],
+    sql: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(tokens: &'a [Token], sql: &'a str) -> Self {
+        Self {
+            tokens,
+            sql,
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&'a Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.pos)?;
+        self.pos += 1;
+        Some(token)
+    }
+
+    fn at_word(&self, keyword: &str) -> bool {
+        self.peek().is_some_and(|t| t.is_word(keyword))
+    }
+
+    fn at_punct(&self, punct: &str) -> bool {
+        self.peek().is_some_and(|t| t.is_punct(punct))
+    }
+
+    fn eat_word(&mut self, keyword: &str) -> bool {
+        let hit = self.at_word(keyword);
+        if hit {
+            self.pos += 1;
+        }
+        hit
+    }
+
+    /// Consume `keywords` if they come next, in order; otherwise nothing.
+    fn eat_words(&mut self, keywords: &[&str]) -> bool {
+        let hit = keywords
+            .iter()
+            .enumerate()
+            .all(|(i, kw)| self.peek_at(i).is_some_and(|t| t.is_word(kw)));
+        if hit {
+            self.pos += keywords.len();
+        }
+        hit
+    }
+
+    fn eat_punct(&mut self, punct: &str) -> bool {
+        let hit = self.at_punct(punct);
+        if hit {
+            self.pos += 1;
+        }
+        hit
+    }
+
+    /// End of the current list element: end of statement, or a `,` / `)`
+    /// at depth zero.
+    fn at_element_end(&self) -> bool {
+        self.peek()
+            .is_none_or(|t| t.is_punct(",") || t.is_punct(")"))
+    }
+
+    /// Skip one token, or a whole parenthesized group.
+    fn skip(&mut self) {
+        if self.eat_punct("(") {
+            let mut depth = 1;
+            while let Some(token) = self.next() {
+                if token.is_punct("(") {
+                    depth += 1;
+                } else if token.is_punct(")") {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+        } else {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_to_element_end(&mut self) {
+        while !self.at_element_end() {
+            self.skip();
+        }
+    }
+
+    /// Source text of tokens `from..to`.
+    fn text(&self, from: usize, to: usize) -> &'a str {
+        if from >= to {
+            return "";
+        }
+        &self.sql[self.tokens[from].start..self.tokens[to - 1].end]
+    }
+
+    /// Source text up to the end of the element or the first depth-zero
+    /// word in `stop`. The first token is always taken.
+    fn expr(&mut self, stop: &[&str]) -> String {
+        let from = self.pos;
+        if !self.at_element_end() {
+            self.skip();
+        }
+        while !self.at_element_end()
+            && !self
+                .peek()
+                .is_some_and(|t| stop.iter().any(|kw| t.is_word(kw)))
+        {
+            self.skip();
+        }
+        self.text(from, self.pos).to_string()
+    }
+
+    /// Source text inside the parenthesized group at the cursor.
+    fn paren_expr(&mut self) -> Option<String> {
+        if !self.at_punct("(") {
+            return None;
+        }
+        let from = self.pos + 1;
+        self.skip();
+        Some(self.text(from, self.pos - 1).to_string())
+    }
+
+    fn number(&mut self) -> Option<i64> {
+        let negative = self.eat_punct("-");
+        let token = self.peek().filter(|t| t.kind == TokenKind::Number)?;
+        let value: i64 = token.text.parse().ok()?;
+        self.pos += 1;
+        Some(if negative { -value } else { value })
+    }
+}
+
+/// Parsed `CREATE INDEX` key list; see [`Builder::index_keys`].
+#[derive(Default)]
+struct IndexKeys {
+    columns: Vec<String>,
+    expressions: Vec<String>,
+    opclasses: BTreeMap<String, String>,
+    orderings: BTreeMap<String, SortOrder>,
+}
+
+/// A possibly schema-qualified object name.
+#[derive(Debug, Clone, PartialEq)]
+struct QualifiedName {
+    schema: Option<String>,
+    name: String,
+}
+
+/// A declared column type, before normalization.
+#[derive(Debug, Default, Clone)]
+struct TypeSpec {
+    /// Lowercased for built-in types, e.g. `character varying`.
+    name: String,
+    schema: Option<String>,
+    args: Vec<String>,
+    array: bool,
+    /// Number of `[]` groups, e.g. 2 for `integer[][]`.
+    dimensions: i32,
+}
+
+struct Builder {
+    dialect: Dialect,
+    tables: Vec<TableInfo>,
+    enums: Vec<EnumInfo>,
+    domains: Vec<DomainInfo>,
+    /// Declared base type of each domain in `domains`, by domain name, so
+    /// domain columns keep the base type's length and precision.
+    domain_types: Vec<(String, TypeSpec)>,
+}
+
+impl Builder {
+    fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            tables: Vec::new(),
+            enums: Vec::new(),
+            domains: Vec::new(),
+            domain_types: Vec::new(),
+        }
+    }
+
+    /// Apply one statement. Returns false if it was skipped.
+    fn statement(&mut self, cur: &mut Cursor) -> bool {
+        if cur.eat_word("CREATE") {
+            cur.eat_words(&["OR", "REPLACE"]);
+            let mut temporary = false;
+            for modifier in ["GLOBAL", "LOCAL", "TEMP", "TEMPORARY", "UNLOGGED"] {
+                if cur.eat_word(modifier) && modifier.starts_with("TEMP") {
+                    temporary = true;
+                }
+            }
+            if cur.eat_word("TABLE") {
+                return self.create_table(cur, temporary);
+            }
+            if cur.eat_word("TYPE") {
+                return self.create_type(cur);
+            }
+            if cur.eat_word("DOMAIN") {
+                return self.create_domain(cur);
+            }
+            let mut index = IndexInfo::new("", cur.eat_word("UNIQUE"), Vec::<String>::new());
+            index.clustered = cur.eat_word("CLUSTERED");
+            if !index.clustered {
+                cur.eat_word("NONCLUSTERED");
+            }
+            index.columnstore = cur.eat_word("COLUMNSTORE");
+            if cur.eat_word("INDEX") {
+                return self.create_index(cur, index);
+            }
+            return false;
+        }
+        if cur.eat_words(&["ALTER", "TABLE"]) {
+            return self.alter_table(cur);
+        }
+        if cur.eat_words(&["COMMENT", "ON"]) {
+            return self.comment_on(cur);
+        }
+        if cur.eat_word("EXEC") || cur.eat_word("EXECUTE") {
+            return self.extended_property(cur);
+        }
+        false
+    }
+
+    fn finish(mut self) -> IntrospectedSchema {
+        // `REFERENCES t` without a column list targets t's primary key.
+        let primary_keys: Vec<(String, String, Vec<String>)> = self
+            .tables
+            .iter()
+            .filter_map(|t| {
+                let pk = t
+                    .constraints
+                    .iter()
+                    .find(|c| c.constraint_type == ConstraintType::PrimaryKey)?;
+                Some((t.schema.clone(), t.name.clone(), pk.columns.clone()))
+            })
+            .collect();
+        let dialect = self.dialect;
+        for table in &mut self.tables {
+            for fk in table
+                .constraints
+                .iter_mut()
+                .filter_map(|c| c.foreign_key.as_mut())
+                .filter(|fk| fk.ref_columns.is_empty())
+            {
+                if let Some((_, _, columns)) = primary_keys.iter().find(|(schema, name, _)| {
+                    same_name(dialect, schema, &fk.ref_schema)
+                        && same_name(dialect, name, &fk.ref_table)
+                }) {
+                    fk.ref_columns = columns.clone();
+                }
+            }
+        }
+        IntrospectedSchema {
+            dialect: self.dialect,
+            tables: self.tables,
+            enums: self.enums,
+            domains: self.domains,
+            sequences: Vec::new(),
+            extensions: Vec::new(),
+        }
+    }
+
+    // ----- names -----
+
+    /// Identifier at the cursor. PostgreSQL folds unquoted names to lower
+    /// case; SQL Server keeps them as written.
+    fn ident(&self, cur: &mut Cursor) -> Option<String> {
+        let token = cur.peek()?;
+        let name = match token.kind {
+            TokenKind::QuotedIdent => token.text.clone(),
+            TokenKind::Word if self.dialect == Dialect::Postgres => token.text.to_lowercase(),
+            TokenKind::Word => token.text.clone(),
+            _ => return None,
+        };
+        cur.pos += 1;
+        Some(name)
+    }
+
+    /// `name`, `schema.name` or `database.schema.name` (database dropped).
+    fn qualified_name(&self, cur: &mut Cursor) -> Option<QualifiedName> {
+        let mut parts = vec![self.ident(cur)?];
+        while cur.at_punct(".") {
+            cur.pos += 1;
+            parts.push(self.ident(cur)?);
+        }
+        let name = parts.pop()?;
+        Some(QualifiedName {
+            schema: parts.pop(),
+            name,
+        })
+    }
+
+    fn schema_or_default(&self, schema: Option<String>) -> String {
+        schema.unwrap_or_else(|| self.dialect.default_schema().to_string())
+    }
+
+    fn table_mut(&mut self, name: &QualifiedName) -> Option<&mut TableInfo> {
+        let dialect = self.dialect;
+        let schema = self.schema_or_default(name.schema.clone());
+        self.tables.iter_mut().find(|t| {
+            same_name(dialect, &t.schema, &schema) && same_name(dialect, &t.name, &name.name)
+        })
+    }
+
+    /// `( col [ASC|DESC], ... )`. `None` if an element is an expression
+    /// rather than a plain column.
+    fn column_list(&self, cur: &mut Cursor) -> Option<Vec<String>> {
+        if !cur.eat_punct("(") {
+            return None;
+        }
+        let mut columns = Vec::new();
+        let mut plain = true;
+        loop {
+            match self.ident(cur) {
+                Some(column) => columns.push(column),
+                None => plain = false,
+            }
+            // Sort order, NULLS FIRST/LAST and operator classes are words;
+            // anything else makes this an expression element.
+            while !cur.at_element_end() {
+                if cur.peek().is_some_and(|t| t.kind != TokenKind::Word) {
+                    plain = false;
+                }
+                cur.skip();
+            }
+            if !cur.eat_punct(",") {
+                break;
+            }
+        }
+        cur.eat_punct(")");
+        plain.then_some(columns)
+    }
+
+    /// `CREATE INDEX` keys: the plain columns and their operator classes,
+    /// plus every key as source text when any of them is an expression
+    /// (`lower(email)`).
+    fn index_keys(&self, cur: &mut Cursor) -> Option<IndexKeys> {
+        if !cur.eat_punct("(") {
+            return None;
+        }
+        let mut parsed = IndexKeys::default();
+        let mut keys = Vec::new();
+        let mut expressions = false;
+        loop {
+            let start = cur.pos;
+            let column = self.ident(cur).filter(|_| {
+                cur.at_element_end() || cur.peek().is_some_and(|t| t.kind == TokenKind::Word)
+            });
+            match column {
+                Some(column) => {
+                    if let Some(opclass) = self.opclass(cur) {
+                        parsed.opclasses.insert(column.clone(), opclass);
+                    }
+                    keys.push(column.clone());
+                    parsed.columns.push(column);
+                }
+                None => {
+                    cur.pos = start;
+                    expressions = true;
+                    let expr = match cur.paren_expr() {
+                        Some(expr) => expr,
+                        None => cur.expr(INDEX_KEY_WORDS),
+                    };
+                    keys.push(expr);
+                }
+            }
+            let descending = cur.eat_word("DESC");
+            if !descending {
+                cur.eat_word("ASC");
+            }
+            let nulls_first = if cur.eat_words(&["NULLS", "FIRST"]) {
+                true
+            } else if cur.eat_words(&["NULLS", "LAST"]) {
+                false
+            } else {
+                descending
+            };
+            let order = SortOrder {
+                descending,
+                nulls_first,
+            };
+            if let Some(key) = keys.last().filter(|_| !order.is_default()) {
+                parsed.orderings.insert(key.clone(), order);
+            }
+            // Anything else: collations on expressions, index options.
+            cur.skip_to_element_end();
+            if !cur.eat_punct(",") {
+                break;
+            }
+        }
+        cur.eat_punct(")");
+        if expressions {
+            parsed.expressions = keys;
+        }
+        Some(parsed)
+    }
+
+    /// The operator class after an index key column, if one is named
+    /// (`name gin_trgm_ops`, `path public.text_ops DESC`).
+    fn opclass(&self, cur: &mut Cursor) -> Option<String> {
+        if cur.eat_word("COLLATE") {
+            self.qualified_name(cur);
+        }
+        let at_option = cur
+            .peek()
+            .is_none_or(|t| INDEX_KEY_WORDS.iter().any(|w| t.is_word(w)));
+        if at_option || cur.at_element_end() {
+            return None;
+        }
+        self.qualified_name(cur).map(|n| n.name)
+    }
+
+    // ----- CREATE TABLE -----
+
+    fn create_table(&mut self, cur: &mut Cursor, temporary: bool) -> bool {
+        cur.eat_words(&["IF", "NOT", "EXISTS"]);
+        let Some(name) = self.qualified_name(cur) else {
+            return false;
+        };
+        // `PARTITION OF`, `AS SELECT` and friends carry no column list.
+        if !cur.eat_punct("(") {
+            return false;
+        }
+        let schema = self.schema_or_default(name.schema.clone());
+        if self.table_mut(&name).is_some() {
+            return false;
+        }
+        self.tables.push(TableInfo {
+            is_temporary: temporary,
+            ..TableInfo::new(schema, name.name.clone(), TableType::Table)
+        });
+
+        loop {
+            if cur.at_punct(")") || cur.peek().is_none() {
+                break;
+            }
+            if !self.table_constraint(cur, &name) && !self.table_element(cur, &name) {
+                self.add_column(cur, &name);
+            }
+            cur.skip_to_element_end();
+            if !cur.eat_punct(",") {
+                break;
+            }
+        }
+        cur.eat_punct(")");
+        if cur.eat_word("INHERITS") {
+            self.inherit(cur, &name);
+        }
+        true
+    }
+
+    /// `INHERITS (parent, ...)`: the parents' columns come first, marked
+    /// inherited, followed by the child's own, and the parents' CHECK
+    /// constraints apply to the child, as the catalog reports them. A
+    /// column the child redeclares is merged into the inherited one.
+    fn inherit(&mut self, cur: &mut Cursor, table: &QualifiedName) {
+        if !cur.eat_punct("(") {
+            return;
+        }
+        let mut parents = Vec::new();
+        while let Some(parent) = self.qualified_name(cur) {
+            parents.push(parent);
+            if !cur.eat_punct(",") {
+                break;
+            }
+        }
+        cur.eat_punct(")");
+
+        let dialect = self.dialect;
+        let mut columns: Vec<ColumnInfo> = Vec::new();
+        let mut checks: Vec<ConstraintInfo> = Vec::new();
+        let mut names = Vec::new();
+        for parent in &parents {
+            let Some(parent) = self.table_mut(parent) else {
+                continue;
+            };
+            names.push(parent.name.clone());
+            for col in &parent.columns {
+                if !columns
+                    .iter()
+                    .any(|c| same_name(dialect, &c.name, &col.name))
+                {
+                    columns.push(ColumnInfo {
+                        inherited: true,
+                        ..col.clone()
+                    });
+                }
+            }
+            checks.extend(
+                parent
+                    .constraints
+                    .iter()
+                    .filter(|c| c.constraint_type == ConstraintType::Check)
+                    .cloned(),
+            );
+        }
+        let Some(child) = self.table_mut(table) else {
+            return;
+        };
+        for col in std::mem::take(&mut child.columns) {
+            match columns
+                .iter_mut()
+                .find(|c| same_name(dialect, &c.name, &col.name))
+            {
+                Some(inherited) => {
+                    *inherited = ColumnInfo {
+                        inherited: true,
+                        ..col
+                    }
+                }
+                None => columns.push(col),
+            }
+        }
+        for (i, col) in columns.iter_mut().enumerate() {
+            col.ordinal_position = i as i32 + 1;
+        }
+        child.columns = columns;
+        child.inherits = names;
+        for check in checks {
+            if !child.constraints.iter().any(|c| c.name == check.name) {
+                child.constraints.push(check);
+            }
+        }
+    }
+
+    /// Non-column `CREATE TABLE` elements: `LIKE`, `EXCLUDE`, MSSQL
+    /// `PERIOD FOR SYSTEM_TIME` (all skipped) and MSSQL inline `INDEX`.
+    fn table_element(&mut self, cur: &mut Cursor, table: &QualifiedName) -> bool {
+        let token = |i: usize| cur.peek_at(i).filter(|t| t.kind == TokenKind::Word);
+        if token(0).is_some_and(|t| ["LIKE", "EXCLUDE"].iter().any(|kw| t.is_word(kw)))
+            || (token(0).is_some_and(|t| t.is_word("PERIOD"))
+                && token(1).is_some_and(|t| t.is_word("FOR")))
+        {
+            return true;
+        }
+        let inline_index = token(0).is_some_and(|t| t.is_word("INDEX"))
+            && cur.peek_at(2).is_some_and(|t| {
+                t.is_punct("(")
+                    || ["UNIQUE", "CLUSTERED", "NONCLUSTERED"]
+                        .iter()
+                        .any(|kw| t.is_word(kw))
+            });
+        if !inline_index {
+            return false;
+        }
+        cur.pos += 1;
+        let Some(name) = self.ident(cur) else {
+            return true;
+        };
+        let unique = cur.eat_word("UNIQUE");
+        self.skip_index_kind(cur);
+        if let (Some(columns), Some(t)) = (self.column_list(cur), self.table_mut(table)) {
+            t.indexes.push(IndexInfo::new(name, unique, columns));
+        }
+        true
+    }
+
+    /// Parse a column definition at the cursor and add it to `table`.
+    fn add_column(&mut self, cur: &mut Cursor, table: &QualifiedName) {
+        let Some(column_name) = self.ident(cur) else {
+            return;
+        };
+        // MSSQL computed column (`total AS (a * b) [PERSISTED]`): the type
+        // is not declared, so it is left empty and maps to NullType.
+        if cur.eat_word("AS") {
+            let mut col = ColumnInfo::new(column_name.clone(), 0, true, "", "");
+            col.computed = Some(ComputedInfo {
+                expression: cur.expr(COLUMN_CONSTRAINT_WORDS),
+                persisted: Some(false),
+            });
+            self.column_constraints(cur, table, col);
+            return;
+        }
+        let spec = self.type_spec(cur);
+        let mut col = self.column_from_type(&column_name, &spec);
+        if self.dialect == Dialect::Postgres && spec.name.contains("serial") && !spec.array {
+            // What `serial` expands to: a NOT NULL column defaulting to its
+            // owned sequence.
+            col.is_nullable = false;
+            col.column_default = Some(format!(
+                "nextval('{}_{}_seq'::regclass)",
+                table.name, column_name
+            ));
+        }
+        self.column_constraints(cur, table, col);
+    }
+
+    /// Parse the constraints after a column's type, then add the column
+    /// and its constraints to `table`.
+    fn column_constraints(&mut self, cur: &mut Cursor, table: &QualifiedName, mut col: ColumnInfo) {
+        let column_name = col.name.clone();
+        let mut pending_name: Option<String> = None;
+        let mut constraints: Vec<ConstraintInfo> = Vec::new();
+        while !cur.at_element_end() {
+            if cur.eat_word("CONSTRAINT") {
+                pending_name = self.ident(cur);
+            } else if cur.eat_words(&["NOT", "NULL"]) {
+                col.is_nullable = false;
+            } else if cur.eat_word("NULL") {
+                col.is_nullable = true;
+            } else if cur.eat_word("DEFAULT") {
+                col.column_default = Some(cur.expr(COLUMN_CONSTRAINT_WORDS));
+                let name = pending_name.take();
+                if self.dialect == Dialect::Mssql {
+                    col.default_constraint = name;
+                }
+            } else if cur.eat_words(&["PRIMARY", "KEY"]) {
+                col.is_nullable = false;
+                constraints.push(ConstraintInfo::primary_key(
+                    pending_name.take().unwrap_or_default(),
+                    [column_name.clone()],
+                ));
+            } else if cur.eat_word("UNIQUE") {
+                constraints.push(ConstraintInfo {
+                    nulls_not_distinct: self.nulls_not_distinct(cur),
+                    ..ConstraintInfo::unique(
+                        pending_name.take().unwrap_or_default(),
+                        [column_name.clone()],
+                    )
+                });
+            } else if cur.eat_word("REFERENCES") {
+                if let Some(fk) = self.references(cur) {
+                    constraints.push(ConstraintInfo::foreign_key(
+                        pending_name.take().unwrap_or_default(),
+                        [column_name.clone()],
+                        fk,
+                    ));
+                }
+            } else if cur.eat_word("CHECK") {
+                cur.eat_words(&["NOT", "FOR", "REPLICATION"]);
+                if let Some(expr) = cur.paren_expr() {
+                    constraints.push(ConstraintInfo {
+                        columns: vec![column_name.clone()],
+                        ..ConstraintInfo::check(pending_name.take().unwrap_or_default(), expr)
+                    });
+                }
+            } else if cur.eat_word("COLLATE") {
+                col.collation = self.qualified_name(cur).map(|n| n.name);
+            } else if cur.eat_word("GENERATED") {
+                self.generated(cur, &mut col);
+            } else if cur.eat_word("IDENTITY") {
+                let (seed, increment) = self.identity_args(cur);
+                set_identity(&mut col, "ALWAYS", IdentityInfo::seed(seed, increment));
+            } else if cur.eat_word("PERSISTED") {
+                if let Some(computed) = col.computed.as_mut() {
+                    computed.persisted = Some(true);
+                }
+            } else {
+                cur.skip();
+            }
+        }
+
+        let Some(t) = self.table_mut(table) else {
+            return;
+        };
+        col.ordinal_position = t.columns.len() as i32 + 1;
+        t.columns.push(col);
+        for constraint in constraints {
+            add_constraint(t, constraint);
+        }
+    }
+
+    /// `GENERATED {ALWAYS | BY DEFAULT} AS IDENTITY [(options)]`, or a
+    /// stored generated column (`GENERATED ALWAYS AS (expr) STORED`).
+    fn generated(&self, cur: &mut Cursor, col: &mut ColumnInfo) {
+        let generation = if cur.eat_word("ALWAYS") {
+            "ALWAYS"
+        } else if cur.eat_words(&["BY", "DEFAULT"]) {
+            "BY DEFAULT"
+        } else {
+            return;
+        };
+        if cur.eat_words(&["AS", "IDENTITY"]) {
+            let identity = self.sequence_options(cur, col);
+            set_identity(col, generation, identity);
+        } else if cur.eat_word("AS") {
+            if let Some(expression) = cur.paren_expr() {
+                let persisted = !cur.eat_word("VIRTUAL");
+                cur.eat_word("STORED");
+                col.computed = Some(ComputedInfo {
+                    expression,
+                    persisted: Some(persisted),
+                });
+            }
+        }
+    }
+
+    /// MSSQL `IDENTITY [(seed, increment)]`.
+    fn identity_args(&self, cur: &mut Cursor) -> (i64, i64) {
+        if !cur.eat_punct("(") {
+            return (1, 1);
+        }
+        let seed = cur.number().unwrap_or(1);
+        cur.eat_punct(",");
+        let increment = cur.number().unwrap_or(1);
+        cur.skip_to_element_end();
+        cur.eat_punct(")");
+        (seed, increment)
+    }
+
+    /// PostgreSQL identity sequence options, with the server's defaults
+    /// for anything not spelled out.
+    fn sequence_options(&self, cur: &mut Cursor, col: &ColumnInfo) -> IdentityInfo {
+        let max_value = match col.udt_name.as_str() {
+            "int2" => i16::MAX as i64,
+            "int4" => i32::MAX as i64,
+            _ => i64::MAX,
+        };
+        let mut identity = IdentityInfo::new(1, 1, 1, max_value, false, 1);
+        if !cur.eat_punct("(") {
+            return identity;
+        }
+        while !cur.eat_punct(")") && cur.peek().is_some() {
+            if cur.eat_words(&["SEQUENCE", "NAME"]) {
+                self.qualified_name(cur);
+            } else if cur.eat_word("START") {
+                cur.eat_word("WITH");
+                identity.start = cur.number().unwrap_or(identity.start);
+            } else if cur.eat_word("INCREMENT") {
+                cur.eat_word("BY");
+                identity.increment = cur.number().unwrap_or(identity.increment);
+            } else if cur.eat_word("MINVALUE") {
+                identity.min_value = cur.number().or(identity.min_value);
+            } else if cur.eat_word("MAXVALUE") {
+                identity.max_value = cur.number().or(identity.max_value);
+            } else if cur.eat_word("CACHE") {
+                identity.cache = cur.number().or(identity.cache);
+            } else if cur.eat_word("CYCLE") {
+                identity.cycle = Some(true);
+            } else {
+                // NO MINVALUE / NO MAXVALUE / NO CYCLE keep the defaults.
+                cur.skip();
+            }
+        }
+        if identity.increment < 0 {
+            // Descending sequences default to MINVALUE = type minimum and
+            // MAXVALUE = -1.
+            identity.min_value = identity.max_value.map(|max| -max - 1);
+            identity.max_value = Some(-1);
+        }
+        identity
+    }
+
+    /// `REFERENCES t [(cols)] [ON DELETE action] [ON UPDATE action] ...`
+    fn references(&self, cur: &mut Cursor) -> Option<ForeignKeyInfo> {
+        let mut parts = self.qualified_name_parts(cur)?;
+        let name = parts.pop()?;
+        let schema = parts.pop();
+        // SQL Server `database.schema.table` points into another database.
+        let ref_database = parts.pop();
+        let ref_columns = if cur.at_punct("(") {
+            self.column_list(cur).unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let mut update_rule = "NO ACTION".to_string();
+        let mut delete_rule = "NO ACTION".to_string();
+        loop {
+            let rule = if cur.eat_words(&["ON", "DELETE"]) {
+                &mut delete_rule
+            } else if cur.eat_words(&["ON", "UPDATE"]) {
+                &mut update_rule
+            } else {
+                break;
+            };
+            let action = ["CASCADE", "RESTRICT"]
+                .into_iter()
+                .find(|kw| cur.eat_word(kw))
+                .map(str::to_string)
+                .or_else(|| {
+                    [["SET", "NULL"], ["SET", "DEFAULT"], ["NO", "ACTION"]]
+                        .into_iter()
+                        .find(|kws| cur.eat_words(kws))
+                        .map(|kws| kws.join(" "))
+                });
+            if let Some(action) = action {
+                *rule = action;
+            }
+        }
+        let mut fk = ForeignKeyInfo::new(
+            self.schema_or_default(schema),
+            name,
+            ref_columns,
+            update_rule,
+            delete_rule,
+        );
+        loop {
+            if cur.eat_words(&["NOT", "DEFERRABLE"]) {
+                fk.deferrable = false;
+            } else if cur.eat_word("DEFERRABLE") {
+                fk.deferrable = true;
+            } else if cur.eat_words(&["INITIALLY", "DEFERRED"]) {
+                fk.initially_deferred = true;
+            } else if !cur.eat_words(&["INITIALLY", "IMMEDIATE"]) {
+                break;
+            }
+        }
+        fk.ref_database = ref_database;
+        Some(fk)
+    }
+
+    /// Parse a table-level constraint at the cursor and add it to `table`.
+    /// Returns false (consuming nothing) if the element is not a constraint.
+    fn table_constraint(&mut self, cur: &mut Cursor, table: &QualifiedName) -> bool {
+        let start = cur.pos;
+        let name = if cur.eat_word("CONSTRAINT") {
+            self.ident(cur)
+        } else {
+            None
+        };
+        let constraint = if cur.eat_words(&["PRIMARY", "KEY"]) {
+            self.skip_index_kind(cur);
+            self.column_list(cur)
+                .map(|cols| ConstraintInfo::primary_key(name.unwrap_or_default(), cols))
+        } else if cur.eat_word("UNIQUE") {
+            let nulls_not_distinct = self.nulls_not_distinct(cur);
+            self.skip_index_kind(cur);
+            self.column_list(cur).map(|cols| ConstraintInfo {
+                nulls_not_distinct,
+                ..ConstraintInfo::unique(name.unwrap_or_default(), cols)
+            })
+        } else if cur.eat_words(&["FOREIGN", "KEY"]) {
+            let columns = self.column_list(cur).unwrap_or_default();
+            cur.eat_word("REFERENCES");
+            self.references(cur)
+                .map(|fk| ConstraintInfo::foreign_key(name.unwrap_or_default(), columns, fk))
+        } else if cur.eat_word("CHECK") {
+            cur.eat_words(&["NOT", "FOR", "REPLICATION"]);
+            cur.paren_expr()
+                .map(|expr| ConstraintInfo::check(name.unwrap_or_default(), expr))
+        } else if cur.eat_word("EXCLUDE") {
+            self.exclude(cur)
+                .map(|exclude| ConstraintInfo::exclude(name.unwrap_or_default(), exclude))
+        } else if name.is_some() && cur.eat_word("DEFAULT") {
+            // MSSQL `CONSTRAINT df DEFAULT (expr) FOR col`.
+            let expr = cur.expr(&["FOR"]);
+            if cur.eat_word("FOR") {
+                if let Some(column) = self.ident(cur) {
+                    let dialect = self.dialect;
+                    if let Some(col) = self.table_mut(table).and_then(|t| {
+                        t.columns
+                            .iter_mut()
+                            .find(|c| same_name(dialect, &c.name, &column))
+                    }) {
+                        col.column_default = Some(expr);
+                        col.default_constraint = name;
+                    }
+                }
+            }
+            return true;
+        } else {
+            cur.pos = start;
+            return false;
+        };
+
+        if let (Some(constraint), Some(t)) = (constraint, self.table_mut(table)) {
+            if constraint.constraint_type == ConstraintType::PrimaryKey {
+                for col in t
+                    .columns
+                    .iter_mut()
+                    .filter(|c| constraint.columns.contains(&c.name))
+                {
+                    col.is_nullable = false;
+                }
+            }
+            add_constraint(t, constraint);
+        }
+        true
+    }
+
+    /// MSSQL `CLUSTERED` / `NONCLUSTERED` after `PRIMARY KEY` / `UNIQUE`.
+    fn skip_index_kind(&self, cur: &mut Cursor) {
+        cur.eat_word("CLUSTERED");
+        cur.eat_word("NONCLUSTERED");
+    }
+
+    /// `EXCLUDE [USING method] (key WITH op, ...) ... [WHERE (predicate)]`,
+    /// after `EXCLUDE`.
+    fn exclude(&self, cur: &mut Cursor) -> Option<ExcludeInfo> {
+        let using = if cur.eat_word("USING") {
+            self.ident(cur)?
+        } else {
+            "gist".to_string()
+        };
+        if !cur.eat_punct("(") {
+            return None;
+        }
+        let mut elements = Vec::new();
+        loop {
+            let start = cur.pos;
+            let (key, is_expression) = match self.ident(cur).filter(|_| cur.at_word("WITH")) {
+                Some(column) => (column, false),
+                None => {
+                    cur.pos = start;
+                    match cur.paren_expr().filter(|_| cur.at_word("WITH")) {
+                        Some(expr) => (expr, true),
+                        None => {
+                            cur.pos = start;
+                            (cur.expr(&["WITH"]), true)
+                        }
+                    }
+                }
+            };
+            if !cur.eat_word("WITH") {
+                return None;
+            }
+            let from = cur.pos;
+            cur.skip_to_element_end();
+            elements.push(ExcludeElement {
+                key,
+                is_expression,
+                operator: cur.text(from, cur.pos).to_string(),
+            });
+            if !cur.eat_punct(",") {
+                break;
+            }
+        }
+        cur.eat_punct(")");
+        // Index parameters (`INCLUDE`, `WITH (...)`, tablespace) are skipped.
+        while !cur.at_element_end() && !cur.at_word("WHERE") {
+            cur.skip();
+        }
+        let predicate = if cur.eat_word("WHERE") {
+            cur.paren_expr()
+        } else {
+            None
+        };
+        Some(ExcludeInfo {
+            using,
+            elements,
+            predicate,
+        })
+    }
+
+    /// PostgreSQL 15 `NULLS [NOT] DISTINCT`; `true` for `NOT DISTINCT`.
+    fn nulls_not_distinct(&self, cur: &mut Cursor) -> bool {
+        if cur.eat_words(&["NULLS", "NOT", "DISTINCT"]) {
+            return true;
+        }
+        cur.eat_words(&["NULLS", "DISTINCT"]);
+        false
+    }
+
+    // ----- types -----
+
+    fn type_spec(&self, cur: &mut Cursor) -> TypeSpec {
+        let Some(first) = cur.peek() else {
+            return TypeSpec::default();
+        };
+        let builtin = first.kind == TokenKind::Word || self.dialect == Dialect::Mssql;
+        let Some(name) = self.qualified_name(cur) else {
+            return TypeSpec::default();
+        };
+        let mut spec = TypeSpec {
+            name: if builtin {
+                name.name.to_lowercase()
+            } else {
+                name.name
+            },
+            schema: name.schema,
+            ..TypeSpec::default()
+        };
+
+        match spec.name.as_str() {
+            "double" if cur.eat_word("PRECISION") => spec.name.push_str(" precision"),
+            "character" | "char" | "bit" | "national" if cur.eat_word("VARYING") => {
+                spec.name.push_str(" varying")
+            }
+            _ => {}
+        }
+        if cur.eat_punct("(") {
+            while !cur.at_punct(")") && cur.peek().is_some() {
+                let from = cur.pos;
+                cur.skip_to_element_end();
+                spec.args.push(cur.text(from, cur.pos).to_string());
+                cur.eat_punct(",");
+            }
+            cur.eat_punct(")");
+        }
+        if cur.eat_words(&["WITH", "TIME", "ZONE"]) {
+            spec.name.push_str(" with time zone");
+        } else if cur.eat_words(&["WITHOUT", "TIME", "ZONE"]) {
+            spec.name.push_str(" without time zone");
+        }
+        while cur.at_punct("[") {
+            cur.pos += 1;
+            while !cur.eat_punct("]") && cur.peek().is_some() {
+                cur.pos += 1;
+            }
+            spec.array = true;
+            spec.dimensions += 1;
+        }
+        if cur.eat_word("ARRAY") {
+            spec.array = true;
+            spec.dimensions += 1;
+            if cur.eat_punct("[") {
+                while !cur.eat_punct("]") && cur.peek().is_some() {
+                    cur.pos += 1;
+                }
+            }
+        }
+        spec
+    }
+
+    /// A nullable column of the declared type, described the way the live
+    /// introspector for this dialect reports it.
+    fn column_from_type(&self, name: &str, spec: &TypeSpec) -> ColumnInfo {
+        let arg = |i: usize| spec.args.get(i).and_then(|a| a.trim().parse::<i32>().ok());
+        match self.dialect {
+            Dialect::Mssql => {
+                let udt = spec.name.as_str();
+                let mut col = ColumnInfo::new(name, 0, true, udt, udt);
+                match udt {
+                    "varchar" | "nvarchar" | "char" | "nchar" | "binary" | "varbinary" => {
+                        // `(max)` reports no length, like the live introspector.
+                        col.character_maximum_length = if spec.args.is_empty() {
+                            Some(1)
+                        } else {
+                            arg(0)
+                        };
+                    }
+                    "decimal" | "numeric" => {
+                        col.numeric_precision = Some(arg(0).unwrap_or(18));
+                        col.numeric_scale = Some(arg(1).unwrap_or(0));
+                    }
+                    // 7 is the default scale, which the live introspector
+                    // leaves unset.
+                    "time" | "datetime2" | "datetimeoffset" => {
+                        col.datetime_precision = arg(0).filter(|&p| p != 7);
+                    }
+                    _ => {}
+                }
+                col
+            }
+            _ => {
+                if let Some((_, base)) = self.domain_types.iter().find(|(d, _)| *d == spec.name) {
+                    let base = TypeSpec {
+                        array: spec.array,
+                        dimensions: spec.dimensions,
+                        ..base.clone()
+                    };
+                    let mut col = self.column_from_type(name, &base);
+                    if !spec.array {
+                        col.domain = Some(spec.name.clone());
+                    }
+                    return col;
+                }
+                let (data_type, udt_name) = self.pg_type(spec);
+                let mut col = ColumnInfo::new(name, 0, true, data_type, udt_name.clone());
+                let element = udt_name.trim_start_matches('_');
+                match element {
+                    "varchar" | "bpchar" | "bit" | "varbit" => {
+                        col.character_maximum_length =
+                            arg(0).or((element == "bpchar" || element == "bit").then_some(1));
+                    }
+                    "numeric" => {
+                        col.numeric_precision = arg(0);
+                        col.numeric_scale = arg(0).map(|_| arg(1).unwrap_or(0));
+                    }
+                    "time" | "timetz" | "timestamp" | "timestamptz" => {
+                        col.datetime_precision = arg(0);
+                    }
+                    _ => {}
+                }
+                if spec.dimensions > 1 {
+                    col.array_dimensions = Some(spec.dimensions);
+                }
+                if !is_pg_builtin(element) {
+                    col.udt_schema = Some(
+                        spec.schema
+                            .clone()
+                            .unwrap_or_else(|| self.dialect.default_schema().to_string()),
+                    );
+                }
+                col
+            }
+        }
+    }
+
+    /// `(data_type, udt_name)` for a PostgreSQL type, as
+    /// `information_schema.columns` reports them.
+    fn pg_type(&self, spec: &TypeSpec) -> (String, String) {
+        let (data_type, udt) = match spec.name.as_str() {
+            "int" | "integer" | "int4" | "serial" | "serial4" => ("integer", "int4"),
+            "smallint" | "int2" | "smallserial" | "serial2" => ("smallint", "int2"),
+            "bigint" | "int8" | "bigserial" | "serial8" => ("bigint", "int8"),
+            "bool" | "boolean" => ("boolean", "bool"),
+            "real" | "float4" => ("real", "float4"),
+            "double precision" | "float8" => ("double precision", "float8"),
+            "float" => match spec.args.first().and_then(|a| a.trim().parse::<i32>().ok()) {
+                Some(p) if p <= 24 => ("real", "float4"),
+                _ => ("double precision", "float8"),
+            },
+            "numeric" | "decimal" => ("numeric", "numeric"),
+            "varchar" | "character varying" | "char varying" => ("character varying", "varchar"),
+            "char" | "character" | "bpchar" => ("character", "bpchar"),
+            "bit varying" | "varbit" => ("bit varying", "varbit"),
+            "timestamp" | "timestamp without time zone" => {
+                ("timestamp without time zone", "timestamp")
+            }
+            "timestamptz" | "timestamp with time zone" => {
+                ("timestamp with time zone", "timestamptz")
+            }
+            "time" | "time without time zone" => ("time without time zone", "time"),
+            "timetz" | "time with time zone" => ("time with time zone", "timetz"),
+            other if is_pg_builtin(other) => (other, other),
+            other => ("USER-DEFINED", other),
+        };
+        if spec.array {
+            ("ARRAY".to_string(), format!("_{udt}"))
+        } else {
+            (data_type.to_string(), udt.to_string())
+        }
+    }
+
+    // ----- other statements -----
+
+    fn create_type(&mut self, cur: &mut Cursor) -> bool {
+        let Some(name) = self.qualified_name(cur) else {
+            return false;
+        };
+        if !cur.eat_words(&["AS", "ENUM"]) || !cur.eat_punct("(") {
+            return false;
+        }
+        let mut values = Vec::new();
+        while let Some(token) = cur.next() {
+            match token.kind {
+                TokenKind::Str => values.push(token.text.clone()),
+                _ if token.is_punct(")") => break,
+                _ => {}
+            }
+        }
+        let schema = Some(self.schema_or_default(name.schema));
+        self.enums.push(EnumInfo {
+            name: name.name,
+            schema,
+            values,
+        });
+        true
+    }
+
+    fn create_domain(&mut self, cur: &mut Cursor) -> bool {
+        let Some(name) = self.qualified_name(cur) else {
+            return false;
+        };
+        cur.eat_word("AS");
+        let spec = self.type_spec(cur);
+        let base_type = self.pg_type(&spec).1;
+        let mut domain = DomainInfo {
+            name: name.name,
+            schema: Some(self.schema_or_default(name.schema)),
+            base_type,
+            constraint_name: None,
+            not_null: false,
+            check_expression: None,
+        };
+        while cur.peek().is_some() {
+            if cur.eat_word("CONSTRAINT") {
+                domain.constraint_name = self.ident(cur);
+            } else if cur.eat_words(&["NOT", "NULL"]) {
+                domain.not_null = true;
+            } else if cur.eat_word("CHECK") {
+                domain.check_expression = cur.paren_expr();
+            } else {
+                cur.skip();
+            }
+        }
+        self.domain_types.push((domain.name.clone(), spec));
+        self.domains.push(domain);
+        true
+    }
+
+    /// `CREATE [UNIQUE] INDEX [CONCURRENTLY] [IF NOT EXISTS] [name] ON
+    /// [ONLY] table [USING method] (cols) ...`. Expression indexes are
+    /// skipped, as the live introspectors do.
+    /// `index` arrives with the flags read before `INDEX` (`UNIQUE`,
+    /// `CLUSTERED`, `COLUMNSTORE`).
+    fn create_index(&mut self, cur: &mut Cursor, mut index: IndexInfo) -> bool {
+        cur.eat_word("CONCURRENTLY");
+        cur.eat_words(&["IF", "NOT", "EXISTS"]);
+        let index_name = if cur.at_word("ON") {
+            None
+        } else {
+            self.qualified_name(cur).map(|n| n.name)
+        };
+        if !cur.eat_word("ON") {
+            return false;
+        }
+        cur.eat_word("ONLY");
+        let Some(table) = self.qualified_name(cur) else {
+            return false;
+        };
+        let method = if cur.eat_word("USING") {
+            self.ident(cur)
+        } else {
+            None
+        };
+        // A clustered columnstore index covers every column and lists none.
+        let keys = if index.clustered && index.columnstore && !cur.at_punct("(") {
+            let columns = self
+                .table_mut(&table)
+                .map(|t| t.columns.iter().map(|c| c.name.clone()).collect())
+                .unwrap_or_default();
+            Some(IndexKeys {
+                columns,
+                ..IndexKeys::default()
+            })
+        } else {
+            self.index_keys(cur)
+        };
+        let Some(IndexKeys {
+            columns,
+            expressions,
+            opclasses,
+            orderings,
+        }) = keys
+        else {
+            return false;
+        };
+        let include = if cur.eat_word("INCLUDE") {
+            self.column_list(cur).unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        index.nulls_not_distinct = self.nulls_not_distinct(cur);
+        let dialect = self.dialect;
+        let Some(t) = self.table_mut(&table) else {
+            return false;
+        };
+        let name = index_name.unwrap_or_else(|| {
+            let keys = if columns.is_empty() {
+                "expr".to_string()
+            } else {
+                columns.join("_")
+            };
+            format!("{}_{keys}_idx", t.name)
+        });
+        index.name = name;
+        index.columns = columns;
+        index.expressions = expressions;
+        index.orderings = orderings;
+        index.include = include;
+        if dialect == Dialect::Postgres {
+            index.opclasses = opclasses;
+        }
+        if let Some(method) = method.filter(|m| dialect == Dialect::Postgres && m != "btree") {
+            index.kwargs.insert("postgresql_using".to_string(), method);
+        }
+        t.indexes.push(index);
+        true
+    }
+
+    fn alter_table(&mut self, cur: &mut Cursor) -> bool {
+        cur.eat_words(&["IF", "EXISTS"]);
+        cur.eat_word("ONLY");
+        let Some(table) = self.qualified_name(cur) else {
+            return false;
+        };
+        if self.table_mut(&table).is_none() {
+            return false;
+        }
+        // MSSQL `WITH CHECK ADD CONSTRAINT ...`.
+        if !cur.eat_words(&["WITH", "CHECK"]) {
+            cur.eat_words(&["WITH", "NOCHECK"]);
+        }
+
+        let mut adding = false;
+        while cur.peek().is_some() {
+            if cur.eat_word("ADD") {
+                adding = true;
+                self.alter_add(cur, &table);
+            } else if cur.eat_word("ALTER") {
+                adding = false;
+                cur.eat_word("COLUMN");
+                self.alter_column(cur, &table);
+            } else if cur.eat_word("DROP") {
+                adding = false;
+                self.alter_drop(cur, &table);
+            } else if adding && !cur.at_element_end() {
+                // MSSQL `ADD a int, b int`: one ADD, several elements.
+                self.alter_add(cur, &table);
+            } else {
+                adding = false;
+            }
+            cur.skip_to_element_end();
+            if !cur.eat_punct(",") {
+                break;
+            }
+        }
+        true
+    }
+
+    fn alter_add(&mut self, cur: &mut Cursor, table: &QualifiedName) {
+        if self.table_constraint(cur, table) {
+            return;
+        }
+        cur.eat_word("COLUMN");
+        cur.eat_words(&["IF", "NOT", "EXISTS"]);
+        self.add_column(cur, table);
+    }
+
+    fn alter_column(&mut self, cur: &mut Cursor, table: &QualifiedName) {
+        let Some(column) = self.ident(cur) else {
+            return;
+        };
+        let dialect = self.dialect;
+        let mut target = None;
+        if dialect == Dialect::Mssql && !cur.at_element_end() {
+            // `ALTER COLUMN c type [NULL | NOT NULL]` restates the column.
+            let spec = self.type_spec(cur);
+            target = Some(self.column_from_type(&column, &spec));
+        }
+        let identity = if cur.eat_words(&["ADD", "GENERATED"]) {
+            let generation = if cur.eat_word("ALWAYS") {
+                "ALWAYS"
+            } else {
+                cur.eat_words(&["BY", "DEFAULT"]);
+                "BY DEFAULT"
+            };
+            cur.eat_words(&["AS", "IDENTITY"]);
+            Some(generation)
+        } else {
+            None
+        };
+
+        let udt = {
+            let Some(col) = self.table_mut(table).and_then(|t| {
+                t.columns
+                    .iter_mut()
+                    .find(|c| same_name(dialect, &c.name, &column))
+            }) else {
+                return;
+            };
+            col.udt_name.clone()
+        };
+        let options = identity.map(|_| {
+            let probe = ColumnInfo::new("", 0, true, "", udt);
+            self.sequence_options(cur, &probe)
+        });
+        let new_type = if cur.eat_words(&["SET", "DATA", "TYPE"]) || cur.eat_word("TYPE") {
+            let spec = self.type_spec(cur);
+            Some(self.column_from_type(&column, &spec))
+        } else {
+            None
+        };
+        let default = if cur.eat_words(&["SET", "DEFAULT"]) {
+            Some(Some(cur.expr(&[])))
+        } else if cur.eat_words(&["DROP", "DEFAULT"]) {
+            Some(None)
+        } else {
+            None
+        };
+        let nullable = if cur.eat_words(&["SET", "NOT", "NULL"]) || cur.eat_words(&["NOT", "NULL"])
+        {
+            Some(false)
+        } else if cur.eat_words(&["DROP", "NOT", "NULL"]) || cur.eat_word("NULL") {
+            Some(true)
+        } else {
+            None
+        };
+
+        let Some(col) = self.table_mut(table).and_then(|t| {
+            t.columns
+                .iter_mut()
+                .find(|c| same_name(dialect, &c.name, &column))
+        }) else {
+            return;
+        };
+        if let Some(retyped) = target.or(new_type) {
+            col.data_type = retyped.data_type;
+            col.udt_name = retyped.udt_name;
+            col.udt_schema = retyped.udt_schema;
+            col.character_maximum_length = retyped.character_maximum_length;
+            col.numeric_precision = retyped.numeric_precision;
+            col.numeric_scale = retyped.numeric_scale;
+        }
+        if let (Some(generation), Some(options)) = (identity, options) {
+            set_identity(col, generation, options);
+        }
+        if let Some(default) = default {
+            col.column_default = default;
+        }
+        if let Some(nullable) = nullable {
+            col.is_nullable = nullable;
+        }
+    }
+
+    fn alter_drop(&mut self, cur: &mut Cursor, table: &QualifiedName) {
+        let constraint = cur.eat_word("CONSTRAINT");
+        if !constraint {
+            cur.eat_word("COLUMN");
+        }
+        cur.eat_words(&["IF", "EXISTS"]);
+        let Some(name) = self.ident(cur) else {
+            return;
+        };
+        let dialect = self.dialect;
+        let Some(t) = self.table_mut(table) else {
+            return;
+        };
+        if constraint {
+            t.constraints
+                .retain(|c| !same_name(dialect, &c.name, &name));
+            t.indexes.retain(|i| !same_name(dialect, &i.name, &name));
+        } else {
+            t.columns.retain(|c| !same_name(dialect, &c.name, &name));
+        }
+    }
+
+    /// `COMMENT ON {TABLE | COLUMN} name IS 'text'`.
+    fn comment_on(&mut self, cur: &mut Cursor) -> bool {
+        if cur.eat_word("INDEX") {
+            return self.comment_on_index(cur);
+        }
+        if cur.eat_word("CONSTRAINT") {
+            return self.comment_on_constraint(cur);
+        }
+        let column = if cur.eat_word("TABLE") {
+            false
+        } else if cur.eat_word("COLUMN") {
+            true
+        } else {
+            return false;
+        };
+        let Some(mut name) = self.qualified_name_parts(cur) else {
+            return false;
+        };
+        if !cur.eat_word("IS") {
+            return false;
+        }
+        let comment = cur
+            .peek()
+            .filter(|t| t.kind == TokenKind::Str)
+            .map(|t| t.text.clone());
+        let column_name = if column { name.pop() } else { None };
+        let Some(table_name) = name.pop() else {
+            return false;
+        };
+        let table = QualifiedName {
+            schema: name.pop(),
+            name: table_name,
+        };
+        self.set_comment(&table, column_name.as_deref(), comment)
+    }
+
+    /// `COMMENT ON INDEX [schema.]name IS '...'`.
+    fn comment_on_index(&mut self, cur: &mut Cursor) -> bool {
+        let Some(name) = self.qualified_name(cur) else {
+            return false;
+        };
+        let Some(comment) = comment_value(cur) else {
+            return false;
+        };
+        let dialect = self.dialect;
+        let schema = self.schema_or_default(name.schema);
+        let index = self
+            .tables
+            .iter_mut()
+            .filter(|t| same_name(dialect, &t.schema, &schema))
+            .flat_map(|t| t.indexes.iter_mut())
+            .find(|i| same_name(dialect, &i.name, &name.name));
+        match index {
+            Some(index) => {
+                index.comment = comment;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// `COMMENT ON CONSTRAINT name ON [schema.]table IS '...'`.
+    fn comment_on_constraint(&mut self, cur: &mut Cursor) -> bool {
+        let Some(name) = self.ident(cur) else {
+            return false;
+        };
+        if !cur.eat_word("ON") {
+            return false;
+        }
+        let Some(table) = self.qualified_name(cur) else {
+            return false;
+        };
+        let Some(comment) = comment_value(cur) else {
+            return false;
+        };
+        let dialect = self.dialect;
+        let Some(t) = self.table_mut(&table) else {
+            return false;
+        };
+        match t
+            .constraints
+            .iter_mut()
+            .find(|c| same_name(dialect, &c.name, &name))
+        {
+            Some(constraint) => {
+                constraint.comment = comment;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn qualified_name_parts(&self, cur: &mut Cursor) -> Option<Vec<String>> {
+        let mut parts = vec![self.ident(cur)?];
+        while cur.eat_punct(".") {
+            parts.push(self.ident(cur)?);
+        }
+        Some(parts)
+    }
+
+    /// MSSQL `EXEC sys.sp_addextendedproperty @name = N'...', @value =
+    /// N'...', @level0type = N'SCHEMA', ...`, named or positional.
+    fn extended_property(&mut self, cur: &mut Cursor) -> bool {
+        let Some(procedure) = self.qualified_name(cur) else {
+            return false;
+        };
+        if !procedure
+            .name
+            .eq_ignore_ascii_case("sp_addextendedproperty")
+        {
+            return false;
+        }
+        const PARAMS: [&str; 8] = [
+            "@name",
+            "@value",
+            "@level0type",
+            "@level0name",
+            "@level1type",
+            "@level1name",
+            "@level2type",
+            "@level2name",
+        ];
+        let mut args: [Option<String>; 8] = Default::default();
+        let mut position = 0;
+        while cur.peek().is_some() {
+            if let Some(param) = cur.peek().filter(|t| t.text.starts_with('@')) {
+                if let Some(i) = PARAMS
+                    .iter()
+                    .position(|p| param.text.eq_ignore_ascii_case(p))
+                {
+                    position = i;
+                }
+                cur.pos += 1;
+                cur.eat_punct("=");
+            }
+            if let Some(token) = cur.next() {
+                if position < PARAMS.len() && !token.is_punct(",") {
+                    args[position] = Some(token.text.clone());
+                    position += 1;
+                }
+            }
+            cur.eat_punct(",");
+        }
+
+        let [Some(name), value, _, schema, Some(level1type), Some(table), level2type, column] =
+            args
+        else {
+            return false;
+        };
+        if !level1type.eq_ignore_ascii_case("TABLE") {
+            return false;
+        }
+        let column = column.filter(|_| {
+            level2type
+                .as_deref()
+                .is_some_and(|t| t.eq_ignore_ascii_case("COLUMN"))
+        });
+        let table = QualifiedName {
+            schema,
+            name: table,
+        };
+        if name == "MS_Description" {
+            return self.set_comment(&table, column.as_deref(), value);
+        }
+        let dialect = self.dialect;
+        let Some(t) = self.table_mut(&table) else {
+            return false;
+        };
+        let value = value.unwrap_or_default();
+        match column {
+            Some(column) => match t
+                .columns
+                .iter_mut()
+                .find(|c| same_name(dialect, &c.name, &column))
+            {
+                Some(col) => {
+                    col.properties.insert(name, value);
+                }
+                None => return false,
+            },
+            None => {
+                t.properties.insert(name, value);
+            }
+        }
+        true
+    }
+
+    fn set_comment(
+        &mut self,
+        table: &QualifiedName,
+        column: Option<&str>,
+        comment: Option<String>,
+    ) -> bool {
+        let dialect = self.dialect;
+        let Some(t) = self.table_mut(table) else {
+            return false;
+        };
+        match column {
+            Some(column) => match t
+                .columns
+                .iter_mut()
+                .find(|c| same_name(dialect, &c.name, column))
+            {
+                Some(col) => col.comment = comment,
+                None => return false,
+            },
+            None => t.comment = comment,
+        }
+        true
+    }
+}
+
+/// The `IS '...'` tail of a `COMMENT ON` statement: `Some(None)` for
+/// `IS NULL`, `None` if the statement doesn't have one.
+fn comment_value(cur: &mut Cursor) -> Option<Option<String>> {
+    if !cur.eat_word("IS") {
+        return None;
+    }
+    Some(
+        cur.peek()
+            .filter(|t| t.kind == TokenKind::Str)
+            .map(|t| t.text.clone()),
+    )
+}
+
+fn set_identity(col: &mut ColumnInfo, generation: &str, identity: IdentityInfo) {
+    col.is_identity = true;
+    col.is_nullable = false;
+    col.identity_generation = Some(generation.to_string());
+    col.identity = Some(identity);
+}
+
+/// Add `constraint` to `table`, naming it the way PostgreSQL would if the
+/// script left it unnamed. Unique constraints also get their backing
+/// index, matching what the live introspectors report.
+fn add_constraint(table: &mut TableInfo, mut constraint: ConstraintInfo) {
+    if constraint.name.is_empty() {
+        let (columns, suffix) = match constraint.constraint_type {
+            ConstraintType::PrimaryKey => (&[][..], "pkey"),
+            ConstraintType::Unique => (&constraint.columns[..], "key"),
+            ConstraintType::ForeignKey => (&constraint.columns[..], "fkey"),
+            ConstraintType::Check => (&constraint.columns[..], "check"),
+            ConstraintType::Exclude => (&constraint.columns[..], "excl"),
+        };
+        let base = std::iter::once(table.name.as_str())
+            .chain(columns.iter().map(String::as_str))
+            .chain([suffix])
+            .collect::<Vec<_>>()
+            .join("_");
+        let mut name = base.clone();
+        let mut n = 0;
+        while table.constraints.iter().any(|c| c.name == name) {
+            n += 1;
+            name = format!("{base}{n}");
+        }
+        constraint.name = name;
+    }
+    if constraint.constraint_type == ConstraintType::Check {
+        constraint.columns.clear();
+    }
+    if constraint.constraint_type == ConstraintType::Unique {
+        let mut index = IndexInfo::new(constraint.name.clone(), true, constraint.columns.clone());
+        index.nulls_not_distinct = constraint.nulls_not_distinct;
+        table.indexes.push(index);
+    }
+    table.constraints.push(constraint);
+}
+
+/// Name comparison: exact for PostgreSQL (unquoted names are already
+/// folded), case-insensitive for SQL Server's default collation.
+fn same_name(dialect: Dialect, a: &str, b: &str) -> bool {
+    match dialect {
+        Dialect::Mssql => a.eq_ignore_ascii_case(b),
+        _ => a == b,
+    }
+}
+
+/// PostgreSQL built-in type names reported as their own `udt_name`.
+fn is_pg_builtin(udt: &str) -> bool {
+    matches!(
+        udt,
+        "int2"
+            | "int4"
+            | "int8"
+            | "bool"
+            | "float4"
+            | "float8"
+            | "numeric"
+            | "varchar"
+            | "bpchar"
+            | "text"
+            | "bytea"
+            | "date"
+            | "time"
+            | "timetz"
+            | "timestamp"
+            | "timestamptz"
+            | "interval"
+            | "uuid"
+            | "json"
+            | "jsonb"
+            | "xml"
+            | "inet"
+            | "cidr"
+            | "macaddr"
+            | "macaddr8"
+            | "money"
+            | "bit"
+            | "varbit"
+            | "tsvector"
+            | "tsquery"
+            | "oid"
+            | "point"
+            | "line"
+            | "lseg"
+            | "box"
+            | "path"
+            | "polygon"
+            | "circle"
+            | "int4range"
+            | "int8range"
+            | "numrange"
+            | "tsrange"
+            | "tstzrange"
+            | "daterange"
+    )
+}
+
+#[cfg(test)]
+#[path = "tests.rs"]
+mod tests;
@@ -0,0 +1,737 @@
+use super::*;
+
+const PG_DUMP: &str = r#"
+--
+-- PostgreSQL database dump
+--
+
+SET statement_timeout = 0;
+SELECT pg_catalog.set_config('search_path', '', false);
+
+CREATE TYPE public.mood AS ENUM (
+    'happy',
+    'sad'
+);
+
+CREATE FUNCTION public.touch() RETURNS trigger
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    NEW.updated_at := now();
+    RETURN NEW;
+END;
+$$;
+
+CREATE TABLE public.users (
+    id integer NOT NULL,
+    email character varying(255) NOT NULL,
+    name text DEFAULT 'anon'::text,
+    balance numeric(10,2),
+    tags text[],
+    feeling public.mood,
+    created_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+ALTER TABLE public.users OWNER TO app;
+
+COMMENT ON TABLE public.users IS 'Registered users';
+COMMENT ON COLUMN public.users.email IS 'Login; must be unique';
+
+CREATE TABLE public.posts (
+    id bigint NOT NULL,
+    user_id integer,
+    title character varying(200),
+    CONSTRAINT posts_title_check CHECK ((length((title)::text) > 0))
+);
+
+ALTER TABLE public.posts ALTER COLUMN id ADD GENERATED ALWAYS AS IDENTITY (
+    SEQUENCE NAME public.posts_id_seq
+    START WITH 1
+    INCREMENT BY 1
+    NO MINVALUE
+    NO MAXVALUE
+    CACHE 1
+);
+
+CREATE SEQUENCE public.users_id_seq
+    AS integer
+    START WITH 1
+    INCREMENT BY 1;
+
+ALTER SEQUENCE public.users_id_seq OWNED BY public.users.id;
+
+ALTER TABLE ONLY public.users ALTER COLUMN id SET DEFAULT nextval('public.users_id_seq'::regclass);
+
+ALTER TABLE ONLY public.users
+    ADD CONSTRAINT users_pkey PRIMARY KEY (id);
+
+ALTER TABLE ONLY public.users
+    ADD CONSTRAINT users_email_key UNIQUE (email);
+
+ALTER TABLE ONLY public.posts
+    ADD CONSTRAINT posts_pkey PRIMARY KEY (id);
+
+CREATE INDEX posts_title_idx ON public.posts USING gin (title);
+
+CREATE INDEX users_lower_email_idx ON public.users USING btree (lower((email)::text));
+
+ALTER TABLE ONLY public.posts
+    ADD CONSTRAINT posts_user_id_fkey FOREIGN KEY (user_id) REFERENCES public.users(id) ON DELETE CASCADE;
+
+CREATE TABLE audit.events (
+    id serial PRIMARY KEY
+);
+"#;
+
+const MSSQL_SCRIPT: &str = r#"
+SET ANSI_NULLS ON
+GO
+SET QUOTED_IDENTIFIER ON
+GO
+CREATE TABLE [dbo].[Customers](
+	[CustomerID] [int] IDENTITY(100,5) NOT NULL,
+	[Name] [nvarchar](100) NOT NULL,
+	[Notes] [nvarchar](max) NULL,
+	[Credit] [decimal](12, 2) NULL,
+	[IsActive] [bit] NOT NULL,
+	[Total]  AS ([Credit]*(2)),
+ CONSTRAINT [PK_Customers] PRIMARY KEY CLUSTERED
+(
+	[CustomerID] ASC
+)WITH (PAD_INDEX = OFF, STATISTICS_NORECOMPUTE = OFF) ON [PRIMARY]
+) ON [PRIMARY] TEXTIMAGE_ON [PRIMARY]
+GO
+CREATE TABLE [dbo].[Orders](
+	[OrderID] [int] IDENTITY(1,1) NOT NULL,
+	[CustomerID] [int] NOT NULL,
+	CONSTRAINT [PK_Orders] PRIMARY KEY ([OrderID])
+) ON [PRIMARY]
+GO
+ALTER TABLE [dbo].[Customers] ADD  CONSTRAINT [DF_Customers_IsActive]  DEFAULT ((1)) FOR [IsActive]
+GO
+ALTER TABLE [dbo].[Orders]  WITH CHECK ADD  CONSTRAINT [FK_Orders_Customers] FOREIGN KEY([CustomerID])
+REFERENCES [dbo].[Customers] ([CustomerID])
+ON DELETE CASCADE
+GO
+ALTER TABLE [dbo].[Orders] CHECK CONSTRAINT [FK_Orders_Customers]
+GO
+CREATE NONCLUSTERED INDEX [IX_Orders_CustomerID] ON [dbo].[Orders]
+(
+	[CustomerID] ASC
+)WITH (PAD_INDEX = OFF) ON [PRIMARY]
+GO
+EXEC sys.sp_addextendedproperty @name=N'MS_Description', @value=N'Display name' , @level0type=N'SCHEMA',@level0name=N'dbo', @level1type=N'TABLE',@level1name=N'Customers', @level2type=N'COLUMN',@level2name=N'Name'
+GO
+EXEC sys.sp_addextendedproperty @name=N'Owner', @value=N'sales' , @level0type=N'SCHEMA',@level0name=N'dbo', @level1type=N'TABLE',@level1name=N'Customers'
+GO
+"#;
+
+fn find<'a>(schema: &'a IntrospectedSchema, name: &str) -> &'a TableInfo {
+    schema.tables.iter().find(|t| t.name == name).unwrap()
+}
+
+fn column<'a>(table: &'a TableInfo, name: &str) -> &'a ColumnInfo {
+    table.columns.iter().find(|c| c.name == name).unwrap()
+}
+
+#[test]
+fn detects_dialect_from_script() {
+    assert_eq!(detect_dialect(PG_DUMP), Dialect::Postgres);
+    assert_eq!(detect_dialect(MSSQL_SCRIPT), Dialect::Mssql);
+}
+
+#[test]
+fn pg_dump_columns_match_introspected_types() {
+    let schema = parse(PG_DUMP);
+    let users = find(&schema, "users");
+
+    assert_eq!(users.schema, "public");
+    let email = column(users, "email");
+    assert_eq!(email.udt_name, "varchar");
+    assert_eq!(email.data_type, "character varying");
+    assert_eq!(email.character_maximum_length, Some(255));
+    assert!(!email.is_nullable);
+    assert_eq!(email.comment.as_deref(), Some("Login; must be unique"));
+
+    let balance = column(users, "balance");
+    assert_eq!(
+        (balance.numeric_precision, balance.numeric_scale),
+        (Some(10), Some(2))
+    );
+    assert_eq!(column(users, "tags").udt_name, "_text");
+    assert_eq!(column(users, "tags").data_type, "ARRAY");
+    assert_eq!(
+        column(users, "name").column_default.as_deref(),
+        Some("'anon'::text")
+    );
+    assert_eq!(column(users, "created_at").udt_name, "timestamptz");
+
+    let feeling = column(users, "feeling");
+    assert_eq!(feeling.data_type, "USER-DEFINED");
+    assert_eq!(feeling.udt_name, "mood");
+    assert_eq!(feeling.udt_schema.as_deref(), Some("public"));
+    assert_eq!(schema.enums[0].values, ["happy", "sad"]);
+
+    assert_eq!(users.comment.as_deref(), Some("Registered users"));
+    assert_eq!(
+        column(users, "id").column_default.as_deref(),
+        Some("nextval('public.users_id_seq'::regclass)")
+    );
+}
+
+#[test]
+fn pg_dump_constraints_indexes_and_identity() {
+    let schema = parse(PG_DUMP);
+    let users = find(&schema, "users");
+    let posts = find(&schema, "posts");
+
+    let kinds: Vec<(&str, &ConstraintType)> = users
+        .constraints
+        .iter()
+        .map(|c| (c.name.as_str(), &c.constraint_type))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            ("users_pkey", &ConstraintType::PrimaryKey),
+            ("users_email_key", &ConstraintType::Unique)
+        ]
+    );
+    // The unique constraint's backing index, as live introspection reports,
+    // then the expression index.
+    assert_eq!(users.indexes.len(), 2);
+    assert!(users.indexes[0].is_unique);
+    let lower_email = &users.indexes[1];
+    assert_eq!(lower_email.name, "users_lower_email_idx");
+    assert!(lower_email.columns.is_empty());
+    assert_eq!(lower_email.expressions, ["lower((email)::text)"]);
+
+    let fk = posts
+        .constraints
+        .iter()
+        .find_map(|c| c.foreign_key.as_ref())
+        .unwrap();
+    assert_eq!(
+        (fk.ref_table.as_str(), fk.ref_columns.as_slice()),
+        ("users", &["id".to_string()][..])
+    );
+    assert_eq!(fk.delete_rule, "CASCADE");
+
+    let check = posts
+        .constraints
+        .iter()
+        .find(|c| c.constraint_type == ConstraintType::Check)
+        .unwrap();
+    assert_eq!(
+        check.check_expression.as_deref(),
+        Some("(length((title)::text) > 0)")
+    );
+
+    assert_eq!(posts.indexes.len(), 1);
+    assert_eq!(
+        posts.indexes[0]
+            .kwargs
+            .get("postgresql_using")
+            .map(String::as_str),
+        Some("gin")
+    );
+
+    let id = column(posts, "id");
+    assert!(id.is_identity);
+    assert_eq!(id.identity_generation.as_deref(), Some("ALWAYS"));
+    let identity = id.identity.as_ref().unwrap();
+    assert_eq!(
+        (identity.start, identity.max_value, identity.cache),
+        (1, Some(i64::MAX), Some(1))
+    );
+}
+
+#[test]
+fn pg_serial_expands_to_sequence_default() {
+    let schema = parse(PG_DUMP);
+    let events = find(&schema, "events");
+
+    assert_eq!(events.schema, "audit");
+    let id = column(events, "id");
+    assert_eq!(id.udt_name, "int4");
+    assert!(!id.is_nullable);
+    assert_eq!(
+        id.column_default.as_deref(),
+        Some("nextval('events_id_seq'::regclass)")
+    );
+    assert_eq!(events.constraints[0].name, "events_pkey");
+}
+
+#[test]
+fn mssql_script_parses_tables_defaults_and_keys() {
+    let schema = parse(MSSQL_SCRIPT);
+    assert_eq!(schema.dialect, Dialect::Mssql);
+    let customers = find(&schema, "Customers");
+    let orders = find(&schema, "Orders");
+
+    assert_eq!(customers.schema, "dbo");
+    let names: Vec<&str> = customers.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["CustomerID", "Name", "Notes", "Credit", "IsActive", "Total"]
+    );
+
+    let id = column(customers, "CustomerID");
+    assert_eq!(id.udt_name, "int");
+    let identity = id.identity.as_ref().unwrap();
+    assert_eq!((identity.start, identity.increment), (100, 5));
+
+    assert_eq!(
+        column(customers, "Name").character_maximum_length,
+        Some(100)
+    );
+    assert_eq!(column(customers, "Notes").character_maximum_length, None);
+    assert_eq!(column(customers, "Credit").numeric_precision, Some(12));
+    assert_eq!(
+        column(customers, "IsActive").column_default.as_deref(),
+        Some("((1))")
+    );
+    assert_eq!(
+        column(customers, "Name").comment.as_deref(),
+        Some("Display name")
+    );
+    assert_eq!(
+        customers.properties.get("Owner").map(String::as_str),
+        Some("sales")
+    );
+    assert_eq!(customers.constraints[0].name, "PK_Customers");
+    assert_eq!(customers.constraints[0].columns, ["CustomerID"]);
+
+    let fk = orders
+        .constraints
+        .iter()
+        .find(|c| c.name == "FK_Orders_Customers")
+        .and_then(|c| c.foreign_key.as_ref())
+        .unwrap();
+    assert_eq!(fk.ref_table, "Customers");
+    assert_eq!(fk.delete_rule, "CASCADE");
+    assert_eq!(orders.indexes[0].name, "IX_Orders_CustomerID");
+    assert_eq!(orders.indexes[0].columns, ["CustomerID"]);
+}
+
+#[test]
+fn unnamed_inline_constraints_get_postgres_names() {
+    let schema = parse_dialect(
+        "CREATE TABLE t (
+            id int PRIMARY KEY,
+            code varchar(10) UNIQUE,
+            parent_id int REFERENCES t,
+            qty int CHECK (qty > 0)
+        );",
+        Dialect::Postgres,
+    );
+    let names: Vec<&str> = schema.tables[0]
+        .constraints
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect();
+
+    assert_eq!(
+        names,
+        ["t_pkey", "t_code_key", "t_parent_id_fkey", "t_qty_check"]
+    );
+    let fk = schema.tables[0].constraints[2]
+        .foreign_key
+        .as_ref()
+        .unwrap();
+    assert_eq!(fk.ref_columns, ["id"]);
+}
+
+#[test]
+fn pg_generated_column_is_computed() {
+    let schema = parse_dialect(
+        "CREATE TABLE public.lines (
+            qty integer,
+            price numeric,
+            total numeric GENERATED ALWAYS AS ((price * (qty)::numeric)) STORED
+        );",
+        Dialect::Postgres,
+    );
+    let total = column(&schema.tables[0], "total");
+    let computed = total.computed.as_ref().unwrap();
+    assert_eq!(computed.expression, "(price * (qty)::numeric)");
+    assert_eq!(computed.persisted, Some(true));
+    assert!(column(&schema.tables[0], "qty").computed.is_none());
+}
+
+#[test]
+fn mssql_computed_columns_are_kept() {
+    let schema = parse_dialect(
+        "CREATE TABLE [dbo].[lines] (
+             [Qty] int NOT NULL,
+             [Price] money NOT NULL,
+             [Total] AS ([Qty]*[Price]) PERSISTED NOT NULL,
+             [Label] AS (concat('#',[Qty]))
+         );",
+        Dialect::Mssql,
+    );
+    let lines = &schema.tables[0];
+    let names: Vec<&str> = lines.columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["Qty", "Price", "Total", "Label"]);
+
+    let total = column(lines, "Total");
+    assert_eq!(total.udt_name, "");
+    assert!(!total.is_nullable);
+    let computed = total.computed.as_ref().unwrap();
+    assert_eq!(computed.expression, "([Qty]*[Price])");
+    assert_eq!(computed.persisted, Some(true));
+    let label = column(lines, "Label").computed.as_ref().unwrap();
+    assert_eq!(label.expression, "(concat('#',[Qty]))");
+    assert_eq!(label.persisted, Some(false));
+}
+
+#[test]
+fn datetime_precision_and_array_dimensions_are_recorded() {
+    let schema = parse_dialect(
+        "CREATE TABLE public.t (
+            at timestamp(3) with time zone,
+            plain timestamp,
+            grid integer[][],
+            tags text[]
+        );",
+        Dialect::Postgres,
+    );
+    let t = &schema.tables[0];
+    assert_eq!(column(t, "at").datetime_precision, Some(3));
+    assert_eq!(column(t, "plain").datetime_precision, None);
+    assert_eq!(column(t, "grid").array_dimensions, Some(2));
+    assert_eq!(column(t, "tags").array_dimensions, None);
+
+    let schema = parse_dialect(
+        "CREATE TABLE [dbo].[t] ([at] datetime2(3), [plain] datetime2(7));",
+        Dialect::Mssql,
+    );
+    let t = &schema.tables[0];
+    assert_eq!(column(t, "at").datetime_precision, Some(3));
+    assert_eq!(column(t, "plain").datetime_precision, None);
+}
+
+#[test]
+fn pg_inherits_copies_parent_columns_and_checks() {
+    let schema = parse_dialect(
+        "CREATE TABLE public.items (
+            id integer NOT NULL,
+            name text,
+            CONSTRAINT items_name_check CHECK ((name <> ''))
+        );
+        CREATE TABLE public.books (
+            name text NOT NULL,
+            isbn text
+        )
+        INHERITS (public.items);",
+        Dialect::Postgres,
+    );
+    let books = find(&schema, "books");
+    let columns: Vec<(&str, i32, bool)> = books
+        .columns
+        .iter()
+        .map(|c| (c.name.as_str(), c.ordinal_position, c.inherited))
+        .collect();
+    assert_eq!(
+        columns,
+        [("id", 1, true), ("name", 2, true), ("isbn", 3, false)]
+    );
+    assert!(!column(books, "id").is_nullable);
+    // The child's redeclaration wins.
+    assert!(!column(books, "name").is_nullable);
+    assert_eq!(books.inherits, ["items"]);
+    assert_eq!(books.constraints[0].name, "items_name_check");
+}
+
+#[test]
+fn temp_tables_are_flagged_for_exclude_kinds() {
+    use crate::table_filter::{TableFilter, TableKind};
+
+    let schema = parse_dialect(
+        "CREATE TABLE public.orders (id integer NOT NULL);
+        CREATE TEMPORARY TABLE scratch (id integer);
+        CREATE GLOBAL TEMP TABLE staging (id integer);",
+        Dialect::Postgres,
+    );
+    let flags: Vec<(&str, bool)> = schema
+        .tables
+        .iter()
+        .map(|t| (t.name.as_str(), t.is_temporary))
+        .collect();
+    assert_eq!(
+        flags,
+        [("orders", false), ("scratch", true), ("staging", true)]
+    );
+
+    let filter = TableFilter::allow_all().with_excluded_kinds([TableKind::Temp]);
+    let kept: Vec<&str> = schema
+        .tables
+        .iter()
+        .filter(|t| filter.admits(t))
+        .map(|t| t.name.as_str())
+        .collect();
+    assert_eq!(kept, ["orders"]);
+}
+
+#[test]
+fn pg_foreign_key_records_deferrability() {
+    let schema = parse_dialect(
+        "CREATE TABLE parents (id int PRIMARY KEY);
+         CREATE TABLE children (
+             a int REFERENCES parents (id) DEFERRABLE INITIALLY DEFERRED,
+             b int REFERENCES parents (id) ON DELETE CASCADE DEFERRABLE,
+             c int REFERENCES parents (id) NOT DEFERRABLE INITIALLY IMMEDIATE
+         );",
+        Dialect::Postgres,
+    );
+    let children = find(&schema, "children");
+    let fk = |col: &str| {
+        children
+            .constraints
+            .iter()
+            .find(|c| c.columns == [col])
+            .and_then(|c| c.foreign_key.as_ref())
+            .unwrap()
+    };
+
+    assert!(fk("a").deferrable && fk("a").initially_deferred);
+    assert!(fk("b").deferrable && !fk("b").initially_deferred);
+    assert_eq!(fk("b").delete_rule, "CASCADE");
+    assert!(!fk("c").deferrable && !fk("c").initially_deferred);
+}
+
+#[test]
+fn pg_index_records_method_and_operator_classes() {
+    let schema = parse_dialect(
+        "CREATE TABLE docs (id int, title text, path text);
+         CREATE INDEX docs_title_trgm ON public.docs USING gin (title public.gin_trgm_ops);
+         CREATE INDEX docs_path ON docs (path COLLATE \"C\" text_pattern_ops DESC, id ASC);",
+        Dialect::Postgres,
+    );
+    let indexes = &schema.tables[0].indexes;
+
+    assert_eq!(
+        indexes[0]
+            .kwargs
+            .get("postgresql_using")
+            .map(String::as_str),
+        Some("gin")
+    );
+    assert_eq!(
+        indexes[0].opclasses.get("title").map(String::as_str),
+        Some("gin_trgm_ops")
+    );
+    assert_eq!(indexes[1].columns, ["path", "id"]);
+    assert_eq!(indexes[1].opclasses.len(), 1);
+    assert_eq!(
+        indexes[1].opclasses.get("path").map(String::as_str),
+        Some("text_pattern_ops")
+    );
+}
+
+#[test]
+fn index_keys_record_sort_order() {
+    let schema = parse_dialect(
+        "CREATE TABLE events (id int, created_at timestamp, kind text);
+         CREATE INDEX events_recent ON events (created_at DESC NULLS LAST, id ASC, (lower(kind)) DESC);",
+        Dialect::Postgres,
+    );
+    let index = &schema.tables[0].indexes[0];
+
+    assert_eq!(
+        index.orderings.get("created_at"),
+        Some(&SortOrder {
+            descending: true,
+            nulls_first: false,
+        })
+    );
+    assert!(!index.orderings.contains_key("id"));
+    assert_eq!(index.orderings.get("lower(kind)"), Some(&SortOrder::DESC));
+}
+
+#[test]
+fn index_include_columns_are_kept_apart() {
+    let schema = parse_dialect(
+        "CREATE TABLE orders (id int, customer_id int, total numeric, status text);
+         CREATE INDEX orders_customer ON orders (customer_id) INCLUDE (total, status);",
+        Dialect::Postgres,
+    );
+    let index = &schema.tables[0].indexes[0];
+
+    assert_eq!(index.columns, ["customer_id"]);
+    assert_eq!(index.include, ["total", "status"]);
+}
+
+#[test]
+fn nulls_not_distinct_is_recorded() {
+    let schema = parse_dialect(
+        "CREATE TABLE accounts (
+             email text UNIQUE NULLS NOT DISTINCT,
+             phone text,
+             handle text,
+             CONSTRAINT accounts_handle_key UNIQUE NULLS DISTINCT (handle)
+         );
+         CREATE UNIQUE INDEX accounts_phone ON accounts (phone) NULLS NOT DISTINCT;",
+        Dialect::Postgres,
+    );
+    let table = &schema.tables[0];
+
+    assert!(table.constraints[0].nulls_not_distinct);
+    assert_eq!(table.constraints[1].columns, ["handle"]);
+    assert!(!table.constraints[1].nulls_not_distinct);
+    let phone = table.indexes.iter().find(|i| i.name == "accounts_phone");
+    assert!(phone.is_some_and(|i| i.nulls_not_distinct));
+}
+
+#[test]
+fn mssql_cross_database_references_keep_the_database() {
+    let schema = parse_dialect(
+        "CREATE TABLE [dbo].[customers] ([id] int PRIMARY KEY);
+         CREATE TABLE [dbo].[orders] (
+             [id] int PRIMARY KEY,
+             [customer_id] int CONSTRAINT [FK_orders_customers]
+                 REFERENCES [crm].[dbo].[customers] ([id])
+         );",
+        Dialect::Mssql,
+    );
+    let orders = find(&schema, "orders");
+    let fk = orders.constraints[1].foreign_key.as_ref().unwrap();
+
+    assert_eq!(fk.ref_database.as_deref(), Some("crm"));
+    assert_eq!(fk.qualified_target(), "crm.dbo.customers");
+    // The local table of the same name is not the one referenced.
+    assert!(schema.referenced_table(fk).is_none());
+    assert_eq!(schema.dangling_fks().len(), 1);
+    assert_eq!(
+        crate::codegen::fk_target(fk, "id", Dialect::Mssql),
+        "crm.dbo.customers.id"
+    );
+}
+
+#[test]
+fn mssql_default_constraint_names_are_kept() {
+    let schema = parse_dialect(
+        "CREATE TABLE [dbo].[orders] (
+             [status] varchar(10) CONSTRAINT [DF_orders_status] DEFAULT ('new'),
+             [notes] varchar(10)
+         );
+         ALTER TABLE [dbo].[orders] ADD CONSTRAINT [DF_orders_notes] DEFAULT ('') FOR [notes];",
+        Dialect::Mssql,
+    );
+    let columns = &schema.tables[0].columns;
+
+    assert_eq!(
+        columns[0].default_constraint.as_deref(),
+        Some("DF_orders_status")
+    );
+    assert_eq!(
+        columns[1].default_constraint.as_deref(),
+        Some("DF_orders_notes")
+    );
+    assert_eq!(columns[1].column_default.as_deref(), Some("('')"));
+}
+
+#[test]
+fn index_and_constraint_comments_are_kept() {
+    let schema = parse_dialect(
+        "CREATE TABLE public.orders (
+             id int,
+             total numeric,
+             CONSTRAINT orders_total_check CHECK (total >= 0)
+         );
+         CREATE INDEX orders_total ON public.orders (total);
+         COMMENT ON INDEX public.orders_total IS 'Reporting lookups';
+         COMMENT ON CONSTRAINT orders_total_check ON public.orders IS 'No refunds here';",
+        Dialect::Postgres,
+    );
+    let table = &schema.tables[0];
+
+    assert_eq!(
+        table.indexes[0].comment.as_deref(),
+        Some("Reporting lookups")
+    );
+    assert_eq!(
+        table.constraints[0].comment.as_deref(),
+        Some("No refunds here")
+    );
+}
+
+#[test]
+fn exclusion_constraints_are_parsed() {
+    let schema = parse_dialect(
+        "CREATE TABLE bookings (
+             room_id int,
+             starts timestamp,
+             ends timestamp,
+             cancelled boolean,
+             CONSTRAINT bookings_no_overlap EXCLUDE USING gist
+                 (room_id WITH =, (tsrange(starts, ends)) WITH &&) WHERE (NOT cancelled)
+         );",
+        Dialect::Postgres,
+    );
+    let constraint = &schema.tables[0].constraints[0];
+
+    assert_eq!(constraint.constraint_type, ConstraintType::Exclude);
+    assert_eq!(constraint.name, "bookings_no_overlap");
+    assert_eq!(constraint.columns, ["room_id"]);
+    let exclude = constraint.exclude.as_ref().expect("exclude info");
+    assert_eq!(exclude.using, "gist");
+    assert_eq!(exclude.elements.len(), 2);
+    assert_eq!(exclude.elements[1].key, "tsrange(starts, ends)");
+    assert!(exclude.elements[1].is_expression);
+    assert_eq!(exclude.elements[1].operator, "&&");
+    assert_eq!(exclude.predicate.as_deref(), Some("NOT cancelled"));
+}
+
+#[test]
+fn mssql_clustered_and_columnstore_indexes_are_flagged() {
+    let schema = parse_dialect(
+        "CREATE TABLE dbo.orders (id int, customer_id int, total money);
+         CREATE CLUSTERED INDEX cix_orders ON dbo.orders (customer_id);
+         CREATE NONCLUSTERED COLUMNSTORE INDEX ncci_orders ON dbo.orders (customer_id, total);
+         CREATE CLUSTERED COLUMNSTORE INDEX cci_orders ON dbo.orders;",
+        Dialect::Mssql,
+    );
+    let indexes = &schema.tables[0].indexes;
+
+    assert!(indexes[0].clustered && !indexes[0].columnstore);
+    assert!(!indexes[1].clustered && indexes[1].columnstore);
+    assert_eq!(indexes[1].columns, ["customer_id", "total"]);
+    assert!(indexes[2].clustered && indexes[2].columnstore);
+    assert_eq!(indexes[2].columns, ["id", "customer_id", "total"]);
+}
+
+#[test]
+fn domain_columns_take_the_base_type() {
+    let schema = parse_dialect(
+        "CREATE DOMAIN public.email_address AS character varying(255)
+             CONSTRAINT email_check CHECK ((VALUE ~~ '%@%'::text));
+         CREATE DOMAIN public.work_email AS public.email_address;
+         CREATE TABLE people (
+             email public.email_address,
+             work public.work_email,
+             others public.email_address[]
+         );",
+        Dialect::Postgres,
+    );
+    let people = &schema.tables[0];
+
+    let email = column(people, "email");
+    assert_eq!(email.udt_name, "varchar");
+    assert_eq!(email.character_maximum_length, Some(255));
+    assert_eq!(email.domain.as_deref(), Some("email_address"));
+    let work = column(people, "work");
+    assert_eq!(work.udt_name, "varchar");
+    assert_eq!(work.domain.as_deref(), Some("work_email"));
+    let others = column(people, "others");
+    assert_eq!(others.udt_name, "_varchar");
+    assert_eq!(others.domain, None);
+    assert_eq!(schema.domains.len(), 2);
+}
+
+#[test]
+fn input_path_recognizes_sql_files() {
+    assert_eq!(input_path("schema.sql"), Some(Path::new("schema.sql")));
+    assert_eq!(input_path("sqlite:///schema.sql"), None);
+    assert_eq!(input_path("@schema.yaml"), None);
+}
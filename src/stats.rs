@@ -0,0 +1,282 @@
+//! Opt-in column statistics (`--stats`).
+//!
+//! Copies the optimizer's existing per-column statistics — null fraction,
+//! distinct-value estimate and most-common values — onto each column's
+//! `stats` field, so snapshots carry a profiling view next to the
+//! structure. Nothing is computed from table data: PostgreSQL reads
+//! `pg_stats`, SQL Server reads single-column statistics histograms through
+//! `sys.dm_db_stats_histogram`. Columns the database has not analyzed get
+//! no statistics. MySQL and SQLite are not supported.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use sqlx::Row;
+
+use crate::connection::ConnectionConfig;
+use crate::schema::{ColumnStats, IntrospectedSchema};
+
+/// Most-common values kept per column.
+pub const MAX_COMMON_VALUES: usize = 10;
+
+/// Statistics for one column, keyed by schema, table and column name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatsRow {
+    pub schema: String,
+    pub table: String,
+    pub column: String,
+    pub stats: ColumnStats,
+}
+
+/// One step of a SQL Server statistics histogram.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistogramStep {
+    /// `range_high_key` as text; `None` for the NULL step.
+    pub key: Option<String>,
+    pub equal_rows: f64,
+    pub distinct_range_rows: f64,
+}
+
+/// (schema, table, column), stats_id, row count, steps.
+type Histogram = ((String, String, String), i32, f64, Vec<HistogramStep>);
+
+const PG_STATS_QUERY: &str = r#"
+SELECT schemaname::text, tablename::text, attname::text,
+       null_frac::float8, n_distinct::float8, most_common_vals::text
+FROM pg_stats
+WHERE schemaname = ANY($1)
+"#;
+
+/// Single-column statistics only; when a column has several (an index plus
+/// auto-created statistics), the lowest `stats_id` wins.
+const MSSQL_STATS_QUERY: &str = r#"
+SELECT s.name, t.name, c.name, st.stats_id,
+       CAST(sp.rows AS FLOAT),
+       CAST(h.range_high_key AS NVARCHAR(4000)),
+       CAST(h.equal_rows AS FLOAT),
+       CAST(h.distinct_range_rows AS FLOAT)
+FROM sys.stats st
+JOIN sys.tables t ON t.object_id = st.object_id
+JOIN sys.schemas s ON s.schema_id = t.schema_id
+JOIN sys.stats_columns sc
+    ON sc.object_id = st.object_id AND sc.stats_id = st.stats_id AND sc.stats_column_id = 1
+JOIN sys.columns c ON c.object_id = sc.object_id AND c.column_id = sc.column_id
+CROSS APPLY sys.dm_db_stats_properties(st.object_id, st.stats_id) sp
+CROSS APPLY sys.dm_db_stats_histogram(st.object_id, st.stats_id) h
+WHERE NOT EXISTS (
+    SELECT 1 FROM sys.stats_columns sc2
+    WHERE sc2.object_id = st.object_id AND sc2.stats_id = st.stats_id
+      AND sc2.stats_column_id > 1
+)
+ORDER BY s.name, t.name, c.name, st.stats_id, h.step_number
+"#;
+
+/// Read statistics for every column in `schema` and attach them to each
+/// column's `stats` field. Returns the number of columns that received
+/// statistics.
+pub async fn collect_stats(
+    config: &ConnectionConfig,
+    schema: &mut IntrospectedSchema,
+) -> Result<usize> {
+    let rows = match config {
+        ConnectionConfig::Postgres(url) => {
+            let pool = sqlx::postgres::PgPoolOptions::new()
+                .max_connections(1)
+                .connect(url)
+                .await?;
+            let mut schemas: Vec<String> = schema.tables.iter().map(|t| t.schema.clone()).collect();
+            schemas.sort();
+            schemas.dedup();
+            let result = sqlx::query(PG_STATS_QUERY)
+                .bind(&schemas)
+                .fetch_all(&pool)
+                .await?;
+            pool.close().await;
+            result
+                .iter()
+                .map(|row| -> Result<StatsRow> {
+                    let mcv: Option<String> = row.try_get(5)?;
+                    Ok(StatsRow {
+                        schema: row.try_get(0)?,
+                        table: row.try_get(1)?,
+                        column: row.try_get(2)?,
+                        stats: ColumnStats {
+                            null_frac: row.try_get(3)?,
+                            n_distinct: row.try_get(4)?,
+                            most_common_values: mcv
+                                .as_deref()
+                                .map(parse_pg_array)
+                                .unwrap_or_default()
+                                .into_iter()
+                                .take(MAX_COMMON_VALUES)
+                                .collect(),
+                        },
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?
+        }
+        ConnectionConfig::Mssql {
+            host,
+            port,
+            instance,
+            database,
+            user,
+            password,
+            trust_cert,
+            encrypt,
+            auth,
+        } => {
+            let mut client = crate::introspect::mssql::connect(
+                host,
+                *port,
+                instance.as_deref(),
+                database,
+                user,
+                password,
+                *auth,
+                *trust_cert,
+                *encrypt,
+            )
+            .await?;
+            let result = client
+                .simple_query(MSSQL_STATS_QUERY)
+                .await?
+                .into_first_result()
+                .await?;
+
+            let mut histograms: Vec<Histogram> = Vec::new();
+            for row in &result {
+                let key = (
+                    row.get::<&str, _>(0).unwrap_or_default().to_string(),
+                    row.get::<&str, _>(1).unwrap_or_default().to_string(),
+                    row.get::<&str, _>(2).unwrap_or_default().to_string(),
+                );
+                let stats_id = row.get::<i32, _>(3).unwrap_or_default();
+                let step = HistogramStep {
+                    key: row.get::<&str, _>(5).map(str::to_string),
+                    equal_rows: row.get::<f64, _>(6).unwrap_or_default(),
+                    distinct_range_rows: row.get::<f64, _>(7).unwrap_or_default(),
+                };
+                match histograms.last_mut() {
+                    Some((last_key, last_id, _, steps)) if *last_key == key => {
+                        if *last_id == stats_id {
+                            steps.push(step);
+                        }
+                    }
+                    _ => histograms.push((
+                        key,
+                        stats_id,
+                        row.get::<f64, _>(4).unwrap_or_default(),
+                        vec![step],
+                    )),
+                }
+            }
+            histograms
+                .into_iter()
+                .map(|((schema, table, column), _, rows, steps)| StatsRow {
+                    schema,
+                    table,
+                    column,
+                    stats: from_histogram(rows, &steps),
+                })
+                .collect()
+        }
+        ConnectionConfig::Mysql(_) | ConnectionConfig::Sqlite(_) => {
+            anyhow::bail!("--stats is only supported for PostgreSQL and SQL Server");
+        }
+    };
+    Ok(apply_stats(schema, rows))
+}
+
+/// Attach `rows` to the matching columns. Returns how many matched.
+pub fn apply_stats(schema: &mut IntrospectedSchema, rows: Vec<StatsRow>) -> usize {
+    let mut by_column: HashMap<(String, String, String), ColumnStats> = rows
+        .into_iter()
+        .map(|r| ((r.schema, r.table, r.column), r.stats))
+        .collect();
+    let mut matched = 0;
+    for table in &mut schema.tables {
+        for col in &mut table.columns {
+            let key = (table.schema.clone(), table.name.clone(), col.name.clone());
+            if let Some(stats) = by_column.remove(&key) {
+                col.stats = Some(stats);
+                matched += 1;
+            }
+        }
+    }
+    matched
+}
+
+/// Summarize a SQL Server histogram: the NULL step gives the null fraction,
+/// step keys plus in-range distinct counts give the distinct estimate, and
+/// the steps with the most equal rows are the most common values.
+pub fn from_histogram(rows: f64, steps: &[HistogramStep]) -> ColumnStats {
+    let null_rows: f64 = steps
+        .iter()
+        .filter(|s| s.key.is_none())
+        .map(|s| s.equal_rows)
+        .sum();
+    let non_null: Vec<&HistogramStep> = steps.iter().filter(|s| s.key.is_some()).collect();
+    let n_distinct =
+        non_null.len() as f64 + non_null.iter().map(|s| s.distinct_range_rows).sum::<f64>();
+
+    let mut common = non_null.clone();
+    common.sort_by(|a, b| b.equal_rows.total_cmp(&a.equal_rows));
+    ColumnStats {
+        null_frac: (rows > 0.0).then(|| null_rows / rows),
+        n_distinct: Some(n_distinct),
+        most_common_values: common
+            .into_iter()
+            .take(MAX_COMMON_VALUES)
+            .filter_map(|s| s.key.clone())
+            .collect(),
+    }
+}
+
+/// Split a PostgreSQL array literal (`{a,"b c",NULL}`) into its elements.
+/// Unquoted `NULL` elements are dropped.
+pub fn parse_pg_array(text: &str) -> Vec<String> {
+    let inner = text
+        .trim()
+        .strip_prefix('{')
+        .and_then(|t| t.strip_suffix('}'))
+        .unwrap_or("");
+    let mut values = Vec::new();
+    let mut chars = inner.chars().peekable();
+    while chars.peek().is_some() {
+        let mut value = String::new();
+        let quoted = chars.peek() == Some(&'"');
+        if quoted {
+            chars.next();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => value.extend(chars.next()),
+                    '"' => break,
+                    c => value.push(c),
+                }
+            }
+            // Skip up to and including the separator.
+            for c in chars.by_ref() {
+                if c == ',' {
+                    break;
+                }
+            }
+            values.push(value);
+        } else {
+            for c in chars.by_ref() {
+                if c == ',' {
+                    break;
+                }
+                value.push(c);
+            }
+            if value != "NULL" {
+                values.push(value);
+            }
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+#[path = "stats_tests.rs"]
+mod tests;
@@ -0,0 +1,66 @@
+use super::*;
+use crate::testutil::*;
+
+#[test]
+fn parse_pg_array_handles_quotes_and_nulls() {
+    assert_eq!(parse_pg_array("{a,b,c}"), vec!["a", "b", "c"]);
+    assert_eq!(
+        parse_pg_array(r#"{"new york","say \"hi\"",NULL,"NULL"}"#),
+        vec!["new york", "say \"hi\"", "NULL"]
+    );
+    assert!(parse_pg_array("{}").is_empty());
+}
+
+#[test]
+fn from_histogram_summarizes_steps() {
+    let step = |key: Option<&str>, equal_rows: f64, distinct_range_rows: f64| HistogramStep {
+        key: key.map(str::to_string),
+        equal_rows,
+        distinct_range_rows,
+    };
+    let stats = from_histogram(
+        100.0,
+        &[
+            step(None, 20.0, 0.0),
+            step(Some("a"), 10.0, 0.0),
+            step(Some("m"), 40.0, 5.0),
+            step(Some("z"), 5.0, 3.0),
+        ],
+    );
+    assert_eq!(stats.null_frac, Some(0.2));
+    assert_eq!(stats.n_distinct, Some(11.0));
+    assert_eq!(stats.most_common_values, vec!["m", "a", "z"]);
+}
+
+#[test]
+fn apply_stats_matches_schema_table_and_column() {
+    let mut schema = schema_pg(vec![table("users")
+        .column(col("id").build())
+        .column(col("email").udt("text").build())
+        .build()]);
+    let stats = ColumnStats {
+        null_frac: Some(0.0),
+        n_distinct: Some(-1.0),
+        most_common_values: vec![],
+    };
+    let matched = apply_stats(
+        &mut schema,
+        vec![
+            StatsRow {
+                schema: "public".to_string(),
+                table: "users".to_string(),
+                column: "email".to_string(),
+                stats: stats.clone(),
+            },
+            StatsRow {
+                schema: "other".to_string(),
+                table: "users".to_string(),
+                column: "id".to_string(),
+                stats: ColumnStats::default(),
+            },
+        ],
+    );
+    assert_eq!(matched, 1);
+    assert!(schema.tables[0].columns[0].stats.is_none());
+    assert_eq!(schema.tables[0].columns[1].stats, Some(stats));
+}
@@ -4,21 +4,157 @@
 //!
 //! Pattern syntax is standard glob (`*`, `?`, `[abc]`), per the `glob`
 //! crate. A bare name with no metacharacters degenerates to an exact
-//! match — back-compat with the original `--tables foo,bar` form.
+//! match — back-compat with the original `--tables foo,bar` form. `%` is
+//! accepted as a synonym for `*` so SQL `LIKE`-style patterns (`audit_%`)
+//! work as written; `_` stays literal. A `re:` prefix switches the item to
+//! a regular expression, matched anywhere in the name unless anchored
+//! (`re:^order_\d+$`).
 //!
 //! Match order is: an empty `includes` list means "all tables"; non-empty
 //! `includes` filters to only tables matching at least one pattern; then
 //! `excludes` removes any matching table.
+//!
+//! Table-kind rules (`--exclude-kinds`) run after the name patterns and
+//! drop whole classes of tables — temporary tables, partition children,
+//! temporal history tables, migration bookkeeping, foreign tables — using
+//! the metadata the introspectors attach to each `TableInfo`.
+//!
+//! With `--follow-fks`, `includes` no longer limits what is introspected.
+//! Once foreign keys are known, the tables are cut down to those matching
+//! `includes` plus every table reachable from them through foreign keys
+//! (see [`TableFilter::close_over_fks`]). Excludes and kinds still apply to
+//! the tables reached that way.
+//!
+//! `--schemas` items take the same pattern syntax. A pattern is expanded
+//! against the database's non-system schemas before introspection (see
+//! [`expand_schema_patterns`]); a plain name is used as given.
+
+use std::str::FromStr;
 
 use glob::Pattern;
+use regex::Regex;
 
+use crate::codegen::fk_closure;
 use crate::error::UvgError;
+use crate::schema::{IntrospectedSchema, TableInfo, TableType};
+
+/// Migration-tool bookkeeping tables dropped by `--exclude-kinds migrations`.
+pub const MIGRATION_TABLES: &[&str] = &[
+    "alembic_version",
+    "flyway_schema_history",
+    "__EFMigrationsHistory",
+    "django_migrations",
+    "schema_migrations",
+    "uvg_version",
+];
+
+/// A class of tables that can be excluded wholesale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableKind {
+    /// `CREATE TEMP TABLE`s read from a SQL file or snapshot. Live
+    /// databases never show one session's temporary tables to another, so
+    /// introspection finds none.
+    Temp,
+    /// Declarative partition children (the parent is kept).
+    Partitions,
+    /// History tables backing system-versioned temporal tables.
+    History,
+    /// Migration bookkeeping tables (see [`MIGRATION_TABLES`]).
+    Migrations,
+    /// Every base table, leaving only views (`--views-only`).
+    Tables,
+    /// PostgreSQL foreign (FDW) tables.
+    Foreign,
+}
+
+impl TableKind {
+    /// `true` when `table` belongs to this kind.
+    pub fn applies_to(self, table: &TableInfo) -> bool {
+        match self {
+            TableKind::Temp => table.is_temporary,
+            TableKind::Partitions => table.partition_of.is_some(),
+            TableKind::History => table.history_of.is_some(),
+            TableKind::Migrations => MIGRATION_TABLES.contains(&table.name.as_str()),
+            TableKind::Tables => table.table_type == TableType::Table,
+            TableKind::Foreign => table.foreign_server.is_some(),
+        }
+    }
+}
+
+impl FromStr for TableKind {
+    type Err = UvgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "temp" | "temporary" => Ok(TableKind::Temp),
+            "partitions" | "partition" => Ok(TableKind::Partitions),
+            "history" => Ok(TableKind::History),
+            "migrations" | "migration" => Ok(TableKind::Migrations),
+            "tables" | "table" => Ok(TableKind::Tables),
+            "foreign" => Ok(TableKind::Foreign),
+            other => Err(UvgError::InvalidTableKind(other.to_string())),
+        }
+    }
+}
+
+/// What to do with a foreign key whose referenced table was filtered out
+/// (`--dangling-fks`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DanglingFks {
+    /// Emit it as introspected and report a warning. Default.
+    #[default]
+    Keep,
+    /// Leave it out of the output and report a warning.
+    Drop,
+    /// Add the referenced tables to the selection, as bare `--follow-fks`
+    /// does.
+    Include,
+}
+
+/// How `--follow-fks` grows the `--tables` selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FollowFks {
+    /// Maximum FK hops from a selected table; `None` for no limit.
+    pub depth: Option<usize>,
+    /// Also follow foreign keys backwards, to tables that reference the
+    /// selection (`--follow-fks-both`).
+    pub both: bool,
+}
+
+/// One `--tables`/`--exclude-tables` item.
+#[derive(Debug)]
+enum NamePattern {
+    Glob(Pattern),
+    Regex(Regex),
+}
+
+impl NamePattern {
+    fn parse(raw: &str) -> Result<Self, String> {
+        match raw.strip_prefix("re:") {
+            Some(re) => Regex::new(re)
+                .map(NamePattern::Regex)
+                .map_err(|e| e.to_string()),
+            None => Pattern::new(&raw.replace('%', "*"))
+                .map(NamePattern::Glob)
+                .map_err(|e| e.to_string()),
+        }
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            NamePattern::Glob(p) => p.matches(name),
+            NamePattern::Regex(r) => r.is_match(name),
+        }
+    }
+}
 
 /// Decision oracle: "should this table name be introspected?"
 #[derive(Debug, Default)]
 pub struct TableFilter {
-    includes: Vec<Pattern>,
-    excludes: Vec<Pattern>,
+    includes: Vec<NamePattern>,
+    excludes: Vec<NamePattern>,
+    excluded_kinds: Vec<TableKind>,
+    follow_fks: Option<FollowFks>,
 }
 
 impl TableFilter {
@@ -29,9 +165,27 @@ impl TableFilter {
         Ok(Self {
             includes: parse_patterns(includes, "tables")?,
             excludes: parse_patterns(excludes, "exclude-tables")?,
+            excluded_kinds: Vec::new(),
+            follow_fks: None,
         })
     }
 
+    /// Add table-kind exclusion rules on top of the name patterns.
+    pub fn with_excluded_kinds(mut self, kinds: impl IntoIterator<Item = TableKind>) -> Self {
+        for kind in kinds {
+            if !self.excluded_kinds.contains(&kind) {
+                self.excluded_kinds.push(kind);
+            }
+        }
+        self
+    }
+
+    /// Grow the `--tables` selection along foreign keys.
+    pub fn with_follow_fks(mut self, follow: Option<FollowFks>) -> Self {
+        self.follow_fks = follow;
+        self
+    }
+
     /// Convenience constructor for the empty filter (matches everything).
     pub fn allow_all() -> Self {
         Self::default()
@@ -47,15 +201,135 @@ impl TableFilter {
         }
         !self.excludes.iter().any(|p| p.matches(name))
     }
+
+    /// `true` when the introspected table survives both the name patterns
+    /// and the table-kind rules. This is the check every introspector
+    /// applies once table-level metadata is known. With `--follow-fks` only
+    /// the excludes apply here; [`Self::close_over_fks`] applies the
+    /// includes once foreign keys are known.
+    pub fn admits(&self, table: &TableInfo) -> bool {
+        let named = if self.follow_fks.is_some() {
+            !self.excludes.iter().any(|p| p.matches(&table.name))
+        } else {
+            self.matches(&table.name)
+        };
+        named && !self.excluded_kinds.iter().any(|k| k.applies_to(table))
+    }
+
+    /// With `--follow-fks`, cut `tables` (every table [`Self::admits`])
+    /// down to those matching `--tables` plus the tables their foreign keys
+    /// reach. Foreign keys into tables outside `tables` (another schema,
+    /// or excluded) are not followed. A no-op without `--follow-fks` or
+    /// without `--tables`.
+    pub fn close_over_fks(&self, tables: &mut Vec<TableInfo>) {
+        let Some(follow) = self.follow_fks else {
+            return;
+        };
+        if self.includes.is_empty() {
+            return;
+        }
+        let seeds: Vec<usize> = tables
+            .iter()
+            .enumerate()
+            .filter(|(_, table)| self.matches(&table.name))
+            .map(|(i, _)| i)
+            .collect();
+        let keep = fk_closure(tables, seeds, follow.depth, follow.both);
+        let mut index = 0;
+        tables.retain(|_| {
+            index += 1;
+            keep.contains(&(index - 1))
+        });
+    }
+}
+
+/// `--dangling-fks drop`: remove foreign keys whose referenced table is not
+/// in `schema`, noting each on its table's [`TableInfo::skipped`] list.
+pub fn drop_dangling_fks(schema: &mut IntrospectedSchema) {
+    let dangling: Vec<(String, String, String)> = schema
+        .dangling_fks()
+        .into_iter()
+        .map(|(table, constraint)| {
+            (
+                table.schema.clone(),
+                table.name.clone(),
+                constraint.name.clone(),
+            )
+        })
+        .collect();
+    for (schema_name, table_name, constraint_name) in dangling {
+        let Some(table) = schema
+            .tables
+            .iter_mut()
+            .find(|t| t.schema == schema_name && t.name == table_name)
+        else {
+            continue;
+        };
+        let Some(pos) = table
+            .constraints
+            .iter()
+            .position(|c| c.name == constraint_name && c.foreign_key.is_some())
+        else {
+            continue;
+        };
+        let constraint = table.constraints.remove(pos);
+        if let Some(fk) = constraint.foreign_key {
+            table.skipped.push(format!(
+                "foreign key {} references {}, {}; dropped",
+                constraint.name,
+                fk.qualified_target(),
+                fk.unresolved_reason()
+            ));
+        }
+    }
+}
+
+/// Whether a `--schemas` item is a pattern rather than a schema name.
+pub fn is_schema_pattern(raw: &str) -> bool {
+    raw.starts_with("re:") || raw.contains(['*', '?', '[', '%'])
+}
+
+/// Whether any `--schemas` item needs the schema list to expand.
+pub fn has_schema_patterns(requested: &[String]) -> bool {
+    requested.iter().any(|raw| is_schema_pattern(raw))
+}
+
+/// `requested` with each pattern replaced by the `available` schemas it
+/// matches, in `available` order. Plain names pass through unchanged;
+/// duplicates are dropped.
+pub fn expand_schema_patterns(
+    requested: &[String],
+    available: &[String],
+) -> Result<Vec<String>, UvgError> {
+    let mut schemas: Vec<String> = Vec::new();
+    for raw in requested {
+        let matched: Vec<&String> = if is_schema_pattern(raw) {
+            let pattern =
+                NamePattern::parse(raw).map_err(|reason| UvgError::InvalidTablePattern {
+                    flag: "schemas",
+                    pattern: raw.clone(),
+                    reason,
+                })?;
+            available.iter().filter(|s| pattern.matches(s)).collect()
+        } else {
+            vec![raw]
+        };
+        for schema in matched {
+            if !schemas.contains(schema) {
+                schemas.push(schema.clone());
+            }
+        }
+    }
+    Ok(schemas)
 }
 
-fn parse_patterns(raw: &[String], flag: &'static str) -> Result<Vec<Pattern>, UvgError> {
+fn parse_patterns(raw: &[String], flag: &'static str) -> Result<Vec<NamePattern>, UvgError> {
     raw.iter()
         .map(|s| {
-            Pattern::new(s).map_err(|e| UvgError::InvalidTablePattern {
+            NamePattern::parse(s).map_err(|reason| UvgError::InvalidTablePattern {
                 flag,
                 pattern: s.clone(),
-                reason: e.to_string(),
+                reason,
             })
         })
         .collect()
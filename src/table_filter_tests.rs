@@ -104,3 +104,245 @@ fn invalid_pattern_in_excludes_errors_with_flag_context() {
         "expected exclude-tables flag in error: {msg}"
     );
 }
+
+#[test]
+fn like_percent_is_a_glob_star() {
+    let f = TableFilter::new(&s(&[]), &s(&["audit_%", "alembic_version"])).unwrap();
+    assert!(!f.matches("audit_log"));
+    assert!(!f.matches("alembic_version"));
+    assert!(f.matches("auditlog"));
+    assert!(f.matches("users"));
+}
+
+#[test]
+fn regex_prefix_matches_unanchored_unless_anchored() {
+    let f = TableFilter::new(&s(&[r"re:^order_\d+$"]), &s(&["re:tmp"])).unwrap();
+    assert!(f.matches("order_2024"));
+    assert!(!f.matches("order_items"));
+    assert!(!f.matches("order_2024_tmp"));
+
+    let err = TableFilter::new(&s(&["re:(unclosed"]), &s(&[])).unwrap_err();
+    assert!(err.to_string().contains("re:(unclosed"), "{err}");
+}
+
+fn t(name: &str) -> TableInfo {
+    TableInfo::new("public", name, crate::schema::TableType::Table)
+}
+
+#[test]
+fn table_kind_parses_aliases_and_rejects_unknown() {
+    assert_eq!("temp".parse::<TableKind>().unwrap(), TableKind::Temp);
+    assert_eq!(
+        " Partitions ".parse::<TableKind>().unwrap(),
+        TableKind::Partitions
+    );
+    assert_eq!("history".parse::<TableKind>().unwrap(), TableKind::History);
+    assert_eq!(
+        "migrations".parse::<TableKind>().unwrap(),
+        TableKind::Migrations
+    );
+    assert_eq!("tables".parse::<TableKind>().unwrap(), TableKind::Tables);
+    let err = "views".parse::<TableKind>().unwrap_err();
+    assert!(err.to_string().contains("views"), "{err}");
+}
+
+#[test]
+fn admits_without_kind_rules_matches_names_only() {
+    let f = TableFilter::new(&s(&[]), &s(&["audit_*"])).unwrap();
+    let mut temp = t("scratch");
+    temp.is_temporary = true;
+    assert!(f.admits(&temp));
+    assert!(f.admits(&t("alembic_version")));
+    assert!(!f.admits(&t("audit_log")));
+}
+
+#[test]
+fn excluded_kinds_drop_matching_tables() {
+    let f = TableFilter::allow_all().with_excluded_kinds([
+        TableKind::Temp,
+        TableKind::Partitions,
+        TableKind::History,
+        TableKind::Migrations,
+    ]);
+
+    let mut temp = t("scratch");
+    temp.is_temporary = true;
+    let mut child = t("events_2024");
+    child.partition_of = Some("events".to_string());
+    let mut history = t("orders_history");
+    history.history_of = Some("orders".to_string());
+
+    assert!(!f.admits(&temp));
+    assert!(!f.admits(&child));
+    assert!(!f.admits(&history));
+    assert!(!f.admits(&t("alembic_version")));
+    assert!(!f.admits(&t("flyway_schema_history")));
+    assert!(!f.admits(&t("__EFMigrationsHistory")));
+    assert!(f.admits(&t("events")));
+    assert!(f.admits(&t("orders")));
+}
+
+#[test]
+fn foreign_kind_matches_tables_with_a_server() {
+    let f = TableFilter::allow_all().with_excluded_kinds(["foreign".parse().unwrap()]);
+    let mut remote = t("remote_orders");
+    remote.foreign_server = Some("warehouse".to_string());
+
+    assert!(!f.admits(&remote));
+    assert!(f.admits(&t("orders")));
+}
+
+#[test]
+fn excluding_tables_keeps_only_views() {
+    let f = TableFilter::allow_all().with_excluded_kinds([TableKind::Tables]);
+    let view = TableInfo::new("public", "active_users", crate::schema::TableType::View);
+
+    assert!(f.admits(&view));
+    assert!(!f.admits(&t("users")));
+}
+
+/// `countries <- users <- orders -> products`, plus an unrelated `audit`.
+fn shop() -> Vec<TableInfo> {
+    use crate::testutil::table;
+    vec![
+        table("audit").build(),
+        table("countries").build(),
+        table("orders")
+            .fk("orders_user_id_fkey", &["user_id"], "users", &["id"])
+            .fk(
+                "orders_product_id_fkey",
+                &["product_id"],
+                "products",
+                &["id"],
+            )
+            .build(),
+        table("products").build(),
+        table("users")
+            .fk(
+                "users_country_id_fkey",
+                &["country_id"],
+                "countries",
+                &["id"],
+            )
+            .build(),
+    ]
+}
+
+fn names(tables: &[TableInfo]) -> Vec<&str> {
+    tables.iter().map(|t| t.name.as_str()).collect()
+}
+
+fn follow(depth: Option<usize>, both: bool) -> TableFilter {
+    TableFilter::new(&s(&["orders"]), &s(&[]))
+        .unwrap()
+        .with_follow_fks(Some(FollowFks { depth, both }))
+}
+
+#[test]
+fn follow_fks_admits_everything_until_closed_over() {
+    let f = follow(None, false);
+    assert!(f.admits(&t("audit")));
+
+    let f = TableFilter::new(&s(&["orders"]), &s(&["audit"]))
+        .unwrap()
+        .with_follow_fks(Some(FollowFks {
+            depth: None,
+            both: false,
+        }));
+    assert!(!f.admits(&t("audit")));
+}
+
+#[test]
+fn follow_fks_adds_referenced_tables_transitively() {
+    let mut tables = shop();
+    follow(None, false).close_over_fks(&mut tables);
+    assert_eq!(names(&tables), ["countries", "orders", "products", "users"]);
+}
+
+#[test]
+fn follow_fks_depth_limits_hops() {
+    let mut tables = shop();
+    follow(Some(1), false).close_over_fks(&mut tables);
+    assert_eq!(names(&tables), ["orders", "products", "users"]);
+
+    let mut tables = shop();
+    follow(Some(0), false).close_over_fks(&mut tables);
+    assert_eq!(names(&tables), ["orders"]);
+}
+
+#[test]
+fn follow_fks_both_adds_referencing_tables() {
+    let filter = |both| {
+        TableFilter::new(&s(&["users"]), &s(&[]))
+            .unwrap()
+            .with_follow_fks(Some(FollowFks { depth: None, both }))
+    };
+
+    let mut tables = shop();
+    filter(false).close_over_fks(&mut tables);
+    assert_eq!(names(&tables), ["countries", "users"]);
+
+    let mut tables = shop();
+    filter(true).close_over_fks(&mut tables);
+    assert_eq!(names(&tables), ["countries", "orders", "products", "users"]);
+}
+
+#[test]
+fn close_over_fks_without_follow_is_a_no_op() {
+    let mut tables = shop();
+    TableFilter::new(&s(&["orders"]), &s(&[]))
+        .unwrap()
+        .close_over_fks(&mut tables);
+    assert_eq!(tables.len(), 5);
+}
+
+#[test]
+fn dropping_dangling_fks_notes_them_on_the_table() {
+    let mut schema = crate::testutil::schema_pg(shop());
+    schema
+        .tables
+        .retain(|t| t.name == "orders" || t.name == "products");
+
+    drop_dangling_fks(&mut schema);
+
+    let orders = &schema.tables[0];
+    let fks: Vec<&str> = orders.constraints.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(fks, ["orders_product_id_fkey"]);
+    assert_eq!(
+        orders.skipped,
+        ["foreign key orders_user_id_fkey references public.users, which is not generated; dropped"]
+    );
+    assert!(schema.dangling_fks().is_empty());
+}
+
+#[test]
+fn schema_patterns_expand_against_available_schemas() {
+    let available = s(&["billing", "public", "tenant_a", "tenant_b"]);
+
+    assert!(!has_schema_patterns(&s(&["public", "sales"])));
+    assert!(has_schema_patterns(&s(&["public", "tenant_%"])));
+    assert_eq!(
+        expand_schema_patterns(&s(&["tenant_%", "public", "tenant_a"]), &available).unwrap(),
+        s(&["tenant_a", "tenant_b", "public"])
+    );
+    assert_eq!(
+        expand_schema_patterns(&s(&["*"]), &available).unwrap(),
+        available
+    );
+    assert_eq!(
+        expand_schema_patterns(&s(&["re:^t.*_b$", "missing"]), &available).unwrap(),
+        s(&["tenant_b", "missing"])
+    );
+}
+
+#[test]
+fn invalid_schema_patterns_are_rejected() {
+    let err = expand_schema_patterns(&s(&["re:("]), &[]).unwrap_err();
+    assert!(matches!(
+        err,
+        UvgError::InvalidTablePattern {
+            flag: "schemas",
+            ..
+        }
+    ));
+}
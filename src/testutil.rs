@@ -56,6 +56,11 @@ impl ColumnInfoBuilder {
         self
     }
 
+    pub fn datetime_precision(mut self, p: i32) -> Self {
+        self.inner.datetime_precision = Some(p);
+        self
+    }
+
     pub fn default_val(mut self, d: &str) -> Self {
         self.inner.column_default = Some(d.to_string());
         self
@@ -79,6 +84,14 @@ impl ColumnInfoBuilder {
         self
     }
 
+    pub fn computed(mut self, expression: &str, persisted: Option<bool>) -> Self {
+        self.inner.computed = Some(ComputedInfo {
+            expression: expression.to_string(),
+            persisted,
+        });
+        self
+    }
+
     #[allow(dead_code)]
     pub fn collation(mut self, c: &str) -> Self {
         self.inner.collation = Some(c.to_string());
@@ -90,6 +103,16 @@ impl ColumnInfoBuilder {
         self
     }
 
+    pub fn period(mut self, period: PeriodColumn) -> Self {
+        self.inner.period = Some(period);
+        self
+    }
+
+    pub fn inherited(mut self) -> Self {
+        self.inner.inherited = true;
+        self
+    }
+
     pub fn build(self) -> ColumnInfo {
         self.inner
     }
@@ -156,6 +179,31 @@ impl TableInfoBuilder {
         self
     }
 
+    pub fn fk_deferrable(
+        mut self,
+        name: &str,
+        local_cols: &[&str],
+        ref_table: &str,
+        ref_cols: &[&str],
+        initially_deferred: bool,
+    ) -> Self {
+        let mut fk = ForeignKeyInfo::new(
+            "public",
+            ref_table,
+            ref_cols.iter().copied(),
+            "NO ACTION",
+            "NO ACTION",
+        );
+        fk.deferrable = true;
+        fk.initially_deferred = initially_deferred;
+        self.inner.constraints.push(ConstraintInfo::foreign_key(
+            name,
+            local_cols.iter().copied(),
+            fk,
+        ));
+        self
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn fk_full(
         mut self,
@@ -195,6 +243,34 @@ impl TableInfoBuilder {
         self
     }
 
+    /// An index with expression keys: `columns` are its plain-column keys,
+    /// `keys` every key in order.
+    pub fn expression_index(mut self, name: &str, columns: &[&str], keys: &[&str]) -> Self {
+        let mut index = IndexInfo::new(name, false, columns.iter().copied());
+        index.expressions = keys.iter().map(|k| k.to_string()).collect();
+        self.inner.indexes.push(index);
+        self
+    }
+
+    pub fn index_with_opclasses(
+        mut self,
+        name: &str,
+        cols: &[&str],
+        using: &str,
+        opclasses: &[(&str, &str)],
+    ) -> Self {
+        let mut index = IndexInfo::new(name, false, cols.iter().copied());
+        index
+            .kwargs
+            .insert("postgresql_using".to_string(), using.to_string());
+        index.opclasses = opclasses
+            .iter()
+            .map(|(c, o)| (c.to_string(), o.to_string()))
+            .collect();
+        self.inner.indexes.push(index);
+        self
+    }
+
     pub fn index_with_kwargs(
         mut self,
         name: &str,
@@ -222,6 +298,17 @@ impl TableInfoBuilder {
         self
     }
 
+    pub fn inherits(mut self, parent: &str) -> Self {
+        self.inner.inherits.push(parent.to_string());
+        self
+    }
+
+    pub fn view(mut self, definition: &str) -> Self {
+        self.inner.table_type = TableType::View;
+        self.inner.view_definition = Some(definition.to_string());
+        self
+    }
+
     pub fn build(self) -> TableInfo {
         self.inner
     }
@@ -234,6 +321,8 @@ pub fn schema_pg(tables: Vec<TableInfo>) -> IntrospectedSchema {
         tables,
         enums: vec![],
         domains: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
     }
 }
 
@@ -244,6 +333,8 @@ pub fn schema_mssql(tables: Vec<TableInfo>) -> IntrospectedSchema {
         tables,
         enums: vec![],
         domains: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
     }
 }
 
@@ -254,6 +345,8 @@ pub fn schema_pg_with_enums(tables: Vec<TableInfo>, enums: Vec<EnumInfo>) -> Int
         tables,
         enums,
         domains: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
     }
 }
 
@@ -265,6 +358,8 @@ pub fn schema_mysql(tables: Vec<TableInfo>) -> IntrospectedSchema {
         tables,
         enums: vec![],
         domains: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
     }
 }
 
@@ -275,6 +370,8 @@ pub fn schema_sqlite(tables: Vec<TableInfo>) -> IntrospectedSchema {
         tables,
         enums: vec![],
         domains: vec![],
+        sequences: Vec::new(),
+        extensions: Vec::new(),
     }
 }
 
@@ -12,10 +12,14 @@ pub(super) async fn generate_ddl(app: &mut App) -> Result<Vec<Change>> {
     let source_url = app.source_url.trim().to_string();
     let target_url = app.target_url.trim().to_string();
 
-    let source_config = parse_connection_url(&source_url, app.trust_cert)?;
+    let source_config = parse_connection_url(&source_url, app.auth, app.trust_cert)?
+        .with_pg_ssl(&app.pg_ssl)
+        .with_mssql_encrypt(app.encrypt);
     let source_dialect = source_config.dialect();
 
-    let target_config = parse_connection_url(&target_url, app.trust_cert)?;
+    let target_config = parse_connection_url(&target_url, app.auth, app.trust_cert)?
+        .with_pg_ssl(&app.pg_ssl)
+        .with_mssql_encrypt(app.encrypt);
     let target_dialect = target_config.dialect();
 
     // Introspect source
@@ -55,10 +59,13 @@ pub(super) async fn generate_ddl(app: &mut App) -> Result<Vec<Change>> {
     let ddl_opts = DdlOptions {
         target_dialect,
         split_tables: false,
+        split_by_schema: false,
         apply: false,
         noindexes: false,
         noconstraints: false,
         nocomments: false,
+        notablecomments: false,
+        nocolumncomments: false,
     };
 
     Ok(compute_changes(
@@ -70,7 +77,9 @@ pub(super) async fn generate_ddl(app: &mut App) -> Result<Vec<Change>> {
 
 pub(super) async fn apply_ddl(app: &mut App) -> Result<ApplyReport> {
     let target_url = app.target_url.trim().to_string();
-    let config = parse_connection_url(&target_url, app.trust_cert)?;
+    let config = parse_connection_url(&target_url, app.auth, app.trust_cert)?
+        .with_pg_ssl(&app.pg_ssl)
+        .with_mssql_encrypt(app.encrypt);
     let sql = collect_apply_sql(&app.nodes);
     // The TUI renders its own per-statement status from the returned results,
     // but validation, parse-check, and retry behavior are exactly the same as
@@ -47,7 +47,10 @@ pub(super) struct App {
     pub(super) error_msg: Option<String>,
     pub(super) success_msg: Option<String>,
     pub(super) apply_results: Vec<db::StmtResult>,
+    pub(super) auth: crate::connection::MssqlAuth,
+    pub(super) pg_ssl: crate::connection::PgSslOptions,
     pub(super) trust_cert: bool,
+    pub(super) encrypt: crate::connection::MssqlEncrypt,
     pub(super) apply_retries: u8,
     pub(super) parse_check: bool,
 }
@@ -72,7 +75,10 @@ impl App {
             error_msg: None,
             success_msg: None,
             apply_results: Vec::new(),
+            auth: cli.auth,
+            pg_ssl: cli.pg_ssl(),
             trust_cert: cli.trust_cert,
+            encrypt: cli.encrypt,
             apply_retries: cli.apply_retries,
             parse_check: !cli.no_parse_check,
         }
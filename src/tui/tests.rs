@@ -44,7 +44,10 @@ fn view_app(nodes: Vec<TreeNode>) -> App {
         error_msg: None,
         success_msg: None,
         apply_results: Vec::new(),
+        auth: crate::connection::MssqlAuth::Sql,
+        pg_ssl: crate::connection::PgSslOptions::default(),
         trust_cert: false,
+        encrypt: crate::connection::MssqlEncrypt::Required,
         apply_retries: 3,
         parse_check: true,
     };
@@ -104,7 +104,7 @@ pub(super) fn generic(ct: &CanonicalType, source: Dialect) -> MappedType {
                 python_type: "list".to_string(),
                 import_module: SA.to_string(),
                 import_name: "ARRAY".to_string(),
-                element_import: Some((inner.import_module, inner.import_name)),
+                extra_imports: vec![(inner.import_module, inner.import_name)],
             }
         }
         CanonicalType::Raw { type_name } => raw(type_name, source),
@@ -112,21 +112,73 @@ pub(super) fn generic(ct: &CanonicalType, source: Dialect) -> MappedType {
 }
 
 /// Resolve a `Raw` (non-portable) type to its SQLAlchemy form. PG has a few
-/// dialect types (INET/CIDR) and reports untyped columns as NullType; every
-/// dialect otherwise falls back to the uppercased name from `sqlalchemy`.
+/// dialect and extension types (see [`pg_raw`]); every dialect otherwise
+/// falls back to the uppercased name from `sqlalchemy`.
 fn raw(type_name: &str, source: Dialect) -> MappedType {
     if source == Dialect::Postgres {
-        match type_name {
-            "INET" => return simple("INET", "str", PG),
-            "CIDR" => return simple("CIDR", "str", PG),
-            "TSVECTOR" => return simple("TSVECTOR", "str", PG),
-            "" => return simple("NullType", "str", "sqlalchemy.sql.sqltypes"),
-            _ => {}
+        if let Some(mapped) = pg_raw(type_name) {
+            return mapped;
         }
     }
     simple(type_name, "str", SA)
 }
 
+/// PostgreSQL types canonical keeps raw that still have a known
+/// SQLAlchemy form: the INET/CIDR/MACADDR/MONEY/OID/BIT/TSVECTOR/HSTORE
+/// dialect types, `xml` and the `citext` and `ltree` extension types
+/// (plain `Text`; SQLAlchemy has no importable class for them), ranges,
+/// and untyped columns as NullType.
+pub(super) fn pg_raw(type_name: &str) -> Option<MappedType> {
+    match type_name {
+        "INET" => Some(simple("INET", "str", PG)),
+        "CIDR" => Some(simple("CIDR", "str", PG)),
+        "MACADDR" => Some(simple("MACADDR", "str", PG)),
+        "MACADDR8" => Some(simple("MACADDR8", "str", PG)),
+        // Drivers return money as a locale-formatted string ('$1.00').
+        "MONEY" => Some(simple("MONEY", "str", PG)),
+        "OID" => Some(simple("OID", "int", PG)),
+        "BIT" => Some(simple("BIT", "str", PG)),
+        "VARBIT" => Some(MappedType {
+            sa_type: "BIT(varying=True)".to_string(),
+            python_type: "str".to_string(),
+            import_module: PG.to_string(),
+            import_name: "BIT".to_string(),
+            extra_imports: Vec::new(),
+        }),
+        "TSVECTOR" => Some(simple("TSVECTOR", "str", PG)),
+        "HSTORE" => Some(simple("HSTORE", "dict", PG)),
+        "XML" | "CITEXT" | "LTREE" => Some(simple("Text", "str", SA)),
+        "" => Some(simple("NullType", "str", "sqlalchemy.sql.sqltypes")),
+        other => pg_range(other),
+    }
+}
+
+/// PostgreSQL range and multirange types (`INT4RANGE`, `TSTZMULTIRANGE`),
+/// annotated with SQLAlchemy 2.0's `Range[...]` over the bound type. The
+/// `Range` class rides along as the secondary import.
+fn pg_range(type_name: &str) -> Option<MappedType> {
+    let (bound, multi) = match type_name.strip_suffix("MULTIRANGE") {
+        Some(bound) => (bound, true),
+        None => (type_name.strip_suffix("RANGE")?, false),
+    };
+    let bound_type = match bound {
+        "INT4" | "INT8" => "int",
+        "NUM" => "decimal.Decimal",
+        "DATE" => "datetime.date",
+        "TS" | "TSTZ" => "datetime.datetime",
+        _ => return None,
+    };
+    let python_type = if multi {
+        format!("list[Range[{bound_type}]]")
+    } else {
+        format!("Range[{bound_type}]")
+    };
+    Some(MappedType {
+        extra_imports: vec![(PG.to_string(), "Range".to_string())],
+        ..simple(type_name, &python_type, PG)
+    })
+}
+
 /// A MappedType whose rendered expression carries parameters while the
 /// import is the bare base name.
 fn parameterized(
@@ -140,6 +192,6 @@ fn parameterized(
         python_type: python_type.to_string(),
         import_module: import_module.to_string(),
         import_name: import_name.to_string(),
-        element_import: None,
+        extra_imports: Vec::new(),
     }
 }
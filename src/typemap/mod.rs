@@ -1,9 +1,11 @@
 mod canonical_sa;
 pub mod mssql;
 pub mod mysql;
+pub mod overrides;
 pub mod pg;
 pub mod sqlite;
 
+use crate::ddl_typemap::{self, CanonicalType};
 use crate::dialect::Dialect;
 use crate::schema::ColumnInfo;
 
@@ -18,12 +20,18 @@ pub struct MappedType {
     pub import_module: String,
     /// The type name to import (e.g. "Integer", "JSONB"). For parameterized types, just the base name.
     pub import_name: String,
-    /// For ARRAY types, the element type import info.
-    pub element_import: Option<(String, String)>,
+    /// Secondary imports as module and name: the element type for ARRAY
+    /// types, the class a PostgreSQL range or GeoAlchemy2 annotation names
+    /// (`Range`, `WKBElement`), or a `--typemap` override's `imports`.
+    pub extra_imports: Vec<(String, String)>,
 }
 
 /// Map a column to its SQLAlchemy type representation, dispatching by dialect.
+/// A `--typemap` override on the column wins over the dialect tables.
 pub fn map_column_type(col: &ColumnInfo, dialect: Dialect) -> MappedType {
+    if let Some(ref type_override) = col.type_override {
+        return overrides::mapped_type(type_override);
+    }
     match dialect {
         Dialect::Postgres => pg::map_column_type(col),
         Dialect::Mssql => mssql::map_column_type(col),
@@ -34,6 +42,9 @@ pub fn map_column_type(col: &ColumnInfo, dialect: Dialect) -> MappedType {
 
 /// Map a column keeping dialect-specific types (for keep_dialect_types option).
 pub fn map_column_type_dialect(col: &ColumnInfo, dialect: Dialect) -> MappedType {
+    if let Some(ref type_override) = col.type_override {
+        return overrides::mapped_type(type_override);
+    }
     match dialect {
         Dialect::Postgres => pg::map_column_type_dialect(col),
         Dialect::Mssql => mssql::map_column_type_dialect(col),
@@ -42,6 +53,84 @@ pub fn map_column_type_dialect(col: &ColumnInfo, dialect: Dialect) -> MappedType
     }
 }
 
+/// Uppercase type names the `sqlalchemy` package exports.
+const SQLALCHEMY_UPPERCASE_TYPES: &[&str] = &[
+    "ARRAY",
+    "BIGINT",
+    "BINARY",
+    "BLOB",
+    "BOOLEAN",
+    "CHAR",
+    "CLOB",
+    "DATE",
+    "DATETIME",
+    "DECIMAL",
+    "DOUBLE",
+    "DOUBLE_PRECISION",
+    "FLOAT",
+    "INT",
+    "INTEGER",
+    "JSON",
+    "NCHAR",
+    "NUMERIC",
+    "NVARCHAR",
+    "REAL",
+    "SMALLINT",
+    "TEXT",
+    "TIME",
+    "TIMESTAMP",
+    "UUID",
+    "VARBINARY",
+    "VARCHAR",
+];
+
+/// Note to emit above a column whose type is a stand-in because SQLAlchemy
+/// has no equivalent (or the column has no declared type at all); `None`
+/// for overridden columns. `mapped` is the type the generator rendered,
+/// if it rendered the column's mapping rather than an `Enum` or `DOMAIN`:
+/// an unknown type falls back to its uppercased name from `sqlalchemy`,
+/// which only imports when the package happens to export one.
+pub fn fallback_note(
+    col: &ColumnInfo,
+    dialect: Dialect,
+    mapped: Option<&MappedType>,
+) -> Option<String> {
+    if col.type_override.is_some() {
+        return None;
+    }
+    if col.udt_name.is_empty() {
+        return Some("no declared type; mapped to NullType".to_string());
+    }
+    if dialect == Dialect::Mssql {
+        if let Some(note) = mssql::fallback_note(col) {
+            return Some(note);
+        }
+    }
+    let mapped = mapped?;
+    let CanonicalType::Raw { type_name } = ddl_typemap::to_canonical(col, dialect) else {
+        return None;
+    };
+    (mapped.import_module == "sqlalchemy"
+        && mapped.import_name == type_name
+        && !SQLALCHEMY_UPPERCASE_TYPES.contains(&type_name.as_str()))
+    .then(|| {
+        format!(
+            "{} has no SQLAlchemy type; mapped to {type_name}, which sqlalchemy does not export \
+             (add a --typemap override)",
+            col.udt_name
+        )
+    })
+}
+
+/// `ARRAY(inner)`, with `dimensions=N` for a multi-dimensional
+/// PostgreSQL array.
+pub fn array_type(inner: &str, dimensions: Option<i32>) -> String {
+    match dimensions {
+        Some(n) => format!("ARRAY({inner}, dimensions={n})"),
+        None => format!("ARRAY({inner})"),
+    }
+}
+
 /// Helper to create a simple MappedType with no parameters or element imports.
 pub fn simple(sa_type: &str, python_type: &str, import_module: &str) -> MappedType {
     MappedType {
@@ -49,6 +138,6 @@ pub fn simple(sa_type: &str, python_type: &str, import_module: &str) -> MappedTy
         python_type: python_type.to_string(),
         import_module: import_module.to_string(),
         import_name: sa_type.to_string(),
-        element_import: None,
+        extra_imports: Vec::new(),
     }
 }
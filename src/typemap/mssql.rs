@@ -11,14 +11,21 @@ const MS: &str = "sqlalchemy.dialects.mssql";
 /// Numeric/temporal parsing rides on `ddl_typemap::to_canonical`
 /// (money → Decimal(19,4), datetimeoffset → tz-aware Timestamp, ...); the
 /// leaf table below keeps only what canonical collapses: the
-/// String/Unicode split with collation, NTEXT, bare LargeBinary for the
-/// binary family, TINYINT, and UNIQUEIDENTIFIER.
+/// String/Unicode split with collation (Text/UnicodeText for the `max`
+/// forms), NTEXT, bare LargeBinary for the
+/// binary family, TINYINT, and UNIQUEIDENTIFIER. SQL_VARIANT and XML
+/// come from the dialect; the CLR types fall back to LargeBinary (see
+/// [`fallback_note`]).
 pub fn map_column_type(col: &ColumnInfo) -> MappedType {
     match col.udt_name.as_str() {
         // canonical folds tinyint into SmallInt; SA keeps the dialect type.
         "tinyint" => simple("TINYINT", "int", MS),
         // canonical folds nvarchar/nchar into Varchar/Char, losing the
         // unicode-ness and the collation that sqlacodegen renders.
+        // varchar(max)/nvarchar(max) carry no length; a bare String would
+        // create VARCHAR with no length elsewhere.
+        "varchar" if col.character_maximum_length.is_none() => string_type("Text", col),
+        "nvarchar" if col.character_maximum_length.is_none() => string_type("UnicodeText", col),
         "varchar" | "char" => string_type("String", col),
         "nvarchar" | "nchar" => string_type("Unicode", col),
         // canonical folds ntext into Text.
@@ -29,6 +36,16 @@ pub fn map_column_type(col: &ColumnInfo) -> MappedType {
         // canonical maps uniqueidentifier to Uuid; MSSQL reflects it as the
         // dialect's UNIQUEIDENTIFIER with a str annotation.
         "uniqueidentifier" => simple("UNIQUEIDENTIFIER", "str", MS),
+        "sql_variant" => simple("SQL_VARIANT", "str", MS),
+        "xml" => simple("XML", "str", MS),
+        "hierarchyid" | "geography" | "geometry" => simple("LargeBinary", "bytes", "sqlalchemy"),
+        // A computed column read from a SQL script declares no type.
+        "" => simple("NullType", "str", "sqlalchemy.sql.sqltypes"),
+        // SQLAlchemy's generic Time/DateTime take no precision; a declared
+        // scale keeps the dialect type.
+        "time" | "datetime2" | "datetimeoffset" if col.datetime_precision.is_some() => {
+            temporal(col)
+        }
         _ => {
             let ct = ddl_typemap::to_canonical(col, Dialect::Mssql);
             canonical_sa::generic(&ct, Dialect::Mssql)
@@ -58,7 +75,7 @@ pub fn map_column_type_dialect(col: &ColumnInfo) -> MappedType {
                 python_type: "decimal.Decimal".to_string(),
                 import_module: MS.to_string(),
                 import_name: "NUMERIC".to_string(),
-                element_import: None,
+                extra_imports: Vec::new(),
             }
         }
         "money" => simple("MONEY", "decimal.Decimal", MS),
@@ -73,12 +90,16 @@ pub fn map_column_type_dialect(col: &ColumnInfo) -> MappedType {
         "varbinary" => simple("VARBINARY", "bytes", MS),
         "image" => simple("IMAGE", "bytes", MS),
         "datetime" => simple("DATETIME", "datetime.datetime", MS),
-        "datetime2" => simple("DATETIME2", "datetime.datetime", MS),
+        "datetime2" => temporal(col),
         "smalldatetime" => simple("SMALLDATETIME", "datetime.datetime", MS),
-        "datetimeoffset" => simple("DATETIMEOFFSET", "datetime.datetime", MS),
+        "datetimeoffset" => temporal(col),
         "date" => simple("DATE", "datetime.date", MS),
-        "time" => simple("TIME", "datetime.time", MS),
+        "time" => temporal(col),
         "uniqueidentifier" => simple("UNIQUEIDENTIFIER", "str", MS),
+        "sql_variant" => simple("SQL_VARIANT", "str", MS),
+        "xml" => simple("XML", "str", MS),
+        "hierarchyid" | "geography" | "geometry" => simple("LargeBinary", "bytes", "sqlalchemy"),
+        "" => simple("NullType", "str", "sqlalchemy.sql.sqltypes"),
         other => {
             let upper = other.to_uppercase();
             simple(&upper, "str", MS)
@@ -86,7 +107,40 @@ pub fn map_column_type_dialect(col: &ColumnInfo) -> MappedType {
     }
 }
 
-/// Format a String/Unicode type expression with optional length and
+/// TIME/DATETIME2/DATETIMEOFFSET with the column's fractional-seconds
+/// scale, e.g. `DATETIME2(3)`.
+fn temporal(col: &ColumnInfo) -> MappedType {
+    let (base, python_type) = match col.udt_name.as_str() {
+        "time" => ("TIME", "datetime.time"),
+        "datetimeoffset" => ("DATETIMEOFFSET", "datetime.datetime"),
+        _ => ("DATETIME2", "datetime.datetime"),
+    };
+    let sa_type = match col.datetime_precision {
+        Some(p) => format!("{base}({p})"),
+        None => base.to_string(),
+    };
+    MappedType {
+        sa_type,
+        python_type: python_type.to_string(),
+        import_module: MS.to_string(),
+        import_name: base.to_string(),
+        extra_imports: Vec::new(),
+    }
+}
+
+/// Note for a column whose type has no SQLAlchemy equivalent. The CLR
+/// types `hierarchyid`, `geography`, and `geometry` come back from the
+/// driver as their binary serialization, so they map to `LargeBinary`.
+pub fn fallback_note(col: &ColumnInfo) -> Option<String> {
+    match col.udt_name.as_str() {
+        udt @ ("hierarchyid" | "geography" | "geometry") => Some(format!(
+            "{udt} has no SQLAlchemy type; mapped to LargeBinary"
+        )),
+        _ => None,
+    }
+}
+
+/// Format a String/Unicode/Text type expression with optional length and
 /// collation, matching sqlacodegen output: `String(50, 'collation')` or
 /// `Unicode(collation='collation')`.
 fn string_type(base: &str, col: &ColumnInfo) -> MappedType {
@@ -101,7 +155,7 @@ fn string_type(base: &str, col: &ColumnInfo) -> MappedType {
         python_type: "str".to_string(),
         import_module: "sqlalchemy".to_string(),
         import_name: base.to_string(),
-        element_import: None,
+        extra_imports: Vec::new(),
     }
 }
 
@@ -115,7 +169,7 @@ fn sized(base: &str, length: Option<i32>) -> MappedType {
         python_type: "str".to_string(),
         import_module: MS.to_string(),
         import_name: base.to_string(),
-        element_import: None,
+        extra_imports: Vec::new(),
     }
 }
 
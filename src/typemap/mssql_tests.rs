@@ -82,7 +82,16 @@ fn test_string_types() {
 fn test_varchar_max() {
     // varchar(max) has no character_maximum_length
     let m = map_column_type(&col("varchar"));
-    assert_eq!(m.sa_type, "String");
+    assert_eq!(m.sa_type, "Text");
+    assert_eq!(m.import_name, "Text");
+    assert_eq!(map_column_type(&col("nvarchar")).sa_type, "UnicodeText");
+
+    let mut c = col("nvarchar");
+    c.collation = Some("Latin1_General_CI_AS".to_string());
+    assert_eq!(
+        map_column_type(&c).sa_type,
+        "UnicodeText(collation='Latin1_General_CI_AS')"
+    );
 }
 
 #[test]
@@ -117,3 +126,61 @@ fn test_fallback() {
     let m = map_column_type(&col("xml"));
     assert_eq!(m.sa_type, "XML");
 }
+
+#[test]
+fn test_dialect_only_types() {
+    for (udt, sa_type) in [("sql_variant", "SQL_VARIANT"), ("xml", "XML")] {
+        let m = map_column_type(&col(udt));
+        assert_eq!(m.sa_type, sa_type);
+        assert_eq!(m.import_module, "sqlalchemy.dialects.mssql");
+        assert_eq!(map_column_type_dialect(&col(udt)), m);
+        assert_eq!(fallback_note(&col(udt)), None);
+    }
+}
+
+#[test]
+fn test_clr_types_fall_back_to_large_binary() {
+    for udt in ["hierarchyid", "geography", "geometry"] {
+        let m = map_column_type(&col(udt));
+        assert_eq!(m.sa_type, "LargeBinary");
+        assert_eq!(m.python_type, "bytes");
+        assert_eq!(m.import_module, "sqlalchemy");
+        assert_eq!(map_column_type_dialect(&col(udt)), m);
+        assert!(fallback_note(&col(udt)).unwrap().starts_with(udt));
+    }
+}
+
+#[test]
+fn test_temporal_precision() {
+    let cases = [
+        ("datetime2", 3, "DATETIME2(3)", "datetime.datetime"),
+        ("time", 0, "TIME(0)", "datetime.time"),
+        (
+            "datetimeoffset",
+            2,
+            "DATETIMEOFFSET(2)",
+            "datetime.datetime",
+        ),
+    ];
+    for (udt, precision, sa_type, python_type) in cases {
+        let c = ColumnInfo {
+            datetime_precision: Some(precision),
+            ..col(udt)
+        };
+        let m = map_column_type(&c);
+        assert_eq!(m.sa_type, sa_type);
+        assert_eq!(m.python_type, python_type);
+        assert_eq!(m.import_module, "sqlalchemy.dialects.mssql");
+        assert_eq!(map_column_type_dialect(&c), m);
+    }
+    // The default scale keeps the generic types.
+    assert_eq!(map_column_type(&col("datetime2")).sa_type, "DateTime");
+}
+
+#[test]
+fn test_undeclared_type_is_null_type() {
+    for m in [map_column_type(&col("")), map_column_type_dialect(&col(""))] {
+        assert_eq!(m.sa_type, "NullType");
+        assert_eq!(m.import_module, "sqlalchemy.sql.sqltypes");
+    }
+}
@@ -18,9 +18,10 @@ pub fn map_column_type(col: &ColumnInfo) -> MappedType {
 
     match col.udt_name.as_str() {
         // canonical folds non-bool tinyint into SmallInt; SA keeps TINYINT.
-        "tinyint" if !is_tinyint_bool(col) => unsigned_aware("TINYINT", col),
+        // Unsigned integers take canonical's widened generic type instead.
+        "tinyint" if !is_tinyint_bool(col) && !is_unsigned(col) => simple("TINYINT", "int", MY),
         // canonical folds mediumint into Integer; SA keeps MEDIUMINT.
-        "mediumint" => unsigned_aware("MEDIUMINT", col),
+        "mediumint" if !is_unsigned(col) => simple("MEDIUMINT", "int", MY),
         // canonical folds the size classes into Text/Bytes.
         "tinytext" => simple("TINYTEXT", "str", MY),
         "mediumtext" => simple("MEDIUMTEXT", "str", MY),
@@ -52,7 +53,7 @@ pub fn map_column_type_dialect(col: &ColumnInfo) -> MappedType {
             python_type: "int".to_string(),
             import_module: MY.to_string(),
             import_name: "TINYINT".to_string(),
-            element_import: None,
+            extra_imports: Vec::new(),
         },
         "tinyint" => unsigned_aware("TINYINT", col),
         "smallint" => unsigned_aware("SMALLINT", col),
@@ -143,7 +144,7 @@ fn named(sa_type: String, python_type: &str, import_name: &str) -> MappedType {
         python_type: python_type.to_string(),
         import_module: MY.to_string(),
         import_name: import_name.to_string(),
-        element_import: None,
+        extra_imports: Vec::new(),
     }
 }
 
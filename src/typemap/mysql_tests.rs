@@ -27,13 +27,34 @@ fn test_tinyint_not_bool() {
 fn test_unsigned_int() {
     let c = mysql_col("int", "int unsigned");
     let m = map_column_type(&c);
-    assert_eq!(m.sa_type, "Integer");
+    assert_eq!(m.sa_type, "BigInteger");
 
     let md = map_column_type_dialect(&c);
     assert_eq!(md.sa_type, "INTEGER(unsigned=True)");
     assert_eq!(md.import_module, MY);
 }
 
+#[test]
+fn test_unsigned_integers_widen_from_signed_flag() {
+    let unsigned = |udt: &str| {
+        let mut c = mysql_col(udt, udt);
+        c.signed = false;
+        map_column_type(&c).sa_type
+    };
+    assert_eq!(unsigned("tinyint"), "SmallInteger");
+    assert_eq!(unsigned("smallint"), "Integer");
+    assert_eq!(unsigned("mediumint"), "Integer");
+    assert_eq!(unsigned("int"), "BigInteger");
+    assert_eq!(unsigned("bigint"), "BigInteger");
+
+    let mut c = mysql_col("tinyint", "tinyint");
+    c.signed = false;
+    assert_eq!(
+        map_column_type_dialect(&c).sa_type,
+        "TINYINT(unsigned=True)"
+    );
+}
+
 #[test]
 fn test_varchar() {
     let mut c = mysql_col("varchar", "varchar(255)");
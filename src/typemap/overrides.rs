@@ -0,0 +1,135 @@
+//! User type-mapping overrides (`--typemap overrides.toml`).
+//!
+//! ```toml
+//! [types.citext]
+//! sa_type = "CITEXT"
+//! python_type = "str"
+//! import = "sqlalchemy.dialects.postgresql.CITEXT"
+//!
+//! [columns."public.users.tags"]
+//! sa_type = "ARRAY(String)"
+//! python_type = "list[str]"
+//! import = "sqlalchemy.ARRAY"
+//! imports = ["sqlalchemy.String"]
+//! ```
+//!
+//! `types` entries match a column's udt name; `columns` entries match
+//! `schema.table.column` or `table.column`. Column entries win over type
+//! entries. Matches are stamped onto [`ColumnInfo::type_override`], which
+//! `map_column_type` consults before the dialect tables.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use super::MappedType;
+use crate::schema::{ColumnInfo, IntrospectedSchema, TableInfo, TypeOverride};
+
+/// Parsed contents of a `--typemap` file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeOverrides {
+    #[serde(default)]
+    types: BTreeMap<String, TypeOverride>,
+    #[serde(default)]
+    columns: BTreeMap<String, TypeOverride>,
+}
+
+impl TypeOverrides {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read typemap file {}", path.display()))?;
+        Self::parse(&text)
+            .with_context(|| format!("failed to parse typemap file {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let overrides: Self = toml::from_str(text)?;
+        for (key, entry) in overrides.types.iter().chain(&overrides.columns) {
+            for import in entry.import.iter().chain(&entry.imports) {
+                if split_import(import).is_none() {
+                    bail!("`{key}`: import `{import}` must be a dotted path like `module.Name`");
+                }
+            }
+        }
+        Ok(overrides)
+    }
+
+    /// Built-in overrides for `--options citext`: `citext` columns become
+    /// `CIText()` from the `sqlalchemy-citext` package.
+    pub fn citext_package() -> Self {
+        let citext = TypeOverride {
+            sa_type: "CIText()".to_string(),
+            python_type: "str".to_string(),
+            import: Some("citext.CIText".to_string()),
+            imports: Vec::new(),
+        };
+        Self {
+            types: BTreeMap::from([("citext".to_string(), citext)]),
+            columns: BTreeMap::new(),
+        }
+    }
+
+    /// The override for one column, if any.
+    pub fn lookup(&self, table: &TableInfo, col: &ColumnInfo) -> Option<&TypeOverride> {
+        self.columns
+            .get(&format!("{}.{}.{}", table.schema, table.name, col.name))
+            .or_else(|| self.columns.get(&format!("{}.{}", table.name, col.name)))
+            .or_else(|| self.types.get(&col.udt_name))
+    }
+
+    /// Stamp matching overrides onto the schema's columns. Returns how many
+    /// columns were overridden.
+    pub fn apply(&self, schema: &mut IntrospectedSchema) -> usize {
+        let mut count = 0;
+        for table in &mut schema.tables {
+            for i in 0..table.columns.len() {
+                if let Some(found) = self.lookup(table, &table.columns[i]).cloned() {
+                    table.columns[i].type_override = Some(found);
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+}
+
+/// Convert an override into the mapping the generators consume.
+pub(super) fn mapped_type(type_override: &TypeOverride) -> MappedType {
+    let base = type_override
+        .sa_type
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .trim();
+    let (import_module, import_name) = type_override
+        .import
+        .as_deref()
+        .and_then(split_import)
+        .unwrap_or(("sqlalchemy", base));
+    MappedType {
+        sa_type: type_override.sa_type.clone(),
+        python_type: type_override.python_type.clone(),
+        import_module: import_module.to_string(),
+        import_name: import_name.to_string(),
+        extra_imports: type_override
+            .imports
+            .iter()
+            .filter_map(|import| split_import(import))
+            .map(|(module, name)| (module.to_string(), name.to_string()))
+            .collect(),
+    }
+}
+
+fn split_import(import: &str) -> Option<(&str, &str)> {
+    import
+        .rsplit_once('.')
+        .filter(|(module, name)| !module.is_empty() && !name.is_empty())
+}
+
+#[cfg(test)]
+#[path = "overrides_tests.rs"]
+mod tests;
@@ -0,0 +1,119 @@
+use super::*;
+use crate::testutil::*;
+
+const TYPEMAP: &str = r#"
+[types.citext]
+sa_type = "CITEXT"
+python_type = "str"
+import = "sqlalchemy.dialects.postgresql.CITEXT"
+
+[columns."public.users.email"]
+sa_type = "String(320)"
+python_type = "str"
+
+[columns."users.tags"]
+sa_type = "ARRAY(String)"
+python_type = "list[str]"
+import = "sqlalchemy.ARRAY"
+imports = ["sqlalchemy.String"]
+"#;
+
+fn users() -> IntrospectedSchema {
+    schema_pg(vec![table("users")
+        .column(col("id").build())
+        .column(col("email").udt("citext").build())
+        .column(col("nickname").udt("citext").build())
+        .column(col("tags").udt("_text").build())
+        .build()])
+}
+
+#[test]
+fn column_paths_win_over_udt_names() {
+    let overrides = TypeOverrides::parse(TYPEMAP).unwrap();
+    let mut schema = users();
+    assert_eq!(overrides.apply(&mut schema), 3);
+
+    let columns = &schema.tables[0].columns;
+    assert!(columns[0].type_override.is_none());
+    assert_eq!(
+        columns[1].type_override.as_ref().unwrap().sa_type,
+        "String(320)"
+    );
+    assert_eq!(columns[2].type_override.as_ref().unwrap().sa_type, "CITEXT");
+    assert_eq!(
+        columns[3].type_override.as_ref().unwrap().sa_type,
+        "ARRAY(String)"
+    );
+}
+
+#[test]
+fn import_defaults_to_sqlalchemy_base_name() {
+    let mapped = mapped_type(&TypeOverride {
+        sa_type: "String(320)".to_string(),
+        python_type: "str".to_string(),
+        import: None,
+        imports: Vec::new(),
+    });
+    assert_eq!(mapped.import_module, "sqlalchemy");
+    assert_eq!(mapped.import_name, "String");
+
+    let mapped = mapped_type(&TypeOverride {
+        sa_type: "LtreeType".to_string(),
+        python_type: "str".to_string(),
+        import: Some("sqlalchemy_utils.types.ltree.LtreeType".to_string()),
+        imports: Vec::new(),
+    });
+    assert_eq!(mapped.import_module, "sqlalchemy_utils.types.ltree");
+    assert_eq!(mapped.import_name, "LtreeType");
+}
+
+#[test]
+fn imports_list_adds_secondary_imports() {
+    let overrides = TypeOverrides::parse(TYPEMAP).unwrap();
+    let mut schema = users();
+    overrides.apply(&mut schema);
+
+    let mapped = crate::typemap::map_column_type(&schema.tables[0].columns[3], schema.dialect);
+    assert_eq!(mapped.import_name, "ARRAY");
+    assert_eq!(
+        mapped.extra_imports,
+        [("sqlalchemy".to_string(), "String".to_string())]
+    );
+
+    let output = crate::codegen::declarative::generate(&schema, &Default::default());
+    assert!(
+        output.contains("from sqlalchemy import ARRAY, Column, Integer, MetaData, String, Table"),
+        "{output}"
+    );
+}
+
+#[test]
+fn rejects_undotted_import_and_unknown_keys() {
+    let err = TypeOverrides::parse(
+        "[types.citext]\nsa_type = \"CITEXT\"\npython_type = \"str\"\nimport = \"CITEXT\"\n",
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("dotted path"));
+
+    let err = TypeOverrides::parse(
+        "[types.tags]\nsa_type = \"ARRAY(String)\"\npython_type = \"list[str]\"\nimports = [\"String\"]\n",
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("`String` must be a dotted path"));
+
+    assert!(TypeOverrides::parse(
+        "[types.citext]\nsa_type = \"CITEXT\"\npython_type = \"str\"\nmodule = \"x\"\n"
+    )
+    .is_err());
+}
+
+#[test]
+fn citext_package_maps_citext_columns() {
+    let mut schema = users();
+    assert_eq!(TypeOverrides::citext_package().apply(&mut schema), 2);
+
+    let mapped = crate::typemap::map_column_type(&schema.tables[0].columns[1], schema.dialect);
+    assert_eq!(mapped.sa_type, "CIText()");
+    assert_eq!(mapped.import_module, "citext");
+    assert_eq!(mapped.import_name, "CIText");
+}
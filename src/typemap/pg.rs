@@ -13,16 +13,100 @@ const PG: &str = "sqlalchemy.dialects.postgresql";
 /// covers PG entirely — PG's dialect types (UUID/JSON/JSONB/INET/CIDR) are
 /// resolved there from the canonical form.
 pub fn map_column_type(col: &ColumnInfo) -> MappedType {
+    if let Some(bit) = bit_type(col) {
+        return bit;
+    }
     let ct = ddl_typemap::to_canonical(col, Dialect::Postgres);
-    canonical_sa::generic(&ct, Dialect::Postgres)
+    match ct {
+        // SQLAlchemy's generic Time/DateTime take no precision; like
+        // sqlacodegen, keep the dialect type when one was declared.
+        CanonicalType::Time {
+            precision: Some(_), ..
+        }
+        | CanonicalType::Timestamp {
+            precision: Some(_), ..
+        } => dialect_from_canonical(&ct),
+        _ => with_dimensions(canonical_sa::generic(&ct, Dialect::Postgres), col),
+    }
 }
 
 /// Map a PostgreSQL column keeping dialect-specific types
 /// (`keep_dialect_types` option): everything imports from
 /// `sqlalchemy.dialects.postgresql` under its native uppercase name.
 pub fn map_column_type_dialect(col: &ColumnInfo) -> MappedType {
+    if let Some(bit) = bit_type(col) {
+        return bit;
+    }
     let ct = ddl_typemap::to_canonical(col, Dialect::Postgres);
-    dialect_from_canonical(&ct)
+    with_dimensions(dialect_from_canonical(&ct), col)
+}
+
+/// Carry the declared dimensions of a multi-dimensional array column into
+/// its `ARRAY(...)` mapping.
+fn with_dimensions(mut mapped: MappedType, col: &ColumnInfo) -> MappedType {
+    let inner = mapped
+        .sa_type
+        .strip_prefix("ARRAY(")
+        .and_then(|rest| rest.strip_suffix(')'));
+    if let (Some(inner), Some(_)) = (inner, col.array_dimensions) {
+        mapped.sa_type = super::array_type(inner, col.array_dimensions);
+    }
+    mapped
+}
+
+/// `bit(n)` and `varbit(n)` carry their length, which canonical drops
+/// with the raw type name: `BIT(8)`, `BIT(64, varying=True)`.
+fn bit_type(col: &ColumnInfo) -> Option<MappedType> {
+    let varying = match col.udt_name.as_str() {
+        "bit" => false,
+        "varbit" => true,
+        _ => return None,
+    };
+    let mut args = Vec::new();
+    if let Some(n) = col.character_maximum_length {
+        args.push(n.to_string());
+    }
+    if varying {
+        args.push("varying=True".to_string());
+    }
+    let sa_type = if args.is_empty() {
+        "BIT".to_string()
+    } else {
+        format!("BIT({})", args.join(", "))
+    };
+    Some(MappedType {
+        sa_type,
+        python_type: "str".to_string(),
+        import_module: PG.to_string(),
+        import_name: "BIT".to_string(),
+        extra_imports: Vec::new(),
+    })
+}
+
+/// Map a PostGIS `geometry`/`geography` column to GeoAlchemy2
+/// (`geoalchemy` option), carrying the introspected geometry type and SRID.
+/// `None` for every other column.
+pub fn map_spatial_type(col: &ColumnInfo) -> Option<MappedType> {
+    let class = match col.udt_name.as_str() {
+        "geometry" => "Geometry",
+        "geography" => "Geography",
+        _ => return None,
+    };
+    let spatial = col.spatial.as_ref();
+    let mut args = Vec::new();
+    if let Some(geometry_type) = spatial.and_then(|s| s.geometry_type.as_deref()) {
+        args.push(format!("geometry_type='{geometry_type}'"));
+    }
+    if let Some(srid) = spatial.and_then(|s| s.srid) {
+        args.push(format!("srid={srid}"));
+    }
+    Some(MappedType {
+        sa_type: format!("{class}({})", args.join(", ")),
+        python_type: "WKBElement".to_string(),
+        import_module: "geoalchemy2".to_string(),
+        import_name: class.to_string(),
+        extra_imports: vec![("geoalchemy2".to_string(), "WKBElement".to_string())],
+    })
 }
 
 fn dialect_from_canonical(ct: &CanonicalType) -> MappedType {
@@ -44,7 +128,7 @@ fn dialect_from_canonical(ct: &CanonicalType) -> MappedType {
                 python_type: "decimal.Decimal".to_string(),
                 import_module: PG.to_string(),
                 import_name: "NUMERIC".to_string(),
-                element_import: None,
+                extra_imports: Vec::new(),
             }
         }
         CanonicalType::Varchar { length } => sized("VARCHAR", *length, "str"),
@@ -52,24 +136,12 @@ fn dialect_from_canonical(ct: &CanonicalType) -> MappedType {
         CanonicalType::Text => simple("TEXT", "str", PG),
         CanonicalType::Bytes { .. } => simple("BYTEA", "bytes", PG),
         CanonicalType::Date => simple("DATE", "datetime.date", PG),
-        CanonicalType::Time { with_tz: false, .. } => simple("TIME", "datetime.time", PG),
-        CanonicalType::Time { with_tz: true, .. } => MappedType {
-            sa_type: "TIME(timezone=True)".to_string(),
-            python_type: "datetime.time".to_string(),
-            import_module: PG.to_string(),
-            import_name: "TIME".to_string(),
-            element_import: None,
-        },
-        CanonicalType::Timestamp { with_tz: false, .. } => {
-            simple("TIMESTAMP", "datetime.datetime", PG)
+        CanonicalType::Time { with_tz, precision } => {
+            temporal("TIME", *with_tz, *precision, "datetime.time")
+        }
+        CanonicalType::Timestamp { with_tz, precision } => {
+            temporal("TIMESTAMP", *with_tz, *precision, "datetime.datetime")
         }
-        CanonicalType::Timestamp { with_tz: true, .. } => MappedType {
-            sa_type: "TIMESTAMP(timezone=True)".to_string(),
-            python_type: "datetime.datetime".to_string(),
-            import_module: PG.to_string(),
-            import_name: "TIMESTAMP".to_string(),
-            element_import: None,
-        },
         CanonicalType::Interval => simple("INTERVAL", "datetime.timedelta", PG),
         CanonicalType::Uuid => simple("UUID", "uuid.UUID", PG),
         CanonicalType::Json => simple("JSON", "dict", PG),
@@ -87,18 +159,13 @@ fn dialect_from_canonical(ct: &CanonicalType) -> MappedType {
                 python_type: "list".to_string(),
                 import_module: "sqlalchemy".to_string(),
                 import_name: "ARRAY".to_string(),
-                element_import: Some((inner.import_module, inner.import_name)),
+                extra_imports: vec![(inner.import_module, inner.import_name)],
             }
         }
-        CanonicalType::Raw { type_name } => match type_name.as_str() {
-            "INET" => simple("INET", "str", PG),
-            "CIDR" => simple("CIDR", "str", PG),
-            "TSVECTOR" => simple("TSVECTOR", "str", PG),
-            "" => simple("NullType", "str", "sqlalchemy.sql.sqltypes"),
-            // Fallback imports from sqlalchemy (not the dialect module) to
-            // avoid generating invalid dialect imports.
-            other => simple(other, "str", "sqlalchemy"),
-        },
+        // Fallback imports from sqlalchemy (not the dialect module) to
+        // avoid generating invalid dialect imports.
+        CanonicalType::Raw { type_name } => canonical_sa::pg_raw(type_name)
+            .unwrap_or_else(|| simple(type_name, "str", "sqlalchemy")),
     }
 }
 
@@ -112,7 +179,31 @@ fn sized(base: &str, length: Option<i32>, python_type: &str) -> MappedType {
         python_type: python_type.to_string(),
         import_module: PG.to_string(),
         import_name: base.to_string(),
-        element_import: None,
+        extra_imports: Vec::new(),
+    }
+}
+
+/// `TIME`/`TIMESTAMP` with the keyword arguments that differ from the
+/// defaults, e.g. `TIMESTAMP(timezone=True, precision=3)`.
+fn temporal(base: &str, with_tz: bool, precision: Option<u8>, python_type: &str) -> MappedType {
+    let mut args = Vec::new();
+    if with_tz {
+        args.push("timezone=True".to_string());
+    }
+    if let Some(p) = precision {
+        args.push(format!("precision={p}"));
+    }
+    let sa_type = if args.is_empty() {
+        base.to_string()
+    } else {
+        format!("{base}({})", args.join(", "))
+    };
+    MappedType {
+        sa_type,
+        python_type: python_type.to_string(),
+        import_module: PG.to_string(),
+        import_name: base.to_string(),
+        extra_imports: Vec::new(),
     }
 }
 
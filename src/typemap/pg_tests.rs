@@ -1,4 +1,5 @@
 use super::*;
+use crate::schema::SpatialInfo;
 use crate::testutil::test_column;
 
 fn col(udt_name: &str) -> ColumnInfo {
@@ -100,14 +101,67 @@ fn test_dialect_types() {
     );
 }
 
+#[test]
+fn test_extension_types() {
+    let hstore = map_column_type(&col("hstore"));
+    assert_eq!(hstore.sa_type, "HSTORE");
+    assert_eq!(hstore.python_type, "dict");
+    assert_eq!(hstore.import_module, "sqlalchemy.dialects.postgresql");
+
+    for udt in ["citext", "ltree"] {
+        let m = map_column_type(&col(udt));
+        assert_eq!(m.sa_type, "Text");
+        assert_eq!(m.python_type, "str");
+        assert_eq!(m.import_module, "sqlalchemy");
+        assert_eq!(map_column_type_dialect(&col(udt)), m);
+    }
+
+    let tags = map_column_type(&col("_citext"));
+    assert_eq!(tags.sa_type, "ARRAY(Text)");
+}
+
+#[test]
+fn test_range_types() {
+    let cases = [
+        ("int4range", "INT4RANGE", "Range[int]"),
+        ("int8range", "INT8RANGE", "Range[int]"),
+        ("numrange", "NUMRANGE", "Range[decimal.Decimal]"),
+        ("daterange", "DATERANGE", "Range[datetime.date]"),
+        ("tsrange", "TSRANGE", "Range[datetime.datetime]"),
+        ("tstzrange", "TSTZRANGE", "Range[datetime.datetime]"),
+        ("int4multirange", "INT4MULTIRANGE", "list[Range[int]]"),
+    ];
+    for (udt, sa_type, python_type) in cases {
+        let m = map_column_type(&col(udt));
+        assert_eq!(m.sa_type, sa_type);
+        assert_eq!(m.python_type, python_type);
+        assert_eq!(m.import_module, "sqlalchemy.dialects.postgresql");
+        assert_eq!(
+            m.extra_imports,
+            [(
+                "sqlalchemy.dialects.postgresql".to_string(),
+                "Range".to_string()
+            )]
+        );
+        assert_eq!(map_column_type_dialect(&col(udt)), m);
+    }
+}
+
+#[test]
+fn test_unknown_range_suffix_falls_back() {
+    let m = map_column_type(&col("floatrange"));
+    assert_eq!(m.sa_type, "FLOATRANGE");
+    assert_eq!(m.import_module, "sqlalchemy");
+}
+
 #[test]
 fn test_array_type() {
     let m = map_column_type(&col("_int4"));
     assert_eq!(m.sa_type, "ARRAY(Integer)");
     assert_eq!(m.import_name, "ARRAY");
     assert_eq!(
-        m.element_import,
-        Some(("sqlalchemy".to_string(), "Integer".to_string()))
+        m.extra_imports,
+        [("sqlalchemy".to_string(), "Integer".to_string())]
     );
 
     let m2 = map_column_type(&col("_text"));
@@ -127,3 +181,128 @@ fn test_interval() {
     assert_eq!(m.sa_type, "Interval");
     assert_eq!(m.python_type, "datetime.timedelta");
 }
+
+#[test]
+fn test_spatial_types() {
+    let mut point = col("geometry");
+    point.spatial = Some(SpatialInfo::from_type_modifier("geometry(Point,4326)"));
+    let m = map_spatial_type(&point).unwrap();
+    assert_eq!(m.sa_type, "Geometry(geometry_type='POINT', srid=4326)");
+    assert_eq!(m.python_type, "WKBElement");
+    assert_eq!(m.import_module, "geoalchemy2");
+    assert_eq!(m.import_name, "Geometry");
+
+    let mut area = col("geography");
+    area.spatial = Some(SpatialInfo::from_type_modifier("geography(PolygonZ)"));
+    assert_eq!(
+        map_spatial_type(&area).unwrap().sa_type,
+        "Geography(geometry_type='POLYGONZ')"
+    );
+
+    assert_eq!(
+        map_spatial_type(&col("geometry")).unwrap().sa_type,
+        "Geometry()"
+    );
+    assert!(map_spatial_type(&col("text")).is_none());
+}
+
+#[test]
+fn test_spatial_info_ignores_unset_srid() {
+    let info = SpatialInfo::from_type_modifier("geometry(Point,0)");
+    assert_eq!(info.geometry_type.as_deref(), Some("POINT"));
+    assert_eq!(info.srid, None);
+
+    let bare = SpatialInfo::from_type_modifier("geometry");
+    assert_eq!(bare.geometry_type, None);
+    assert_eq!(bare.srid, None);
+}
+
+#[test]
+fn test_temporal_precision() {
+    let precise = |udt: &str, p: i32| ColumnInfo {
+        datetime_precision: Some(p),
+        ..col(udt)
+    };
+    let cases = [
+        ("timestamp", 3, "TIMESTAMP(precision=3)"),
+        ("timestamptz", 0, "TIMESTAMP(timezone=True, precision=0)"),
+        ("time", 2, "TIME(precision=2)"),
+        ("timetz", 6, "TIME(timezone=True, precision=6)"),
+    ];
+    for (udt, p, sa_type) in cases {
+        let m = map_column_type(&precise(udt, p));
+        assert_eq!(m.sa_type, sa_type);
+        assert_eq!(m.import_module, "sqlalchemy.dialects.postgresql");
+        assert_eq!(map_column_type_dialect(&precise(udt, p)), m);
+    }
+    assert_eq!(map_column_type(&col("timestamp")).sa_type, "DateTime");
+    assert_eq!(
+        map_column_type_dialect(&col("timestamptz")).sa_type,
+        "TIMESTAMP(timezone=True)"
+    );
+}
+
+#[test]
+fn test_money_macaddr_oid_xml() {
+    let cases = [
+        ("money", "MONEY", "str", "sqlalchemy.dialects.postgresql"),
+        (
+            "macaddr",
+            "MACADDR",
+            "str",
+            "sqlalchemy.dialects.postgresql",
+        ),
+        (
+            "macaddr8",
+            "MACADDR8",
+            "str",
+            "sqlalchemy.dialects.postgresql",
+        ),
+        ("oid", "OID", "int", "sqlalchemy.dialects.postgresql"),
+        ("xml", "Text", "str", "sqlalchemy"),
+    ];
+    for (udt, sa_type, python_type, module) in cases {
+        let m = map_column_type(&col(udt));
+        assert_eq!(m.sa_type, sa_type);
+        assert_eq!(m.python_type, python_type);
+        assert_eq!(m.import_module, module);
+        assert_eq!(map_column_type_dialect(&col(udt)), m);
+    }
+}
+
+#[test]
+fn test_bit_types() {
+    assert_eq!(
+        map_column_type(&col_with_length("bit", 8)).sa_type,
+        "BIT(8)"
+    );
+    assert_eq!(
+        map_column_type(&col_with_length("varbit", 64)).sa_type,
+        "BIT(64, varying=True)"
+    );
+    assert_eq!(
+        map_column_type_dialect(&col("varbit")).sa_type,
+        "BIT(varying=True)"
+    );
+    let bits = map_column_type(&col("bit"));
+    assert_eq!(bits.import_module, "sqlalchemy.dialects.postgresql");
+    assert_eq!(bits.import_name, "BIT");
+    assert_eq!(map_column_type(&col("_bit")).sa_type, "ARRAY(BIT)");
+}
+
+#[test]
+fn test_multidimensional_array() {
+    let grid = ColumnInfo {
+        array_dimensions: Some(2),
+        ..col("_int4")
+    };
+    assert_eq!(
+        map_column_type(&grid).sa_type,
+        "ARRAY(Integer, dimensions=2)"
+    );
+    assert_eq!(
+        map_column_type_dialect(&grid).sa_type,
+        "ARRAY(INTEGER, dimensions=2)"
+    );
+    assert_eq!(map_column_type(&col("_int4")).sa_type, "ARRAY(Integer)");
+}
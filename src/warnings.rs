@@ -0,0 +1,294 @@
+//! Lossy steps of a run: a type with no exact equivalent, a default the
+//! output cannot carry, an object that was skipped, a name that had to be
+//! changed. Introspection records what it skips on the schema model and
+//! each generator reports the rest here; the binary prints a summary once
+//! the output is written and, with `--strict`, fails the run.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::cli::GeneratorOptions;
+use crate::codegen::declarative::class_attr_names;
+use crate::codegen::has_primary_key;
+use crate::codegen::order_tables;
+use crate::schema::{IntrospectedSchema, ReplicaIdentity, TableInfo};
+
+/// What kind of information a warning says was lost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningKind {
+    /// A column type mapped to a stand-in.
+    TypeFallback,
+    /// A column default left out of the output.
+    DroppedDefault,
+    /// An index, constraint, or other object left out of the output.
+    SkippedConstruct,
+    /// An identifier changed to be valid in the output language.
+    NameSanitized,
+    /// A foreign key to a table that is not in the output.
+    DanglingReference,
+    /// Columns that need a database extension the output does not create.
+    ExtensionDependency,
+    /// A foreign key cycle, broken by creating one constraint after its
+    /// tables.
+    CircularReference,
+    /// A published table whose changes logical replication cannot identify.
+    ReplicationHazard,
+    /// A primary key uvg chose for a table or view that declares none.
+    InferredKey,
+}
+
+impl WarningKind {
+    fn label(self) -> &'static str {
+        match self {
+            WarningKind::TypeFallback => "type fallback",
+            WarningKind::DroppedDefault => "dropped default",
+            WarningKind::SkippedConstruct => "skipped",
+            WarningKind::NameSanitized => "renamed",
+            WarningKind::DanglingReference => "dangling reference",
+            WarningKind::ExtensionDependency => "extension",
+            WarningKind::CircularReference => "circular reference",
+            WarningKind::ReplicationHazard => "replication",
+            WarningKind::InferredKey => "inferred key",
+        }
+    }
+}
+
+/// One lossy step, located by `schema.table` or `schema.table.column`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Warning {
+    pub kind: WarningKind,
+    pub object: String,
+    pub message: String,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}: {}",
+            self.kind.label(),
+            self.object,
+            self.message
+        )
+    }
+}
+
+/// Warnings gathered over one run, in the order they were reported.
+#[derive(Debug, Default)]
+pub struct Warnings {
+    items: Vec<Warning>,
+}
+
+impl Warnings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(
+        &mut self,
+        kind: WarningKind,
+        object: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        self.items.push(Warning {
+            kind,
+            object: object.into(),
+            message: message.into(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Warning> {
+        self.items.iter()
+    }
+
+    /// Objects introspection found but could not represent
+    /// ([`TableInfo::skipped`]).
+    pub fn introspection(&mut self, schema: &IntrospectedSchema) {
+        for table in &schema.tables {
+            for note in &table.skipped {
+                self.push(WarningKind::SkippedConstruct, table_path(table), note);
+            }
+        }
+    }
+
+    /// Primary keys stood in for missing ones ([`TableInfo::inferred_keys`]).
+    pub fn inferred_keys(&mut self, schema: &IntrospectedSchema) {
+        for table in &schema.tables {
+            for note in &table.inferred_keys {
+                self.push(WarningKind::InferredKey, table_path(table), note);
+            }
+        }
+    }
+
+    /// PostgreSQL tables with no primary key and `REPLICA IDENTITY NOTHING`,
+    /// whose UPDATE and DELETE changes logical-replication consumers cannot
+    /// identify. A key `synthesize_pk` stood in for does not count.
+    pub fn replica_identity(&mut self, schema: &IntrospectedSchema) {
+        for table in &schema.tables {
+            if table.replica_identity != Some(ReplicaIdentity::Nothing)
+                || (has_primary_key(&table.constraints) && table.inferred_keys.is_empty())
+            {
+                continue;
+            }
+            let published = if table.publications.is_empty() {
+                String::new()
+            } else {
+                format!(" (published in {})", table.publications.join(", "))
+            };
+            self.push(
+                WarningKind::ReplicationHazard,
+                table_path(table),
+                format!(
+                    "no primary key and REPLICA IDENTITY NOTHING{published}; \
+                     UPDATE and DELETE will break logical replication consumers"
+                ),
+            );
+        }
+    }
+
+    /// Foreign keys kept although their referenced table is not in the
+    /// output (`--dangling-fks keep`).
+    pub fn dangling_fks(&mut self, schema: &IntrospectedSchema) {
+        for (table, constraint) in schema.dangling_fks() {
+            let Some(ref fk) = constraint.foreign_key else {
+                continue;
+            };
+            self.push(
+                WarningKind::DanglingReference,
+                table_path(table),
+                format!(
+                    "foreign key {} references {}, {}",
+                    constraint.name,
+                    fk.qualified_target(),
+                    fk.unresolved_reason()
+                ),
+            );
+        }
+    }
+
+    /// Foreign key cycles the SQLAlchemy generators break by declaring one
+    /// constraint with `use_alter=True`.
+    pub fn fk_cycles(&mut self, schema: &IntrospectedSchema) {
+        for cycle in order_tables(&schema.tables).cycles {
+            let object = if cycle.schema.is_empty() {
+                cycle.table.clone()
+            } else {
+                format!("{}.{}", cycle.schema, cycle.table)
+            };
+            self.push(
+                WarningKind::CircularReference,
+                object,
+                format!(
+                    "foreign key {} closes the cycle {}; declared with use_alter=True",
+                    cycle.constraint,
+                    cycle.path()
+                ),
+            );
+        }
+    }
+
+    /// Tables whose columns need a PostgreSQL extension, which must exist
+    /// before the generated models or DDL can create them.
+    pub fn extensions(&mut self, schema: &IntrospectedSchema) {
+        for ext in crate::extensions::required(schema) {
+            let mut tables: Vec<(&TableInfo, Vec<&str>)> = Vec::new();
+            for (table, col) in &ext.columns {
+                match tables.iter_mut().find(|(t, _)| std::ptr::eq(*t, *table)) {
+                    Some((_, columns)) => columns.push(&col.name),
+                    None => tables.push((table, vec![&col.name])),
+                }
+            }
+            for (table, columns) in tables {
+                let verb = if columns.len() == 1 { "needs" } else { "need" };
+                self.push(
+                    WarningKind::ExtensionDependency,
+                    table_path(table),
+                    format!("{} {verb} the {} extension", columns.join(", "), ext.name),
+                );
+            }
+        }
+    }
+
+    /// What the SQLAlchemy generators lose: types that fall back to a
+    /// stand-in, columnstore indexes they cannot declare, and (with
+    /// `attributes`, for the class-based generators) column names that
+    /// are not valid attribute names.
+    pub fn models(
+        &mut self,
+        schema: &IntrospectedSchema,
+        options: &GeneratorOptions,
+        attributes: bool,
+    ) {
+        for table in &schema.tables {
+            for col in &table.columns {
+                // Named enum columns render as `Enum(...)`, not their mapping.
+                let mapped = crate::codegen::find_column_enum(col, &schema.enums)
+                    .is_none()
+                    .then(|| crate::codegen::map_column_type_with(col, schema.dialect, options));
+                if let Some(note) =
+                    crate::typemap::fallback_note(col, schema.dialect, mapped.as_ref())
+                {
+                    self.push(
+                        WarningKind::TypeFallback,
+                        column_path(table, &col.name),
+                        note,
+                    );
+                }
+            }
+            for index in table.indexes.iter().filter(|index| index.columnstore) {
+                self.push(
+                    WarningKind::SkippedConstruct,
+                    table_path(table),
+                    format!("columnstore index {} is not declared", index.name),
+                );
+            }
+            if !attributes {
+                continue;
+            }
+            let names = class_attr_names(table, schema.dialect, options);
+            for (col, attr) in table.columns.iter().zip(names) {
+                if attr != col.name && options.rename_map.attr_name(table, col).is_none() {
+                    self.push(
+                        WarningKind::NameSanitized,
+                        column_path(table, &col.name),
+                        format!("mapped to attribute {attr}"),
+                    );
+                }
+            }
+        }
+    }
+
+    /// The end-of-run summary: a count line, then one line per warning.
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!("uvg: {} warning(s)", self.items.len())];
+        lines.extend(self.items.iter().map(|warning| format!("  {warning}")));
+        lines.join("\n")
+    }
+}
+
+/// `schema.table`, or just `table` when the schema is empty.
+pub(crate) fn table_path(table: &TableInfo) -> String {
+    if table.schema.is_empty() {
+        table.name.clone()
+    } else {
+        format!("{}.{}", table.schema, table.name)
+    }
+}
+
+pub(crate) fn column_path(table: &TableInfo, column: &str) -> String {
+    format!("{}.{column}", table_path(table))
+}
+
+#[cfg(test)]
+#[path = "warnings_tests.rs"]
+mod tests;
@@ -0,0 +1,284 @@
+use super::*;
+use crate::testutil::{col, schema_mssql, schema_pg, schema_sqlite, table};
+
+#[test]
+fn models_report_type_fallbacks_columnstore_and_renamed_columns() {
+    let mut schema = schema_mssql(vec![table("nodes")
+        .schema("dbo")
+        .column(col("path").udt("hierarchyid").build())
+        .column(col("class").udt("int").build())
+        .column(col("label").udt("nvarchar").max_length(50).build())
+        .index("cci_nodes", &["label"], false)
+        .build()]);
+    schema.tables[0].indexes[0].columnstore = true;
+
+    let mut warnings = Warnings::new();
+    warnings.models(&schema, &GeneratorOptions::default(), true);
+    let found: Vec<(WarningKind, &str, &str)> = warnings
+        .iter()
+        .map(|w| (w.kind, w.object.as_str(), w.message.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (
+                WarningKind::TypeFallback,
+                "dbo.nodes.path",
+                "hierarchyid has no SQLAlchemy type; mapped to LargeBinary"
+            ),
+            (
+                WarningKind::SkippedConstruct,
+                "dbo.nodes",
+                "columnstore index cci_nodes is not declared"
+            ),
+            (
+                WarningKind::NameSanitized,
+                "dbo.nodes.class",
+                "mapped to attribute class_"
+            ),
+        ]
+    );
+
+    // `Table()` output keeps column names as they are.
+    let mut warnings = Warnings::new();
+    warnings.models(&schema, &GeneratorOptions::default(), false);
+    assert!(warnings
+        .iter()
+        .all(|w| w.kind != WarningKind::NameSanitized));
+}
+
+#[test]
+fn rename_map_entries_are_not_reported() {
+    let schema = schema_sqlite(vec![table("orders")
+        .schema("main")
+        .column(col("order id").udt("INTEGER").build())
+        .build()]);
+    let options = GeneratorOptions {
+        rename_map: crate::naming::RenameMap::parse(
+            "[columns]\n\"orders.order id\" = \"order_id\"\n",
+        )
+        .unwrap(),
+        ..GeneratorOptions::default()
+    };
+
+    let mut warnings = Warnings::new();
+    warnings.models(&schema, &options, true);
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn summary_lists_introspection_skips() {
+    let mut orders = table("orders").schema("main").build();
+    orders.skipped = vec!["index ix_lower_email has only expression keys and was skipped".into()];
+    let schema = schema_sqlite(vec![orders]);
+
+    let mut warnings = Warnings::new();
+    warnings.introspection(&schema);
+    assert_eq!(
+        warnings.summary(),
+        "uvg: 1 warning(s)\n  skipped: main.orders: index ix_lower_email has only expression keys and was skipped"
+    );
+}
+
+#[test]
+fn inferred_keys_are_not_reported_as_skips() {
+    let mut logs = table("logs").schema("main").build();
+    logs.inferred_keys = vec![
+        "no primary key in the database; msg, at (all columns) promoted to primary key".into(),
+    ];
+    let schema = schema_sqlite(vec![logs]);
+
+    let mut warnings = Warnings::new();
+    warnings.introspection(&schema);
+    assert!(warnings.is_empty());
+    warnings.inferred_keys(&schema);
+    assert_eq!(
+        warnings.summary(),
+        "uvg: 1 warning(s)\n  inferred key: main.logs: no primary key in the database; msg, at (all columns) promoted to primary key"
+    );
+}
+
+#[test]
+fn foreign_keys_to_missing_tables_are_reported() {
+    let schema = crate::testutil::schema_pg(vec![table("orders")
+        .column(col("user_id").build())
+        .fk("orders_user_id_fkey", &["user_id"], "users", &["id"])
+        .fk("orders_parent_fkey", &["user_id"], "orders", &["id"])
+        .build()]);
+
+    let mut warnings = Warnings::new();
+    warnings.dangling_fks(&schema);
+
+    assert_eq!(
+        warnings.summary(),
+        "uvg: 1 warning(s)\n  dangling reference: public.orders: foreign key \
+         orders_user_id_fkey references public.users, which is not generated"
+    );
+}
+
+#[test]
+fn cross_database_foreign_keys_are_reported() {
+    let mut schema = schema_mssql(vec![
+        table("customers")
+            .schema("dbo")
+            .column(col("id").build())
+            .build(),
+        table("orders")
+            .schema("dbo")
+            .column(col("customer_id").build())
+            .fk(
+                "FK_orders_customers",
+                &["customer_id"],
+                "customers",
+                &["id"],
+            )
+            .build(),
+    ]);
+    let fk = schema.tables[1].constraints[0]
+        .foreign_key
+        .as_mut()
+        .unwrap();
+    fk.ref_schema = "dbo".to_string();
+    fk.ref_database = Some("crm".to_string());
+
+    let mut warnings = Warnings::new();
+    warnings.dangling_fks(&schema);
+
+    assert_eq!(
+        warnings.summary(),
+        "uvg: 1 warning(s)\n  dangling reference: dbo.orders: foreign key \
+         FK_orders_customers references crm.dbo.customers, a table in another database"
+    );
+}
+
+#[test]
+fn extensions_report_dependent_columns_per_table() {
+    let schema = schema_pg(vec![
+        table("users")
+            .column(col("email").udt("citext").build())
+            .column(col("nickname").udt("citext").nullable().build())
+            .column(col("attrs").udt("hstore").nullable().build())
+            .build(),
+        table("orders").column(col("id").build()).build(),
+    ]);
+
+    let mut warnings = Warnings::new();
+    warnings.extensions(&schema);
+    let lines: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        lines,
+        [
+            "extension: public.users: email, nickname need the citext extension",
+            "extension: public.users: attrs needs the hstore extension",
+        ]
+    );
+}
+
+#[test]
+fn models_report_uppercased_sqlalchemy_fallbacks() {
+    let schema = crate::testutil::schema_pg_with_enums(
+        vec![table("places")
+            .column(col("shape").udt("geometry").build())
+            .column(col("mood").udt("mood").build())
+            .column(col("ip").udt("inet").build())
+            .build()],
+        vec![crate::schema::EnumInfo {
+            name: "mood".to_string(),
+            schema: Some("public".to_string()),
+            values: vec!["ok".to_string()],
+        }],
+    );
+
+    let mut warnings = Warnings::new();
+    warnings.models(&schema, &GeneratorOptions::default(), false);
+    let found: Vec<(&str, &str)> = warnings
+        .iter()
+        .map(|w| (w.object.as_str(), w.message.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [(
+            "public.places.shape",
+            "geometry has no SQLAlchemy type; mapped to GEOMETRY, which sqlalchemy does not \
+             export (add a --typemap override)"
+        )]
+    );
+
+    // GeoAlchemy2 maps it to an importable type.
+    let options = GeneratorOptions {
+        geoalchemy: true,
+        ..GeneratorOptions::default()
+    };
+    let mut warnings = Warnings::new();
+    warnings.models(&schema, &options, false);
+    assert!(warnings.iter().next().is_none());
+}
+
+#[test]
+fn fk_cycles_are_reported() {
+    let schema = schema_pg(vec![
+        table("a")
+            .column(col("id").build())
+            .column(col("b_id").build())
+            .pk("a_pkey", &["id"])
+            .fk("a_b_fkey", &["b_id"], "b", &["id"])
+            .build(),
+        table("b")
+            .column(col("id").build())
+            .column(col("a_id").build())
+            .pk("b_pkey", &["id"])
+            .fk("b_a_fkey", &["a_id"], "a", &["id"])
+            .build(),
+    ]);
+
+    let mut warnings = Warnings::new();
+    warnings.fk_cycles(&schema);
+    let found: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        found,
+        [
+            "circular reference: public.b: foreign key b_a_fkey closes the cycle a -> b -> a; \
+          declared with use_alter=True"
+        ]
+    );
+}
+
+#[test]
+fn keyless_tables_with_replica_identity_nothing_are_reported() {
+    let mut schema = schema_pg(vec![
+        table("events").column(col("payload").build()).build(),
+        table("users")
+            .column(col("id").build())
+            .pk("users_pkey", &["id"])
+            .build(),
+        table("audit").column(col("payload").build()).build(),
+    ]);
+    schema.tables[0].replica_identity = Some(ReplicaIdentity::Nothing);
+    schema.tables[0].publications = vec!["cdc".to_string()];
+    schema.tables[1].replica_identity = Some(ReplicaIdentity::Nothing);
+    schema.tables[2].replica_identity = Some(ReplicaIdentity::Full);
+
+    let mut warnings = Warnings::new();
+    warnings.replica_identity(&schema);
+    assert_eq!(
+        warnings.summary(),
+        "uvg: 1 warning(s)\n  replication: public.events: no primary key and REPLICA IDENTITY \
+         NOTHING (published in cdc); UPDATE and DELETE will break logical replication consumers"
+    );
+}
+
+#[test]
+fn synthesized_keys_do_not_hide_replica_identity_nothing() {
+    let mut schema = schema_pg(vec![table("events").column(col("payload").build()).build()]);
+    schema.tables[0].replica_identity = Some(ReplicaIdentity::Nothing);
+    crate::primary_keys::synthesize(&mut schema);
+    assert!(has_primary_key(&schema.tables[0].constraints));
+
+    let mut warnings = Warnings::new();
+    warnings.replica_identity(&schema);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings.iter().next().unwrap().kind,
+        WarningKind::ReplicationHazard
+    );
+}
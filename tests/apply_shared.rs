@@ -2,7 +2,7 @@ mod common;
 
 use common::{exec_sql, tmpdir};
 use uvg::apply::{apply_manifest, apply_sql, ApplyOptions, ParseCheckStatus};
-use uvg::connection::parse_connection_url;
+use uvg::connection::{parse_connection_url, MssqlAuth};
 use uvg::output::{Manifest, Stats};
 
 async fn table_exists(db_path: &std::path::Path, table: &str) -> bool {
@@ -36,7 +36,7 @@ async fn shared_apply_executes_valid_sql_and_blocks_marker_blobs_atomically() {
         "sqlite:////{}",
         target.display().to_string().trim_start_matches('/')
     );
-    let config = parse_connection_url(&target_url, false).unwrap();
+    let config = parse_connection_url(&target_url, MssqlAuth::Sql, false).unwrap();
     let options = ApplyOptions::new(true, 7, false);
 
     let report = apply_sql(
@@ -121,7 +121,7 @@ async fn manifest_failure_warns_that_nontransactional_work_may_have_persisted()
         "sqlite:////{}",
         target.display().to_string().trim_start_matches('/')
     );
-    let config = parse_connection_url(&target_url, false).unwrap();
+    let config = parse_connection_url(&target_url, MssqlAuth::Sql, false).unwrap();
     let error = apply_manifest(
         &config,
         &manifest,
@@ -0,0 +1,56 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::{exec_sql, run_uvg, tmpdir};
+
+    #[tokio::test]
+    async fn test_check_passes_when_current_and_fails_on_drift() {
+        let dir = tmpdir("check-drift");
+        let source = dir.join("source.db");
+        let models = dir.join("models.py");
+        exec_sql(
+            &source,
+            "CREATE TABLE users(id INTEGER PRIMARY KEY, name TEXT NOT NULL);",
+        )
+        .await;
+        let src_url = format!("sqlite:///{}", source.display());
+        let models_path = models.display().to_string();
+
+        let out = run_uvg(&["--outfile", &models_path, &src_url]);
+        assert!(
+            out.status.success(),
+            "generate failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        // The provenance header changes every run but is not drift.
+        let written = std::fs::read_to_string(&models).unwrap();
+        assert!(written.starts_with("# Generated by uvg "), "{written}");
+
+        let out = run_uvg(&["--check", &models_path, &src_url]);
+        assert!(
+            out.status.success(),
+            "check of fresh models failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        assert!(out.stdout.is_empty());
+
+        exec_sql(&source, "ALTER TABLE users ADD COLUMN email TEXT;").await;
+        let out = run_uvg(&["--check", "--outfile", &models_path, &src_url]);
+        assert!(!out.status.success(), "drift must exit non-zero");
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(
+            stdout.contains(&format!("--- a/{models_path}")),
+            "missing diff header: {stdout}"
+        );
+        assert!(
+            stdout.contains("+    email"),
+            "missing added column: {stdout}"
+        );
+        let before = std::fs::read_to_string(&models).unwrap();
+        assert!(
+            !before.contains("email"),
+            "--check must not rewrite the file"
+        );
+    }
+}
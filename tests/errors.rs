@@ -0,0 +1,71 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::{exec_sql, run_uvg, tmpdir};
+
+    fn json_error(stderr: &[u8]) -> serde_json::Value {
+        let stderr = String::from_utf8_lossy(stderr);
+        let line = stderr
+            .lines()
+            .last()
+            .unwrap_or_else(|| panic!("no error on stderr"));
+        serde_json::from_str(line).unwrap_or_else(|e| panic!("{e}: {stderr}"))
+    }
+
+    #[tokio::test]
+    async fn test_empty_schema_exits_5_with_json_error() {
+        let dir = tmpdir("errors-empty");
+        let source = dir.join("empty.db");
+        exec_sql(&source, "CREATE VIEW v AS SELECT 1 AS one;").await;
+        let src_url = format!("sqlite:///{}", source.display());
+
+        let out = run_uvg(&["--noviews", "--error-format", "json", &src_url]);
+
+        assert_eq!(out.status.code(), Some(5));
+        let error = json_error(&out.stderr);
+        assert_eq!(error["kind"], "empty_schema");
+        assert_eq!(error["exit_code"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_connection_and_generation_failures_have_own_codes() {
+        let dir = tmpdir("errors-codes");
+        let missing = format!("sqlite:///{}", dir.join("missing/none.db").display());
+        let out = run_uvg(&["--error-format", "json", &missing]);
+        assert_eq!(out.status.code(), Some(3));
+        assert_eq!(json_error(&out.stderr)["kind"], "connection");
+
+        let source = dir.join("source.db");
+        exec_sql(&source, "CREATE TABLE users(id INTEGER PRIMARY KEY);").await;
+        let src_url = format!("sqlite:///{}", source.display());
+        let outfile = dir.join("no-such-dir/models.py").display().to_string();
+        let out = run_uvg(&["--outfile", &outfile, &src_url]);
+        assert_eq!(out.status.code(), Some(6));
+        assert!(String::from_utf8_lossy(&out.stderr).starts_with("Error: Cannot write"));
+
+        let out = run_uvg(&["--generator", "nope", &src_url]);
+        assert_eq!(out.status.code(), Some(2));
+    }
+
+    #[test]
+    fn test_usage_errors_honor_json_format() {
+        let out = run_uvg(&["--error-format", "json", "--no-such-flag", "sqlite:///x.db"]);
+        assert_eq!(out.status.code(), Some(2));
+        let error = json_error(&out.stderr);
+        assert_eq!(error["kind"], "usage");
+        assert_eq!(error["exit_code"], 2);
+        assert_eq!(
+            error["message"],
+            "unexpected argument '--no-such-flag' found"
+        );
+
+        let out = run_uvg(&["--no-such-flag", "sqlite:///x.db"]);
+        assert_eq!(out.status.code(), Some(2));
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(
+            stderr.starts_with("error: unexpected argument '--no-such-flag' found"),
+            "{stderr}"
+        );
+    }
+}
@@ -0,0 +1,42 @@
+mod common;
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::common::{exec_sql, run_uvg, tmpdir};
+
+    #[tokio::test]
+    async fn test_exec_generator_pipes_schema_json_through_plugin() {
+        let dir = tmpdir("exec-plugin");
+        let source = dir.join("source.db");
+        exec_sql(&source, "CREATE TABLE users(id INTEGER PRIMARY KEY);").await;
+        let src_url = format!("sqlite:///{}", source.display());
+        let plugin = dir.join("gen.sh");
+        std::fs::write(
+            &plugin,
+            "grep -o '\"name\": \"users\"' | head -n 1\necho \"args: $*\"\n",
+        )
+        .unwrap();
+
+        let generator = format!("exec:sh {} --upper", plugin.display());
+        let out = run_uvg(&["--generator", &generator, &src_url]);
+        assert_eq!(
+            out.status.code(),
+            Some(0),
+            "{}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&out.stdout),
+            "\"name\": \"users\"\nargs: --upper\n"
+        );
+
+        let failing = dir.join("fail.sh");
+        std::fs::write(&failing, "echo broken >&2\nexit 3\n").unwrap();
+        let generator = format!("exec:sh {}", failing.display());
+        let out = run_uvg(&["--generator", &generator, &src_url]);
+        assert_eq!(out.status.code(), Some(6));
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(stderr.contains("broken"), "{stderr}");
+        assert!(stderr.contains("exited with status 3"), "{stderr}");
+    }
+}
@@ -100,6 +100,100 @@ mod tests {
         std::fs::remove_dir_all(&dir).ok();
     }
 
+    #[tokio::test]
+    async fn test_follow_fks_adds_referenced_tables() {
+        // `--tables orders` alone would leave `users` out and the
+        // generated ForeignKey dangling; `--follow-fks` pulls it in.
+        let dir = tmpdir("follow-fks");
+        let source = dir.join("source.db");
+        exec_sql(
+            &source,
+            "CREATE TABLE users(id INTEGER PRIMARY KEY);
+             CREATE TABLE orders(id INTEGER PRIMARY KEY, user_id INTEGER REFERENCES users(id));
+             CREATE TABLE audit(id INTEGER PRIMARY KEY);",
+        )
+        .await;
+        let src_url = format!("sqlite:///{}", source.display());
+
+        let out = run_uvg(&[
+            "--generator",
+            "tables",
+            "--tables",
+            "orders",
+            "--follow-fks",
+            &src_url,
+        ]);
+        assert!(
+            out.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        let stdout = String::from_utf8_lossy(&out.stdout);
+
+        assert!(stdout.contains("t_orders = Table("), "{stdout}");
+        assert!(stdout.contains("t_users = Table("), "{stdout}");
+        assert!(
+            !stdout.contains("t_audit"),
+            "unrelated table leaked: {stdout}"
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_dangling_fks_modes() {
+        let dir = tmpdir("dangling-fks");
+        let source = dir.join("source.db");
+        exec_sql(
+            &source,
+            "CREATE TABLE users(id INTEGER PRIMARY KEY);
+             CREATE TABLE orders(id INTEGER PRIMARY KEY, user_id INTEGER REFERENCES users(id));",
+        )
+        .await;
+        let src_url = format!("sqlite:///{}", source.display());
+        let run = |mode: &str| {
+            let out = run_uvg(&[
+                "--generator",
+                "tables",
+                "--tables",
+                "orders",
+                "--dangling-fks",
+                mode,
+                &src_url,
+            ]);
+            assert!(
+                out.status.success(),
+                "stderr: {}",
+                String::from_utf8_lossy(&out.stderr)
+            );
+            (
+                String::from_utf8_lossy(&out.stdout).into_owned(),
+                String::from_utf8_lossy(&out.stderr).into_owned(),
+            )
+        };
+
+        let (stdout, stderr) = run("keep");
+        assert!(stdout.contains("ForeignKeyConstraint"), "{stdout}");
+        assert!(
+            stderr.contains("dangling reference: main.orders: foreign key"),
+            "{stderr}"
+        );
+
+        let (stdout, stderr) = run("drop");
+        assert!(!stdout.contains("ForeignKeyConstraint"), "{stdout}");
+        assert!(stdout.contains("# WARNING: foreign key"), "{stdout}");
+        assert!(
+            stderr.contains("skipped: main.orders: foreign key"),
+            "{stderr}"
+        );
+
+        let (stdout, stderr) = run("include");
+        assert!(stdout.contains("t_users = Table("), "{stdout}");
+        assert!(!stderr.contains("warning"), "{stderr}");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[tokio::test]
     async fn test_invalid_tables_glob_errors_before_connection() {
         // A malformed glob pattern must fail with a clean error before any
@@ -155,4 +155,34 @@ mod tests {
 
         std::fs::remove_dir_all(&dir).ok();
     }
+
+    #[tokio::test]
+    async fn test_split_by_schema_writes_into_out_dir() {
+        let dir = tmpdir("split-by-schema-out-dir");
+        let source = dir.join("source.db");
+        let models = dir.join("models");
+
+        exec_sql(&source, "CREATE TABLE users(id INTEGER PRIMARY KEY);").await;
+        let src_url = format!("sqlite:///{}", source.display());
+        let models_str = models.display().to_string();
+
+        let out = run_uvg(&["--split-by-schema", "--out-dir", &models_str, &src_url]);
+        assert!(
+            out.status.success(),
+            "run failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        assert!(
+            out.stdout.is_empty(),
+            "modules went to stdout: {}",
+            String::from_utf8_lossy(&out.stdout)
+        );
+        let files: Vec<String> = snapshot_dir(&models)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert!(files.iter().any(|name| name == "main.py"), "{files:?}");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
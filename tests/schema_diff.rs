@@ -0,0 +1,64 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::{exec_sql, run_uvg, tmpdir};
+
+    #[tokio::test]
+    async fn test_diff_reports_changes_between_databases() {
+        let dir = tmpdir("schema-diff");
+        let a = dir.join("a.db");
+        let b = dir.join("b.db");
+        exec_sql(
+            &a,
+            "CREATE TABLE users(id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+             CREATE TABLE legacy(id INTEGER PRIMARY KEY);",
+        )
+        .await;
+        exec_sql(
+            &b,
+            "CREATE TABLE users(id INTEGER PRIMARY KEY, name TEXT, email TEXT);
+             CREATE TABLE orders(id INTEGER PRIMARY KEY);",
+        )
+        .await;
+        let a_url = format!("sqlite:///{}", a.display());
+        let b_url = format!("sqlite:///{}", b.display());
+
+        let out = run_uvg(&["diff", &a_url, &b_url]);
+        assert!(
+            out.status.success(),
+            "diff failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(stdout.contains("+ table main.orders\n"), "{stdout}");
+        assert!(stdout.contains("- table main.legacy\n"), "{stdout}");
+        assert!(stdout.contains("~ table main.users\n"), "{stdout}");
+        assert!(stdout.contains("    + column email\n"), "{stdout}");
+        assert!(
+            stdout.contains("    ~ column name\n        nullable: false -> true\n"),
+            "{stdout}"
+        );
+
+        let report = dir.join("diff.json");
+        let report_path = report.display().to_string();
+        let out = run_uvg(&[
+            "diff",
+            "--format",
+            "json",
+            "-o",
+            &report_path,
+            &a_url,
+            &a_url,
+        ]);
+        assert!(
+            out.status.success(),
+            "json diff failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&report).unwrap()).unwrap();
+        assert_eq!(json["tables_added"], serde_json::json!([]));
+        assert_eq!(json["tables_changed"], serde_json::json!([]));
+    }
+}
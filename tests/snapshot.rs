@@ -46,6 +46,8 @@ mod tests {
             "ddl",
             "--target-dialect",
             "sqlite",
+            "--options",
+            "noheader",
             &src_url,
             &tgt_url,
         ]);
@@ -59,6 +61,8 @@ mod tests {
             "ddl",
             "--target-dialect",
             "sqlite",
+            "--options",
+            "noheader",
             &snapshot_ref,
             &tgt_url,
         ]);
@@ -74,6 +78,8 @@ mod tests {
             "ddl",
             "--target-dialect",
             "sqlite",
+            "--options",
+            "noheader",
             &tgt_url,
             &src_url,
         ]);
@@ -87,6 +93,8 @@ mod tests {
             "ddl",
             "--target-dialect",
             "sqlite",
+            "--options",
+            "noheader",
             &tgt_url,
             &snapshot_ref,
         ]);
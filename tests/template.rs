@@ -0,0 +1,41 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::{exec_sql, run_uvg, tmpdir};
+
+    #[tokio::test]
+    async fn test_template_generator_renders_user_template() {
+        let dir = tmpdir("template-generator");
+        let source = dir.join("source.db");
+        exec_sql(
+            &source,
+            "CREATE TABLE users(id INTEGER PRIMARY KEY, email VARCHAR(80) NOT NULL);",
+        )
+        .await;
+        let src_url = format!("sqlite:///{}", source.display());
+        let template = dir.join("models.tera");
+        std::fs::write(
+            &template,
+            "{% for t in tables %}{{ t.class_name }}{% for c in t.columns %} {{ c.name }}:{{ c.python_type }}{% endfor %}\n{% endfor %}",
+        )
+        .unwrap();
+        let template = template.display().to_string();
+
+        let out = run_uvg(&["--generator", "template", "--template", &template, &src_url]);
+        assert_eq!(
+            out.status.code(),
+            Some(0),
+            "{}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&out.stdout),
+            "Users id:int email:str\n"
+        );
+
+        let out = run_uvg(&["--generator", "template", &src_url]);
+        assert_ne!(out.status.code(), Some(0));
+        assert!(String::from_utf8_lossy(&out.stderr).contains("requires --template"));
+    }
+}
@@ -0,0 +1,73 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::{exec_sql, run_uvg, tmpdir};
+
+    #[tokio::test]
+    async fn test_warnings_are_summarized_and_fail_strict_runs() {
+        let dir = tmpdir("warnings-strict");
+        let source = dir.join("source.db");
+        exec_sql(
+            &source,
+            "CREATE TABLE notes(id INTEGER PRIMARY KEY, body);
+             CREATE INDEX ix_notes_lower_body ON notes(lower(body));",
+        )
+        .await;
+        let src_url = format!("sqlite:///{}", source.display());
+
+        let out = run_uvg(&["--generator", "tables", &src_url]);
+        assert_eq!(out.status.code(), Some(0));
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(stdout.contains(
+            "# WARNING: index ix_notes_lower_body has only expression keys and was skipped"
+        ));
+        assert!(stdout.contains("    # WARNING: no declared type; mapped to NullType"));
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(stderr.contains("uvg: 2 warning(s)"), "{stderr}");
+        assert!(stderr.contains("type fallback: main.notes.body: no declared type"));
+
+        let out = run_uvg(&["--generator", "tables", "--strict", &src_url]);
+        assert_eq!(out.status.code(), Some(7));
+        assert!(String::from_utf8_lossy(&out.stdout).contains("t_notes = Table("));
+        assert!(String::from_utf8_lossy(&out.stderr).contains("Error: 2 warning(s) with --strict"));
+    }
+
+    #[tokio::test]
+    async fn test_synthesized_primary_key_stays_out_of_ddl() {
+        let dir = tmpdir("synthesize-pk");
+        let source = dir.join("source.db");
+        exec_sql(
+            &source,
+            "CREATE TABLE logs(msg TEXT NOT NULL, at TEXT NOT NULL)",
+        )
+        .await;
+        let src_url = format!("sqlite:///{}", source.display());
+
+        let out = run_uvg(&["--options", "synthesize_pk", &src_url]);
+        assert_eq!(out.status.code(), Some(0));
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(stdout.contains("class Logs(Base):"), "{stdout}");
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(
+            stderr.contains("inferred key: main.logs: no primary key"),
+            "{stderr}"
+        );
+        assert!(!stderr.contains("skipped:"), "{stderr}");
+
+        for generator in ["tables", "ddl", "json", "dbml"] {
+            let out = run_uvg(&[
+                "--generator",
+                generator,
+                "--options",
+                "synthesize_pk",
+                &src_url,
+            ]);
+            assert_eq!(out.status.code(), Some(0));
+            let stdout = String::from_utf8_lossy(&out.stdout);
+            assert!(!stdout.contains("logs_pkey"), "{generator}: {stdout}");
+            assert!(!stdout.contains("promoted"), "{generator}: {stdout}");
+            assert!(!String::from_utf8_lossy(&out.stderr).contains("promoted"));
+        }
+    }
+}